@@ -0,0 +1,47 @@
+"""
+CLI commands for inspecting configuration.
+
+One command:
+  az config show [--resolved]  — Config files in use, or the effective values
+"""
+
+import typer
+from rich.console import Console
+from rich.syntax import Syntax
+from rich.table import Table
+
+from azathoth.config import config_files, get_config
+
+console = Console()
+app = typer.Typer(help="Inspect azathoth configuration.", no_args_is_help=True)
+
+
+@app.command("show")
+def show_cmd(
+    resolved: bool = typer.Option(
+        False,
+        "--resolved",
+        "-r",
+        help="Print the effective merged configuration (files, profile, env).",
+    ),
+):
+    """Show the configuration files, or the resolved settings with --resolved."""
+    settings = get_config()
+
+    if not resolved:
+        for path in config_files():
+            if not path.is_file():
+                console.print(f"[dim]{path} (not found)[/]")
+                continue
+            console.print(f"[bold cyan]{path}[/]")
+            console.print(Syntax(path.read_text(encoding="utf-8"), "toml"))
+        return
+
+    profile = settings.profile or "-"
+    table = Table(title=f"Resolved configuration — profile: {profile}")
+    table.add_column("Key", style="bold cyan")
+    table.add_column("Value")
+    for key, value in settings.model_dump().items():
+        # SecretStr values render masked via str().
+        table.add_row(key, str(value))
+    console.print(table)
@@ -0,0 +1,58 @@
+"""
+CLI commands for maintaining directive packs.
+
+One command:
+  az directives lint TARGET...  — Validate directive files, directories or names
+"""
+
+from typing import List
+
+import typer
+from rich.console import Console
+
+from azathoth.core.directive_lint import MAX_SECTION_TOKENS, lint_directives
+from azathoth.core.exceptions import DirectiveError
+
+console = Console()
+app = typer.Typer(help="Maintain coding directives.", no_args_is_help=True)
+
+
+@app.command("lint")
+def lint_cmd(
+    targets: List[str] = typer.Argument(
+        ..., help="Directive files, directories of directives, or directive names."
+    ),
+    max_section_tokens: int = typer.Option(
+        MAX_SECTION_TOKENS,
+        "--max-section-tokens",
+        help="Warn about sections larger than this many (estimated) tokens.",
+    ),
+    strict: bool = typer.Option(
+        False, "--strict", help="Exit non-zero on warnings too."
+    ),
+):
+    """Check directives for front-matter, extends, link and structure problems."""
+    errors = warnings = 0
+    for target in targets:
+        try:
+            report = lint_directives(target, max_section_tokens)
+        except DirectiveError as exc:
+            console.print(f"[red]Error: {exc}[/red]")
+            raise typer.Exit(1)
+        errors += report.errors
+        warnings += report.warnings
+        for lint in report.files:
+            if not lint.diagnostics:
+                console.print(f"[green]✓[/] {lint.path}")
+                continue
+            for d in lint.diagnostics:
+                where = f"{lint.path}:{d.line}" if d.line else lint.path
+                colour = "red" if d.severity == "error" else "yellow"
+                console.print(
+                    f"{where}: [{colour}]{d.severity}[/] [dim]{d.code}[/] {d.message}",
+                    highlight=False,
+                )
+
+    console.print(f"\n{errors} error(s), {warnings} warning(s).")
+    if errors or (strict and warnings):
+        raise typer.Exit(1)
@@ -0,0 +1,89 @@
+"""
+CLI commands for launching the MCP servers.
+
+Two commands:
+  az serve <server>   — Run a server (stdio by default, or sse/http on a port)
+  az list-servers     — Show the available servers
+"""
+
+import logging
+from typing import Optional
+
+import typer
+from rich.console import Console
+from rich.table import Table
+
+from azathoth.config import get_config
+from azathoth.mcp import SERVERS, load_server
+from azathoth.mcp.telemetry import configure_telemetry
+
+console = Console()
+log = logging.getLogger("azathoth.mcp")
+
+TRANSPORTS = ("stdio", "sse", "http")
+
+
+def serve_cmd(
+    server: str = typer.Argument(..., help="Server to run (see list-servers)."),
+    transport: Optional[str] = typer.Option(
+        None,
+        "--transport",
+        "-t",
+        help="Transport: stdio, sse or http (default: mcp_transport).",
+    ),
+    host: Optional[str] = typer.Option(
+        None, "--host", help="Bind address for sse/http (default: mcp_host)."
+    ),
+    port: Optional[int] = typer.Option(
+        None, "--port", help="Port for sse/http transports (default: mcp_port)."
+    ),
+    dry_run: bool = typer.Option(
+        False, "--dry-run", help="Workflow server: report mutating commands only."
+    ),
+    require_approval: bool = typer.Option(
+        False,
+        "--require-approval",
+        help="Refuse destructive tools unless the call carries approval_token.",
+    ),
+):
+    """Run one of the MCP servers."""
+    settings = get_config()
+    transport = transport or settings.mcp_transport
+    if server not in SERVERS:
+        console.print(
+            f"[bold red]Unknown server:[/] {server}. "
+            f"Available: {', '.join(SERVERS)}"
+        )
+        raise typer.Exit(1)
+    if transport not in TRANSPORTS:
+        console.print(
+            f"[bold red]Unknown transport:[/] {transport}. "
+            f"Choose one of: {', '.join(TRANSPORTS)}"
+        )
+        raise typer.Exit(1)
+
+    if dry_run:
+        settings.workflow_dry_run = True
+    if require_approval:
+        settings.approval_required = True
+
+    configure_telemetry(server)
+    mcp = load_server(server)
+    if transport == "stdio":
+        log.info("Serving %s over stdio", server)
+        mcp.run(transport="stdio")
+    else:
+        host, port = host or settings.mcp_host, port or settings.mcp_port
+        log.info("Serving %s over %s on %s:%s", server, transport, host, port)
+        mcp.run(transport=transport, host=host, port=port)
+
+
+def list_servers_cmd():
+    """List the MCP servers that `serve` can launch."""
+    table = Table(title="MCP servers", show_lines=False)
+    table.add_column("Name", style="bold cyan")
+    table.add_column("Module", style="dim")
+    table.add_column("Description")
+    for name, (module, description) in SERVERS.items():
+        table.add_row(name, module, description)
+    console.print(table)
@@ -4,7 +4,7 @@ CLI commands for git workflow automation.
 Three commands:
   az workflow commit   — AI-powered commit message generation
   az workflow status   — At-a-glance repo overview
-  az workflow release  — AI-powered release notes + gh release
+  az workflow release  — AI-powered release notes + forge release (gh/glab/tea)
 """
 
 import asyncio
@@ -18,15 +18,14 @@ from rich.text import Text
 from typing import Optional
 
 from azathoth.core.workflow import (
-    stage_all,
-    commit,
-    get_diff,
     get_latest_tag,
     get_log_since,
     create_release,
-    _run_git,
 )
-from azathoth.core.prompts import get_commit_system_prompt, get_release_system_prompt
+from azathoth.core.vcs import get_vcs
+from azathoth.core.commit_policy import load_commit_policy
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core import prompts
 from azathoth.core.llm import generate, LLMError
 
 console = Console()
@@ -55,9 +54,16 @@ def commit_cmd(
     """Generate an AI commit message and commit staged changes."""
 
     async def _run():
+        try:
+            vcs = get_vcs()
+            policy = load_commit_policy()
+        except WorkflowError as exc:
+            console.print(f"[bold red]{exc}[/]")
+            raise typer.Exit(1)
+
         # 1. Stage everything
-        await stage_all()
-        diff = await get_diff(staged=True)
+        await vcs.stage()
+        diff = await vcs.diff(staged=True)
         if not diff:
             console.print("[yellow]No staged changes — nothing to commit.[/]")
             raise typer.Exit()
@@ -65,7 +71,7 @@ def commit_cmd(
         console.print(f"[dim]Staged diff: {len(diff):,} chars[/]")
 
         # 2. Ask Gemini
-        system_prompt = get_commit_system_prompt(focus)
+        system_prompt = prompts.commit_system(focus, policy)
         with console.status("[bold cyan]Generating commit message…[/]"):
             try:
                 raw = await asyncio.to_thread(
@@ -92,6 +98,13 @@ def commit_cmd(
         preview.append(body, style="dim")
         console.print(Panel(preview, title="📝 Commit Message", border_style="cyan"))
 
+        violations = policy.check(title, body)
+        if violations:
+            console.print("[bold red]✗ Message violates the commit policy:[/]")
+            for v in violations:
+                console.print(f"  • {v}")
+            raise typer.Exit(1)
+
         if dry_run:
             console.print("[yellow]--dry-run: skipping commit.[/]")
             return
@@ -101,7 +114,7 @@ def commit_cmd(
             console.print("[yellow]Aborted.[/]")
             return
 
-        res = await commit(title, body)
+        res = await vcs.commit(title, body)
         if res.success:
             console.print("[bold green]✓ Committed.[/]")
         else:
@@ -118,27 +131,22 @@ def status_cmd():
     """Show a rich overview of the current repo state."""
 
     async def _run():
-        # Branch
-        _, branch, _ = await _run_git(["rev-parse", "--abbrev-ref", "HEAD"])
-
-        # Porcelain status
-        _, porcelain, _ = await _run_git(["status", "--porcelain"])
-        staged = unstaged = untracked = 0
-        for line in porcelain.splitlines():
-            if not line:
-                continue
-            x, y = line[0], line[1]
-            if x == "?":
-                untracked += 1
-            elif x != " ":
-                staged += 1
-            if y not in (" ", "?"):
-                unstaged += 1
+        try:
+            vcs = get_vcs()
+        except WorkflowError as exc:
+            console.print(f"[bold red]{exc}[/]")
+            raise typer.Exit(1)
+
+        # Branch + working-copy status
+        status = await vcs.status()
+        branch = status.branch
+        staged, unstaged = len(status.staged), len(status.unstaged)
+        untracked = len(status.untracked)
 
         # Tag info
-        tag = await get_latest_tag()
+        tag = await vcs.latest_tag()
         if tag:
-            log = await get_log_since(tag)
+            log = await vcs.log_since(tag)
             commits_since = len(log.splitlines()) if log else 0
         else:
             commits_since = 0
@@ -153,6 +161,7 @@ def status_cmd():
         table.add_column("Key", style="bold")
         table.add_column("Value")
 
+        table.add_row("Backend", vcs.name)
         table.add_row("Branch", f"[bold]{branch}[/]")
         table.add_row("Staged", f"[green]{staged}[/]" if staged else "[dim]0[/]")
         table.add_row("Unstaged", f"[yellow]{unstaged}[/]" if unstaged else "[dim]0[/]")
@@ -182,7 +191,7 @@ def release_cmd(
         help="Override the LLM provider for this invocation (e.g. 'ollama', 'gemini').",
     ),
 ):
-    """Generate AI release notes and publish via `gh release create`."""
+    """Generate AI release notes and publish them on GitHub, GitLab or Gitea."""
 
     async def _run():
         # 1. Gather context
@@ -203,7 +212,7 @@ def release_cmd(
         )
 
         # 2. Ask Gemini
-        system_prompt = get_release_system_prompt()
+        system_prompt = prompts.release_system()
         user_msg = f"Previous tag: {tag}\n\nCommit log:\n{log}"
 
         with console.status("[bold cyan]Generating release notes…[/]"):
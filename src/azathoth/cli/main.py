@@ -6,7 +6,9 @@ import typer
 from importlib.metadata import version, PackageNotFoundError
 
 from azathoth.cli.commands.ingest import main as ingest_cmd
-from azathoth.cli.commands import workflow, i18n
+from azathoth.config import reload_config
+from azathoth.cli.commands import workflow, i18n, config, directives
+from azathoth.cli.commands.serve import serve_cmd, list_servers_cmd
 
 app = typer.Typer(
     name="azathoth",
@@ -15,8 +17,12 @@ app = typer.Typer(
 )
 
 app.command(name="ingest")(ingest_cmd)
+app.command(name="serve")(serve_cmd)
+app.command(name="list-servers")(list_servers_cmd)
 app.add_typer(workflow.app, name="workflow")
 app.add_typer(i18n.app, name="i18n")
+app.add_typer(config.app, name="config")
+app.add_typer(directives.app, name="directives")
 
 
 def _version_callback(value: bool) -> None:
@@ -41,8 +47,18 @@ def _main(
             help="Show version and exit.",
         ),
     ] = None,
+    profile: Annotated[
+        Optional[str],
+        typer.Option(
+            "--profile",
+            envvar="AZATHOTH_PROFILE",
+            help="Configuration profile to apply (e.g. work, personal, ci).",
+        ),
+    ] = None,
 ) -> None:
     """Azathoth: Dual-Protocol AI Intelligence Layer."""
+    if profile:
+        reload_config(profile)
 
 
 if __name__ == "__main__":
@@ -6,33 +6,99 @@ Provider selection (Phase 3+):
                                  OR comma-separated string from env var
   - ``Settings.llm_total_timeout``  wall-clock budget enforced by resolver
   - ``Settings.ollama_*``       Ollama daemon config (Phase 4)
+
+Configuration files (later files override earlier ones):
+  - ``$XDG_CONFIG_HOME/azathoth/config.toml``    per-user settings
+  - ``$XDG_CONFIG_HOME/azathoth/azathoth.toml``  (``~/.config`` when unset)
+  - ``./azathoth.toml``                          per-project settings, shareable
+    (the launch directory's, not that of a repo selected with ``repo_path``)
+
+Server settings may be grouped in ``[workflow]``, ``[scout]``,
+``[directives]`` and ``[mcp]`` tables: ``[workflow] dry_run = true`` is
+``workflow_dry_run = true``.  An unknown key in one of those tables, or a
+value of the wrong type anywhere, raises ``ConfigError`` naming the file.
+Settings that grant trust (``USER_ONLY_SETTINGS``) are refused in
+``./azathoth.toml`` the same way: a cloned repository must not configure
+them for its own tools.
+
+String values in the user config files may reference ``${ENV_VAR}`` (or
+``${ENV_VAR:-default}``); ``./azathoth.toml`` values are taken literally so
+a repository cannot read the environment into them.
+A ``[profiles.<name>]`` table overrides base keys when that profile is
+selected via ``AZATHOTH_PROFILE`` / ``azathoth --profile`` or a top-level
+``profile = "<name>"`` key.  Environment variables still win over files.
 """
 
 from __future__ import annotations
 
 import json
 import os
+import re
+import tomllib
 import warnings
+from collections.abc import Sequence
 from pathlib import Path
-from typing import Any
+from typing import Any, Literal
 
-from pydantic import Field, SecretStr, model_validator
+from pydantic import Field, SecretStr, TypeAdapter, ValidationError, model_validator
 from pydantic_settings import (
     BaseSettings,
     EnvSettingsSource,
     PydanticBaseSettingsSource,
     SettingsConfigDict,
-    TomlConfigSettingsSource,
 )
 
-_CONFIG_DIR = Path.home() / ".config" / "azathoth"
+_XDG_CONFIG_HOME = Path(os.environ.get("XDG_CONFIG_HOME") or Path.home() / ".config")
+_CONFIG_DIR = _XDG_CONFIG_HOME / "azathoth"
 _CONFIG_FILE = _CONFIG_DIR / "config.toml"
+_PROJECT_CONFIG_NAME = "azathoth.toml"
+
+#: Per-server tables → the prefix their keys take as settings.
+SECTIONS: dict[str, str] = {
+    "workflow": "workflow_",
+    "scout": "scout_",
+    "directives": "directives_",
+    "mcp": "mcp_",
+}
+
+#: Settings only the user config files and the environment may set.
+USER_ONLY_SETTINGS = frozenset(
+    {
+        "approval_required",
+        "approval_token",
+        "workflow_repos",
+        "github_api_url",
+        "scout_embedding_url",
+        "scout_root",
+        "audit_enabled",
+        "elicit_confirmations",
+        "workflow_protected_branches",
+        "scout_deny",
+        "config_dir",
+        "mcp_otlp_endpoint",
+        "llm_provider",
+        "llm_providers",
+        "ollama_host",
+    }
+)
+
+_ENV_REF = re.compile(r"\$\{([A-Za-z_][A-Za-z0-9_]*)(?::-([^}]*))?\}")
 
 _PREVIEW_TAGS = ("preview", "experimental", "exp")
 
 # Fields whose env-var values need pre-processing before pydantic-settings'
 # decode_complex_value (json.loads) runs.
-_LIST_FIELDS_ENV_KEYS = {"AZATHOTH_LLM_PROVIDERS"}
+_LIST_FIELDS_ENV_KEYS = {
+    "AZATHOTH_LLM_PROVIDERS",
+    "AZATHOTH_SCOUT_DENY",
+    "AZATHOTH_SCOUT_EXCLUDE",
+    "AZATHOTH_WORKFLOW_PROTECTED_BRANCHES",
+    "AZATHOTH_WORKFLOW_REPOS",
+}
+
+
+class ConfigError(ValueError):
+    """A configuration file has an unknown key or an invalid value."""
 
 
 def _resolve_api_key() -> SecretStr:
@@ -71,7 +137,170 @@ class _ListAwareEnvSource(EnvSettingsSource):
         return super().prepare_field_value(field_name, field, value, value_is_complex)
 
 
+def project_config_file() -> Path:
+    """The per-project ``azathoth.toml`` in the working directory.
+
+    That is the directory azathoth was launched from: settings are loaded
+    once per process, so a repository selected later with ``repo_path`` does
+    not contribute its ``azathoth.toml`` (its ``.azathoth.toml`` policy, see
+    ``core.repo_config``, does apply).
+    """
+    return Path.cwd() / _PROJECT_CONFIG_NAME
+
+
+def config_files() -> list[Path]:
+    """Return the TOML files consulted, lowest precedence first."""
+    return [
+        _CONFIG_FILE,
+        _CONFIG_DIR / _PROJECT_CONFIG_NAME,
+        project_config_file(),
+    ]
+
+
+def _setting_name(key: str) -> str:
+    """*key* as written in a file: ``[workflow] dry_run`` for ``workflow_dry_run``."""
+    for section, prefix in SECTIONS.items():
+        if key.startswith(prefix):
+            return f"[{section}] {key.removeprefix(prefix)}"
+    return key
+
+
+def _flatten_sections(data: dict[str, Any], origin: str) -> dict[str, Any]:
+    """Lift ``[workflow]`` / ``[scout]`` / … tables into prefixed keys."""
+    flat = dict(data)
+    for section, prefix in SECTIONS.items():
+        if section not in flat:
+            continue
+        table = flat.pop(section)
+        if not isinstance(table, dict):
+            raise ConfigError(f"{origin}: '{section}' must be a table ([{section}]).")
+        for key, value in table.items():
+            if prefix + key not in Settings.model_fields:
+                raise ConfigError(f"{origin}: unknown setting '{key}' in [{section}].")
+            flat[prefix + key] = value
+    return flat
+
+
+def _interpolate(value: Any) -> Any:
+    """Expand ``${VAR}`` / ``${VAR:-default}`` in strings, recursively."""
+    if isinstance(value, str):
+
+        def _sub(match: re.Match[str]) -> str:
+            name, default = match.group(1), match.group(2)
+            if name in os.environ:
+                return os.environ[name]
+            if default is not None:
+                return default
+            warnings.warn(
+                f"Config references unset environment variable ${{{name}}}",
+                UserWarning,
+                stacklevel=2,
+            )
+            return ""
+
+        return _ENV_REF.sub(_sub, value)
+    if isinstance(value, list):
+        return [_interpolate(v) for v in value]
+    if isinstance(value, dict):
+        return {k: _interpolate(v) for k, v in value.items()}
+    return value
+
+
+def load_layered_config(
+    files: Sequence[Path], profile: str | None = None
+) -> dict[str, Any]:
+    """Merge TOML *files* (later wins), apply *profile*, expand ``${VAR}``.
+
+    ``${VAR}`` is not expanded in ``project_config_file()``.  The profile
+    defaults to a top-level ``profile`` key in the files.  An unknown
+    profile is reported with a warning and otherwise ignored.
+
+    Raises:
+        ConfigError: If a file is not valid TOML or a server table has an
+            unknown key.
+    """
+    return _load_layered(files, profile)[0]
+
+
+def _load_layered(
+    files: Sequence[Path], profile: str | None
+) -> tuple[dict[str, Any], dict[str, str]]:
+    """``load_layered_config`` plus the file (and profile) each key came from."""
+    base: dict[str, Any] = {}
+    origins: dict[str, str] = {}
+    profiles: dict[str, dict[str, Any]] = {}
+    profile_origins: dict[str, dict[str, str]] = {}
+    project = project_config_file()
+    for path in files:
+        if not path.is_file():
+            continue
+        try:
+            with open(path, "rb") as f:
+                data = tomllib.load(f)
+        except tomllib.TOMLDecodeError as exc:
+            raise ConfigError(f"{path}: invalid TOML: {exc}") from exc
+        if path != project:
+            data = _interpolate(data)
+        for name, table in data.pop("profiles", {}).items():
+            origin = f"{path} [profiles.{name}]"
+            table = _flatten_sections(table, origin)
+            profiles.setdefault(name, {}).update(table)
+            profile_origins.setdefault(name, {}).update(dict.fromkeys(table, origin))
+        data = _flatten_sections(data, str(path))
+        base.update(data)
+        origins.update(dict.fromkeys(data, str(path)))
+
+    profile = profile or base.get("profile")
+    if profile:
+        if profile in profiles:
+            base.update(profiles[profile])
+            origins.update(profile_origins[profile])
+        else:
+            warnings.warn(
+                f"Unknown config profile '{profile}' "
+                f"(defined: {', '.join(sorted(profiles)) or 'none'})",
+                UserWarning,
+                stacklevel=2,
+            )
+        base["profile"] = profile
+    return base, origins
+
+
+class _LayeredTomlSource(PydanticBaseSettingsSource):
+    """Settings source backed by ``load_layered_config(config_files())``."""
+
+    def get_field_value(self, field: Any, field_name: str) -> tuple[Any, str, bool]:
+        # Unused: __call__ returns the whole mapping at once.
+        return None, field_name, False
+
+    def __call__(self) -> dict[str, Any]:
+        profile = os.environ.get("AZATHOTH_PROFILE") or None
+        data, origins = _load_layered(config_files(), profile)
+        fields = self.settings_cls.model_fields
+        values = {k: v for k, v in data.items() if k in fields}
+        project = str(project_config_file())
+        for key in sorted(USER_ONLY_SETTINGS & values.keys()):
+            origin = origins.get(key, "")
+            if origin == project or origin.startswith(f"{project} ["):
+                raise ConfigError(
+                    f"{origin}: {_setting_name(key)} can only be set in the "
+                    "user config or the environment."
+                )
+        for key, value in values.items():
+            try:
+                TypeAdapter(fields[key].annotation).validate_python(value)
+            except ValidationError as exc:
+                problem = exc.errors()[0]["msg"]
+                raise ConfigError(
+                    f"{origins.get(key, 'config')}: {_setting_name(key)}: {problem}"
+                ) from exc
+        return values
+
+
 class Settings(BaseSettings):
+    #: Active configuration profile (``[profiles.<name>]`` in the TOML files).
+    profile: str | None = Field(default=None)
+
     # ── LLM provider selection ────────────────────────────────────────────
     #: Single-provider override for CLI/test use; takes precedence over
     #: ``llm_providers`` when set.
@@ -95,6 +324,11 @@ class Settings(BaseSettings):
     #: Deprecated alias for llm_chain_timeout
     llm_total_timeout: float | None = Field(default=None)
 
+    #: When MCP tools use the connected client's model (MCP sampling) for
+    #: generated text such as commit messages: ``fallback`` only when every
+    #: provider fails, ``prefer`` before the providers, ``off`` never.
+    llm_sampling: Literal["off", "fallback", "prefer"] = Field(default="fallback")
+
     # ── Gemini ────────────────────────────────────────────────────────────
     gemini_api_key: SecretStr = Field(default_factory=_resolve_api_key)
     gemini_model: str = "gemini-3.1-flash-lite-preview"
@@ -105,11 +339,261 @@ class Settings(BaseSettings):
     ollama_num_ctx: int = Field(default=32768)
     ollama_request_timeout: float = Field(default=120.0)
 
+    # ── Workflow server ───────────────────────────────────────────────────
+    #: Server-wide dry run: mutating git tools report the commands they would
+    #: run instead of executing them.  Also set by ``workflow --dry-run``.
+    workflow_dry_run: bool = Field(default=False)
+
+    #: Allow tools to run ``git fetch --deepen`` in shallow clones (e.g. CI
+    #: checkouts) when they need history that was not fetched.
+    workflow_allow_deepen: bool = Field(default=False)
+
+    #: Branches (names or fnmatch patterns) whose history tools never rewrite
+    #: or delete, and that ``stage_and_commit`` / ``bump_version`` refuse to
+    #: commit to unless called with ``allow_protected``.  User config or
+    #: environment only.
+    workflow_protected_branches: list[str] = Field(
+        default_factory=lambda: ["main", "master", "release/*"]
+    )
+
+    #: Make ``stage_and_commit`` refuse to commit when the staged changes
+    #: contain likely credentials (see ``scan_secrets``); a call can also ask
+    #: for this with ``block_secrets``.
+    workflow_block_secrets: bool = Field(default=False)
+
+    #: Repositories (paths or fnmatch patterns such as ``~/code/*``) that
+    #: workflow tools may select with ``repo_path``; see ``list_repos``.
+    workflow_repos: list[Path] = Field(default_factory=list)
+
+    #: Where ``create_worktree`` puts new worktrees when no path is given
+    #: (as ``<dir>/<repo>/<branch>``).  Relative paths are resolved against
+    #: the repository root; unset means the system temp directory.
+    workflow_worktree_dir: Path | None = Field(default=None)
+
+    #: Files larger than this (bytes) fail ``preflight``; 0 disables the check.
+    workflow_max_file_size: int = Field(default=5 * 1024 * 1024)
+
+    #: ``summarize_diff`` includes a file's patch text only up to this many
+    #: bytes; larger files are listed with their hunk headers.
+    workflow_diff_inline_bytes: int = Field(default=8000)
+
+    #: Default and upper bound (seconds) for ``run_script``.
+    workflow_script_timeout: float = Field(default=300.0)
+
+    #: Upper bound (seconds) for a whole ``bisect_run``, every step included.
+    workflow_bisect_timeout: float = Field(default=1800.0)
+
+    #: Command ``run_tests`` runs instead of the detected suite (``cargo
+    #: test``, ``pytest``, ``go test``, ``npm test``), e.g. ``make check``.
+    workflow_test_command: str | None = Field(default=None)
+
+    #: Default and upper bound (seconds) for ``run_tests``.
+    workflow_test_timeout: float = Field(default=900.0)
+
+    #: Make the autocommit and autorelease prompts run the tests first and
+    #: stop unless they pass.
+    workflow_test_gate: bool = Field(default=False)
+
+    #: Commands ``run_build``, ``run_lint`` and ``run_fmt`` run instead of
+    #: the detected ones (cargo, go, package.json scripts, eslint, prettier,
+    #: ruff).  A configured formatter runs as given, ``check`` or not.
+    workflow_build_command: str | None = Field(default=None)
+    workflow_lint_command: str | None = Field(default=None)
+    workflow_fmt_command: str | None = Field(default=None)
+
+    #: Default and upper bound (seconds) for ``run_build``, ``run_lint`` and
+    #: ``run_fmt``.
+    workflow_check_timeout: float = Field(default=600.0)
+
+    #: Tag naming for ``release_workspace``: one tag per package, with
+    #: ``{name}`` and ``{version}`` substituted (e.g. ``{name}@{version}``).
+    workflow_workspace_tag_format: str = Field(default="{name}-v{version}")
+
+    #: Forge used by ``create_release``: ``auto`` infers it from the origin
+    #: remote (GitHub when unknown), or ``github`` / ``gitlab`` / ``gitea``.
+    release_backend: Literal["auto", "github", "gitlab", "gitea"] = Field(
+        default="auto"
+    )
+
+    #: How ``create_release`` and ``promote_release_candidate`` tag:
+    #: ``annotated`` (``git tag -a``), ``signed`` (``git tag -s``, with the
+    #: commit signing key) or ``lightweight``.
+    release_tag_kind: Literal["annotated", "signed", "lightweight"] = Field(
+        default="annotated"
+    )
+
+    #: Token for the GitHub REST API, used for releases and pull requests
+    #: instead of the ``gh`` CLI.  Unset falls back to ``GITHUB_TOKEN`` /
+    #: ``GH_TOKEN``, and without any token ``gh`` is used.
+    github_token: SecretStr | None = Field(default=None)
+
+    #: GitHub API root; unset means ``https://api.github.com``.  A GitHub
+    #: Enterprise remote is only reached through the API when this is set
+    #: (else ``gh`` is used).  User config or environment only, since the
+    #: token is sent there.
+    github_api_url: str | None = Field(default=None)
+
+    #: Build command for ``build_release_artifacts`` when the project is not
+    #: built with cargo (e.g. ``make dist``); ``release_artifacts`` then
+    #: lists glob patterns, relative to the repository, of what it produces.
+    release_build_command: str | None = Field(default=None)
+    release_artifacts: list[str] = Field(default_factory=list)
+
+    #: Target triples ``build_release_artifacts`` builds with ``cross``
+    #: (e.g. ``x86_64-unknown-linux-musl``); empty builds for the host only.
+    release_targets: list[str] = Field(default_factory=list)
+
+    #: Version-control backend: ``auto`` (detect ``.jj`` / ``.hg`` / ``.git``),
+    #: ``git``, ``jj`` or ``hg``.
+    vcs_backend: Literal["auto", "git", "jj", "hg"] = Field(default="auto")
+
+    #: The jj and hg backends are experimental; without this flag ``auto``
+    #: always resolves to git and selecting them explicitly is an error.
+    vcs_experimental_backends: bool = Field(default=False)
+
+    #: How git status, diff, log and blame are read: ``auto`` uses pygit2
+    #: (libgit2) when the ``pygit2`` extra is installed, ``pygit2`` requires
+    #: it, ``process`` always runs the ``git`` binary.
+    vcs_git_reads: Literal["auto", "pygit2", "process"] = Field(default="auto")
+
+    # ── Scout server ──────────────────────────────────────────────────────
+    #: ``read_file`` returns at most this many bytes per call (page with
+    #: ``start_line`` for more).
+    scout_max_read_bytes: int = Field(default=256_000)
+
+    #: Upper bound on entries returned by ``list_directory`` and ``glob``.
+    scout_max_entries: int = Field(default=1000)
+
+    #: Keep the scout index (file hashes, line counts, outlines) under
+    #: ``.azathoth/cache/`` in the repository so repeat scans only read what
+    #: changed.  See core/index.py.
+    scout_index: bool = Field(default=True)
+
+    #: Backend computing embeddings for ``semantic_search``: a local Ollama
+    #: model, an OpenAI-compatible API, or ``off`` (the tool reports that it
+    #: is disabled).  See core/embeddings.py.
+    scout_embeddings: Literal["off", "ollama", "openai"] = Field(default="off")
+
+    #: Embedding model; unset means ``nomic-embed-text`` for Ollama and
+    #: ``text-embedding-3-small`` for the OpenAI API.
+    scout_embedding_model: str | None = Field(default=None)
+
+    #: Service URL; unset means ``ollama_host`` or ``https://api.openai.com/v1``.
+    #: User config or environment only, since the API key is sent there.
+    scout_embedding_url: str | None = Field(default=None)
+
+    #: Bearer token for the OpenAI-compatible API.
+    scout_embedding_api_key: SecretStr | None = Field(default=None)
+
+    #: ``search`` stops after this many matching lines unless asked for fewer.
+    scout_max_search_results: int = Field(default=200)
+
+    #: Sandbox root: every scout tool's ``target_directory`` is resolved
+    #: against it and refused if it leads outside.  Unset means the server's
+    #: working directory.  User config or environment only.
+    scout_root: Path | None = Field(default=None)
+
+    #: Paths the file tools (``read_file``, ``list_directory``, ``glob``)
+    #: never read or descend into.  A pattern without ``/`` matches a path
+    #: component anywhere (``node_modules``); one with ``/`` is anchored at
+    #: the project root (``.git/objects``).  fnmatch wildcards are allowed.
+    #: User config or environment only.
+    scout_deny: list[str] = Field(
+        default_factory=lambda: [".git/objects", "node_modules"]
+    )
+
+    #: Skip what the project's ``.gitignore``/``.ignore`` files (and
+    #: ``.git/info/exclude``) exclude when listing, globbing and scanning.
+    scout_respect_gitignore: bool = Field(default=True)
+
+    #: Extra exclude patterns in ``.gitignore`` syntax, applied on top of the
+    #: project's ignore files (e.g. ``["*.min.js", "fixtures/large/"]``).
+    scout_exclude: list[str] = Field(default_factory=list)
+
+    # ── Directives server ─────────────────────────────────────────────────
+    #: Directory of user directives (overriding built-ins); defaults to
+    #: ``<config_dir>/directives``.
+    directives_path: Path | None = Field(default=None)
+
+    #: Preferred language of directives (``es``, ``pt-BR``): ``adapt`` and
+    #: the resources load ``<name>.<locale>.md`` (or ``.toml``) when it
+    #: exists, then the language alone (``pt``), then the default file.
+    directive_locale: str | None = Field(default=None)
+
+    #: Directory of prompt templates (``<name>.md.j2``) overriding the stock
+    #: prompts; defaults to ``<config_dir>/prompts``.  See core/templates.py.
+    prompts_path: Path | None = Field(default=None)
+
+    #: Directory of workflow definitions (``<name>.yaml``) available to every
+    #: repository; defaults to ``<config_dir>/workflows``.  A repository's own
+    #: ``.azathoth/workflows`` wins.  See core/workflows.py.
+    workflows_path: Path | None = Field(default=None)
+
+    # ── Safety ────────────────────────────────────────────────────────────
+    #: Kill-switch: while true, every mutating MCP tool is denied.  A
+    #: ``.azathoth/pause`` file in the working tree has the same effect.
+    mutations_paused: bool = Field(default=False)
+
+    #: Approval mode (``serve --require-approval``): destructive tools —
+    #: history rewrites, branch deletion, releases, publishing — are refused
+    #: unless the call's ``_meta`` carries ``approval_token``.
+    approval_required: bool = Field(default=False)
+
+    #: The token clients present to approve a destructive call.  Unset means
+    #: no destructive call can be approved while approval mode is on.
+    approval_token: SecretStr | None = Field(default=None)
+
+    #: Ask the user through the MCP client (elicitation) before a risky step —
+    #: deleting an unmerged branch or a tag, force-pushing, releasing from a
+    #: dirty tree — instead of refusing it or going ahead silently.  Clients
+    #: without elicitation support keep the old behaviour.  User config or
+    #: environment only.
+    elicit_confirmations: bool = Field(default=True)
+
+    #: Record every MCP tool call (arguments, duration, outcome, git commands)
+    #: as JSON lines in ``audit_file``.  User config or environment only.
+    audit_enabled: bool = Field(default=True)
+
     # ── MCP / A2A ─────────────────────────────────────────────────────────
+    #: Defaults for ``azathoth serve`` (``--transport`` / ``--host`` / ``--port``).
+    mcp_transport: Literal["stdio", "sse", "http"] = Field(default="stdio")
+    mcp_host: str = Field(default="127.0.0.1")
     mcp_port: int = Field(default=8001)
+
+    #: How tool text reaches the client: ``plain`` strips terminal colour
+    #: codes, ``ansi`` keeps them, ``markdown`` strips them and fences diffs.
+    mcp_render_mode: Literal["plain", "ansi", "markdown"] = Field(default="plain")
+
+    #: Per-server override of ``mcp_render_mode``, e.g. ``{"workflow": "ansi"}``.
+    mcp_render_modes: dict[str, Literal["plain", "ansi", "markdown"]] = Field(
+        default_factory=dict
+    )
     agent_port: int = Field(default=8002)
 
+    #: Tool calls a server runs at once (0: unlimited).  Mutating tools also
+    #: run one at a time per repository; see ``mcp/limits.py``.
+    mcp_max_concurrent_calls: int = Field(default=8)
+
+    #: Seconds a call waits for a free slot or its repository before it
+    #: fails as busy.
+    mcp_queue_timeout: float = Field(default=30.0)
+
+    #: Calls per minute allowed per tool, e.g. ``{"create_release": 2}``.
+    mcp_rate_limits: dict[str, int] = Field(default_factory=dict)
+
+    #: Level of the servers' log on stderr; ``DEBUG`` logs every call.
+    mcp_log_level: Literal["DEBUG", "INFO", "WARNING", "ERROR"] = Field(
+        default="INFO"
+    )
+
+    #: OTLP/HTTP collector (e.g. ``http://localhost:4318``) receiving the
+    #: servers' spans and per-tool metrics; needs the ``otel`` extra.  User
+    #: config or environment only, since every tool call is reported there.
+    mcp_otlp_endpoint: str | None = Field(default=None)
+
     # ── Paths ─────────────────────────────────────────────────────────────
+    #: User config or environment only: audit log, directives and prompts
+    #: live under it.
     config_dir: Path = Field(default=_CONFIG_DIR)
 
     # ── Misc ──────────────────────────────────────────────────────────────
@@ -161,7 +645,7 @@ class Settings(BaseSettings):
         return (
             init_settings,
             _ListAwareEnvSource(settings_cls),  # replaces default env_settings
-            TomlConfigSettingsSource(settings_cls, toml_file=_CONFIG_FILE),
+            _LayeredTomlSource(settings_cls),
         )
 
     @property
@@ -173,10 +657,33 @@ class Settings(BaseSettings):
 
     @property
     def directives_dir(self) -> Path:
-        path = self.config_dir / "directives"
+        path = self.directives_path or self.config_dir / "directives"
         path.mkdir(parents=True, exist_ok=True)
         return path
 
+    @property
+    def prompts_dir(self) -> Path:
+        return self.prompts_path or self.config_dir / "prompts"
+
+    @property
+    def workflows_dir(self) -> Path:
+        return self.workflows_path or self.config_dir / "workflows"
+
+    @property
+    def journal_file(self) -> Path:
+        """Markdown journal that focus-session summaries are appended to."""
+        return self.config_dir / "journal.md"
+
+    @property
+    def audit_file(self) -> Path:
+        """JSONL audit trail of tool invocations (see core/audit.py)."""
+        return self.config_dir / "audit.jsonl"
+
+    @property
+    def cache_dir(self) -> Path:
+        """Content-addressed caches (e.g. per-file symbol facts)."""
+        return self.config_dir / "cache"
+
     @property
     def reports_dir(self) -> Path:
         return self.default_output_dir
@@ -188,3 +695,12 @@ config = Settings()
 
 def get_config() -> Settings:
     return config
+
+
+def reload_config(profile: str | None = None) -> Settings:
+    """Rebuild the singleton, optionally switching to *profile*."""
+    global config
+    if profile is not None:
+        os.environ["AZATHOTH_PROFILE"] = profile
+    config = Settings()
+    return config
@@ -0,0 +1,220 @@
+"""azathoth.core.artifacts — build the binaries a release ships.
+
+Public surface:
+  - ``cargo_binaries(root)``                     → ``(target dir, [binary names])``
+  - ``build_release_artifacts(root, targets, …)`` → ``ReleaseArtifacts``
+
+A Rust project is built with ``cargo build --release``, or once per target
+triple with ``cross build --release --target <triple>`` (``cargo`` when
+``cross=False``).  ``cargo metadata`` names the binaries and the target
+directory, and each binary is copied into the output directory (``dist``
+by default) as ``<bin>`` or ``<bin>-<triple>`` for target builds.  Projects
+built some other way set ``release_build_command`` and
+``release_artifacts``, glob patterns for the files the command leaves
+behind.  The command comes from configuration only; callers cannot pass
+one.  The output directory and every file the patterns match must stay
+inside the repository.
+
+A ``SHA256SUMS`` file in ``sha256sum`` format is written next to the
+binaries.  Paths are returned relative to the repository root, so they can
+be passed to ``create_release(assets=…)`` as they are.  A dry run returns
+the build commands instead of running them.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import json
+import shutil
+from collections.abc import Sequence
+from pathlib import Path
+
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+from azathoth.core import host
+from azathoth.core.exceptions import SandboxError, WorkflowError
+from azathoth.core.files import resolve_inside
+from azathoth.core.workflow import format_command, run_command
+
+CHECKSUMS = "SHA256SUMS"
+
+
+class Artifact(BaseModel, frozen=True):
+    path: str = Field(description="Relative to the repository root")
+    target: str | None = Field(None, description="Target triple; None: host")
+    sha256: str
+    size: int
+
+
+class ReleaseArtifacts(BaseModel, frozen=True):
+    """Outcome (or dry-run plan) of building release artifacts."""
+
+    artifacts: list[Artifact] = Field(default_factory=list)
+    checksums: str | None = Field(None, description="The SHA256SUMS file")
+    assets: list[str] = Field(
+        default_factory=list, description="Artifacts and checksums, for create_release"
+    )
+    built: bool = False
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+async def cargo_binaries(root: Path) -> tuple[Path, list[str]]:
+    """The target directory and binary names of the Cargo project at *root*.
+
+    Raises:
+        WorkflowError: If ``cargo metadata`` fails or there are no binaries.
+    """
+    code, out, err = await run_command(
+        ["cargo", "metadata", "--format-version", "1", "--no-deps"], cwd=str(root)
+    )
+    if code != 0:
+        raise WorkflowError(f"cargo metadata failed: {err or out}")
+    metadata = json.loads(out)
+    binaries = sorted(
+        {
+            target["name"]
+            for package in metadata.get("packages", [])
+            for target in package.get("targets", [])
+            if "bin" in target.get("kind", [])
+        }
+    )
+    if not binaries:
+        raise WorkflowError(f"The Cargo project at {root} has no binary targets.")
+    return Path(metadata["target_directory"]), binaries
+
+
+def _artifact(root: Path, path: Path, target: str | None) -> Artifact:
+    data = path.read_bytes()
+    return Artifact(
+        path=path.relative_to(root).as_posix(),
+        target=target,
+        sha256=hashlib.sha256(data).hexdigest(),
+        size=len(data),
+    )
+
+
+def _write_checksums(out_dir: Path, artifacts: list[Artifact]) -> Path:
+    lines = [f"{a.sha256}  {Path(a.path).name}\n" for a in artifacts]
+    path = out_dir / CHECKSUMS
+    path.write_text("".join(lines))
+    return path
+
+
+def _inside(root: Path, path: str | Path) -> Path:
+    try:
+        return resolve_inside(root, path)
+    except SandboxError as exc:
+        raise WorkflowError(f"Release artifacts stay in the repository: {exc}") from exc
+
+
+def _collect(root: Path, patterns: Sequence[str]) -> list[Path]:
+    """The files *patterns* match under *root*, refusing any outside it."""
+    matches = {_inside(root, m) for pattern in patterns for m in root.glob(pattern)}
+    return sorted(matches)
+
+
+async def _build(argv: list[str], root: Path) -> None:
+    code, out, err = await run_command(argv, cwd=str(root))
+    if code != 0:
+        raise WorkflowError(f"{format_command(argv)} failed: {err or out}")
+
+
+async def _cargo_artifacts(
+    root: Path, out_dir: Path, targets: Sequence[str], cross: bool, dry_run: bool
+) -> tuple[list[list[str]], list[tuple[Path, str | None]]]:
+    """Build commands and, unless *dry_run*, the built ``(file, target)``s."""
+    tool = "cross" if cross and targets else "cargo"
+    builds = [[tool, "build", "--release", "--target", t] for t in targets]
+    builds = builds or [["cargo", "build", "--release"]]
+    if dry_run:
+        return builds, []
+
+    target_dir, binaries = await cargo_binaries(root)
+    built: list[tuple[Path, str | None]] = []
+    for argv, target in zip(builds, targets or [None], strict=True):
+        await _build(argv, root)
+        release_dir = target_dir / (target or "") / "release"
+        suffix = ".exe" if target and "windows" in target else ""
+        for name in binaries:
+            source = release_dir / f"{name}{suffix}"
+            if not source.is_file():
+                raise WorkflowError(
+                    f"{format_command(argv)} did not produce {source}."
+                )
+            dest = out_dir / (f"{name}-{target}{suffix}" if target else name)
+            shutil.copy2(source, dest)
+            built.append((dest, target))
+    return builds, built
+
+
+async def build_release_artifacts(
+    root: Path,
+    targets: Sequence[str] = (),
+    patterns: Sequence[str] = (),
+    out_dir: str = "dist",
+    cross: bool = True,
+    dry_run: bool = False,
+) -> ReleaseArtifacts:
+    """Build release binaries for *targets* and checksum them into *out_dir*.
+
+    Projects with a ``release_build_command`` are built with it and
+    *patterns* (default ``release_artifacts``) collect its output; without
+    one the project must be a Cargo project.  *targets* default to
+    ``release_targets``.
+
+    Raises:
+        WorkflowError: If the build fails, produces nothing, the project has
+            no build to run, or *out_dir* or a pattern leaves *root*.
+    """
+    config = get_config()
+    command = config.release_build_command
+    targets = list(targets or config.release_targets)
+    root = root.resolve()
+    out = _inside(root, out_dir)
+
+    if command:
+        builds = [host.split(command)]
+        if dry_run:
+            return ReleaseArtifacts(commands=[format_command(b) for b in builds])
+        patterns = list(patterns or config.release_artifacts)
+        if not patterns:
+            raise WorkflowError(
+                "A custom build command needs release_artifacts (glob patterns "
+                "for the files it produces)."
+            )
+        if absolute := [p for p in patterns if Path(p).is_absolute()]:
+            raise WorkflowError(
+                f"release_artifacts must be relative to {root}: {', '.join(absolute)}"
+            )
+        await _build(builds[0], root)
+        out.mkdir(parents=True, exist_ok=True)
+        files = _collect(root, patterns)
+        built: list[tuple[Path, str | None]] = []
+        for source in (f for f in files if f.is_file()):
+            dest = out / source.name
+            if source != dest.resolve():
+                shutil.copy2(source, dest)
+            built.append((dest, None))
+    elif (root / "Cargo.toml").is_file():
+        if not dry_run:
+            out.mkdir(parents=True, exist_ok=True)
+        builds, built = await _cargo_artifacts(root, out, targets, cross, dry_run)
+        if dry_run:
+            return ReleaseArtifacts(commands=[format_command(b) for b in builds])
+    else:
+        raise WorkflowError(
+            f"No Cargo.toml in {root}; set release_build_command and "
+            "release_artifacts to build this project."
+        )
+
+    if not built:
+        raise WorkflowError(f"The build produced no artifacts in {out}.")
+    artifacts = [_artifact(root, path, target) for path, target in built]
+    checksums = _write_checksums(out, artifacts).relative_to(root).as_posix()
+    return ReleaseArtifacts(
+        artifacts=artifacts,
+        checksums=checksums,
+        assets=[*(a.path for a in artifacts), checksums],
+        built=True,
+    )
@@ -0,0 +1,236 @@
+"""azathoth.core.assets — inventory of a project's non-code assets.
+
+Public surface:
+  - ``ASSET_KINDS``            — file suffix → asset kind
+  - ``image_dimensions(path)`` → ``(width, height)`` or ``None``
+  - ``catalog_assets(root)``   → ``AssetCatalog``
+
+Images, fonts, 3D/ML models and test fixtures are listed with size and
+format; image dimensions come from the file header (PNG, GIF, JPEG, WebP,
+BMP, SVG), never from decoding.  An asset counts as referenced when its file
+name appears in any source or markup file of the project — a cheap textual
+check that flags likely dead weight without resolving imports or bundler
+configuration, so an unreferenced asset is a candidate, not a certainty.
+"""
+
+from __future__ import annotations
+
+import re
+import struct
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.traverse import iter_files
+
+AssetKind = Literal["image", "font", "model", "fixture"]
+
+ASSET_KINDS: dict[str, AssetKind] = {
+    **dict.fromkeys(
+        [".png", ".jpg", ".jpeg", ".gif", ".webp", ".avif", ".svg", ".ico", ".bmp"],
+        "image",
+    ),
+    **dict.fromkeys([".ttf", ".otf", ".woff", ".woff2", ".eot"], "font"),
+    **dict.fromkeys(
+        [".glb", ".gltf", ".obj", ".fbx", ".stl", ".onnx", ".safetensors", ".tflite"],
+        "model",
+    ),
+}
+
+#: Directories whose non-code files are fixtures, whatever their format.
+FIXTURE_DIRS = frozenset({"fixtures", "__fixtures__", "testdata", "test_data"})
+
+_CODE_SUFFIXES = frozenset(
+    ".py .pyi .rs .go .java .kt .c .cpp .h .js .jsx .mjs .cjs .ts .tsx .svelte "
+    ".vue .astro".split()
+)
+# Files searched for references to assets.
+_SOURCE_SUFFIXES = _CODE_SUFFIXES | frozenset(
+    ".html .css .scss .sass .less .md .mdx .json .toml .yaml .yml .webmanifest".split()
+)
+_MAX_SOURCE_BYTES = 1_000_000
+_HEADER_BYTES = 64 * 1024
+
+_SVG_SIZE = re.compile(r"""\b(width|height)\s*=\s*["']\s*([\d.]+)(?:px)?\s*["']""")
+_SVG_VIEWBOX = re.compile(
+    r"""viewBox\s*=\s*["']\s*[-\d.]+[\s,]+[-\d.]+[\s,]+([\d.]+)[\s,]+([\d.]+)"""
+)
+
+
+class Asset(BaseModel, frozen=True):
+    path: str
+    kind: AssetKind
+    format: str = Field(description="Lower-case suffix without the dot")
+    size: int
+    width: int | None = None
+    height: int | None = None
+    referenced: bool = True
+
+
+class AssetCatalog(BaseModel, frozen=True):
+    root: str
+    assets: list[Asset] = Field(default_factory=list)
+
+    @property
+    def unreferenced(self) -> list[Asset]:
+        return [a for a in self.assets if not a.referenced]
+
+    @property
+    def total_size(self) -> int:
+        return sum(a.size for a in self.assets)
+
+    def render_markdown(self) -> str:
+        lines = [f"# Assets: {self.root}", ""]
+        if not self.assets:
+            lines.append("No assets found.")
+            return "\n".join(lines)
+        lines.append(f"{len(self.assets)} assets, {self.total_size:,} bytes.")
+        lines.append("")
+        for asset in self.assets:
+            dims = f" {asset.width}×{asset.height}" if asset.width else ""
+            flag = "" if asset.referenced else " — unreferenced"
+            lines.append(
+                f"- `{asset.path}` ({asset.kind}, {asset.format}{dims}, "
+                f"{asset.size:,} B){flag}"
+            )
+        return "\n".join(lines)
+
+
+# ── Image headers ─────────────────────────────────────────────────────────────
+
+
+def _png(head: bytes) -> tuple[int, int] | None:
+    if head[:8] == b"\x89PNG\r\n\x1a\n" and head[12:16] == b"IHDR":
+        return struct.unpack(">II", head[16:24])
+    return None
+
+
+def _gif(head: bytes) -> tuple[int, int] | None:
+    if head[:6] in (b"GIF87a", b"GIF89a"):
+        return struct.unpack("<HH", head[6:10])
+    return None
+
+
+def _bmp(head: bytes) -> tuple[int, int] | None:
+    if head[:2] == b"BM" and len(head) >= 26:
+        width, height = struct.unpack("<ii", head[18:26])
+        return width, abs(height)
+    return None
+
+
+def _webp(head: bytes) -> tuple[int, int] | None:
+    if head[:4] != b"RIFF" or head[8:12] != b"WEBP" or len(head) < 30:
+        return None
+    chunk = head[12:16]
+    if chunk == b"VP8 ":
+        width, height = struct.unpack("<HH", head[26:30])
+        return width & 0x3FFF, height & 0x3FFF
+    if chunk == b"VP8L":
+        bits = int.from_bytes(head[21:25], "little")
+        return (bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1
+    if chunk == b"VP8X":
+        width = int.from_bytes(head[24:27], "little") + 1
+        height = int.from_bytes(head[27:30], "little") + 1
+        return width, height
+    return None
+
+
+def _jpeg(head: bytes) -> tuple[int, int] | None:
+    if head[:2] != b"\xff\xd8":
+        return None
+    i = 2
+    while i + 9 < len(head):
+        if head[i] != 0xFF:
+            return None
+        marker = head[i + 1]
+        # SOF0–SOF15 except DHT (C4), JPG (C8) and DAC (CC) carry the size.
+        if 0xC0 <= marker <= 0xCF and marker not in (0xC4, 0xC8, 0xCC):
+            height, width = struct.unpack(">HH", head[i + 5 : i + 9])
+            return width, height
+        i += 2 + struct.unpack(">H", head[i + 2 : i + 4])[0]
+    return None
+
+
+def _svg(head: bytes) -> tuple[int, int] | None:
+    text = head.decode("utf-8", errors="ignore")
+    tag = text[text.find("<svg") :].split(">", 1)[0] if "<svg" in text else ""
+    sizes = dict(_SVG_SIZE.findall(tag))
+    if "width" in sizes and "height" in sizes:
+        return round(float(sizes["width"])), round(float(sizes["height"]))
+    if match := _SVG_VIEWBOX.search(tag):
+        return round(float(match.group(1))), round(float(match.group(2)))
+    return None
+
+
+_HEADER_PARSERS = {
+    "png": _png,
+    "gif": _gif,
+    "bmp": _bmp,
+    "webp": _webp,
+    "jpg": _jpeg,
+    "jpeg": _jpeg,
+    "svg": _svg,
+}
+
+
+def image_dimensions(path: Path) -> tuple[int, int] | None:
+    """Width and height read from *path*'s header, or ``None`` if unknown."""
+    parser = _HEADER_PARSERS.get(path.suffix.lower().lstrip("."))
+    if parser is None:
+        return None
+    try:
+        with open(path, "rb") as f:
+            head = f.read(_HEADER_BYTES)
+        return parser(head)
+    except (OSError, struct.error, ValueError):
+        return None
+
+
+# ── Catalog ───────────────────────────────────────────────────────────────────
+
+
+def _kind(rel: Path) -> AssetKind | None:
+    if any(part in FIXTURE_DIRS for part in rel.parts[:-1]):
+        return None if rel.suffix.lower() in _CODE_SUFFIXES else "fixture"
+    return ASSET_KINDS.get(rel.suffix.lower())
+
+
+def _reference_text(root: Path, assets: set[Path]) -> str:
+    chunks: list[str] = []
+    for path in iter_files(root, suffixes=_SOURCE_SUFFIXES):
+        if path in assets:
+            continue
+        try:
+            with open(path, encoding="utf-8", errors="ignore") as f:
+                chunks.append(f.read(_MAX_SOURCE_BYTES))
+        except OSError:
+            continue
+    return "\n".join(chunks)
+
+
+def catalog_assets(root: Path) -> AssetCatalog:
+    """Inventory images, fonts, models and fixtures under *root*."""
+    root = root.resolve()
+    found = {
+        path: kind
+        for path in iter_files(root)
+        if (kind := _kind(path.relative_to(root))) is not None
+    }
+    corpus = _reference_text(root, set(found))
+
+    assets: list[Asset] = []
+    for path, kind in found.items():
+        dims = image_dimensions(path) if kind == "image" else None
+        assets.append(
+            Asset(
+                path=path.relative_to(root).as_posix(),
+                kind=kind,
+                format=path.suffix.lower().lstrip(".") or "unknown",
+                size=path.stat().st_size,
+                width=dims[0] if dims else None,
+                height=dims[1] if dims else None,
+                referenced=path.name in corpus,
+            )
+        )
+    return AssetCatalog(root=str(root), assets=assets)
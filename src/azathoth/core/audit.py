@@ -0,0 +1,120 @@
+"""azathoth.core.audit — append-only audit trail of tool invocations.
+
+Public surface:
+  - ``capture_commands()``  → context manager collecting external commands
+  - ``record_command(argv)`` → called by subprocess helpers (``_run_git``, gh)
+  - ``uncaptured()``         → context manager hiding read-only probes
+  - ``AuditRecord``          → one JSONL line
+  - ``write_record(record)`` → append to ``Settings.audit_file``
+  - ``on_record(listener)``  → also hand every record to *listener*
+
+Commands are collected through a ``ContextVar`` so concurrent tool calls on
+the same server never see each other's git invocations.
+"""
+
+from __future__ import annotations
+
+import logging
+import shlex
+from collections.abc import Callable, Iterator
+from contextlib import contextmanager
+from contextvars import ContextVar
+from datetime import datetime, timezone
+from typing import Any
+
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+
+log = logging.getLogger(__name__)
+
+# Long argument values (diffs, release notes) are clipped in the log.
+_MAX_VALUE_CHARS = 500
+
+_commands: ContextVar[list[str] | None] = ContextVar("audit_commands", default=None)
+_listeners: list[Callable[[AuditRecord], None]] = []
+
+
+class AuditRecord(BaseModel, frozen=True):
+    """A single tool invocation."""
+
+    timestamp: datetime = Field(default_factory=lambda: datetime.now(timezone.utc))
+    server: str
+    tool: str
+    arguments: dict[str, Any] = Field(default_factory=dict)
+    duration_ms: float
+    success: bool
+    error: str | None = None
+    commands: list[str] = Field(
+        default_factory=list, description="External commands executed (git, gh)"
+    )
+
+
+@contextmanager
+def capture_commands() -> Iterator[list[str]]:
+    """Collect every command passed to ``record_command`` inside the block."""
+    collected: list[str] = []
+    token = _commands.set(collected)
+    try:
+        yield collected
+    finally:
+        _commands.reset(token)
+
+
+@contextmanager
+def uncaptured() -> Iterator[None]:
+    """Leave commands run inside the block out of any surrounding capture.
+
+    For internal read-only probes (e.g. the bare-repository check before
+    staging) that would otherwise clutter the tool's command list.
+    """
+    token = _commands.set(None)
+    try:
+        yield
+    finally:
+        _commands.reset(token)
+
+
+def record_command(argv: list[str]) -> None:
+    """Note an executed command for the surrounding ``capture_commands`` block."""
+    collected = _commands.get()
+    if collected is not None:
+        collected.append(_clip(shlex.join(argv)))
+
+
+def _clip(value: str) -> str:
+    if len(value) <= _MAX_VALUE_CHARS:
+        return value
+    return f"{value[:_MAX_VALUE_CHARS]}… ({len(value)} chars)"
+
+
+def redact_arguments(arguments: dict[str, Any]) -> dict[str, Any]:
+    """Clip long string values so a single record stays readable."""
+    return {
+        k: _clip(v) if isinstance(v, str) else v for k, v in arguments.items()
+    }
+
+
+def on_record(listener: Callable[[AuditRecord], None]) -> None:
+    """Hand every record passed to ``write_record`` to *listener* as well,
+    whether or not the log itself is enabled (e.g. focus sessions)."""
+    _listeners.append(listener)
+
+
+def write_record(record: AuditRecord) -> None:
+    """Append *record* to the audit log (no-op when auditing is disabled).
+
+    Write failures are logged, never raised: auditing must not break tools.
+    """
+    for listener in _listeners:
+        listener(record)
+    settings = get_config()
+    if not settings.audit_enabled:
+        return
+    path = settings.audit_file
+    try:
+        path.parent.mkdir(parents=True, exist_ok=True)
+        with open(path, "a", encoding="utf-8") as f:
+            f.write(record.model_dump_json() + "\n")
+    except OSError as exc:
+        log.warning("Could not write audit record to %s: %s", path, exc)
@@ -0,0 +1,158 @@
+"""azathoth.core.bisect — find the commit that broke a declared task.
+
+Public surface:
+  - ``BisectResult``                              — first bad commit and the steps
+  - ``bisect_plan(good, bad, task)``              → the commands ``bisect_run`` runs
+  - ``bisect_run(good, bad, task, timeout, cwd)`` → ``BisectResult``
+
+``git bisect run`` checks out commits between *good* and *bad* and runs the
+test at each one: exit 0 marks the commit good, 125 skips it, anything
+else marks it bad.  The test is one of the repo's declared tasks (see
+core/tasks.py), never an arbitrary shell command.  The bisect is always
+reset afterwards, so HEAD ends where it started, and it is refused while
+tracked files have uncommitted changes that the checkouts would disturb.
+"""
+
+from __future__ import annotations
+
+import re
+from pathlib import Path
+from typing import Any, Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.repo_config import find_repo_root
+from azathoth.core.tasks import Task, run_task
+from azathoth.core.workflow import CommitInfo, _run_git, format_command
+
+_FIRST_BAD = re.compile(r"^([0-9a-f]{40}) is the first bad commit", re.M)
+_LOG_ENTRY = re.compile(r"^# (good|bad|skip): \[([0-9a-f]+)\] ?(.*)$", re.M)
+
+
+class BisectStep(BaseModel, frozen=True):
+    sha: str
+    verdict: Literal["good", "bad", "skip"]
+    subject: str = ""
+
+
+class BisectResult(BaseModel, frozen=True):
+    good: str
+    bad: str
+    command: str = Field(description="The test run at each step")
+    ran: bool = Field(description="False for a dry run")
+    first_bad: CommitInfo | None = None
+    body: str = ""
+    diffstat: str = Field("", description="git show --stat of the first bad commit")
+    files_changed: int = 0
+    insertions: int = 0
+    deletions: int = 0
+    steps: list[BisectStep] = Field(default_factory=list)
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+def bisect_plan(good: str, bad: str, task: Task) -> list[list[str]]:
+    return [
+        ["git", "bisect", "start", bad, good],
+        ["git", "bisect", "run", *task.argv],
+        ["git", "bisect", "reset"],
+    ]
+
+
+async def _check_ready(good: str, bad: str, cwd: str) -> None:
+    _, bisect_log, _ = await _run_git(
+        ["rev-parse", "--git-path", "BISECT_LOG"], cwd=cwd
+    )
+    if bisect_log and (Path(cwd) / bisect_log).is_file():
+        raise WorkflowError(
+            "A bisect is already in progress; finish it or run `git bisect reset`."
+        )
+    _, dirty, _ = await _run_git(
+        ["status", "--porcelain", "--untracked-files=no"], cwd=cwd
+    )
+    if dirty:
+        raise WorkflowError(
+            "Tracked files have uncommitted changes; commit or stash them before "
+            "bisecting."
+        )
+    for ref in (good, bad):
+        code, _, _ = await _run_git(
+            ["rev-parse", "--verify", f"{ref}^{{commit}}"], cwd=cwd
+        )
+        if code != 0:
+            raise WorkflowError(f"Unknown revision '{ref}'.")
+
+
+async def _describe(sha: str, cwd: str) -> dict[str, Any]:
+    """``BisectResult`` fields describing the first bad commit."""
+    _, header, _ = await _run_git(
+        ["show", "-s", "--format=%an%x1f%aI%x1f%s%x1f%b", sha], cwd=cwd
+    )
+    author, date, subject, body = [*header.split("\x1f"), "", "", ""][:4]
+    _, diffstat, _ = await _run_git(["show", "--stat", "--format=", sha], cwd=cwd)
+    _, numstat, _ = await _run_git(["show", "--numstat", "--format=", sha], cwd=cwd)
+    files = insertions = deletions = 0
+    for line in numstat.splitlines():
+        added, removed, _ = [*line.split("\t", 2), "", ""][:3]
+        files += 1
+        # Binary files show "-" for both counts.
+        insertions += int(added) if added.isdigit() else 0
+        deletions += int(removed) if removed.isdigit() else 0
+    return {
+        "first_bad": CommitInfo(sha=sha, author=author, date=date, subject=subject),
+        "body": body.strip(),
+        "diffstat": diffstat,
+        "files_changed": files,
+        "insertions": insertions,
+        "deletions": deletions,
+    }
+
+
+async def bisect_run(
+    good: str,
+    bad: str,
+    task: Task,
+    timeout: float,
+    cwd: str | None = None,
+    dry_run: bool = False,
+) -> BisectResult:
+    """Bisect between *good* and *bad* with *task* as the test.
+
+    Raises:
+        WorkflowError: If the repo is not ready to bisect, a revision is
+            unknown, the run times out, or no single first bad commit is
+            found (e.g. every candidate was skipped).
+    """
+    cwd = cwd or str(find_repo_root())
+    await _check_ready(good, bad, cwd)
+    start, run, reset = bisect_plan(good, bad, task)
+    result = BisectResult(
+        good=good, bad=bad, command=format_command(task.argv), ran=False
+    )
+    if dry_run:
+        return result.model_copy(
+            update={"commands": [format_command(c) for c in (start, run, reset)]}
+        )
+
+    code, out, err = await _run_git(start[1:], cwd=cwd)
+    if code != 0:
+        await _run_git(reset[1:], cwd=cwd)
+        raise WorkflowError(f"git bisect start failed: {err or out}")
+    try:
+        outcome = await run_task(task.model_copy(update={"argv": run}), timeout, cwd)
+        _, log, _ = await _run_git(["bisect", "log"], cwd=cwd)
+    finally:
+        await _run_git(reset[1:], cwd=cwd)
+
+    if outcome.timed_out:
+        raise WorkflowError(f"git bisect run timed out after {timeout:g}s.")
+    found = _FIRST_BAD.search(outcome.stdout)
+    if not found:
+        output = (outcome.stdout + "\n" + outcome.stderr).strip()
+        raise WorkflowError(f"git bisect run found no first bad commit:\n{output}")
+    steps = [
+        BisectStep(sha=sha, verdict=verdict, subject=subject)
+        for verdict, sha, subject in _LOG_ENTRY.findall(log)
+    ]
+    details = await _describe(found.group(1), cwd)
+    return result.model_copy(update={"ran": True, "steps": steps, **details})
@@ -0,0 +1,192 @@
+"""azathoth.core.branches — local branch management with safety checks.
+
+Public surface:
+  - ``list_branches(cwd)``                          → ``[BranchInfo]``
+  - ``create_branch(name, start_point, switch, …)`` → ``GitResult``
+  - ``switch_branch(name, cwd, dry_run)``           → ``GitResult``
+  - ``delete_branch(name, force, cwd, dry_run)``    → ``GitResult``
+
+Deleting refuses the checked-out branch and anything listed in
+``workflow_protected_branches``.  Creating or switching with uncommitted
+changes succeeds (git carries them over) but says so in ``message``, since
+an agent rarely means to move work in progress to another branch.
+"""
+
+from __future__ import annotations
+
+from pydantic import BaseModel
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.policy import is_protected_branch
+from azathoth.core.workflow import (
+    GitResult,
+    _refuse_bare,
+    _run_git,
+    ensure_revision,
+    get_repo_status,
+    planned,
+)
+
+_FIELD = "%1f"  # for-each-ref hex escape (not log's %x1f)
+_FORMAT = _FIELD.join(
+    [
+        "%(refname:short)",
+        "%(HEAD)",
+        "%(upstream:short)",
+        "%(upstream:track,nobracket)",
+        "%(objectname:short)",
+        "%(contents:subject)",
+    ]
+)
+
+
+class BranchInfo(BaseModel, frozen=True):
+    name: str
+    current: bool = False
+    protected: bool = False
+    upstream: str | None = None
+    ahead: int = 0
+    behind: int = 0
+    upstream_gone: bool = False
+    sha: str = ""
+    subject: str = ""
+
+
+def _parse_track(track: str) -> tuple[int, int, bool]:
+    """Parse ``ahead 1, behind 2`` / ``gone`` into (ahead, behind, gone)."""
+    if track == "gone":
+        return 0, 0, True
+    ahead = behind = 0
+    for part in filter(None, (p.strip() for p in track.split(","))):
+        word, _, count = part.partition(" ")
+        if word == "ahead":
+            ahead = int(count)
+        elif word == "behind":
+            behind = int(count)
+    return ahead, behind, False
+
+
+def _fail(message: str) -> GitResult:
+    return GitResult(success=False, stdout="", stderr=message, message=message)
+
+
+async def _dirty_warning(cwd: str | None) -> str | None:
+    status = await get_repo_status(cwd)
+    if not (status.staged or status.unstaged):
+        return None
+    count = len({c.path for c in (*status.staged, *status.unstaged)})
+    return (
+        f"Warning: {count} file(s) with uncommitted changes were carried over "
+        f"from '{status.branch}'."
+    )
+
+
+async def _branch_exists(name: str, cwd: str | None) -> bool:
+    code, _, _ = await _run_git(
+        ["show-ref", "--verify", "--quiet", f"refs/heads/{name}"], cwd=cwd
+    )
+    return code == 0
+
+
+async def list_branches(cwd: str | None = None) -> list[BranchInfo]:
+    """Local branches with upstream tracking and tip commit."""
+    code, out, _ = await _run_git(
+        ["for-each-ref", f"--format={_FORMAT}", "refs/heads"], cwd=cwd
+    )
+    if code != 0:
+        return []
+    branches = []
+    for line in out.splitlines():
+        fields = line.split("\x1f") + [""] * 6
+        name, head, upstream, track, sha, subject = fields[:6]
+        ahead, behind, gone = _parse_track(track)
+        branches.append(
+            BranchInfo(
+                name=name,
+                current=head == "*",
+                protected=is_protected_branch(name),
+                upstream=upstream or None,
+                ahead=ahead,
+                behind=behind,
+                upstream_gone=gone,
+                sha=sha,
+                subject=subject,
+            )
+        )
+    return branches
+
+
+async def create_branch(
+    name: str,
+    start_point: str | None = None,
+    switch: bool = True,
+    cwd: str | None = None,
+    dry_run: bool = False,
+) -> GitResult:
+    """Create *name* at *start_point* (default ``HEAD``) and, by default, switch."""
+    code, _, _ = await _run_git(["check-ref-format", "--branch", name], cwd=cwd)
+    if code != 0:
+        return _fail(f"'{name}' is not a valid branch name.")
+    if start_point is not None:
+        try:
+            start_point = ensure_revision(start_point)
+        except WorkflowError as exc:
+            return _fail(str(exc))
+    if await _branch_exists(name, cwd):
+        return _fail(f"Branch '{name}' already exists.")
+    if switch:
+        if refused := await _refuse_bare(cwd):
+            return refused
+        args = ["switch", "--create", name, *([start_point] if start_point else [])]
+    else:
+        args = ["branch", name, *([start_point] if start_point else [])]
+    if dry_run:
+        return planned(["git", *args])
+
+    code, out, err = await _run_git(args, cwd=cwd)
+    result = GitResult(success=(code == 0), stdout=out, stderr=err)
+    if result.success and switch:
+        result.message = await _dirty_warning(cwd)
+    return result
+
+
+async def switch_branch(
+    name: str, cwd: str | None = None, dry_run: bool = False
+) -> GitResult:
+    """Check out an existing local branch."""
+    if refused := await _refuse_bare(cwd):
+        return refused
+    if not await _branch_exists(name, cwd):
+        return _fail(f"Branch '{name}' does not exist.")
+    args = ["switch", name]
+    if dry_run:
+        return planned(["git", *args])
+
+    code, out, err = await _run_git(args, cwd=cwd)
+    result = GitResult(success=(code == 0), stdout=out, stderr=err)
+    if result.success:
+        result.message = await _dirty_warning(cwd)
+    return result
+
+
+async def delete_branch(
+    name: str, force: bool = False, cwd: str | None = None, dry_run: bool = False
+) -> GitResult:
+    """Delete a local branch.
+
+    Refuses the current and protected branches.  Without *force*, git itself
+    refuses branches with commits not merged into their upstream or ``HEAD``.
+    """
+    if is_protected_branch(name):
+        return _fail(f"'{name}' is protected and cannot be deleted.")
+    if not await _branch_exists(name, cwd):
+        return _fail(f"Branch '{name}' does not exist.")
+    _, current, _ = await _run_git(["symbolic-ref", "--short", "HEAD"], cwd=cwd)
+    if name == current:
+        return _fail(f"'{name}' is checked out; switch to another branch first.")
+    args = ["branch", "-D" if force else "-d", name]
+    if dry_run:
+        return planned(["git", *args])
+
+    code, out, err = await _run_git(args, cwd=cwd)
+    return GitResult(success=(code == 0), stdout=out, stderr=err)
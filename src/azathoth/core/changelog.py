@@ -0,0 +1,217 @@
+"""azathoth.core.changelog — Conventional Commits changelog generation.
+
+Public surface:
+  - ``parse_commit(sha, subject, body)`` → ``ConventionalCommit``
+  - ``generate_changelog(from_ref, to_ref, cwd)`` → ``Changelog``
+  - ``Changelog.render_markdown()`` → grouped Markdown document
+
+Commits that do not follow the Conventional Commits grammar are kept and
+grouped under "Other Changes" rather than dropped — a changelog that
+silently loses history is worse than an untidy one.
+"""
+
+from __future__ import annotations
+
+import re
+
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.workflow import (
+    _run_git,
+    deepen_until,
+    ensure_revision,
+    get_latest_tag,
+    get_repo_context,
+    history_contains,
+)
+
+# ``type(scope)!: description`` — scope and ``!`` are optional.
+_HEADER_RE = re.compile(
+    r"^(?P<type>[a-zA-Z]+)(?:\((?P<scope>[^)]*)\))?(?P<breaking>!)?:\s*(?P<desc>.+)$"
+)
+_BREAKING_FOOTER_RE = re.compile(r"^BREAKING[ -]CHANGE:", re.MULTILINE)
+
+# Ordered (type, section title) pairs — drives rendering order.
+SECTIONS: tuple[tuple[str, str], ...] = (
+    ("feat", "🚀 Features"),
+    ("fix", "🐛 Bug Fixes"),
+    ("perf", "⚡ Performance"),
+    ("refactor", "♻️ Refactoring"),
+    ("docs", "📝 Documentation"),
+    ("test", "🧪 Tests"),
+    ("build", "📦 Build System"),
+    ("ci", "🤖 CI"),
+    ("style", "🎨 Style"),
+    ("chore", "🔧 Chores"),
+    ("revert", "⏪ Reverts"),
+)
+OTHER_SECTION = "Other Changes"
+BREAKING_SECTION = "⚠️ Breaking Changes"
+
+# Field / record separators for the ``git log`` pretty format.
+_FS = "\x1f"
+_RS = "\x1e"
+
+
+class ConventionalCommit(BaseModel, frozen=True):
+    """A single commit parsed against the Conventional Commits grammar."""
+
+    sha: str
+    subject: str
+    type: str | None = None
+    scope: str | None = None
+    description: str
+    breaking: bool = False
+
+    @property
+    def short_sha(self) -> str:
+        return self.sha[:7]
+
+
+class Changelog(BaseModel, frozen=True):
+    """Commits between two refs, grouped by Conventional Commits type."""
+
+    from_ref: str | None
+    to_ref: str
+    commits: list[ConventionalCommit] = Field(default_factory=list)
+
+    def grouped(self) -> dict[str, list[ConventionalCommit]]:
+        """Return ``{section title: commits}`` in rendering order, skipping empties."""
+        titles = dict(SECTIONS)
+        groups: dict[str, list[ConventionalCommit]] = {}
+
+        breaking = [c for c in self.commits if c.breaking]
+        if breaking:
+            groups[BREAKING_SECTION] = breaking
+
+        for type_, title in SECTIONS:
+            matched = [c for c in self.commits if c.type == type_]
+            if matched:
+                groups[title] = matched
+
+        other = [c for c in self.commits if c.type not in titles]
+        if other:
+            groups[OTHER_SECTION] = other
+        return groups
+
+    def render_markdown(self) -> str:
+        """Render the changelog as Markdown, one ``##`` section per commit type."""
+        range_label = (
+            f"{self.from_ref}..{self.to_ref}" if self.from_ref else self.to_ref
+        )
+        lines = [f"# Changelog ({range_label})", ""]
+
+        if not self.commits:
+            lines.append("_No commits in range._")
+            return "\n".join(lines)
+
+        for title, commits in self.grouped().items():
+            lines.append(f"## {title}")
+            for c in commits:
+                scope = f"**{c.scope}:** " if c.scope else ""
+                lines.append(f"- {scope}{c.description} ({c.short_sha})")
+            lines.append("")
+
+        return "\n".join(lines).rstrip() + "\n"
+
+
+def parse_commit(sha: str, subject: str, body: str = "") -> ConventionalCommit:
+    """Parse a commit subject/body into a ``ConventionalCommit``.
+
+    Non-conforming subjects yield ``type=None`` with the full subject as the
+    description.
+    """
+    subject = subject.strip()
+    match = _HEADER_RE.match(subject)
+    if not match:
+        return ConventionalCommit(sha=sha, subject=subject, description=subject)
+
+    return ConventionalCommit(
+        sha=sha,
+        subject=subject,
+        type=match["type"].lower(),
+        scope=match["scope"] or None,
+        description=match["desc"].strip(),
+        breaking=bool(match["breaking"]) or bool(_BREAKING_FOOTER_RE.search(body)),
+    )
+
+
+def _check_refs(*refs: str | None) -> None:
+    """Raise ``ValueError`` for a ref git would read as an option."""
+    for ref in refs:
+        if ref is not None:
+            try:
+                ensure_revision(ref)
+            except WorkflowError as exc:
+                raise ValueError(str(exc)) from exc
+
+
+async def get_commits(
+    from_ref: str | None,
+    to_ref: str = "HEAD",
+    cwd: str | None = None,
+    paths: list[str] | None = None,
+) -> list[ConventionalCommit]:
+    """Return parsed commits in ``from_ref..to_ref`` (newest first).
+
+    When *from_ref* is ``None`` the whole history up to *to_ref* is used.
+    With *paths*, only commits touching them are returned.
+    """
+    _check_refs(from_ref, to_ref)
+    rev_range = f"{from_ref}..{to_ref}" if from_ref else to_ref
+    args = ["log", rev_range, f"--pretty=format:%H{_FS}%s{_FS}%b{_RS}"]
+    if paths:
+        args += ["--", *paths]
+    code, out, err = await _run_git(args, cwd=cwd)
+    if code != 0:
+        raise ValueError(f"git log {rev_range} failed: {err}")
+
+    commits: list[ConventionalCommit] = []
+    for record in out.split(_RS):
+        record = record.strip("\n")
+        if not record:
+            continue
+        sha, subject, body = (record.split(_FS) + ["", ""])[:3]
+        commits.append(parse_commit(sha.strip(), subject, body))
+    return commits
+
+
+async def generate_changelog(
+    from_ref: str | None = None, to_ref: str = "HEAD", cwd: str | None = None
+) -> Changelog:
+    """Build a ``Changelog`` for ``from_ref..to_ref``.
+
+    *from_ref* defaults to the most recent tag; if the repo has no tags the
+    entire history is included.
+
+    In a shallow clone the needed history is fetched with ``--deepen`` when
+    ``Settings.workflow_allow_deepen`` is set.  When that is off, or the
+    fetch fails or runs out of history first, a ``ValueError`` explains what
+    is missing rather than returning a truncated changelog.
+    """
+    _check_refs(from_ref, to_ref)
+    context = await get_repo_context(cwd)
+    if context.is_shallow:
+        missing = from_ref or "the latest tag"
+        if get_config().workflow_allow_deepen:
+            try:
+                found = await deepen_until(from_ref, cwd=cwd)
+            except WorkflowError as exc:
+                raise ValueError(f"Shallow clone: deepening failed: {exc}") from exc
+            # A clone deepened to completion without a tag has no history missing.
+            if not found and (await get_repo_context(cwd)).is_shallow:
+                raise ValueError(
+                    f"Shallow clone: deepening did not reach {missing}. "
+                    "Run `git fetch --unshallow`."
+                )
+        elif not await history_contains(from_ref, cwd=cwd):
+            raise ValueError(
+                f"Shallow clone: {missing} is outside the fetched history. "
+                "Run `git fetch --unshallow` or set AZATHOTH_WORKFLOW_ALLOW_DEEPEN."
+            )
+    if from_ref is None:
+        from_ref = await get_latest_tag(cwd=cwd)
+    commits = await get_commits(from_ref, to_ref, cwd=cwd)
+    return Changelog(from_ref=from_ref, to_ref=to_ref, commits=commits)
@@ -0,0 +1,497 @@
+"""azathoth.core.checks — running a project's tests, build, linter and
+formatter, and reading the result.
+
+Public surface:
+  - ``detect_test_command(root)``          → ``(runner, argv)``
+  - ``parse_test_output(runner, output)``  → ``(passed, failed, skipped, failures)``
+  - ``run_tests(root, timeout)``           → ``SuiteResult``
+  - ``detect_check_command(kind, root)``   → ``(runner, argv)``
+  - ``parse_diagnostics(output, root)``    → ``[Diagnostic]``
+  - ``run_check(kind, root, timeout)``     → ``CheckResult``
+
+The suite is the project's own: ``workflow_test_command`` when configured,
+otherwise the first of these that applies at *root*:
+
+  - ``cargo test``  — a Cargo.toml
+  - ``go test -v ./...`` — a go.mod
+  - ``npm test``    — a ``test`` script in package.json, run with the
+    package manager its lockfile names (``pnpm run test``, …)
+  - ``pytest``      — pyproject.toml, setup.py, setup.cfg, pytest.ini or
+    tox.ini (``uv run pytest`` with a uv.lock)
+
+Counts and failing test names are read from the runner's own summary lines
+(cargo's ``test result:``, pytest's ``=== 2 failed, 9 passed ===``, go's
+``--- FAIL:``, jest's and vitest's ``Tests:``); a configured command is read
+with every parser.  When nothing can be parsed the counts stay ``None`` and
+the exit code alone decides ``success``.  Only these commands run; callers
+cannot pass one.
+
+Build, lint and format (``run_check``) work the same way, with
+``workflow_{build,lint,fmt}_command`` overriding the detected command:
+
+  - build — ``cargo build``, ``go build ./...``, or a ``build`` script
+  - lint  — ``cargo clippy``, ``go vet ./...``, a ``lint`` script, eslint
+    (with an eslint config) or ruff (with ruff configured)
+  - fmt   — ``cargo fmt``, ``gofmt -w``, a ``format`` script, prettier (with
+    a prettier config) or ``ruff format``; with *check* the formatter only
+    reports (``cargo fmt --check``, a ``format:check`` script, …)
+
+Diagnostics are read from every format those tools write — rustc's
+``--> file:line:col`` blocks, ``file:line:col: message`` lines (go, ruff,
+eslint's unix format, tsc's ``file(line,col)``), eslint's default grouped
+output, and the file lists of formatters in check mode.
+"""
+
+from __future__ import annotations
+
+import re
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+from azathoth.core import host
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.tasks import discover_tasks, run_argv
+from azathoth.core.workflow import format_command
+
+TestRunner = Literal["cargo", "go", "npm", "pytest", "custom"]
+CheckKind = Literal["build", "lint", "fmt"]
+Severity = Literal["error", "warning", "note"]
+
+#: Output beyond this many characters keeps its tail only.
+OUTPUT_LIMIT = 8_000
+#: At most this many failing test names are listed.
+MAX_FAILURES = 50
+#: At most this many diagnostics are listed.
+MAX_DIAGNOSTICS = 200
+
+_PYTHON_MARKERS = ("pyproject.toml", "setup.py", "setup.cfg", "pytest.ini", "tox.ini")
+_NPM_PLACEHOLDER = "no test specified"
+
+_CARGO_RESULT = re.compile(
+    r"^test result: \w+\. (\d+) passed; (\d+) failed; (\d+) ignored", re.M
+)
+_CARGO_FAILED = re.compile(r"^test (\S+) \.\.\. FAILED$", re.M)
+_PYTEST_SUMMARY = re.compile(r"^=+ (.*\d+ (?:passed|failed|error).*?) =+$", re.M)
+_PYTEST_FAILED = re.compile(r"^(?:FAILED|ERROR) (\S+)", re.M)
+_GO_RESULT = re.compile(r"^\s*--- (PASS|FAIL|SKIP): (\S+)", re.M)
+_JS_SUMMARY = re.compile(r"^\s*Tests:?\s+(.*\d+ (?:passed|failed).*)$", re.M)
+_JEST_FAILED = re.compile(r"^\s*● (.+? › .+)$", re.M)
+_VITEST_FAILED = re.compile(r"^\s*(?:FAIL|×)\s+(\S+ > .+?)(?: \d+ms)?$", re.M)
+_COUNT = re.compile(r"(\d+) (passed|failed|errors?|skipped|ignored|todo)")
+
+_KIND_NAMES = {"build": "build", "lint": "linter", "fmt": "formatter"}
+_RUSTC_HEADER = re.compile(r"^(error|warning)(?:\[[\w:]+\])?: (.+)$")
+_RUSTC_ARROW = re.compile(r"^\s*--> (.+?):(\d+):(\d+)$")
+_LOCATED = re.compile(
+    r"^(?P<file>[^\s:()]+\.\w+)"
+    r"(?:\((?P<pline>\d+),(?P<pcol>\d+)\)|:(?P<line>\d+)(?::(?P<col>\d+))?)"
+    r"[:\s-]*(?P<rest>\S.*)$"
+)
+_SEVERITY_PREFIX = re.compile(
+    r"^(error|warning|note|help|info)(?:\[[^\]]+\])?:?\s+(.+)$", re.I
+)
+_ESLINT_UNIX_SUFFIX = re.compile(r"\s*\[(Error|Warning)(?:/[^\]]*)?\]$")
+_STYLISH_FILE = re.compile(r"^(?:/|[A-Za-z]:[\\/])\S*$")
+_STYLISH_ROW = re.compile(r"^\s+(\d+):(\d+)\s+(error|warning)\s+(.+?)(?:\s{2,}\S+)?$")
+_UNFORMATTED = re.compile(
+    r"^(?:Diff in (.+?)(?::\d+:| at line \d+:)"  # rustfmt
+    r"|Would reformat: (.+)"  # ruff
+    r"|\[warn\] (\S+)"  # prettier
+    r"|(\S+\.go))$"  # gofmt -l
+)
+
+Counts = tuple[int | None, int | None, int | None, list[str]]
+
+
+class SuiteResult(BaseModel, frozen=True):
+    runner: TestRunner
+    command: str
+    ran: bool = Field(description="False for a dry run")
+    success: bool = False
+    exit_code: int | None = None
+    passed: int | None = Field(None, description="None when the output was unparsed")
+    failed: int | None = None
+    skipped: int | None = None
+    failures: list[str] = Field(
+        default_factory=list, description="Failing tests, as the runner names them"
+    )
+    duration_s: float = 0.0
+    timed_out: bool = False
+    output: str = Field("", description="stderr then stdout, tail only")
+    truncated: bool = Field(False, description="Long output kept its tail only")
+
+
+class Diagnostic(BaseModel, frozen=True):
+    file: str = Field(description="Relative to the repo root when under it")
+    line: int | None = Field(None, description="1-based")
+    column: int | None = None
+    severity: Severity
+    message: str
+
+
+class CheckResult(BaseModel, frozen=True):
+    kind: CheckKind
+    runner: str = Field(description="cargo, clippy, go, npm, eslint, ruff, …")
+    command: str
+    ran: bool = Field(description="False for a dry run")
+    success: bool = False
+    exit_code: int | None = None
+    diagnostics: list[Diagnostic] = Field(default_factory=list)
+    errors: int = 0
+    warnings: int = 0
+    duration_s: float = 0.0
+    timed_out: bool = False
+    output: str = Field("", description="stderr then stdout, tail only")
+    truncated: bool = Field(False, description="Long output kept its tail only")
+
+
+def detect_test_command(root: Path) -> tuple[TestRunner, list[str]]:
+    """The runner and argv of the test suite at *root*.
+
+    Raises:
+        WorkflowError: If no suite is configured or recognisable.
+    """
+    if command := get_config().workflow_test_command:
+        return "custom", host.split(command)
+    if (root / "Cargo.toml").is_file():
+        return "cargo", ["cargo", "test"]
+    if (root / "go.mod").is_file():
+        return "go", ["go", "test", "-v", "./..."]
+    npm = next(
+        (t for t in discover_tasks(root) if t.runner == "npm" and t.name == "test"),
+        None,
+    )
+    if npm is not None and not _npm_placeholder(root):
+        return "npm", npm.argv
+    if any((root / marker).is_file() for marker in _PYTHON_MARKERS):
+        uv = (root / "uv.lock").is_file()
+        return "pytest", ["uv", "run", "pytest"] if uv else ["pytest"]
+    raise WorkflowError(
+        f"No test suite found in {root}; set workflow_test_command to run one."
+    )
+
+
+def _npm_placeholder(root: Path) -> bool:
+    """Whether package.json's test script is the one ``npm init`` writes."""
+    text = (root / "package.json").read_text(encoding="utf-8", errors="replace")
+    return _NPM_PLACEHOLDER in text
+
+
+def _sum(counts: list[tuple[str, str]], *kinds: str) -> int:
+    return sum(int(n) for n, kind in counts if kind in kinds)
+
+
+def _cargo(output: str) -> Counts:
+    results = _CARGO_RESULT.findall(output)
+    if not results:
+        return None, None, None, []
+    passed, failed, ignored = (sum(int(r[i]) for r in results) for i in range(3))
+    return passed, failed, ignored, _CARGO_FAILED.findall(output)
+
+
+def _pytest(output: str) -> Counts:
+    summaries = _PYTEST_SUMMARY.findall(output)
+    if not summaries:
+        return None, None, None, []
+    counts = _COUNT.findall(summaries[-1])
+    return (
+        _sum(counts, "passed"),
+        _sum(counts, "failed", "error", "errors"),
+        _sum(counts, "skipped"),
+        _PYTEST_FAILED.findall(output),
+    )
+
+
+def _go(output: str) -> Counts:
+    results = _GO_RESULT.findall(output)
+    if not results:
+        return None, None, None, []
+    verdicts = [verdict for verdict, _ in results]
+    return (
+        verdicts.count("PASS"),
+        verdicts.count("FAIL"),
+        verdicts.count("SKIP"),
+        [name for verdict, name in results if verdict == "FAIL"],
+    )
+
+
+def _js(output: str) -> Counts:
+    summaries = _JS_SUMMARY.findall(output)
+    if not summaries:
+        return None, None, None, []
+    counts = _COUNT.findall(summaries[-1])
+    failures = _JEST_FAILED.findall(output) or _VITEST_FAILED.findall(output)
+    return (
+        _sum(counts, "passed"),
+        _sum(counts, "failed"),
+        _sum(counts, "skipped", "todo"),
+        failures,
+    )
+
+
+_PARSERS = {"cargo": _cargo, "pytest": _pytest, "go": _go, "npm": _js}
+
+
+def parse_test_output(runner: TestRunner, output: str) -> Counts:
+    """``(passed, failed, skipped, failures)`` read from *runner*'s *output*.
+
+    Counts are ``None`` when *output* has no summary *runner* writes; a
+    ``custom`` runner tries every parser.
+    """
+    parsers = [_PARSERS[runner]] if runner in _PARSERS else _PARSERS.values()
+    for parse in parsers:
+        passed, failed, skipped, failures = parse(output)
+        if passed is not None:
+            # Unique, in order: a name can appear in a log and a summary.
+            return passed, failed, skipped, list(dict.fromkeys(failures))
+    return None, None, None, []
+
+
+def _tail(text: str) -> tuple[str, bool]:
+    if len(text) <= OUTPUT_LIMIT:
+        return text, False
+    return f"…(truncated)\n{text[-OUTPUT_LIMIT:]}", True
+
+
+async def run_tests(
+    root: Path,
+    timeout: float,
+    dry_run: bool = False,
+) -> SuiteResult:
+    """Run the test suite at *root*, killing it after *timeout* seconds.
+
+    Raises:
+        WorkflowError: If no suite is configured or recognisable.
+    """
+    runner, argv = detect_test_command(root)
+    planned = SuiteResult(runner=runner, command=format_command(argv), ran=False)
+    if dry_run:
+        return planned
+
+    result = await run_argv(argv, timeout, cwd=str(root))
+    combined = "\n".join(part for part in (result.stderr, result.stdout) if part)
+    passed, failed, skipped, failures = parse_test_output(runner, combined)
+    output, cut = _tail(combined)
+    return planned.model_copy(
+        update={
+            "ran": True,
+            # A runner can exit 0 with failures (a misconfigured script).
+            "success": result.success and not failed,
+            "exit_code": result.exit_code,
+            "passed": passed,
+            "failed": failed,
+            "skipped": skipped,
+            "failures": failures[:MAX_FAILURES],
+            "duration_s": result.duration_s,
+            "timed_out": result.timed_out,
+            "output": output,
+            "truncated": cut or result.truncated,
+        }
+    )
+
+
+# ── Build, lint, format ───────────────────────────────────────────────────────
+
+
+def _script(root: Path, name: str) -> list[str] | None:
+    """argv of the package.json script *name*, if declared."""
+    for task in discover_tasks(root):
+        if task.runner == "npm" and task.name == name:
+            return task.argv
+    return None
+
+
+def _python_tool(root: Path, argv: list[str]) -> list[str]:
+    return ["uv", "run", *argv] if (root / "uv.lock").is_file() else argv
+
+
+def _uses_ruff(root: Path) -> bool:
+    if any((root / name).is_file() for name in ("ruff.toml", ".ruff.toml")):
+        return True
+    pyproject = root / "pyproject.toml"
+    return pyproject.is_file() and "ruff" in pyproject.read_text(
+        encoding="utf-8", errors="replace"
+    )
+
+
+def _has(root: Path, *patterns: str) -> bool:
+    return any(next(root.glob(pattern), None) for pattern in patterns)
+
+
+def _build(root: Path, check: bool) -> tuple[str, list[str]] | None:
+    if (root / "Cargo.toml").is_file():
+        return "cargo", ["cargo", "build", "--all-targets"]
+    if (root / "go.mod").is_file():
+        return "go", ["go", "build", "./..."]
+    if argv := _script(root, "build"):
+        return "npm", argv
+    return None
+
+
+def _lint(root: Path, check: bool) -> tuple[str, list[str]] | None:
+    if (root / "Cargo.toml").is_file():
+        return "clippy", ["cargo", "clippy", "--all-targets"]
+    if (root / "go.mod").is_file():
+        return "go", ["go", "vet", "./..."]
+    if argv := _script(root, "lint"):
+        return "npm", argv
+    if _has(root, "eslint.config.*", ".eslintrc*"):
+        return "eslint", ["npx", "eslint", "."]
+    if _uses_ruff(root):
+        argv = ["ruff", "check", "--output-format=concise", "."]
+        return "ruff", _python_tool(root, argv)
+    return None
+
+
+def _fmt(root: Path, check: bool) -> tuple[str, list[str]] | None:
+    if (root / "Cargo.toml").is_file():
+        return "rustfmt", ["cargo", "fmt", *(["--check"] if check else [])]
+    if (root / "go.mod").is_file():
+        return "gofmt", ["gofmt", "-l", *([] if check else ["-w"]), "."]
+    if argv := _script(root, "format:check" if check else "format"):
+        return "npm", argv
+    if _has(root, "prettier.config.*", ".prettierrc*"):
+        return "prettier", ["npx", "prettier", "--check" if check else "--write", "."]
+    if _uses_ruff(root):
+        argv = ["ruff", "format", *(["--check"] if check else []), "."]
+        return "ruff", _python_tool(root, argv)
+    return None
+
+
+_DETECTORS = {"build": _build, "lint": _lint, "fmt": _fmt}
+
+
+def detect_check_command(
+    kind: CheckKind, root: Path, check: bool = False
+) -> tuple[str, list[str]]:
+    """The runner and argv of *root*'s *kind* command.
+
+    *check* asks a formatter to report instead of rewrite files.
+
+    Raises:
+        WorkflowError: If no command is configured or recognisable.
+    """
+    if command := getattr(get_config(), f"workflow_{kind}_command"):
+        return "custom", host.split(command)
+    if found := _DETECTORS[kind](root, check):
+        return found
+    raise WorkflowError(
+        f"No {_KIND_NAMES[kind]} found in {root}; set workflow_{kind}_command "
+        "to run one."
+    )
+
+
+def _relative(path: str, root: Path) -> str:
+    candidate = Path(path)
+    if candidate.is_absolute() and candidate.is_relative_to(root):
+        return candidate.relative_to(root).as_posix()
+    return path.removeprefix("./")
+
+
+def parse_diagnostics(output: str, root: Path) -> list[Diagnostic]:
+    """Located diagnostics in *output*, in order and without repeats.
+
+    Paths under *root* are made relative to it.  Lines without a file and
+    line number (progress, summaries) are skipped.
+    """
+    found: list[Diagnostic] = []
+    pending: tuple[Severity, str] | None = None  # rustc header awaiting -->
+    stylish_file: str | None = None  # eslint's default format
+
+    def add(file: str, line, column, severity: Severity, message: str) -> None:
+        found.append(
+            Diagnostic(
+                file=_relative(file, root),
+                line=int(line) if line else None,
+                column=int(column) if column else None,
+                severity=severity,
+                message=message.strip(),
+            )
+        )
+
+    for text in output.splitlines():
+        if match := _RUSTC_HEADER.match(text):
+            pending = (_severity(match.group(1)), match.group(2))
+        elif (match := _RUSTC_ARROW.match(text)) and pending:
+            add(*match.groups(), *pending)
+            pending = None
+        elif match := _STYLISH_ROW.match(text):
+            if stylish_file:
+                line, column, severity, message = match.groups()
+                add(stylish_file, line, column, _severity(severity), message)
+        elif match := _UNFORMATTED.match(text):
+            file = next(g for g in match.groups() if g)
+            add(file, None, None, "error", "Not formatted.")
+        elif match := _LOCATED.match(text):
+            file, line, column, rest = (
+                match.group("file"),
+                match.group("line") or match.group("pline"),
+                match.group("col") or match.group("pcol"),
+                match.group("rest"),
+            )
+            severity, message = _split_severity(rest)
+            add(file, line, column, severity, message)
+        elif _STYLISH_FILE.match(text):
+            stylish_file = text.strip()
+    return list(dict.fromkeys(found))
+
+
+def _severity(word: str) -> Severity:
+    word = word.lower()
+    if word.startswith("warn"):
+        return "warning"
+    return "error" if word.startswith("err") else "note"
+
+
+def _split_severity(rest: str) -> tuple[Severity, str]:
+    if match := _SEVERITY_PREFIX.match(rest):
+        return _severity(match.group(1)), match.group(2)
+    if match := _ESLINT_UNIX_SUFFIX.search(rest):
+        return _severity(match.group(1)), rest[: match.start()]
+    return "error", rest
+
+
+async def run_check(
+    kind: CheckKind,
+    root: Path,
+    timeout: float,
+    check: bool = False,
+    dry_run: bool = False,
+) -> CheckResult:
+    """Run *root*'s *kind* command, killing it after *timeout* seconds.
+
+    Raises:
+        WorkflowError: If no command is configured or recognisable.
+    """
+    runner, argv = detect_check_command(kind, root, check)
+    planned = CheckResult(
+        kind=kind, runner=runner, command=format_command(argv), ran=False
+    )
+    if dry_run:
+        return planned
+
+    result = await run_argv(argv, timeout, cwd=str(root))
+    combined = "\n".join(part for part in (result.stderr, result.stdout) if part)
+    diagnostics = parse_diagnostics(combined, root)
+    if runner == "gofmt" and not check:
+        diagnostics = []  # the files it rewrote, not problems left
+    severities = [d.severity for d in diagnostics]
+    output, cut = _tail(combined)
+    return planned.model_copy(
+        update={
+            "ran": True,
+            # gofmt -l exits 0 with unformatted files.
+            "success": result.success and "error" not in severities,
+            "exit_code": result.exit_code,
+            "diagnostics": diagnostics[:MAX_DIAGNOSTICS],
+            "errors": severities.count("error"),
+            "warnings": severities.count("warning"),
+            "duration_s": result.duration_s,
+            "timed_out": result.timed_out,
+            "output": output,
+            "truncated": cut or result.truncated,
+        }
+    )
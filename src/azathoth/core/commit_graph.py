@@ -0,0 +1,209 @@
+"""azathoth.core.commit_graph — commit history as structured DAG data.
+
+Public surface:
+  - ``get_commit_graph(limit, since, all_refs, cwd)`` → ``CommitGraph``
+  - ``CommitGraph.render_mermaid()`` → Mermaid ``gitGraph`` source
+
+Prompts that reason about branch topology (merges, divergence) get nodes
+with explicit parents and refs instead of ``git log --graph`` ASCII art.
+"""
+
+from __future__ import annotations
+
+import re
+from collections import defaultdict
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.workflow import _run_git
+
+_FS = "\x1f"
+_BRANCH_UNSAFE = re.compile(r"[^\w/.-]")
+
+
+class CommitNode(BaseModel, frozen=True):
+    """One commit in the graph.  ``parents`` may reference commits outside the
+    window when the graph was truncated."""
+
+    sha: str
+    parents: list[str]
+    refs: list[str] = Field(default_factory=list)
+    author: str
+    date: str
+    subject: str
+
+    @property
+    def short_sha(self) -> str:
+        return self.sha[:7]
+
+    @property
+    def is_merge(self) -> bool:
+        return len(self.parents) > 1
+
+
+class CommitGraph(BaseModel, frozen=True):
+    """Commits newest first (topological order), plus Mermaid when requested."""
+
+    nodes: list[CommitNode]
+    head: str | None = None
+    truncated: bool = Field(
+        default=False, description="Some parents fall outside the returned window"
+    )
+    mermaid: str | None = None
+
+    @property
+    def merges(self) -> list[CommitNode]:
+        return [n for n in self.nodes if n.is_merge]
+
+    def render_mermaid(self) -> str:
+        """Render the graph as Mermaid ``gitGraph`` source.
+
+        gitGraph can only branch from and merge the *tip* of a branch, so the
+        rendering is an approximation for histories where a branch keeps
+        moving after being merged; the node list stays authoritative.
+        """
+        if not self.nodes:
+            return "gitGraph"
+        ordered = list(reversed(self.nodes))  # parents before children
+        shas = {n.sha for n in ordered}
+
+        # First-parent children decide where lanes fork.
+        children: dict[str, list[str]] = defaultdict(list)
+        for node in ordered:
+            if node.parents and node.parents[0] in shas:
+                children[node.parents[0]].append(node.sha)
+
+        lane_of: dict[str, int] = {}
+        forks: dict[str, list[int]] = {}
+        lane_count = 0
+
+        def _new_lane() -> int:
+            nonlocal lane_count
+            lane_count += 1
+            return lane_count - 1
+
+        for node in ordered:
+            if node.sha not in lane_of:
+                lane_of[node.sha] = _new_lane()
+            kids = children.get(node.sha, [])
+            if kids:
+                lane_of[kids[0]] = lane_of[node.sha]
+                forks[node.sha] = []
+                for kid in kids[1:]:
+                    lane_of[kid] = _new_lane()
+                    forks[node.sha].append(lane_of[kid])
+
+        names = self._lane_names(ordered, lane_of, lane_count)
+
+        lines = [
+            f"%%{{init: {{'gitGraph': {{'mainBranchName': '{names[0]}'}}}}}}%%",
+            "gitGraph",
+        ]
+        started = {0}
+        for node in ordered:
+            lane = lane_of[node.sha]
+            if lane not in started:
+                # Root of a lane whose fork point lies outside the window.
+                lines.append(f"  checkout {names[0]}")
+                lines.append(f"  branch {names[lane]}")
+                started.add(lane)
+            lines.append(f"  checkout {names[lane]}")
+
+            tag = next((r[5:] for r in node.refs if r.startswith("tag: ")), None)
+            attrs = f'id: "{node.short_sha}"' + (f' tag: "{tag}"' if tag else "")
+            merged = [p for p in node.parents[1:] if p in lane_of]
+            if merged:
+                lines.append(f"  merge {names[lane_of[merged[0]]]} {attrs}")
+            else:
+                lines.append(f"  commit {attrs}")
+
+            for fork in forks.get(node.sha, []):
+                lines.append(f"  branch {names[fork]}")
+                started.add(fork)
+        return "\n".join(lines)
+
+    @staticmethod
+    def _lane_names(
+        ordered: list[CommitNode], lane_of: dict[str, int], lane_count: int
+    ) -> list[str]:
+        """Name each lane after a branch ref on one of its commits."""
+        names: list[str | None] = [None] * lane_count
+        for node in reversed(ordered):  # prefer refs nearest the tip
+            lane = lane_of[node.sha]
+            if names[lane] is not None:
+                continue
+            for ref in node.refs:
+                if ref == "HEAD" or ref.startswith("tag: "):
+                    continue
+                name = _BRANCH_UNSAFE.sub("-", ref)
+                if name not in names:
+                    names[lane] = name
+                    break
+        return [
+            name or ("main" if i == 0 else f"lane-{i}")
+            for i, name in enumerate(names)
+        ]
+
+
+def _parse_refs(decoration: str) -> list[str]:
+    refs: list[str] = []
+    for part in decoration.split(", "):
+        part = part.strip()
+        if not part:
+            continue
+        if part.startswith("HEAD -> "):
+            refs += ["HEAD", part.removeprefix("HEAD -> ")]
+        else:
+            refs.append(part)
+    return refs
+
+
+async def get_commit_graph(
+    limit: int = 50,
+    since: str | None = None,
+    all_refs: bool = True,
+    cwd: str | None = None,
+) -> CommitGraph:
+    """Return recent history as a DAG, newest first.
+
+    Args:
+        limit:    Maximum number of commits.
+        since:    Only commits after this date (anything ``git log --since``
+                  accepts, e.g. ``"2 weeks ago"``).
+        all_refs: Include every branch and tag, not just ``HEAD``'s ancestry.
+        cwd:      Repository directory.
+
+    Raises:
+        ValueError: If ``git log`` fails (e.g. not a repository).
+    """
+    args = [
+        "log",
+        "--topo-order",
+        f"--max-count={limit}",
+        f"--pretty=format:%H{_FS}%P{_FS}%D{_FS}%an{_FS}%aI{_FS}%s",
+    ]
+    if since:
+        args.append(f"--since={since}")
+    args.append("--all" if all_refs else "HEAD")
+    code, out, err = await _run_git(args, cwd=cwd)
+    if code != 0:
+        raise ValueError(f"git log failed: {err}")
+
+    nodes: list[CommitNode] = []
+    for line in out.splitlines():
+        sha, parents, refs, author, date, subject = (line.split(_FS) + [""] * 5)[:6]
+        nodes.append(
+            CommitNode(
+                sha=sha,
+                parents=parents.split(),
+                refs=_parse_refs(refs),
+                author=author,
+                date=date,
+                subject=subject,
+            )
+        )
+
+    shas = {n.sha for n in nodes}
+    head = next((n.sha for n in nodes if "HEAD" in n.refs), None)
+    truncated = any(p not in shas for n in nodes for p in n.parents)
+    return CommitGraph(nodes=nodes, head=head, truncated=truncated)
@@ -0,0 +1,176 @@
+"""azathoth.core.commit_lint — check a commit message before committing.
+
+Public surface:
+  - ``imperative_form(word)``              → imperative of a non-imperative
+    verb (``added`` → ``add``), or ``None``
+  - ``lint_commit_message(message, policy)`` → ``CommitLint``
+
+The repository's ``CommitPolicy`` decides what is an error: the
+Conventional Commits header, allowed types and scopes, the title length and
+forbidden trailers.  On top of it come the conventions git tooling relies
+on — a blank line between title and body, body lines wrapped at
+``max_body_line_length`` — and style heuristics reported as warnings: a
+description in the imperative mood ("add", not "added"/"adds"/"adding")
+and no trailing period.  A message is valid when it has no errors; each
+violation carries a suggestion where one can be made.
+"""
+
+from __future__ import annotations
+
+import re
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.changelog import parse_commit
+from azathoth.core.commit_policy import CommitPolicy
+
+Severity = Literal["error", "warning"]
+
+# Verbs commit descriptions commonly start with; their -s/-ed/-ing forms are
+# reported with the imperative as the suggestion.
+_VERBS = (
+    "add allow avoid bump change clarify clean configure convert correct create "
+    "delete deprecate disable document drop enable ensure expose extract fix "
+    "handle hide implement improve include introduce load log make merge move "
+    "optimize pass prevent refactor release remove rename reorder replace "
+    "restore return revert rewrite run show simplify skip sort split "
+    "support switch test tidy update upgrade use validate wrap write"
+).split()
+# Verbs that double their final consonant: dropped, logging …
+_DOUBLING = frozenset({"drop", "log", "run", "skip", "split", "wrap"})
+# -s forms that usually start a noun phrase ("changes to the parser").
+_NOUNS = frozenset({"changes", "logs", "releases", "tests", "updates", "switches"})
+_IRREGULAR = {"made": "make", "ran": "run", "rewrote": "rewrite", "wrote": "write"}
+# Body lines that cannot be wrapped: bare URLs and "…-by:" trailers.
+_UNWRAPPABLE = re.compile(r"^\S+://\S+$|^[A-Za-z][\w-]*-by: ")
+
+
+def _inflections(verb: str) -> list[str]:
+    """*verb*'s third-person, past and gerund forms."""
+    if verb.endswith("y") and verb[-2] not in "aeiou":
+        return [verb[:-1] + "ies", verb[:-1] + "ied", verb + "ing"]
+    if verb.endswith("e"):
+        return [verb + "s", verb + "d", verb[:-1] + "ing"]
+    third = verb + "es" if verb.endswith(("s", "x", "z", "sh", "ch")) else verb + "s"
+    stem = verb + verb[-1] if verb in _DOUBLING else verb
+    return [third, stem + "ed", stem + "ing"]
+
+
+_NON_IMPERATIVE = {
+    form: verb
+    for verb in _VERBS
+    for form in _inflections(verb)
+    if form not in _NOUNS
+} | _IRREGULAR
+
+
+class LintViolation(BaseModel, frozen=True):
+    rule: str
+    severity: Severity
+    message: str
+    suggestion: str | None = None
+    line: int | None = Field(None, description="1-based line of the message")
+
+
+class CommitLint(BaseModel, frozen=True):
+    """Outcome of linting a commit message."""
+
+    valid: bool = Field(description="No errors (warnings allowed)")
+    title: str
+    violations: list[LintViolation] = Field(default_factory=list)
+
+
+def imperative_form(word: str) -> str | None:
+    """The imperative of *word* when it is a known verb in another form."""
+    return _NON_IMPERATIVE.get(word.lower())
+
+
+def _replace_first_word(description: str, word: str) -> str:
+    first, _, rest = description.partition(" ")
+    replacement = word.capitalize() if first[:1].isupper() else word
+    return f"{replacement} {rest}".strip()
+
+
+def _title_violations(title: str, policy: CommitPolicy) -> list[LintViolation]:
+    violations = [
+        LintViolation(rule="policy", severity="error", message=problem, line=1)
+        for problem in policy.check(title)
+    ]
+    parsed = parse_commit("", title)
+    description = parsed.description if parsed.type else title
+    if title.endswith(".") and not title.endswith("..."):
+        violations.append(
+            LintViolation(
+                rule="trailing-period",
+                severity="warning",
+                message="The title should not end with a period.",
+                suggestion=title.rstrip("."),
+                line=1,
+            )
+        )
+    first_word = re.split(r"\W+", description, maxsplit=1)[0]
+    if verb := imperative_form(first_word):
+        prefix = title[: len(title) - len(description)]
+        violations.append(
+            LintViolation(
+                rule="imperative-mood",
+                severity="warning",
+                message=f"Use the imperative mood: '{verb}', not '{first_word}'.",
+                suggestion=prefix + _replace_first_word(description, verb),
+                line=1,
+            )
+        )
+    return violations
+
+
+def lint_commit_message(
+    message: str, policy: CommitPolicy | None = None
+) -> CommitLint:
+    """Check *message* (title, blank line, body) against *policy* and style."""
+    policy = policy or CommitPolicy()
+    lines = message.strip("\n").splitlines()
+    title = lines[0].strip() if lines else ""
+    if not title:
+        return CommitLint(
+            valid=False,
+            title="",
+            violations=[
+                LintViolation(
+                    rule="empty", severity="error", message="The message is empty."
+                )
+            ],
+        )
+
+    violations = _title_violations(title, policy)
+    if len(lines) > 1 and lines[1].strip():
+        violations.append(
+            LintViolation(
+                rule="blank-line",
+                severity="error",
+                message="Separate the title from the body with a blank line.",
+                suggestion="\n".join([lines[0], "", *lines[1:]]),
+                line=2,
+            )
+        )
+    body = "\n".join(lines[1:])
+    violations += [
+        LintViolation(rule="policy", severity="error", message=problem)
+        for problem in policy.check_body(body)
+    ]
+    limit = policy.max_body_line_length
+    for number, line in enumerate(lines[1:], start=2):
+        if limit and len(line) > limit and not _UNWRAPPABLE.match(line.strip()):
+            violations.append(
+                LintViolation(
+                    rule="body-line-length",
+                    severity="warning",
+                    message=f"Line is {len(line)} characters; wrap at {limit}.",
+                    line=number,
+                )
+            )
+    return CommitLint(
+        valid=not any(v.severity == "error" for v in violations),
+        title=title,
+        violations=violations,
+    )
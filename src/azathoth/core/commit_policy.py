@@ -0,0 +1,145 @@
+"""azathoth.core.commit_policy — configurable commit message conventions.
+
+Public surface:
+  - ``CommitPolicy``               → rules for commit titles, scopes and trailers
+  - ``CommitPolicy.check(title, body)`` → list of violations (empty = valid)
+  - ``CommitPolicy.check_body(body)``   → the body's violations alone
+  - ``CommitPolicy.render_rules()``     → rule list for LLM prompts
+  - ``load_commit_policy(cwd)``    → policy from ``.azathoth.toml`` ``[commit]``
+
+Example ``.azathoth.toml``::
+
+    [commit]
+    types = ["feat", "fix", "docs", "chore"]
+    require_scope = true
+    scopes = ["cli", "core", "mcp"]
+    max_title_length = 60
+    max_body_line_length = 72
+    forbidden_trailers = ["^Co-authored-by:", "^Signed-off-by:"]
+    template = '''
+    <type>(<scope>): <summary>
+
+    <why the change was needed>
+    '''
+
+Without a ``[commit]`` table the defaults reproduce the previous hardcoded
+behaviour: Conventional Commits, 72-column titles, no co-author/sign-off.
+"""
+
+from __future__ import annotations
+
+import re
+from pathlib import Path
+
+from pydantic import BaseModel, Field, ValidationError, field_validator
+
+from azathoth.core.changelog import SECTIONS, parse_commit
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.repo_config import load_repo_config
+
+
+class CommitPolicy(BaseModel, frozen=True):
+    """Commit message conventions for a repository."""
+
+    #: Free-form message skeleton shown to the model; not parsed.
+    template: str | None = None
+    types: list[str] = Field(default_factory=lambda: [t for t, _ in SECTIONS])
+    require_scope: bool = False
+    #: Allowed scopes; empty means any scope is accepted.
+    scopes: list[str] = Field(default_factory=list)
+    max_title_length: int = 72
+    #: Body lines longer than this are flagged by ``lint_commit_message``
+    #: (0: no limit); ``check`` does not enforce it.
+    max_body_line_length: int = 72
+    allow_breaking: bool = True
+    #: Regexes matched case-insensitively against each body line.
+    forbidden_trailers: list[str] = Field(
+        default_factory=lambda: [r"^co-authored-by:", r"^signed-off-by:"]
+    )
+
+    @field_validator("forbidden_trailers")
+    @classmethod
+    def _valid_patterns(cls, patterns: list[str]) -> list[str]:
+        for pattern in patterns:
+            re.compile(pattern)
+        return patterns
+
+    def check(self, title: str, body: str = "") -> list[str]:
+        """Return every way *title*/*body* break the policy."""
+        violations: list[str] = []
+        title = title.strip()
+
+        if len(title) > self.max_title_length:
+            violations.append(
+                f"Title is {len(title)} characters; the limit is "
+                f"{self.max_title_length}."
+            )
+
+        parsed = parse_commit("", title)
+        if parsed.type is None:
+            violations.append(
+                "Title must look like 'type(scope): description' "
+                f"with type one of: {', '.join(self.types)}."
+            )
+        else:
+            if parsed.type not in self.types:
+                violations.append(
+                    f"Type '{parsed.type}' is not allowed; "
+                    f"use one of: {', '.join(self.types)}."
+                )
+            if self.require_scope and not parsed.scope:
+                violations.append("A scope is required, e.g. 'feat(core): …'.")
+            if parsed.scope and self.scopes and parsed.scope not in self.scopes:
+                violations.append(
+                    f"Scope '{parsed.scope}' is not allowed; "
+                    f"use one of: {', '.join(self.scopes)}."
+                )
+            if parsed.breaking and not self.allow_breaking:
+                violations.append("Breaking changes ('!') are not allowed.")
+
+        return violations + self.check_body(body)
+
+    def check_body(self, body: str) -> list[str]:
+        """Return every forbidden trailer line in *body*."""
+        violations: list[str] = []
+        for line in body.splitlines():
+            for pattern in self.forbidden_trailers:
+                if re.search(pattern, line.strip(), re.IGNORECASE):
+                    violations.append(f"Forbidden trailer line: '{line.strip()}'.")
+        return violations
+
+    def render_rules(self) -> str:
+        """Bullet list of the policy, phrased as instructions for a model."""
+        scope = "REQUIRED" if self.require_scope else "optional"
+        if self.scopes:
+            scope += f", one of: {', '.join(self.scopes)}"
+        rules = [
+            "- The title MUST follow the form 'type(scope): description' with "
+            f"type one of: {', '.join(self.types)}.",
+            f"- The scope is {scope}.",
+            f"- Keep the title at most {self.max_title_length} characters.",
+            "- NEVER add co-author, signed-off-by, or trailer lines matching: "
+            f"{', '.join(self.forbidden_trailers)}.",
+        ]
+        if not self.allow_breaking:
+            rules.append("- Do NOT mark the change as breaking ('!').")
+        if self.template:
+            rules.append(
+                f"- Shape the message like this template:\n{self.template.strip()}"
+            )
+        return "\n".join(rules)
+
+
+def load_commit_policy(cwd: str | Path | None = None) -> CommitPolicy:
+    """Load the ``[commit]`` table of the repo's ``.azathoth.toml``.
+
+    Raises:
+        WorkflowError: If the table contains invalid values.
+    """
+    table = load_repo_config(cwd).get("commit", {})
+    try:
+        return CommitPolicy.model_validate(table)
+    except (ValidationError, re.error) as exc:
+        raise WorkflowError(
+            f"Invalid [commit] policy in .azathoth.toml: {exc}"
+        ) from exc
@@ -0,0 +1,290 @@
+"""azathoth.core.config_drift — compare per-environment configuration files.
+
+Public surface:
+  - ``find_config_families(root)`` → ``[ConfigFamily]`` of sibling env files
+  - ``flatten_shapes(data)``       → ``{dotted.key: shape}``
+  - ``check_config_drift(root)``   → ``ConfigDriftReport``
+
+A *family* is a set of files in one directory that differ only by an
+environment token: ``config/dev.toml`` / ``config/prod.toml``,
+``settings.staging.json`` / ``settings.production.json``, or ``.env`` /
+``.env.example`` / ``.env.production.example``.  Within a family every key
+should appear in every file with the same value shape; anything else is
+reported.  Values are never included in the report — ``.env`` files hold
+secrets — only their shape (``string``, ``integer``, ``url``, ``table``…).
+"""
+
+from __future__ import annotations
+
+import json
+import re
+import tomllib
+from collections import defaultdict
+from datetime import date, datetime, time
+from pathlib import Path
+from typing import Any, Literal
+
+from dotenv import dotenv_values
+from pydantic import BaseModel, Field
+
+from azathoth.core.formatter import Table
+from azathoth.core.traverse import iter_files
+
+ConfigFormat = Literal["toml", "json", "dotenv"]
+ConfigDriftKind = Literal["missing", "shape"]
+
+#: Name tokens that mark a file as environment-specific.
+ENV_TOKENS: frozenset[str] = frozenset(
+    {
+        "dev",
+        "development",
+        "local",
+        "test",
+        "testing",
+        "staging",
+        "stage",
+        "qa",
+        "uat",
+        "preview",
+        "prod",
+        "production",
+        "ci",
+        "example",
+        "sample",
+        "template",
+        "dist",
+    }
+)
+_FORMATS: dict[str, ConfigFormat] = {".toml": "toml", ".json": "json"}
+_TOKEN_SPLIT = re.compile(r"[._-]")
+_URL = re.compile(r"^[a-zA-Z][a-zA-Z0-9+.-]*://")
+_MAX_FILES = 5000
+
+
+class ConfigFamily(BaseModel, frozen=True):
+    """Sibling config files that should carry the same keys."""
+
+    name: str
+    format: ConfigFormat
+    files: list[str]
+
+
+class ConfigDriftIssue(BaseModel, frozen=True):
+    """A key that is missing from, or shaped differently in, some files."""
+
+    family: str
+    key: str
+    kind: ConfigDriftKind
+    files: list[str] = Field(description="Files missing the key / with a shape")
+    message: str
+
+
+class ConfigDriftReport(BaseModel, frozen=True):
+    """Key and value-shape differences between environment config files."""
+
+    root: str
+    families: list[ConfigFamily] = Field(default_factory=list)
+    issues: list[ConfigDriftIssue] = Field(default_factory=list)
+    unreadable: list[str] = Field(default_factory=list)
+
+    def render_markdown(self) -> str:
+        lines = [f"# Config drift: {self.root}", ""]
+        if not self.families:
+            lines.append("No environment-specific config files found.")
+            return "\n".join(lines)
+        for family in self.families:
+            lines.append(f"- **{family.name}**: {', '.join(family.files)}")
+        if self.unreadable:
+            lines.append(f"\nUnreadable: {', '.join(self.unreadable)}")
+        lines.append("")
+        if not self.issues:
+            lines.append("No drift detected.")
+            return "\n".join(lines)
+        table = (
+            Table(overflow="ellipsis")
+            .column("Family", max_width=30)
+            .column("Key", max_width=40)
+            .column("Kind")
+            .column("Problem", max_width=70)
+        )
+        for issue in self.issues:
+            table.row([issue.family, issue.key, issue.kind, issue.message])
+        lines += ["```", table.render(), "```"]
+        return "\n".join(lines)
+
+
+# ── Discovery ─────────────────────────────────────────────────────────────────
+
+
+def _family_key(path: Path) -> tuple[str, ConfigFormat] | None:
+    """``(family stem, format)`` of an env-specific config file, or ``None``."""
+    name = path.name
+    if name == ".env" or name.startswith(".env."):
+        return ".env", "dotenv"
+    fmt = _FORMATS.get(path.suffix.lower())
+    if fmt is None:
+        return None
+    tokens = _TOKEN_SPLIT.split(path.stem)
+    if not any(t.lower() in ENV_TOKENS for t in tokens):
+        return None
+    stem = ".".join(t for t in tokens if t.lower() not in ENV_TOKENS)
+    return f"{stem or '*'}{path.suffix.lower()}", fmt
+
+
+def find_config_families(root: Path) -> list[ConfigFamily]:
+    """Group env-specific config files under *root* into families (2+ files)."""
+    root = root.resolve()
+    groups: dict[tuple[str, str, ConfigFormat], list[str]] = defaultdict(list)
+    for path in iter_files(root, max_files=_MAX_FILES):
+        if key := _family_key(path):
+            rel = path.relative_to(root)
+            groups[(rel.parent.as_posix(), *key)].append(rel.as_posix())
+    families = []
+    for (parent, stem, fmt), files in sorted(groups.items()):
+        if len(files) < 2:
+            continue
+        name = stem if parent == "." else f"{parent}/{stem}"
+        families.append(ConfigFamily(name=name, format=fmt, files=sorted(files)))
+    return families
+
+
+# ── Shapes ────────────────────────────────────────────────────────────────────
+
+
+def _scalar_shape(value: Any) -> str:
+    if value is None:
+        return "null"
+    if isinstance(value, bool):
+        return "boolean"
+    if isinstance(value, int):
+        return "integer"
+    if isinstance(value, float):
+        return "float"
+    if isinstance(value, (datetime, date, time)):
+        return "datetime"
+    if isinstance(value, list):
+        return "array"
+    if isinstance(value, str):
+        if not value.strip():
+            return "empty"
+        return "url" if _URL.match(value) else "string"
+    return type(value).__name__
+
+
+def _dotenv_shape(value: str | None) -> str:
+    """dotenv values are all strings; infer what they are meant to be."""
+    value = (value or "").strip()
+    if not value:
+        return "empty"
+    if value.lower() in ("true", "false", "yes", "no", "on", "off"):
+        return "boolean"
+    if re.fullmatch(r"[+-]?\d+", value):
+        return "integer"
+    if re.fullmatch(r"[+-]?\d*\.\d+", value):
+        return "float"
+    return "url" if _URL.match(value) else "string"
+
+
+def flatten_shapes(data: dict[str, Any], prefix: str = "") -> dict[str, str]:
+    """Map every leaf of nested *data* to its shape, keyed by dotted path.
+
+    Tables are recorded too (shape ``table``) so a key that is a table in
+    one file and a scalar in another is reported as a shape mismatch rather
+    than as a pile of missing children.
+    """
+    shapes: dict[str, str] = {}
+    for key, value in data.items():
+        dotted = f"{prefix}{key}"
+        if isinstance(value, dict):
+            shapes[dotted] = "table"
+            shapes.update(flatten_shapes(value, f"{dotted}."))
+        else:
+            shapes[dotted] = _scalar_shape(value)
+    return shapes
+
+
+def _load_shapes(path: Path, fmt: ConfigFormat) -> dict[str, str]:
+    """Raises ``ValueError`` (or ``OSError``) for unparsable files."""
+    if fmt == "dotenv":
+        return {k: _dotenv_shape(v) for k, v in dotenv_values(path).items()}
+    if fmt == "toml":
+        with open(path, "rb") as f:
+            return flatten_shapes(tomllib.load(f))
+    data = json.loads(path.read_text(encoding="utf-8"))
+    if not isinstance(data, dict):
+        raise ValueError("top level is not an object")
+    return flatten_shapes(data)
+
+
+# ── Comparison ────────────────────────────────────────────────────────────────
+
+
+def _parent_is_table(key: str, shapes: dict[str, str]) -> bool:
+    """True if *key*'s parent exists as a table in *shapes* (or it is top-level).
+
+    A missing table is reported once, not once per child; a table that is a
+    scalar in another file is reported as a shape mismatch instead.
+    """
+    parent = key.rpartition(".")[0]
+    return not parent or shapes.get(parent) == "table"
+
+
+def _compare(
+    family: ConfigFamily, loaded: dict[str, dict[str, str]]
+) -> list[ConfigDriftIssue]:
+    issues: list[ConfigDriftIssue] = []
+    keys = sorted({k for shapes in loaded.values() for k in shapes})
+    for key in keys:
+        present = {f: s[key] for f, s in loaded.items() if key in s}
+        absent = [
+            f
+            for f, shapes in loaded.items()
+            if key not in shapes and _parent_is_table(key, shapes)
+        ]
+        if absent:
+            issues.append(
+                ConfigDriftIssue(
+                    family=family.name,
+                    key=key,
+                    kind="missing",
+                    files=absent,
+                    message=(
+                        f"missing from {', '.join(absent)} "
+                        f"(present in {', '.join(present)})"
+                    ),
+                )
+            )
+        # Empty values are placeholders (``API_KEY=`` in an example file).
+        distinct = {s for s in present.values() if s != "empty"}
+        if len(distinct) > 1:
+            detail = ", ".join(f"{f}: {s}" for f, s in present.items())
+            issues.append(
+                ConfigDriftIssue(
+                    family=family.name,
+                    key=key,
+                    kind="shape",
+                    files=list(present),
+                    message=f"value shapes differ ({detail})",
+                )
+            )
+    return issues
+
+
+def check_config_drift(root: Path) -> ConfigDriftReport:
+    """Compare every config family under *root* key by key."""
+    root = root.resolve()
+    families = find_config_families(root)
+    issues: list[ConfigDriftIssue] = []
+    unreadable: list[str] = []
+    for family in families:
+        loaded: dict[str, dict[str, str]] = {}
+        for rel in family.files:
+            try:
+                loaded[rel] = _load_shapes(root / rel, family.format)
+            except (ValueError, OSError):
+                unreadable.append(rel)
+        if len(loaded) >= 2:
+            issues += _compare(family, loaded)
+    return ConfigDriftReport(
+        root=str(root), families=families, issues=issues, unreadable=unreadable
+    )
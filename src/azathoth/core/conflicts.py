@@ -0,0 +1,303 @@
+"""azathoth.core.conflicts — inspect and resolve conflicts of a stopped operation.
+
+Public surface:
+  - ``operation_in_progress(cwd)``          → ``Operation`` or ``None``
+  - ``has_conflict_markers(text)``          → ``bool``
+  - ``parse_conflict_hunks(text)``          → ``[ConflictHunk]``
+  - ``list_conflicts(cwd)``                 → ``ConflictReport``
+  - ``resolve_conflict(path, content, …)``  → ``GitResult``
+  - ``abort_operation(cwd, dry_run)``       → ``GitResult``
+  - ``continue_operation(cwd, dry_run)``    → ``GitResult``
+
+Hunks are always three-way: the index stages of each conflicted file are
+checked out to temporary files and re-merged with ``git merge-file --diff3``,
+so ``base`` is filled in whatever ``merge.conflictStyle`` the repository
+uses, and the agent's partial edits in the working tree do not disturb the
+report.  During a rebase git's "ours" is the branch being rebased onto and
+"theirs" the commit being replayed.
+"""
+
+from __future__ import annotations
+
+import shutil
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.rebase import _MESSAGE_DIR
+from azathoth.core.repos import repo_dir
+from azathoth.core.workflow import (
+    GitResult,
+    _run_git,
+    get_repo_context,
+    planned,
+)
+
+Operation = Literal["merge", "rebase", "cherry-pick", "revert"]
+
+# Marker files in the (per-worktree) git directory, checked in order.
+_MARKERS: list[tuple[str, Operation]] = [
+    ("rebase-merge", "rebase"),
+    ("rebase-apply", "rebase"),
+    ("MERGE_HEAD", "merge"),
+    ("CHERRY_PICK_HEAD", "cherry-pick"),
+    ("REVERT_HEAD", "revert"),
+]
+
+# Index stages present (1 base, 2 ours, 3 theirs) → what happened.
+_KINDS = {
+    frozenset({1, 2, 3}): "both modified",
+    frozenset({2, 3}): "both added",
+    frozenset({1, 2}): "deleted by them",
+    frozenset({1, 3}): "deleted by us",
+    frozenset({2}): "added by us",
+    frozenset({3}): "added by them",
+    frozenset({1}): "both deleted",
+}
+
+_MARKER_SIZE = 7
+
+
+class ConflictHunk(BaseModel, frozen=True):
+    context: list[str] = Field(
+        default_factory=list, description="Up to 3 lines preceding the hunk"
+    )
+    ours: str
+    base: str
+    theirs: str
+
+
+class ConflictFile(BaseModel, frozen=True):
+    path: str
+    kind: str = Field(description="both modified, deleted by them, …")
+    markers_in_tree: bool = Field(
+        False, description="The working-tree file still has conflict markers"
+    )
+    hunks: list[ConflictHunk] = Field(default_factory=list)
+
+
+class ConflictReport(BaseModel, frozen=True):
+    operation: Operation | None = None
+    files: list[ConflictFile] = Field(default_factory=list)
+
+
+def _fail(message: str) -> GitResult:
+    return GitResult(success=False, stdout="", stderr=message, message=message)
+
+
+async def operation_in_progress(cwd: str | None = None) -> Operation | None:
+    """The merge, rebase, cherry-pick or revert stopped in *cwd*'s worktree."""
+    git_dir = Path((await get_repo_context(cwd)).git_dir)
+    for marker, operation in _MARKERS:
+        if (git_dir / marker).exists():
+            return operation
+    return None
+
+
+# ── Inspection ────────────────────────────────────────────────────────────────
+
+
+def _is_marker(line: str, char: str) -> bool:
+    return line.startswith(char * _MARKER_SIZE) and (
+        len(line) == _MARKER_SIZE or line[_MARKER_SIZE] in " \t"
+    )
+
+
+def has_conflict_markers(text: str) -> bool:
+    lines = text.splitlines()
+    return any(_is_marker(line, "<") for line in lines) and any(
+        _is_marker(line, ">") for line in lines
+    )
+
+
+def parse_conflict_hunks(text: str) -> list[ConflictHunk]:
+    """Hunks of a file with diff3-style markers (``base`` empty without one)."""
+    hunks: list[ConflictHunk] = []
+    context: list[str] = []
+    section: str | None = None
+    parts: dict[str, list[str]] = {}
+    for line in text.splitlines():
+        if section is None:
+            if _is_marker(line, "<"):
+                section, parts = "ours", {"ours": [], "base": [], "theirs": []}
+            else:
+                context = [*context, line][-3:]
+            continue
+        if _is_marker(line, "|"):
+            section = "base"
+        elif _is_marker(line, "="):
+            section = "theirs"
+        elif _is_marker(line, ">"):
+            hunks.append(
+                ConflictHunk(
+                    context=context, **{k: "\n".join(v) for k, v in parts.items()}
+                )
+            )
+            section, context = None, []
+        else:
+            parts[section].append(line)
+    return hunks
+
+
+async def _unmerged_stages(cwd: str) -> dict[str, set[int]]:
+    _, out, _ = await _run_git(["ls-files", "--unmerged", "-z"], cwd=cwd)
+    stages: dict[str, set[int]] = {}
+    for record in filter(None, out.split("\0")):
+        info, _, path = record.partition("\t")
+        stages.setdefault(path, set()).add(int(info.split()[2]))
+    return stages
+
+
+async def _three_way_hunks(path: str, root: str) -> list[ConflictHunk]:
+    """Re-merge the index stages of *path* with diff3 markers."""
+    code, out, _ = await _run_git(
+        ["checkout-index", "--stage=all", "--temp", "--", path], cwd=root
+    )
+    if code != 0:
+        return []
+    names = out.split("\t", 1)[0].split()
+    temps = [Path(root, name) for name in names if name != "."]
+    empty = Path(root, ".merge_file_empty")
+    try:
+        empty.touch()
+        base, ours, theirs = (
+            Path(root, name) if name != "." else empty for name in names
+        )
+        await _run_git(
+            [
+                "merge-file",
+                "--diff3",
+                "-L",
+                "ours",
+                "-L",
+                "base",
+                "-L",
+                "theirs",
+                str(ours),
+                str(base),
+                str(theirs),
+            ],
+            cwd=root,
+        )
+        merged = ours.read_text(encoding="utf-8", errors="replace")
+    finally:
+        for temp in (*temps, empty):
+            temp.unlink(missing_ok=True)
+    return parse_conflict_hunks(merged)
+
+
+async def list_conflicts(cwd: str | None = None) -> ConflictReport:
+    """Conflicted paths of the stopped operation, with three-way hunks."""
+    context = await get_repo_context(cwd)
+    if context.root is None:
+        return ConflictReport()
+    files = []
+    for path, stages in sorted((await _unmerged_stages(context.root)).items()):
+        both = {2, 3} <= stages
+        tree_file = Path(context.root, path)
+        in_tree = tree_file.is_file() and has_conflict_markers(
+            tree_file.read_text(encoding="utf-8", errors="replace")
+        )
+        files.append(
+            ConflictFile(
+                path=path,
+                kind=_KINDS.get(frozenset(stages), "unmerged"),
+                markers_in_tree=in_tree,
+                hunks=await _three_way_hunks(path, context.root) if both else [],
+            )
+        )
+    return ConflictReport(operation=await operation_in_progress(cwd), files=files)
+
+
+# ── Resolution ────────────────────────────────────────────────────────────────
+
+
+async def resolve_conflict(
+    path: str,
+    content: str | None = None,
+    delete: bool = False,
+    cwd: str | None = None,
+    dry_run: bool = False,
+) -> GitResult:
+    """Write the resolved *content* of a conflicted *path* and stage it.
+
+    With *delete* the file is removed instead (``git rm``), e.g. to accept
+    a deletion.  Content that still has conflict markers is refused.
+    """
+    context = await get_repo_context(cwd)
+    if context.root is None:
+        return _fail("A bare repository has no conflicts to resolve.")
+    root = Path(context.root)
+    target = (Path(cwd or repo_dir() or ".").resolve() / path).resolve()
+    if not target.is_relative_to(root):
+        return _fail(f"{path} is outside the repository.")
+    rel = target.relative_to(root).as_posix()
+    if rel not in await _unmerged_stages(str(root)):
+        return _fail(f"{rel} has no unresolved conflict.")
+    if delete:
+        args = ["rm", "--quiet", "--", rel]
+    elif content is None:
+        return _fail("Pass the resolved content, or delete=True.")
+    elif has_conflict_markers(content):
+        return _fail(f"The content for {rel} still has conflict markers.")
+    else:
+        args = ["add", "--", rel]
+    if dry_run:
+        return planned(["git", *args])
+
+    if content is not None and not delete:
+        target.write_text(content, encoding="utf-8")
+    code, out, err = await _run_git(args, cwd=str(root))
+    return GitResult(success=(code == 0), stdout=out, stderr=err)
+
+
+async def abort_operation(
+    cwd: str | None = None, dry_run: bool = False
+) -> GitResult:
+    """Abort the stopped merge, rebase, cherry-pick or revert."""
+    operation = await operation_in_progress(cwd)
+    if operation is None:
+        return _fail("No merge, rebase, cherry-pick or revert is in progress.")
+    args = [operation, "--abort"]
+    if dry_run:
+        return planned(["git", *args])
+    code, out, err = await _run_git(args, cwd=cwd)
+    if code == 0 and operation == "rebase":
+        shutil.rmtree(
+            Path((await get_repo_context(cwd)).git_dir) / _MESSAGE_DIR,
+            ignore_errors=True,
+        )
+    return GitResult(success=(code == 0), stdout=out, stderr=err)
+
+
+async def continue_operation(
+    cwd: str | None = None, dry_run: bool = False
+) -> GitResult:
+    """Continue the stopped operation once every conflict is resolved.
+
+    Commit messages are kept as git proposes them (no editor opens).  If
+    the next step conflicts, the new conflicted paths are in ``message``.
+    """
+    operation = await operation_in_progress(cwd)
+    if operation is None:
+        return _fail("No merge, rebase, cherry-pick or revert is in progress.")
+    context = await get_repo_context(cwd)
+    if unresolved := await _unmerged_stages(context.root or repo_dir() or "."):
+        return _fail(f"Unresolved conflicts remain: {', '.join(sorted(unresolved))}.")
+    args = [operation, "--continue"]
+    if dry_run:
+        return planned(["git", *args])
+
+    code, out, err = await _run_git(args, cwd=cwd, env={"GIT_EDITOR": "true"})
+    if code == 0:
+        if await operation_in_progress(cwd) is None:
+            shutil.rmtree(Path(context.git_dir) / _MESSAGE_DIR, ignore_errors=True)
+        return GitResult(success=True, stdout=out, stderr=err)
+    conflicts = sorted(await _unmerged_stages(context.root or repo_dir() or "."))
+    message = (
+        f"Stopped again on conflicts in: {', '.join(conflicts)}."
+        if conflicts
+        else None
+    )
+    return GitResult(success=False, stdout=out, stderr=err, message=message)
@@ -0,0 +1,175 @@
+"""azathoth.core.crates — publish a Rust crate to crates.io.
+
+Public surface:
+  - ``find_crate(root, package)``             → ``Crate``
+  - ``is_published(name, version)``           → ``bool``
+  - ``publish_crate(root, package, cwd, …)``  → ``CratePublish``
+
+Publishing is the one release step that cannot be undone (crates.io only
+allows yanking), so the checks come first: the version must not be on
+crates.io yet, HEAD must carry the tag for it (``v<version>``,
+``<version>`` or the workspace tag ``<name>-v<version>``), and
+``cargo publish --dry-run`` must package and build the crate.  Only then
+is ``cargo publish`` run.  A dry run performs every check and returns the
+publish command instead of running it.
+"""
+
+from __future__ import annotations
+
+import tomllib
+from pathlib import Path
+
+import httpx
+from pydantic import BaseModel, Field
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.version import Manifest, find_manifest
+from azathoth.core.workflow import _run_git, format_command, run_command
+from azathoth.core.workspace import discover_workspace, package_tag
+
+REGISTRY_API = "https://crates.io/api/v1/crates"
+CRATE_URL = "https://crates.io/crates"
+# crates.io rejects API requests without an identifying User-Agent.
+_USER_AGENT = "azathoth (https://github.com/Yrrrrrf/azathoth)"
+
+
+class Crate(BaseModel, frozen=True):
+    name: str
+    version: str
+    manifest: Manifest
+    member: bool = Field(False, description="Published with --package")
+
+
+class CratePublish(BaseModel, frozen=True):
+    """Outcome (or dry-run plan) of publishing a crate."""
+
+    name: str
+    version: str
+    tag: str
+    url: str
+    published: bool = False
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+def _crate_name(path: Path) -> str | None:
+    with open(path, "rb") as f:
+        return tomllib.load(f).get("package", {}).get("name")
+
+
+def find_crate(root: Path, package: str | None = None) -> Crate:
+    """The crate at *root*, or the workspace member named *package*.
+
+    Raises:
+        WorkflowError: If there is no such crate or its version is not static.
+    """
+    if package:
+        for member in discover_workspace(root):
+            if member.name == package and member.manifest.kind == "cargo":
+                return Crate(
+                    name=member.name,
+                    version=member.manifest.version,
+                    manifest=member.manifest,
+                    member=True,
+                )
+        raise WorkflowError(f"No crate named '{package}' in the workspace at {root}.")
+
+    if not (root / "Cargo.toml").is_file():
+        raise WorkflowError(f"No Cargo.toml in {root}.")
+    manifest = find_manifest(root)
+    try:
+        name = _crate_name(manifest.path) if manifest.kind == "cargo" else None
+    except tomllib.TOMLDecodeError as exc:
+        raise WorkflowError(f"Invalid Cargo.toml: {exc}") from exc
+    if name is None:
+        raise WorkflowError(
+            f"{root / 'Cargo.toml'} has no [package] with a version; pass the "
+            "package name of a workspace member."
+        )
+    return Crate(name=name, version=manifest.version, manifest=manifest)
+
+
+async def is_published(name: str, version: str) -> bool:
+    """Whether crates.io already has *version* of crate *name*.
+
+    Raises:
+        WorkflowError: If crates.io cannot be reached or answers unexpectedly.
+    """
+    try:
+        async with httpx.AsyncClient(
+            headers={"User-Agent": _USER_AGENT}, timeout=15
+        ) as client:
+            resp = await client.get(f"{REGISTRY_API}/{name}/{version}")
+    except httpx.HTTPError as exc:
+        raise WorkflowError(f"Could not reach crates.io: {exc}") from exc
+    if resp.status_code == 404:
+        return False
+    if resp.status_code == 200:
+        return True
+    raise WorkflowError(f"crates.io answered {resp.status_code} for {name} {version}.")
+
+
+async def _release_tag(crate: Crate, cwd: str | None) -> str:
+    """The tag on HEAD naming *crate*'s version."""
+    _, out, _ = await _run_git(["tag", "--points-at", "HEAD"], cwd=cwd)
+    tags = out.splitlines()
+    accepted = [
+        f"v{crate.version}",
+        crate.version,
+        package_tag(crate.name, crate.version),
+    ]
+    for tag in accepted:
+        if tag in tags:
+            return tag
+    if tags:
+        raise WorkflowError(
+            f"HEAD is tagged {', '.join(tags)}, but Cargo.toml says "
+            f"{crate.name} {crate.version} (expected {accepted[0]} or {accepted[2]})."
+        )
+    raise WorkflowError(
+        f"HEAD has no release tag for {crate.name} {crate.version}; tag it "
+        f"{accepted[0]} (or {accepted[2]}) first."
+    )
+
+
+async def publish_crate(
+    root: Path,
+    package: str | None = None,
+    cwd: str | None = None,
+    dry_run: bool = False,
+) -> CratePublish:
+    """Check and publish the crate at *root* (or workspace member *package*).
+
+    Raises:
+        WorkflowError: If the version is already on crates.io, HEAD's tag
+            does not match Cargo.toml, or ``cargo publish`` fails.
+    """
+    crate = find_crate(root, package)
+    url = f"{CRATE_URL}/{crate.name}/{crate.version}"
+    if await is_published(crate.name, crate.version):
+        raise WorkflowError(
+            f"{crate.name} {crate.version} is already on crates.io ({url}); "
+            "bump the version first."
+        )
+    tag = await _release_tag(crate, cwd)
+
+    publish = ["cargo", "publish"]
+    if crate.member:
+        publish += ["--package", crate.name]
+    code, out, err = await run_command([*publish, "--dry-run"], cwd=str(root))
+    if code != 0:
+        raise WorkflowError(f"cargo publish --dry-run failed: {err or out}")
+    if dry_run:
+        return CratePublish(
+            name=crate.name,
+            version=crate.version,
+            tag=tag,
+            url=url,
+            commands=[format_command(publish)],
+        )
+
+    code, out, err = await run_command(publish, cwd=str(root))
+    if code != 0:
+        raise WorkflowError(f"cargo publish failed: {err or out}")
+    return CratePublish(
+        name=crate.name, version=crate.version, tag=tag, url=url, published=True
+    )
@@ -0,0 +1,159 @@
+"""azathoth.core.defaults — tool parameter defaults computed from repo state.
+
+Public surface:
+  - ``default_branch(cwd)``         → the branch PRs and history target
+  - ``bump_level(commits)``         → ``(BumpLevel, reason)`` for commits
+  - ``bump_evidence(commits, level)`` → the commits that call for *level*
+  - ``suggest_next_version(cwd)``   → ``VersionSuggestion`` from commits
+    since the latest tag, with the commits that decided it
+  - ``RESOLVERS``                   — resolver name → async ``(cwd) → str``
+  - ``resolve_defaults(arguments, spec, cwd)`` → arguments with omitted
+    parameters filled in, plus the values that were resolved
+
+A tool declares ``{parameter: resolver name}``; when the caller omits the
+parameter (or passes ``null``) the resolver runs server-side.  A resolver
+that cannot answer leaves the parameter unset so the tool's own fallback
+applies — resolution never turns a working call into a failing one.
+"""
+
+from __future__ import annotations
+
+import logging
+from collections.abc import Awaitable, Callable, Mapping
+from typing import Any
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.changelog import ConventionalCommit, get_commits
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.policy import protected_branch_names
+from azathoth.core.version import BumpLevel, bump
+from azathoth.core.workflow import _run_git, get_latest_tag
+
+log = logging.getLogger(__name__)
+
+Resolver = Callable[[str | None], Awaitable[str]]
+
+#: Tag proposed when the repository has never been tagged.
+FIRST_VERSION = "v0.1.0"
+
+
+class VersionSuggestion(BaseModel, frozen=True):
+    current: str | None
+    next: str
+    level: BumpLevel
+    reason: str
+    evidence: list[str] = Field(
+        default_factory=list,
+        description="'<sha> <subject>' of the commits that decided the level",
+    )
+
+
+async def default_branch(cwd: str | None = None) -> str:
+    """``origin/HEAD``'s branch, else the first protected branch that exists.
+
+    Raises:
+        WorkflowError: If neither is available.
+    """
+    code, out, _ = await _run_git(
+        ["symbolic-ref", "--quiet", "--short", "refs/remotes/origin/HEAD"], cwd=cwd
+    )
+    if code == 0 and out:
+        return out.removeprefix("origin/")
+    for branch in protected_branch_names():
+        code, _, _ = await _run_git(
+            ["rev-parse", "--verify", "--quiet", f"refs/heads/{branch}"], cwd=cwd
+        )
+        if code == 0:
+            return branch
+    raise WorkflowError("Cannot determine the default branch (no origin/HEAD).")
+
+
+def bump_level(commits: list[ConventionalCommit]) -> tuple[BumpLevel, str]:
+    """Level *commits* call for — breaking → major, feat → minor, else patch."""
+    breaking = sum(c.breaking for c in commits)
+    features = sum(c.type == "feat" for c in commits)
+    if breaking:
+        return "major", f"{breaking} breaking change(s)"
+    if features:
+        return "minor", f"{features} feature(s)"
+    return "patch", f"{len(commits)} commit(s), no features"
+
+
+def bump_evidence(commits: list[ConventionalCommit], level: BumpLevel) -> list[str]:
+    """The commits behind *level*: breaking ones, features, or all of them."""
+    if level == "major":
+        decisive = [c for c in commits if c.breaking]
+    elif level == "minor":
+        decisive = [c for c in commits if c.type == "feat"]
+    else:
+        decisive = commits
+    return [f"{c.short_sha} {c.subject}" for c in decisive]
+
+
+async def suggest_next_version(cwd: str | None = None) -> VersionSuggestion:
+    """Next tag by Conventional Commits since the latest tag.
+
+    Breaking changes (``!`` or a ``BREAKING CHANGE:`` footer) bump major,
+    ``feat`` minor, anything else patch; a ``v`` prefix on the current tag
+    is kept.  ``evidence`` lists the commits that decided the level.
+
+    Raises:
+        WorkflowError: If the latest tag is not a semantic version or there
+            are no commits since it.
+    """
+    current = await get_latest_tag(cwd=cwd)
+    if current is None:
+        return VersionSuggestion(
+            current=None, next=FIRST_VERSION, level="minor", reason="no tags yet"
+        )
+    try:
+        commits = await get_commits(current, cwd=cwd)
+    except ValueError as exc:
+        raise WorkflowError(str(exc)) from exc
+    if not commits:
+        raise WorkflowError(f"No commits since {current} — nothing to release.")
+
+    level, reason = bump_level(commits)
+    prefix = "v" if current.startswith("v") else ""
+    return VersionSuggestion(
+        current=current,
+        next=prefix + bump(current, level),
+        level=level,
+        reason=reason,
+        evidence=bump_evidence(commits, level),
+    )
+
+
+async def _next_version_tag(cwd: str | None) -> str:
+    return (await suggest_next_version(cwd)).next
+
+
+RESOLVERS: dict[str, Resolver] = {
+    "default_branch": default_branch,
+    "next_version": _next_version_tag,
+}
+
+
+async def resolve_defaults(
+    arguments: Mapping[str, Any],
+    spec: Mapping[str, str],
+    cwd: str | None = None,
+) -> tuple[dict[str, Any], dict[str, str]]:
+    """Fill parameters in *spec* that *arguments* omits or sets to ``None``.
+
+    Returns ``(arguments, resolved)`` where *resolved* holds only the
+    parameters that were filled in.
+    """
+    filled = dict(arguments)
+    resolved: dict[str, str] = {}
+    for param, name in spec.items():
+        if filled.get(param) is not None:
+            continue
+        try:
+            value = await RESOLVERS[name](cwd)
+        except WorkflowError as exc:
+            log.debug("Default for %s (%s) unresolved: %s", param, name, exc)
+            continue
+        filled[param] = resolved[param] = value
+    return filled, resolved
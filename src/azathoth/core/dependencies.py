@@ -0,0 +1,264 @@
+"""azathoth.core.dependencies — resolved dependency list from manifests and lockfiles.
+
+Public surface:
+  - ``Dependency``             — one package, direct or transitive
+  - ``dependencies(root)``     → ``DependencyReport``
+
+Direct dependencies come from the manifests (see ``declared_dependencies``
+in core/stack.py).  Lockfiles add resolved versions and the transitive
+packages: Cargo.lock, package-lock.json, uv.lock and poetry.lock are read
+as dependency graphs, and go.mod's ``// indirect`` requirements are taken
+as transitive.  A transitive package is ``dev`` when it is only reachable
+through dev dependencies.  Without a lockfile only direct dependencies,
+with their declared version specs, are reported.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+from collections.abc import Iterable, Mapping
+from pathlib import Path
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.stack import DeclaredDependency, _load_toml, _norm
+from azathoth.core.stack import declared_dependencies
+
+_GO_INDIRECT = re.compile(r"^\s*(?:require\s+)?(\S+)\s+(\S+)\s*//\s*indirect", re.M)
+
+
+class Dependency(BaseModel, frozen=True):
+    name: str
+    ecosystem: str
+    version: str = Field("", description="Resolved version, or the declared spec")
+    direct: bool
+    dev: bool = False
+    source: str = Field(description="Manifest or lockfile it was read from")
+
+
+class DependencyReport(BaseModel, frozen=True):
+    dependencies: list[Dependency] = Field(description="Direct first, then by name")
+    manifests: list[str]
+    lockfiles: list[str]
+
+    @property
+    def direct(self) -> list[Dependency]:
+        return [dep for dep in self.dependencies if dep.direct]
+
+
+# A lock graph: package name → (resolved version, names it depends on).
+_Graph = Mapping[str, tuple[str, list[str]]]
+
+
+def _reachable(graph: _Graph, roots: Iterable[str]) -> set[str]:
+    seen: set[str] = set()
+    stack = [name for name in roots if name in graph]
+    while stack:
+        name = stack.pop()
+        if name in seen:
+            continue
+        seen.add(name)
+        stack.extend(dep for dep in graph[name][1] if dep in graph)
+    return seen
+
+
+def _from_graph(
+    graph: _Graph,
+    direct: list[DeclaredDependency],
+    ecosystem: str,
+    lockfile: str,
+    skip: Iterable[str] = (),
+) -> list[Dependency]:
+    """Every package of *graph* but the project's own (*skip*), classified."""
+    declared = {dep.name: dep for dep in direct}
+    runtime = _reachable(graph, [n for n, d in declared.items() if not d.dev])
+    dev_only = _reachable(graph, [n for n, d in declared.items() if d.dev]) - runtime
+    skipped = set(skip)
+    deps = []
+    for name, (version, _) in graph.items():
+        if name in skipped:
+            continue
+        in_manifest = declared.get(name)
+        deps.append(
+            Dependency(
+                name=name,
+                ecosystem=ecosystem,
+                version=version,
+                direct=in_manifest is not None,
+                dev=in_manifest.dev if in_manifest else name in dev_only,
+                source=lockfile,
+            )
+        )
+    return deps
+
+
+def _cargo_lock(
+    root: Path, direct: list[DeclaredDependency]
+) -> list[Dependency] | None:
+    packages = _load_toml(root / "Cargo.lock").get("package", [])
+    if not packages:
+        return None
+    # Entries are "name" or "name version" when several versions are locked.
+    graph = {
+        _norm(p["name"]): (
+            p.get("version", ""),
+            [_norm(d.split()[0]) for d in p.get("dependencies", [])],
+        )
+        for p in packages
+    }
+    local = [_norm(p["name"]) for p in packages if "source" not in p]
+    # Workspace crates depend on crates the root Cargo.toml may not list.
+    known = {dep.name for dep in direct}
+    direct = direct + [
+        DeclaredDependency(name=name, ecosystem="rust", manifest="Cargo.lock")
+        for crate in local
+        for name in graph[crate][1]
+        if name not in known and name not in local
+    ]
+    return _from_graph(graph, direct, "rust", "Cargo.lock", skip=local)
+
+
+def _package_lock(root: Path) -> list[Dependency] | None:
+    try:
+        data = json.loads((root / "package-lock.json").read_text(encoding="utf-8"))
+    except (OSError, json.JSONDecodeError):
+        return None
+    packages = data.get("packages")
+    if not packages:
+        return None
+    project = packages.get("", {})
+    direct = {
+        *project.get("dependencies", {}),
+        *project.get("devDependencies", {}),
+        *project.get("peerDependencies", {}),
+    }
+    deps = []
+    for path, info in packages.items():
+        if not path or "node_modules/" not in path or info.get("link"):
+            continue
+        name = path.rsplit("node_modules/", 1)[1]
+        top_level = path == f"node_modules/{name}"
+        deps.append(
+            Dependency(
+                name=_norm(name),
+                ecosystem="javascript",
+                version=info.get("version", ""),
+                direct=top_level and name in direct,
+                dev=bool(info.get("dev") or info.get("devOptional")),
+                source="package-lock.json",
+            )
+        )
+    return deps
+
+
+def _uv_lock(root: Path, direct: list[DeclaredDependency]) -> list[Dependency] | None:
+    packages = _load_toml(root / "uv.lock").get("package", [])
+    if not packages:
+        return None
+    graph = {
+        _norm(p["name"]): (
+            p.get("version", ""),
+            [_norm(d["name"]) for d in p.get("dependencies", [])],
+        )
+        for p in packages
+    }
+    # The project itself is locked as an editable (or virtual) package whose
+    # edges are the direct dependencies, dev groups included.
+    local = []
+    declared = list(direct)
+    known = {dep.name for dep in direct}
+    for p in packages:
+        source = p.get("source", {})
+        if "editable" not in source and "virtual" not in source:
+            continue
+        local.append(_norm(p["name"]))
+        groups = [(p.get("dependencies", []), False)]
+        groups += [(g, True) for g in p.get("dev-dependencies", {}).values()]
+        for members, dev in groups:
+            for d in members:
+                if (name := _norm(d["name"])) not in known:
+                    known.add(name)
+                    declared.append(
+                        DeclaredDependency(
+                            name=name, ecosystem="python", dev=dev, manifest="uv.lock"
+                        )
+                    )
+    return _from_graph(graph, declared, "python", "uv.lock", skip=local)
+
+
+def _poetry_lock(
+    root: Path, direct: list[DeclaredDependency]
+) -> list[Dependency] | None:
+    packages = _load_toml(root / "poetry.lock").get("package", [])
+    if not packages:
+        return None
+    graph = {
+        _norm(p["name"]): (
+            p.get("version", ""),
+            [_norm(name) for name in p.get("dependencies", {})],
+        )
+        for p in packages
+    }
+    return _from_graph(graph, direct, "python", "poetry.lock")
+
+
+def _go_indirect(root: Path) -> set[str]:
+    """Modules go.mod marks ``// indirect`` (required only transitively)."""
+    go_mod = root / "go.mod"
+    if not go_mod.exists():
+        return set()
+    text = go_mod.read_text(errors="ignore")
+    return {module.lower() for module, _ in _GO_INDIRECT.findall(text)}
+
+
+def _unlocked(
+    declared: list[DeclaredDependency], indirect: set[str]
+) -> list[Dependency]:
+    return [
+        Dependency(
+            name=dep.name,
+            ecosystem=dep.ecosystem,
+            version=dep.version_spec,
+            direct=dep.name not in indirect,
+            dev=dep.dev,
+            source=dep.manifest,
+        )
+        for dep in declared
+    ]
+
+
+def dependencies(root: Path) -> DependencyReport:
+    """Direct and (where a lockfile exists) transitive dependencies of *root*."""
+    declared = declared_dependencies(root)
+    by_ecosystem: dict[str, list[DeclaredDependency]] = {}
+    for dep in declared:
+        by_ecosystem.setdefault(dep.ecosystem, []).append(dep)
+    # A Cargo.lock or uv.lock can stand in for a manifest we cannot read.
+    for ecosystem in ("rust", "python"):
+        by_ecosystem.setdefault(ecosystem, [])
+
+    lock_readers = {
+        "rust": [("Cargo.lock", _cargo_lock)],
+        "javascript": [("package-lock.json", lambda root, _: _package_lock(root))],
+        "python": [("uv.lock", _uv_lock), ("poetry.lock", _poetry_lock)],
+    }
+    deps: list[Dependency] = []
+    lockfiles: list[str] = []
+    for ecosystem, direct in sorted(by_ecosystem.items()):
+        locked = None
+        for lockfile, read in lock_readers.get(ecosystem, []):
+            if (root / lockfile).is_file():
+                locked = read(root, direct)
+            if locked is not None:
+                lockfiles.append(lockfile)
+                break
+        indirect = _go_indirect(root) if ecosystem == "go" else set()
+        deps += locked if locked is not None else _unlocked(direct, indirect)
+
+    deps.sort(key=lambda dep: (not dep.direct, dep.ecosystem, dep.name))
+    return DependencyReport(
+        dependencies=deps,
+        manifests=sorted({dep.manifest for dep in declared}),
+        lockfiles=lockfiles,
+    )
@@ -0,0 +1,253 @@
+"""azathoth.core.detect — language and framework detection from manifests.
+
+Public surface:
+  - ``MANIFEST_LANGUAGES``  — manifest file name → language
+  - ``detect_stack(root)``  → ``StackDetection`` (languages, frameworks,
+    manifests, container files and the directives ``adapt`` should load)
+  - ``languages_of(paths)`` → the ``adapt`` languages of some source files,
+    most files first (for scoping directives to a diff)
+
+Only manifests are read — at the root and up to ``_MAX_DEPTH`` directories
+below it, so workspaces and monorepos are covered — never source files;
+``stack_profile`` is the tool for import-level evidence.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+from collections import Counter
+from pathlib import Path
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.directives import list_directives
+from azathoth.core.stack import declared_dependencies
+from azathoth.core.traverse import DEFAULT_SKIP_DIRS, IgnoreRules
+
+MANIFEST_LANGUAGES: dict[str, str] = {
+    "Cargo.toml": "rust",
+    "package.json": "javascript",
+    "pyproject.toml": "python",
+    "requirements.txt": "python",
+    "setup.py": "python",
+    "go.mod": "go",
+    "pom.xml": "java",
+    "build.gradle": "java",
+    "build.gradle.kts": "kotlin",
+    "deno.json": "typescript",
+}
+# Source suffix → language, named as in MANIFEST_LANGUAGES (what ``adapt``
+# takes).  Markup, data and config files have none.
+SOURCE_LANGUAGES: dict[str, str] = {
+    ".rs": "rust",
+    ".py": "python",
+    ".pyi": "python",
+    ".ts": "typescript",
+    ".tsx": "typescript",
+    ".mts": "typescript",
+    ".js": "javascript",
+    ".jsx": "javascript",
+    ".mjs": "javascript",
+    ".cjs": "javascript",
+    ".svelte": "svelte",
+    ".vue": "vue",
+    ".go": "go",
+    ".java": "java",
+    ".kt": "kotlin",
+    ".kts": "kotlin",
+    ".swift": "swift",
+    ".c": "c",
+    ".h": "c",
+    ".cc": "cpp",
+    ".cpp": "cpp",
+    ".hpp": "cpp",
+    ".cs": "csharp",
+    ".rb": "ruby",
+    ".php": "php",
+    ".sh": "shell",
+    ".sql": "sql",
+}
+CONTAINER_FILES = (
+    "Dockerfile",
+    "Containerfile",
+    "compose.yaml",
+    "docker-compose.yml",
+)
+
+_MAX_DEPTH = 2
+
+# ecosystem (as in core.stack) → {dependency name: framework}.  Dependency
+# names are normalised the way ``declared_dependencies`` reports them.
+_FRAMEWORKS: dict[str, dict[str, str]] = {
+    "python": {
+        "django": "django",
+        "flask": "flask",
+        "fastapi": "fastapi",
+        "starlette": "starlette",
+        "fastmcp": "fastmcp",
+        "typer": "typer",
+        "click": "click",
+        "streamlit": "streamlit",
+    },
+    "javascript": {
+        "react": "react",
+        "next": "next",
+        "vue": "vue",
+        "nuxt": "nuxt",
+        "svelte": "svelte",
+        "@sveltejs/kit": "sveltekit",
+        "@angular/core": "angular",
+        "solid-js": "solid",
+        "astro": "astro",
+        "express": "express",
+        "hono": "hono",
+        "electron": "electron",
+    },
+    "rust": {
+        "axum": "axum",
+        "actix-web": "actix-web",
+        "rocket": "rocket",
+        "tokio": "tokio",
+        "leptos": "leptos",
+        "yew": "yew",
+        "tauri": "tauri",
+        "bevy": "bevy",
+        "clap": "clap",
+    },
+    "go": {
+        "github.com/gin-gonic/gin": "gin",
+        "github.com/labstack/echo/v4": "echo",
+        "github.com/gofiber/fiber/v2": "fiber",
+        "github.com/spf13/cobra": "cobra",
+    },
+}
+
+
+class Framework(BaseModel, frozen=True):
+    name: str
+    language: str
+    manifest: str = Field(description="Manifest declaring it, relative to root")
+
+
+class StackDetection(BaseModel, frozen=True):
+    """What a project is written in, as declared by its manifests."""
+
+    root: str
+    languages: list[str] = Field(
+        default_factory=list, description="Primary language first"
+    )
+    frameworks: list[Framework] = Field(default_factory=list)
+    manifests: list[str] = Field(default_factory=list)
+    container_files: list[str] = Field(default_factory=list)
+    directives: list[str] = Field(
+        default_factory=list,
+        description="Arguments for adapt that resolve to an installed directive",
+    )
+
+    def render_markdown(self) -> str:
+        lines = [f"# Stack: {self.root}", ""]
+        lines.append(f"**Languages:** {', '.join(self.languages) or 'none detected'}")
+        if self.frameworks:
+            names = ", ".join(f"{f.name} ({f.manifest})" for f in self.frameworks)
+            lines.append(f"**Frameworks:** {names}")
+        if self.container_files:
+            lines.append(f"**Containers:** {', '.join(self.container_files)}")
+        lines.append(f"**Manifests:** {', '.join(self.manifests) or 'none'}")
+        lines.append("")
+        lines.append(
+            f"Call adapt with {self.directives}."
+            if self.directives
+            else "No language directives installed; adapt loads core only."
+        )
+        return "\n".join(lines)
+
+
+def _manifest_files(root: Path) -> list[Path]:
+    wanted = {*MANIFEST_LANGUAGES, *CONTAINER_FILES}
+    ignore = IgnoreRules.for_root(root)
+    found: list[Path] = []
+    for dirpath, dirnames, filenames in os.walk(root):
+        here = Path(dirpath)
+        depth = len(here.relative_to(root).parts)
+        dirnames[:] = (
+            []
+            if depth >= _MAX_DEPTH
+            else sorted(
+                d
+                for d in dirnames
+                if d not in DEFAULT_SKIP_DIRS and not ignore.matches(here / d, True)
+            )
+        )
+        found += [here / n for n in sorted(filenames) if n in wanted]
+    return found
+
+
+def _is_typescript(directory: Path) -> bool:
+    if (directory / "tsconfig.json").is_file():
+        return True
+    try:
+        data = json.loads((directory / "package.json").read_text(encoding="utf-8"))
+    except (OSError, json.JSONDecodeError):
+        return False
+    deps = {**data.get("dependencies", {}), **data.get("devDependencies", {})}
+    return "typescript" in deps
+
+
+def _adapt_names(candidates: list[str]) -> list[str]:
+    available = set(list_directives())
+    return [
+        name
+        for name in dict.fromkeys(candidates)
+        if name in available or f"d-{name}" in available
+    ]
+
+
+def languages_of(paths: list[str]) -> list[str]:
+    """Languages of the source files among *paths*, most files first."""
+    counts = Counter(
+        SOURCE_LANGUAGES[suffix]
+        for path in paths
+        if (suffix := Path(path).suffix.lower()) in SOURCE_LANGUAGES
+    )
+    return sorted(counts, key=lambda lang: (-counts[lang], lang))
+
+
+def detect_stack(root: Path) -> StackDetection:
+    """Detect languages and frameworks of *root* from its manifests."""
+    root = root.resolve()
+    files = _manifest_files(root)
+    manifests = [p for p in files if p.name in MANIFEST_LANGUAGES]
+
+    # Root manifests outrank nested ones; then the language with more manifests.
+    weight: Counter[str] = Counter()
+    for path in manifests:
+        language = MANIFEST_LANGUAGES[path.name]
+        if language == "javascript" and _is_typescript(path.parent):
+            language = "typescript"
+        weight[language] += 100 if path.parent == root else 1
+    languages = sorted(weight, key=lambda lang: (-weight[lang], lang))
+
+    frameworks: dict[str, Framework] = {}
+    for directory in dict.fromkeys(p.parent for p in manifests):
+        rel = directory.relative_to(root)
+        for dep in declared_dependencies(directory):
+            name = _FRAMEWORKS.get(dep.ecosystem, {}).get(dep.name)
+            # No dev filter: JS app frameworks usually sit in devDependencies.
+            if name and name not in frameworks:
+                frameworks[name] = Framework(
+                    name=name,
+                    language=dep.ecosystem,
+                    manifest=(rel / dep.manifest).as_posix(),
+                )
+
+    return StackDetection(
+        root=str(root),
+        languages=languages,
+        frameworks=list(frameworks.values()),
+        manifests=[p.relative_to(root).as_posix() for p in manifests],
+        container_files=[
+            p.relative_to(root).as_posix() for p in files if p.name in CONTAINER_FILES
+        ],
+        directives=_adapt_names([*languages, *frameworks]),
+    )
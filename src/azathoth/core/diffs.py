@@ -0,0 +1,166 @@
+"""azathoth.core.diffs — size-aware summaries of large diffs.
+
+Public surface:
+  - ``split_diff(patch)``                                → ``[FilePatch]``
+  - ``summarize_diff(patch, paths, max_file_bytes, …)``  → ``DiffPage``
+
+A staged diff of a few hundred files does not fit a model's context, so
+``summarize_diff`` returns every changed file's status and line counts
+plus its hunk headers (ranges and function context), and the patch text
+only for files whose patch is at most ``max_file_bytes``.  A caller reads
+the rest selectively: files named in *paths* always carry their text, and
+``offset`` / ``limit`` page through the file list (``next_offset`` is the
+offset of the following page, ``None`` on the last one).  Input is a
+git-format diff, as every backend's ``diff`` returns.
+"""
+
+from __future__ import annotations
+
+import re
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.reviews import parse_hunks
+
+FileStatus = Literal["added", "modified", "removed", "renamed"]
+
+_HEADER = re.compile(r"^diff --git a/(.*) b/(.*)$")
+
+
+class FilePatch(BaseModel, frozen=True):
+    """One file's section of a git-format diff."""
+
+    path: str
+    previous_path: str | None = None
+    status: FileStatus = "modified"
+    binary: bool = False
+    text: str = Field(description="The section, from its diff --git line")
+
+
+class HunkSummary(BaseModel, frozen=True):
+    header: str = Field(description="Function context after the @@ range")
+    old_start: int
+    old_lines: int
+    new_start: int
+    new_lines: int
+    insertions: int = 0
+    deletions: int = 0
+
+
+class FileSummary(BaseModel, frozen=True):
+    path: str
+    previous_path: str | None = None
+    status: FileStatus = "modified"
+    binary: bool = False
+    insertions: int = 0
+    deletions: int = 0
+    size: int = Field(description="Bytes of this file's patch")
+    hunks: list[HunkSummary] = Field(default_factory=list)
+    patch: str | None = Field(
+        None, description="Full patch text; None when over the size threshold"
+    )
+
+
+class DiffPage(BaseModel, frozen=True):
+    files_changed: int
+    insertions: int = 0
+    deletions: int = 0
+    offset: int = 0
+    files: list[FileSummary] = Field(default_factory=list, description="This page")
+    next_offset: int | None = Field(None, description="None on the last page")
+
+
+def _file_patch(lines: list[str]) -> FilePatch:
+    match = _HEADER.match(lines[0])
+    old, new = match.groups() if match else ("", "")
+    status: FileStatus = "modified"
+    binary = False
+    for line in lines[1:]:
+        if line.startswith("@@"):
+            break
+        if line.startswith("new file mode"):
+            status = "added"
+        elif line.startswith("deleted file mode"):
+            status = "removed"
+        elif line.startswith("rename to "):
+            status, new = "renamed", line.removeprefix("rename to ")
+        elif line.startswith("rename from "):
+            old = line.removeprefix("rename from ")
+        elif line.startswith("Binary files") or line == "GIT binary patch":
+            binary = True
+    return FilePatch(
+        path=new,
+        previous_path=old if status == "renamed" else None,
+        status=status,
+        binary=binary,
+        text="\n".join(lines) + "\n",
+    )
+
+
+def split_diff(patch: str) -> list[FilePatch]:
+    """Split a git-format *patch* into one section per file, in order."""
+    sections: list[list[str]] = []
+    for line in patch.splitlines():
+        if line.startswith("diff --git "):
+            sections.append([line])
+        elif sections:
+            sections[-1].append(line)
+    return [_file_patch(lines) for lines in sections]
+
+
+def _summarize_file(
+    file: FilePatch, max_file_bytes: int, requested: bool
+) -> FileSummary:
+    hunks = []
+    for hunk in parse_hunks(file.text):
+        hunks.append(
+            HunkSummary(
+                header=hunk.header,
+                old_start=hunk.old_start,
+                old_lines=hunk.old_lines,
+                new_start=hunk.new_start,
+                new_lines=hunk.new_lines,
+                insertions=sum(line.startswith("+") for line in hunk.lines),
+                deletions=sum(line.startswith("-") for line in hunk.lines),
+            )
+        )
+    size = len(file.text.encode())
+    return FileSummary(
+        path=file.path,
+        previous_path=file.previous_path,
+        status=file.status,
+        binary=file.binary,
+        insertions=sum(h.insertions for h in hunks),
+        deletions=sum(h.deletions for h in hunks),
+        size=size,
+        hunks=hunks,
+        patch=file.text if requested or size <= max_file_bytes else None,
+    )
+
+
+def summarize_diff(
+    patch: str,
+    paths: list[str] | None = None,
+    max_file_bytes: int = 8000,
+    offset: int = 0,
+    limit: int = 50,
+) -> DiffPage:
+    """Summarize *patch* file by file, one page of *limit* files at a time.
+
+    With *paths*, only those files are listed, each with its full text.
+    """
+    files = split_diff(patch)
+    if paths:
+        wanted = set(paths)
+        files = [f for f in files if wanted & {f.path, f.previous_path}]
+    summaries = [_summarize_file(f, max_file_bytes, bool(paths)) for f in files]
+    end = offset + max(limit, 1)
+    return DiffPage(
+        files_changed=len(summaries),
+        insertions=sum(s.insertions for s in summaries),
+        deletions=sum(s.deletions for s in summaries),
+        offset=offset,
+        files=summaries[offset:end],
+        next_offset=end if end < len(summaries) else None,
+    )
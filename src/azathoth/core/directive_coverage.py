@@ -0,0 +1,162 @@
+"""azathoth.core.directive_coverage — which of a project's technologies have
+directives.
+
+Public surface:
+  - ``TechnologyCoverage``                   — one language or framework
+  - ``directive_coverage(root, stale_days)`` → ``CoverageReport``
+
+The technologies are what ``detect_stack`` finds in *root*: its languages
+and frameworks.  Each gets a status:
+
+  - ``covered`` — a directive of its own: ``<name>`` or ``d-<name>`` (as
+    ``adapt`` resolves it, repository overlays included), or one whose
+    ``applies_to`` lists it
+  - ``stale``   — covered, but the directive file was last changed more than
+    *stale_days* ago
+  - ``partial`` — a framework without a directive of its own whose language
+    has one, so only general guidance applies
+  - ``missing`` — no guidance beyond the core philosophy
+
+Technologies are ordered the way maintainers should work through them:
+missing first, then partial, then stale, primary language first within
+each.  Directive files that fail to load are listed in ``invalid`` (run
+``lint_directive`` on them) and count as absent.
+"""
+
+from __future__ import annotations
+
+import time
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.detect import detect_stack
+from azathoth.core.directives import (
+    SUFFIXES,
+    _find,
+    _is_variant,
+    find_project_directives,
+    list_directives,
+    load_directive,
+)
+from azathoth.core.exceptions import DirectiveError
+
+CoverageStatus = Literal["covered", "stale", "partial", "missing"]
+
+_ORDER = {"missing": 0, "partial": 1, "stale": 2, "covered": 3}
+
+
+class TechnologyCoverage(BaseModel, frozen=True):
+    name: str
+    kind: Literal["language", "framework"]
+    status: CoverageStatus
+    directive: str | None = Field(None, description="Directive giving guidance")
+    path: str | None = Field(None, description="File of that directive")
+    updated: str | None = Field(None, description="Last change, ISO date")
+    age_days: int | None = None
+
+
+class CoverageReport(BaseModel, frozen=True):
+    root: str
+    stale_days: int
+    technologies: list[TechnologyCoverage] = Field(default_factory=list)
+    invalid: list[str] = Field(default_factory=list)
+
+    def render_markdown(self) -> str:
+        lines = [f"# Directive coverage: {self.root}", ""]
+        if not self.technologies:
+            lines.append("No languages or frameworks detected.")
+        for tech in self.technologies:
+            detail = f" — {tech.directive}" if tech.directive else ""
+            if tech.age_days is not None and tech.status != "partial":
+                detail += f" (updated {tech.updated}, {tech.age_days} days ago)"
+            lines.append(f"- **{tech.name}** ({tech.kind}): {tech.status}{detail}")
+        if self.invalid:
+            lines += ["", f"Invalid directives: {', '.join(self.invalid)}."]
+        return "\n".join(lines)
+
+
+def _age(path: Path) -> tuple[str, int]:
+    mtime = path.stat().st_mtime
+    updated = datetime.fromtimestamp(mtime, timezone.utc).date().isoformat()
+    return updated, max(int((time.time() - mtime) // 86400), 0)
+
+
+async def directive_coverage(root: Path, stale_days: int = 180) -> CoverageReport:
+    """Guidance coverage of the technologies detected in *root*."""
+    stack = detect_stack(root)
+    project = find_project_directives(root)
+    directories = [project] if project else ()
+
+    def path_of(name: str) -> Path | None:
+        # With a repository overlay, the newer of the two files counts.
+        paths = [p for p in (_find(name), _find(name, None, directories)) if p]
+        return max(paths, key=lambda p: p.stat().st_mtime, default=None)
+
+    # Directive name → technologies its ``applies_to`` names.
+    invalid: list[str] = []
+    applies: dict[str, set[str]] = {}
+    names = set(list_directives())
+    if project:
+        names |= {
+            p.stem
+            for p in project.iterdir()
+            if p.suffix in SUFFIXES and not _is_variant(p.stem)
+        }
+    for name in sorted(names):
+        try:
+            directive = await load_directive(name, project=project)
+        except DirectiveError:
+            invalid.append(name)
+            continue
+        if directive is not None:
+            applies[name] = {a.lower() for a in directive.meta.applies_to} - {"*"}
+
+    def own(tech: str) -> str | None:
+        for name in (tech, f"d-{tech}"):
+            if name in applies:
+                return name
+        return next((n for n, techs in applies.items() if tech in techs), None)
+
+    entries: list[TechnologyCoverage] = []
+
+    def add(tech: str, kind: str, directive: str | None, status: str) -> None:
+        path = path_of(directive) if directive else None
+        updated, age = _age(path) if path else (None, None)
+        if status == "covered" and age is not None and age > stale_days:
+            status = "stale"
+        entries.append(
+            TechnologyCoverage(
+                name=tech,
+                kind=kind,
+                status=status,
+                directive=directive,
+                path=str(path) if path else None,
+                updated=updated,
+                age_days=age,
+            )
+        )
+
+    for language in stack.languages:
+        directive = own(language)
+        add(language, "language", directive, "covered" if directive else "missing")
+    for framework in stack.frameworks:
+        if directive := own(framework.name):
+            add(framework.name, "framework", directive, "covered")
+            continue
+        parents = [framework.language]
+        if framework.language == "javascript" and "typescript" in stack.languages:
+            parents.insert(0, "typescript")
+        parent = next((d for p in parents if (d := own(p))), None)
+        add(framework.name, "framework", parent, "partial" if parent else "missing")
+
+    # Stable: within a status, detection order (primary language first).
+    entries.sort(key=lambda e: (_ORDER[e.status], e.kind != "language"))
+    return CoverageReport(
+        root=stack.root,
+        stale_days=stale_days,
+        technologies=entries,
+        invalid=invalid,
+    )
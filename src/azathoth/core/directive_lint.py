@@ -0,0 +1,345 @@
+"""azathoth.core.directive_lint — checks for user-authored directive files.
+
+Public surface:
+  - ``DirectiveDiagnostic``             — one finding, with code and line
+  - ``lint_directive_file(path)``       → ``DirectiveLint`` for one file
+  - ``lint_directives(target)``         → ``DirectiveLintReport`` for a file, a
+    directory of directives or a directive name
+
+Codes, errors first:
+  - ``front-matter``   — unparseable front matter or TOML, or a field of the
+    wrong type (``priority: high``)
+  - ``unknown-extends`` — ``extends`` names no directive, built-in, user or
+    beside the file; extending itself counts too
+  - ``broken-link``    — ``[…](#anchor)`` without that heading, a relative
+    link to a missing file, or ``directive://name`` for no directive
+  - ``unknown-key``    — a front-matter key or TOML table ``adapt`` ignores
+    (warning; usually a typo)
+  - ``unknown-section`` — a ``section_priority`` heading the file lacks
+    (warning: the priority would never apply)
+  - ``duplicate-heading`` — the same heading twice at one level (warning:
+    anchors become ambiguous and merging keeps only one)
+  - ``oversized-section`` — a section over *max_section_tokens* (warning:
+    budget trimming drops whole sections, so big ones trim badly)
+
+Headings and links inside fenced code blocks are ignored.
+"""
+
+from __future__ import annotations
+
+import re
+import tomllib
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field, ValidationError
+
+from azathoth.core.directives import (
+    SUFFIXES,
+    Directive,
+    DirectiveMeta,
+    _find,
+    _is_variant,
+    list_directives,
+    parse_front_matter,
+)
+from azathoth.core.exceptions import DirectiveError
+from azathoth.core.utils import estimate_tokens
+
+MAX_SECTION_TOKENS = 1500
+
+_META_KEYS = frozenset(DirectiveMeta.model_fields) | {"mode"}
+_TOML_TABLES = frozenset(Directive.model_fields)
+_FENCE = re.compile(r"^\s*(```|~~~)")
+_HEADING = re.compile(r"^(#{1,6})\s+(.*?)\s*#*\s*$")
+_LINK = re.compile(r"\[[^\]]*\]\(\s*<?([^)\s>]+)>?(?:\s+\"[^\"]*\")?\s*\)")
+
+
+class DirectiveDiagnostic(BaseModel, frozen=True):
+    severity: Literal["error", "warning"]
+    code: str
+    message: str
+    line: int | None = Field(None, description="1-based, in the whole file")
+
+
+class DirectiveLint(BaseModel, frozen=True):
+    path: str
+    name: str
+    diagnostics: list[DirectiveDiagnostic] = Field(default_factory=list)
+
+
+class DirectiveLintReport(BaseModel, frozen=True):
+    files: list[DirectiveLint] = Field(default_factory=list)
+    errors: int = 0
+    warnings: int = 0
+
+
+def _slug(heading: str) -> str:
+    """GitHub-style anchor of *heading*."""
+    text = re.sub(r"[`*_~]|\[([^\]]*)\]\([^)]*\)", r"\1", heading).strip().lower()
+    return re.sub(r"\s", "-", re.sub(r"[^\w\s-]", "", text))
+
+
+def _markdown_lines(body: str, offset: int) -> list[tuple[int, str]]:
+    """``(line number, text)`` of *body* outside fenced code blocks."""
+    lines, fenced = [], False
+    for number, line in enumerate(body.splitlines(), offset + 1):
+        if _FENCE.match(line):
+            fenced = not fenced
+        elif not fenced:
+            lines.append((number, line))
+    return lines
+
+
+def _key_line(text: str, key: str) -> int | None:
+    """Line of the first ``key:``/``key =`` (or ``[key]`` table) in *text*."""
+    pattern = re.compile(rf"^(?:{re.escape(key)}\s*[:=]|\[{re.escape(key)}\])")
+    for number, line in enumerate(text.splitlines(), 1):
+        if pattern.match(line.strip()):
+            return number
+    return None
+
+
+def _base_name(stem: str) -> str:
+    return stem.rpartition(".")[0] if _is_variant(stem) else stem
+
+
+def _known_beside(directory: Path) -> set[str]:
+    """Directive names available to files in *directory*."""
+    return set(list_directives()) | {
+        _base_name(p.stem) for p in _directive_files(directory)
+    }
+
+
+class _Linter:
+    def __init__(self, path: Path, known: set[str], max_section_tokens: int):
+        self.path = path
+        self.name = _base_name(path.stem)
+        self.known = known
+        self.max_section_tokens = max_section_tokens
+        self.found: list[DirectiveDiagnostic] = []
+
+    def add(self, severity: str, code: str, message: str, line: int | None) -> None:
+        self.found.append(
+            DirectiveDiagnostic(
+                severity=severity, code=code, message=message, line=line
+            )
+        )
+
+    def run(self, text: str) -> str:
+        """Lint *text*; returns the directive's name."""
+        name = self.name
+        if self.path.suffix == ".toml":
+            meta, body, offset = self._toml(text)
+        else:
+            meta, body, offset = self._markdown(text)
+        if meta is None:
+            return name
+        for parent in meta.extends:
+            if parent == name or parent not in self.known:
+                what = "itself" if parent == name else f"unknown directive '{parent}'"
+                self.add(
+                    "error",
+                    "unknown-extends",
+                    f"extends {what}.",
+                    _key_line(text, "extends"),
+                )
+        self._body(body, offset, meta, text)
+        return meta.name or name
+
+    def _validated(self, data: dict, text: str) -> DirectiveMeta | None:
+        try:
+            return DirectiveMeta.model_validate(data)
+        except ValidationError as exc:
+            for error in exc.errors():
+                field = str(error["loc"][0]) if error["loc"] else "front matter"
+                self.add(
+                    "error",
+                    "front-matter",
+                    f"'{field}': {error['msg']}.",
+                    _key_line(text, field),
+                )
+            return None
+
+    def _markdown(self, text: str) -> tuple[DirectiveMeta | None, str, int]:
+        try:
+            data, body = parse_front_matter(text)
+        except DirectiveError as exc:
+            self.add("error", "front-matter", str(exc), 1)
+            return None, "", 0
+        for key in data:
+            if key not in _META_KEYS:
+                self.add(
+                    "warning",
+                    "unknown-key",
+                    f"Front matter key '{key}' is not used by directives.",
+                    _key_line(text, key),
+                )
+        fields = {k: v for k, v in data.items() if k in DirectiveMeta.model_fields}
+        for key in ("applies_to", "extends", "tags"):
+            if isinstance(fields.get(key), str):
+                fields[key] = [fields[key]]
+        fields.setdefault("name", self.name)
+        if fields.get("section_priority") == []:
+            fields["section_priority"] = {}
+        offset = len(text.splitlines()) - len(body.splitlines())
+        return self._validated(fields, text), body, offset
+
+    def _toml(self, text: str) -> tuple[DirectiveMeta | None, str, int]:
+        try:
+            data = tomllib.loads(text)
+        except tomllib.TOMLDecodeError as exc:
+            self.add("error", "front-matter", f"Invalid TOML: {exc}.", None)
+            return None, "", 0
+        for key in data:
+            if key not in _TOML_TABLES:
+                self.add(
+                    "warning",
+                    "unknown-key",
+                    f"Table '{key}' is not used by directives.",
+                    _key_line(text, key),
+                )
+        meta = data.get("meta")
+        if not isinstance(meta, dict):
+            self.add("error", "front-matter", "Missing [meta] table.", None)
+            return None, "", 0
+        body = data.get("body") or ""
+        offset = 0
+        if body and (start := text.find(body.splitlines()[0])) >= 0:
+            offset = text.count("\n", 0, start)
+        return self._validated(meta, text), body, offset
+
+    def _body(self, body: str, offset: int, meta: DirectiveMeta, text: str) -> None:
+        lines = _markdown_lines(body, offset)
+        headings = []  # (line, level, title)
+        for number, line in lines:
+            if match := _HEADING.match(line):
+                headings.append((number, len(match.group(1)), match.group(2)))
+
+        seen: dict[tuple[int, str], int] = {}
+        for number, level, title in headings:
+            key = (level, " ".join(title.split()).lower())
+            if key in seen:
+                self.add(
+                    "warning",
+                    "duplicate-heading",
+                    f"Heading '{title}' repeats the one on line {seen[key]}.",
+                    number,
+                )
+            else:
+                seen[key] = number
+
+        titles = {" ".join(t.split()).lower() for _, _, t in headings}
+        for heading in meta.section_priority:
+            if " ".join(heading.split()).lower() not in titles:
+                self.add(
+                    "warning",
+                    "unknown-section",
+                    f"section_priority names '{heading}', which no heading has.",
+                    _key_line(text, heading),
+                )
+
+        anchors = {_slug(t) for _, _, t in headings}
+        for number, line in lines:
+            for target in _LINK.findall(line):
+                problem = self._link_problem(target, anchors)
+                if problem:
+                    self.add("error", "broken-link", problem, number)
+
+        self._sizes(lines, headings)
+
+    def _link_problem(self, target: str, anchors: set[str]) -> str | None:
+        if target.startswith("#"):
+            if target[1:].lower() not in anchors:
+                return f"No heading for anchor '{target}'."
+            return None
+        if target.startswith("directive://"):
+            name = target.removeprefix("directive://")
+            return None if name in self.known else f"Unknown directive '{name}'."
+        if re.match(r"^[a-z][a-z0-9+.-]*:", target, re.IGNORECASE):
+            return None  # http:, mailto:, …
+        relative = target.split("#", 1)[0]
+        if relative and not (self.path.parent / relative).exists():
+            return f"Link target '{relative}' does not exist."
+        return None
+
+    def _sizes(self, lines: list[tuple[int, str]], headings: list) -> None:
+        # Same units as budget trimming: the text under each #/## heading.
+        starts = [(n, t) for n, level, t in headings if level <= 2]
+        for index, (number, title) in enumerate(starts):
+            end = starts[index + 1][0] if index + 1 < len(starts) else None
+            text = "\n".join(
+                line for n, line in lines if n >= number and (end is None or n < end)
+            )
+            tokens = estimate_tokens(text)
+            if tokens > self.max_section_tokens:
+                self.add(
+                    "warning",
+                    "oversized-section",
+                    f"Section '{title}' is ~{tokens} tokens (limit "
+                    f"{self.max_section_tokens}); split it or give it a "
+                    "section_priority so trimming can drop it.",
+                    number,
+                )
+
+
+def _directive_files(directory: Path) -> list[Path]:
+    return sorted(p for s in SUFFIXES for p in directory.glob(f"*{s}") if p.is_file())
+
+
+def lint_directive_file(
+    path: Path,
+    known: set[str] | None = None,
+    max_section_tokens: int = MAX_SECTION_TOKENS,
+) -> DirectiveLint:
+    """Diagnostics for the directive at *path*.
+
+    *known* is the set of directive names ``extends`` and ``directive://``
+    links may use (default: every available directive plus the files beside
+    *path*).
+
+    Raises:
+        DirectiveError: If *path* cannot be read.
+    """
+    if known is None:
+        known = _known_beside(path.parent)
+    try:
+        text = path.read_text(encoding="utf-8")
+    except (OSError, UnicodeDecodeError) as exc:
+        raise DirectiveError(f"Cannot read directive {path}: {exc}") from exc
+    linter = _Linter(path, known, max_section_tokens)
+    name = linter.run(text)
+    diagnostics = sorted(
+        linter.found,
+        key=lambda d: (d.severity != "error", d.line or 0, d.code),
+    )
+    return DirectiveLint(path=str(path), name=name, diagnostics=diagnostics)
+
+
+def lint_directives(
+    target: str | Path, max_section_tokens: int = MAX_SECTION_TOKENS
+) -> DirectiveLintReport:
+    """Lint a directive file, every directive file in a directory, or the
+    directive *target* names (user override first, as ``adapt`` loads it).
+
+    Raises:
+        DirectiveError: If *target* is none of these, or a file is unreadable.
+    """
+    path = Path(target)
+    if path.is_dir():
+        paths = _directive_files(path)
+    elif path.is_file():
+        paths = [path]
+    elif (found := _find(str(target))) is not None:
+        paths = [found]
+    else:
+        raise DirectiveError(f"No directive file, directory or name '{target}'.")
+
+    known = _known_beside(paths[0].parent) if paths else set()
+    files = [lint_directive_file(p, known, max_section_tokens) for p in paths]
+    severities = [d.severity for f in files for d in f.diagnostics]
+    return DirectiveLintReport(
+        files=files,
+        errors=severities.count("error"),
+        warnings=severities.count("warning"),
+    )
@@ -1,31 +1,75 @@
+import re
 import tomllib
 from pathlib import Path
-from typing import Dict, List, Optional
-from pydantic import BaseModel
+from typing import Callable, Dict, Iterable, List, Literal, Optional, Tuple
+from pydantic import BaseModel, Field, ValidationError
 from azathoth.config import get_config
+from azathoth.core.exceptions import DirectiveError
+from azathoth.core.repo_config import find_repo_root
+from azathoth.core.utils import estimate_tokens
 
-config = get_config()
+BUILTIN_DIR = Path(__file__).parent.parent / "directives"
+# Per-project core philosophy, found from the working directory up to the
+# repo root.  Front matter ``mode: extend`` (default) merges it after the core
+# directive; ``mode: override`` replaces the core directive outright.
+PROJECT_CORE = Path(".azathoth") / "core-philosophy.md"
+PROJECT_CORE_MODES = ("extend", "override")
+# Per-repository directive overlays, found the same way.  A file named like a
+# global directive is layered over it (its sections replace same-titled ones,
+# its rules win); any other file is a directive of its own.
+PROJECT_DIRECTIVES = Path(".azathoth") / "directives"
+
+# Directive files are either TOML ([meta] + [rules]) or Markdown with an
+# optional ``---`` front-matter block; TOML wins when both exist.
+SUFFIXES = (".toml", ".md")
+
+# Localized variants sit next to the default file as ``<name>.<locale>.md``
+# (``d-rust.es.md``, ``core.pt-br.toml``); the locale is a BCP 47-ish tag.
+_LOCALE = re.compile(r"^[a-z]{2,3}(?:-[a-z0-9]{2,8})*$")
+
+_FRONT_MATTER_KEY = re.compile(r"^([A-Za-z_][\w-]*)\s*:\s*(.*)$")
+_HEADING = re.compile(r"^#{1,6}\s")
+# Budget trimming drops whole top- and second-level sections.
+_TRIM_HEADING = re.compile(r"^(#{1,2})\s+(.*?)\s*$")
+
+# What ``adapt`` returns: the Markdown document, the same as plain text, or
+# the Markdown plus its sections as data for clients assembling prompts.
+OutputFormat = Literal["markdown", "plain", "json"]
+OUTPUT_FORMATS = ("markdown", "plain", "json")
 
 
 class DirectiveMeta(BaseModel):
     name: str
-    version: str
-    applies_to: List[str]
+    version: str = ""
+    applies_to: List[str] = Field(default_factory=list)
+    # Directives (by file name) whose content this one builds on.
+    extends: List[str] = Field(default_factory=list)
+    tags: List[str] = Field(default_factory=list)
+    # Among directives not ordered by ``extends``, higher priority is merged
+    # later and wins conflicting rules.
+    priority: int = 0
+    # Section heading → priority when ``adapt`` must trim to a budget: lower
+    # priorities are dropped first; unlisted sections count as 0.
+    section_priority: Dict[str, int] = Field(default_factory=dict)
 
 
 class Directive(BaseModel):
     meta: DirectiveMeta
-    rules: Dict[str, str]
+    rules: Dict[str, str] = Field(default_factory=dict)
     examples: Optional[Dict[str, List[str]]] = None
+    # Markdown directives carry their content verbatim instead of rules.
+    body: Optional[str] = None
 
     def render(self) -> str:
         """Renders the directive as a markdown string for the LLM."""
-        lines = [f"# Directive: {self.meta.name} (v{self.meta.version})", ""]
+        version = f" (v{self.meta.version})" if self.meta.version else ""
+        lines = [f"# Directive: {self.meta.name}{version}", ""]
 
-        lines.append("## Rules")
-        for key, value in self.rules.items():
-            lines.append(f"- **{key}**: {value}")
-        lines.append("")
+        if self.rules:
+            lines.append("## Rules")
+            for key, value in self.rules.items():
+                lines.append(f"- **{key}**: {value}")
+            lines.append("")
 
         if self.examples:
             lines.append("## Examples")
@@ -35,47 +79,726 @@ class Directive(BaseModel):
                     lines.append(ex)
                     lines.append("")
 
+        if self.body:
+            lines.append(self.body.strip())
+            lines.append("")
+
         return "\n".join(lines)
 
 
-async def load_directive(name: str) -> Optional[Directive]:
+class DirectiveSection(BaseModel):
+    """One ``#``/``##`` section of the merged document (``json`` format)."""
+
+    directive: str
+    title: str
+    # ``section_priority`` of the section in its directive (0 if unlisted).
+    priority: int = 0
+    content: str
+
+
+class DirectiveContext(BaseModel):
+    """What ``adapt`` returns: the merged document and how it was built."""
+
+    # Directive file names in merge order, parents first.
+    directives: List[str] = Field(default_factory=list)
+    content: str
+    estimated_tokens: int = 0
+    # Sections dropped to fit the budget, as "<directive> › <heading>".
+    omitted: List[str] = Field(default_factory=list)
+    # Locale requested (argument or ``directive_locale``), normalized.
+    locale: Optional[str] = None
+    # Directives loaded in the default language for lack of a translation.
+    untranslated: List[str] = Field(default_factory=list)
+    # Directives with a repository overlay from ``.azathoth/directives/``.
+    overlaid: List[str] = Field(default_factory=list)
+    format: OutputFormat = "markdown"
+    # The document's sections in order; only filled in the ``json`` format.
+    sections: Optional[List[DirectiveSection]] = None
+
+
+# ── Parsing ──────────────────────────────────────────────────────────────
+
+
+def _scalar(value: str) -> str:
+    value = value.strip()
+    if len(value) >= 2 and value[0] == value[-1] and value[0] in "'\"":
+        return value[1:-1]
+    return value
+
+
+def parse_front_matter(text: str) -> Tuple[Dict[str, object], str]:
     """
-    Loads a directive by name, searching built-ins first then user overrides.
+    Splits ``---`` front matter from a Markdown document.
+
+    Only the YAML subset directives need is understood: ``key: value``,
+    inline lists (``key: [a, b]``), block lists (``- item`` lines) and one
+    level of indented ``key: value`` mapping under an empty key.
     """
-    # 1. Check user overrides first (as per guide, user wins)
-    user_path = config.directives_dir / f"{name}.toml"
+    lines = text.splitlines()
+    if not lines or lines[0].strip() != "---":
+        return {}, text
+    try:
+        end = next(i for i, line in enumerate(lines[1:], 1) if line.strip() == "---")
+    except StopIteration:
+        raise DirectiveError("Unterminated front matter (missing closing '---').")
+
+    data: Dict[str, object] = {}
+    key: Optional[str] = None
+    for number, raw in enumerate(lines[1:end], 2):
+        line = raw.split(" #")[0].rstrip()
+        if not line.strip() or line.lstrip().startswith("#"):
+            continue
+        stripped = line.strip()
+        if stripped.startswith("- ") and key is not None:
+            items = data.setdefault(key, [])
+            if not isinstance(items, list):
+                raise DirectiveError(
+                    f"Front matter line {number}: '{key}' is not a list."
+                )
+            items.append(_scalar(stripped[2:]))
+            continue
+        match = _FRONT_MATTER_KEY.match(stripped)
+        if not match:
+            raise DirectiveError(
+                f"Front matter line {number}: cannot parse '{stripped}'."
+            )
+        if line[0].isspace() and key is not None:
+            mapping = data[key]
+            if mapping == []:
+                mapping = data[key] = {}
+            if isinstance(mapping, dict):
+                mapping[match.group(1)] = _scalar(match.group(2))
+                continue
+        key, value = match.group(1), match.group(2).strip()
+        if value.startswith("[") and value.endswith("]"):
+            data[key] = [_scalar(v) for v in value[1:-1].split(",") if v.strip()]
+        elif value:
+            data[key] = _scalar(value)
+        else:
+            data[key] = []
+    return data, "\n".join(lines[end + 1 :])
+
+
+def _as_list(value: object) -> List[str]:
+    if value is None:
+        return []
+    return list(value) if isinstance(value, list) else [str(value)]
 
-    # 2. Check built-ins (we'll look in a relative 'directives/' folder in the package)
-    builtin_path = Path(__file__).parent.parent / "directives" / f"{name}.toml"
 
-    target_path = None
-    if user_path.exists():
-        target_path = user_path
-    elif builtin_path.exists():
-        target_path = builtin_path
+def _markdown_directive(path: Path) -> Directive:
+    meta, body = parse_front_matter(path.read_text(encoding="utf-8"))
+    stem = path.stem.rpartition(".")[0] if _is_variant(path.stem) else path.stem
+    return Directive(
+        meta=DirectiveMeta(
+            name=str(meta.get("name", stem)),
+            version=str(meta.get("version", "")),
+            applies_to=_as_list(meta.get("applies_to")),
+            extends=_as_list(meta.get("extends")),
+            tags=_as_list(meta.get("tags")),
+            priority=meta.get("priority", 0),
+            section_priority=meta.get("section_priority") or {},
+        ),
+        body=body,
+    )
 
-    if not target_path:
+
+def normalize_locale(locale: Optional[str]) -> Optional[str]:
+    """*locale* as variant files spell it (``pt_BR`` → ``pt-br``), or ``None``.
+
+    Raises:
+        DirectiveError: If *locale* is not a language tag.
+    """
+    if not locale:
         return None
+    tag = locale.strip().replace("_", "-").lower()
+    if not _LOCALE.match(tag):
+        raise DirectiveError(
+            f"Invalid locale '{locale}' (expected a tag like 'es' or 'pt-BR')."
+        )
+    return tag
+
+
+def _fallbacks(locale: Optional[str]) -> List[str]:
+    """Variant infixes to try for *locale*, most specific first, then none."""
+    tag = normalize_locale(locale)
+    if tag is None:
+        return [""]
+    parts = tag.split("-")
+    return [f".{'-'.join(parts[:n])}" for n in range(len(parts), 0, -1)] + [""]
+
+
+def _nearest(start: Optional[Path], relative: Path) -> Optional[Path]:
+    """*relative* under *start* (default: cwd) or its nearest parent having
+    it, up to the repository root."""
+    here = Path(start or ".").resolve()
+    root = find_repo_root(here)
+    for candidate in (here, *here.parents):
+        if (candidate / relative).exists():
+            return candidate / relative
+        if candidate == root:
+            break
+    return None
+
+
+def find_project_directives(start: Optional[Path] = None) -> Optional[Path]:
+    """Nearest ``.azathoth/directives/`` from *start* (default: cwd) up to the
+    repository root, or ``None``."""
+    found = _nearest(start, PROJECT_DIRECTIVES)
+    return found if found is not None and found.is_dir() else None
+
+
+def find_project_core(
+    start: Optional[Path] = None, locale: Optional[str] = None
+) -> Optional[Path]:
+    """Nearest ``.azathoth/core-philosophy.md`` from *start* (default: cwd) up
+    to the repository root, or ``None``.  A ``core-philosophy.<locale>.md``
+    next to it is preferred when *locale* is given."""
+    here = Path(start or ".").resolve()
+    root = find_repo_root(here)
+    for candidate in (here, *here.parents):
+        default = candidate / PROJECT_CORE
+        for infix in _fallbacks(locale):
+            path = default.with_name(f"{default.stem}{infix}{default.suffix}")
+            if path.is_file():
+                return path
+        if candidate == root:
+            break
+    return None
+
+
+def load_project_core(path: Path) -> Tuple[Directive, str]:
+    """
+    Loads a project core philosophy file and its merge mode.
+
+    Raises:
+        DirectiveError: If the file is malformed or its ``mode`` is unknown.
+    """
+    try:
+        meta, _ = parse_front_matter(path.read_text(encoding="utf-8"))
+        directive = _markdown_directive(path)
+    except (OSError, ValidationError, DirectiveError) as exc:
+        raise DirectiveError(f"Invalid project core philosophy {path}: {exc}") from exc
+    mode = str(meta.get("mode", "extend"))
+    if mode not in PROJECT_CORE_MODES:
+        raise DirectiveError(
+            f"{path}: mode must be one of {', '.join(PROJECT_CORE_MODES)}, "
+            f"not '{mode}'."
+        )
+    if "name" not in meta:
+        named = directive.meta.model_copy(update={"name": "Project Core Philosophy"})
+        directive = directive.model_copy(update={"meta": named})
+    return directive, mode
+
+
+def _find(
+    name: str,
+    locale: Optional[str] = None,
+    directories: Optional[Iterable[Path]] = None,
+) -> Optional[Path]:
+    if "/" in name or "\\" in name or ".." in name:
+        return None  # a directive name, never a path out of its directory
+    # User overrides win over built-ins (as per guide, user wins), even over
+    # a built-in translation: a customized directive is never swapped for a
+    # translated stock one.
+    if directories is None:
+        directories = (get_config().directives_dir, BUILTIN_DIR)
+    for directory in directories:
+        for infix in _fallbacks(locale):
+            for suffix in SUFFIXES:
+                path = directory / f"{name}{infix}{suffix}"
+                if path.exists():
+                    return path
+    return None
+
+
+def _is_variant(stem: str) -> bool:
+    _, dot, tag = stem.rpartition(".")
+    return bool(dot) and bool(_LOCALE.match(tag))
+
+
+def _load_file(target_path: Path) -> Directive:
+    try:
+        if target_path.suffix == ".md":
+            return _markdown_directive(target_path)
+        with open(target_path, "rb") as f:
+            data = tomllib.load(f)
+            return Directive(**data)
+    except (tomllib.TOMLDecodeError, ValidationError, DirectiveError) as exc:
+        raise DirectiveError(f"Invalid directive {target_path.name}: {exc}") from exc
+
+
+def overlay(base: Directive, layer: Directive) -> Directive:
+    """
+    *base* with a repository *layer* on top.
+
+    The layer's rules and examples win per key; each of its Markdown sections
+    replaces the base section with the same heading (case and spacing
+    ignored) in place, or is appended.  ``extends`` and ``tags`` are
+    combined and ``section_priority`` entries of the layer win; the base's
+    name, version and priority are kept.
+    """
+
+    def title(section: str) -> str:
+        first = section.splitlines()[0]
+        return _normalized(first) if _HEADING.match(first) else ""
+
+    sections = {title(s): s for s in _sections(base.body or "")}
+    for section in _sections(layer.body or ""):
+        sections[title(section)] = section
+    examples = {**(base.examples or {}), **(layer.examples or {})}
+    meta = base.meta.model_copy(
+        update={
+            "extends": list(dict.fromkeys(base.meta.extends + layer.meta.extends)),
+            "tags": list(dict.fromkeys(base.meta.tags + layer.meta.tags)),
+            "section_priority": {
+                **base.meta.section_priority,
+                **layer.meta.section_priority,
+            },
+        }
+    )
+    return Directive(
+        meta=meta,
+        rules={**base.rules, **layer.rules},
+        examples=examples or None,
+        body="\n\n".join(sections.values()) or None,
+    )
+
+
+async def load_directive(
+    name: str, locale: Optional[str] = None, project: Optional[Path] = None
+) -> Optional[Directive]:
+    """
+    Loads a directive by name, searching user overrides first then built-ins.
+
+    With *locale*, the ``<name>.<locale>`` variant is preferred in each
+    directory, falling back to the language alone and then the default.
+    With *project* (a repository's ``.azathoth/directives``), a file of that
+    name there is layered over the global directive by ``overlay``, or
+    stands alone when there is none.
+
+    Raises:
+        DirectiveError: If a file exists but is malformed, or *locale* is
+            not a language tag.
+    """
+    target_path = _find(name, locale)
+    local_path = _find(name, locale, [project]) if project else None
+    if local_path is None:
+        return _load_file(target_path) if target_path else None
+    local = _load_file(local_path)
+    return overlay(_load_file(target_path), local) if target_path else local
+
+
+def list_directives() -> List[str]:
+    """
+    Names of all available directives (built-ins plus user overrides), sorted.
+
+    Localized variants are not listed separately; see ``list_locales``.
+    """
+    names = set()
+    for directory in (BUILTIN_DIR, get_config().directives_dir):
+        for suffix in SUFFIXES:
+            names |= {p.stem for p in directory.glob(f"*{suffix}")}
+    return sorted(n for n in names if not _is_variant(n))
+
 
-    with open(target_path, "rb") as f:
-        data = tomllib.load(f)
-        return Directive(**data)
+def list_locales(name: str) -> List[str]:
+    """Locales *name* has a translation for (built-in or user), sorted."""
+    locales = set()
+    for directory in (BUILTIN_DIR, get_config().directives_dir):
+        for suffix in SUFFIXES:
+            for path in directory.glob(f"{name}.*{suffix}"):
+                base, _, tag = path.stem.rpartition(".")
+                if base == name and _LOCALE.match(tag):
+                    locales.add(tag)
+    return sorted(locales)
 
 
-async def get_master_context(languages: List[str]) -> str:
+# ── Composition ──────────────────────────────────────────────────────────
+
+
+async def resolve_directives(
+    names: List[str], locale: Optional[str] = None, project: Optional[Path] = None
+) -> List[Tuple[str, Directive]]:
+    """
+    Loads *names* and everything they extend, ordered for merging.
+
+    Every directive comes after the ones it extends; otherwise lower
+    ``priority`` first, then name, so the result is deterministic.  Each
+    is loaded in *locale* where a translation exists and with the *project*
+    overlays applied (see ``load_directive``); ``extends`` always names the
+    default file.
+
+    Raises:
+        DirectiveError: On an unknown ``extends`` target or a cycle.
+    """
+    loaded: Dict[str, Directive] = {}
+
+    async def visit(name: str, chain: List[str]) -> None:
+        if name in chain:
+            cycle = " → ".join([*chain[chain.index(name) :], name])
+            raise DirectiveError(f"Directive cycle: {cycle}")
+        if name in loaded:
+            return
+        directive = await load_directive(name, locale, project)
+        if directive is None:
+            origin = f"'{chain[-1]}' extends unknown" if chain else "Unknown"
+            raise DirectiveError(f"{origin} directive '{name}'.")
+        loaded[name] = directive
+        for parent in directive.meta.extends:
+            await visit(parent, [*chain, name])
+
+    for name in names:
+        if name not in loaded:
+            await visit(name, [])
+
+    ordered: List[Tuple[str, Directive]] = []
+    done: set = set()
+    while len(ordered) < len(loaded):
+        ready = [
+            (d.meta.priority, n)
+            for n, d in loaded.items()
+            if n not in done and all(p in done for p in d.meta.extends)
+        ]
+        _, name = min(ready)
+        done.add(name)
+        ordered.append((name, loaded[name]))
+    return ordered
+
+
+def _sections(body: str) -> List[str]:
+    """Splits Markdown into heading-led sections (the preamble is one too)."""
+    sections: List[List[str]] = [[]]
+    for line in body.strip().splitlines():
+        if _HEADING.match(line) and sections[-1]:
+            sections.append([])
+        sections[-1].append(line)
+    return ["\n".join(s).strip() for s in sections if "".join(s).strip()]
+
+
+def _normalized(text: str) -> str:
+    return " ".join(text.split()).lower()
+
+
+def _merge(directives: List[Directive]) -> List[Tuple[int, Directive]]:
+    last_owner = {key: i for i, d in enumerate(directives) for key in d.rules}
+    seen_sections: set = set()
+    merged = []
+    for i, directive in enumerate(directives):
+        rules = {k: v for k, v in directive.rules.items() if last_owner[k] == i}
+        body = None
+        if directive.body:
+            kept = []
+            for section in _sections(directive.body):
+                if _normalized(section) not in seen_sections:
+                    seen_sections.add(_normalized(section))
+                    kept.append(section)
+            body = "\n\n".join(kept) or None
+        if not rules and not body and not directive.examples:
+            continue
+        merged.append((i, directive.model_copy(update={"rules": rules, "body": body})))
+    return merged
+
+
+def _trim_units(text: str) -> List[Tuple[str, str]]:
+    """Splits rendered Markdown into ``(heading, text)`` units at ``#``/``##``."""
+    units: List[Tuple[str, List[str]]] = [("", [])]
+    for line in text.strip().splitlines():
+        match = _TRIM_HEADING.match(line)
+        if match and units[-1][1]:
+            units.append((match.group(2), []))
+        elif match:
+            units[-1] = (match.group(2), units[-1][1])
+        units[-1][1].append(line)
+    return [(h, "\n".join(lines).strip()) for h, lines in units]
+
+
+def compose(
+    directives: List[Directive],
+    fits: Optional[Callable[[str], bool]] = None,
+    protected: Iterable[int] = (),
+) -> str:
+    """
+    Merges already-ordered directives into one document.
+
+    See ``compose_with_omissions``; this drops the list of omitted sections.
+    """
+    return compose_with_omissions(directives, fits, protected)[0]
+
+
+def compose_with_omissions(
+    directives: List[Directive],
+    fits: Optional[Callable[[str], bool]] = None,
+    protected: Iterable[int] = (),
+) -> Tuple[str, List[str]]:
+    """
+    Merges already-ordered directives into one document.
+
+    A rule key defined more than once is kept only in the last directive
+    that defines it; a Markdown section repeated verbatim (ignoring
+    whitespace and case) is kept only where it first appears.
+
+    With *fits*, sections are dropped until the document satisfies it:
+    lowest ``section_priority`` first, later directives before earlier ones.
+    Directives whose index is in *protected* are never trimmed, so the
+    result may still exceed the budget if they alone do.
+
+    Returns the document and the omitted sections as ``"<name> › <heading>"``.
+    """
+    text, omitted, _ = compose_sections(directives, fits, protected)
+    return text, omitted
+
+
+def compose_sections(
+    directives: List[Directive],
+    fits: Optional[Callable[[str], bool]] = None,
+    protected: Iterable[int] = (),
+) -> Tuple[str, List[str], List[DirectiveSection]]:
+    """
+    ``compose_with_omissions`` plus the sections kept, in document order.
+
+    Text a directive has before its first section heading becomes a section
+    titled with the directive's name.
+    """
+    protected = set(protected)
+    # (input index, name, full render, title unit, [(heading, text, priority)])
+    docs = []
+    for i, directive in _merge(directives):
+        full = directive.render()
+        units = _trim_units(full)
+        priorities = {k.lower(): v for k, v in directive.meta.section_priority.items()}
+        sections = [(h, t, priorities.get(h.lower(), 0)) for h, t in units[1:]]
+        docs.append((i, directive.meta.name, full, units[0][1], sections))
+
+    def build(dropped: set) -> str:
+        rendered = []
+        for d, (_, _, full, title, sections) in enumerate(docs):
+            kept = [t for s, (_, t, _) in enumerate(sections) if (d, s) not in dropped]
+            if len(kept) == len(sections):
+                rendered.append(full)
+            elif kept:
+                rendered.append("\n\n".join([title, *kept]))
+        text = "\n\n---\n\n".join(rendered)
+        if dropped:
+            text += (
+                "\n\n> Trimmed to fit the context budget; omitted: "
+                f"{', '.join(omissions(dropped))}."
+            )
+        return text
+
+    def omissions(dropped: set) -> List[str]:
+        return [f"{docs[d][1]} › {docs[d][4][s][0]}" for d, s in sorted(dropped)]
+
+    def kept(dropped: set) -> List[DirectiveSection]:
+        result = []
+        for d, (_, name, _, title, sections) in enumerate(docs):
+            if sections and all((d, s) in dropped for s in range(len(sections))):
+                continue  # dropped entirely, title included
+            preamble = "\n".join(title.splitlines()[1:]).strip()
+            if preamble:
+                result.append(
+                    DirectiveSection(directive=name, title=name, content=preamble)
+                )
+            result.extend(
+                DirectiveSection(
+                    directive=name, title=heading, priority=priority, content=text
+                )
+                for s, (heading, text, priority) in enumerate(sections)
+                if (d, s) not in dropped
+            )
+        return result
+
+    dropped: set = set()
+    text = build(dropped)
+    if fits is None:
+        return text, [], kept(dropped)
+    candidates = sorted(
+        (
+            (priority, -d, -s)
+            for d, (i, _, _, _, sections) in enumerate(docs)
+            if i not in protected
+            for s, (_, _, priority) in enumerate(sections)
+        ),
+        reverse=True,
+    )
+    while candidates and not fits(text):
+        _, d, s = candidates.pop()
+        dropped.add((-d, -s))
+        text = build(dropped)
+    return text, omissions(dropped), kept(dropped)
+
+
+_PLAIN_RULES = [
+    (re.compile(r"^#{1,6}\s+(.*?)\s*#*$"), r"\1"),
+    (re.compile(r"^>\s?"), ""),
+    (re.compile(r"!?\[([^\]]*)\]\(([^)\s]+)[^)]*\)"), r"\1 (\2)"),
+    (re.compile(r"(\*\*|__)(.+?)\1"), r"\2"),
+    (re.compile(r"(?<![\w*])\*(?!\s)([^*]+?)\*(?![\w*])"), r"\1"),
+    (re.compile(r"`([^`]+)`"), r"\1"),
+]
+
+
+def plain_text(markdown: str) -> str:
+    """*markdown* without its markup: headings, emphasis, code spans and
+    fences, links (kept as ``text (url)``) and quotes become plain lines."""
+    lines = []
+    fenced = False
+    for line in markdown.splitlines():
+        if re.match(r"^\s*(```|~~~)", line):
+            fenced = not fenced
+            continue
+        if not fenced:
+            for pattern, replacement in _PLAIN_RULES:
+                line = pattern.sub(replacement, line)
+        lines.append(line)
+    return re.sub(r"\n{3,}", "\n\n", "\n".join(lines)).strip() + "\n"
+
+
+async def get_master_context(
+    languages: List[str],
+    max_chars: Optional[int] = None,
+    max_tokens: Optional[int] = None,
+    project_core: bool = True,
+    cwd: Optional[Path] = None,
+    locale: Optional[str] = None,
+    project_directives: bool = True,
+) -> str:
+    """Combined directives as Markdown; see ``build_master_context``."""
+    context = await build_master_context(
+        languages,
+        max_chars=max_chars,
+        max_tokens=max_tokens,
+        project_core=project_core,
+        cwd=cwd,
+        locale=locale,
+        project_directives=project_directives,
+    )
+    return context.content
+
+
+async def build_master_context(
+    languages: List[str],
+    max_chars: Optional[int] = None,
+    max_tokens: Optional[int] = None,
+    project_core: bool = True,
+    cwd: Optional[Path] = None,
+    locale: Optional[str] = None,
+    project_directives: bool = True,
+    output_format: str = "markdown",
+) -> DirectiveContext:
     """
     Combines core philosophy with language-specific directives.
+
+    A language resolves to ``<lang>`` or ``d-<lang>``; each directive pulls in
+    what it ``extends`` and the whole set is merged by ``compose``.  With
+    *max_chars* and/or *max_tokens*, lower-priority sections of the language
+    directives are dropped to fit; core philosophy (and anything it extends)
+    is always kept whole.
+
+    Core philosophy precedence, highest first: the project's
+    ``.azathoth/core-philosophy.md`` (unless *project_core* is false), the
+    user's ``core`` directive, the built-in one.  The project file extends
+    the core directive unless its front matter says ``mode: override``.
+
+    Unless *project_directives* is false, the repository's
+    ``.azathoth/directives/`` (nearest from *cwd* up to the repo root) is
+    layered over the global set: a file named like a global directive
+    overrides its same-titled sections and rules (see ``overlay``), and the
+    others add directives languages and ``extends`` can name.  The
+    directives overlaid are listed in ``overlaid``.
+
+    Every directive, the project file included, is read in *locale*
+    (default: the ``directive_locale`` setting) where a translation exists;
+    the ones that fall back to the default language are listed in
+    ``untranslated``.
+
+    *output_format* ``plain`` returns the content stripped of Markdown
+    (``plain_text``); ``json`` keeps the Markdown and also fills
+    ``sections`` with each section's directive, title, priority and text.
+    Budgets are measured on the Markdown either way.
+
+    Raises:
+        DirectiveError: If a directive is malformed, its ``extends`` chain
+            cannot be resolved, *locale* is not a language tag, or
+            *output_format* is unknown.
     """
-    directives = []
+    if output_format not in OUTPUT_FORMATS:
+        raise DirectiveError(
+            f"Unknown output format '{output_format}' "
+            f"(expected one of {', '.join(OUTPUT_FORMATS)})."
+        )
+    locale = normalize_locale(locale or get_config().directive_locale)
+    project = find_project_directives(cwd) if project_directives else None
+
+    def local_file(name: str) -> Optional[Path]:
+        return _find(name, locale, [project]) if project else None
+
+    def exists(name: str) -> bool:
+        return _find(name) is not None or local_file(name) is not None
 
     # Always load core philosophy
-    core = await load_directive("core")
-    if core:
-        directives.append(core.render())
+    names = ["core"] if exists("core") else []
 
     for lang in languages:
-        d = await load_directive(lang.lower())
-        if d:
-            directives.append(d.render())
+        lang = lang.lower()
+        name = lang if exists(lang) else f"d-{lang}"
+        if exists(name) and name not in names:
+            names.append(name)
+
+    resolved = await resolve_directives(names, locale, project)
+    core = {"core"}
+    # Ancestors precede descendants in *resolved*, so walk it backwards.
+    for name, directive in reversed(resolved):
+        if name in core:
+            core |= set(directive.meta.extends)
+
+    local = find_project_core(cwd, locale) if project_core else None
+    if local is not None:
+        directive, mode = load_project_core(local)
+        if mode == "override":
+            resolved = [(n, d) for n, d in resolved if n not in core]
+        at = max((i + 1 for i, (n, _) in enumerate(resolved) if n in core), default=0)
+        resolved.insert(at, (str(PROJECT_CORE), directive))
+        core.add(str(PROJECT_CORE))
+
+    fits: Optional[Callable[[str], bool]] = None
+    if max_chars is not None or max_tokens is not None:
+
+        def within_budget(text: str) -> bool:
+            return (max_chars is None or len(text) <= max_chars) and (
+                max_tokens is None or estimate_tokens(text) <= max_tokens
+            )
+
+        fits = within_budget
+
+    untranslated = []
+    if locale is not None:
+        for name, _ in resolved:
+            if name == str(PROJECT_CORE):
+                path = local
+            else:
+                path = _find(name, locale) or local_file(name)
+            if path is not None and not _is_variant(path.stem):
+                untranslated.append(name)
+    overlaid = [
+        name
+        for name, _ in resolved
+        if name != str(PROJECT_CORE) and _find(name) and local_file(name)
+    ]
 
-    return "\n\n---\n\n".join(directives)
+    protected = [i for i, (name, _) in enumerate(resolved) if name in core]
+    content, omitted, sections = compose_sections(
+        [d for _, d in resolved], fits=fits, protected=protected
+    )
+    if output_format == "plain":
+        content = plain_text(content)
+    return DirectiveContext(
+        directives=[name for name, _ in resolved],
+        content=content,
+        estimated_tokens=estimate_tokens(content),
+        omitted=omitted,
+        locale=locale,
+        untranslated=untranslated,
+        overlaid=overlaid,
+        format=output_format,
+        sections=sections if output_format == "json" else None,
+    )
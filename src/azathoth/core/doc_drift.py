@@ -0,0 +1,376 @@
+"""azathoth.core.doc_drift — cross-check documentation against the repo.
+
+Public surface:
+  - ``extract_commands(markdown)`` → ``[(line, command)]`` from shell blocks
+  - ``check_doc_drift(root)``      → ``DriftReport``
+
+Checked claims:
+  - **command** — ``just``/``make``/``npm run``/``uv run``/``cargo run``
+    invocations in shell code blocks name a recipe, script or example
+    that exists.
+  - **path**    — relative links, images and path-like inline code exist.
+  - **badge**   — crates.io / PyPI / npm badges match a manifest and its
+    package name.
+  - **version** — pinned versions of the project itself and static version
+    badges match the manifest version.
+
+Findings are heuristics meant to give a "fix the docs" prompt concrete
+targets; each issue carries the file and line it came from.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+import shlex
+import tomllib
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.formatter import Table
+from azathoth.core.tasks import just_recipes, make_targets
+from azathoth.core.traverse import iter_files
+
+DriftKind = Literal["command", "path", "badge", "version"]
+
+_SHELL_LANGS = {"sh", "bash", "shell", "console", "zsh", "fish"}
+_FENCE = re.compile(r"^\s*(```|~~~)\s*([\w+-]*)")
+_MD_LINK = re.compile(r"!?\[[^\]]*\]\(\s*<?([^)\s>]+)>?(?:\s+\"[^\"]*\")?\s*\)")
+_HTML_REF = re.compile(r"""(?:src|href)\s*=\s*["']([^"']+)["']""")
+_INLINE_CODE = re.compile(r"(?<!`)`([^`\n]+)`(?!`)")
+_URL_SCHEME = re.compile(r"^[a-zA-Z][a-zA-Z0-9+.-]*:")
+_RAW_GITHUB = re.compile(
+    r"^https://raw\.githubusercontent\.com/[^/]+/[^/]+/[^/]+/(?P<path>.+)$"
+)
+_SEMVER = r"v?(\d+\.\d+\.\d+(?:[-+][\w.]+)?)"
+_STATIC_VERSION_BADGE = re.compile(
+    rf"img\.shields\.io/badge/version-{_SEMVER}-", re.IGNORECASE
+)
+_REGISTRY_BADGES: tuple[tuple[re.Pattern[str], str], ...] = (
+    (re.compile(r"img\.shields\.io/crates/\w+/([\w-]+)"), "rust"),
+    (re.compile(r"docs\.rs/([\w-]+)"), "rust"),
+    (
+        re.compile(r"img\.shields\.io/pypi/\w+/([\w.-]+?)(?:\.svg)?(?=[)?\"'\s]|$)"),
+        "python",
+    ),
+    (re.compile(r"pepy\.tech/badge/([\w.-]+)"), "python"),
+    (re.compile(r"img\.shields\.io/npm/\w+/((?:@[\w-]+/)?[\w.-]+)"), "javascript"),
+)
+_MANIFEST_FOR = {
+    "rust": "Cargo.toml",
+    "python": "pyproject.toml",
+    "javascript": "package.json",
+}
+
+
+class DriftIssue(BaseModel, frozen=True):
+    """A documentation claim that does not match the repository."""
+
+    doc: str
+    line: int
+    kind: DriftKind
+    reference: str
+    message: str
+
+
+class DriftReport(BaseModel, frozen=True):
+    """Stale or broken references found in a project's documentation."""
+
+    root: str
+    docs_checked: list[str]
+    issues: list[DriftIssue] = Field(default_factory=list)
+
+    def render_markdown(self) -> str:
+        lines = [f"# Doc drift: {self.root}", ""]
+        lines.append(f"Checked: {', '.join(self.docs_checked) or 'no docs found'}")
+        lines.append("")
+        if not self.issues:
+            lines.append("No drift detected.")
+            return "\n".join(lines)
+        table = (
+            Table(overflow="ellipsis")
+            .column("Location", max_width=40)
+            .column("Kind")
+            .column("Reference", max_width=40)
+            .column("Problem", max_width=60)
+        )
+        for issue in self.issues:
+            table.row(
+                [
+                    f"{issue.doc}:{issue.line}",
+                    issue.kind,
+                    issue.reference,
+                    issue.message,
+                ]
+            )
+        lines += ["```", table.render(), "```"]
+        return "\n".join(lines)
+
+
+# ── Repo facts ────────────────────────────────────────────────────────────────
+
+
+class _RepoFacts:
+    """What the docs are checked against, loaded once per report."""
+
+    def __init__(self, root: Path):
+        self.root = root
+        self.packages: dict[str, tuple[str, str]] = {}  # eco → (name, version)
+        self.scripts: dict[str, set[str]] = {"python": set(), "javascript": set()}
+        self.dependencies: set[str] = set()
+        self.cargo_bins: set[str] = set()
+
+        pyproject = _load_toml(root / "pyproject.toml")
+        if project := pyproject.get("project"):
+            self.packages["python"] = (
+                project.get("name", ""),
+                str(project.get("version", "")),
+            )
+            self.scripts["python"] = set(project.get("scripts", {}))
+            for spec in project.get("dependencies", []):
+                self.dependencies.add(re.split(r"[<>=!~\[; ]", spec, 1)[0].lower())
+            for group in project.get("optional-dependencies", {}).values():
+                for spec in group:
+                    self.dependencies.add(
+                        re.split(r"[<>=!~\[; ]", spec, 1)[0].lower()
+                    )
+
+        cargo = _load_toml(root / "Cargo.toml")
+        if package := cargo.get("package"):
+            version = package.get("version", "")
+            self.packages["rust"] = (
+                package.get("name", ""),
+                version if isinstance(version, str) else "",
+            )
+            self.cargo_bins = {b.get("name", "") for b in cargo.get("bin", [])}
+            self.cargo_bins.add(package.get("name", ""))
+
+        package_json = root / "package.json"
+        if package_json.is_file():
+            try:
+                data = json.loads(package_json.read_text(encoding="utf-8"))
+            except (json.JSONDecodeError, OSError):
+                data = {}
+            if "name" in data:
+                self.packages["javascript"] = (data["name"], data.get("version", ""))
+            self.scripts["javascript"] = set(data.get("scripts", {}))
+
+        self.just_recipes = just_recipes(root)
+        self.make_targets = make_targets(root)
+
+
+def _load_toml(path: Path) -> dict:
+    if not path.is_file():
+        return {}
+    try:
+        with open(path, "rb") as f:
+            return tomllib.load(f)
+    except (tomllib.TOMLDecodeError, OSError):
+        return {}
+
+
+# ── Extraction ────────────────────────────────────────────────────────────────
+
+
+def extract_commands(markdown: str) -> list[tuple[int, str]]:
+    """Return ``(line number, command)`` for each line in shell code blocks.
+
+    Prompt markers (``$``, ``>``) are stripped and comment lines skipped.
+    """
+    commands: list[tuple[int, str]] = []
+    in_shell: bool | None = None  # None = outside any fence
+    for number, line in enumerate(markdown.splitlines(), start=1):
+        fence = _FENCE.match(line)
+        if fence:
+            in_shell = None if in_shell is not None else fence.group(2) in _SHELL_LANGS
+            continue
+        if not in_shell:
+            continue
+        text = line.strip()
+        if text.startswith(("$ ", "> ")):
+            text = text[2:].strip()
+        if text and not text.startswith("#"):
+            commands.append((number, text))
+    return commands
+
+
+# ── Checks ────────────────────────────────────────────────────────────────────
+
+
+def _check_command(command: str, facts: _RepoFacts) -> str | None:
+    """Return a problem description, or ``None`` if the command checks out."""
+    try:
+        argv = shlex.split(command, comments=True)
+    except ValueError:
+        return None
+    if not argv:
+        return None
+    tool, args = argv[0], argv[1:]
+    positional = [a for a in args if not a.startswith("-")]
+
+    if tool == "just" and positional:
+        if positional[0] not in facts.just_recipes:
+            return f"justfile has no recipe '{positional[0]}'"
+    elif tool == "make" and positional:
+        if positional[0] not in facts.make_targets:
+            return f"Makefile has no target '{positional[0]}'"
+    elif tool in ("npm", "pnpm", "bun") and args[:1] == ["run"] and len(args) > 1:
+        if args[1] not in facts.scripts["javascript"]:
+            return f"package.json has no script '{args[1]}'"
+    elif tool == "uv" and args[:1] == ["run"] and positional[1:]:
+        target = positional[1]
+        if (
+            target not in facts.scripts["python"]
+            and target.lower() not in facts.dependencies
+            and not (facts.root / target).exists()
+            and target not in ("python", "python3", "pytest")
+        ):
+            return f"'{target}' is not a project script, dependency or file"
+    elif tool == "cargo" and args[:1] == ["run"]:
+        for flag, kind in (("--example", "example"), ("--bin", "binary")):
+            if flag in args and args.index(flag) + 1 < len(args):
+                name = args[args.index(flag) + 1]
+                if not _cargo_target_exists(facts, kind, name):
+                    return f"no cargo {kind} named '{name}'"
+    return None
+
+
+def _cargo_target_exists(facts: _RepoFacts, kind: str, name: str) -> bool:
+    base = facts.root / ("examples" if kind == "example" else "src/bin")
+    if (base / f"{name}.rs").is_file() or (base / name / "main.rs").is_file():
+        return True
+    return kind == "binary" and name in facts.cargo_bins
+
+
+def _local_target(ref: str) -> str | None:
+    """Map a link target to a repo-relative path, or ``None`` if external."""
+    if raw := _RAW_GITHUB.match(ref):
+        return raw.group("path")
+    if _URL_SCHEME.match(ref) or ref.startswith(("#", "//")):
+        return None
+    return ref.split("#", 1)[0].split("?", 1)[0] or None
+
+
+def _looks_like_path(text: str, root: Path) -> bool:
+    """Inline code counts as a path only if its first segment exists."""
+    if any(c in text for c in " <>*{}$|") or text.startswith(("~", "/", "-")):
+        return False
+    if "/" not in text.strip("/"):
+        return False
+    first = text.removeprefix("./").split("/", 1)[0]
+    return bool(first) and (root / first).exists()
+
+
+def _check_doc(doc: Path, rel: str, facts: _RepoFacts) -> list[DriftIssue]:
+    text = doc.read_text(encoding="utf-8", errors="ignore")
+    issues: list[DriftIssue] = []
+
+    def _issue(line: int, kind: DriftKind, ref: str, message: str) -> None:
+        issues.append(
+            DriftIssue(doc=rel, line=line, kind=kind, reference=ref, message=message)
+        )
+
+    for line, command in extract_commands(text):
+        if problem := _check_command(command, facts):
+            _issue(line, "command", command, problem)
+
+    own = {name.lower(): eco for eco, (name, _) in facts.packages.items() if name}
+    in_fence = False
+    for number, line in enumerate(text.splitlines(), start=1):
+        if _FENCE.match(line):
+            in_fence = not in_fence
+            continue
+
+        # Install snippets usually live in code blocks, so pins are checked
+        # everywhere; the remaining checks apply to prose only.
+        for name, eco in own.items():
+            version = facts.packages[eco][1]
+            pin = re.search(
+                rf"\b{re.escape(name)}\s*(?:==|@|=\s*\"?)\s*{_SEMVER}",
+                line,
+                re.IGNORECASE,
+            )
+            if pin and version and pin.group(1) != version:
+                _issue(
+                    number,
+                    "version",
+                    pin.group(0),
+                    f"{_MANIFEST_FOR[eco]} version is {version}",
+                )
+        if in_fence:
+            continue
+
+        refs = _MD_LINK.findall(line) + _HTML_REF.findall(line)
+        for ref in refs:
+            target = _local_target(ref)
+            if target and not (doc.parent / target).exists():
+                if not (facts.root / target).exists():
+                    _issue(number, "path", ref, "linked file does not exist")
+
+        for code in _INLINE_CODE.findall(line):
+            path = code.strip()
+            if _looks_like_path(path, facts.root) and not (
+                facts.root / path
+            ).exists():
+                _issue(number, "path", path, "referenced path does not exist")
+
+        for pattern, eco in _REGISTRY_BADGES:
+            for name in pattern.findall(line):
+                manifest = _MANIFEST_FOR[eco]
+                if eco not in facts.packages:
+                    _issue(
+                        number,
+                        "badge",
+                        name,
+                        f"{eco} registry badge but no {manifest} package",
+                    )
+                elif facts.packages[eco][0].lower() != name.lower():
+                    _issue(
+                        number,
+                        "badge",
+                        name,
+                        f"{manifest} names the package "
+                        f"'{facts.packages[eco][0]}'",
+                    )
+
+        for version in _STATIC_VERSION_BADGE.findall(line):
+            current = {v for _, v in facts.packages.values() if v}
+            if current and version not in current:
+                _issue(
+                    number,
+                    "version",
+                    version,
+                    f"manifest version is {', '.join(sorted(current))}",
+                )
+
+    return issues
+
+
+def _doc_files(root: Path) -> list[Path]:
+    docs = [
+        p
+        for p in sorted(root.iterdir())
+        if p.is_file()
+        and p.suffix.lower() in (".md", ".markdown")
+        and p.stem.upper() in ("README", "CONTRIBUTING", "INSTALL", "USAGE")
+    ]
+    if (root / "docs").is_dir():
+        docs += list(iter_files(root / "docs", suffixes=(".md", ".markdown")))
+    return docs
+
+
+def check_doc_drift(root: Path) -> DriftReport:
+    """Cross-check README/CONTRIBUTING and ``docs/`` against *root*."""
+    root = root.resolve()
+    facts = _RepoFacts(root)
+    docs = _doc_files(root)
+    issues: list[DriftIssue] = []
+    for doc in docs:
+        issues += _check_doc(doc, doc.relative_to(root).as_posix(), facts)
+    return DriftReport(
+        root=str(root),
+        docs_checked=[d.relative_to(root).as_posix() for d in docs],
+        issues=issues,
+    )
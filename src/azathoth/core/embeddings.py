@@ -0,0 +1,119 @@
+"""azathoth.core.embeddings — text embeddings for semantic search.
+
+Public surface:
+  - ``Embedder``                      — Protocol: ``await embedder.embed(texts)``
+  - ``OllamaEmbedder(host, model)``   — a local model served by Ollama
+  - ``OpenAIEmbedder(url, model, key)`` — any OpenAI-compatible ``/embeddings``
+    API (OpenAI itself, or a local server speaking the same protocol)
+  - ``get_embedder()``                → the one ``scout_embeddings`` selects,
+    or ``None`` when semantic search is off
+
+Both backends speak plain HTTP through ``httpx``; no SDK is involved.
+Failures surface as the ``ProviderError`` family of ``core.exceptions``
+(``ProviderUnavailable`` when the service cannot be reached), so callers
+handle them as they handle LLM calls.  ``Embedder.model`` names the model
+in the scout index, so switching models never mixes vectors.
+"""
+
+from __future__ import annotations
+
+from typing import Any, Protocol, runtime_checkable
+
+import httpx
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import (
+    ProviderAuthError,
+    ProviderError,
+    ProviderUnavailable,
+)
+
+_TIMEOUT = 120.0
+
+
+@runtime_checkable
+class Embedder(Protocol):
+    #: Identifies the vectors in the index (e.g. ``"ollama/nomic-embed-text"``).
+    model: str
+
+    async def embed(self, texts: list[str]) -> list[list[float]]:
+        """One vector per text, in order.
+
+        Raises:
+            ProviderUnavailable: The service cannot be reached.
+            ProviderError:       It rejected the request.
+        """
+        ...  # pragma: no cover
+
+
+async def _post(url: str, payload: dict[str, Any], headers: dict[str, str]) -> Any:
+    try:
+        async with httpx.AsyncClient(timeout=_TIMEOUT) as client:
+            resp = await client.post(url, json=payload, headers=headers)
+    except (httpx.TimeoutException, httpx.ConnectError) as exc:
+        raise ProviderUnavailable(
+            f"Embedding service at {url} unreachable: {exc}"
+        ) from exc
+    if resp.status_code in (401, 403):
+        raise ProviderAuthError(
+            f"Embedding service refused the key: {resp.text[:200]}"
+        )
+    if resp.status_code >= 500:
+        raise ProviderUnavailable(
+            f"Embedding service error: HTTP {resp.status_code} — {resp.text[:200]}"
+        )
+    if resp.status_code >= 400:
+        raise ProviderError(
+            f"Embedding request rejected ({resp.status_code}): {resp.text[:200]}"
+        )
+    return resp.json()
+
+
+class OllamaEmbedder:
+    """Embeddings from a model pulled into a local Ollama daemon."""
+
+    def __init__(self, host: str, model: str):
+        self._host = host.rstrip("/")
+        self._model = model
+        self.model = f"ollama/{model}"
+
+    async def embed(self, texts: list[str]) -> list[list[float]]:
+        data = await _post(
+            f"{self._host}/api/embed", {"model": self._model, "input": texts}, {}
+        )
+        return data["embeddings"]
+
+
+class OpenAIEmbedder:
+    """Embeddings from an OpenAI-compatible ``POST {url}/embeddings`` API."""
+
+    def __init__(self, url: str, model: str, api_key: str | None):
+        self._url = url.rstrip("/")
+        self._model = model
+        self._key = api_key
+        self.model = f"openai/{model}"
+
+    async def embed(self, texts: list[str]) -> list[list[float]]:
+        headers = {"Authorization": f"Bearer {self._key}"} if self._key else {}
+        data = await _post(
+            f"{self._url}/embeddings", {"model": self._model, "input": texts}, headers
+        )
+        rows = sorted(data["data"], key=lambda row: row["index"])
+        return [row["embedding"] for row in rows]
+
+
+def get_embedder() -> Embedder | None:
+    """The embedder configured by ``scout_embeddings`` (``None`` when off)."""
+    settings = get_config()
+    model = settings.scout_embedding_model
+    url = settings.scout_embedding_url
+    if settings.scout_embeddings == "ollama":
+        return OllamaEmbedder(url or settings.ollama_host, model or "nomic-embed-text")
+    if settings.scout_embeddings == "openai":
+        key = settings.scout_embedding_api_key
+        return OpenAIEmbedder(
+            url or "https://api.openai.com/v1",
+            model or "text-embedding-3-small",
+            key.get_secret_value() if key else None,
+        )
+    return None
@@ -27,6 +27,26 @@ class LLMError(AzathothError):
     """Raised when an LLM façade call fails (legacy; prefer ProviderError subclasses)."""
 
 
+class WorkflowError(AzathothError):
+    """Base exception for git workflow errors."""
+
+
+class PolicyDenied(AzathothError):
+    """Raised when a safety policy (e.g. the pause kill-switch) blocks a mutation."""
+
+
+class DirectiveError(AzathothError):
+    """Raised when a directive is malformed or its ``extends`` chain is broken."""
+
+
+class SandboxError(AzathothError):
+    """Raised when a path escapes the directory a tool is confined to."""
+
+
+class CompositionError(AzathothError):
+    """Raised when servers combined into one expose clashing names."""
+
+
 class I18nError(AzathothError):
     """Base exception for i18n errors."""
 
@@ -46,6 +66,11 @@ class RegistryError(I18nError):
 __all__ = [
     "AzathothError",
     "LLMError",
+    "WorkflowError",
+    "PolicyDenied",
+    "DirectiveError",
+    "SandboxError",
+    "CompositionError",
     "I18nError",
     "ConfigParseError",
     "TranslationError",
@@ -0,0 +1,370 @@
+"""azathoth.core.files — sandboxed file access for the scout server, and the
+workflow server's file writes.
+
+Public surface:
+  - ``Sandbox(root, deny)``                      — the directory tools may see
+  - ``Sandbox.for_root(root)``                   → one with ``scout_deny``
+  - ``resolve_inside(root, path)``               → absolute path under *root*
+  - ``is_binary(sample)``                        → bool
+  - ``read_file(sandbox, path, start_line, …)``  → ``FileContent``
+  - ``list_directory(sandbox, path, recursive)`` → ``DirectoryListing``
+  - ``glob_files(sandbox, pattern)``             → ``GlobResult``
+  - ``write_file(sandbox, path, content)``       → ``FileWrite``
+  - ``edit_file(sandbox, path, old, new)``       → ``FileWrite``
+
+Every path goes through ``Sandbox.resolve``: it is canonicalized (symlinks
+included) and must stay inside the root, so ``..`` segments, absolute paths
+and links pointing out of the project are refused with ``SandboxError``, as
+is anything under a deny pattern (``.git/objects`` and ``node_modules`` by
+default).  A pattern without ``/`` matches a path component at any depth; one
+with ``/`` is anchored at the root.  Denied directories still appear in
+listings, but are never read, descended into or matched by ``glob``; what
+the project ignores (``core.traverse.IgnoreRules``) is left out of both.  Reads
+are capped at ``scout_max_read_bytes`` and listings at ``scout_max_entries``;
+binary files are reported, not returned.  Writes go through the same
+checks and are also refused anywhere under ``.git``; edits replace exact
+text and refuse binary files.
+"""
+
+from __future__ import annotations
+
+import os
+from dataclasses import dataclass
+from fnmatch import fnmatchcase
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import SandboxError
+from azathoth.core.traverse import DEFAULT_SKIP_DIRS, IgnoreRules
+
+EntryKind = Literal["file", "dir", "symlink"]
+
+#: What a ``Sandbox`` denies unless given other patterns (``scout_deny``
+#: defaults to the same).
+DEFAULT_DENY = (".git/objects", "node_modules")
+
+_SNIFF_BYTES = 8192
+# Control characters other than tab/newline/carriage return/form feed.
+_TEXT_CONTROL = set(range(32)) - {9, 10, 12, 13}
+
+
+class FileContent(BaseModel, frozen=True):
+    path: str
+    size: int
+    binary: bool = False
+    start_line: int = 1
+    end_line: int = 0
+    total_lines: int | None = Field(
+        None, description="Known only when the whole file fit in the read limit"
+    )
+    truncated: bool = Field(False, description="More lines follow end_line")
+    content: str = ""
+
+
+class DirectoryEntry(BaseModel, frozen=True):
+    path: str
+    kind: EntryKind
+    size: int | None = None
+
+
+class DirectoryListing(BaseModel, frozen=True):
+    path: str
+    entries: list[DirectoryEntry] = Field(default_factory=list)
+    truncated: bool = False
+
+
+class GlobResult(BaseModel, frozen=True):
+    pattern: str
+    matches: list[str] = Field(default_factory=list)
+    truncated: bool = False
+
+
+class FileWrite(BaseModel, frozen=True):
+    path: str
+    written: bool = Field(description="False for a dry run")
+    created: bool = False
+    size: int = Field(description="Bytes after the write")
+    replacements: int = 0
+
+
+# ── Sandbox ───────────────────────────────────────────────────────────────────
+
+
+def resolve_inside(root: Path, path: str | os.PathLike[str]) -> Path:
+    """Resolve *path* relative to *root*, refusing anything outside it.
+
+    Raises:
+        SandboxError: If the resolved path is not *root* or below it.
+    """
+    root = root.resolve()
+    target = (root / path).resolve()
+    if not target.is_relative_to(root):
+        raise SandboxError(f"'{path}' is outside {root}.")
+    return target
+
+
+@dataclass(frozen=True)
+class Sandbox:
+    """A project root the file tools are confined to, minus *deny* patterns."""
+
+    root: Path
+    deny: tuple[str, ...] = DEFAULT_DENY
+
+    def __post_init__(self) -> None:
+        object.__setattr__(self, "root", self.root.resolve())
+
+    @classmethod
+    def for_root(cls, root: Path) -> Sandbox:
+        """A sandbox on *root* with the configured ``scout_deny`` patterns."""
+        return cls(root, tuple(get_config().scout_deny))
+
+    def denied_by(self, path: Path) -> str | None:
+        """The deny pattern covering *path* (absolute, inside the root), if any."""
+        parts = path.relative_to(self.root).parts
+        for pattern in self.deny:
+            anchored = pattern.strip("/").split("/")
+            if len(anchored) == 1:
+                if any(fnmatchcase(part, anchored[0]) for part in parts):
+                    return pattern
+            elif len(parts) >= len(anchored) and all(
+                fnmatchcase(part, want) for part, want in zip(parts, anchored)
+            ):
+                return pattern
+        return None
+
+    def resolve(self, path: str | os.PathLike[str]) -> Path:
+        """Canonicalize *path* relative to the root and validate it.
+
+        Raises:
+            SandboxError: If it leads outside the root or into a denied path.
+        """
+        target = resolve_inside(self.root, path)
+        if (pattern := self.denied_by(target)) is not None:
+            raise SandboxError(f"'{path}' is off limits (matches '{pattern}').")
+        return target
+
+    def rel(self, path: Path) -> str:
+        """*path* relative to the root, POSIX-style (``.`` for the root)."""
+        return path.relative_to(self.root).as_posix() or "."
+
+
+def is_binary(sample: bytes) -> bool:
+    """Heuristic: any NUL byte, or >10% undecodable/control characters."""
+    if b"\0" in sample:
+        return True
+    text = sample.decode("utf-8", errors="replace")
+    bad = sum(ch == "\ufffd" or ord(ch) in _TEXT_CONTROL for ch in text)
+    return bad / max(len(text), 1) > 0.1
+
+
+# ── Reading ───────────────────────────────────────────────────────────────────
+
+
+def read_file(
+    sandbox: Sandbox, path: str, start_line: int = 1, max_lines: int | None = None
+) -> FileContent:
+    """Read *path* from line *start_line* (1-based), within the byte limit.
+
+    Raises:
+        SandboxError: If the sandbox refuses *path* or it is not a regular file.
+    """
+    target = sandbox.resolve(path)
+    if not target.is_file():
+        raise SandboxError(f"'{path}' is not a file.")
+    size = target.stat().st_size
+    rel = sandbox.rel(target)
+
+    with open(target, "rb") as f:
+        if is_binary(f.read(_SNIFF_BYTES)):
+            return FileContent(path=rel, size=size, binary=True)
+
+    limit = get_config().scout_max_read_bytes
+    start_line = max(start_line, 1)
+    lines: list[str] = []
+    used = 0
+    line_no = 0
+    more = False
+    with open(target, encoding="utf-8", errors="replace", newline="") as f:
+        for line_no, line in enumerate(f, 1):
+            if line_no < start_line:
+                continue
+            size_of_line = len(line.encode())
+            if (max_lines is not None and len(lines) >= max_lines) or (
+                used + size_of_line > limit and lines
+            ):
+                more = True
+                break
+            if size_of_line > limit:  # one huge line (minified code, data)
+                lines.append(line.encode()[:limit].decode(errors="ignore"))
+                more = True
+                break
+            lines.append(line)
+            used += size_of_line
+    end_line = start_line + len(lines) - 1 if lines else start_line - 1
+    return FileContent(
+        path=rel,
+        size=size,
+        start_line=start_line,
+        end_line=end_line,
+        total_lines=None if more else line_no,
+        truncated=more,
+        content="".join(lines),
+    )
+
+
+# ── Listing ───────────────────────────────────────────────────────────────────
+
+
+def _entry(sandbox: Sandbox, path: Path) -> DirectoryEntry:
+    rel = sandbox.rel(path)
+    if path.is_symlink():
+        return DirectoryEntry(path=rel, kind="symlink")
+    if path.is_dir():
+        return DirectoryEntry(path=rel, kind="dir")
+    return DirectoryEntry(path=rel, kind="file", size=path.stat().st_size)
+
+
+def list_directory(
+    sandbox: Sandbox, path: str = ".", recursive: bool = False
+) -> DirectoryListing:
+    """Entries of *path* (directories first), recursing if asked.
+
+    Dependency, build, VCS and denied directories are listed but not
+    descended into; what the project's ignore rules exclude is left out.
+
+    Raises:
+        SandboxError: If the sandbox refuses *path* or it is not a directory.
+    """
+    target = sandbox.resolve(path)
+    if not target.is_dir():
+        raise SandboxError(f"'{path}' is not a directory.")
+    limit = get_config().scout_max_entries
+    ignore = IgnoreRules.for_root(sandbox.root)
+
+    entries: list[DirectoryEntry] = []
+    pending = [target]
+    while pending:
+        directory = pending.pop(0)
+        children = sorted(
+            directory.iterdir(), key=lambda p: (not p.is_dir(), p.name.lower())
+        )
+        for child in children:
+            if ignore.matches(child, child.is_dir()):
+                continue
+            if len(entries) >= limit:
+                return DirectoryListing(
+                    path=sandbox.rel(target), entries=entries, truncated=True
+                )
+            entries.append(_entry(sandbox, child))
+            if (
+                recursive
+                and child.is_dir()
+                and not child.is_symlink()
+                and child.name not in DEFAULT_SKIP_DIRS
+                and sandbox.denied_by(child) is None
+            ):
+                pending.append(child)
+    return DirectoryListing(path=sandbox.rel(target), entries=entries)
+
+
+def glob_files(sandbox: Sandbox, pattern: str) -> GlobResult:
+    """Paths in *sandbox* matching *pattern* (``**`` recurses), sorted.
+
+    Raises:
+        SandboxError: If *pattern* is absolute or climbs out with ``..``.
+    """
+    root = sandbox.root
+    if Path(pattern).is_absolute() or ".." in Path(pattern).parts:
+        raise SandboxError(f"Pattern '{pattern}' must stay inside {root}.")
+    limit = get_config().scout_max_entries
+    ignore = IgnoreRules.for_root(root)
+    matches: list[str] = []
+    for match in root.glob(pattern):
+        rel = match.relative_to(root)
+        if any(part in DEFAULT_SKIP_DIRS for part in rel.parts[:-1]):
+            continue
+        resolved = match.resolve()
+        if not resolved.is_relative_to(root):
+            continue
+        if sandbox.denied_by(resolved) is not None or ignore.ignored(match):
+            continue
+        if len(matches) >= limit:
+            return GlobResult(pattern=pattern, matches=sorted(matches), truncated=True)
+        matches.append(rel.as_posix())
+    return GlobResult(pattern=pattern, matches=sorted(matches))
+
+
+# ── Writing ───────────────────────────────────────────────────────────────────
+
+
+def _writable(sandbox: Sandbox, path: str) -> Path:
+    target = sandbox.resolve(path)
+    if ".git" in target.relative_to(sandbox.root).parts:
+        raise SandboxError(f"'{path}' is inside .git; it cannot be written.")
+    if target.is_dir():
+        raise SandboxError(f"'{path}' is a directory.")
+    return target
+
+
+def write_file(
+    sandbox: Sandbox, path: str, content: str, dry_run: bool = False
+) -> FileWrite:
+    """Write *content* to *path*, creating it and its directories if needed.
+
+    Raises:
+        SandboxError: If the sandbox refuses *path* or it is a directory.
+    """
+    target = _writable(sandbox, path)
+    created = not target.exists()
+    data = content.encode()
+    if not dry_run:
+        target.parent.mkdir(parents=True, exist_ok=True)
+        target.write_bytes(data)
+    return FileWrite(
+        path=sandbox.rel(target), written=not dry_run, created=created, size=len(data)
+    )
+
+
+def edit_file(
+    sandbox: Sandbox,
+    path: str,
+    old: str,
+    new: str,
+    replace_all: bool = False,
+    dry_run: bool = False,
+) -> FileWrite:
+    """Replace the text *old* in *path* with *new*.
+
+    *old* must occur exactly once unless *replace_all*.
+
+    Raises:
+        SandboxError: If the sandbox refuses *path*, it is not a text file,
+            or *old* is missing or (without *replace_all*) ambiguous.
+    """
+    target = _writable(sandbox, path)
+    if not target.is_file():
+        raise SandboxError(f"'{path}' is not a file.")
+    data = target.read_bytes()
+    if is_binary(data[:_SNIFF_BYTES]):
+        raise SandboxError(f"'{path}' is a binary file.")
+    text = data.decode("utf-8", errors="surrogateescape")
+    count = text.count(old) if old else 0
+    if count == 0:
+        raise SandboxError(f"The text to replace is not in '{path}'.")
+    if count > 1 and not replace_all:
+        raise SandboxError(
+            f"The text to replace occurs {count} times in '{path}'; include "
+            "more context to make it unique, or pass replace_all."
+        )
+    updated = text.replace(old, new).encode("utf-8", errors="surrogateescape")
+    if not dry_run:
+        target.write_bytes(updated)
+    return FileWrite(
+        path=sandbox.rel(target),
+        written=not dry_run,
+        size=len(updated),
+        replacements=count,
+    )
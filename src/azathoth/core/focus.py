@@ -0,0 +1,151 @@
+"""azathoth.core.focus — time-boxed focus sessions for agent work.
+
+A focus session records a goal and a time box, collects the tool calls made
+while it is active from the audit trail (``core.audit``), and on completion
+summarises the commits created during the window.  The summary is appended to the user's journal
+(``Settings.journal_file``) and rendered as a commit trailer so the session
+can be referenced from history.
+
+Only one session can be active per server process.
+"""
+
+from __future__ import annotations
+
+from collections import Counter
+from datetime import datetime, timedelta, timezone
+
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+from azathoth.core.audit import AuditRecord, on_record
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.workflow import _run_git
+
+_active: FocusSession | None = None
+
+
+class FocusSession(BaseModel):
+    """An in-progress focus session.
+
+    Mutable: ``tool_calls`` grows as tools are invoked during the session.
+    """
+
+    goal: str
+    minutes: int
+    started_at: datetime
+    tool_calls: list[str] = Field(default_factory=list)
+
+    @property
+    def deadline(self) -> datetime:
+        return self.started_at + timedelta(minutes=self.minutes)
+
+    def remaining(self, now: datetime | None = None) -> timedelta:
+        """Time left in the box; negative once the session has overrun."""
+        return self.deadline - (now or datetime.now(timezone.utc))
+
+
+class FocusSummary(BaseModel, frozen=True):
+    """Outcome of a finished focus session."""
+
+    goal: str
+    started_at: datetime
+    ended_at: datetime
+    planned_minutes: int
+    tool_calls: dict[str, int]
+    commits: list[str]
+
+    @property
+    def elapsed_minutes(self) -> int:
+        return round((self.ended_at - self.started_at).total_seconds() / 60)
+
+    def trailer(self) -> str:
+        """A git trailer line referencing this session."""
+        return (
+            f"Focus-Session: {self.goal} "
+            f"({self.elapsed_minutes}m of {self.planned_minutes}m)"
+        )
+
+    def render_markdown(self) -> str:
+        status = (
+            "on time"
+            if self.elapsed_minutes <= self.planned_minutes
+            else f"overran by {self.elapsed_minutes - self.planned_minutes}m"
+        )
+        lines = [
+            f"## Focus session: {self.goal}",
+            f"- **Window:** {self.started_at:%Y-%m-%d %H:%M} → "
+            f"{self.ended_at:%H:%M} UTC ({self.elapsed_minutes}m, {status})",
+            f"- **Tool calls:** {sum(self.tool_calls.values())}",
+        ]
+        lines += [f"  - `{name}` × {n}" for name, n in self.tool_calls.items()]
+        lines.append(f"- **Commits:** {len(self.commits)}")
+        lines += [f"  - {c}" for c in self.commits]
+        return "\n".join(lines)
+
+
+def active_session() -> FocusSession | None:
+    """Return the running session, if any."""
+    return _active
+
+
+def start_session(goal: str, minutes: int = 25) -> FocusSession:
+    """Start a new focus session.
+
+    Raises:
+        WorkflowError: If a session is already running or *minutes* < 1.
+    """
+    global _active
+    if _active is not None:
+        raise WorkflowError(
+            f"A focus session is already running: '{_active.goal}'. End it first."
+        )
+    if minutes < 1:
+        raise WorkflowError("A focus session must last at least one minute.")
+    _active = FocusSession(
+        goal=goal, minutes=minutes, started_at=datetime.now(timezone.utc)
+    )
+    return _active
+
+
+def record_tool_call(record: AuditRecord) -> None:
+    """Attribute an audited tool call to the running session (no-op when idle)."""
+    if _active is not None:
+        _active.tool_calls.append(record.tool)
+
+
+on_record(record_tool_call)
+
+
+async def end_session(cwd: str | None = None, journal: bool = True) -> FocusSummary:
+    """Finish the running session and summarise it.
+
+    Raises:
+        WorkflowError: If no session is running.
+    """
+    global _active
+    if _active is None:
+        raise WorkflowError("No focus session is running.")
+    session, _active = _active, None
+
+    since = session.started_at.isoformat()
+    code, out, _ = await _run_git(
+        ["log", f"--since={since}", "--pretty=format:%h %s"], cwd=cwd
+    )
+    commits = out.splitlines() if code == 0 and out else []
+
+    summary = FocusSummary(
+        goal=session.goal,
+        started_at=session.started_at,
+        ended_at=datetime.now(timezone.utc),
+        planned_minutes=session.minutes,
+        tool_calls=dict(Counter(session.tool_calls)),
+        commits=commits,
+    )
+
+    if journal:
+        path = get_config().journal_file
+        path.parent.mkdir(parents=True, exist_ok=True)
+        with open(path, "a", encoding="utf-8") as f:
+            f.write(summary.render_markdown() + "\n\n")
+
+    return summary
@@ -0,0 +1,287 @@
+"""azathoth.core.formatter — plain-text rendering for MCP tool output.
+
+MCP clients display tool results verbatim in a monospace transcript, so
+tables must be aligned by *display columns*, not by ``len()``.  CJK
+ideographs and most emoji occupy two columns; combining marks and
+zero-width joiners occupy none.
+
+Public surface:
+  - ``display_width(text)``                      → int
+  - ``truncate(text, width)``                    → str (ellipsised)
+  - ``wrap(text, width)``                        → list[str]
+  - ``render_table(headers, rows, max_widths=…)`` → str
+  - ``Table().column("ID", align="right").rows(items).render()`` → str
+
+``Table`` accepts rows as sequences, mappings, dataclasses or pydantic
+models; cells are rendered with ``str()``.
+
+The CLI keeps using ``rich`` tables; this module is for string-returning
+MCP tools only and has no third-party dependencies.
+"""
+
+from __future__ import annotations
+
+import dataclasses
+import unicodedata
+from collections.abc import Iterable, Mapping, Sequence
+from typing import Any, Literal
+
+Overflow = Literal["wrap", "ellipsis"]
+Align = Literal["left", "right", "center"]
+
+ELLIPSIS = "…"
+
+# Zero-width code points that ``unicodedata`` does not flag as combining.
+_ZERO_WIDTH = frozenset(
+    {
+        "\u200b",  # zero width space
+        "\u200c",  # zero width non-joiner
+        "\u200d",  # zero width joiner
+        "\u2060",  # word joiner
+        "\ufeff",  # zero width no-break space
+    }
+)
+
+
+def char_width(ch: str) -> int:
+    """Return the number of terminal columns *ch* occupies (0, 1 or 2)."""
+    if ch in _ZERO_WIDTH or unicodedata.combining(ch):
+        return 0
+    # Variation selectors (text/emoji presentation) render with the base char.
+    if "\ufe00" <= ch <= "\ufe0f":
+        return 0
+    category = unicodedata.category(ch)
+    if category in ("Cc", "Cf", "Mn", "Me"):
+        return 0
+    if unicodedata.east_asian_width(ch) in ("W", "F"):
+        return 2
+    return 1
+
+
+def display_width(text: str) -> int:
+    """Return the display width of *text* in terminal columns."""
+    return sum(char_width(ch) for ch in text)
+
+
+def _sanitize(text: str) -> str:
+    """Collapse tabs/newlines so a single cell never breaks table rows."""
+    return " ".join(text.split()) if any(c in text for c in "\t\r\n") else text
+
+
+def truncate(text: str, width: int) -> str:
+    """Cut *text* to at most *width* columns, ending in ``…`` when shortened."""
+    if display_width(text) <= width:
+        return text
+    if width <= 0:
+        return ""
+    budget = width - display_width(ELLIPSIS)
+    out: list[str] = []
+    used = 0
+    for ch in text:
+        w = char_width(ch)
+        if used + w > budget:
+            break
+        out.append(ch)
+        used += w
+    return "".join(out) + ELLIPSIS
+
+
+def wrap(text: str, width: int) -> list[str]:
+    """Break *text* into lines of at most *width* columns.
+
+    Breaks on spaces where possible; words wider than *width* (including
+    runs of CJK text, which has no spaces) are split at character
+    boundaries.  A double-width character is never split across lines; when
+    *width* is 1 it cannot fit at all and is shown as ``…`` instead.
+    """
+    if width <= 0:
+        return [""]
+    lines: list[str] = []
+    current = ""
+    for word in text.split(" "):
+        candidate = f"{current} {word}" if current else word
+        if display_width(candidate) <= width:
+            current = candidate
+            continue
+        if current:
+            lines.append(current)
+        current = ""
+        # Hard-split words that cannot fit on a line of their own.
+        for ch in word:
+            if char_width(ch) > width:
+                ch = ELLIPSIS
+            if current and display_width(current) + char_width(ch) > width:
+                lines.append(current)
+                current = ""
+            current += ch
+    lines.append(current)
+    return lines
+
+
+def pad(text: str, width: int, align: Align = "left") -> str:
+    """Pad *text* with spaces to exactly *width* display columns."""
+    gap = max(0, width - display_width(text))
+    if align == "right":
+        return " " * gap + text
+    if align == "center":
+        return " " * (gap // 2) + text + " " * (gap - gap // 2)
+    return text + " " * gap
+
+
+def render_table(
+    headers: Sequence[str],
+    rows: Sequence[Sequence[str]],
+    *,
+    max_widths: Sequence[int | None] | None = None,
+    overflow: Overflow = "wrap",
+) -> str:
+    """Render an aligned plain-text table.
+
+    Args:
+        headers:    Column titles.
+        rows:       Cell values; each row must have ``len(headers)`` cells.
+        max_widths: Optional per-column cap in display columns (``None`` =
+                    unbounded).  Cells wider than the cap wrap onto extra
+                    lines or are ellipsised, depending on *overflow*.
+        overflow:   ``"wrap"`` (default) or ``"ellipsis"``.
+
+    Every line of the result has the same display width.
+    """
+    ncols = len(headers)
+    caps = list(max_widths or [None] * ncols)
+    if len(caps) != ncols:
+        raise ValueError(f"max_widths has {len(caps)} entries for {ncols} columns")
+
+    table = Table(overflow=overflow)
+    for header, cap in zip(headers, caps):
+        table.column(header, max_width=cap)
+    return table.rows(rows).render()
+
+
+# ── Table builder ─────────────────────────────────────────────────────────────
+
+
+@dataclasses.dataclass(frozen=True)
+class Column:
+    """A table column.  *key* selects the field for mapping/model rows."""
+
+    title: str
+    key: str | None = None
+    align: Align = "left"
+    max_width: int | None = None
+
+    @property
+    def field(self) -> str:
+        return self.key or self.title
+
+
+class Table:
+    """Builder for aligned plain-text tables.
+
+    Example::
+
+        Table().column("ID", align="right").column("Label", max_width=30)
+            .rows(items).render()
+
+    Column widths are sized to the widest cell, capped by ``max_width``.
+    """
+
+    def __init__(self, *, overflow: Overflow = "wrap") -> None:
+        self.overflow: Overflow = overflow
+        self.columns: list[Column] = []
+        self._rows: list[list[str]] = []
+
+    def column(
+        self,
+        title: str,
+        *,
+        key: str | None = None,
+        align: Align = "left",
+        max_width: int | None = None,
+    ) -> Table:
+        """Append a column.  Columns must be declared before rows are added."""
+        if self._rows:
+            raise ValueError("columns must be declared before adding rows")
+        self.columns.append(Column(title, key, align, max_width))
+        return self
+
+    def row(self, source: Any) -> Table:
+        """Append one row from a sequence, mapping, dataclass or pydantic model."""
+        self._rows.append(self._cells(source))
+        return self
+
+    def rows(self, sources: Iterable[Any]) -> Table:
+        """Append every row in *sources*."""
+        for source in sources:
+            self.row(source)
+        return self
+
+    def _cells(self, source: Any) -> list[str]:
+        index = len(self._rows)
+        if hasattr(source, "model_dump"):
+            source = source.model_dump()
+        elif dataclasses.is_dataclass(source) and not isinstance(source, type):
+            source = dataclasses.asdict(source)
+
+        if isinstance(source, Mapping):
+            return [_cell_text(source.get(col.field)) for col in self.columns]
+        if isinstance(source, Sequence) and not isinstance(source, str):
+            if len(source) != len(self.columns):
+                raise ValueError(
+                    f"row {index} has {len(source)} cells, "
+                    f"expected {len(self.columns)}"
+                )
+            return [_cell_text(value) for value in source]
+        raise TypeError(f"row {index}: unsupported row type {type(source).__name__}")
+
+    def render(self) -> str:
+        """Render the table; every line has the same display width."""
+
+        def _fit(cell: str, cap: int | None) -> list[str]:
+            cell = _sanitize(cell)
+            if cap is None or display_width(cell) <= cap:
+                return [cell]
+            if self.overflow == "wrap":
+                return wrap(cell, cap)
+            return [truncate(cell, cap)]
+
+        caps = [col.max_width for col in self.columns]
+        fitted_header = [_fit(col.title, col.max_width) for col in self.columns]
+        fitted_rows = [
+            [_fit(c, cap) for c, cap in zip(row, caps)] for row in self._rows
+        ]
+
+        widths = [
+            max(
+                display_width(line)
+                for cell in [fitted_header[i], *(r[i] for r in fitted_rows)]
+                for line in cell
+            )
+            for i in range(len(self.columns))
+        ]
+
+        def _emit(cells: list[list[str]], *, header: bool = False) -> list[str]:
+            height = max(len(c) for c in cells)
+            return [
+                " | ".join(
+                    pad(
+                        cell[n] if n < len(cell) else "",
+                        widths[i],
+                        "left" if header else self.columns[i].align,
+                    )
+                    for i, cell in enumerate(cells)
+                )
+                for n in range(height)
+            ]
+
+        separator = "-+-".join("-" * w for w in widths)
+        lines = [*_emit(fitted_header, header=True), separator]
+        for row in fitted_rows:
+            lines.extend(_emit(row))
+        return "\n".join(lines)
+
+    __str__ = render
+
+
+def _cell_text(value: Any) -> str:
+    return "" if value is None else str(value)
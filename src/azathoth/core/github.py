@@ -0,0 +1,338 @@
+"""azathoth.core.github — releases and pull requests through the GitHub REST API.
+
+Public surface:
+  - ``github_token()``                  → API token from config or env, or ``None``
+  - ``GitHubRepo``                      — owner/name on a host, with its API root
+  - ``github_repo(cwd)``                → ``GitHubRepo`` of ``origin``, or ``None``
+  - ``api_release(repo, tag, …)``       → ``(html_url, [asset urls])``
+  - ``create_pull_request(title, …)``   → ``PullRequest``
+  - ``github_api(method, path, cwd, …)`` → JSON body of a repository endpoint
+  - ``api_plan(method, path, cwd)``     → how a dry run shows that call
+
+``create_release`` (``core.release``) and ``create_pull_request`` talk to the
+API when a token is available — ``github_token`` in config, else the
+``GITHUB_TOKEN`` or ``GH_TOKEN`` environment variable — and ``origin`` points
+at GitHub: github.com, or an Enterprise host once ``github_api_url`` names
+its API root.  Only the user config or the environment can set that, and
+the token never goes to an API root inferred from a remote, so neither a
+project nor a remote named ``github.<anything>`` can redirect it.
+Without a token they fall back to the ``gh`` CLI, so machines where ``gh``
+is already authenticated keep working unchanged.  Dry runs list the API requests
+(``POST https://api.github.com/repos/…``) or ``gh`` commands they would
+make.
+
+``github_api`` is the same choice for any endpoint under the repository
+(``pulls``, ``issues/12/labels`` …): the REST API with a token, else
+``gh api``, which resolves ``{owner}/{repo}`` from the checkout itself.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import tempfile
+from collections.abc import Sequence
+from pathlib import Path
+from typing import Any, Literal
+
+import httpx
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.workflow import (
+    _run_git,
+    ensure_revision,
+    format_command,
+    run_command,
+)
+
+GITHUB_API = "https://api.github.com"
+_TOKEN_ENV = ("GITHUB_TOKEN", "GH_TOKEN")
+_USER_AGENT = "azathoth (https://github.com/Yrrrrrf/azathoth)"
+
+
+class GitHubRepo(BaseModel, frozen=True):
+    host: str
+    owner: str
+    name: str
+
+    @property
+    def api_url(self) -> str:
+        """``.../repos/<owner>/<name>`` under ``github_api_url`` or github.com."""
+        root = get_config().github_api_url or GITHUB_API
+        return f"{root.rstrip('/')}/repos/{self.owner}/{self.name}"
+
+
+class PullRequest(BaseModel, frozen=True):
+    """Outcome (or dry-run plan) of opening a pull request."""
+
+    title: str
+    head: str
+    base: str | None = Field(None, description="None: the repo's default branch")
+    draft: bool = False
+    number: int | None = None
+    url: str | None = None
+    via: Literal["api", "gh"]
+    created: bool = Field(description="False for a dry run")
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+def github_token() -> str | None:
+    """Token for the REST API: config first, then the environment."""
+    configured = get_config().github_token
+    if configured is not None and configured.get_secret_value():
+        return configured.get_secret_value()
+    return next((os.environ[k] for k in _TOKEN_ENV if os.environ.get(k)), None)
+
+
+async def github_repo(cwd: str | None = None) -> GitHubRepo | None:
+    """The GitHub repository ``origin`` points at, or ``None``.
+
+    An Enterprise host counts only when ``github_api_url`` is configured.
+    """
+    # late import — release imports this module
+    from azathoth.core.release import detect_release_backend, web_url
+
+    code, url, _ = await _run_git(["remote", "get-url", "origin"], cwd=cwd)
+    if code != 0 or not url:
+        return None
+    backend = get_config().release_backend
+    if detect_release_backend(url) != "github" and backend != "github":
+        return None
+    host, _, path = web_url(url).removeprefix("https://").partition("/")
+    owner, _, name = path.partition("/")
+    if not owner or not name or "/" in name:
+        return None
+    if host != "github.com" and not get_config().github_api_url:
+        return None
+    return GitHubRepo(host=host, owner=owner, name=name)
+
+
+async def _request(method: str, url: str, token: str, **kwargs: Any) -> Any:
+    """One API call; returns the JSON body.
+
+    Raises:
+        WorkflowError: If the API cannot be reached or answers with an error.
+    """
+    headers = {
+        "Accept": "application/vnd.github+json",
+        "Authorization": f"Bearer {token}",
+        "User-Agent": _USER_AGENT,
+        "X-GitHub-Api-Version": "2022-11-28",
+        **kwargs.pop("headers", {}),
+    }
+    try:
+        async with httpx.AsyncClient(headers=headers, timeout=60) as client:
+            resp = await client.request(method, url, **kwargs)
+    except httpx.HTTPError as exc:
+        raise WorkflowError(f"Could not reach the GitHub API: {exc}") from exc
+    if resp.status_code >= 400:
+        try:
+            detail = resp.json().get("message", resp.text)
+        except ValueError:
+            detail = resp.text
+        raise WorkflowError(
+            f"GitHub API {method} {url} answered {resp.status_code}: {detail}"
+        )
+    return resp.json() if resp.content else {}
+
+
+async def api_plan(method: str, path: str, cwd: str | None = None) -> str:
+    """The request (or ``gh api`` command) ``github_api`` would make."""
+    token = github_token()
+    repo = await github_repo(cwd) if token else None
+    if repo is not None:
+        return f"{method} {repo.api_url}/{path}"
+    return format_command(_gh_api(method, path))
+
+
+def _gh_api(method: str, path: str) -> list[str]:
+    return ["gh", "api", "--method", method, f"repos/{{owner}}/{{repo}}/{path}"]
+
+
+async def github_api(
+    method: str,
+    path: str,
+    cwd: str | None = None,
+    body: dict[str, Any] | None = None,
+) -> Any:
+    """Call the repository endpoint *path* (``pulls/7/reviews``); return its JSON.
+
+    Raises:
+        WorkflowError: If neither a token nor ``gh`` can make the call, or
+            GitHub answers with an error.
+    """
+    token = github_token()
+    repo = await github_repo(cwd) if token else None
+    if repo is not None and token is not None:
+        kwargs = {"json": body} if body is not None else {}
+        return await _request(method, f"{repo.api_url}/{path}", token, **kwargs)
+
+    cmd = _gh_api(method, path)
+    with tempfile.TemporaryDirectory() as tmp:
+        if body is not None:
+            payload = Path(tmp) / "body.json"
+            payload.write_text(json.dumps(body), encoding="utf-8")
+            cmd += ["--input", str(payload)]
+        code, out, err = await run_command(cmd, cwd=cwd)
+    if code != 0:
+        raise WorkflowError(f"gh api {method} {path} failed: {err or out}")
+    try:
+        return json.loads(out) if out.strip() else {}
+    except ValueError as exc:
+        raise WorkflowError(f"gh api {method} {path} returned non-JSON output") from exc
+
+
+def _upload_url(template: str, path: Path) -> str:
+    """The release's ``upload_url`` (``…/assets{?name,label}``) for *path*."""
+    return f"{template.split('{', 1)[0]}?name={path.name}"
+
+
+async def api_release(
+    repo: GitHubRepo,
+    tag: str,
+    notes: str,
+    title: str,
+    prerelease: bool,
+    token: str,
+    assets: Sequence[Path] = (),
+) -> tuple[str, list[str]]:
+    """Publish a release for the pushed *tag* and upload *assets* to it.
+
+    Returns the release page URL and the assets' download URLs.
+
+    Raises:
+        WorkflowError: If the release or an upload is rejected.
+    """
+    release = await _request(
+        "POST",
+        f"{repo.api_url}/releases",
+        token,
+        json={
+            "tag_name": tag,
+            "name": title,
+            "body": notes,
+            "prerelease": prerelease,
+        },
+    )
+    uploaded = []
+    for path in assets:
+        asset = await _request(
+            "POST",
+            _upload_url(release["upload_url"], path),
+            token,
+            content=path.read_bytes(),
+            headers={"Content-Type": "application/octet-stream"},
+        )
+        uploaded.append(asset.get("browser_download_url", ""))
+    return release.get("html_url", ""), uploaded
+
+
+# ── Pull requests ────────────────────────────────────────────────────────────
+
+
+async def _current_branch(cwd: str | None) -> str:
+    code, branch, err = await _run_git(["symbolic-ref", "--short", "HEAD"], cwd=cwd)
+    if code != 0:
+        raise WorkflowError(f"HEAD is not on a branch: {err or branch}")
+    return branch
+
+
+async def _push(push_cmd: list[str], cwd: str | None) -> None:
+    code, out, err = await _run_git(push_cmd, cwd=cwd)
+    if code != 0:
+        raise WorkflowError(f"Pushing {push_cmd[-1]} failed: {err or out}")
+
+
+async def create_pull_request(
+    title: str,
+    body: str = "",
+    base: str | None = None,
+    head: str | None = None,
+    draft: bool = False,
+    dry_run: bool = False,
+    cwd: str | None = None,
+) -> PullRequest:
+    """Push *head* (default: the current branch) and open a pull request.
+
+    Uses the REST API when a token is available and ``origin`` is on
+    GitHub, else ``gh pr create``.  *base* defaults to the repository's
+    default branch.
+
+    Raises:
+        WorkflowError: If HEAD is detached, *head* looks like an option, the
+            push fails or GitHub rejects the pull request.
+    """
+    head = ensure_revision(head) if head else await _current_branch(cwd)
+    push_cmd = ["push", "--set-upstream", "--", "origin", head]
+    token = github_token()
+    repo = await github_repo(cwd) if token else None
+
+    if repo is not None and token is not None:
+        endpoint = f"{repo.api_url}/pulls"
+        if dry_run:
+            return PullRequest(
+                title=title,
+                head=head,
+                base=base,
+                draft=draft,
+                via="api",
+                created=False,
+                commands=[format_command(["git", *push_cmd]), f"POST {endpoint}"],
+            )
+        await _push(push_cmd, cwd)
+        if base is None:
+            base = (await _request("GET", repo.api_url, token))["default_branch"]
+        created = await _request(
+            "POST",
+            endpoint,
+            token,
+            json={
+                "title": title,
+                "body": body,
+                "head": head,
+                "base": base,
+                "draft": draft,
+            },
+        )
+        return PullRequest(
+            title=title,
+            head=head,
+            base=base,
+            draft=draft,
+            number=created.get("number"),
+            url=created.get("html_url"),
+            via="api",
+            created=True,
+        )
+
+    gh_cmd = ["gh", "pr", "create", "--title", title, "--body", body, "--head", head]
+    gh_cmd += ["--base", base] if base else []
+    gh_cmd += ["--draft"] if draft else []
+    if dry_run:
+        return PullRequest(
+            title=title,
+            head=head,
+            base=base,
+            draft=draft,
+            via="gh",
+            created=False,
+            commands=[format_command(["git", *push_cmd]), format_command(gh_cmd)],
+        )
+    await _push(push_cmd, cwd)
+    code, out, err = await run_command(gh_cmd, cwd=cwd)
+    if code != 0:
+        raise WorkflowError(f"gh pr create failed: {err or out}")
+    url = out.splitlines()[-1].strip() if out else None
+    number = url.rsplit("/", 1)[-1] if url else ""
+    return PullRequest(
+        title=title,
+        head=head,
+        base=base,
+        draft=draft,
+        number=int(number) if number.isdigit() else None,
+        url=url,
+        via="gh",
+        created=True,
+    )
@@ -0,0 +1,299 @@
+"""azathoth.core.gitlib — read-only git queries through libgit2 (pygit2).
+
+Public surface:
+  - ``open_repo(cwd)``                          → ``pygit2.Repository`` or ``None``
+  - ``read_status(repo)``                       → ``LibraryStatus``
+  - ``read_diff(repo, staged, paths)``          → ``(patch, [LibraryChange])``
+  - ``read_log(repo, rev_range, limit)``        → ``[(sha, author, date, subject)]``
+  - ``read_blame(repo, path, start, end, rev)`` → ``LibraryBlame``
+
+Status, diff, log and blame are answered in-process instead of by spawning
+``git`` and parsing its porcelain, which is faster on large repositories and
+works where no ``git`` binary is on PATH.  ``vcs_git_reads`` selects the
+path: ``auto`` uses pygit2 when it is installed (the ``pygit2`` extra),
+``pygit2`` requires it, and ``process`` always runs ``git``.  Reads also go
+to ``git`` whenever a custom ``CommandRunner`` is active, since that runner
+may execute commands somewhere else.
+
+``open_repo`` returning ``None`` means "use git".  Every reader may also
+return ``None`` for a case it does not reproduce exactly — an unborn HEAD,
+merge conflicts, a pathspec with wildcards, a coloured diff, a symmetric
+range, an invalid revision — and the caller then asks ``git``, which stays
+the reference.  Results use git's shapes (status letters, ISO 8601 author
+dates, patch text), so callers build the same models from either path.
+"""
+
+from __future__ import annotations
+
+import os
+from dataclasses import dataclass, field
+from datetime import datetime, timedelta, timezone
+from typing import TYPE_CHECKING
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.repos import repo_dir
+from azathoth.core.runner import SubprocessRunner, current_runner
+
+if TYPE_CHECKING:
+    import pygit2
+
+try:
+    import pygit2 as _pygit2
+    from pygit2.enums import FileStatus, RevSpecFlag, SortMode
+except ImportError:  # optional extra
+    _pygit2 = None
+
+_GLOB_CHARS = frozenset("*?[")
+
+
+@dataclass(frozen=True)
+class LibraryChange:
+    path: str
+    status: str
+    insertions: int = 0
+    deletions: int = 0
+    binary: bool = False
+
+
+@dataclass(frozen=True)
+class LibraryStatus:
+    branch: str
+    staged: list[LibraryChange] = field(default_factory=list)
+    unstaged: list[LibraryChange] = field(default_factory=list)
+    untracked: list[str] = field(default_factory=list)
+
+
+@dataclass(frozen=True)
+class LibraryBlame:
+    #: (line number, sha, content) for each line of the range
+    lines: list[tuple[int, str, str]]
+    #: sha → (author, ISO 8601 author date, subject)
+    commits: dict[str, tuple[str, str, str]]
+
+
+def open_repo(cwd: str | None = None) -> pygit2.Repository | None:
+    """The repository at *cwd* (or the scoped one) when pygit2 should read it.
+
+    Raises:
+        WorkflowError: If ``vcs_git_reads = "pygit2"`` but pygit2 is missing.
+    """
+    mode = get_config().vcs_git_reads
+    if mode == "process" or not isinstance(current_runner(), SubprocessRunner):
+        return None
+    if _pygit2 is None:
+        if mode == "pygit2":
+            raise WorkflowError(
+                "vcs_git_reads is 'pygit2' but pygit2 is not installed "
+                "(install the 'pygit2' extra)."
+            )
+        return None
+    path = _pygit2.discover_repository(cwd or repo_dir() or os.getcwd())
+    if path is None:
+        return None
+    repo = _pygit2.Repository(path)
+    return None if repo.is_bare else repo
+
+
+def _iso(time: int, offset_minutes: int) -> str:
+    tz = timezone(timedelta(minutes=offset_minutes))
+    return datetime.fromtimestamp(time, tz).isoformat()
+
+
+def _subject(message: str) -> str:
+    return message.strip().split("\n", 1)[0].strip()
+
+
+# ── Status and diff ──────────────────────────────────────────────────────────
+
+
+def _index_letter(flags: int) -> str:
+    for flag, letter in (
+        (FileStatus.INDEX_NEW, "A"),
+        (FileStatus.INDEX_MODIFIED, "M"),
+        (FileStatus.INDEX_DELETED, "D"),
+        (FileStatus.INDEX_RENAMED, "R"),
+        (FileStatus.INDEX_TYPECHANGE, "T"),
+    ):
+        if flags & flag:
+            return letter
+    return "."
+
+
+def _worktree_letter(flags: int) -> str:
+    for flag, letter in (
+        (FileStatus.WT_MODIFIED, "M"),
+        (FileStatus.WT_DELETED, "D"),
+        (FileStatus.WT_RENAMED, "R"),
+        (FileStatus.WT_TYPECHANGE, "T"),
+    ):
+        if flags & flag:
+            return letter
+    return "."
+
+
+def _changes(diff: pygit2.Diff) -> dict[str, tuple[int, int, bool]]:
+    """{path: (insertions, deletions, binary)}, like ``git diff --numstat``."""
+    counts = {}
+    for patch in diff:
+        if patch is None:
+            continue
+        delta = patch.delta
+        path = delta.new_file.path or delta.old_file.path
+        if delta.is_binary:
+            counts[path] = (0, 0, True)
+        else:
+            _, insertions, deletions = patch.line_stats
+            counts[path] = (insertions, deletions, False)
+    return counts
+
+
+def _staged_diff(repo: pygit2.Repository) -> pygit2.Diff:
+    return repo.index.diff_to_tree(repo.head.peel(_pygit2.Commit).tree)
+
+
+def read_status(repo: pygit2.Repository) -> LibraryStatus | None:
+    """``git status --porcelain=v2 --untracked-files=all`` plus line counts."""
+    if repo.head_is_unborn:
+        return None
+    entries = repo.status(untracked_files="all")
+    # Unmerged entries carry both sides' states (UU, AA, DU …); leave to git.
+    if any(flags & FileStatus.CONFLICTED for flags in entries.values()):
+        return None
+    branch = "HEAD" if repo.head_is_detached else repo.head.shorthand
+    staged_counts = _changes(_staged_diff(repo))
+    unstaged_counts = _changes(repo.index.diff_to_workdir())
+
+    status = LibraryStatus(branch=branch)
+    for path, flags in sorted(entries.items()):
+        if flags == FileStatus.WT_NEW:
+            status.untracked.append(path)
+            continue
+        x, y = _index_letter(flags), _worktree_letter(flags)
+        if x != ".":
+            ins, dels, binary = staged_counts.get(path, (0, 0, False))
+            status.staged.append(LibraryChange(path, x, ins, dels, binary))
+        if y != ".":
+            ins, dels, binary = unstaged_counts.get(path, (0, 0, False))
+            status.unstaged.append(LibraryChange(path, y, ins, dels, binary))
+    return status
+
+
+def _wants_color(repo: pygit2.Repository) -> bool:
+    """Whether the user's config asks git to colour diffs even when piped."""
+    for key in ("color.diff", "color.ui"):
+        try:
+            return repo.config[key] == "always"
+        except KeyError:
+            continue
+    return False
+
+
+def _selected(path: str, paths: list[str]) -> bool:
+    return any(path == p or path.startswith(p.rstrip("/") + "/") for p in paths)
+
+
+def read_diff(
+    repo: pygit2.Repository, staged: bool, paths: list[str] | None = None
+) -> tuple[str, list[LibraryChange]] | None:
+    """Patch text and per-file changes of ``git diff [--staged] [-- paths]``."""
+    if repo.head_is_unborn and staged:
+        return None
+    if _wants_color(repo) or any(_GLOB_CHARS & set(p) for p in paths or []):
+        return None
+    diff = _staged_diff(repo) if staged else repo.index.diff_to_workdir()
+    texts: list[str] = []
+    changes: list[LibraryChange] = []
+    for patch in diff:
+        if patch is None:
+            continue
+        delta = patch.delta
+        path = delta.new_file.path or delta.old_file.path
+        if paths and not _selected(path, paths):
+            continue
+        texts.append(patch.text or "")
+        _, insertions, deletions = (0, 0, 0) if delta.is_binary else patch.line_stats
+        changes.append(
+            LibraryChange(
+                path, delta.status_char(), insertions, deletions, delta.is_binary
+            )
+        )
+    return "".join(texts).strip(), changes
+
+
+# ── Log and blame ────────────────────────────────────────────────────────────
+
+
+def read_log(
+    repo: pygit2.Repository, rev_range: str | None, limit: int
+) -> list[tuple[str, str, str, str]] | None:
+    """``git log --max-count=limit [rev_range]``, newest first."""
+    try:
+        if rev_range is None:
+            if repo.head_is_unborn:
+                return None
+            start, hide = repo.head.target, None
+        else:
+            spec = repo.revparse(rev_range)
+            if spec.flags & RevSpecFlag.MERGE_BASE:
+                return None
+            if spec.flags & RevSpecFlag.RANGE:
+                start, hide = spec.to_object.id, spec.from_object.id
+            else:
+                start, hide = spec.from_object.peel(_pygit2.Commit).id, None
+        walker = repo.walk(start, SortMode.TIME)
+        if hide is not None:
+            walker.hide(hide)
+    except (KeyError, ValueError, _pygit2.GitError):
+        return None
+
+    entries = []
+    for commit in walker:
+        if len(entries) >= limit:
+            break
+        author = commit.author
+        entries.append(
+            (
+                str(commit.id),
+                author.name,
+                _iso(author.time, author.offset),
+                _subject(commit.message),
+            )
+        )
+    return entries
+
+
+def read_blame(
+    repo: pygit2.Repository, path: str, start: int, end: int, rev: str
+) -> LibraryBlame | None:
+    """``git blame -L start,end rev -- path`` as lines and their commits."""
+    try:
+        commit = repo.revparse_single(rev).peel(_pygit2.Commit)
+        blob = commit.tree / path
+        content = blob.data.decode("utf-8", errors="replace").splitlines()
+        if end > len(content):
+            return None
+        blame = repo.blame(
+            path, newest_commit=commit.id, min_line=start, max_line=end
+        )
+    except (KeyError, ValueError, _pygit2.GitError):
+        return None
+
+    lines: list[tuple[int, str, str]] = []
+    commits: dict[str, tuple[str, str, str]] = {}
+    for hunk in blame:
+        sha = str(hunk.final_commit_id)
+        first = hunk.final_start_line_number
+        for number in range(first, first + hunk.lines_in_hunk):
+            if start <= number <= end:
+                lines.append((number, sha, content[number - 1]))
+        if sha not in commits:
+            found = repo[hunk.final_commit_id]
+            author = found.author
+            commits[sha] = (
+                author.name,
+                _iso(author.time, author.offset),
+                _subject(found.message),
+            )
+    lines.sort()
+    return LibraryBlame(lines=lines, commits=commits)
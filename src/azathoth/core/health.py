@@ -0,0 +1,133 @@
+"""azathoth.core.health — what a server process sees of its environment.
+
+Public surface:
+  - ``BINARIES``                              — external programs checked
+  - ``check_health(server, started_at, cwd)`` → ``HealthReport``
+
+The report is the first thing to look at when a workflow fails for no
+visible reason: which azathoth and Python are running, for how long,
+from which directory, whether that is inside a git repository (and on
+which branch), whether ``git`` and ``gh`` are on ``PATH``, and the SHA-256
+of every directive file in use, so a user override or a stale install
+shows up as a checksum that differs from another machine's.  ``problems``
+lists what will make tools fail.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import platform
+import shutil
+from datetime import datetime, timezone
+from importlib.metadata import PackageNotFoundError, version
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+from azathoth.core.directives import BUILTIN_DIR, SUFFIXES
+from azathoth.core.github import github_token
+from azathoth.core.repo_config import find_repo_root
+from azathoth.core.workflow import run_command
+
+BINARIES = ("git", "gh")
+
+
+class BinaryStatus(BaseModel, frozen=True):
+    name: str
+    path: str | None = Field(None, description="None when not on PATH")
+    version: str | None = None
+
+
+class DirectiveChecksum(BaseModel, frozen=True):
+    name: str
+    source: Literal["builtin", "user"]
+    sha256: str
+
+
+class HealthReport(BaseModel, frozen=True):
+    server: str
+    version: str = Field(description="Installed azathoth version")
+    python: str
+    platform: str
+    uptime_seconds: float
+    cwd: str
+    repo_root: str | None = Field(None, description="None outside a git repository")
+    branch: str | None = Field(None, description="None when detached or no repo")
+    binaries: list[BinaryStatus] = Field(default_factory=list)
+    directives: list[DirectiveChecksum] = Field(default_factory=list)
+    problems: list[str] = Field(
+        default_factory=list, description="Empty when nothing is known to be wrong"
+    )
+
+
+def _package_version() -> str:
+    try:
+        return version("azathoth")
+    except PackageNotFoundError:
+        return "unknown"
+
+
+async def _binary(name: str) -> BinaryStatus:
+    path = shutil.which(name)
+    if path is None:
+        return BinaryStatus(name=name)
+    code, out, _ = await run_command([name, "--version"])
+    first = out.strip().splitlines()[0] if code == 0 and out.strip() else None
+    return BinaryStatus(name=name, path=path, version=first)
+
+
+def _directive_checksums() -> list[DirectiveChecksum]:
+    found: list[DirectiveChecksum] = []
+    sources = ((BUILTIN_DIR, "builtin"), (get_config().directives_dir, "user"))
+    for directory, source in sources:
+        for suffix in SUFFIXES:
+            for path in sorted(directory.glob(f"*{suffix}")):
+                digest = hashlib.sha256(path.read_bytes()).hexdigest()
+                found.append(
+                    DirectiveChecksum(name=path.stem, source=source, sha256=digest)
+                )
+    return sorted(found, key=lambda d: (d.name, d.source))
+
+
+async def check_health(
+    server: str, started_at: datetime, cwd: str | None = None
+) -> HealthReport:
+    """Report on the environment of *server*, running since *started_at*."""
+    here = Path(cwd or ".").resolve()
+    root = find_repo_root(cwd)
+    in_repo = (root / ".git").exists()
+    branch = None
+    if in_repo:
+        code, out, _ = await run_command(
+            ["git", "branch", "--show-current"], cwd=str(root)
+        )
+        branch = (out.strip() or None) if code == 0 else None
+    binaries = [await _binary(name) for name in BINARIES]
+
+    problems = []
+    if binaries[0].path is None:
+        problems.append("git is not on PATH; every workflow tool needs it.")
+    if not in_repo:
+        problems.append(f"{here} is not inside a git repository.")
+    if binaries[1].path is None and github_token() is None:
+        problems.append(
+            "gh is not on PATH and no GitHub token is set; GitHub tools "
+            "(releases, pull requests, issues) will fail."
+        )
+    return HealthReport(
+        server=server,
+        version=_package_version(),
+        python=platform.python_version(),
+        platform=platform.platform(),
+        uptime_seconds=round(
+            (datetime.now(timezone.utc) - started_at).total_seconds(), 1
+        ),
+        cwd=str(here),
+        repo_root=str(root) if in_repo else None,
+        branch=branch,
+        binaries=binaries,
+        directives=_directive_checksums(),
+        problems=problems,
+    )
@@ -0,0 +1,227 @@
+"""azathoth.core.history — who changed a file, when, and why.
+
+Public surface:
+  - ``blame_range(path, start, end, rev, cwd)``     → ``BlameReport``
+  - ``file_history(path, limit, include_diffs, …)``  → ``FileHistory``
+
+Read-only answers to "who wrote this and why" without shell access.
+``blame_range`` attributes each line of a range to the commit that last
+touched it and lists those commits once, with their subjects.
+``file_history`` follows the file across renames and gives each commit's
+line counts for it; patches are only included on request, since a long
+history of diffs would swamp the caller.
+"""
+
+from __future__ import annotations
+
+import re
+from datetime import datetime, timedelta, timezone
+
+from pydantic import BaseModel, Field
+
+from azathoth.core import gitlib
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.workflow import _run_git, ensure_revision
+
+_BLAME_HEADER = re.compile(r"^([0-9a-f]{40}) \d+ (\d+)(?: \d+)?$")
+# "src/{old => new}/x.py" or "old.py => new.py" in --numstat output.
+_BRACED_RENAME = re.compile(r"^(.*)\{(.*) => (.*)\}(.*)$")
+_UNCOMMITTED = "0" * 40
+
+
+class BlameLine(BaseModel, frozen=True):
+    line: int
+    sha: str
+    content: str
+
+
+class BlameCommit(BaseModel, frozen=True):
+    sha: str
+    author: str
+    date: str = Field(description="ISO 8601 author date")
+    subject: str
+    lines: int = Field(description="Lines of the range it last touched")
+
+
+class BlameReport(BaseModel, frozen=True):
+    path: str
+    rev: str
+    start: int
+    end: int
+    lines: list[BlameLine]
+    commits: list[BlameCommit] = Field(description="In order of first appearance")
+
+
+class FileCommit(BaseModel, frozen=True):
+    sha: str
+    author: str
+    date: str
+    subject: str
+    path: str = Field(description="The file's path in this commit")
+    insertions: int = 0
+    deletions: int = 0
+    diff: str | None = Field(None, description="Only with include_diffs")
+
+
+class FileHistory(BaseModel, frozen=True):
+    path: str
+    commits: list[FileCommit] = Field(description="Newest first")
+
+
+def _iso(epoch: str, tz: str) -> str:
+    """``author-time`` / ``author-tz`` from blame porcelain as ISO 8601."""
+    sign = -1 if tz.startswith("-") else 1
+    offset = timedelta(hours=int(tz[1:3]), minutes=int(tz[3:5])) * sign
+    return datetime.fromtimestamp(int(epoch), timezone(offset)).isoformat()
+
+
+def _library_blame(
+    read: gitlib.LibraryBlame, path: str, rev: str, start: int, end: int
+) -> BlameReport:
+    lines = [
+        BlameLine(line=number, sha=sha, content=content)
+        for number, sha, content in read.lines
+    ]
+    counts: dict[str, int] = {}
+    for entry in lines:
+        counts[entry.sha] = counts.get(entry.sha, 0) + 1
+    commits = [
+        BlameCommit(
+            sha=sha,
+            author=read.commits[sha][0],
+            date=read.commits[sha][1],
+            subject=read.commits[sha][2],
+            lines=count,
+        )
+        for sha, count in counts.items()
+    ]
+    return BlameReport(
+        path=path, rev=rev, start=start, end=end, lines=lines, commits=commits
+    )
+
+
+async def blame_range(
+    path: str,
+    start: int,
+    end: int,
+    rev: str = "HEAD",
+    cwd: str | None = None,
+) -> BlameReport:
+    """Attribute lines *start*..*end* (1-based, inclusive) of *path* at *rev*.
+
+    Raises:
+        WorkflowError: If the range or *rev* is invalid or git cannot blame
+            the file.
+    """
+    if start < 1 or end < start:
+        raise WorkflowError(f"Invalid line range {start}-{end}.")
+    rev = ensure_revision(rev)
+    if (repo := gitlib.open_repo(cwd)) is not None:
+        if (read := gitlib.read_blame(repo, path, start, end, rev)) is not None:
+            return _library_blame(read, path, rev, start, end)
+    code, out, err = await _run_git(
+        ["blame", "--porcelain", "-L", f"{start},{end}", rev, "--", path], cwd=cwd
+    )
+    if code != 0:
+        raise WorkflowError(f"git blame failed: {err or out}")
+
+    lines: list[BlameLine] = []
+    info: dict[str, dict[str, str]] = {}
+    sha, line_no = "", 0
+    for raw in out.splitlines():
+        if raw.startswith("\t"):
+            lines.append(BlameLine(line=line_no, sha=sha, content=raw[1:]))
+        elif header := _BLAME_HEADER.match(raw):
+            sha, line_no = header.group(1), int(header.group(2))
+            info.setdefault(sha, {})
+        else:
+            key, _, value = raw.partition(" ")
+            info[sha][key] = value
+
+    counts: dict[str, int] = {}
+    for entry in lines:
+        counts[entry.sha] = counts.get(entry.sha, 0) + 1
+    commits = [
+        BlameCommit(
+            sha=commit,
+            author=info[commit].get("author", ""),
+            date=_iso(info[commit]["author-time"], info[commit]["author-tz"])
+            if "author-time" in info[commit]
+            else "",
+            subject="(uncommitted)"
+            if commit == _UNCOMMITTED
+            else info[commit].get("summary", ""),
+            lines=count,
+        )
+        for commit, count in counts.items()
+    ]
+    return BlameReport(
+        path=path, rev=rev, start=start, end=end, lines=lines, commits=commits
+    )
+
+
+def _renamed_to(path: str) -> str:
+    """The new path of a ``--numstat`` rename entry (or *path* unchanged)."""
+    if braced := _BRACED_RENAME.match(path):
+        prefix, _, new, suffix = braced.groups()
+        return re.sub("/+", "/", f"{prefix}{new}{suffix}")
+    return path.split(" => ")[-1]
+
+
+async def file_history(
+    path: str,
+    limit: int = 20,
+    include_diffs: bool = False,
+    cwd: str | None = None,
+) -> FileHistory:
+    """Commits that changed *path* (following renames), newest first.
+
+    Raises:
+        WorkflowError: If git cannot read the file's history.
+    """
+    code, out, err = await _run_git(
+        [
+            "log",
+            "--follow",
+            f"--max-count={limit}",
+            "--numstat",
+            "--format=%x1e%H%x1f%an%x1f%aI%x1f%s",
+            "--",
+            path,
+        ],
+        cwd=cwd,
+    )
+    if code != 0:
+        raise WorkflowError(f"git log failed: {err or out}")
+
+    commits: list[FileCommit] = []
+    for record in out.split("\x1e"):
+        header, _, stats = record.strip().partition("\n")
+        if not header:
+            continue
+        sha, author, date, subject = [*header.split("\x1f"), "", "", ""][:4]
+        insertions = deletions = 0
+        changed = path
+        for line in stats.splitlines():
+            added, removed, name = [*line.split("\t", 2), "", ""][:3]
+            insertions += int(added) if added.isdigit() else 0
+            deletions += int(removed) if removed.isdigit() else 0
+            changed = _renamed_to(name) or changed
+        diff = None
+        if include_diffs:
+            _, diff, _ = await _run_git(
+                ["show", "--format=", "-M", sha, "--", changed], cwd=cwd
+            )
+        commits.append(
+            FileCommit(
+                sha=sha,
+                author=author,
+                date=date,
+                subject=subject,
+                path=changed,
+                insertions=insertions,
+                deletions=deletions,
+                diff=diff,
+            )
+        )
+    return FileHistory(path=path, commits=commits)
@@ -0,0 +1,169 @@
+"""azathoth.core.hooks — run a repository's pre-commit hooks ahead of a commit.
+
+Public surface:
+  - ``detect_hooks(cwd)``                → ``HookSetup`` or ``None``
+  - ``parse_pre_commit(output)``         → ``list[HookOutcome]``
+  - ``run_hooks(setup, paths, cwd)``     → ``HookRun``
+
+Two setups are recognised: the pre-commit framework (``.pre-commit-config.yaml``
+at the repo root, run as ``pre-commit run``) and an executable ``pre-commit``
+script in ``core.hooksPath`` or ``.git/hooks``.  Running them before the
+commit turns git's bare "hook failed" into the failing hook's name and
+output; ``git commit`` itself still runs its installed hooks (commit-msg
+included) unless the caller asks for ``--no-verify``.
+"""
+
+from __future__ import annotations
+
+import os
+import re
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.repo_config import find_repo_root
+from azathoth.core.repos import repo_dir
+from azathoth.core.workflow import _run_git, format_command, run_command
+
+PRE_COMMIT_CONFIG = ".pre-commit-config.yaml"
+
+HookKind = Literal["pre-commit", "script"]
+HookStatus = Literal["passed", "failed", "skipped"]
+
+# ``ruff.........Failed`` / ``mypy....(no files to check)Skipped``
+_STATUS_LINE = re.compile(
+    r"^(?P<name>\S.*?)\.{2,}(?:\([^)]*\))?(?P<status>Passed|Failed|Skipped)$"
+)
+_STATUSES: dict[str, HookStatus] = {
+    "Passed": "passed",
+    "Failed": "failed",
+    "Skipped": "skipped",
+}
+
+
+class HookSetup(BaseModel, frozen=True):
+    kind: HookKind
+    path: str = Field(description="pre-commit config or hook script")
+    command: list[str]
+
+
+class HookOutcome(BaseModel, frozen=True):
+    name: str
+    status: HookStatus
+    output: str = ""
+
+
+class HookRun(BaseModel, frozen=True):
+    setup: HookSetup
+    success: bool
+    hooks: list[HookOutcome] = Field(default_factory=list)
+    output: str = Field("", description="Combined output when no hook is named")
+
+    @property
+    def failed(self) -> list[HookOutcome]:
+        return [h for h in self.hooks if h.status == "failed"]
+
+    def render_failure(self) -> str:
+        """Which hooks failed and what they printed."""
+        if not self.failed:
+            return f"Pre-commit hooks failed ({self.setup.path}):\n{self.output}"
+        names = ", ".join(h.name for h in self.failed)
+        sections = [f"Pre-commit hook failed: {names}"]
+        sections += [f"── {h.name} ──\n{h.output}".rstrip() for h in self.failed]
+        return "\n".join(sections)
+
+
+# ── Detection ─────────────────────────────────────────────────────────────────
+
+
+async def _hooks_dir(root: Path, cwd: str | None) -> Path | None:
+    code, out, _ = await _run_git(["config", "--get", "core.hooksPath"], cwd=cwd)
+    if code == 0 and out:
+        return root / Path(out).expanduser()
+    code, out, _ = await _run_git(["rev-parse", "--git-path", "hooks"], cwd=cwd)
+    if code != 0 or not out:
+        return None
+    return Path(cwd or repo_dir() or ".").resolve() / out
+
+
+async def detect_hooks(cwd: str | None = None) -> HookSetup | None:
+    """The pre-commit setup of the repository at *cwd*, if it has one."""
+    root = find_repo_root(cwd)
+    config = root / PRE_COMMIT_CONFIG
+    if config.is_file():
+        return HookSetup(
+            kind="pre-commit",
+            path=PRE_COMMIT_CONFIG,
+            command=["pre-commit", "run", "--color", "never"],
+        )
+    hooks_dir = await _hooks_dir(root, cwd)
+    script = hooks_dir / "pre-commit" if hooks_dir else None
+    if script and script.is_file() and os.access(script, os.X_OK):
+        return HookSetup(kind="script", path=str(script), command=[str(script)])
+    return None
+
+
+# ── Running ───────────────────────────────────────────────────────────────────
+
+
+def parse_pre_commit(output: str) -> list[HookOutcome]:
+    """Per-hook results from ``pre-commit run`` output, failures with output."""
+    outcomes: list[tuple[str, HookStatus, list[str]]] = []
+    for line in output.splitlines():
+        if match := _STATUS_LINE.match(line.rstrip()):
+            status = _STATUSES[match.group("status")]
+            outcomes.append((match.group("name").strip(), status, []))
+        elif outcomes and outcomes[-1][1] == "failed":
+            outcomes[-1][2].append(line)
+    return [
+        HookOutcome(name=name, status=status, output="\n".join(lines).strip())
+        for name, status, lines in outcomes
+    ]
+
+
+def hook_command(setup: HookSetup, paths: list[str] | None = None) -> list[str]:
+    """argv running *setup* on the staged files (or only *paths*).
+
+    Raises:
+        WorkflowError: If a path starts with ``-`` and would read as an option.
+    """
+    if setup.kind == "pre-commit" and paths:
+        if options := [p for p in paths if p.startswith("-")]:
+            raise WorkflowError(
+                f"Invalid path {options[0]!r} for the hooks; "
+                "paths must not start with '-'."
+            )
+        return [*setup.command, "--files", *paths]
+    return setup.command
+
+
+async def run_hooks(
+    setup: HookSetup, paths: list[str] | None = None, cwd: str | None = None
+) -> HookRun:
+    """Run *setup* from the repo root and report each hook's outcome.
+
+    Raises:
+        WorkflowError: If a path in *paths* starts with ``-``.
+    """
+    argv = hook_command(setup, paths)
+    code, out, err = await run_command(argv, cwd=str(find_repo_root(cwd)))
+    output = "\n".join(part for part in (out, err) if part)
+    if code == 127 and setup.kind == "pre-commit":
+        output = (
+            f"`{format_command(argv)}` could not run — is pre-commit installed? "
+            f"({output})"
+        )
+    hooks = (
+        parse_pre_commit(out)
+        if setup.kind == "pre-commit"
+        else [
+            HookOutcome(
+                name="pre-commit",
+                status="passed" if code == 0 else "failed",
+                output=output,
+            )
+        ]
+    )
+    return HookRun(setup=setup, success=code == 0, hooks=hooks, output=output)
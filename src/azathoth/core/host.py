@@ -0,0 +1,91 @@
+"""azathoth.core.host — what differs between POSIX and Windows hosts.
+
+Public surface:
+  - ``IS_WINDOWS``                    — the servers run on Windows
+  - ``resolve_argv(argv)``            → argv ready for ``create_subprocess_exec``
+  - ``quote(arg)`` / ``join(argv)``   → copy-pasteable for the host's shell
+  - ``split(command)``                → argv from a configured command line
+  - ``session_kwargs()``              → spawn options giving a killable group
+  - ``kill_tree(process)``            — stop a process and its children
+  - ``sh_path(path)``                 → *path* as git's ``sh`` expects it
+
+Commands are never run through a shell, so the differences are narrow.
+On Windows ``CreateProcess`` only appends ``.exe`` to a bare program name,
+so ``npm`` or ``yarn`` (installed as ``.cmd`` shims) are located through
+``PATHEXT`` first; batch files are then started through ``cmd /c``, which
+``CreateProcess`` does itself.  Rendered commands use ``cmd``-style quoting
+there (``"C:\\Program Files\\…"``) and configured ones keep their
+backslashes.  Editors git runs (``GIT_SEQUENCE_EDITOR``) go through the
+``sh`` bundled with Git for Windows, hence forward slashes for them.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import os
+import shlex
+import shutil
+import signal
+import subprocess
+from collections.abc import Sequence
+from pathlib import Path
+from typing import Any
+
+IS_WINDOWS = os.name == "nt"
+
+
+def resolve_argv(argv: Sequence[str]) -> list[str]:
+    """*argv* with the program resolved through ``PATHEXT`` on Windows."""
+    argv = list(argv)
+    if IS_WINDOWS and argv and not Path(argv[0]).suffix:
+        if (found := shutil.which(argv[0])) is not None:
+            argv[0] = found
+    return argv
+
+
+def quote(arg: str) -> str:
+    """Quote *arg* for the host's shell (``sh`` or ``cmd``)."""
+    return subprocess.list2cmdline([arg]) if IS_WINDOWS else shlex.quote(arg)
+
+
+def join(argv: Sequence[str]) -> str:
+    """Render *argv* as one command line for the host's shell."""
+    return subprocess.list2cmdline(argv) if IS_WINDOWS else shlex.join(argv)
+
+
+def split(command: str) -> list[str]:
+    """Split a configured *command* into argv; backslashes survive on Windows."""
+    if not IS_WINDOWS:
+        return shlex.split(command)
+    lexer = shlex.shlex(command, posix=True)
+    lexer.whitespace_split = True
+    lexer.escape = ""
+    return list(lexer)
+
+
+def session_kwargs() -> dict[str, Any]:
+    """Options for ``create_subprocess_exec`` putting the child in its own group."""
+    if IS_WINDOWS:
+        return {"creationflags": subprocess.CREATE_NEW_PROCESS_GROUP}
+    return {"start_new_session": True}
+
+
+async def kill_tree(process: asyncio.subprocess.Process) -> None:
+    """Kill *process* and everything it started (``make`` and its jobs)."""
+    try:
+        if IS_WINDOWS:
+            killer = await asyncio.create_subprocess_exec(
+                *["taskkill", "/F", "/T", "/PID", str(process.pid)],
+                stdout=asyncio.subprocess.DEVNULL,
+                stderr=asyncio.subprocess.DEVNULL,
+            )
+            await killer.wait()
+        else:
+            os.killpg(process.pid, signal.SIGKILL)
+    except (ProcessLookupError, FileNotFoundError):
+        pass
+
+
+def sh_path(path: Path) -> str:
+    """*path* quoted for the ``sh`` git runs hooks and editors with."""
+    return shlex.quote(path.as_posix())
@@ -0,0 +1,195 @@
+"""azathoth.core.index — the on-disk scout index of a project.
+
+Public surface:
+  - ``INDEX_DIR``             — where the index lives, relative to the repo root
+  - ``IndexedFile``           — size, mtime and content hash of one file
+  - ``RepoIndex.open(root)``  → the index of the repository containing *root*
+  - ``RepoIndex.cached(kind, path, model, compute)`` → per-file facts, from
+    the index while the file is unchanged
+  - ``RepoIndex.lookup(kind, path, model)`` / ``RepoIndex.store(kind, entry,
+    facts)`` — the same in two steps, for facts computed asynchronously
+
+Scout tools that derive something from each file (line counts for
+``repo_stats``, outlines for ``outline``) ask the index instead of
+re-reading the tree.  The index keeps, per file, the size, ``mtime_ns`` and
+a sha256 of the content: while size and mtime match nothing is read;
+otherwise the file is hashed again, and only a changed hash invalidates what
+was derived from it.  Derived facts are stored per kind under
+``.azathoth/cache/<kind>/<hash>.json``, so identical files share one entry
+and a file reverted to earlier content finds its facts again.  Each tool
+calls ``save`` once at the end, which also forgets deleted files.  The
+directory carries its own ``.gitignore`` so nothing in it gets committed;
+``scout_index = false`` keeps everything in memory for the call.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import logging
+import os
+from collections.abc import Callable
+from pathlib import Path
+from typing import TypeVar
+
+from pydantic import BaseModel, Field, ValidationError
+
+from azathoth.config import get_config
+from azathoth.core.repo_config import find_repo_root
+
+log = logging.getLogger(__name__)
+
+INDEX_DIR = Path(".azathoth") / "cache"
+
+#: Bump when the manifest format changes so old indexes are discarded.
+_INDEX_VERSION = 1
+_MANIFEST = "index.json"
+
+T = TypeVar("T", bound=BaseModel)
+
+
+class IndexedFile(BaseModel, frozen=True):
+    path: str = Field(description="Relative to the repository root")
+    size: int
+    mtime_ns: int
+    digest: str = Field(description="sha256 of the content")
+
+
+class _Manifest(BaseModel):
+    version: int = _INDEX_VERSION
+    files: dict[str, IndexedFile] = Field(default_factory=dict)
+
+
+def _digest(data: bytes) -> str:
+    return hashlib.sha256(data, usedforsecurity=False).hexdigest()
+
+
+class RepoIndex:
+    """File metadata and derived facts of one repository, cached on disk.
+
+    ``hits`` and ``misses`` count ``cached`` answers taken from the index
+    and computed afresh, for callers that report on cache efficiency.
+    """
+
+    def __init__(self, root: Path, persist: bool = True):
+        self.root = root.resolve()
+        self.directory = self.root / INDEX_DIR
+        self.persist = persist
+        self.hits = self.misses = 0
+        self._manifest = self._load() if persist else _Manifest()
+        self._memory: dict[tuple[str, str], str] = {}
+        self._dirty = False
+
+    @classmethod
+    def open(cls, root: Path) -> RepoIndex:
+        """The index of the repository containing *root* (or of *root*)."""
+        return cls(find_repo_root(root), get_config().scout_index)
+
+    def _load(self) -> _Manifest:
+        try:
+            manifest = _Manifest.model_validate_json(
+                (self.directory / _MANIFEST).read_text(encoding="utf-8")
+            )
+        except (OSError, ValidationError):
+            return _Manifest()
+        return manifest if manifest.version == _INDEX_VERSION else _Manifest()
+
+    def file(self, path: Path) -> IndexedFile | None:
+        """Metadata of *path*, re-hashed only when its size or mtime changed.
+
+        Returns ``None`` if the file cannot be read.
+        """
+        rel = Path(os.path.relpath(os.path.abspath(path), self.root)).as_posix()
+        try:
+            stat = path.stat()
+        except OSError:
+            return None
+        known = self._manifest.files.get(rel)
+        if known and (known.size, known.mtime_ns) == (stat.st_size, stat.st_mtime_ns):
+            return known
+        try:
+            digest = _digest(path.read_bytes())
+        except OSError:
+            return None
+        entry = IndexedFile(
+            path=rel, size=stat.st_size, mtime_ns=stat.st_mtime_ns, digest=digest
+        )
+        self._manifest.files[rel] = entry
+        self._dirty = True
+        return entry
+
+    def lookup(
+        self, kind: str, path: Path, model: type[T]
+    ) -> tuple[IndexedFile | None, T | None]:
+        """The index entry of *path* and its stored *kind* facts, if current.
+
+        The entry is ``None`` if the file cannot be read; the facts are
+        ``None`` when nothing is stored for its content yet.
+        """
+        entry = self.file(path)
+        if entry is None:
+            return None, None
+        stored = self.directory / kind / f"{entry.digest}.json"
+        if self.persist:
+            raw = stored.read_text(encoding="utf-8") if stored.is_file() else None
+        else:
+            raw = self._memory.get((kind, entry.digest))
+        if raw is not None:
+            try:
+                facts = model.model_validate_json(raw)
+            except ValidationError:
+                pass
+            else:
+                self.hits += 1
+                return entry, facts
+        self.misses += 1
+        return entry, None
+
+    def store(self, kind: str, entry: IndexedFile, facts: BaseModel) -> None:
+        """Keep *facts* as the *kind* facts of *entry*'s content."""
+        if not self.persist:
+            self._memory[(kind, entry.digest)] = facts.model_dump_json()
+            return
+        stored = self.directory / kind / f"{entry.digest}.json"
+        try:
+            stored.parent.mkdir(parents=True, exist_ok=True)
+            stored.write_text(facts.model_dump_json(), encoding="utf-8")
+        except OSError as exc:
+            log.debug("Could not cache %s facts for %s: %s", kind, entry.path, exc)
+
+    def cached(
+        self, kind: str, path: Path, model: type[T], compute: Callable[[Path], T]
+    ) -> T | None:
+        """*kind* facts of *path*: stored ones while its content is unchanged,
+        else ``compute(path)`` (stored for next time).
+
+        Returns ``None`` if the file cannot be read.
+        """
+        entry, facts = self.lookup(kind, path, model)
+        if entry is None or facts is not None:
+            return facts
+        facts = compute(path)
+        self.store(kind, entry, facts)
+        return facts
+
+    def save(self) -> None:
+        """Write the manifest back if anything changed (best effort).
+
+        Entries of files that no longer exist are dropped.
+        """
+        if not (self.persist and self._dirty):
+            return
+        files = self._manifest.files
+        for rel in [rel for rel in files if not (self.root / rel).is_file()]:
+            del files[rel]
+        try:
+            self.directory.mkdir(parents=True, exist_ok=True)
+            ignore = self.directory / ".gitignore"
+            if not ignore.exists():
+                ignore.write_text("*\n")
+            staging = self.directory / f"{_MANIFEST}.tmp"
+            staging.write_text(self._manifest.model_dump_json(), encoding="utf-8")
+            staging.replace(self.directory / _MANIFEST)
+        except OSError as exc:
+            log.debug("Could not save the scout index in %s: %s", self.directory, exc)
+            return
+        self._dirty = False
@@ -0,0 +1,206 @@
+"""azathoth.core.issues — triage GitHub issues.
+
+Public surface:
+  - ``list_issues(state, labels, unlabeled, limit, cwd)``  → ``[IssueInfo]``
+  - ``list_labels(cwd)``                                   → ``[LabelInfo]``
+  - ``label_issue(number, add, remove, …)``                → ``IssueAction``
+  - ``comment_issue(number, body, …)``                     → ``IssueAction``
+  - ``close_issue(number, reason, comment, …)``            → ``IssueAction``
+
+Like ``core.reviews`` everything goes through ``github_api``: the REST API
+with a token, else ``gh api``.  GitHub's issues endpoint also returns pull
+requests; ``list_issues`` drops them.  ``label_issue`` only applies labels
+the repository already has, so triage cannot invent a taxonomy by typo.
+Mutating calls honour ``dry_run`` and return the requests they would make.
+"""
+
+from __future__ import annotations
+
+from typing import Any, Literal
+from urllib.parse import quote
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.github import api_plan, github_api
+
+IssueState = Literal["open", "closed", "all"]
+CloseReason = Literal["completed", "not_planned"]
+
+_BODY_LIMIT = 4000
+
+
+class IssueInfo(BaseModel, frozen=True):
+    number: int
+    title: str
+    author: str | None = None
+    state: str = "open"
+    labels: list[str] = Field(default_factory=list)
+    body: str = Field("", description="Truncated to 4000 characters")
+    comments: int = 0
+    created_at: str | None = None
+    url: str | None = None
+
+
+class LabelInfo(BaseModel, frozen=True):
+    name: str
+    description: str | None = None
+
+
+class IssueAction(BaseModel, frozen=True):
+    """Outcome (or dry-run plan) of labelling, commenting on or closing an issue."""
+
+    number: int
+    action: Literal["label", "comment", "close"]
+    done: bool = Field(description="False for a dry run")
+    labels: list[str] = Field(default_factory=list, description="Labels afterwards")
+    url: str | None = None
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+def _issue_info(raw: dict[str, Any]) -> IssueInfo:
+    return IssueInfo(
+        number=raw["number"],
+        title=raw.get("title", ""),
+        author=(raw.get("user") or {}).get("login"),
+        state=raw.get("state", "open"),
+        labels=[label["name"] for label in raw.get("labels", [])],
+        body=(raw.get("body") or "")[:_BODY_LIMIT],
+        comments=raw.get("comments", 0),
+        created_at=raw.get("created_at"),
+        url=raw.get("html_url"),
+    )
+
+
+async def list_issues(
+    state: IssueState = "open",
+    labels: list[str] | None = None,
+    unlabeled: bool = False,
+    limit: int = 30,
+    cwd: str | None = None,
+) -> list[IssueInfo]:
+    """Issues (not pull requests) in *state*, newest first.
+
+    *labels* keeps issues carrying all of them; *unlabeled* keeps only
+    issues without any label — the ones still waiting for triage.
+    """
+    query = f"issues?state={state}&sort=created&direction=desc&per_page=100"
+    query += f"&labels={quote(','.join(labels))}" if labels else ""
+    issues = [
+        _issue_info(raw)
+        for raw in await github_api("GET", query, cwd)
+        if "pull_request" not in raw
+    ]
+    if unlabeled:
+        issues = [issue for issue in issues if not issue.labels]
+    return issues[:limit]
+
+
+async def list_labels(cwd: str | None = None) -> list[LabelInfo]:
+    """The labels defined in the repository."""
+    return [
+        LabelInfo(name=raw["name"], description=raw.get("description") or None)
+        for raw in await github_api("GET", "labels?per_page=100", cwd)
+    ]
+
+
+async def label_issue(
+    number: int,
+    add: list[str] | None = None,
+    remove: list[str] | None = None,
+    dry_run: bool = False,
+    cwd: str | None = None,
+) -> IssueAction:
+    """Add and remove labels on issue *number*.
+
+    Raises:
+        WorkflowError: If there is nothing to change or a label to add does
+            not exist in the repository.
+    """
+    add, remove = add or [], remove or []
+    if not add and not remove:
+        raise WorkflowError("Pass labels to add or remove.")
+    if add:
+        known = {label.name.lower() for label in await list_labels(cwd)}
+        unknown = [name for name in add if name.lower() not in known]
+        if unknown:
+            raise WorkflowError(
+                f"Unknown label(s): {', '.join(unknown)}. Use one of the "
+                "repository's labels (see the autotriage prompt)."
+            )
+    requests = [("POST", f"issues/{number}/labels")] if add else []
+    requests += [
+        ("DELETE", f"issues/{number}/labels/{quote(name, safe='')}") for name in remove
+    ]
+    if dry_run:
+        return IssueAction(
+            number=number,
+            action="label",
+            done=False,
+            commands=[await api_plan(method, path, cwd) for method, path in requests],
+        )
+    current: list[dict[str, Any]] = []
+    for method, path in requests:
+        body = {"labels": add} if method == "POST" else None
+        current = await github_api(method, path, cwd, body=body)
+    return IssueAction(
+        number=number,
+        action="label",
+        done=True,
+        labels=[label["name"] for label in current],
+    )
+
+
+async def comment_issue(
+    number: int, body: str, dry_run: bool = False, cwd: str | None = None
+) -> IssueAction:
+    """Post *body* as a comment on issue *number*.
+
+    Raises:
+        WorkflowError: If *body* is empty or GitHub rejects the comment.
+    """
+    if not body.strip():
+        raise WorkflowError("The comment body is empty.")
+    endpoint = f"issues/{number}/comments"
+    if dry_run:
+        return IssueAction(
+            number=number,
+            action="comment",
+            done=False,
+            commands=[await api_plan("POST", endpoint, cwd)],
+        )
+    posted = await github_api("POST", endpoint, cwd, body={"body": body})
+    return IssueAction(
+        number=number, action="comment", done=True, url=posted.get("html_url")
+    )
+
+
+async def close_issue(
+    number: int,
+    reason: CloseReason = "completed",
+    comment: str | None = None,
+    dry_run: bool = False,
+    cwd: str | None = None,
+) -> IssueAction:
+    """Close issue *number* as *reason*, after posting *comment* if given."""
+    endpoint = f"issues/{number}"
+    if dry_run:
+        plan = [await api_plan("POST", f"{endpoint}/comments", cwd)] if comment else []
+        return IssueAction(
+            number=number,
+            action="close",
+            done=False,
+            commands=[*plan, await api_plan("PATCH", endpoint, cwd)],
+        )
+    if comment:
+        await comment_issue(number, comment, cwd=cwd)
+    closed = await github_api(
+        "PATCH", endpoint, cwd, body={"state": "closed", "state_reason": reason}
+    )
+    return IssueAction(
+        number=number,
+        action="close",
+        done=True,
+        labels=[label["name"] for label in closed.get("labels", [])],
+        url=closed.get("html_url"),
+    )
@@ -0,0 +1,518 @@
+"""azathoth.core.licenses — project license and dependency license compliance.
+
+Public surface:
+  - ``identify_license(text)``   → SPDX id of a license file's text, or ``None``
+  - ``normalize_license(value)`` → SPDX expression for a manifest/metadata value
+  - ``category_of(expression)``  → ``permissive`` … ``network_copyleft``
+  - ``license_report(root)``     → ``LicenseReport``
+
+The project license comes from its license files (LICENSE, COPYING, …),
+recognised by their wording, and from the manifests (``license`` in
+pyproject.toml, Cargo.toml and package.json).  Dependency licenses are read
+from metadata already on disk — ``node_modules/*/package.json``, the
+``.dist-info`` of a ``.venv``, the cargo registry sources and the Go module
+cache — for the packages ``core.dependencies`` resolves; nothing is fetched.
+
+Licenses fall into categories (permissive, weak copyleft, strong copyleft,
+network copyleft).  A runtime dependency is *incompatible* when its license
+is more restrictive than the project's allows — strong or network copyleft
+in a permissive or unlicensed project, or Apache-2.0 and (L/A)GPL-3.0 in a
+GPL-2.0 project — and worth a *review* when it is weak copyleft there.  Dev
+dependencies are not distributed and are never flagged.  For ``OR``
+expressions the most permissive alternative counts, for ``AND`` the most
+restrictive.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import re
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.dependencies import Dependency, dependencies
+from azathoth.core.formatter import Table
+from azathoth.core.stack import _load_toml, _norm
+
+Category = Literal[
+    "permissive", "weak_copyleft", "strong_copyleft", "network_copyleft", "unknown"
+]
+
+#: Names a license file goes by (matched case-insensitively, any suffix).
+LICENSE_FILE_NAMES = ("license", "licence", "copying", "unlicense")
+
+# (SPDX id, all of these phrases), checked in order: most specific first.
+_TEXT_MARKERS: list[tuple[str, tuple[str, ...]]] = [
+    ("AGPL-3.0", ("gnu affero general public license",)),
+    ("LGPL-2.1", ("gnu lesser general public license", "version 2.1")),
+    ("LGPL-3.0", ("gnu lesser general public license",)),
+    ("GPL-2.0", ("gnu general public license", "version 2,")),
+    ("GPL-3.0", ("gnu general public license",)),
+    ("MPL-2.0", ("mozilla public license", "2.0")),
+    ("Apache-2.0", ("apache license", "version 2.0")),
+    ("BSL-1.0", ("boost software license",)),
+    ("Unlicense", ("free and unencumbered software released into the public domain",)),
+    ("ISC", ("permission to use, copy, modify, and/or distribute",)),
+    ("MIT", ("permission is hereby granted, free of charge",)),
+    ("BSD-3-Clause", ("redistribution and use in source and binary forms", "neither")),
+    ("BSD-2-Clause", ("redistribution and use in source and binary forms",)),
+]
+
+# Lower-cased spellings seen in manifests and metadata → SPDX id.
+_ALIASES = {
+    "mit license": "MIT",
+    "the mit license": "MIT",
+    "expat": "MIT",
+    "apache 2.0": "Apache-2.0",
+    "apache-2": "Apache-2.0",
+    "apache license 2.0": "Apache-2.0",
+    "apache license, version 2.0": "Apache-2.0",
+    "apache software license": "Apache-2.0",
+    "asl 2.0": "Apache-2.0",
+    "bsd": "BSD-3-Clause",
+    "bsd license": "BSD-3-Clause",
+    "new bsd license": "BSD-3-Clause",
+    "simplified bsd license": "BSD-2-Clause",
+    "isc license": "ISC",
+    "isc license (iscl)": "ISC",
+    "mozilla public license 2.0 (mpl 2.0)": "MPL-2.0",
+    "gplv2": "GPL-2.0",
+    "gplv3": "GPL-3.0",
+    "gnu general public license v2 (gplv2)": "GPL-2.0",
+    "gnu general public license v3 (gplv3)": "GPL-3.0",
+    "gnu lesser general public license v3 (lgplv3)": "LGPL-3.0",
+    "gnu affero general public license v3": "AGPL-3.0",
+    "lgplv3": "LGPL-3.0",
+    "agplv3": "AGPL-3.0",
+    "the unlicense (unlicense)": "Unlicense",
+    "public domain": "Unlicense",
+}
+
+_CATEGORIES: dict[str, Category] = {
+    **dict.fromkeys(
+        [
+            "MIT",
+            "MIT-0",
+            "Apache-2.0",
+            "BSD-2-Clause",
+            "BSD-3-Clause",
+            "0BSD",
+            "ISC",
+            "Zlib",
+            "BSL-1.0",
+            "Unlicense",
+            "CC0-1.0",
+            "Unicode-3.0",
+            "Unicode-DFS-2016",
+            "PSF-2.0",
+            "Python-2.0",
+            "BlueOak-1.0.0",
+        ],
+        "permissive",
+    ),
+    **dict.fromkeys(
+        ["LGPL-2.1", "LGPL-3.0", "MPL-2.0", "EPL-1.0", "EPL-2.0", "CDDL-1.0"],
+        "weak_copyleft",
+    ),
+    **dict.fromkeys(["GPL-2.0", "GPL-3.0"], "strong_copyleft"),
+    "AGPL-3.0": "network_copyleft",
+}
+_RANK: dict[Category, int] = {
+    "permissive": 0,
+    "weak_copyleft": 1,
+    "strong_copyleft": 2,
+    "network_copyleft": 3,
+    "unknown": 4,
+}
+# Licenses a GPL-2.0-only project cannot take in.
+_GPL2_INCOMPATIBLE = {"Apache-2.0", "GPL-3.0", "LGPL-3.0", "AGPL-3.0"}
+_SPDX_SUFFIX = re.compile(r"(-only|-or-later|\+)$")
+
+
+class DependencyLicense(BaseModel, frozen=True):
+    name: str
+    ecosystem: str
+    version: str
+    direct: bool
+    dev: bool
+    license: str | None = Field(None, description="SPDX expression, if found")
+    category: Category
+    source: str = Field("", description="File the license was read from")
+
+
+class LicenseIssue(BaseModel, frozen=True):
+    severity: Literal["error", "warning"]
+    kind: Literal[
+        "missing_license_file",
+        "undeclared",
+        "mismatch",
+        "incompatible",
+        "review",
+        "unknown_license",
+    ]
+    subject: str = Field(description="File, manifest or dependency concerned")
+    message: str
+
+
+class LicenseReport(BaseModel, frozen=True):
+    project_license: str | None
+    category: Category
+    license_files: dict[str, str | None] = Field(
+        description="License file → SPDX id recognised in it"
+    )
+    declared: dict[str, str] = Field(description="Manifest → declared license")
+    dependencies: list[DependencyLicense]
+    issues: list[LicenseIssue]
+
+    def render_markdown(self) -> str:
+        license_ = self.project_license or "none found"
+        lines = [f"**Project license:** {license_} ({self.category})"]
+        counts: dict[str, int] = {}
+        for dep in self.dependencies:
+            counts[dep.category] = counts.get(dep.category, 0) + 1
+        if counts:
+            summary = ", ".join(f"{n} {cat}" for cat, n in sorted(counts.items()))
+            lines.append(f"**Dependencies:** {summary}")
+        if self.issues:
+            table = (
+                Table(overflow="ellipsis")
+                .column("Severity")
+                .column("Kind")
+                .column("Subject", max_width=40)
+                .column("Message", max_width=70)
+            )
+            for issue in self.issues:
+                table.row([issue.severity, issue.kind, issue.subject, issue.message])
+            lines += ["", "```", table.render(), "```"]
+        else:
+            lines.append("No license issues found.")
+        return "\n".join(lines)
+
+
+# ── Identification ───────────────────────────────────────────────────────────
+
+
+def identify_license(text: str) -> str | None:
+    """SPDX id of the license whose text *text* is, by its wording."""
+    wording = " ".join(text.lower().split())
+    for spdx, phrases in _TEXT_MARKERS:
+        if all(phrase in wording for phrase in phrases):
+            return spdx
+    return None
+
+
+def _normalize_id(value: str) -> str:
+    value = value.strip().strip("()")
+    if alias := _ALIASES.get(value.lower()):
+        return alias
+    suffix = match[0] if (match := _SPDX_SUFFIX.search(value)) else ""
+    base = value.removesuffix(suffix) if suffix else value
+    for known in _CATEGORIES:
+        if base.lower() == known.lower():
+            return known + suffix
+    return value
+
+
+def normalize_license(value: str) -> str | None:
+    """SPDX expression for a manifest or metadata *value* (``None`` if empty)."""
+    value = value.strip()
+    if not value or value.upper() in {"UNKNOWN", "NONE", "UNLICENSED"}:
+        return None
+    if value.lower().startswith("see license"):
+        return None
+    # Old cargo manifests separate alternatives with "/".
+    alternatives = re.split(r"\s+OR\s+|\s*/\s*", value)
+    parts = [
+        " AND ".join(_normalize_id(p) for p in re.split(r"\s+AND\s+", alt))
+        for alt in alternatives
+    ]
+    return " OR ".join(parts)
+
+
+def category_of(expression: str | None) -> Category:
+    """Category of an SPDX *expression*: most permissive ``OR`` branch wins."""
+    if not expression:
+        return "unknown"
+
+    def _conjunction(part: str) -> Category:
+        ids = [_SPDX_SUFFIX.sub("", p.strip("() ")) for p in part.split(" AND ")]
+        cats = [_CATEGORIES.get(i, "unknown") for i in ids]
+        return max(cats, key=lambda c: _RANK[c])
+
+    return min(
+        (_conjunction(part) for part in expression.split(" OR ")),
+        key=lambda c: _RANK[c],
+    )
+
+
+# ── Project license ──────────────────────────────────────────────────────────
+
+
+def _license_files(root: Path) -> dict[str, str | None]:
+    found: dict[str, str | None] = {}
+    for path in sorted(root.iterdir()):
+        stem = path.name.lower().split(".")[0].split("-")[0]
+        if path.is_file() and stem in LICENSE_FILE_NAMES:
+            text = path.read_text(encoding="utf-8", errors="ignore")
+            found[path.name] = identify_license(text)
+    return found
+
+
+def _declared(root: Path) -> dict[str, str]:
+    declared: dict[str, str] = {}
+    if (root / "pyproject.toml").is_file():
+        project = _load_toml(root / "pyproject.toml").get("project", {})
+        value = project.get("license")
+        if isinstance(value, dict) and "text" in value:
+            value = value["text"]
+        elif isinstance(value, dict) and "file" in value:
+            path = root / value["file"]
+            value = identify_license(path.read_text(errors="ignore")) or ""
+        if isinstance(value, str) and (spdx := normalize_license(value)):
+            declared["pyproject.toml"] = spdx
+    if (root / "Cargo.toml").is_file():
+        data = _load_toml(root / "Cargo.toml")
+        value = data.get("package", {}).get("license")
+        if isinstance(value, dict):
+            value = data.get("workspace", {}).get("package", {}).get("license")
+        if isinstance(value, str) and (spdx := normalize_license(value)):
+            declared["Cargo.toml"] = spdx
+    if (root / "package.json").is_file():
+        try:
+            value = json.loads((root / "package.json").read_text(encoding="utf-8"))
+        except (OSError, json.JSONDecodeError):
+            value = {}
+        license_ = value.get("license")
+        if isinstance(license_, str) and (spdx := normalize_license(license_)):
+            declared["package.json"] = spdx
+    return declared
+
+
+# ── Dependency metadata ──────────────────────────────────────────────────────
+
+
+def _read_json(path: Path) -> dict:
+    try:
+        return json.loads(path.read_text(encoding="utf-8"))
+    except (OSError, json.JSONDecodeError):
+        return {}
+
+
+def _js_license(root: Path, dep: Dependency) -> tuple[str | None, str]:
+    path = root / "node_modules" / dep.name / "package.json"
+    data = _read_json(path)
+    value = data.get("license") or data.get("licenses")
+    if isinstance(value, dict):
+        value = value.get("type")
+    elif isinstance(value, list):
+        types = [v.get("type", "") if isinstance(v, dict) else str(v) for v in value]
+        value = " OR ".join(t for t in types if t)
+    if not isinstance(value, str):
+        return None, ""
+    return normalize_license(value), path.relative_to(root).as_posix()
+
+
+def _site_packages(root: Path) -> list[Path]:
+    return sorted(root.glob(".venv/lib/python*/site-packages")) + sorted(
+        root.glob(".venv/Lib/site-packages")
+    )
+
+
+def _python_license(root: Path, dep: Dependency) -> tuple[str | None, str]:
+    for site in _site_packages(root):
+        for info in site.glob("*.dist-info"):
+            if _norm(info.name.split("-")[0]) != dep.name:
+                continue
+            metadata = info / "METADATA"
+            try:
+                text = metadata.read_text(encoding="utf-8", errors="ignore")
+            except OSError:
+                continue
+            source = metadata.relative_to(root).as_posix()
+            headers = text.split("\n\n", 1)[0].splitlines()
+            for line in headers:
+                if line.startswith("License-Expression:"):
+                    return normalize_license(line.partition(":")[2]), source
+            for line in headers:
+                if line.startswith("Classifier: License ::"):
+                    name = line.rsplit("::", 1)[1]
+                    return normalize_license(name), source
+            for line in headers:
+                value = line.partition(":")[2].strip()
+                if line.startswith("License:") and 0 < len(value) < 60:
+                    return normalize_license(value), source
+            return None, source
+    return None, ""
+
+
+def _cargo_license(dep: Dependency) -> tuple[str | None, str]:
+    home = Path(os.environ.get("CARGO_HOME", Path.home() / ".cargo"))
+    for manifest in home.glob(f"registry/src/*/{dep.name}-{dep.version}/Cargo.toml"):
+        value = _load_toml(manifest).get("package", {}).get("license")
+        if isinstance(value, str):
+            return normalize_license(value), str(manifest)
+    return None, ""
+
+
+def _go_license(dep: Dependency) -> tuple[str | None, str]:
+    cache = os.environ.get("GOMODCACHE") or str(
+        Path(os.environ.get("GOPATH", Path.home() / "go")) / "pkg" / "mod"
+    )
+    # The module cache escapes upper-case letters as "!" + lower case.
+    escaped = re.sub(r"[A-Z]", lambda m: "!" + m[0].lower(), dep.name)
+    module = Path(cache) / f"{escaped}@{dep.version}"
+    for path in sorted(module.glob("*")) if module.is_dir() else []:
+        if path.name.lower().split(".")[0] in LICENSE_FILE_NAMES:
+            text = path.read_text(encoding="utf-8", errors="ignore")
+            return identify_license(text), str(path)
+    return None, ""
+
+
+def _dependency_license(root: Path, dep: Dependency) -> tuple[str | None, str]:
+    if dep.ecosystem == "javascript":
+        return _js_license(root, dep)
+    if dep.ecosystem == "python":
+        return _python_license(root, dep)
+    if dep.ecosystem == "rust":
+        return _cargo_license(dep)
+    if dep.ecosystem == "go":
+        return _go_license(dep)
+    return None, ""
+
+
+# ── Report ───────────────────────────────────────────────────────────────────
+
+
+def _ids(expression: str) -> set[str]:
+    return {
+        _SPDX_SUFFIX.sub("", part.strip("() "))
+        for part in re.split(r" OR | AND ", expression)
+    }
+
+
+def _gpl2_only(expression: str) -> bool:
+    parts = re.split(r" OR | AND ", expression)
+    return any(p.strip("() ") in {"GPL-2.0", "GPL-2.0-only"} for p in parts)
+
+
+def _conflict(
+    project: str | None, project_category: Category, dep: DependencyLicense
+) -> LicenseIssue | None:
+    if dep.dev or dep.license is None:
+        return None
+    subject = f"{dep.name} {dep.version}".strip()
+    if project and _gpl2_only(project):
+        # One compatible alternative of an OR expression is enough.
+        alternatives = dep.license.split(" OR ")
+        if all(_ids(alt) & _GPL2_INCOMPATIBLE for alt in alternatives):
+            return LicenseIssue(
+                severity="error",
+                kind="incompatible",
+                subject=subject,
+                message=f"{dep.license} cannot be combined with {project}",
+            )
+    lenient = project_category in {"permissive", "unknown"}
+    if not lenient and _RANK[dep.category] <= _RANK[project_category]:
+        return None
+    license_ = project or "no license"
+    if dep.category == "weak_copyleft" and lenient:
+        return LicenseIssue(
+            severity="warning",
+            kind="review",
+            subject=subject,
+            message=f"{dep.license} (weak copyleft) in a {license_} project: "
+            "keep it a separate, replaceable library and ship its source notices",
+        )
+    if dep.category in {"strong_copyleft", "network_copyleft"}:
+        return LicenseIssue(
+            severity="error",
+            kind="incompatible",
+            subject=subject,
+            message=f"{dep.license} ({dep.category.replace('_', ' ')}) would "
+            f"require relicensing a {license_} project",
+        )
+    return None
+
+
+def license_report(root: Path) -> LicenseReport:
+    """Project license, dependency licenses and compliance issues for *root*."""
+    files = _license_files(root)
+    declared = _declared(root)
+    identified = [spdx for spdx in files.values() if spdx]
+    project = next(iter(declared.values()), None) or next(iter(identified), None)
+    project_category = category_of(project)
+
+    issues: list[LicenseIssue] = []
+    if not files:
+        issues.append(
+            LicenseIssue(
+                severity="error" if declared else "warning",
+                kind="missing_license_file",
+                subject=str(root.name),
+                message="No LICENSE or COPYING file at the project root"
+                + (f" although {project} is declared" if declared else ""),
+            )
+        )
+    if files and not declared:
+        issues.append(
+            LicenseIssue(
+                severity="warning",
+                kind="undeclared",
+                subject=", ".join(files),
+                message="No manifest declares the license (e.g. `license` in "
+                "pyproject.toml, Cargo.toml or package.json)",
+            )
+        )
+    for manifest, expression in declared.items():
+        if identified and not _ids(expression) & set(identified):
+            issues.append(
+                LicenseIssue(
+                    severity="error",
+                    kind="mismatch",
+                    subject=manifest,
+                    message=f"declares {expression} but the license file is "
+                    + " / ".join(sorted(set(identified))),
+                )
+            )
+
+    deps: list[DependencyLicense] = []
+    unknown = 0
+    for dep in dependencies(root).dependencies:
+        license_, source = _dependency_license(root, dep)
+        entry = DependencyLicense(
+            name=dep.name,
+            ecosystem=dep.ecosystem,
+            version=dep.version,
+            direct=dep.direct,
+            dev=dep.dev,
+            license=license_,
+            category=category_of(license_),
+            source=source,
+        )
+        deps.append(entry)
+        if license_ is None and not dep.dev:
+            unknown += 1
+        elif issue := _conflict(project, project_category, entry):
+            issues.append(issue)
+    if unknown:
+        issues.append(
+            LicenseIssue(
+                severity="warning",
+                kind="unknown_license",
+                subject=f"{unknown} runtime dependencies",
+                message="No license metadata on disk; install dependencies "
+                "(node_modules, .venv, cargo fetch, go mod download) and re-run",
+            )
+        )
+
+    return LicenseReport(
+        project_license=project,
+        category=project_category,
+        license_files=files,
+        declared=declared,
+        dependencies=deps,
+        issues=issues,
+    )
@@ -0,0 +1,203 @@
+"""azathoth.core.markers — TODO/FIXME/HACK/XXX comments and who left them.
+
+Public surface:
+  - ``MARKER_TAGS``          — the tags scanned for
+  - ``scan_markers(root)``   → ``MarkerReport``
+
+Inside a git work tree the files come from ``git ls-files``, so anything
+``.gitignore`` excludes is skipped, and each marker is attributed through
+``git blame`` to the author of its line (untracked files have no author,
+uncommitted lines show as "(uncommitted)").  Outside git the tree is walked
+with ``iter_files`` and markers carry no author.  A tag only counts
+inside a comment (``#``, ``//``, ``/*``, ``*``, ``--``, ``;`` or ``<!--``).
+The report groups the markers by file and by author and carries a Markdown
+summary for reports.
+"""
+
+from __future__ import annotations
+
+import re
+from collections import Counter
+from datetime import datetime, timezone
+from pathlib import Path
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.files import is_binary
+from azathoth.core.traverse import iter_files
+from azathoth.core.workflow import run_command
+
+MARKER_TAGS = ("TODO", "FIXME", "HACK", "XXX")
+
+# A tag counts only after a comment opener, so identifiers and strings that
+# merely mention one are not reported.
+_MARKER = re.compile(
+    r"(?:#|//|/\*|<!--|^\s*\*|^\s*--|^\s*;).*?"
+    rf"\b({'|'.join(MARKER_TAGS)})\b(?:\([^)]*\))?:?(.*)"
+)
+_BLAME_HEADER = re.compile(r"^[0-9a-f]{40} \d+ (\d+)")
+_UNCOMMITTED = "Not Committed Yet"
+_MAX_FILES = 20_000
+_MAX_BYTES = 1_000_000
+_MAX_MARKERS = 2_000
+_MAX_TEXT = 200
+_TOP = 20
+
+
+class Marker(BaseModel, frozen=True):
+    path: str
+    line: int
+    tag: str
+    text: str
+    author: str | None = Field(None, description="From git blame; None if untracked")
+    date: str | None = Field(None, description="ISO date of the line's commit")
+
+
+class MarkerGroup(BaseModel, frozen=True):
+    name: str = Field(description="File path or author name")
+    count: int
+    tags: dict[str, int]
+
+
+class MarkerReport(BaseModel, frozen=True):
+    root: str
+    total: int
+    tags: dict[str, int]
+    by_file: list[MarkerGroup] = Field(description="Most markers first")
+    by_author: list[MarkerGroup] = Field(description="Most markers first")
+    markers: list[Marker]
+    truncated: bool = Field(False, description=f"Stopped after {_MAX_MARKERS}")
+    markdown: str = Field("", description="Summary for inclusion in a report")
+
+    def render_markdown(self) -> str:
+        if not self.total:
+            return "No TODO/FIXME/HACK/XXX markers found."
+        counts = ", ".join(f"{n} {tag}" for tag, n in self.tags.items())
+        noun = "marker" if self.total == 1 else "markers"
+        lines = [f"**{self.total} {noun}** ({counts})"]
+        sections = (("By file", self.by_file), ("By author", self.by_author))
+        for title, groups in sections:
+            if not groups:
+                continue
+            lines += ["", f"#### {title}"]
+            lines += [f"- `{g.name}`: {g.count}" for g in groups[:_TOP]]
+            if len(groups) > _TOP:
+                lines.append(f"- … and {len(groups) - _TOP} more")
+        urgent = [m for m in self.markers if m.tag in ("FIXME", "HACK", "XXX")]
+        if urgent:
+            lines += ["", "#### Urgent"]
+            lines += [
+                f"- `{m.path}:{m.line}` {m.tag}: {m.text}" for m in urgent[:_TOP]
+            ]
+        if self.truncated:
+            lines += ["", f"_Stopped after {_MAX_MARKERS} markers._"]
+        return "\n".join(lines)
+
+
+async def _candidate_files(root: Path) -> tuple[list[str], set[str]]:
+    """Files to scan and, in a git tree, which of them are tracked."""
+    code, out, _ = await run_command(
+        ["git", "ls-files", "-z", "--cached", "--others", "--exclude-standard"],
+        cwd=str(root),
+    )
+    if code != 0:
+        walked = iter_files(root, max_files=_MAX_FILES)
+        return [p.relative_to(root).as_posix() for p in walked], set()
+    _, tracked, _ = await run_command(["git", "ls-files", "-z"], cwd=str(root))
+    files = sorted(dict.fromkeys(f for f in out.split("\0") if f))
+    return files[:_MAX_FILES], set(tracked.split("\0"))
+
+
+def _scan(root: Path, path: str) -> list[tuple[int, str, str]]:
+    try:
+        if (root / path).stat().st_size > _MAX_BYTES:
+            return []
+        data = (root / path).read_bytes()
+    except OSError:
+        return []
+    if is_binary(data[:8192]):
+        return []
+    found = []
+    text = data.decode("utf-8", errors="replace")
+    for number, line in enumerate(text.splitlines(), start=1):
+        if match := _MARKER.search(line):
+            note = match.group(2).strip().removesuffix("*/").removesuffix("-->")
+            note = note.strip().lstrip("-: ")
+            found.append((number, match.group(1), note[:_MAX_TEXT]))
+    return found
+
+
+async def _blame(
+    root: Path, path: str, lines: list[int]
+) -> dict[int, tuple[str, str]]:
+    """Line number → (author, ISO date) for *lines* of *path*."""
+    ranges = [arg for n in lines for arg in ("-L", f"{n},{n}")]
+    code, out, _ = await run_command(
+        ["git", "blame", "--line-porcelain", *ranges, "--", path], cwd=str(root)
+    )
+    if code != 0:
+        return {}
+    authors: dict[int, tuple[str, str]] = {}
+    line, author = 0, ""
+    for raw in out.splitlines():
+        if header := _BLAME_HEADER.match(raw):
+            line = int(header.group(1))
+        elif raw.startswith("author "):
+            author = raw.removeprefix("author ")
+            if author == _UNCOMMITTED:
+                author = "(uncommitted)"
+        elif raw.startswith("author-time "):
+            stamp = int(raw.removeprefix("author-time "))
+            date = datetime.fromtimestamp(stamp, timezone.utc).date().isoformat()
+            authors[line] = (author, date)
+    return authors
+
+
+def _groups(markers: list[Marker], key: str) -> list[MarkerGroup]:
+    grouped: dict[str, Counter[str]] = {}
+    for m in markers:
+        name = getattr(m, key)
+        if name is not None:
+            grouped.setdefault(name, Counter())[m.tag] += 1
+    groups = [
+        MarkerGroup(name=name, count=sum(tags.values()), tags=dict(tags))
+        for name, tags in grouped.items()
+    ]
+    return sorted(groups, key=lambda g: (-g.count, g.name))
+
+
+async def scan_markers(root: Path) -> MarkerReport:
+    """Find TODO/FIXME/HACK/XXX markers under *root*, attributed via blame."""
+    files, tracked = await _candidate_files(root)
+    markers: list[Marker] = []
+    truncated = False
+    for path in files:
+        found = _scan(root, path)
+        if not found:
+            continue
+        if len(markers) + len(found) > _MAX_MARKERS:
+            found, truncated = found[: _MAX_MARKERS - len(markers)], True
+        blamed = {}
+        if path in tracked:
+            blamed = await _blame(root, path, [number for number, _, _ in found])
+        for number, tag, text in found:
+            author, date = blamed.get(number, (None, None))
+            markers.append(
+                Marker(
+                    path=path, line=number, tag=tag, text=text, author=author, date=date
+                )
+            )
+        if truncated:
+            break
+
+    tags = Counter(m.tag for m in markers)
+    report = MarkerReport(
+        root=str(root),
+        total=len(markers),
+        tags={tag: tags[tag] for tag in MARKER_TAGS if tags[tag]},
+        by_file=_groups(markers, "path"),
+        by_author=_groups(markers, "author"),
+        markers=markers,
+        truncated=truncated,
+    )
+    return report.model_copy(update={"markdown": report.render_markdown()})
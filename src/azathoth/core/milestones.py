@@ -0,0 +1,186 @@
+"""azathoth.core.milestones — plan releases with GitHub milestones.
+
+Public surface:
+  - ``list_milestones(state, cwd)``                     → ``[MilestoneInfo]``
+  - ``find_milestone(milestone, cwd)``                  → ``MilestoneInfo``
+  - ``create_milestone(title, description, due_on, …)`` → ``MilestoneAction``
+  - ``close_milestone(milestone, …)``                   → ``MilestoneAction``
+  - ``assign_milestone(number, milestone, …)``          → ``MilestoneAction``
+  - ``milestone_status(milestone, cwd)``                → ``MilestoneStatus``
+
+A milestone is named by its title (``v1.4.0``) or number.  Issues and
+pull requests are assigned the same way, since GitHub treats every PR as
+an issue.  ``milestone_status`` is the release gate: ``complete`` only
+when nothing assigned to the milestone is still open, and ``open_items``
+lists what is blocking it.  Mutating calls honour ``dry_run``.
+"""
+
+from __future__ import annotations
+
+from typing import Any, Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.github import api_plan, github_api
+
+MilestoneState = Literal["open", "closed", "all"]
+
+
+class MilestoneInfo(BaseModel, frozen=True):
+    number: int
+    title: str
+    state: str = "open"
+    description: str | None = None
+    due_on: str | None = None
+    open_issues: int = Field(0, description="Open issues and pull requests")
+    closed_issues: int = 0
+    url: str | None = None
+
+    @property
+    def progress(self) -> float:
+        """Share of assigned items that are closed, 0–1 (1 when empty)."""
+        total = self.open_issues + self.closed_issues
+        return self.closed_issues / total if total else 1.0
+
+
+class MilestoneItem(BaseModel, frozen=True):
+    number: int
+    title: str
+    kind: Literal["issue", "pull_request"]
+    url: str | None = None
+
+
+class MilestoneStatus(BaseModel, frozen=True):
+    milestone: MilestoneInfo
+    complete: bool = Field(description="Nothing assigned to it is still open")
+    progress: float = Field(description="Share of closed items, 0–1")
+    open_items: list[MilestoneItem] = Field(default_factory=list)
+
+
+class MilestoneAction(BaseModel, frozen=True):
+    """Outcome (or dry-run plan) of a milestone change."""
+
+    action: Literal["create", "close", "assign"]
+    done: bool = Field(description="False for a dry run")
+    milestone: MilestoneInfo | None = Field(None, description="None for a dry run")
+    item: int | None = Field(None, description="Issue or PR assigned")
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+def _milestone_info(raw: dict[str, Any]) -> MilestoneInfo:
+    return MilestoneInfo(
+        number=raw["number"],
+        title=raw.get("title", ""),
+        state=raw.get("state", "open"),
+        description=raw.get("description") or None,
+        due_on=raw.get("due_on"),
+        open_issues=raw.get("open_issues", 0),
+        closed_issues=raw.get("closed_issues", 0),
+        url=raw.get("html_url"),
+    )
+
+
+async def list_milestones(
+    state: MilestoneState = "open", cwd: str | None = None
+) -> list[MilestoneInfo]:
+    """Milestones in *state*, by due date."""
+    query = f"milestones?state={state}&sort=due_on&direction=asc&per_page=100"
+    return [_milestone_info(raw) for raw in await github_api("GET", query, cwd)]
+
+
+async def find_milestone(milestone: str | int, cwd: str | None = None) -> MilestoneInfo:
+    """The milestone titled (or numbered) *milestone*, open or closed.
+
+    Raises:
+        WorkflowError: If the repository has no such milestone.
+    """
+    if isinstance(milestone, int) or milestone.isdigit():
+        return _milestone_info(await github_api("GET", f"milestones/{milestone}", cwd))
+    for info in await list_milestones("all", cwd):
+        if info.title == milestone:
+            return info
+    raise WorkflowError(f"No milestone titled '{milestone}'.")
+
+
+async def create_milestone(
+    title: str,
+    description: str = "",
+    due_on: str | None = None,
+    dry_run: bool = False,
+    cwd: str | None = None,
+) -> MilestoneAction:
+    """Create an open milestone; *due_on* is an ISO 8601 date or timestamp."""
+    if dry_run:
+        return MilestoneAction(
+            action="create",
+            done=False,
+            commands=[await api_plan("POST", "milestones", cwd)],
+        )
+    body: dict[str, Any] = {"title": title, "description": description}
+    if due_on:
+        body["due_on"] = due_on if "T" in due_on else f"{due_on}T00:00:00Z"
+    created = await github_api("POST", "milestones", cwd, body=body)
+    return MilestoneAction(
+        action="create", done=True, milestone=_milestone_info(created)
+    )
+
+
+async def close_milestone(
+    milestone: str | int, dry_run: bool = False, cwd: str | None = None
+) -> MilestoneAction:
+    """Close *milestone* (title or number)."""
+    info = await find_milestone(milestone, cwd)
+    endpoint = f"milestones/{info.number}"
+    if dry_run:
+        return MilestoneAction(
+            action="close",
+            done=False,
+            commands=[await api_plan("PATCH", endpoint, cwd)],
+        )
+    closed = await github_api("PATCH", endpoint, cwd, body={"state": "closed"})
+    return MilestoneAction(action="close", done=True, milestone=_milestone_info(closed))
+
+
+async def assign_milestone(
+    number: int,
+    milestone: str | int | None,
+    dry_run: bool = False,
+    cwd: str | None = None,
+) -> MilestoneAction:
+    """Assign issue or pull request *number* to *milestone*; ``None`` clears it."""
+    info = await find_milestone(milestone, cwd) if milestone is not None else None
+    endpoint = f"issues/{number}"
+    if dry_run:
+        return MilestoneAction(
+            action="assign",
+            done=False,
+            item=number,
+            commands=[await api_plan("PATCH", endpoint, cwd)],
+        )
+    body = {"milestone": info.number if info else None}
+    await github_api("PATCH", endpoint, cwd, body=body)
+    return MilestoneAction(action="assign", done=True, milestone=info, item=number)
+
+
+async def milestone_status(
+    milestone: str | int, cwd: str | None = None
+) -> MilestoneStatus:
+    """Whether everything assigned to *milestone* is closed, and what is not."""
+    info = await find_milestone(milestone, cwd)
+    query = f"issues?milestone={info.number}&state=open&per_page=100"
+    items = [
+        MilestoneItem(
+            number=raw["number"],
+            title=raw.get("title", ""),
+            kind="pull_request" if "pull_request" in raw else "issue",
+            url=raw.get("html_url"),
+        )
+        for raw in await github_api("GET", query, cwd)
+    ]
+    return MilestoneStatus(
+        milestone=info,
+        complete=not items and info.open_issues == 0,
+        progress=round(info.progress, 3),
+        open_items=items,
+    )
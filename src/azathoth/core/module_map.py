@@ -0,0 +1,307 @@
+"""azathoth.core.module_map — structural outline of a source tree.
+
+Public surface:
+  - ``module_map(root, depth)`` → ``ModuleMap``
+
+The outline has three parts.  *Targets* are the binaries and libraries
+the manifests declare: Cargo ``[lib]``/``[[bin]]`` and the ``src/main.rs``,
+``src/lib.rs`` and ``src/bin/*.rs`` conventions, ``[project.scripts]`` and
+the import package of a pyproject.toml, ``bin``/``main``/``exports`` of a
+package.json, and Go ``package main`` directories.  *Entry points* are the
+files execution starts from — the binaries' entry files plus conventional
+ones such as ``__main__.py`` or ``main.go``.  *Modules* are source
+directories cut off at *depth* levels, each with its subtree's file and
+line counts and number of public symbols (from ``core.symbols``), so a
+large repo still fits in a compact summary.
+"""
+
+from __future__ import annotations
+
+import json
+import posixpath
+from collections import defaultdict
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.formatter import Table
+from azathoth.core.stack import _load_toml
+from azathoth.core.summarize import ENTRY_FILES
+from azathoth.core.symbols import FileFacts, index_tree
+
+#: File names execution conventionally starts from.
+ENTRY_POINT_FILES = frozenset(
+    {"__main__.py", "main.py", "manage.py", "main.rs", "main.go", "main.ts", "main.js"}
+)
+
+_TEST_DIRS = {"tests", "test", "__tests__", "testdata", "benches", "examples"}
+
+
+class Target(BaseModel, frozen=True):
+    name: str
+    kind: Literal["binary", "library"]
+    entry: str = Field("", description="Entry file or import path, if known")
+    manifest: str
+
+
+class ModuleInfo(BaseModel, frozen=True):
+    path: str
+    language: str = Field(description="Most lines, if the module mixes languages")
+    files: int
+    lines: int
+    public_symbols: int
+    public: bool = Field(description="Exports symbols and is not private or tests")
+    doc: str = Field("", description="Docstring of the module's entry file")
+
+
+class ModuleMap(BaseModel, frozen=True):
+    root: str
+    targets: list[Target]
+    entry_points: list[str]
+    modules: list[ModuleInfo] = Field(description="Largest first")
+    files: int
+    lines: int
+
+    def render_markdown(self) -> str:
+        lines = [f"# Module map of {self.root}", ""]
+        lines.append(f"{self.files} source files, {self.lines} lines")
+        if self.targets:
+            lines += ["", "## Targets"]
+            for t in self.targets:
+                entry = f" → `{t.entry}`" if t.entry else ""
+                lines.append(f"- {t.kind} `{t.name}`{entry} ({t.manifest})")
+        if self.entry_points:
+            entries = ", ".join(f"`{e}`" for e in self.entry_points)
+            lines += ["", "## Entry points", entries]
+        if self.modules:
+            table = (
+                Table(overflow="ellipsis")
+                .column("Module", max_width=50)
+                .column("Files", align="right")
+                .column("Lines", align="right")
+                .column("Public", align="right")
+                .column("About", max_width=50)
+            )
+            for m in self.modules:
+                public = m.public_symbols if m.public else "-"
+                table.row([m.path, m.files, m.lines, public, m.doc])
+            lines += ["", "## Modules", "```", table.render(), "```"]
+        return "\n".join(lines)
+
+
+# ── Targets ──────────────────────────────────────────────────────────────────
+
+
+def _cargo_targets(root: Path, manifest: Path) -> list[Target]:
+    data = _load_toml(manifest)
+    package = data.get("package", {}).get("name")
+    if not package:
+        return []
+    base = manifest.parent
+    rel = manifest.relative_to(root).as_posix()
+
+    def _path(path: str | Path) -> str:
+        return (base / path).relative_to(root).as_posix()
+
+    targets: list[Target] = []
+    lib = data.get("lib", {})
+    lib_path = lib.get("path", "src/lib.rs")
+    if "lib" in data or (base / lib_path).is_file():
+        name = lib.get("name", package)
+        targets.append(
+            Target(name=name, kind="library", entry=_path(lib_path), manifest=rel)
+        )
+    bins = {b.get("name", package): b.get("path", "") for b in data.get("bin", [])}
+    if (base / "src/main.rs").is_file():
+        bins.setdefault(package, "src/main.rs")
+    for path in sorted((base / "src/bin").glob("*.rs")):
+        bins.setdefault(path.stem, f"src/bin/{path.name}")
+    for name, path in bins.items():
+        path = path or f"src/bin/{name}.rs"
+        targets.append(
+            Target(name=name, kind="binary", entry=_path(path), manifest=rel)
+        )
+    return targets
+
+
+def _cargo_manifests(root: Path) -> list[Path]:
+    manifest = root / "Cargo.toml"
+    if not manifest.is_file():
+        return []
+    found = [manifest]
+    for member in _load_toml(manifest).get("workspace", {}).get("members", []):
+        found += sorted(p / "Cargo.toml" for p in root.glob(member))
+    return [m for m in dict.fromkeys(found) if m.is_file()]
+
+
+def _python_script_file(root: Path, ref: str) -> str:
+    """The file behind a ``module:attr`` script reference, else *ref* itself."""
+    module = ref.partition(":")[0].strip().replace(".", "/")
+    for base in ("src/", ""):
+        for candidate in (f"{base}{module}.py", f"{base}{module}/__init__.py"):
+            if (root / candidate).is_file():
+                return candidate
+    return ref
+
+
+def _python_targets(root: Path) -> list[Target]:
+    pyproject = root / "pyproject.toml"
+    if not pyproject.is_file():
+        return []
+    project = _load_toml(pyproject).get("project", {})
+    if not project:
+        return []
+    targets = [
+        Target(
+            name=name,
+            kind="binary",
+            entry=_python_script_file(root, str(ref)),
+            manifest="pyproject.toml",
+        )
+        for name, ref in project.get("scripts", {}).items()
+    ]
+    package = project.get("name", "").replace("-", "_").lower()
+    for base in ("src", "."):
+        init = root / base / package / "__init__.py"
+        if package and init.is_file():
+            entry = init.relative_to(root).as_posix()
+            targets.append(
+                Target(
+                    name=package, kind="library", entry=entry, manifest="pyproject.toml"
+                )
+            )
+            break
+    return targets
+
+
+def _js_targets(root: Path) -> list[Target]:
+    try:
+        data = json.loads((root / "package.json").read_text(encoding="utf-8"))
+    except (OSError, json.JSONDecodeError):
+        return []
+    name = data.get("name", root.name)
+    bins = data.get("bin", {})
+    if isinstance(bins, str):
+        bins = {name.rsplit("/", 1)[-1]: bins}
+    targets = [
+        Target(name=bin_name, kind="binary", entry=path, manifest="package.json")
+        for bin_name, path in bins.items()
+    ]
+    exports = data.get("exports")
+    entry = data.get("module") or data.get("main")
+    if entry is None and isinstance(exports, str):
+        entry = exports
+    elif entry is None and isinstance(exports, dict):
+        entry = next(
+            (v for v in exports.values() if isinstance(v, str)), exports.get(".")
+        )
+    if entry is not None or exports is not None:
+        entry = entry if isinstance(entry, str) else ""
+        targets.append(
+            Target(name=name, kind="library", entry=entry, manifest="package.json")
+        )
+    return targets
+
+
+def _go_targets(root: Path, index: dict[str, FileFacts]) -> list[Target]:
+    if not (root / "go.mod").is_file():
+        return []
+    packages: dict[str, bool] = {}
+    for path, facts in index.items():
+        if facts.language != "go" or path.endswith("_test.go"):
+            continue
+        head = (root / path).read_text(errors="ignore")[:4096]
+        is_main = any(line.strip() == "package main" for line in head.splitlines())
+        directory = posixpath.dirname(path) or "."
+        packages[directory] = packages.get(directory, False) or is_main
+    return [
+        Target(
+            name=posixpath.basename(d) if d != "." else root.name,
+            kind="binary" if is_main else "library",
+            entry=d,
+            manifest="go.mod",
+        )
+        for d, is_main in sorted(packages.items())
+    ]
+
+
+# ── Modules ──────────────────────────────────────────────────────────────────
+
+
+def _is_tests(path: str) -> bool:
+    return any(part in _TEST_DIRS for part in path.split("/"))
+
+
+def _is_private(path: str) -> bool:
+    return any(part.startswith("_") for part in path.split("/"))
+
+
+def _modules(index: dict[str, FileFacts], depth: int) -> list[ModuleInfo]:
+    groups: dict[str, list[FileFacts]] = defaultdict(list)
+    for path, facts in index.items():
+        parts = posixpath.dirname(path).split("/") if "/" in path else ["."]
+        groups["/".join(parts[:depth])].append(facts)
+
+    modules = []
+    for path, members in groups.items():
+        by_language: dict[str, int] = defaultdict(int)
+        for facts in members:
+            by_language[facts.language] += facts.lines
+        symbols = sum(len(f.symbols) for f in members)
+        entry = next(
+            (
+                f
+                for f in sorted(members, key=lambda f: f.path.count("/"))
+                if posixpath.basename(f.path) in ENTRY_FILES and f.doc
+            ),
+            None,
+        )
+        modules.append(
+            ModuleInfo(
+                path=path,
+                language=max(by_language, key=lambda lang: by_language[lang]),
+                files=len(members),
+                lines=sum(f.lines for f in members),
+                public_symbols=symbols,
+                public=bool(symbols) and not _is_tests(path) and not _is_private(path),
+                doc=entry.doc if entry else "",
+            )
+        )
+    modules.sort(key=lambda m: (-m.lines, m.path))
+    return modules
+
+
+def module_map(root: Path, depth: int = 3) -> ModuleMap:
+    """Targets, entry points and per-module sizes of the tree at *root*.
+
+    *depth* is how many directory levels a module path keeps; deeper
+    directories are counted in their ancestor at that level.
+    """
+    root = root.resolve()
+    index = index_tree(root)
+
+    targets: list[Target] = []
+    for manifest in _cargo_manifests(root):
+        targets += _cargo_targets(root, manifest)
+    targets += _python_targets(root)
+    targets += _js_targets(root)
+    targets += _go_targets(root, index)
+
+    entry_points = {
+        t.entry for t in targets if t.kind == "binary" and (root / t.entry).is_file()
+    }
+    entry_points.update(
+        path
+        for path in index
+        if posixpath.basename(path) in ENTRY_POINT_FILES and not _is_tests(path)
+    )
+
+    return ModuleMap(
+        root=str(root),
+        targets=targets,
+        entry_points=sorted(entry_points),
+        modules=_modules(index, max(1, depth)),
+        files=len(index),
+        lines=sum(f.lines for f in index.values()),
+    )
@@ -0,0 +1,473 @@
+"""azathoth.core.outline — compact symbol outlines of source files.
+
+Public surface:
+  - ``OUTLINE_LANGUAGES``                        — suffix → grammar name
+  - ``outline_file(sandbox, path, public_only)`` → ``FileOutline``
+  - ``outline(sandbox, path, public_only)``      → ``Outline`` of a file or
+    every supported file below a directory
+
+An outline lists a file's functions, classes/structs, enums, traits and
+interfaces, impl blocks, type aliases and constants with their signature
+(the declaration up to its body) and line span; methods nest under their
+class, impl, trait or interface.  Rust, Python, TypeScript/JavaScript and Go
+are parsed with tree-sitter when the ``outline`` extra
+(``tree-sitter-language-pack``) is installed.  Without it Python goes
+through ``ast`` and the others through line patterns that find
+declarations by keyword (nesting indented ones, such as Rust methods, under
+their impl or trait) and know only the line a declaration starts on;
+``parser`` on each file says which was used.  Parsed outlines are kept in
+the scout index (``core.index``) by content hash.  ``public`` follows each
+language's rule: ``pub`` in Rust, ``export`` in TypeScript/JavaScript (class
+members unless private), a capital letter in Go, no leading underscore in
+Python.
+"""
+
+from __future__ import annotations
+
+import ast
+import re
+from pathlib import Path
+from typing import Any, Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.exceptions import SandboxError
+from azathoth.core.files import Sandbox
+from azathoth.core.index import RepoIndex
+from azathoth.core.traverse import iter_files
+
+try:
+    from tree_sitter_language_pack import get_parser as _ts_parser
+except ImportError:  # optional extra
+    _ts_parser = None
+
+OutlineKind = Literal[
+    "function",
+    "method",
+    "class",
+    "struct",
+    "enum",
+    "trait",
+    "interface",
+    "impl",
+    "type",
+    "module",
+    "constant",
+]
+
+OUTLINE_LANGUAGES: dict[str, str] = {
+    ".rs": "rust",
+    ".py": "python",
+    ".pyi": "python",
+    ".ts": "typescript",
+    ".mts": "typescript",
+    ".tsx": "tsx",
+    ".js": "javascript",
+    ".jsx": "javascript",
+    ".mjs": "javascript",
+    ".cjs": "javascript",
+    ".go": "go",
+}
+
+_MAX_FILES = 500
+_MAX_BYTES = 512_000
+_MAX_SIGNATURE = 200
+
+
+class OutlineItem(BaseModel, frozen=True):
+    kind: OutlineKind
+    name: str
+    signature: str = Field(description="The declaration up to its body")
+    line: int
+    end_line: int
+    public: bool
+    children: list[OutlineItem] = Field(default_factory=list)
+
+
+class FileOutline(BaseModel, frozen=True):
+    path: str
+    language: str
+    parser: Literal["tree-sitter", "ast", "patterns"]
+    items: list[OutlineItem] = Field(default_factory=list)
+
+
+class Outline(BaseModel, frozen=True):
+    path: str
+    files: list[FileOutline] = Field(default_factory=list)
+    truncated: bool = Field(False, description=f"Stopped after {_MAX_FILES} files")
+
+    def render_markdown(self) -> str:
+        lines: list[str] = []
+        for file in self.files:
+            lines += [f"## {file.path}", ""]
+            stack = [(item, 0) for item in reversed(file.items)]
+            while stack:
+                item, depth = stack.pop()
+                lines.append(f"{'  ' * depth}- L{item.line} `{item.signature}`")
+                stack += [(child, depth + 1) for child in reversed(item.children)]
+            lines.append("")
+        if self.truncated:
+            lines.append(f"_Stopped after {_MAX_FILES} files._")
+        return "\n".join(lines).rstrip() + "\n"
+
+
+def _signature(text: str) -> str:
+    flat = " ".join(text.split()).rstrip("{:; ").rstrip()
+    return flat if len(flat) <= _MAX_SIGNATURE else f"{flat[:_MAX_SIGNATURE]}…"
+
+
+# ── tree-sitter ───────────────────────────────────────────────────────────────
+
+# node type → kind, per grammar family; wrappers are descended through.
+_TS_KINDS: dict[str, dict[str, OutlineKind]] = {
+    "rust": {
+        "function_item": "function",
+        "function_signature_item": "function",
+        "struct_item": "struct",
+        "enum_item": "enum",
+        "union_item": "struct",
+        "trait_item": "trait",
+        "impl_item": "impl",
+        "type_item": "type",
+        "const_item": "constant",
+        "static_item": "constant",
+        "mod_item": "module",
+    },
+    "python": {"function_definition": "function", "class_definition": "class"},
+    "typescript": {
+        "function_declaration": "function",
+        "generator_function_declaration": "function",
+        "class_declaration": "class",
+        "abstract_class_declaration": "class",
+        "interface_declaration": "interface",
+        "type_alias_declaration": "type",
+        "enum_declaration": "enum",
+        "method_definition": "method",
+        "method_signature": "method",
+        "abstract_method_signature": "method",
+        "internal_module": "module",
+    },
+    "go": {
+        "function_declaration": "function",
+        "method_declaration": "method",
+        "type_spec": "type",
+        "const_spec": "constant",
+    },
+}
+_TS_FAMILY = {"tsx": "typescript", "javascript": "typescript"}
+_TS_WRAPPERS = {
+    "decorated_definition",
+    "export_statement",
+    "type_declaration",
+    "const_declaration",
+    "lexical_declaration",
+    "variable_declarator",
+    "declaration_list",
+    "class_body",
+    "interface_body",
+    "object_type",
+    "block",
+    "statement_block",
+}
+
+
+def _ts_name(node: Any) -> str:
+    for field in ("name", "type"):
+        if (child := node.child_by_field_name(field)) is not None:
+            return child.text.decode(errors="replace")
+    return ""
+
+
+def _ts_public(
+    node: Any, family: str, name: str, exported: bool, in_type: bool
+) -> bool:
+    if family == "typescript" and in_type:
+        hidden = (b"private", b"protected")
+        return not name.startswith("#") and not any(
+            c.type == "accessibility_modifier" and c.text in hidden
+            for c in node.children
+        )
+    if family == "rust":
+        return any(c.type == "visibility_modifier" for c in node.children)
+    if family == "go":
+        return name[:1].isupper()
+    if family == "python":
+        return not name.startswith("_")
+    return exported
+
+
+def _ts_items(
+    nodes: list[Any], source: bytes, family: str, exported: bool, in_type: bool
+) -> list[OutlineItem]:
+    kinds = _TS_KINDS[family]
+    items: list[OutlineItem] = []
+    for node in nodes:
+        kind = kinds.get(node.type)
+        if kind is None:
+            if node.type in _TS_WRAPPERS:
+                items += _ts_items(
+                    node.named_children,
+                    source,
+                    family,
+                    exported or node.type == "export_statement",
+                    in_type,
+                )
+            continue
+        if kind == "function" and in_type:
+            kind = "method"
+        if family == "go" and kind == "type":
+            inner = node.child_by_field_name("type")
+            inner_type = inner.type if inner is not None else ""
+            kind = {"struct_type": "struct", "interface_type": "interface"}.get(
+                inner_type, "type"
+            )
+        body = node.child_by_field_name("body")
+        if body is not None:
+            head = source[node.start_byte : body.start_byte]
+        else:
+            head = node.text
+        name = _ts_name(node)
+        children: list[OutlineItem] = []
+        if body is not None and kind in ("class", "impl", "trait", "interface"):
+            children = _ts_items(body.named_children, source, family, False, True)
+        items.append(
+            OutlineItem(
+                kind=kind,
+                name=name,
+                signature=_signature(
+                    head.decode(errors="replace").split("\n")[0]
+                    if body is None
+                    else head.decode(errors="replace")
+                ),
+                line=node.start_point[0] + 1,
+                end_line=node.end_point[0] + 1,
+                public=_ts_public(node, family, name, exported, in_type),
+                children=children,
+            )
+        )
+    return items
+
+
+def _tree_sitter_items(source: bytes, language: str) -> list[OutlineItem] | None:
+    if _ts_parser is None:
+        return None
+    try:
+        parser = _ts_parser(language)
+    except (LookupError, ValueError, RuntimeError):
+        return None
+    tree = parser.parse(source)
+    family = _TS_FAMILY.get(language, language)
+    return _ts_items(tree.root_node.named_children, source, family, False, False)
+
+
+# ── Built-in fallbacks ────────────────────────────────────────────────────────
+
+
+def _python_items(
+    nodes: list[ast.stmt], lines: list[str], in_class: bool
+) -> list[OutlineItem]:
+    items: list[OutlineItem] = []
+    for node in nodes:
+        if isinstance(node, (ast.FunctionDef, ast.AsyncFunctionDef, ast.ClassDef)):
+            head = "\n".join(lines[node.lineno - 1 : node.body[0].lineno - 1])
+            is_class = isinstance(node, ast.ClassDef)
+            items.append(
+                OutlineItem(
+                    kind="class" if is_class else "method" if in_class else "function",
+                    name=node.name,
+                    signature=_signature(head or lines[node.lineno - 1]),
+                    line=node.lineno,
+                    end_line=node.end_lineno or node.lineno,
+                    public=not node.name.startswith("_"),
+                    children=_python_items(node.body, lines, True) if is_class else [],
+                )
+            )
+        elif isinstance(node, (ast.Assign, ast.AnnAssign)) and not in_class:
+            targets = node.targets if isinstance(node, ast.Assign) else [node.target]
+            for target in targets:
+                if isinstance(target, ast.Name) and target.id.isupper():
+                    items.append(
+                        OutlineItem(
+                            kind="constant",
+                            name=target.id,
+                            signature=_signature(lines[node.lineno - 1]),
+                            line=node.lineno,
+                            end_line=node.end_lineno or node.lineno,
+                            public=not target.id.startswith("_"),
+                        )
+                    )
+    return items
+
+
+_PATTERNS: dict[str, re.Pattern[str]] = {
+    "rust": re.compile(
+        r"^(?P<indent>\s*)(?P<vis>pub(?:\([^)]*\))?\s+)?"
+        r"(?:(?:async|const|unsafe|default|extern\s+\"[^\"]*\")\s+)*"
+        r"(?P<kw>fn|struct|enum|union|trait|impl|type|const|static|mod)\b"
+        r"(?:<[^>]*>)?\s*(?P<name>[A-Za-z_][\w:<>, ]*)"
+    ),
+    "typescript": re.compile(
+        r"^(?P<indent>\s*)(?P<vis>export\s+(?:default\s+)?)?(?:declare\s+)?"
+        r"(?:abstract\s+)?(?:async\s+)?"
+        r"(?P<kw>function\*?|class|interface|type|enum|namespace)\s+"
+        r"(?P<name>[A-Za-z_$][\w$]*)"
+    ),
+    "go": re.compile(
+        r"^(?P<indent>)(?P<kw>func|type)\s+(?P<recv>\([^)]*\)\s*)?"
+        r"(?P<name>[A-Za-z_]\w*)(?P<rest>.*)"
+    ),
+}
+_KEYWORD_KINDS: dict[str, OutlineKind] = {
+    "fn": "function",
+    "struct": "struct",
+    "union": "struct",
+    "enum": "enum",
+    "trait": "trait",
+    "impl": "impl",
+    "type": "type",
+    "const": "constant",
+    "static": "constant",
+    "mod": "module",
+    "function": "function",
+    "function*": "function",
+    "class": "class",
+    "interface": "interface",
+    "namespace": "module",
+    "func": "function",
+}
+_CONTAINERS = {"class", "impl", "trait", "interface"}
+
+
+def _pattern_items(lines: list[str], family: str) -> list[OutlineItem]:
+    pattern = _PATTERNS[family]
+    # (indent, item fields, children) of the containers still open
+    found: list[tuple[int, dict[str, Any], list[dict[str, Any]]]] = []
+    top: list[dict[str, Any]] = []
+    for number, line in enumerate(lines, 1):
+        match = pattern.match(line)
+        if match is None:
+            continue
+        groups = match.groupdict()
+        indent = len(groups["indent"])
+        kind = _KEYWORD_KINDS[groups["kw"]]
+        name = groups["name"].strip()
+        if kind != "impl":
+            name = re.match(r"[\w$]*", name).group()
+        if family == "go":
+            rest = groups["rest"].split()
+            if groups["recv"]:
+                kind = "method"
+            elif kind == "type" and rest[:1] in (["struct"], ["interface"]):
+                kind = "struct" if rest[0] == "struct" else "interface"
+            public = name[:1].isupper()
+        else:
+            public = bool(groups["vis"])
+        while found and found[-1][0] >= indent:
+            found.pop()
+        if found and kind == "function":
+            kind = "method"
+            public = public or family == "typescript"
+        fields: dict[str, Any] = {
+            "kind": kind,
+            "name": name,
+            "signature": _signature(line),
+            "line": number,
+            "end_line": number,
+            "public": public,
+            "children": [],
+        }
+        (found[-1][2] if found else top).append(fields)
+        if kind in _CONTAINERS:
+            found.append((indent, fields, fields["children"]))
+    return [OutlineItem.model_validate(fields) for fields in top]
+
+
+# ── Entry points ──────────────────────────────────────────────────────────────
+
+
+def _public_only(items: list[OutlineItem]) -> list[OutlineItem]:
+    return [
+        item.model_copy(update={"children": _public_only(item.children)})
+        for item in items
+        if item.public or item.kind == "impl"
+    ]
+
+
+def _parse_file(path: Path) -> FileOutline:
+    language = OUTLINE_LANGUAGES[path.suffix.lower()]
+    source = path.read_bytes()[:_MAX_BYTES]
+    items = _tree_sitter_items(source, language)
+    parser: Literal["tree-sitter", "ast", "patterns"] = "tree-sitter"
+    if items is None:
+        text = source.decode("utf-8", errors="replace")
+        lines = text.splitlines()
+        if language == "python":
+            parser = "ast"
+            try:
+                items = _python_items(ast.parse(text).body, lines, False)
+            except SyntaxError:
+                items = []
+        else:
+            parser = "patterns"
+            items = _pattern_items(lines, _TS_FAMILY.get(language, language))
+    return FileOutline(path="", language=language, parser=parser, items=items)
+
+
+def _outline_path(
+    sandbox: Sandbox, path: Path, public_only: bool, index: RepoIndex
+) -> FileOutline:
+    kind = "outline-tree-sitter" if _ts_parser is not None else "outline"
+    parsed = index.cached(kind, path, FileOutline, _parse_file)
+    if parsed is None:
+        raise SandboxError(f"'{sandbox.rel(path)}' cannot be read.")
+    items = _public_only(parsed.items) if public_only else parsed.items
+    return parsed.model_copy(update={"path": sandbox.rel(path), "items": items})
+
+
+def outline_file(sandbox: Sandbox, path: str, public_only: bool = False) -> FileOutline:
+    """The outline of one source file in *sandbox*.
+
+    Raises:
+        SandboxError: If the sandbox refuses *path*, or it is not a file in a
+            supported language.
+    """
+    target = sandbox.resolve(path)
+    if not target.is_file():
+        raise SandboxError(f"'{path}' is not a file.")
+    if target.suffix.lower() not in OUTLINE_LANGUAGES:
+        supported = ", ".join(sorted(OUTLINE_LANGUAGES))
+        raise SandboxError(f"'{path}' is not a supported source file ({supported}).")
+    index = RepoIndex.open(sandbox.root)
+    result = _outline_path(sandbox, target, public_only, index)
+    index.save()
+    return result
+
+
+def outline(sandbox: Sandbox, path: str = ".", public_only: bool = False) -> Outline:
+    """Outlines of *path*, or of every supported file below it, in path order.
+
+    Raises:
+        SandboxError: If the sandbox refuses *path* or it is an unsupported file.
+    """
+    target = sandbox.resolve(path)
+    if target.is_file():
+        return Outline(
+            path=sandbox.rel(target),
+            files=[outline_file(sandbox, path, public_only)],
+        )
+    if not target.is_dir():
+        raise SandboxError(f"'{path}' does not exist.")
+    index = RepoIndex.open(sandbox.root)
+    files: list[FileOutline] = []
+    truncated = False
+    for source in iter_files(target, suffixes=OUTLINE_LANGUAGES):
+        if len(files) >= _MAX_FILES:
+            truncated = True
+            break
+        resolved = source.resolve()
+        if not resolved.is_relative_to(sandbox.root):
+            continue
+        if sandbox.denied_by(resolved) is None:
+            files.append(_outline_path(sandbox, source, public_only, index))
+    index.save()
+    return Outline(path=sandbox.rel(target), files=files, truncated=truncated)
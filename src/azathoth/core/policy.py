@@ -0,0 +1,193 @@
+"""azathoth.core.policy — safety policies enforced before mutating operations.
+
+Public surface:
+  - ``pause_file(cwd)`` → path of the ``.azathoth/pause`` sentinel
+  - ``pause_state(cwd)`` → ``PauseState`` (whether mutations are halted, and why)
+  - ``ensure_mutations_allowed(action, cwd)`` → raises ``PolicyDenied`` while paused
+  - ``set_paused(paused)`` → toggles the agent's in-process pause
+  - ``is_protected_branch(name)`` → matches ``workflow_protected_branches``
+  - ``protected_branch_names()`` → the entries that are plain branch names
+  - ``ensure_branch_writable(action, allow_protected, cwd, branch)`` → raises
+    ``PolicyDenied`` on (or when pushing to) a protected branch
+  - ``ToolClass`` → ``read_only``, ``mutating`` or ``destructive``
+  - ``ensure_approved(action, token)`` → raises ``PolicyDenied`` in approval
+    mode unless *token* is the configured approval token
+
+The kill-switch is deliberately client-agnostic: ``touch .azathoth/pause``
+halts every mutating tool on every server, whatever MCP client is driving
+the agent.  ``set_paused`` (the ``pause_mutations`` tool) keeps its own
+in-process flag, so resuming through ``set_paused(False)`` only lifts a
+pause the agent set: one the user set with ``mutations_paused`` or the
+sentinel file can only be lifted by the user.
+
+Approval mode is for servers shared with other people's repositories:
+destructive tools (history rewrites, branch deletion, tags pushed by a
+release, publishing) are refused until the client presents the token the
+operator configured, so an agent cannot approve itself.
+"""
+
+from __future__ import annotations
+
+import hmac
+from fnmatch import fnmatchcase
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import PolicyDenied
+from azathoth.core.repos import repo_dir
+from azathoth.core.workflow import _run_git
+
+PAUSE_FILE = Path(".azathoth") / "pause"
+
+ToolClass = Literal["read_only", "mutating", "destructive"]
+
+_paused_by_agent = False
+
+
+class PauseState(BaseModel, frozen=True):
+    """Whether mutations are currently halted, and by which switch."""
+
+    paused: bool
+    by_file: bool = False
+    by_config: bool = False
+    by_agent: bool = False
+    reason: str = ""
+
+    def describe(self) -> str:
+        if not self.paused:
+            return "Mutations are allowed."
+        sources = []
+        if self.by_file:
+            sources.append(f"{PAUSE_FILE} is present")
+        if self.by_config:
+            sources.append("mutations_paused is set")
+        if self.by_agent:
+            sources.append("pause_mutations was called")
+        text = f"Mutations are paused ({' and '.join(sources)})"
+        return f"{text}: {self.reason}" if self.reason else f"{text}."
+
+
+def pause_file(cwd: str | None = None) -> Path:
+    """Return the sentinel path for the working tree at *cwd*."""
+    return Path(cwd or repo_dir() or ".") / PAUSE_FILE
+
+
+def pause_state(cwd: str | None = None) -> PauseState:
+    """Inspect every kill-switch.  The sentinel's content, if any, is the reason."""
+    sentinel = pause_file(cwd)
+    by_file = sentinel.is_file()
+    by_config = get_config().mutations_paused
+    reason = ""
+    if by_file:
+        try:
+            reason = sentinel.read_text(encoding="utf-8").strip()
+        except OSError:
+            pass
+    return PauseState(
+        paused=by_file or by_config or _paused_by_agent,
+        by_file=by_file,
+        by_config=by_config,
+        by_agent=_paused_by_agent,
+        reason=reason,
+    )
+
+
+def ensure_mutations_allowed(action: str, cwd: str | None = None) -> None:
+    """Raise ``PolicyDenied`` if a kill-switch is active.
+
+    Args:
+        action: Name of the operation being attempted (used in the message).
+        cwd:    Working tree whose ``.azathoth/pause`` is checked.
+    """
+    state = pause_state(cwd)
+    if state.paused:
+        raise PolicyDenied(
+            f"PolicyDenied: '{action}' was blocked. {state.describe()} "
+            "Only the user can resume."
+        )
+
+
+def set_paused(paused: bool) -> None:
+    """Set the agent's pause; the user's config flag and sentinel are untouched."""
+    global _paused_by_agent
+    _paused_by_agent = paused
+
+
+# ── Protected branches ───────────────────────────────────────────────────
+
+
+def is_protected_branch(name: str) -> bool:
+    """Whether *name* matches an entry of ``workflow_protected_branches``."""
+    return any(
+        fnmatchcase(name, pattern)
+        for pattern in get_config().workflow_protected_branches
+    )
+
+
+def protected_branch_names() -> list[str]:
+    """Protected entries naming a branch outright (patterns like ``release/*``
+    are skipped), in configured order."""
+    return [
+        name
+        for name in get_config().workflow_protected_branches
+        if not any(ch in name for ch in "*?[")
+    ]
+
+
+async def ensure_branch_writable(
+    action: str,
+    allow_protected: bool = False,
+    cwd: str | None = None,
+    branch: str | None = None,
+) -> None:
+    """Raise ``PolicyDenied`` if *branch* (default: HEAD's) is protected.
+
+    Pushing tools pass the branch they push.  Detached HEADs and non-git
+    working copies are not checked.
+    """
+    if allow_protected:
+        return
+    if branch is None:
+        code, branch, _ = await _run_git(
+            ["symbolic-ref", "--quiet", "--short", "HEAD"], cwd=cwd
+        )
+        if code != 0:
+            return
+    if is_protected_branch(branch):
+        raise PolicyDenied(
+            f"PolicyDenied: '{action}' would write directly to protected branch "
+            f"'{branch}'. Switch to a feature branch (create_branch), or pass "
+            "allow_protected=true if that is intended."
+        )
+
+
+# ── Approval ─────────────────────────────────────────────────────────────
+
+
+def ensure_approved(action: str, token: str | None) -> None:
+    """Raise ``PolicyDenied`` if approval mode is on and *token* does not match.
+
+    With ``approval_required`` set and no ``approval_token`` configured,
+    every destructive call is refused.
+    """
+    settings = get_config()
+    if not settings.approval_required:
+        return
+    expected = settings.approval_token
+    if expected is None:
+        raise PolicyDenied(
+            f"Approval required: '{action}' is destructive and no approval_token "
+            "is configured, so it cannot be approved on this server."
+        )
+    if token is None or not hmac.compare_digest(
+        token.encode(), expected.get_secret_value().encode()
+    ):
+        problem = "a wrong approval token" if token else "no approval token"
+        raise PolicyDenied(
+            f"Approval required: '{action}' is destructive and the call carried "
+            f"{problem}. Ask the user to approve it; the client must send the "
+            "token as approval_token in the request's _meta."
+        )
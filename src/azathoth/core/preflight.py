@@ -0,0 +1,193 @@
+"""azathoth.core.preflight — problems to fix before committing.
+
+Public surface:
+  - ``ARTIFACT_PATTERNS``  — globs of build output that should be ignored
+  - ``is_artifact(path)``  → whether *path* is build output
+  - ``preflight(cwd)``     → ``PreflightReport``
+
+Checks what ``git add .`` followed by a commit would pick up — staged,
+modified and untracked files — for:
+
+  - **merge-marker** (error): ``<<<<<<<`` / ``>>>>>>>`` left from a conflict
+  - **large-file** (error): files over ``workflow_max_file_size`` bytes
+  - **build-artifact** (warning): untracked build output (``target/``,
+    ``dist/``, ``__pycache__/``, ``*.o`` …) missing from ``.gitignore``
+  - **missing-newline** (warning): text files not ending with a newline
+  - **behind-upstream** (warning): the branch is behind its upstream as of
+    the last fetch, so the commit will need a rebase or merge to push
+
+``ok`` is false while any error remains.  Every issue carries the fix an
+agent can apply.
+"""
+
+from __future__ import annotations
+
+from fnmatch import fnmatch
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+from azathoth.core.branches import list_branches
+from azathoth.core.conflicts import has_conflict_markers
+from azathoth.core.files import is_binary
+from azathoth.core.workflow import get_repo_context, get_repo_status
+
+Check = Literal[
+    "merge-marker", "large-file", "build-artifact", "missing-newline", "behind-upstream"
+]
+
+#: Untracked paths matching these (or inside such a directory) are build output.
+ARTIFACT_PATTERNS = (
+    "target/*",
+    "build/*",
+    "dist/*",
+    "node_modules/*",
+    "__pycache__/*",
+    ".pytest_cache/*",
+    ".mypy_cache/*",
+    ".ruff_cache/*",
+    "*.egg-info/*",
+    "coverage/*",
+    "*.pyc",
+    "*.o",
+    "*.obj",
+    "*.so",
+    "*.dylib",
+    "*.dll",
+    "*.exe",
+    "*.class",
+    "*.log",
+    ".DS_Store",
+)
+_SAMPLE_BYTES = 8192
+_MAX_READ_BYTES = 2_000_000
+
+
+class PreflightIssue(BaseModel, frozen=True):
+    check: Check
+    severity: Literal["error", "warning"]
+    path: str | None = None
+    message: str
+    fix: str
+
+
+class PreflightReport(BaseModel, frozen=True):
+    """What to fix before committing; ``ok`` when nothing blocks it."""
+
+    ok: bool
+    branch: str
+    files_checked: int
+    issues: list[PreflightIssue] = Field(default_factory=list)
+
+
+def is_artifact(path: str) -> bool:
+    """Whether *path* looks like build output by ``ARTIFACT_PATTERNS``."""
+    parts = path.split("/")
+    candidates = ["/".join(parts[i:]) for i in range(len(parts))]
+    return any(fnmatch(c, p) for c in candidates for p in ARTIFACT_PATTERNS)
+
+
+def _artifact_root(path: str) -> str:
+    """The ignorable directory (``dist/``) or file pattern for *path*."""
+    for part in path.split("/")[:-1]:
+        if any(fnmatch(f"{part}/x", p) for p in ARTIFACT_PATTERNS):
+            return f"{part}/"
+    return f"*{Path(path).suffix}" if Path(path).suffix else Path(path).name
+
+
+def _file_issues(root: Path, path: str, limit: int) -> list[PreflightIssue]:
+    file = root / path
+    if not file.is_file() or file.is_symlink():
+        return []
+    size = file.stat().st_size
+    if limit and size > limit:
+        return [
+            PreflightIssue(
+                check="large-file",
+                severity="error",
+                path=path,
+                message=f"{path} is {size:,} bytes (limit {limit:,}).",
+                fix="Leave it out of the commit (unstage it and add it to "
+                ".gitignore) or track it with Git LFS.",
+            )
+        ]
+    if size == 0 or size > _MAX_READ_BYTES:
+        return []
+    data = file.read_bytes()
+    if is_binary(data[:_SAMPLE_BYTES]):
+        return []
+    issues = []
+    if has_conflict_markers(data.decode("utf-8", errors="replace")):
+        issues.append(
+            PreflightIssue(
+                check="merge-marker",
+                severity="error",
+                path=path,
+                message=f"{path} still contains conflict markers.",
+                fix="Resolve the conflict and remove the <<<<<<< / ======= / "
+                ">>>>>>> lines.",
+            )
+        )
+    if not data.endswith(b"\n"):
+        issues.append(
+            PreflightIssue(
+                check="missing-newline",
+                severity="warning",
+                path=path,
+                message=f"{path} does not end with a newline.",
+                fix="Append a newline to the end of the file.",
+            )
+        )
+    return issues
+
+
+async def preflight(cwd: str | None = None) -> PreflightReport:
+    """Check the changes a commit of everything would include."""
+    context = await get_repo_context(cwd)
+    root = Path(context.root or cwd or ".")
+    status = await get_repo_status(cwd)
+    limit = get_config().workflow_max_file_size
+
+    issues: list[PreflightIssue] = []
+    artifacts: dict[str, int] = {}
+    for path in status.untracked:
+        if is_artifact(path):
+            key = _artifact_root(path)
+            artifacts[key] = artifacts.get(key, 0) + 1
+    for pattern, count in sorted(artifacts.items()):
+        issues.append(
+            PreflightIssue(
+                check="build-artifact",
+                severity="warning",
+                path=pattern,
+                message=f"{count} untracked build artifact(s) under {pattern}.",
+                fix=f"Add '{pattern}' to .gitignore instead of committing it.",
+            )
+        )
+
+    changed = {c.path for c in (*status.staged, *status.unstaged) if c.status != "D"}
+    candidates = sorted(changed | {p for p in status.untracked if not is_artifact(p)})
+    for path in candidates:
+        issues += _file_issues(root, path, limit)
+
+    current = next((b for b in await list_branches(cwd) if b.current), None)
+    if current is not None and current.behind:
+        issues.append(
+            PreflightIssue(
+                check="behind-upstream",
+                severity="warning",
+                message=(
+                    f"{current.name} is {current.behind} commit(s) behind "
+                    f"{current.upstream} (as of the last fetch)."
+                ),
+                fix="Pull (rebase) before or right after committing, then push.",
+            )
+        )
+    return PreflightReport(
+        ok=not any(i.severity == "error" for i in issues),
+        branch=status.branch,
+        files_checked=len(candidates),
+        issues=issues,
+    )
@@ -0,0 +1,83 @@
+"""azathoth.core.progress — live forwarding of subprocess output.
+
+Public surface:
+  - ``stream_output(sink)``  — context manager; inside it, output of every
+    command run through ``core.workflow.run_command`` / ``core.tasks.run_task``
+    is passed to ``sink`` line by line as it arrives
+  - ``collect(process)``     → ``(stdout, stderr)`` bytes, like
+    ``process.communicate()`` but feeding the active sink
+
+The sink lives in a ``ContextVar`` (as ``core.audit`` does for commands), so
+core functions need no callback parameters and concurrent tool calls never
+see each other's output.  Carriage returns count as line breaks so progress
+bars (``git push``, cargo) stream too.  Output is still returned in full;
+streaming is purely additional.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+import re
+from collections.abc import Awaitable, Callable, Iterator
+from contextlib import contextmanager
+from contextvars import ContextVar
+
+log = logging.getLogger(__name__)
+
+OutputSink = Callable[[str], Awaitable[None]]
+
+#: Longer lines are clipped before reaching the sink (never in the result).
+MAX_LINE_CHARS = 500
+
+_LINE_BREAK = re.compile(rb"\r\n|\r|\n")
+
+_sink: ContextVar[OutputSink | None] = ContextVar("output_sink", default=None)
+
+
+@contextmanager
+def stream_output(sink: OutputSink) -> Iterator[None]:
+    """Send command output produced inside the block to *sink*."""
+    token = _sink.set(sink)
+    try:
+        yield
+    finally:
+        _sink.reset(token)
+
+
+async def _emit(sink: OutputSink, raw: bytes) -> None:
+    line = raw.decode(errors="replace").rstrip()
+    if not line:
+        return
+    try:
+        await sink(line[:MAX_LINE_CHARS])
+    except Exception as exc:  # a lost notification must not fail the command
+        log.debug("Output sink failed: %s", exc)
+
+
+async def _pump(
+    reader: asyncio.StreamReader | None, sink: OutputSink, chunks: list[bytes]
+) -> None:
+    if reader is None:
+        return
+    pending = b""
+    while chunk := await reader.read(4096):
+        chunks.append(chunk)
+        *lines, pending = _LINE_BREAK.split(pending + chunk)
+        for line in lines:
+            await _emit(sink, line)
+    await _emit(sink, pending)
+
+
+async def collect(process: asyncio.subprocess.Process) -> tuple[bytes, bytes]:
+    """Read *process* to completion, streaming lines if a sink is active."""
+    sink = _sink.get()
+    if sink is None:
+        return await process.communicate()
+    out: list[bytes] = []
+    err: list[bytes] = []
+    await asyncio.gather(
+        _pump(process.stdout, sink, out), _pump(process.stderr, sink, err)
+    )
+    await process.wait()
+    return b"".join(out), b"".join(err)
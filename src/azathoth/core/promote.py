@@ -0,0 +1,282 @@
+"""azathoth.core.promote — promote a release candidate to a final release.
+
+Public surface:
+  - ``final_tag(rc_tag)``                     → ``v1.2.0`` for ``v1.2.0-rc.2``
+  - ``parse_checksums(text)``                 → ``{file name: sha256}``
+  - ``verify_checksums(directory)``           → ``(verified, problems)``
+  - ``promote_release_candidate(rc_tag, …)``  → ``Promotion``
+
+Promotion encodes the usual RC → GA routine: every CI run on the RC's
+commit must have passed, every downloaded release asset must match a
+published checksum (``SHA256SUMS``, ``checksums.txt`` or ``<asset>.sha256``),
+then the final tag is created on the *same* commit and released with the
+RC's notes and assets, marked as the latest release.  Checks happen before
+anything is tagged; a dry run performs them and returns the plan.
+
+GitHub only for now: CI status and the "latest" flag come from ``gh``.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import json
+import re
+import tempfile
+from pathlib import Path
+
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.release import get_release_backend
+from azathoth.core.tags import tag_command
+from azathoth.core.workflow import _run_git, format_command, run_command
+
+_PRERELEASE = re.compile(
+    r"^(v?\d+\.\d+\.\d+)-(?:rc|alpha|beta|pre|preview)(?:[.-]?\d+)?$", re.I
+)
+_CHECKSUM_LINE = re.compile(r"^([0-9a-fA-F]{64})\s+\*?(.+?)\s*$")
+_CHECKSUM_FILES = {"sha256sums", "sha256sums.txt", "checksums.txt", "checksums"}
+_PASSING = {"success", "skipped", "neutral"}
+
+
+class CheckRun(BaseModel, frozen=True):
+    name: str
+    status: str
+    conclusion: str = ""
+
+    @property
+    def passed(self) -> bool:
+        return self.status == "completed" and self.conclusion in _PASSING
+
+
+class Promotion(BaseModel, frozen=True):
+    """Outcome (or dry-run plan) of an RC → final promotion."""
+
+    rc_tag: str
+    tag: str
+    commit: str
+    checks: list[CheckRun] = Field(default_factory=list)
+    verified_assets: list[str] = Field(default_factory=list)
+    published: bool = False
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+# ── Pure helpers ──────────────────────────────────────────────────────────────
+
+
+def final_tag(rc_tag: str) -> str:
+    """Strip the pre-release suffix from *rc_tag*.
+
+    Raises:
+        WorkflowError: If *rc_tag* is not a pre-release version tag.
+    """
+    match = _PRERELEASE.match(rc_tag.strip())
+    if not match:
+        raise WorkflowError(f"'{rc_tag}' is not a pre-release tag (e.g. v1.2.0-rc.1).")
+    return match.group(1)
+
+
+def _is_checksum_file(name: str) -> bool:
+    return name.lower() in _CHECKSUM_FILES or name.lower().endswith(".sha256")
+
+
+def parse_checksums(text: str, default_name: str | None = None) -> dict[str, str]:
+    """Parse ``sha256sum`` output; a bare hash is attributed to *default_name*."""
+    sums: dict[str, str] = {}
+    for line in text.splitlines():
+        line = line.strip()
+        if match := _CHECKSUM_LINE.match(line):
+            sums[Path(match.group(2)).name] = match.group(1).lower()
+        elif default_name and re.fullmatch(r"[0-9a-fA-F]{64}", line):
+            sums[default_name] = line.lower()
+    return sums
+
+
+def _sha256(path: Path) -> str:
+    digest = hashlib.sha256()
+    with open(path, "rb") as f:
+        for block in iter(lambda: f.read(1 << 20), b""):
+            digest.update(block)
+    return digest.hexdigest()
+
+
+def verify_checksums(directory: Path) -> tuple[list[str], list[str]]:
+    """Check every asset in *directory* against the checksum files beside it.
+
+    Returns ``(verified asset names, problems)``; an asset no checksum file
+    covers is a problem, as is a mismatch.
+    """
+    files = sorted(p for p in directory.iterdir() if p.is_file())
+    expected: dict[str, str] = {}
+    for path in files:
+        if _is_checksum_file(path.name):
+            owner = path.name[: -len(".sha256")] if path.suffix == ".sha256" else None
+            expected |= parse_checksums(path.read_text(errors="ignore"), owner)
+
+    verified: list[str] = []
+    problems: list[str] = []
+    for path in files:
+        if _is_checksum_file(path.name):
+            continue
+        want = expected.get(path.name)
+        if want is None:
+            problems.append(f"{path.name}: no published checksum")
+        elif _sha256(path) != want:
+            problems.append(f"{path.name}: checksum mismatch")
+        else:
+            verified.append(path.name)
+    return verified, problems
+
+
+# ── Forge queries ─────────────────────────────────────────────────────────────
+
+
+async def _gh_json(args: list[str], cwd: str | None) -> object:
+    code, out, err = await run_command(["gh", *args], cwd=cwd)
+    if code != 0:
+        raise WorkflowError(f"gh {args[0]} {args[1]} failed: {err or out}")
+    try:
+        return json.loads(out or "null")
+    except json.JSONDecodeError as exc:
+        raise WorkflowError(f"Unexpected gh output: {exc}") from exc
+
+
+async def ci_runs(commit: str, cwd: str | None = None) -> list[CheckRun]:
+    """Workflow runs GitHub Actions recorded for *commit*."""
+    runs = await _gh_json(
+        [
+            "run",
+            "list",
+            "--commit",
+            commit,
+            "--json",
+            "name,status,conclusion",
+            "--limit",
+            "100",
+        ],
+        cwd,
+    )
+    return [
+        CheckRun(
+            name=r.get("name", ""),
+            status=r.get("status", ""),
+            conclusion=r.get("conclusion") or "",
+        )
+        for r in runs or []
+    ]
+
+
+async def _tag_commit(tag: str, cwd: str | None) -> str | None:
+    code, out, _ = await _run_git(["rev-list", "-n", "1", tag, "--"], cwd=cwd)
+    return out if code == 0 and out else None
+
+
+# ── Promotion ─────────────────────────────────────────────────────────────────
+
+
+async def promote_release_candidate(
+    rc_tag: str,
+    allow_missing_ci: bool = False,
+    dry_run: bool = False,
+    cwd: str | None = None,
+) -> Promotion:
+    """Verify *rc_tag* and publish its final release on the same commit.
+
+    Raises:
+        WorkflowError: If the forge is not GitHub, the tags are wrong, CI did
+            not pass, an asset fails verification, or a command fails.
+    """
+    backend = await get_release_backend(cwd)
+    if backend.name != "github":
+        raise WorkflowError(
+            f"RC promotion needs GitHub (gh); this repo releases on {backend.label}."
+        )
+    tag = final_tag(rc_tag)
+    commit = await _tag_commit(rc_tag, cwd)
+    if commit is None:
+        raise WorkflowError(f"Tag {rc_tag} does not exist locally; fetch tags first.")
+    if await _tag_commit(tag, cwd) is not None:
+        raise WorkflowError(f"Tag {tag} already exists.")
+
+    checks = await ci_runs(commit, cwd)
+    if not checks and not allow_missing_ci:
+        raise WorkflowError(
+            f"No CI runs found for {commit[:7]}; pass allow_missing_ci to promote "
+            "without CI."
+        )
+    if failing := [c for c in checks if not c.passed]:
+        listing = ", ".join(f"{c.name} ({c.conclusion or c.status})" for c in failing)
+        raise WorkflowError(f"CI has not passed on {rc_tag}: {listing}")
+
+    release = await _gh_json(["release", "view", rc_tag, "--json", "body,assets"], cwd)
+    notes = (release or {}).get("body", "").replace(rc_tag, tag)
+    has_assets = bool((release or {}).get("assets"))
+
+    with tempfile.TemporaryDirectory(prefix="azathoth-promote-") as tmp:
+        assets: list[Path] = []
+        verified: list[str] = []
+        if has_assets:
+            code, out, err = await run_command(
+                ["gh", "release", "download", rc_tag, "--dir", tmp], cwd=cwd
+            )
+            if code != 0:
+                raise WorkflowError(f"Downloading {rc_tag} assets failed: {err or out}")
+            verified, problems = verify_checksums(Path(tmp))
+            if problems:
+                raise WorkflowError(
+                    f"Asset verification failed for {rc_tag}: {'; '.join(problems)}"
+                )
+            assets = sorted(Path(tmp).iterdir())
+
+        tag_cmd = tag_command(
+            tag, f"Release {tag}", get_config().release_tag_kind, commit
+        )
+        push_cmd = ["push", "origin", tag]
+        publish_cmd = [
+            "gh",
+            "release",
+            "create",
+            tag,
+            "--verify-tag",
+            "--latest",
+            "--title",
+            f"Release {tag}",
+            "--notes",
+            notes,
+        ]
+        if dry_run:
+            upload = [p.name for p in assets]
+            return Promotion(
+                rc_tag=rc_tag,
+                tag=tag,
+                commit=commit,
+                checks=checks,
+                verified_assets=verified,
+                commands=[
+                    format_command(["git", *tag_cmd]),
+                    format_command(["git", *push_cmd]),
+                    format_command([*publish_cmd, *upload]),
+                ],
+            )
+
+        for args, action in ((tag_cmd, "Tagging"), (push_cmd, "Pushing tag")):
+            code, out, err = await _run_git(args, cwd=cwd)
+            if code != 0:
+                raise WorkflowError(f"{action} {tag} failed: {err or out}")
+        code, out, err = await run_command(
+            [*publish_cmd, *(str(p) for p in assets)], cwd=cwd
+        )
+        if code != 0:
+            raise WorkflowError(
+                f"Tag {tag} was pushed but publishing failed: {err or out}"
+            )
+
+    return Promotion(
+        rc_tag=rc_tag,
+        tag=tag,
+        commit=commit,
+        checks=checks,
+        verified_assets=verified,
+        published=True,
+    )
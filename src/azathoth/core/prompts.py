@@ -1,23 +1,76 @@
+"""azathoth.core.prompts — the prompts the MCP servers and CLI hand to models.
+
+Public surface — one builder per prompt, named after it:
+  - ``explore(target_directory, windows)``                 — scout a codebase
+  - ``onboard(target_directory, role)``                    — a new developer's guide
+  - ``review(files, languages, directives, guidance, …)``  — review a PR or range
+  - ``autocommit(focus, policy, scope)``                   — stage and commit
+  - ``autofix(max_iterations, commit, focus)``             — fix until green
+  - ``autorelease(new_version, repo_url, old_version, …)`` — notes, bump, publish
+  - ``autotriage(labels, limit, focus)``                   — label and answer issues
+  - ``run_workflow(plan, embedded)``                      — a YAML workflow's steps
+  - ``commit_system(focus, policy)`` / ``release_system()`` — JSON-mode system
+    prompts for direct LLM calls
+
+Servers, the CLI and downstream code build prompts through these
+(``prompts.autocommit(focus="the login fix")``) rather than copying the
+wording.  Each prompt is a Jinja template rendered through
+``core.templates``: an ``<name>.md.j2`` file in the user's prompts directory
+or the checkout's ``assets/prompts`` wins, and the ``*_TEMPLATE`` source
+below is the built-in fallback.  Keep the two in step when changing the
+stock wording.
+"""
+
+from collections.abc import Mapping, Sequence
 from typing import Optional
 
+from azathoth.core.commit_policy import CommitPolicy
+from azathoth.core.host import IS_WINDOWS
+from azathoth.core.issues import LabelInfo
+from azathoth.core.templates import render_prompt
+from azathoth.core.workflows import WorkflowPlan
+
+__all__ = [
+    "autocommit",
+    "autofix",
+    "autorelease",
+    "autotriage",
+    "commit_system",
+    "explore",
+    "onboard",
+    "release_system",
+    "review",
+    "run_workflow",
+]
 
-def get_scout_prompt(target_directory: str) -> str:
-    return f"""
-You are an expert software architect acting as a 'Code Scout'. Your mission is to explore the codebase in '{target_directory}' and produce a high-level overview report, adapted to the project's specific coding philosophy.
+
+#: Roles ``onboard`` tailors its guide to.
+ONBOARD_ROLES = ("backend", "frontend", "devops")
+
+EXPLORE_TEMPLATE = """
+You are an expert software architect acting as a 'Code Scout'. Your mission is to explore the codebase in '{{ target_directory }}' and produce a high-level overview report, adapted to the project's specific coding philosophy.
 
 You MUST base your entire analysis on the output of the tools you run.
 
 **Your Scouting Process MUST be as follows:**
 
-1.  **Reconnaissance:** Get a high-level view of the project structure using the `ls -R` command.
+1.  **Reconnaissance:** Get a high-level view of the project structure using the `list_directory` tool with `recursive=true`. If it is unavailable, fall back to {% if windows %}`Get-ChildItem -Recurse -Name` in PowerShell (or `dir /s /b` in cmd) — this is a Windows host{% else %}`ls -R` (or `find . -type f -not -path './.git/*'`){% endif %}.
+
+2.  **Identify Language and Stack:** Call the `detect_stack` tool on the project. It reads every manifest (`pyproject.toml`, `package.json`, `Cargo.toml`, `go.mod`, …) and returns the languages (primary first), frameworks, and the directive names to load. Do not read the manifests by hand for this.
 
-2.  **Identify Language and Stack:** Find and use the `ReadFile` tool on the project's manifest (`pyproject.toml`, `package.json`, etc.) to determine the primary programming language and key dependencies.
+    Then call `repo_stats` for measured lines of code per language, the largest files and recent commit activity, and `dependencies` for the resolved dependency list (name, version, direct or transitive, dev or runtime) from the manifests and lockfiles. Do not read `Cargo.lock`, `package-lock.json` or other lockfiles by hand.
 
-3.  **Adapt to Coding Style:** Based on the primary language you just identified, you MUST immediately call the `adapt` tool. Pass the language name (e.g., 'python') as the argument. The output of this tool is now your **prime directive** and will inform the tone and content of your final report.
+    Call `license_report` for the project license and any dependency whose license conflicts with it.
 
-4.  **Find the Entry Point:** Locate the application's primary entry point (`main.py`, `src/index.ts`, etc.) and use `ReadFile` on it to understand the high-level architecture and startup sequence.
+3.  **Adapt to Coding Style:** You MUST immediately call the `adapt` tool with the `directives` list from `detect_stack` (or, if it is empty, the primary language, e.g. 'python'). The output of this tool is now your **prime directive** and will inform the tone and content of your final report.
 
-5.  **Synthesize and Report:** After completing your investigation, you MUST synthesize your findings into a single Markdown overview. Your final output must ONLY be this report. Use the following template:
+4.  **Check Configuration Drift:** Call the `config_drift` tool on the project. Keys missing from one environment's config file, or typed differently between environments, are a common cause of deploy failures and belong in the report.
+
+5.  **Map the Structure:** Call the `module_map` tool. It lists the binaries and libraries the project builds, their entry points, and the size and public surface of each module. Then call `outline` on the primary entry point it reports (and on the main modules) for their signatures, and use `read_file` only on the parts whose bodies explain the startup sequence.
+
+6.  **Survey Tech Debt:** Call the `scan_markers` tool. Its TODO/FIXME/HACK/XXX counts by file and author show where known debt sits and who to ask about it.
+
+7.  **Synthesize and Report:** After completing your investigation, you MUST synthesize your findings into a single Markdown overview. Your final output must ONLY be this report. Use the following template:
 
 ---
 # Codebase Overview
@@ -27,11 +80,14 @@ You MUST base your entire analysis on the output of the tools you run.
 *   **Why it exists:** The problem this project aims to solve.
 
 ### 2. Technology Stack & Key Dependencies
-*   **Language/Runtime:** The primary language and version identified.
-*   **Core Libraries:** The 3-5 most important dependencies and their likely role.
+*   **Language/Runtime:** The primary language and version identified, with its share of the code from `repo_stats`.
+*   **Size & Activity:** Lines of code and files (`repo_stats`), and how active the last twelve weeks were.
+*   **Core Libraries:** The 3-5 most important direct runtime dependencies from `dependencies`, with their versions and likely role, and how many transitive packages they pull in.
+*   **Licensing:** The project license and any `license_report` issues (incompatible or unknown dependency licenses, missing license file), or "No issues".
 
 ### 3. Architecture & High-Level Structure
 *   **Architectural Pattern:** [e.g., Command-Line Application, Monolithic Web Server, Library]
+*   **Targets & Modules:** The binaries and libraries from `module_map`, and its largest modules with their role and approximate size.
 *   **Startup Sequence:** A brief description of what happens when the application starts, based on the entry point file.
 
 ### 4. Coding Style & Best Practices
@@ -41,54 +97,81 @@ You MUST base your entire analysis on the output of the tools you run.
 ### 5. Key Insights for a New Developer
 *   **Core Logic Location:** The directory or file where the central, most important business logic appears to be located.
 *   **First File to Read:** The single file a new developer should read first to get the best understanding of the project's architecture.
+*   **Configuration Drift:** Keys missing or mistyped between environment config files, as reported by `config_drift` (or "None detected").
+*   **Tech Debt:** The `markdown` summary from `scan_markers` (or "No markers found").
 ---
 """
 
+AUTOCOMMIT_TEMPLATE = """
+You are an expert software engineer. Your task is to intelligently create and execute a conventional Git commit.
 
-def get_commit_prompt(focus: Optional[str] = None) -> str:
-    focus_section = ""
-    if focus:
-        focus_section = f"\n\n**User's Focus for this commit is:** '{focus}'. Tailor the commit message accordingly."
+{% if test_gate %}**Test Gate:** Before anything else, call the `run_tests` tool. If `success` is false, do NOT commit: fix the code behind its `failures` (the `output` shows why they fail) and call it again until it passes. If you cannot make it pass, report the failing tests and stop.
 
-    return f"""
-You are an expert software engineer. Your task is to intelligently create and execute a conventional Git commit.
+{% endif %}**Your process MUST be as follows:**
 
-**Your process MUST be as follows:**
+{% if forbidden_trailers %}THE ZERO LAW OF GIT COMMITS:
+0. **UNDEBATABLE RULE**: You MUST NEVER! Add trailer lines matching {{ forbidden_trailers }} (e.g. co-author or sign-off lines) to the commit message. The commit MUST be clean and professional!
 
-THE ZERO LAW OF GIT COMMITS:
-0. **UNDEBATABLE RULE**: You MUST NEVER! Add some kind of coauthor or sign-off lines to the commit message. The commit MUST be clean and professional!
+{% endif %}1.  **Preflight:** Before staging anything, call the `preflight` tool. Fix every error it reports (resolve leftover conflict markers, keep oversized files out of the commit) and apply the fixes for its warnings where they belong to this change (add build artifacts to `.gitignore`, add missing final newlines). Call it again until `ok` is true.
 
-1.  **Stage All Changes:** First, you MUST run `git add .` to ensure that all modified and new files are staged. This guarantees that the commit will be comprehensive.
+2.  **Stage All Changes:** Next, you MUST run `git add .` to ensure that all modified and new files are staged. This guarantees that the commit will be comprehensive. If the working tree holds work unrelated to this commit, shelve it first with the `stash_save` tool (passing its `paths`) and restore it with `stash_pop` after committing.
 
-2.  **Analyze Staged Changes:** After staging, you MUST review the context of the staged code changes by inspecting `git diff --staged`.
+3.  **Analyze Staged Changes:** After staging, you MUST review the context of the staged code changes by calling the `git_diff_staged` tool (equivalent to `git diff --staged`, with per-file line counts).
 
-3.  **Generate a Commit Message:** Based on the changes and the user's focus, write a high-quality conventional commit message with a `title` and a `body`.
+4.  **Generate a Commit Message:** Based on the changes and the user's focus, write a high-quality commit message with a `title` and a `body` that follows this repository's commit policy:
+{{ rules }}
 
-4.  **Execute the Commit:** You MUST immediately call the `stage_and_commit` tool to finalize the process. Pass the `commit_title` and `commit_body` you just generated as arguments to the tool.
+5.  **Execute the Commit:** You MUST immediately call the `stage_and_commit` tool to finalize the process. Pass the title and body you just generated as its `focus`; it writes the final message under the same policy and commits.
 
 Do not ask for confirmation at any step. Perform this entire sequence of actions directly.
-{focus_section}
+{% if focus %}
+
+**User's Focus for this commit is:** '{{ focus }}'. Tailor the commit message accordingly.{% endif %}{% if scope %}
+
+**Scope:** use `{{ scope }}` as the conventional-commit scope, e.g. `feat({{ scope }}): ...`.{% endif %}
 """
 
+AUTOFIX_TEMPLATE = """
+You are an expert software engineer. Your task is to get this project's build, linter and tests passing by fixing the code{% if commit %}, then commit the fixes{% endif %}.
 
-def get_release_prompt(new_version: str, repo_url: str, old_version: str) -> str:
-    repo_name = repo_url.split("/")[-1].replace(".git", "")
-    return f"""
-You are an expert release manager. Your task is to fully automate the creation and publication of the new software release: **{new_version}**.
+**Work in rounds, at most {{ max_iterations }} of them. In each round, without asking for confirmation:**
 
-**Your process MUST be as follows, without asking for confirmation:**
+1.  **Format:** Call the `run_fmt` tool, so formatting never shows up as a finding.
+
+2.  **Build:** Call the `run_build` tool. If `success` is false, fix every diagnostic with severity `error`: read the lines around its `file` and `line` with `read_file`, then change them with `edit_file` (the exact text to replace, with enough context to be unique), or create a missing file with `write_file`. Fix the first error of each file first — later ones are often caused by it. Then start the next round.
+
+3.  **Lint:** Call the `run_lint` tool and fix its diagnostics the same way, errors first, then warnings. Fix the code the finding is about; never silence it with allow, ignore or disable comments or configuration.
+
+4.  **Test:** Call the `run_tests` tool. If `success` is false, read each test in `failures` and the code it exercises (`output` shows why it failed), and fix the code. Change a test only when the test itself is wrong; never delete, skip or weaken one to make it pass.
+
+The loop ends when a round finds the build, the linter and the tests all passing. If they still fail after {{ max_iterations }} rounds, or a round cannot fix anything, stop and report what remains: the failing step, its diagnostics or failing tests, and what you tried.
+{% if focus %}
+
+**Focus:** '{{ focus }}'. Keep your edits to what this needs.{% endif %}{% if commit %}
+
+**Commit:** Once everything passes, call the `preflight` tool and fix what it reports, then call `stage_and_commit` with a `focus` summarising what you fixed. Never add co-author or sign-off lines.{% endif %}
+"""
+
+AUTORELEASE_TEMPLATE = """
+You are an expert release manager. Your task is to fully automate the creation and publication of the new software release: **{{ new_version }}**.
+
+{% if milestone %}**Milestone Gate:** Before anything else, call the `milestone_status` tool with `milestone` set to `{{ milestone }}`. If `complete` is false, do NOT release: report the `open_items` still blocking the milestone and stop. Once the release is published, call the `close_milestone` tool for `{{ milestone }}`.
+
+{% endif %}{% if test_gate %}**Test Gate:** Before anything else, call the `run_tests` tool. If `success` is false, do NOT release: report its `failures` and stop.
 
-1.  **Find Previous Version:** Execute the shell command `git describe --tags --abbrev=0` to find the most recent Git tag. This is the `old_version`.
+{% endif %}**Your process MUST be as follows, without asking for confirmation:**
 
-2.  **Gather Commit History:** Get the log of all commits between the `old_version` and HEAD. The command `git log <old_version>..HEAD --pretty=format:"- %s"` is ideal for this, as it provides a clean list for your analysis.
+1.  **Previous Version:** The most recent Git tag is `{{ old_version }}`. This is the `old_version`.
 
-3.  **Generate Release Notes:** You must now write the release notes. Your writing style and structure MUST strictly follow the template provided below. Use the commit history you just gathered as your primary source of information.
+2.  **Gather Commit History:** Call the `generate_changelog` tool with `from_ref` set to the `old_version` (and `to_ref` left as HEAD). It returns the commits already grouped by conventional-commit type (features, fixes, chores, breaking changes), so you do not need to run `git log` yourself.
+
+3.  **Generate Release Notes:** You must now write the release notes. Your writing style and structure MUST strictly follow the template provided below. Use the grouped changelog you just gathered as your primary source of information.
 
     ---
     **RELEASE NOTES TEMPLATE:**
-    # Release {new_version}
+    # Release {{ new_version }}
 
-    ## 🚀 {repo_name} {new_version} is here!
+    ## 🚀 {{ repo_name }} {{ new_version }} is here!
     
     [One sentence summary of the release]
 
@@ -100,40 +183,69 @@ You are an expert release manager. Your task is to fully automate the creation a
     *   [Fix 1]
     *   [Fix 2]
     
-    **Full Changelog**: {repo_url}/compare/{old_version}...{new_version}
+    **Full Changelog**: {{ repo_url }}/compare/{{ old_version }}...{{ new_version }}
     ---
 
-4.  **Create the Release:** You MUST immediately call the `create_git_release` tool. Pass the `{new_version}` as the `version_tag` and the full Markdown notes you just generated as the `release_notes`.
-"""
+4.  **Bump the Manifest:** First call the `suggest_next_version` tool. It returns the `level` (`major`, `minor` or `patch`) the commits since `old_version` call for and the commits that decided it as `evidence`; make sure every breaking change in the evidence is in the release notes. Then call the `bump_version` tool with the level that takes `old_version` to {{ new_version }} (the suggested one, unless {{ new_version }} differs from its `next`), so the project manifest and the tag agree. It commits the change itself.
 
+{% if build_artifacts %}5.  **Build the Binaries:** Call the `build_release_artifacts` tool. It builds the release binaries and a `SHA256SUMS` file and returns their paths as `assets`. If it reports that the project has no binary targets, release without assets.
 
-# ── Direct API variants (no tool-calling, structured JSON output) ────────
+6.  **Create the Release:** You MUST immediately call the `create_release` tool with `tag` set to `{{ new_version }}`, `assets` set to the `assets` from the previous step{% if prerelease %} and `pre` set to true (this is a pre-release){% endif %}. It tags, pushes and publishes the release on the repo's forge, with the binaries attached.
+{% else %}5.  **Create the Release:** You MUST immediately call the `create_release` tool with `tag` set to `{{ new_version }}`{% if prerelease %} and `pre` set to true (this is a pre-release){% endif %}. It tags, pushes and publishes the release on the repo's forge.
+{% endif %}"""
 
+AUTOTRIAGE_TEMPLATE = """
+You are an experienced open-source maintainer. Your task is to triage the repository's new issues: categorize each one, label it, and answer it.
 
-def get_commit_system_prompt(focus: Optional[str] = None) -> str:
-    """System prompt for direct LLM commit-message generation (JSON mode)."""
-    focus_section = ""
-    if focus:
-        focus_section = f'\n\nThe user wants the commit message to focus on: "{focus}". Tailor the title and body accordingly.'
+**Your process MUST be as follows, without asking for confirmation:**
+
+1.  **Collect the Issues:** Call the `list_issues` tool with `unlabeled` set to true and `limit` set to {{ limit }}. These are the issues nobody has triaged yet. If there are none, say so and stop.
+
+2.  **Categorize Each Issue:** Read its title and body and decide what it is: a bug report, a feature request, a question, a documentation problem, a duplicate of another issue, or not actionable (spam, off-topic, no information at all).
+
+3.  **Apply Labels:** Call the `label_issue` tool with the labels that fit, using only the repository's existing labels:
+{% if labels %}{% for label in labels %}    - `{{ label.name }}`{% if label.description %} — {{ label.description }}{% endif %}
+{% endfor %}{% else %}    (The repository's labels could not be listed; use the ones already on other issues.)
+{% endif %}
+4.  **Draft a Response:** Call the `comment_issue` tool with a short, friendly reply that moves the issue forward: for a bug without enough detail, ask for the version, the steps to reproduce and the expected and actual behaviour; for a question, answer it if the codebase lets you, pointing to the relevant files; for a feature request, restate it and ask what problem it solves if that is unclear. Never promise a fix or a release date.
+
+5.  **Close Only the Obvious:** Call the `close_issue` tool only for exact duplicates (`reason` `not_planned`, with a `comment` linking the original issue) and for spam. Leave everything else open.
+
+6.  **Report:** Finish with a table of the issues you triaged: number, category, labels applied, and whether you commented or closed it.
+{% if focus %}
+
+**Triage focus:** {{ focus }}{% endif %}
+"""
 
-    return f"""You are an expert git commit message writer.
+RUN_WORKFLOW_TEMPLATE = """
+You are running the `{{ workflow }}` workflow.{% if description %} {{ description }}{% endif %}
+
+**Carry out these steps in order, without asking for confirmation.** Each step either calls one tool with the arguments given or follows the embedded instructions of another prompt. A step marked "only if" runs only when its condition holds; say so when you skip it.
+{% for step in steps %}
+{{ loop.index }}.  **{{ step.title[:1] | upper }}{{ step.title[1:] }}**{% if step.condition %} (only if {{ step.condition }}){% endif %}: {% if step.tool %}Call the `{{ step.tool }}` tool{% if step.arguments %} with {% for key, value in step.arguments.items() %}`{{ key }}` set to `{{ value | tojson }}`{% if not loop.last %}, {% endif %}{% endfor %}{% endif %}.{% else %}Follow the `{{ step.prompt }}` instructions below.{% endif %}{% if step.instructions %} {{ step.instructions }}{% endif %}{% if step.on_failure == "stop" %} If this step fails, stop and report why.{% else %} If this step fails, note it and go on.{% endif %}
+{% if step.id in embedded %}
+    ---
+{{ embedded[step.id] | trim | indent(4, true) }}
+    ---
+{% endif %}{% endfor %}
+Finish with a short report of what each step did{% if skipped %} (not planned for these inputs: {{ skipped | join(", ") }}){% endif %}.
+"""
+
+COMMIT_SYSTEM_TEMPLATE = """You are an expert git commit message writer.
 
 Analyze the provided git diff and produce a single JSON object with exactly two keys:
-  "title" — A concise imperative-mood summary following Conventional Commits (e.g. "feat: add user auth", "fix: resolve null pointer in parser").
+  "title" — A concise imperative-mood summary (e.g. "feat: add user auth", "fix: resolve null pointer in parser").
   "body"  — A short paragraph or bullet list explaining *why* the changes were made, not just *what* changed.
 
 Rules:
-- The title MUST start with a valid Conventional Commits type (feat, fix, refactor, chore, docs, style, test, perf, ci, build, revert).
-- Keep the title under 72 characters.
+{{ rules }}
 - The body should be informative but concise (3-5 lines max).
-- NEVER add co-author, signed-off-by, or trailer lines.
 - Output ONLY the JSON object, nothing else.
-{focus_section}"""
+{% if focus %}
 
+The user wants the commit message to focus on: "{{ focus }}". Tailor the title and body accordingly.{% endif %}"""
 
-def get_release_system_prompt() -> str:
-    """System prompt for direct LLM release-notes generation (JSON mode)."""
-    return """You are an expert release manager.
+RELEASE_SYSTEM_TEMPLATE = """You are an expert release manager.
 
 You will receive a commit log (one commit per line, prefixed with "- ").
 Analyze the commits and produce a single JSON object with exactly two keys:
@@ -151,3 +263,280 @@ Analyze the commits and produce a single JSON object with exactly two keys:
 - [Chore/refactor 1]
 
 Omit any empty sections. Output ONLY the JSON object, nothing else."""
+
+
+ONBOARD_TEMPLATE = """
+You are a senior {{ role }} engineer on the project in '{{ target_directory }}', writing the onboarding guide for a new {{ role }} developer who joins tomorrow. The guide must be specific to this codebase and to the {{ role }} role: what they will touch first, how to run it, and the conventions they must follow.
+
+You MUST base every statement on the output of the tools you run; write "unknown" rather than guess.
+
+**Your Process MUST be as follows:**
+
+1.  **Stack:** Call `detect_stack` on the project for its languages (primary first), frameworks, manifests, container files and the directive names to load.
+
+2.  **Conventions:** Call `adapt` with the `directives` list from `detect_stack` (or the primary language if it is empty). Its output is the team's coding philosophy; the guide's conventions section comes from it, not from general best practice.
+
+3.  **Size and Activity:** Call `repo_stats` for lines of code per language, the largest files and recent commit activity.
+
+4.  **Structure:** Call `module_map` for the binaries and libraries the project builds, their entry points and the size of each module. Use `outline` on the modules that matter to a {{ role }} developer rather than reading them whole.
+
+5.  **Role-specific survey:**
+{%- if role == "frontend" %}
+    *   Call `stack_profile` for the UI framework, state management, styling, HTTP client and testing libraries already in use.
+    *   Call `catalog_assets` for images, fonts and other assets, and where they live.
+    *   Use `glob` to find the routes, pages and components directories (e.g. `src/**/*.svelte`, `src/**/*.tsx`) and `outline` one representative component.
+{%- elif role == "devops" %}
+    *   From `detect_stack`, list the container files; `read_file` the Dockerfile/compose file and the CI workflows (`glob` for `.github/workflows/*` or `.gitlab-ci.yml`).
+    *   Call `config_drift` for keys missing or typed differently between environment config files.
+    *   Call `dependencies` for runtime dependencies and `license_report` for licensing issues that affect distribution.
+    *   Call `scan_secrets` on the tree for credentials that must move into a secret store.
+{%- else %}
+    *   Call `stack_profile` for the web framework, database/ORM, HTTP client, serialization, logging and testing libraries already in use.
+    *   Call `dependencies` for the direct runtime dependencies and their versions.
+    *   Call `config_drift` for environment configuration a backend developer must keep in step.
+    *   Use `search` to find where requests are handled or the data model is defined (e.g. route decorators, `class .*Model`), then `outline` those files.
+{%- endif %}
+
+6.  **Known Debt:** Call `scan_markers` and keep the TODO/FIXME/HACK items in the areas a {{ role }} developer will work on.
+
+7.  **Write the Guide:** Synthesize everything into a single Markdown document. Your final output must ONLY be this guide, using this template:
+
+---
+# Onboarding Guide — {{ role | capitalize }} Developer
+
+## 1. The Project in One Paragraph
+What it does, for whom, and which parts a {{ role }} developer owns.
+
+## 2. Stack at a Glance
+*   **Languages & size:** from `detect_stack` and `repo_stats`.
+*   **Frameworks & key libraries:** the ones that matter for {{ role }} work, with versions.
+
+## 3. Getting Started
+*   **Setup:** the commands to install, build and run the project locally, from the manifests and docs.
+*   **Tests:** how to run them.
+
+## 4. Where Things Live
+A table of the directories and files a {{ role }} developer will work in, with a one-line role each (from `module_map` and `outline`), and the entry point(s).
+
+## 5. Conventions You Must Follow
+*   **Directives loaded:** which ones `adapt` returned.
+*   **Rules:** the 3-5 rules from the directives most relevant to {{ role }} work, each with where it shows in the code.
+*   **Libraries to reuse:** the existing choices new code must use instead of adding alternatives.
+
+## 6. {% if role == "devops" %}Deployment & Operations{% elif role == "frontend" %}UI Architecture{% else %}Services & Data{% endif %}
+{%- if role == "devops" %}
+Containers, CI pipelines, environment configuration and drift, secrets handling.
+{%- elif role == "frontend" %}
+Routing, component structure, state, styling and assets.
+{%- else %}
+Request handling, data model and persistence, external services.
+{%- endif %}
+
+## 7. Known Pitfalls & Tech Debt
+The relevant `scan_markers` items and anything else the tools flagged.
+
+## 8. Your First Week
+Three concrete starter tasks, each naming the files to read first.
+---
+"""
+
+
+REVIEW_TEMPLATE = """
+You are a meticulous senior reviewer. Review {% if pr_number %}pull request #{{ pr_number }}{% else %}the changes in `{{ revisions }}`{% endif %} against the project's coding directives below, and produce a structured review.
+
+**Changed files ({{ files | length }}):** {{ files[:50] | join(", ") }}{% if files | length > 50 %}, … ({{ files | length - 50 }} more){% endif %}
+
+**Directives loaded** (for {{ languages | join(", ") if languages else "no source language; core philosophy only" }}): {{ directives | join(", ") }}
+
+<directives>
+{{ guidance }}
+</directives>
+
+**Your Process MUST be as follows:**
+
+1.  **Read the diff:** {% if pr_number %}Call `pr_diff` with number={{ pr_number }}. It returns each file's hunks with new-file line numbers; use those (side RIGHT) for every line you cite.{% else %}Call `summarize_diff` with revisions="{{ revisions }}". Files too large to inline come with hunk headers only; call it again with `paths` to read them in full.{% endif %}
+
+2.  **Read the context:** Where a hunk's correctness depends on code outside it (callers, types, tests), read that code before judging. Do not comment on lines the change did not touch unless the change breaks them.
+
+3.  **Judge against the directives:** The directives above are the team's rules. A violation of an explicit rule is at least a suggestion; one that causes bugs, data loss, security issues or broken builds/tests is blocking.
+
+4.  **Classify every finding** as exactly one of:
+    *   **Blocking** — must be fixed before merging: bugs, security problems, broken or missing tests for changed behaviour, clear directive violations with real consequences.
+    *   **Suggestion** — would make the change better: design, naming, error handling, test coverage, directive rules.
+    *   **Nitpick** — style and wording the author may ignore.
+
+5.  **Write the review** using this template (omit empty sections, never invent findings to fill one):
+
+---
+## Summary
+One paragraph: what the change does and your verdict (approve, approve with suggestions, or changes requested).
+
+## Blocking
+- `path:line` — the problem, why it matters, and the fix.
+
+## Suggestions
+- `path:line` — the improvement and why.
+
+## Nitpicks
+- `path:line` — the nit.
+---
+{% if pr_number %}
+6.  **Post it:** Call `pr_review` with number={{ pr_number }}: event REQUEST_CHANGES if there is any blocking finding, otherwise COMMENT (APPROVE only when there are no findings at all), the Summary as body, and one line comment per finding ({path, line, body, side: "RIGHT"}) prefixed with **Blocking:**, **Suggestion:** or **Nitpick:**. Call it with dry_run=true first and show the request; post only after the user confirms.
+{%- else %}
+Your final output must ONLY be this review.
+{%- endif %}
+"""
+
+
+def explore(target_directory: str, windows: bool = IS_WINDOWS) -> str:
+    """Scout *target_directory* and report an overview of the codebase.
+
+    *windows* picks the shell commands suggested when a tool is unavailable.
+    """
+    return render_prompt(
+        "explore",
+        EXPLORE_TEMPLATE,
+        target_directory=target_directory,
+        windows=windows,
+    )
+
+
+def onboard(target_directory: str, role: str = "backend") -> str:
+    """Write an onboarding guide to *target_directory* for a new *role*
+    developer (one of ``ONBOARD_ROLES``).
+
+    Raises:
+        ValueError: If *role* is not one of ``ONBOARD_ROLES``.
+    """
+    if role not in ONBOARD_ROLES:
+        raise ValueError(
+            f"Unknown role '{role}' (expected one of {', '.join(ONBOARD_ROLES)})."
+        )
+    return render_prompt(
+        "onboard", ONBOARD_TEMPLATE, target_directory=target_directory, role=role
+    )
+
+
+def review(
+    files: Sequence[str],
+    languages: Sequence[str],
+    directives: Sequence[str],
+    guidance: str,
+    pr_number: Optional[int] = None,
+    revisions: Optional[str] = None,
+) -> str:
+    """Review PR *pr_number* (posting through ``pr_review``) or the diff of
+    *revisions*, against *guidance*: the directives of the changed *files*'
+    *languages*, as ``adapt`` merged them."""
+    return render_prompt(
+        "review",
+        REVIEW_TEMPLATE,
+        files=list(files),
+        languages=list(languages),
+        directives=list(directives),
+        guidance=guidance,
+        pr_number=pr_number,
+        revisions=revisions,
+    )
+
+
+def autocommit(
+    focus: Optional[str] = None,
+    policy: Optional[CommitPolicy] = None,
+    scope: Optional[str] = None,
+    test_gate: bool = False,
+) -> str:
+    """Stage everything and commit it under *policy* (default: the stock one);
+    with *test_gate*, only once ``run_tests`` passes."""
+    policy = policy or CommitPolicy()
+    return render_prompt(
+        "autocommit",
+        AUTOCOMMIT_TEMPLATE,
+        rules=policy.render_rules(),
+        forbidden_trailers=", ".join(policy.forbidden_trailers),
+        focus=focus,
+        scope=scope,
+        test_gate=test_gate,
+    )
+
+
+def autofix(
+    max_iterations: int = 5, commit: bool = False, focus: Optional[str] = None
+) -> str:
+    """Fix the code until ``run_build``, ``run_lint`` and ``run_tests`` pass,
+    in at most *max_iterations* rounds; with *commit*, commit the fixes."""
+    return render_prompt(
+        "autofix",
+        AUTOFIX_TEMPLATE,
+        max_iterations=max_iterations,
+        commit=commit,
+        focus=focus,
+    )
+
+
+def autorelease(
+    new_version: str,
+    repo_url: str,
+    old_version: str,
+    prerelease: bool = False,
+    build_artifacts: bool = False,
+    milestone: Optional[str] = None,
+    test_gate: bool = False,
+) -> str:
+    """Write notes for *old_version*..*new_version*, bump and publish; with
+    *test_gate*, only once ``run_tests`` passes."""
+    return render_prompt(
+        "autorelease",
+        AUTORELEASE_TEMPLATE,
+        new_version=new_version,
+        old_version=old_version,
+        repo_url=repo_url,
+        repo_name=repo_url.split("/")[-1].replace(".git", ""),
+        prerelease=prerelease,
+        build_artifacts=build_artifacts,
+        milestone=milestone,
+        test_gate=test_gate,
+    )
+
+
+def autotriage(
+    labels: Sequence[LabelInfo] = (), limit: int = 20, focus: Optional[str] = None
+) -> str:
+    """Triage up to *limit* unlabeled issues with the repository's *labels*."""
+    return render_prompt(
+        "autotriage", AUTOTRIAGE_TEMPLATE, labels=labels, limit=limit, focus=focus
+    )
+
+
+def run_workflow(plan: WorkflowPlan, embedded: Mapping[str, str] | None = None) -> str:
+    """Walk the agent through *plan*; *embedded* maps prompt steps to their text."""
+    return render_prompt(
+        "run-workflow",
+        RUN_WORKFLOW_TEMPLATE,
+        workflow=plan.name,
+        description=plan.description,
+        steps=plan.steps,
+        skipped=plan.skipped,
+        embedded=dict(embedded or {}),
+    )
+
+
+# ── Direct API variants (no tool-calling, structured JSON output) ────────
+
+
+def commit_system(
+    focus: Optional[str] = None, policy: Optional[CommitPolicy] = None
+) -> str:
+    """System prompt for direct LLM commit-message generation (JSON mode)."""
+    policy = policy or CommitPolicy()
+    return render_prompt(
+        "commit-system",
+        COMMIT_SYSTEM_TEMPLATE,
+        rules=policy.render_rules(),
+        focus=focus,
+    )
+
+
+def release_system() -> str:
+    """System prompt for direct LLM release-notes generation (JSON mode)."""
+    return render_prompt("release-system", RELEASE_SYSTEM_TEMPLATE)
@@ -0,0 +1,389 @@
+"""azathoth.core.rebase — plan and run an interactive rebase without an editor.
+
+Public surface:
+  - ``find_candidates(commits)``               → ``[FixupCandidate]``
+  - ``plan_rebase(base, cwd)``                  → ``RebasePlan``
+  - ``validate_steps(steps, commits)``          → problems with a step list
+  - ``rebase_todo(steps, commits, msg_dir)``    → todo text for git
+  - ``execute_rebase(steps, base, cwd, …)``     → ``RebaseResult``
+
+Unlike ``core.rewrite`` (which only folds contiguous commits and never
+touches the working tree), a rebase can reorder and drop commits, so it
+replays them with ``git rebase -i``.  The todo list is written up front and
+handed to git through ``GIT_SEQUENCE_EDITOR``; new messages are applied by
+``exec git commit --amend`` lines reading files under the git directory, so
+no editor ever opens.  Fixup candidates are ``fixup!``/``squash!`` commits
+(git's autosquash convention) and commits touching only files an earlier
+branch commit already touched — the latter a heuristic the suggested plan
+leaves as ``pick``.
+"""
+
+from __future__ import annotations
+
+import shutil
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.host import sh_path
+from azathoth.core.policy import is_protected_branch
+from azathoth.core.rewrite import (
+    BACKUP_REF_PREFIX,
+    _git_ok,
+    _match,
+    _published,
+    branch_commits,
+    resolve_base,
+)
+from azathoth.core.workflow import (
+    CommitInfo,
+    _run_git,
+    format_command,
+    get_repo_context,
+    get_repo_status,
+)
+
+RebaseAction = Literal["pick", "reword", "squash", "fixup", "drop"]
+
+_AUTOSQUASH = {"fixup! ": "fixup", "squash! ": "squash"}
+_MESSAGE_DIR = "azathoth-rebase"
+
+
+class RebaseCommit(BaseModel, frozen=True):
+    sha: str
+    subject: str
+    files: list[str] = Field(default_factory=list)
+
+
+class FixupCandidate(BaseModel, frozen=True):
+    commit: str
+    target: str = Field(description="Earlier commit it should be folded into")
+    action: Literal["fixup", "squash"]
+    detected_by: Literal["message", "files"] = Field(
+        description="fixup!/squash! subject, or overlapping files"
+    )
+
+
+class RebaseStep(BaseModel):
+    """One line of the todo list.
+
+    ``message`` replaces the commit message (required for ``reword``;
+    optional for ``pick`` and ``squash``, not allowed otherwise).
+    """
+
+    action: RebaseAction = "pick"
+    commit: str
+    message: str = ""
+
+
+class RebasePlan(BaseModel, frozen=True):
+    base: str
+    commits: list[RebaseCommit] = Field(default_factory=list)
+    candidates: list[FixupCandidate] = Field(default_factory=list)
+    suggested: list[RebaseStep] = Field(
+        default_factory=list, description="Autosquash applied to the commits"
+    )
+
+
+class RebaseResult(BaseModel, frozen=True):
+    branch: str
+    old_head: str
+    new_head: str | None = None
+    backup_ref: str | None = None
+    commits: list[str] = Field(default_factory=list, description="New subjects")
+    conflicts: list[str] = Field(default_factory=list)
+    stopped: bool = Field(False, description="Rebase left in progress")
+    pushed: bool = False
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+# ── Planning ──────────────────────────────────────────────────────────────────
+
+
+async def _touched_files(sha: str, cwd: str | None) -> list[str]:
+    out = await _git_ok(
+        ["diff-tree", "--no-commit-id", "--name-only", "-r", sha], cwd, "git diff-tree"
+    )
+    return out.splitlines()
+
+
+def _autosquash_target(
+    subject: str, earlier: list[RebaseCommit]
+) -> tuple[str, RebaseCommit] | None:
+    for prefix, action in _AUTOSQUASH.items():
+        if not subject.startswith(prefix):
+            continue
+        wanted = subject.removeprefix(prefix).strip()
+        for commit in earlier:
+            if commit.subject.startswith(wanted) or commit.sha.startswith(wanted):
+                return action, commit
+    return None
+
+
+def find_candidates(commits: list[RebaseCommit]) -> list[FixupCandidate]:
+    """Commits that look like they belong to an earlier one, oldest first."""
+    candidates = []
+    for i, commit in enumerate(commits):
+        earlier = commits[:i]
+        if hit := _autosquash_target(commit.subject, earlier):
+            action, target = hit
+            candidates.append(
+                FixupCandidate(
+                    commit=commit.sha,
+                    target=target.sha,
+                    action="squash" if action == "squash" else "fixup",
+                    detected_by="message",
+                )
+            )
+            continue
+        files = set(commit.files)
+        for previous in reversed(earlier):
+            if files and files <= set(previous.files):
+                candidates.append(
+                    FixupCandidate(
+                        commit=commit.sha,
+                        target=previous.sha,
+                        action="squash",
+                        detected_by="files",
+                    )
+                )
+                break
+    return candidates
+
+
+def _suggest(
+    commits: list[RebaseCommit], candidates: list[FixupCandidate]
+) -> list[RebaseStep]:
+    """Todo with autosquash commits moved after their targets."""
+    moved = {c.commit: c for c in candidates if c.detected_by == "message"}
+    steps: list[RebaseStep] = []
+    for commit in commits:
+        if commit.sha in moved:
+            continue
+        steps.append(RebaseStep(commit=commit.sha))
+        steps += [
+            RebaseStep(action=c.action, commit=c.commit)
+            for c in moved.values()
+            if c.target == commit.sha
+        ]
+    # Chains (a fixup of a fixup) follow their own target.
+    placed = {s.commit for s in steps}
+    while len(placed) < len(commits):
+        for c in moved.values():
+            if c.commit in placed or c.target not in placed:
+                continue
+            at = next(i for i, s in enumerate(steps) if s.commit == c.target)
+            steps.insert(at + 1, RebaseStep(action=c.action, commit=c.commit))
+            placed.add(c.commit)
+    return steps
+
+
+async def plan_rebase(base: str | None = None, cwd: str | None = None) -> RebasePlan:
+    """Commits ``base..HEAD`` would replay, fixup candidates and a suggested todo.
+
+    Raises:
+        WorkflowError: If no base can be resolved or the range has merges.
+    """
+    base_sha = await resolve_base(base, cwd)
+    commits = [
+        RebaseCommit(
+            sha=c.sha, subject=c.subject, files=await _touched_files(c.sha, cwd)
+        )
+        for c in await branch_commits(base_sha, cwd)
+    ]
+    candidates = find_candidates(commits)
+    return RebasePlan(
+        base=base_sha,
+        commits=commits,
+        candidates=candidates,
+        suggested=_suggest(commits, candidates),
+    )
+
+
+# ── Execution ─────────────────────────────────────────────────────────────────
+
+
+def validate_steps(steps: list[RebaseStep], commits: list[CommitInfo]) -> list[str]:
+    """Check that *steps* lists every commit of the range once, in any order."""
+    if not commits:
+        return ["The branch has no commits beyond its base."]
+    problems: list[str] = []
+    seen: set[int] = set()
+    for number, step in enumerate(steps, start=1):
+        index = _match(step.commit, commits)
+        if index is None:
+            problems.append(
+                f"Step {number}: '{step.commit}' does not identify a branch commit."
+            )
+        elif index in seen:
+            problems.append(f"Step {number}: {step.commit} is listed twice.")
+        else:
+            seen.add(index)
+        if step.action == "reword" and not step.message.strip():
+            problems.append(f"Step {number}: reword needs a message.")
+        if step.message.strip() and step.action in ("fixup", "drop"):
+            problems.append(f"Step {number}: {step.action} takes no message.")
+    kept = [s for s in steps if s.action != "drop"]
+    if kept and kept[0].action in ("squash", "fixup"):
+        problems.append(f"The first kept step cannot be {kept[0].action}.")
+    missing = [c.sha[:7] for i, c in enumerate(commits) if i not in seen]
+    if missing:
+        problems.append(
+            f"Commits missing from the steps (use drop to remove one): "
+            f"{', '.join(missing)}."
+        )
+    return problems
+
+
+def rebase_todo(
+    steps: list[RebaseStep], commits: list[CommitInfo], msg_dir: Path
+) -> str:
+    """The todo list for *steps*; messages are read from ``msg_dir/<n>.msg``."""
+    lines = []
+    for number, step in enumerate(steps, start=1):
+        index = _match(step.commit, commits)
+        sha = commits[index].sha if index is not None else step.commit
+        action = "pick" if step.action == "reword" else step.action
+        lines.append(f"{action} {sha}")
+        if step.message.strip():
+            message_file = msg_dir / f"{number}.msg"
+            lines.append(
+                "exec "
+                + format_command(
+                    [
+                        "git",
+                        "commit",
+                        "--amend",
+                        "--quiet",
+                        "--no-verify",
+                        f"--file={message_file}",
+                    ]
+                )
+            )
+    return "\n".join(lines) + "\n"
+
+
+async def _unmerged(cwd: str | None) -> list[str]:
+    _, out, _ = await _run_git(["diff", "--name-only", "--diff-filter=U"], cwd=cwd)
+    return out.splitlines()
+
+
+async def execute_rebase(
+    steps: list[RebaseStep],
+    base: str | None = None,
+    cwd: str | None = None,
+    allow_force_push: bool = False,
+    dry_run: bool = False,
+) -> RebaseResult:
+    """Replay ``base..HEAD`` as *steps* with ``git rebase -i``.
+
+    On conflicts the rebase is left in progress (``stopped``) with the
+    conflicted paths listed; the old tip is kept under the backup ref either
+    way.
+
+    Raises:
+        WorkflowError: On invalid steps, a protected or dirty branch, a bare
+            repository, or published commits without *allow_force_push*.
+    """
+    context = await get_repo_context(cwd)
+    if context.is_bare:
+        raise WorkflowError("Cannot rebase in a bare repository.")
+    branch = await _git_ok(
+        ["symbolic-ref", "--short", "HEAD"], cwd, "Resolving the current branch"
+    )
+    if is_protected_branch(branch):
+        raise WorkflowError(f"'{branch}' is protected; history is not rewritten.")
+    status = await get_repo_status(cwd)
+    if status.staged or status.unstaged:
+        raise WorkflowError("Commit or stash local changes before rebasing.")
+
+    base_sha = await resolve_base(base, cwd)
+    commits = await branch_commits(base_sha, cwd)
+    if problems := validate_steps(steps, commits):
+        listing = "\n".join(f"  {c.sha[:7]} {c.subject}" for c in commits)
+        raise WorkflowError(
+            "Steps rejected:\n- "
+            + "\n- ".join(problems)
+            + f"\nBranch commits (oldest first):\n{listing}"
+        )
+
+    published, upstream = await _published(commits, cwd)
+    if published and not allow_force_push:
+        raise WorkflowError(
+            f"Some commits are already on {upstream}; pass allow_force_push=True "
+            "to rewrite them and push with --force-with-lease."
+        )
+
+    old_head = commits[-1].sha
+    stamp = datetime.now(timezone.utc).strftime("%Y%m%dT%H%M%S")
+    backup_ref = f"{BACKUP_REF_PREFIX}/{branch}/{stamp}"
+    msg_dir = Path(context.git_dir) / _MESSAGE_DIR
+    todo = rebase_todo(steps, commits, msg_dir)
+    push_cmd = (
+        ["push", "--force-with-lease", upstream.split("/", 1)[0], branch]
+        if published and upstream
+        else []
+    )
+
+    if dry_run:
+        commands = [
+            format_command(["git", "update-ref", backup_ref, old_head]),
+            format_command(["git", "rebase", "-i", base_sha]) + " with todo:",
+            *(f"  {line}" for line in todo.splitlines()),
+        ]
+        if push_cmd:
+            commands.append(format_command(["git", *push_cmd]))
+        return RebaseResult(branch=branch, old_head=old_head, commands=commands)
+
+    await _git_ok(["update-ref", backup_ref, old_head], cwd, "Saving a backup ref")
+    shutil.rmtree(msg_dir, ignore_errors=True)
+    msg_dir.mkdir()
+    for number, step in enumerate(steps, start=1):
+        if step.message.strip():
+            (msg_dir / f"{number}.msg").write_text(step.message.strip() + "\n")
+    todo_file = msg_dir / "todo"
+    todo_file.write_text(todo)
+
+    code, out, err = await _run_git(
+        ["rebase", "-i", base_sha],
+        cwd=cwd,
+        env={
+            "GIT_SEQUENCE_EDITOR": f"cp {sh_path(todo_file)}",
+            # Squash keeps the combined messages as git proposes them.
+            "GIT_EDITOR": "true",
+        },
+    )
+    if code != 0:
+        conflicts = await _unmerged(cwd)
+        if not conflicts:
+            await _run_git(["rebase", "--abort"], cwd=cwd)
+            shutil.rmtree(msg_dir, ignore_errors=True)
+            raise WorkflowError(f"git rebase failed and was aborted: {err or out}")
+        return RebaseResult(
+            branch=branch,
+            old_head=old_head,
+            backup_ref=backup_ref,
+            conflicts=conflicts,
+            stopped=True,
+        )
+    shutil.rmtree(msg_dir, ignore_errors=True)
+
+    new_head = await _git_ok(["rev-parse", "HEAD"], cwd, "git rev-parse")
+    subjects = await _git_ok(
+        ["log", "--reverse", "--format=%s", f"{base_sha}..HEAD"], cwd, "git log"
+    )
+    pushed = False
+    if push_cmd:
+        await _git_ok(push_cmd, cwd, "git push --force-with-lease")
+        pushed = True
+    return RebaseResult(
+        branch=branch,
+        old_head=old_head,
+        new_head=new_head,
+        backup_ref=backup_ref,
+        commits=subjects.splitlines(),
+        pushed=pushed,
+    )
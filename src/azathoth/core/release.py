@@ -0,0 +1,257 @@
+"""azathoth.core.release — publish releases on GitHub, GitLab or Gitea.
+
+Public surface:
+  - ``ReleaseBackend``                      — Protocol for a forge CLI
+  - ``RELEASE_BACKENDS``                    — name → backend class
+  - ``detect_release_backend(remote_url)``  → backend name or ``None``
+  - ``web_url(remote_url)``                 → browsable ``https://`` repo URL
+  - ``origin_web_url(cwd)``                 → ``web_url`` of ``origin`` or ``None``
+  - ``get_release_backend(cwd)``            → ``ReleaseBackend``
+  - ``create_release(tag, notes, …)``       → ``GitResult`` (tag, push, publish)
+
+Each backend drives the forge's own CLI (``gh``, ``glab``, ``tea``), which
+already handles authentication and self-hosted instances.  On GitHub a
+token (``github_token``, ``GITHUB_TOKEN`` or ``GH_TOKEN``) switches to the
+REST API (``core.github``), so ``gh`` need not be installed.  The forge is
+taken from ``release_backend`` in config, else inferred from the ``origin``
+remote URL; when neither says anything GitHub is assumed, as before.
+The release tag is annotated, or as ``release_tag_kind`` says
+(``core.tags``).
+"""
+
+from __future__ import annotations
+
+import re
+from collections.abc import Sequence
+from pathlib import Path
+from typing import Protocol, runtime_checkable
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.github import api_release, github_repo, github_token
+from azathoth.core.repos import repo_dir
+from azathoth.core.tags import tag_command
+from azathoth.core.workflow import (
+    GitResult,
+    _run_git,
+    ensure_revision,
+    planned,
+    run_command,
+)
+
+_REMOTE_HOST = re.compile(r"^(?:[\w+.-]+://)?(?:[^@/]+@)?([^/:]+)")
+_SCP_REMOTE = re.compile(r"^(?:[^@/]+@)?([^/:]+):(?!\d+/)(.+)$")
+_URL_REMOTE = re.compile(r"^[\w+.-]+://(?:[^@/]+@)?([^/:]+)(?::\d+)?/(.+)$")
+
+
+@runtime_checkable
+class ReleaseBackend(Protocol):
+    #: Config / registry key (``"github"``, ``"gitlab"``, ``"gitea"``).
+    name: str
+
+    #: Human-readable forge name for messages.
+    label: str
+
+    def command(
+        self,
+        tag: str,
+        notes: str,
+        title: str,
+        prerelease: bool,
+        assets: Sequence[str] = (),
+    ) -> list[str]:
+        """argv publishing a release for the already-pushed *tag*, with *assets*."""
+        ...  # pragma: no cover
+
+
+class GitHubRelease:
+    name = "github"
+    label = "GitHub"
+
+    def command(
+        self,
+        tag: str,
+        notes: str,
+        title: str,
+        prerelease: bool,
+        assets: Sequence[str] = (),
+    ) -> list[str]:
+        cmd = ["gh", "release", "create", tag, "--notes", notes, "--title", title]
+        cmd += ["--prerelease"] if prerelease else []
+        return [*cmd, *assets]
+
+
+class GitLabRelease:
+    """GitLab has no pre-release flag; *prerelease* is noted in the title."""
+
+    name = "gitlab"
+    label = "GitLab"
+
+    def command(
+        self,
+        tag: str,
+        notes: str,
+        title: str,
+        prerelease: bool,
+        assets: Sequence[str] = (),
+    ) -> list[str]:
+        if prerelease:
+            title = f"{title} (pre-release)"
+        cmd = ["glab", "release", "create", tag, "--notes", notes, "--name", title]
+        return [*cmd, *assets]
+
+
+class GiteaRelease:
+    """Gitea / Forgejo (incl. Codeberg) through the ``tea`` CLI."""
+
+    name = "gitea"
+    label = "Gitea"
+
+    def command(
+        self,
+        tag: str,
+        notes: str,
+        title: str,
+        prerelease: bool,
+        assets: Sequence[str] = (),
+    ) -> list[str]:
+        cmd = ["tea", "release", "create", "--tag", tag, "--title", title]
+        cmd += ["--note", notes]
+        cmd += ["--prerelease"] if prerelease else []
+        return [*cmd, *(arg for path in assets for arg in ("--asset", path))]
+
+
+RELEASE_BACKENDS: dict[str, type[ReleaseBackend]] = {
+    "github": GitHubRelease,
+    "gitlab": GitLabRelease,
+    "gitea": GiteaRelease,
+}
+
+
+def detect_release_backend(remote_url: str) -> str | None:
+    """Infer the forge from a remote URL (``https://…`` or ``git@host:…``)."""
+    match = _REMOTE_HOST.match(remote_url.strip())
+    host = match.group(1).lower() if match else ""
+    if host == "github.com" or host.startswith("github."):
+        return "github"
+    if "gitlab" in host:
+        return "gitlab"
+    if "gitea" in host or "forgejo" in host or host == "codeberg.org":
+        return "gitea"
+    return None
+
+
+def web_url(remote_url: str) -> str:
+    """``https://host/owner/repo`` for an https, ssh or scp-style remote URL."""
+    url = remote_url.strip().removesuffix("/").removesuffix(".git")
+    match = _URL_REMOTE.match(url) or _SCP_REMOTE.match(url)
+    return f"https://{match.group(1)}/{match.group(2)}" if match else url
+
+
+async def origin_web_url(cwd: str | None = None) -> str | None:
+    """Browsable URL of the ``origin`` remote, or ``None`` without one."""
+    code, url, _ = await _run_git(["remote", "get-url", "origin"], cwd=cwd)
+    return web_url(url) if code == 0 and url else None
+
+
+async def get_release_backend(cwd: str | None = None) -> ReleaseBackend:
+    """Backend from ``release_backend`` config, else from the origin remote.
+
+    Raises:
+        WorkflowError: If ``release_backend`` names an unknown backend.
+    """
+    name = get_config().release_backend
+    if name == "auto":
+        code, url, _ = await _run_git(["remote", "get-url", "origin"], cwd=cwd)
+        name = (detect_release_backend(url) if code == 0 else None) or "github"
+    if name not in RELEASE_BACKENDS:
+        raise WorkflowError(
+            f"Unknown release backend '{name}'; expected one of "
+            f"{', '.join(RELEASE_BACKENDS)}."
+        )
+    return RELEASE_BACKENDS[name]()
+
+
+async def create_release(
+    tag: str,
+    notes: str,
+    is_prerelease: bool = False,
+    dry_run: bool = False,
+    cwd: str | None = None,
+    assets: Sequence[str] = (),
+) -> GitResult:
+    """Tag HEAD, push the tag to origin and publish a release on the forge.
+
+    *assets* are files (relative to the repository) attached to the release;
+    any that resolves outside it is refused.  On GitHub the REST API is used
+    when a token is available (see ``core.github``), else ``gh``.
+    """
+    try:
+        tag = ensure_revision(tag)
+        backend = await get_release_backend(cwd)
+    except WorkflowError as exc:
+        return GitResult(success=False, stdout="", stderr=str(exc), message=str(exc))
+    base = Path(cwd or repo_dir() or ".")
+    root = base.resolve()
+    if outside := [a for a in assets if not (root / a).resolve().is_relative_to(root)]:
+        message = f"Release assets must be inside the repository: {', '.join(outside)}"
+        return GitResult(success=False, stdout="", stderr=message, message=message)
+    if missing := [a for a in assets if not (base / a).is_file()]:
+        message = f"Release asset not found: {', '.join(missing)}"
+        return GitResult(success=False, stdout="", stderr=message, message=message)
+    title = f"Release {tag}"
+    tag_cmd = tag_command(tag, title, get_config().release_tag_kind)
+    push_cmd = ["push", "origin", tag]
+    token = github_token() if backend.name == "github" else None
+    repo = await github_repo(cwd) if token else None
+    if repo is not None:
+        publish_cmds = [["POST", f"{repo.api_url}/releases"]]
+        publish_cmds += [
+            ["POST", f"{repo.api_url}/releases/<id>/assets?name={Path(a).name}"]
+            for a in assets
+        ]
+    else:
+        publish_cmds = [backend.command(tag, notes, title, is_prerelease, assets)]
+
+    if dry_run:
+        return planned(["git", *tag_cmd], ["git", *push_cmd], *publish_cmds)
+
+    t_code, t_out, t_err = await _run_git(tag_cmd, cwd=cwd)
+    if t_code != 0:
+        return GitResult(
+            success=False, stdout=t_out, stderr=t_err, message="Tagging failed"
+        )
+
+    p_code, p_out, p_err = await _run_git(push_cmd, cwd=cwd)
+    if p_code != 0:
+        return GitResult(
+            success=False, stdout=p_out, stderr=p_err, message="Pushing tag failed"
+        )
+
+    if repo is not None and token is not None:
+        try:
+            url, uploaded = await api_release(
+                repo,
+                tag,
+                notes,
+                title,
+                is_prerelease,
+                token,
+                [base / a for a in assets],
+            )
+        except WorkflowError as exc:
+            return GitResult(
+                success=False,
+                stdout="",
+                stderr=str(exc),
+                message=f"{backend.label} release failed",
+            )
+        return GitResult(success=True, stdout="\n".join([url, *uploaded]), stderr="")
+
+    code, out, err = await run_command(publish_cmds[0], cwd=cwd)
+    return GitResult(
+        success=(code == 0),
+        stdout=out,
+        stderr=err,
+        message=None if code == 0 else f"{backend.label} release failed",
+    )
@@ -0,0 +1,58 @@
+"""azathoth.core.render — how tool text reaches the client.
+
+Public surface:
+  - ``RenderMode``                 — ``plain``, ``ansi`` or ``markdown``
+  - ``render_mode(server)``        → the configured mode for an MCP server
+  - ``render(text, mode, fence)``  → *text* prepared for the client
+  - ``strip_ansi(text)``           → *text* without terminal escape sequences
+
+Tool output often carries what a terminal would show: colour codes from
+task runners, hooks and git itself.  MCP clients display escape sequences
+verbatim, so ``plain`` (the default) removes them; ``ansi`` passes output
+through untouched for clients that render a terminal; ``markdown`` removes
+them too and puts output with a known syntax (a diff, a log) in a fenced
+code block so chat clients highlight it.  The mode is set per server
+(``mcp_render_modes``) with ``mcp_render_mode`` as the fallback.
+"""
+
+from __future__ import annotations
+
+import re
+from typing import Literal
+
+from azathoth.config import get_config
+
+RenderMode = Literal["plain", "ansi", "markdown"]
+
+# CSI sequences (colours, cursor movement) and OSC sequences (hyperlinks, titles).
+_ANSI = re.compile(r"\x1b\[[0-?]*[ -/]*[@-~]|\x1b\][^\x07\x1b]*(?:\x07|\x1b\\)")
+
+
+def strip_ansi(text: str) -> str:
+    return _ANSI.sub("", text)
+
+
+def render_mode(server: str) -> RenderMode:
+    config = get_config()
+    return config.mcp_render_modes.get(server, config.mcp_render_mode)
+
+
+def _fenced(text: str, info: str) -> str:
+    # A fence must be longer than any backtick run inside the block.
+    runs = re.findall(r"`{3,}", text)
+    fence = "`" * max([3, *(len(run) + 1 for run in runs)])
+    return f"{fence}{info}\n{text.rstrip(chr(10))}\n{fence}"
+
+
+def render(text: str, mode: RenderMode = "plain", fence: str | None = None) -> str:
+    """*text* as the client should see it.
+
+    *fence* is the code-block info string (``diff``, ``text``, …) used in
+    ``markdown`` mode; without one the text is only stripped of escapes.
+    """
+    if mode == "ansi":
+        return text
+    text = strip_ansi(text)
+    if mode == "markdown" and fence is not None and text.strip():
+        return _fenced(text, fence)
+    return text
@@ -0,0 +1,49 @@
+"""azathoth.core.repo_config — per-repository settings from ``.azathoth.toml``.
+
+Public surface:
+  - ``find_repo_root(start)``   → nearest ancestor containing ``.git`` (or *start*)
+  - ``load_repo_config(start)`` → parsed ``.azathoth.toml`` at the repo root
+
+User-level settings live in ``azathoth.config``; this file holds policy a
+repository wants enforced for everyone working on it (e.g. ``[commit]``
+conventions) and is meant to be committed alongside the code.
+"""
+
+from __future__ import annotations
+
+import tomllib
+from pathlib import Path
+from typing import Any
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.repos import repo_dir
+
+REPO_CONFIG_NAME = ".azathoth.toml"
+
+
+def find_repo_root(start: str | Path | None = None) -> Path:
+    """Walk up from *start* (default: the selected repo or cwd) to the directory containing ``.git``.
+
+    Falls back to *start* itself outside a repository.
+    """
+    here = Path(start or repo_dir() or ".").resolve()
+    for candidate in (here, *here.parents):
+        if (candidate / ".git").exists():
+            return candidate
+    return here
+
+
+def load_repo_config(start: str | Path | None = None) -> dict[str, Any]:
+    """Return the repo's ``.azathoth.toml`` as a dict (empty if absent).
+
+    Raises:
+        WorkflowError: If the file exists but is not valid TOML.
+    """
+    path = find_repo_root(start) / REPO_CONFIG_NAME
+    if not path.is_file():
+        return {}
+    try:
+        with open(path, "rb") as f:
+            return tomllib.load(f)
+    except tomllib.TOMLDecodeError as exc:
+        raise WorkflowError(f"Invalid {path}: {exc}") from exc
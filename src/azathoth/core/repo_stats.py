@@ -0,0 +1,252 @@
+"""azathoth.core.repo_stats — measured size and activity of a project.
+
+Public surface:
+  - ``LANGUAGE_SUFFIXES``          — file suffix → language for the built-in counter
+  - ``count_lines(text, language)`` → ``(code, comments, blanks)``
+  - ``repo_stats(root)``           → ``RepoStats``
+
+Lines of code per language come from ``tokei`` when it is installed and
+from a built-in counter otherwise.  The built-in counter classifies a line
+as blank, a line comment (``#``, ``//``, ``--`` … by language) or code; it
+does not track block comments, so its comment counts are a floor, close
+enough to rank languages and size a codebase.  Its per-file counts are
+kept in the scout index (``core.index``), so a repeat run only reads the
+files that changed.  Commit activity covers the
+last twelve weeks of the branch that is checked out.
+"""
+
+from __future__ import annotations
+
+import json
+from collections import Counter
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.files import is_binary
+from azathoth.core.index import RepoIndex
+from azathoth.core.traverse import iter_files
+from azathoth.core.workflow import run_command
+
+LANGUAGE_SUFFIXES: dict[str, str] = {
+    ".py": "Python",
+    ".pyi": "Python",
+    ".rs": "Rust",
+    ".go": "Go",
+    ".js": "JavaScript",
+    ".mjs": "JavaScript",
+    ".cjs": "JavaScript",
+    ".jsx": "JSX",
+    ".ts": "TypeScript",
+    ".tsx": "TSX",
+    ".svelte": "Svelte",
+    ".vue": "Vue",
+    ".java": "Java",
+    ".kt": "Kotlin",
+    ".swift": "Swift",
+    ".c": "C",
+    ".h": "C Header",
+    ".cc": "C++",
+    ".cpp": "C++",
+    ".hpp": "C++ Header",
+    ".cs": "C#",
+    ".rb": "Ruby",
+    ".php": "PHP",
+    ".sh": "Shell",
+    ".bash": "Shell",
+    ".sql": "SQL",
+    ".lua": "Lua",
+    ".html": "HTML",
+    ".css": "CSS",
+    ".scss": "Sass",
+    ".toml": "TOML",
+    ".yaml": "YAML",
+    ".yml": "YAML",
+    ".json": "JSON",
+    ".md": "Markdown",
+}
+
+_LINE_COMMENTS: dict[str, tuple[str, ...]] = {
+    **dict.fromkeys(["Python", "Shell", "Ruby", "TOML", "YAML"], ("#",)),
+    **dict.fromkeys(["SQL", "Lua"], ("--",)),
+    "PHP": ("//", "#"),
+    **dict.fromkeys(["HTML", "CSS", "JSON", "Markdown"], ()),
+}
+_C_STYLE = ("//",)
+
+_MAX_FILES = 20_000
+_MAX_BYTES = 2_000_000
+_LARGEST = 10
+_ACTIVITY_WEEKS = 12
+
+
+class LanguageStats(BaseModel, frozen=True):
+    language: str
+    files: int
+    code: int
+    comments: int
+    blanks: int
+
+
+class FileSize(BaseModel, frozen=True):
+    path: str
+    bytes: int
+
+
+class CommitActivity(BaseModel, frozen=True):
+    commits: int = Field(description="Commits in the last twelve weeks")
+    authors: int = Field(description="Distinct author emails in that window")
+    last_commit: str | None = Field(None, description="ISO date of HEAD")
+    weekly: list[int] = Field(description="Commits per week, oldest first")
+
+
+class RepoStats(BaseModel, frozen=True):
+    root: str
+    counter: Literal["tokei", "builtin"]
+    files: int = Field(description="Files outside dependency/build/VCS dirs")
+    code_lines: int
+    languages: list[LanguageStats] = Field(description="Most code first")
+    largest_files: list[FileSize]
+    activity: CommitActivity | None = Field(None, description="None outside git")
+    truncated: bool = Field(False, description=f"Stopped after {_MAX_FILES} files")
+
+
+def count_lines(text: str, language: str) -> tuple[int, int, int]:
+    """Code, comment and blank lines of *text* (line comments only)."""
+    markers = _LINE_COMMENTS.get(language, _C_STYLE)
+    code = comments = blanks = 0
+    for line in text.splitlines():
+        stripped = line.strip()
+        if not stripped:
+            blanks += 1
+        elif markers and stripped.startswith(markers):
+            comments += 1
+        else:
+            code += 1
+    return code, comments, blanks
+
+
+class _FileLines(BaseModel, frozen=True):
+    """Line counts of one file, as kept in the scout index."""
+
+    binary: bool = False
+    code: int = 0
+    comments: int = 0
+    blanks: int = 0
+
+
+def _file_lines(path: Path) -> _FileLines:
+    data = path.read_bytes()
+    if is_binary(data[:8192]):
+        return _FileLines(binary=True)
+    language = LANGUAGE_SUFFIXES[path.suffix.lower()]
+    code, comments, blanks = count_lines(
+        data.decode("utf-8", errors="replace"), language
+    )
+    return _FileLines(code=code, comments=comments, blanks=blanks)
+
+
+def _builtin_counts(files: list[Path], index: RepoIndex) -> list[LanguageStats]:
+    totals: dict[str, list[int]] = {}
+    for path in files:
+        language = LANGUAGE_SUFFIXES.get(path.suffix.lower())
+        if language is None:
+            continue
+        try:
+            if path.stat().st_size > _MAX_BYTES:
+                continue
+            lines = index.cached("lines", path, _FileLines, _file_lines)
+        except OSError:
+            continue
+        if lines is None or lines.binary:
+            continue
+        entry = totals.setdefault(language, [0, 0, 0, 0])
+        entry[0] += 1
+        entry[1] += lines.code
+        entry[2] += lines.comments
+        entry[3] += lines.blanks
+    return [
+        LanguageStats(language=name, files=f, code=c, comments=m, blanks=b)
+        for name, (f, c, m, b) in totals.items()
+    ]
+
+
+async def _tokei_counts(root: Path) -> list[LanguageStats] | None:
+    """Per-language counts from ``tokei``, or ``None`` if it is unavailable."""
+    code, out, _ = await run_command(["tokei", "--output", "json", str(root)])
+    if code != 0:
+        return None
+    try:
+        report = json.loads(out)
+    except json.JSONDecodeError:
+        return None
+    return [
+        LanguageStats(
+            language=name,
+            files=len(stats.get("reports", [])),
+            code=stats.get("code", 0),
+            comments=stats.get("comments", 0),
+            blanks=stats.get("blanks", 0),
+        )
+        for name, stats in report.items()
+        if name != "Total" and stats.get("code", 0) + stats.get("comments", 0)
+    ]
+
+
+async def _activity(root: Path) -> CommitActivity | None:
+    since = datetime.now(timezone.utc) - timedelta(weeks=_ACTIVITY_WEEKS)
+    code, out, _ = await run_command(
+        ["git", "log", f"--since={since.isoformat()}", "--format=%aI%x1f%ae"],
+        cwd=str(root),
+    )
+    if code != 0:
+        return None
+    weekly = [0] * _ACTIVITY_WEEKS
+    authors: set[str] = set()
+    commits = out.splitlines()
+    for line in commits:
+        stamp, _, email = line.partition("\x1f")
+        authors.add(email)
+        age = (datetime.now(timezone.utc) - datetime.fromisoformat(stamp)).days // 7
+        weekly[max(0, _ACTIVITY_WEEKS - 1 - age)] += 1
+    _, last, _ = await run_command(["git", "log", "-1", "--format=%aI"], cwd=str(root))
+    return CommitActivity(
+        commits=len(commits),
+        authors=len(authors),
+        last_commit=last or None,
+        weekly=weekly,
+    )
+
+
+async def repo_stats(root: Path) -> RepoStats:
+    """Lines of code per language, file count, largest files and activity."""
+    files = list(iter_files(root, max_files=_MAX_FILES))
+    sizes: Counter[str] = Counter()
+    for path in files:
+        try:
+            sizes[path.relative_to(root).as_posix()] = path.stat().st_size
+        except OSError:
+            continue
+
+    languages = await _tokei_counts(root)
+    counter: Literal["tokei", "builtin"] = "tokei"
+    if languages is None:
+        index = RepoIndex.open(root)
+        languages, counter = _builtin_counts(files, index), "builtin"
+        index.save()
+    languages.sort(key=lambda stats: (-stats.code, stats.language))
+
+    return RepoStats(
+        root=str(root),
+        counter=counter,
+        files=len(files),
+        code_lines=sum(stats.code for stats in languages),
+        languages=languages,
+        largest_files=[
+            FileSize(path=path, bytes=size) for path, size in sizes.most_common(_LARGEST)
+        ],
+        activity=await _activity(root),
+        truncated=len(files) >= _MAX_FILES,
+    )
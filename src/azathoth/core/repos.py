@@ -0,0 +1,112 @@
+"""azathoth.core.repos — which repository a tool call works on.
+
+Public surface:
+  - ``use_repo(path)``      → context manager scoping commands to *path*
+  - ``repo_dir()``          → the scoped repository, or ``None`` for the cwd
+  - ``resolve_repo(path)``  → *path* resolved and checked against ``workflow_repos``
+  - ``list_repos()``        → ``[RepoEntry]`` for the allow-list
+
+A workflow server normally works on its working directory.  A tool call
+with ``repo_path`` runs inside ``use_repo`` instead: the path is kept in a
+``ContextVar`` that ``run_command`` and the cwd-relative helpers consult,
+so concurrent calls on different repositories never share state.  Only
+directories listed in ``workflow_repos`` (paths or fnmatch patterns) may be
+selected; an agent cannot point the server at an arbitrary directory.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Iterator
+from contextlib import contextmanager
+from contextvars import ContextVar
+from fnmatch import fnmatchcase
+from pathlib import Path
+
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+
+_repo: ContextVar[Path | None] = ContextVar("workflow_repo", default=None)
+
+# A directory is listed as a repository if it holds one of these.
+_MARKERS = (".git", ".jj", ".hg")
+
+
+class RepoEntry(BaseModel, frozen=True):
+    name: str
+    path: str
+    available: bool = Field(description="The directory exists and is a repository")
+    current: bool = Field(description="Calls without repo_path work on it")
+
+
+def repo_dir() -> str | None:
+    """Directory of the repository selected for this call, if any."""
+    repo = _repo.get()
+    return str(repo) if repo is not None else None
+
+
+@contextmanager
+def use_repo(path: Path) -> Iterator[Path]:
+    """Run the block's commands in *path* (already resolved and allowed)."""
+    token = _repo.set(path)
+    try:
+        yield path
+    finally:
+        _repo.reset(token)
+
+
+def _allowed() -> list[Path]:
+    return [entry.expanduser() for entry in get_config().workflow_repos]
+
+
+def _is_pattern(entry: Path) -> bool:
+    return any(ch in str(entry) for ch in "*?[")
+
+
+def resolve_repo(path: str) -> Path:
+    """*path* as an absolute directory, if ``workflow_repos`` allows it.
+
+    Raises:
+        WorkflowError: If the path is not allowed or is not a directory.
+    """
+    allowed = _allowed()
+    target = Path(path).expanduser().resolve()
+    permitted = any(
+        fnmatchcase(str(target), str(entry))
+        if _is_pattern(entry)
+        else target == entry.resolve()
+        for entry in allowed
+    )
+    if not permitted:
+        listed = ", ".join(str(entry) for entry in allowed) or "none configured"
+        raise WorkflowError(
+            f"'{path}' is not an allowed repository (workflow_repos: {listed}); "
+            "call list_repos for the repositories this server manages."
+        )
+    if not target.is_dir():
+        raise WorkflowError(f"Repository '{path}' does not exist.")
+    return target
+
+
+def list_repos() -> list[RepoEntry]:
+    """The repositories ``repo_path`` may select, patterns expanded."""
+    current = Path.cwd().resolve()
+    found: dict[Path, None] = {}
+    for entry in _allowed():
+        if _is_pattern(entry):
+            anchor = Path(entry.anchor or ".")
+            pattern = str(entry.relative_to(anchor)) if entry.anchor else str(entry)
+            matches = sorted(anchor.glob(pattern))
+            found.update(dict.fromkeys(p for p in matches if p.is_dir()))
+        else:
+            found[entry] = None
+    return [
+        RepoEntry(
+            name=path.name,
+            path=str(path.resolve()),
+            available=any((path / marker).exists() for marker in _MARKERS),
+            current=path.resolve() == current,
+        )
+        for path in found
+    ]
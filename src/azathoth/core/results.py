@@ -0,0 +1,98 @@
+"""azathoth.core.results — typed results of the workflow server's action tools.
+
+Public surface:
+  - ``ActionResult``        — a branch, worktree, stash or conflict action
+  - ``CommitResult``        — what ``stage_and_commit`` committed (or would)
+  - ``ReleaseResult``       — what ``create_release`` published (or would)
+  - ``ScriptResult``        — a declared repo task run by ``run_script``
+  - ``RepoOverview``        — ``get_status``'s counts and tag position
+  - ``patch_stats(patch)``  → ``(files, insertions, deletions)``
+
+Tools return these as MCP structured content instead of "✓ …" / "✗ …"
+strings, so clients read fields rather than parsing prose.  Failures are
+raised as tool errors, never returned; a dry run is a result with
+``commands`` filled in and the "done" flag (``committed``, ``published``,
+``ran``, ``done``) false.
+"""
+
+from __future__ import annotations
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.signing import SignatureStatus
+
+class ActionResult(BaseModel, frozen=True):
+    done: bool = Field(description="False for a dry run")
+    summary: str
+    detail: str | None = Field(None, description="Warnings or follow-up notes")
+    output: str = Field("", description="Command output")
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+class CommitResult(BaseModel, frozen=True):
+    committed: bool = Field(description="False for a dry run")
+    sha: str | None = None
+    branch: str | None = None
+    title: str
+    body: str = ""
+    files_changed: int = 0
+    insertions: int = 0
+    deletions: int = 0
+    signature: SignatureStatus | None = Field(
+        None, description="Signature of the new commit (git only)"
+    )
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+class ReleaseResult(BaseModel, frozen=True):
+    tag: str
+    previous_tag: str
+    notes: str
+    prerelease: bool = False
+    published: bool = Field(description="False for a dry run")
+    url: str | None = Field(None, description="Release page, when the forge says")
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+class ScriptResult(BaseModel, frozen=True):
+    name: str
+    source: str = Field(description="Makefile, justfile, package.json, …")
+    command: str
+    ran: bool = Field(description="False for a dry run")
+    success: bool = False
+    exit_code: int | None = None
+    duration_s: float = 0.0
+    timed_out: bool = False
+    stdout: str = ""
+    stderr: str = ""
+    truncated: bool = Field(False, description="Long output kept its tail only")
+
+
+class RepoOverview(BaseModel, frozen=True):
+    backend: str
+    branch: str | None
+    worktree: str | None = None
+    linked_worktree: bool = False
+    shape: str = Field(description="full, shallow or bare")
+    staged: int = 0
+    unstaged: int = 0
+    untracked: int = 0
+    latest_tag: str | None = None
+    commits_since_tag: int = 0
+
+
+def patch_stats(patch: str) -> tuple[int, int, int]:
+    """Files, inserted and deleted lines of a git-format *patch*."""
+    files = insertions = deletions = 0
+    in_hunk = False
+    for line in patch.splitlines():
+        if line.startswith("diff --git "):
+            files += 1
+            in_hunk = False
+        elif line.startswith("@@"):
+            in_hunk = True
+        elif in_hunk and line.startswith("+"):
+            insertions += 1
+        elif in_hunk and line.startswith("-"):
+            deletions += 1
+    return files, insertions, deletions
@@ -0,0 +1,251 @@
+"""azathoth.core.reviews — read and review GitHub pull requests.
+
+Public surface:
+  - ``list_prs(state, base, limit, cwd)``                → ``[PullRequestInfo]``
+  - ``pr_diff(number, cwd)``                             → ``PullRequestDiff``
+  - ``pr_comment(number, body, path, line, …)``          → ``ReviewPost``
+  - ``pr_review(number, event, body, comments, …)``      → ``ReviewPost``
+  - ``parse_hunks(patch)``                               → ``[DiffHunk]``
+
+Everything goes through ``github_api`` (``core.github``): the REST API when
+a token is available, else ``gh api``.  ``pr_diff`` returns each changed
+file with its hunks split out and their line ranges parsed, so a reviewer
+can anchor comments: ``line`` is a line number in the new file (``side``
+``RIGHT``) or, for removed lines, in the old one (``LEFT``).  A comment
+without a path goes to the PR's conversation; a review bundles a verdict
+(``APPROVE``, ``REQUEST_CHANGES`` or ``COMMENT``) with any number of line
+comments.  Posting tools honour ``dry_run`` and return the request they
+would make.
+"""
+
+from __future__ import annotations
+
+import re
+from typing import Any, Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.github import api_plan, github_api
+
+PullRequestState = Literal["open", "closed", "all"]
+Side = Literal["LEFT", "RIGHT"]
+ReviewEvent = Literal["APPROVE", "REQUEST_CHANGES", "COMMENT"]
+
+_HUNK = re.compile(r"^@@ -(\d+)(?:,(\d+))? \+(\d+)(?:,(\d+))? @@ ?(.*)$")
+_MAX_FILES = 3000  # GitHub's own cap on pulls/N/files
+
+
+class PullRequestInfo(BaseModel, frozen=True):
+    number: int
+    title: str
+    author: str | None = None
+    head: str
+    base: str
+    draft: bool = False
+    state: str = "open"
+    url: str | None = None
+    updated_at: str | None = None
+    labels: list[str] = Field(default_factory=list)
+
+
+class DiffHunk(BaseModel, frozen=True):
+    header: str = Field(description="Function context after the @@ range")
+    old_start: int
+    old_lines: int
+    new_start: int
+    new_lines: int
+    lines: list[str] = Field(description="Hunk body, each with its +/-/space prefix")
+
+
+class FileDiff(BaseModel, frozen=True):
+    path: str
+    previous_path: str | None = None
+    status: str = Field(description="added, modified, removed, renamed, …")
+    additions: int = 0
+    deletions: int = 0
+    binary: bool = Field(False, description="No textual patch (binary or too large)")
+    hunks: list[DiffHunk] = Field(default_factory=list)
+
+
+class PullRequestDiff(BaseModel, frozen=True):
+    number: int
+    head_sha: str
+    files: list[FileDiff] = Field(default_factory=list)
+
+
+class ReviewComment(BaseModel, frozen=True):
+    """A line comment inside a review."""
+
+    path: str
+    line: int
+    body: str
+    side: Side = "RIGHT"
+
+
+class ReviewPost(BaseModel, frozen=True):
+    """Outcome (or dry-run plan) of a PR comment or review."""
+
+    number: int
+    kind: Literal["comment", "line-comment", "review"]
+    event: ReviewEvent | None = None
+    posted: bool = Field(description="False for a dry run")
+    url: str | None = None
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+def parse_hunks(patch: str) -> list[DiffHunk]:
+    """Split a unified-diff *patch* (GitHub's per-file ``patch``) into hunks."""
+    hunks: list[DiffHunk] = []
+    current: dict[str, Any] | None = None
+    for line in patch.splitlines():
+        match = _HUNK.match(line)
+        if match:
+            if current is not None:
+                hunks.append(DiffHunk(**current))
+            old_start, old_lines, new_start, new_lines, header = match.groups()
+            current = {
+                "header": header,
+                "old_start": int(old_start),
+                "old_lines": int(old_lines) if old_lines is not None else 1,
+                "new_start": int(new_start),
+                "new_lines": int(new_lines) if new_lines is not None else 1,
+                "lines": [],
+            }
+        elif current is not None:
+            current["lines"].append(line)
+    if current is not None:
+        hunks.append(DiffHunk(**current))
+    return hunks
+
+
+def _pr_info(raw: dict[str, Any]) -> PullRequestInfo:
+    return PullRequestInfo(
+        number=raw["number"],
+        title=raw.get("title", ""),
+        author=(raw.get("user") or {}).get("login"),
+        head=raw.get("head", {}).get("ref", ""),
+        base=raw.get("base", {}).get("ref", ""),
+        draft=bool(raw.get("draft")),
+        state=raw.get("state", "open"),
+        url=raw.get("html_url"),
+        updated_at=raw.get("updated_at"),
+        labels=[label["name"] for label in raw.get("labels", [])],
+    )
+
+
+async def list_prs(
+    state: PullRequestState = "open",
+    base: str | None = None,
+    limit: int = 30,
+    cwd: str | None = None,
+) -> list[PullRequestInfo]:
+    """Pull requests in *state*, most recently updated first."""
+    query = f"pulls?state={state}&sort=updated&direction=desc&per_page={limit}"
+    query += f"&base={base}" if base else ""
+    return [_pr_info(raw) for raw in await github_api("GET", query, cwd)][:limit]
+
+
+async def pr_diff(number: int, cwd: str | None = None) -> PullRequestDiff:
+    """The changed files of PR *number*, each split into hunks."""
+    pr = await github_api("GET", f"pulls/{number}", cwd)
+    files: list[FileDiff] = []
+    page = 1
+    while len(files) < _MAX_FILES:
+        batch = await github_api(
+            "GET", f"pulls/{number}/files?per_page=100&page={page}", cwd
+        )
+        for raw in batch:
+            patch = raw.get("patch")
+            files.append(
+                FileDiff(
+                    path=raw["filename"],
+                    previous_path=raw.get("previous_filename"),
+                    status=raw.get("status", "modified"),
+                    additions=raw.get("additions", 0),
+                    deletions=raw.get("deletions", 0),
+                    binary=patch is None,
+                    hunks=parse_hunks(patch or ""),
+                )
+            )
+        if len(batch) < 100:
+            break
+        page += 1
+    return PullRequestDiff(number=number, head_sha=pr["head"]["sha"], files=files)
+
+
+async def pr_comment(
+    number: int,
+    body: str,
+    path: str | None = None,
+    line: int | None = None,
+    side: Side = "RIGHT",
+    dry_run: bool = False,
+    cwd: str | None = None,
+) -> ReviewPost:
+    """Comment on PR *number*: on *path* at *line* if given, else on the PR.
+
+    Raises:
+        WorkflowError: If only one of *path* and *line* is given, or GitHub
+            rejects the comment (e.g. *line* is not part of the diff).
+    """
+    if (path is None) != (line is None):
+        raise WorkflowError("A line comment needs both path and line.")
+    if not body.strip():
+        raise WorkflowError("The comment body is empty.")
+    if path is None:
+        endpoint, kind = f"issues/{number}/comments", "comment"
+    else:
+        endpoint, kind = f"pulls/{number}/comments", "line-comment"
+    if dry_run:
+        return ReviewPost(
+            number=number,
+            kind=kind,
+            posted=False,
+            commands=[await api_plan("POST", endpoint, cwd)],
+        )
+    payload: dict[str, Any] = {"body": body}
+    if path is not None:
+        head = (await github_api("GET", f"pulls/{number}", cwd))["head"]["sha"]
+        payload |= {"commit_id": head, "path": path, "line": line, "side": side}
+    posted = await github_api("POST", endpoint, cwd, body=payload)
+    return ReviewPost(number=number, kind=kind, posted=True, url=posted.get("html_url"))
+
+
+async def pr_review(
+    number: int,
+    event: ReviewEvent,
+    body: str = "",
+    comments: list[ReviewComment] | None = None,
+    dry_run: bool = False,
+    cwd: str | None = None,
+) -> ReviewPost:
+    """Submit a review of PR *number* with *event* and optional line comments.
+
+    Raises:
+        WorkflowError: If a change request or comment-only review has no
+            body and no comments, or GitHub rejects the review.
+    """
+    comments = comments or []
+    if event != "APPROVE" and not body.strip() and not comments:
+        raise WorkflowError(f"A {event} review needs a body or line comments.")
+    endpoint = f"pulls/{number}/reviews"
+    if dry_run:
+        return ReviewPost(
+            number=number,
+            kind="review",
+            event=event,
+            posted=False,
+            commands=[await api_plan("POST", endpoint, cwd)],
+        )
+    payload: dict[str, Any] = {"event": event, "body": body}
+    if comments:
+        payload["comments"] = [c.model_dump() for c in comments]
+    review = await github_api("POST", endpoint, cwd, body=payload)
+    return ReviewPost(
+        number=number,
+        kind="review",
+        event=event,
+        posted=True,
+        url=review.get("html_url"),
+    )
@@ -0,0 +1,325 @@
+"""azathoth.core.rewrite — non-interactive squash/reword of a feature branch.
+
+Public surface:
+  - ``resolve_base(base, cwd)``         → commit the branch forked from
+  - ``branch_commits(base, cwd)``       → ``[CommitInfo]`` oldest first
+  - ``validate_plan(plan, commits)``    → problems with a ``SquashGroup`` plan
+  - ``group_diff(group, commits, cwd)`` → combined patch of one group
+  - ``rewrite_history(...)``            → ``RewriteResult``
+
+A plan is an ordered list of groups of *contiguous* commits; each group
+becomes one commit whose tree is that of the group's last commit, so the
+final tree is byte-identical to the old HEAD and the working tree is never
+touched.  The old tip is kept under ``refs/azathoth/backup/<branch>``.
+"""
+
+from __future__ import annotations
+
+from datetime import datetime, timezone
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.policy import is_protected_branch, protected_branch_names
+from azathoth.core.workflow import (
+    CommitInfo,
+    _run_git,
+    format_command,
+    get_repo_context,
+    get_repo_status,
+)
+
+BACKUP_REF_PREFIX = "refs/azathoth/backup"
+_MIN_SHA_PREFIX = 7
+
+
+class SquashGroup(BaseModel):
+    """Commits to fold into one, with the message for the result.
+
+    ``title`` may be left empty for the caller to generate one.
+    """
+
+    commits: list[str] = Field(min_length=1)
+    title: str = ""
+    body: str = ""
+
+
+class RewriteResult(BaseModel, frozen=True):
+    branch: str
+    old_head: str
+    new_head: str | None = None
+    backup_ref: str | None = None
+    commits: list[str] = Field(default_factory=list, description="New subjects")
+    pushed: bool = False
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+async def _git_ok(args: list[str], cwd: str | None, action: str) -> str:
+    code, out, err = await _run_git(args, cwd=cwd)
+    if code != 0:
+        raise WorkflowError(f"{action} failed: {err or out}")
+    return out
+
+
+async def resolve_base(base: str | None = None, cwd: str | None = None) -> str:
+    """Return the fork point of HEAD.
+
+    Uses *base* when given, else the upstream branch, else the first
+    protected branch that exists (``main``/``master``).
+
+    Raises:
+        WorkflowError: If no base can be determined.
+    """
+    candidates = [base] if base else ["@{upstream}", *protected_branch_names()]
+    for ref in candidates:
+        code, out, _ = await _run_git(["merge-base", "HEAD", ref], cwd=cwd)
+        if code == 0 and out:
+            return out
+    raise WorkflowError(
+        "Cannot determine the branch base; pass base (e.g. 'main' or a sha)."
+    )
+
+
+async def branch_commits(base: str, cwd: str | None = None) -> list[CommitInfo]:
+    """Commits in ``base..HEAD``, oldest first.
+
+    Raises:
+        WorkflowError: If the range contains merge commits (not rewritable).
+    """
+    merges = await _git_ok(
+        ["rev-list", "--min-parents=2", f"{base}..HEAD"], cwd, "git rev-list"
+    )
+    if merges:
+        raise WorkflowError(
+            "The branch contains merge commits; rebase it before cleaning up."
+        )
+    out = await _git_ok(
+        [
+            "log",
+            "--reverse",
+            "--pretty=format:%H%x1f%an%x1f%aI%x1f%s",
+            f"{base}..HEAD",
+        ],
+        cwd,
+        "git log",
+    )
+    commits = []
+    for line in out.splitlines():
+        sha, author, date, subject = (line.split("\x1f") + ["", "", ""])[:4]
+        commits.append(CommitInfo(sha=sha, author=author, date=date, subject=subject))
+    return commits
+
+
+def _match(ref: str, commits: list[CommitInfo]) -> int | None:
+    ref = ref.strip().lower()
+    if len(ref) < _MIN_SHA_PREFIX:
+        return None
+    hits = [i for i, c in enumerate(commits) if c.sha.startswith(ref)]
+    return hits[0] if len(hits) == 1 else None
+
+
+def validate_plan(plan: list[SquashGroup], commits: list[CommitInfo]) -> list[str]:
+    """Check that *plan* covers *commits* exactly once, in order, contiguously."""
+    problems: list[str] = []
+    if not commits:
+        return ["The branch has no commits beyond its base."]
+    if not plan:
+        return ["The plan is empty."]
+
+    position = 0
+    seen: set[int] = set()
+    for number, group in enumerate(plan, start=1):
+        for ref in group.commits:
+            index = _match(ref, commits)
+            if index is None:
+                problems.append(
+                    f"Group {number}: '{ref}' does not identify a branch commit."
+                )
+                continue
+            if index in seen:
+                problems.append(f"Group {number}: {ref} is listed twice.")
+                continue
+            if index != position:
+                expected = (
+                    commits[position].sha[:7] if position < len(commits) else "nothing"
+                )
+                problems.append(
+                    f"Group {number}: {ref} is out of order; expected {expected}."
+                )
+            seen.add(index)
+            position = index + 1
+
+    missing = [c.sha[:7] for i, c in enumerate(commits) if i not in seen]
+    if missing:
+        problems.append(f"Commits not covered by the plan: {', '.join(missing)}.")
+    return problems
+
+
+def _group_span(group: SquashGroup, commits: list[CommitInfo]) -> tuple[int, int]:
+    indexes = [_match(ref, commits) for ref in group.commits]
+    valid = [i for i in indexes if i is not None]
+    return min(valid), max(valid)
+
+
+async def group_diff(
+    group: SquashGroup, commits: list[CommitInfo], cwd: str | None = None
+) -> str:
+    """Combined patch introduced by *group* (for message generation)."""
+    first, last = _group_span(group, commits)
+    return await _git_ok(
+        ["diff", f"{commits[first].sha}^", commits[last].sha], cwd, "git diff"
+    )
+
+
+async def _published(
+    commits: list[CommitInfo], cwd: str | None
+) -> tuple[bool, str | None]:
+    """Whether any commit is already on the upstream, and the upstream ref."""
+    code, upstream, _ = await _run_git(
+        ["rev-parse", "--abbrev-ref", "--symbolic-full-name", "@{upstream}"], cwd=cwd
+    )
+    if code != 0 or not upstream:
+        return False, None
+    for c in commits:
+        code, _, _ = await _run_git(
+            ["merge-base", "--is-ancestor", c.sha, "@{upstream}"], cwd=cwd
+        )
+        if code == 0:
+            return True, upstream
+    return False, upstream
+
+
+async def rewrite_history(
+    plan: list[SquashGroup],
+    base: str | None = None,
+    cwd: str | None = None,
+    allow_force_push: bool = False,
+    dry_run: bool = False,
+) -> RewriteResult:
+    """Replace ``base..HEAD`` with one commit per group of *plan*.
+
+    Every group must have a title.  Each new commit keeps the author and
+    date of the group's first commit.
+
+    Raises:
+        WorkflowError: On an invalid plan, a protected or dirty branch, a
+            bare repository, or published commits without *allow_force_push*.
+    """
+    context = await get_repo_context(cwd)
+    if context.is_bare:
+        raise WorkflowError("Cannot rewrite history in a bare repository.")
+    branch = await _git_ok(
+        ["symbolic-ref", "--short", "HEAD"], cwd, "Resolving the current branch"
+    )
+    if is_protected_branch(branch):
+        raise WorkflowError(f"'{branch}' is protected; history is not rewritten.")
+    status = await get_repo_status(cwd)
+    if status.staged or status.unstaged:
+        raise WorkflowError("Commit or stash local changes before rewriting history.")
+
+    base_sha = await resolve_base(base, cwd)
+    commits = await branch_commits(base_sha, cwd)
+    problems = validate_plan(plan, commits)
+    problems += [
+        f"Group {n}: a title is required."
+        for n, g in enumerate(plan, start=1)
+        if not g.title.strip()
+    ]
+    if problems:
+        listing = "\n".join(f"  {c.sha[:7]} {c.subject}" for c in commits)
+        raise WorkflowError(
+            "Plan rejected:\n- "
+            + "\n- ".join(problems)
+            + f"\nBranch commits:\n{listing}"
+        )
+
+    published, upstream = await _published(commits, cwd)
+    if published and not allow_force_push:
+        raise WorkflowError(
+            f"Some commits are already on {upstream}; pass allow_force_push=True "
+            "to rewrite them and push with --force-with-lease."
+        )
+
+    old_head = commits[-1].sha
+    stamp = datetime.now(timezone.utc).strftime("%Y%m%dT%H%M%S")
+    backup_ref = f"{BACKUP_REF_PREFIX}/{branch}/{stamp}"
+    push_cmd = (
+        ["push", "--force-with-lease", upstream.split("/", 1)[0], branch]
+        if published and upstream
+        else []
+    )
+
+    if dry_run:
+        commands = [format_command(["git", "update-ref", backup_ref, old_head])]
+        for group in plan:
+            _, last = _group_span(group, commits)
+            commands.append(
+                f"git commit-tree {commits[last].sha[:7]}^{{tree}} "
+                f"-m {format_command([group.title])}"
+            )
+        commands.append(
+            format_command(
+                ["git", "update-ref", f"refs/heads/{branch}", "<new-head>", old_head]
+            )
+        )
+        if push_cmd:
+            commands.append(format_command(["git", *push_cmd]))
+        return RewriteResult(
+            branch=branch,
+            old_head=old_head,
+            commits=[g.title for g in plan],
+            commands=commands,
+        )
+
+    await _git_ok(["update-ref", backup_ref, old_head], cwd, "Saving a backup ref")
+    parent = base_sha
+    for group in plan:
+        first, last = _group_span(group, commits)
+        author = await _git_ok(
+            ["show", "-s", "--pretty=format:%an%x1f%ae%x1f%aI", commits[first].sha],
+            cwd,
+            "Reading the author",
+        )
+        name, email, date = author.split("\x1f")
+        message = ["-m", group.title.strip()]
+        if group.body.strip():
+            message += ["-m", group.body.strip()]
+        code, out, err = await _run_git(
+            [
+                "commit-tree",
+                f"{commits[last].sha}^{{tree}}",
+                "-p",
+                parent,
+                *message,
+            ],
+            cwd=cwd,
+            env={
+                "GIT_AUTHOR_NAME": name,
+                "GIT_AUTHOR_EMAIL": email,
+                "GIT_AUTHOR_DATE": date,
+            },
+        )
+        if code != 0:
+            raise WorkflowError(f"git commit-tree failed: {err}")
+        parent = out
+
+    # Compare-and-swap: fails if the branch moved while we were working.
+    await _git_ok(
+        ["update-ref", f"refs/heads/{branch}", parent, old_head],
+        cwd,
+        "Moving the branch",
+    )
+
+    pushed = False
+    if push_cmd:
+        await _git_ok(push_cmd, cwd, "git push --force-with-lease")
+        pushed = True
+
+    return RewriteResult(
+        branch=branch,
+        old_head=old_head,
+        new_head=parent,
+        backup_ref=backup_ref,
+        commits=[g.title for g in plan],
+        pushed=pushed,
+    )
@@ -0,0 +1,147 @@
+"""azathoth.core.runner — how external commands are executed.
+
+Public surface:
+  - ``CommandRunner``               — Protocol: ``await runner(argv, cwd, env)``
+  - ``SubprocessRunner``            — the real runner (asyncio subprocesses)
+  - ``ScriptedRunner(responses)``   — test double answering from a script
+  - ``Response``                    — one scripted ``(code, stdout, stderr)``
+  - ``use_runner(runner)``          → context manager installing *runner*
+  - ``set_default_runner(runner)``  — install *runner* process-wide
+  - ``current_runner()``            → the runner in effect
+
+``core.workflow.run_command`` — and through it every git, forge and cargo
+call the tools make — hands the command to ``current_runner()``.  A
+``use_runner`` block overrides it for the commands run inside it; the
+runner lives in a ``ContextVar`` (as the selected repository does in
+``core.repos``), so concurrent tool calls keep their own.  A server that
+should execute elsewhere (a container, a remote host) installs its runner
+once with ``set_default_runner``.
+
+``ScriptedRunner`` lets tests exercise the logic of ``stage_and_commit`` or
+``create_release`` without git or ``gh``: each command is matched against
+the script by argv prefix, the first match answers, and every command is
+kept in ``calls``.  Unmatched commands go to *fallback* (e.g. the real
+runner, so a test scripts only the forge) or fail with exit code 127.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import os
+from collections.abc import Iterator, Sequence
+from contextlib import contextmanager
+from contextvars import ContextVar
+from dataclasses import dataclass
+from typing import Protocol, runtime_checkable
+
+from azathoth.core.host import resolve_argv
+from azathoth.core.progress import collect
+
+
+@runtime_checkable
+class CommandRunner(Protocol):
+    async def __call__(
+        self, argv: list[str], cwd: str | None, env: dict[str, str] | None
+    ) -> tuple[int, str, str]:
+        """Run *argv* in *cwd*; *env* extends os.environ.
+
+        Returns ``(code, stdout, stderr)`` with the output stripped.  A
+        missing executable is exit code 127, never an exception.
+        """
+        ...  # pragma: no cover
+
+
+class SubprocessRunner:
+    """Runs commands as local processes, streaming output to ``core.progress``."""
+
+    async def __call__(
+        self, argv: list[str], cwd: str | None, env: dict[str, str] | None
+    ) -> tuple[int, str, str]:
+        try:
+            process = await asyncio.create_subprocess_exec(
+                *resolve_argv(argv),
+                stdout=asyncio.subprocess.PIPE,
+                stderr=asyncio.subprocess.PIPE,
+                cwd=cwd,
+                env={**os.environ, **env} if env else None,
+            )
+        except FileNotFoundError as exc:
+            return 127, "", str(exc)
+        stdout, stderr = await collect(process)
+        assert process.returncode is not None
+        return process.returncode, stdout.decode().strip(), stderr.decode().strip()
+
+
+@dataclass(frozen=True)
+class Response:
+    """Scripted answer to every command whose argv starts with *prefix*."""
+
+    prefix: Sequence[str]
+    code: int = 0
+    stdout: str = ""
+    stderr: str = ""
+    #: Answer only this many times (``None``: always).
+    times: int | None = None
+
+
+class ScriptedRunner:
+    """Answers commands from *responses* instead of running them."""
+
+    def __init__(
+        self, responses: Sequence[Response] = (), fallback: CommandRunner | None = None
+    ):
+        self.responses = list(responses)
+        self.fallback = fallback
+        #: Every command received, in order, with its working directory.
+        self.calls: list[tuple[list[str], str | None]] = []
+        self._used: dict[int, int] = {}
+
+    def commands(self, *prefix: str) -> list[list[str]]:
+        """The received argvs starting with *prefix* (all if none given)."""
+        return [argv for argv, _ in self.calls if argv[: len(prefix)] == list(prefix)]
+
+    def _match(self, argv: list[str]) -> Response | None:
+        for index, response in enumerate(self.responses):
+            if argv[: len(response.prefix)] != list(response.prefix):
+                continue
+            used = self._used.get(index, 0)
+            if response.times is not None and used >= response.times:
+                continue
+            self._used[index] = used + 1
+            return response
+        return None
+
+    async def __call__(
+        self, argv: list[str], cwd: str | None, env: dict[str, str] | None
+    ) -> tuple[int, str, str]:
+        self.calls.append((list(argv), cwd))
+        if (response := self._match(argv)) is not None:
+            return response.code, response.stdout, response.stderr
+        if self.fallback is not None:
+            return await self.fallback(argv, cwd, env)
+        return 127, "", f"No scripted response for: {' '.join(argv)}"
+
+
+_default: CommandRunner = SubprocessRunner()
+_runner: ContextVar[CommandRunner | None] = ContextVar("command_runner", default=None)
+
+
+def current_runner() -> CommandRunner:
+    """The runner of the enclosing ``use_runner`` block, else the default."""
+    return _runner.get() or _default
+
+
+def set_default_runner(runner: CommandRunner) -> None:
+    """Run every command outside a ``use_runner`` block through *runner*."""
+    global _default
+    _default = runner
+
+
+@contextmanager
+def use_runner(runner: CommandRunner) -> Iterator[CommandRunner]:
+    """Run the block's commands through *runner*."""
+    token = _runner.set(runner)
+    try:
+        yield runner
+    finally:
+        _runner.reset(token)
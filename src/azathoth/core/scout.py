@@ -2,6 +2,7 @@ from pathlib import Path
 from typing import List, Optional
 from pydantic import BaseModel
 from azathoth.core.ingest import ingest, IngestionResult
+from azathoth.core.detect import detect_stack
 from azathoth.core.directives import get_master_context
 
 
@@ -23,21 +24,9 @@ async def scout(target_directory: str = ".") -> ScoutReport:
     # 1. Reconnaissance
     result = await ingest(str(root), list_only=True)
 
-    # 2. Identify Language (heuristic based on manifest files)
-    language = "unknown"
-    manifests = {
-        "pyproject.toml": "python",
-        "package.json": "typescript",  # or javascript
-        "Cargo.toml": "rust",
-        "go.mod": "go",
-        "build.gradle": "kotlin",
-        "pom.xml": "java",
-    }
-
-    for manifest, lang in manifests.items():
-        if (root / manifest).exists():
-            language = lang
-            break
+    # 2. Identify Language (from manifests, see core/detect.py)
+    detected = detect_stack(root).languages
+    language = detected[0] if detected else "unknown"
 
     # 3. Load Directives
     master_context = await get_master_context([language])
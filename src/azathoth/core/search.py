@@ -0,0 +1,132 @@
+"""azathoth.core.search — ripgrep-style full-text search of a project.
+
+Public surface:
+  - ``search(sandbox, pattern, …)``  → ``SearchResult``
+
+The files searched are the ones ``iter_files`` walks (so dependency, build
+and VCS directories and whatever the ignore rules exclude are skipped),
+minus the sandbox's denied paths, links leading out of it, binary files
+and files over 1 MB.
+*pattern* is a Python regular expression unless ``fixed_strings`` is set;
+it is matched line by line, like ``grep``.  *glob* narrows the files: a
+pattern without ``/`` matches the file name (``*.py``), one with ``/`` the
+path from the root (``src/*/models.py``, where ``*`` may cross ``/``).
+Each match carries up to *context* lines before and after it.  The result
+stops at *max_results* matches with ``truncated`` set; lines are cut at 300
+characters so minified files stay readable.
+"""
+
+from __future__ import annotations
+
+import re
+from fnmatch import fnmatchcase
+
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.files import Sandbox, is_binary
+from azathoth.core.traverse import iter_files
+
+_MAX_BYTES = 1_000_000
+_MAX_LINE = 300
+
+
+class SearchMatch(BaseModel, frozen=True):
+    path: str
+    line: int = Field(description="1-based line number")
+    text: str
+    before: list[str] = Field(default_factory=list)
+    after: list[str] = Field(default_factory=list)
+
+
+class SearchResult(BaseModel, frozen=True):
+    pattern: str
+    matches: list[SearchMatch] = Field(default_factory=list)
+    files_searched: int = 0
+    files_matched: int = 0
+    truncated: bool = Field(False, description="Stopped at max_results")
+
+
+def _clip(line: str) -> str:
+    line = line.rstrip("\r\n")
+    return line if len(line) <= _MAX_LINE else f"{line[:_MAX_LINE]}…"
+
+
+def _wanted(rel: str, glob: str | None) -> bool:
+    if glob is None:
+        return True
+    return fnmatchcase(rel if "/" in glob else rel.rsplit("/", 1)[-1], glob)
+
+
+def search(
+    sandbox: Sandbox,
+    pattern: str,
+    *,
+    fixed_strings: bool = False,
+    ignore_case: bool = False,
+    glob: str | None = None,
+    context: int = 2,
+    max_results: int | None = None,
+) -> SearchResult:
+    """Lines matching *pattern* in the files of *sandbox*, in path order.
+
+    Raises:
+        WorkflowError: If *pattern* is not a valid regular expression.
+    """
+    source = re.escape(pattern) if fixed_strings else pattern
+    try:
+        regex = re.compile(source, re.IGNORECASE if ignore_case else 0)
+    except re.error as exc:
+        raise WorkflowError(f"Invalid regular expression '{pattern}': {exc}.") from exc
+    limit = max_results or get_config().scout_max_search_results
+    context = max(context, 0)
+
+    matches: list[SearchMatch] = []
+    searched = matched = 0
+    for path in iter_files(sandbox.root):
+        rel = sandbox.rel(path)
+        resolved = path.resolve()  # a symlink may point out of the sandbox
+        if not _wanted(rel, glob) or not resolved.is_relative_to(sandbox.root):
+            continue
+        if sandbox.denied_by(resolved) is not None:
+            continue
+        try:
+            if path.stat().st_size > _MAX_BYTES:
+                continue
+            data = path.read_bytes()
+        except OSError:
+            continue
+        if is_binary(data[:8192]):
+            continue
+        searched += 1
+        lines = data.decode("utf-8", errors="replace").splitlines()
+        hit = False
+        for index, line in enumerate(lines):
+            if not regex.search(line):
+                continue
+            if len(matches) >= limit:
+                return SearchResult(
+                    pattern=pattern,
+                    matches=matches,
+                    files_searched=searched,
+                    files_matched=matched,
+                    truncated=True,
+                )
+            if not hit:
+                matched, hit = matched + 1, True
+            matches.append(
+                SearchMatch(
+                    path=rel,
+                    line=index + 1,
+                    text=_clip(line),
+                    before=[_clip(x) for x in lines[max(index - context, 0) : index]],
+                    after=[_clip(x) for x in lines[index + 1 : index + 1 + context]],
+                )
+            )
+    return SearchResult(
+        pattern=pattern,
+        matches=matches,
+        files_searched=searched,
+        files_matched=matched,
+    )
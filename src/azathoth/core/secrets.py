@@ -0,0 +1,232 @@
+"""azathoth.core.secrets — credentials about to be committed.
+
+Public surface:
+  - ``scan_text(path, text)``   → ``[SecretFinding]`` for one file's lines
+  - ``scan_diff(diff)``         → ``[SecretFinding]`` in a patch's added lines
+  - ``scan_staged(root)``       → ``SecretReport`` for ``git diff --cached``
+  - ``scan_tree(root)``         → ``SecretReport`` for every non-ignored file
+
+Findings are AWS access key ids and secret keys, private key blocks
+(``-----BEGIN … PRIVATE KEY-----``), ``.env`` files (templates such as
+``.env.example`` excepted) and high-entropy strings: base64-like tokens of
+20+ characters mixing upper case, lower case and digits, with a Shannon
+entropy of at least 4.5 bits per char (less for short tokens, which cannot
+reach it).  Pure hex (commit shas, content hashes), URLs and lockfiles are
+ignored.  Excerpts are redacted to their first four characters, so a
+report never repeats the secret it found.
+"""
+
+from __future__ import annotations
+
+import math
+import posixpath
+import re
+from collections import Counter
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.files import is_binary
+from azathoth.core.markers import _candidate_files
+from azathoth.core.workflow import run_command
+
+SecretKind = Literal[
+    "aws_access_key", "aws_secret_key", "private_key", "env_file", "high_entropy"
+]
+
+_AWS_ACCESS_KEY = re.compile(r"\b((?:AKIA|ASIA|AGPA|AIDA|AROA)[0-9A-Z]{16})\b")
+_AWS_SECRET_KEY = re.compile(
+    r"aws_?secret_?(?:access_?)?key\W{0,4}([A-Za-z0-9/+=]{40})\b", re.I
+)
+_PRIVATE_KEY = re.compile(r"-----BEGIN ((?:[A-Z]+ )*)PRIVATE KEY(?: BLOCK)?-----")
+_TOKEN = re.compile(r"[A-Za-z0-9+/_=-]{20,}")
+_HEX = re.compile(r"[0-9a-fA-F]+")
+_URL = re.compile(r"\w+://\S+")
+_ENTROPY_THRESHOLD = 4.5
+# A string of n characters has at most log2(n) bits per character, so short
+# tokens are held to a share of that instead of the fixed threshold.
+_ENTROPY_SHARE = 0.85
+_ENV_TEMPLATES = (".example", ".sample", ".template", ".dist", ".defaults")
+_LOCKFILES = frozenset(
+    {
+        "Cargo.lock",
+        "package-lock.json",
+        "yarn.lock",
+        "pnpm-lock.yaml",
+        "bun.lock",
+        "uv.lock",
+        "poetry.lock",
+        "Pipfile.lock",
+        "go.sum",
+        "composer.lock",
+        "Gemfile.lock",
+    }
+)
+_MAX_BYTES = 1_000_000
+
+
+class SecretFinding(BaseModel, frozen=True):
+    path: str
+    line: int = Field(description="1-based; 0 for file-level findings")
+    kind: SecretKind
+    excerpt: str = Field(description="Redacted match")
+
+
+class SecretReport(BaseModel, frozen=True):
+    scope: Literal["staged", "tree"]
+    files_scanned: int
+    findings: list[SecretFinding]
+
+    @property
+    def clean(self) -> bool:
+        return not self.findings
+
+    def render(self) -> str:
+        if self.clean:
+            return f"No secrets found ({self.files_scanned} files scanned)."
+        lines = [f"{len(self.findings)} possible secret(s):"]
+        lines += [
+            f"  {f.path}{f':{f.line}' if f.line else ''}  {f.kind}  {f.excerpt}"
+            for f in self.findings
+        ]
+        return "\n".join(lines)
+
+
+def _entropy(token: str) -> float:
+    """Shannon entropy of *token* in bits per character."""
+    counts = Counter(token)
+    return -sum(n / len(token) * math.log2(n / len(token)) for n in counts.values())
+
+
+def _random_looking(token: str) -> bool:
+    classes = (str.isdigit, str.islower, str.isupper)
+    if not all(any(test(c) for c in token) for test in classes):
+        return False
+    threshold = min(_ENTROPY_THRESHOLD, _ENTROPY_SHARE * math.log2(len(token)))
+    return _entropy(token) >= threshold
+
+
+def _redact(secret: str) -> str:
+    return f"{secret[:4]}…({len(secret)} chars)"
+
+
+def _is_env_file(path: str) -> bool:
+    name = posixpath.basename(path)
+    if name != ".env" and not name.startswith(".env.") and not name.endswith(".env"):
+        return False
+    return not name.endswith(_ENV_TEMPLATES)
+
+
+def _skipped(path: str) -> bool:
+    return posixpath.basename(path) in _LOCKFILES
+
+
+def _line_findings(path: str, number: int, line: str) -> list[SecretFinding]:
+    found: list[SecretFinding] = []
+    claimed: list[str] = []
+    for kind, pattern in (
+        ("aws_access_key", _AWS_ACCESS_KEY),
+        ("aws_secret_key", _AWS_SECRET_KEY),
+    ):
+        for match in pattern.finditer(line):
+            claimed.append(match.group(1))
+            found.append(
+                SecretFinding(
+                    path=path, line=number, kind=kind, excerpt=_redact(match.group(1))
+                )
+            )
+    if key := _PRIVATE_KEY.search(line):
+        found.append(
+            SecretFinding(path=path, line=number, kind="private_key", excerpt=key[0])
+        )
+    for token in _TOKEN.findall(_URL.sub(" ", line)):
+        if any(token in c or c in token for c in claimed) or _HEX.fullmatch(token):
+            continue
+        if _random_looking(token):
+            found.append(
+                SecretFinding(
+                    path=path, line=number, kind="high_entropy", excerpt=_redact(token)
+                )
+            )
+    return found
+
+
+def scan_text(path: str, text: str) -> list[SecretFinding]:
+    """Findings in the contents of the file at (repo-relative) *path*."""
+    found: list[SecretFinding] = []
+    if _is_env_file(path):
+        found.append(SecretFinding(path=path, line=0, kind="env_file", excerpt=path))
+    if _skipped(path):
+        return found
+    for number, line in enumerate(text.splitlines(), start=1):
+        found += _line_findings(path, number, line)
+    return found
+
+
+_HUNK = re.compile(r"^@@ -\d+(?:,\d+)? \+(\d+)(?:,\d+)? @@")
+
+
+def scan_diff(diff: str) -> list[SecretFinding]:
+    """Findings in the lines *diff* adds, numbered as in the new file."""
+    found: list[SecretFinding] = []
+    path, number, in_hunk = "", 0, False
+    for line in diff.splitlines():
+        if line.startswith("diff --git "):
+            path, in_hunk = "", False
+        elif not in_hunk and line.startswith("+++ "):
+            target = line[4:].strip()
+            path = "" if target == "/dev/null" else target.removeprefix("b/")
+            if path and _is_env_file(path):
+                found.append(
+                    SecretFinding(path=path, line=0, kind="env_file", excerpt=path)
+                )
+        elif hunk := _HUNK.match(line):
+            number, in_hunk = int(hunk.group(1)), True
+        elif not in_hunk or not path or _skipped(path):
+            continue
+        elif line.startswith("+"):
+            found += _line_findings(path, number, line[1:])
+            number += 1
+        elif not line.startswith(("-", "\\")):
+            number += 1
+    return found
+
+
+async def scan_staged(root: Path) -> SecretReport:
+    """Scan what ``git commit`` would record in the repository at *root*.
+
+    Raises:
+        WorkflowError: If *root* is not inside a git work tree.
+    """
+    code, diff, err = await run_command(
+        ["git", "diff", "--cached", "--no-color", "--no-ext-diff"], cwd=str(root)
+    )
+    if code != 0:
+        raise WorkflowError(f"Cannot read staged changes: {err or diff}")
+    _, names, _ = await run_command(
+        ["git", "diff", "--cached", "--name-only"], cwd=str(root)
+    )
+    return SecretReport(
+        scope="staged",
+        files_scanned=len(names.splitlines()),
+        findings=scan_diff(diff),
+    )
+
+
+async def scan_tree(root: Path) -> SecretReport:
+    """Scan every file under *root* that ``.gitignore`` does not exclude."""
+    files, _ = await _candidate_files(root)
+    findings: list[SecretFinding] = []
+    for path in files:
+        try:
+            if (root / path).stat().st_size > _MAX_BYTES:
+                continue
+            data = (root / path).read_bytes()
+        except OSError:
+            continue
+        if is_binary(data[:8192]):
+            continue
+        findings += scan_text(path, data.decode("utf-8", errors="replace"))
+    return SecretReport(scope="tree", files_scanned=len(files), findings=findings)
@@ -0,0 +1,179 @@
+"""azathoth.core.semantic — natural-language search over a project's code.
+
+Public surface:
+  - ``chunk(text)``                              → ``[(start, end, text)]``
+  - ``semantic_search(sandbox, query, embedder, …)`` → ``SemanticResult``
+
+Source files (the ``repo_stats`` languages, minus JSON) are cut into windows
+of ``_CHUNK_LINES`` lines overlapping by ``_OVERLAP``, and every window is
+embedded once: the vectors are kept in the scout index under the embedder's
+model, keyed by file content, so later queries only embed the query and the
+files that changed.  Chunks are ranked by cosine similarity to the query.
+The files considered are the ones ``search`` reads — the sandbox's, minus
+ignored and denied paths, links leading out of it, binary files and files
+over 1 MB — narrowed by *glob* the same way.
+"""
+
+from __future__ import annotations
+
+import math
+from pathlib import Path
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.embeddings import Embedder
+from azathoth.core.files import Sandbox, is_binary
+from azathoth.core.index import IndexedFile, RepoIndex
+from azathoth.core.repo_stats import LANGUAGE_SUFFIXES
+from azathoth.core.search import _wanted
+from azathoth.core.traverse import iter_files
+
+_CHUNK_LINES = 40
+_OVERLAP = 10
+_BATCH = 32
+_MAX_BYTES = 1_000_000
+_MAX_SNIPPET = 2_000
+
+_SUFFIXES = frozenset(s for s, lang in LANGUAGE_SUFFIXES.items() if lang != "JSON")
+
+
+class _Chunk(BaseModel, frozen=True):
+    start_line: int
+    end_line: int
+    vector: list[float]
+
+
+class _FileEmbeddings(BaseModel, frozen=True):
+    chunks: list[_Chunk] = Field(default_factory=list)
+
+
+class SemanticMatch(BaseModel, frozen=True):
+    path: str
+    start_line: int = Field(description="1-based, inclusive")
+    end_line: int
+    score: float = Field(description="Cosine similarity to the query")
+    snippet: str
+
+
+class SemanticResult(BaseModel, frozen=True):
+    query: str
+    model: str = Field(description="Embedding model that ranked the chunks")
+    matches: list[SemanticMatch] = Field(default_factory=list)
+    files_indexed: int = 0
+    chunks_embedded: int = Field(0, description="Embedded by this call")
+
+
+def chunk(text: str) -> list[tuple[int, int, str]]:
+    """Overlapping line windows of *text* as ``(start, end, text)``, 1-based."""
+    lines = text.splitlines()
+    step = _CHUNK_LINES - _OVERLAP
+    windows = []
+    for start in range(0, max(len(lines) - _OVERLAP, 1), step):
+        window = lines[start : start + _CHUNK_LINES]
+        if any(line.strip() for line in window):
+            windows.append((start + 1, start + len(window), "\n".join(window)))
+    return windows
+
+
+def _cosine(a: list[float], b: list[float]) -> float:
+    norm = math.sqrt(sum(x * x for x in a)) * math.sqrt(sum(y * y for y in b))
+    return sum(x * y for x, y in zip(a, b)) / norm if norm else 0.0
+
+
+def _text(path: Path) -> str | None:
+    try:
+        if path.stat().st_size > _MAX_BYTES:
+            return None
+        data = path.read_bytes()
+    except OSError:
+        return None
+    return None if is_binary(data[:8192]) else data.decode("utf-8", errors="replace")
+
+
+async def _embed_all(embedder: Embedder, texts: list[str]) -> list[list[float]]:
+    vectors: list[list[float]] = []
+    for start in range(0, len(texts), _BATCH):
+        vectors.extend(await embedder.embed(texts[start : start + _BATCH]))
+    return vectors
+
+
+async def semantic_search(
+    sandbox: Sandbox,
+    query: str,
+    embedder: Embedder,
+    *,
+    limit: int = 10,
+    glob: str | None = None,
+) -> SemanticResult:
+    """The *limit* chunks of *sandbox*'s source files closest to *query*.
+
+    Raises:
+        ProviderError: The embedding service failed (see core/embeddings.py).
+    """
+    index = RepoIndex.open(sandbox.root)
+    kind = "embeddings-" + "".join(
+        c if c.isalnum() or c in "-." else "_" for c in embedder.model
+    )
+
+    files: dict[str, tuple[Path, _FileEmbeddings]] = {}
+    pending: list[tuple[str, IndexedFile, list[tuple[int, int, str]]]] = []
+    for path in iter_files(sandbox.root):
+        rel = sandbox.rel(path)
+        resolved = path.resolve()  # a symlink may point out of the sandbox
+        if path.suffix.lower() not in _SUFFIXES or not _wanted(rel, glob):
+            continue
+        if not resolved.is_relative_to(sandbox.root):
+            continue
+        if sandbox.denied_by(resolved) is not None:
+            continue
+        entry, stored = index.lookup(kind, path, _FileEmbeddings)
+        if entry is None:
+            continue
+        if stored is not None:
+            files[rel] = (path, stored)
+        elif (text := _text(path)) is not None:
+            pending.append((rel, entry, chunk(text)))
+
+    texts = [f"{rel}\n{body}" for rel, _, chunks in pending for *_, body in chunks]
+    vectors = iter(await _embed_all(embedder, texts)) if texts else iter(())
+    for rel, entry, chunks in pending:
+        facts = _FileEmbeddings(
+            chunks=[
+                _Chunk(start_line=start, end_line=end, vector=next(vectors))
+                for start, end, _ in chunks
+            ]
+        )
+        index.store(kind, entry, facts)
+        files[rel] = (sandbox.root / rel, facts)
+    index.save()
+
+    (target,) = await embedder.embed([query])
+    ranked = sorted(
+        (
+            (_cosine(target, c.vector), rel, c)
+            for rel, (_, facts) in files.items()
+            for c in facts.chunks
+        ),
+        key=lambda hit: (-hit[0], hit[1], hit[2].start_line),
+    )[: max(limit, 0)]
+
+    matches = []
+    for score, rel, c in ranked:
+        lines = (_text(files[rel][0]) or "").splitlines()
+        snippet = "\n".join(lines[c.start_line - 1 : c.end_line])
+        matches.append(
+            SemanticMatch(
+                path=rel,
+                start_line=c.start_line,
+                end_line=c.end_line,
+                score=round(score, 4),
+                snippet=snippet[:_MAX_SNIPPET],
+            )
+        )
+    return SemanticResult(
+        query=query,
+        model=embedder.model,
+        matches=matches,
+        files_indexed=len(files),
+        chunks_embedded=len(texts),
+    )
@@ -0,0 +1,196 @@
+"""azathoth.core.session — undo log of what the workflow tools changed.
+
+Public surface:
+  - ``Operation``                     — one recorded change
+  - ``SessionState``                  — the log: operations, oldest first
+  - ``record(operation, cwd)``        — append to the repository's log
+  - ``record_head_commit(tool, cwd)`` — record the commit HEAD points at
+  - ``session_state(cwd)``            → ``SessionState``
+  - ``undo_last(dry_run, cwd)``       → ``GitResult``
+
+Mutating tools record what they created — a commit, a branch, a tag (and
+the remote it was pushed to), a release — so an agent that got one wrong can take it
+back with ``undo_last`` instead of improvising a ``git reset``.  Undo
+pops the newest operation not yet undone:
+
+  - commit → ``git reset --soft`` to its parent; the changes stay staged.
+    Refused once HEAD has moved past it or the commit is on a remote.
+  - branch → switch back to the branch it was created from (if it is
+    checked out), then ``git branch -d``, which keeps unmerged work.
+  - tag → ``git tag -d``, plus deleting it on the remote it was pushed to.
+  - release → refused: the release lives on the forge, and deleting only
+    its tag would leave it orphaned.  Delete the release there first.
+
+The log lives in the repository's git directory (``azathoth/session.json``,
+per worktree), so it survives server restarts; only the newest
+``MAX_OPERATIONS`` are kept.
+"""
+
+from __future__ import annotations
+
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field, ValidationError
+
+from azathoth.core.workflow import GitResult, _run_git, get_repo_context, planned
+
+OperationKind = Literal["commit", "branch", "tag", "release"]
+
+MAX_OPERATIONS = 100
+_SESSION_FILE = Path("azathoth") / "session.json"
+
+
+def _now() -> datetime:
+    return datetime.now(timezone.utc)
+
+
+class Operation(BaseModel, frozen=True):
+    kind: OperationKind
+    tool: str = Field(description="Tool that made the change")
+    ref: str = Field(description="Commit sha, branch or tag name")
+    previous: str | None = Field(
+        None, description="Commit: its parent; branch: the branch it left"
+    )
+    remote: str | None = Field(
+        None, description="Tag, release: remote the tag was pushed to"
+    )
+    at: datetime = Field(default_factory=_now)
+    undone: bool = False
+
+    def describe(self) -> str:
+        if self.kind == "commit":
+            return f"commit {self.ref[:12]}"
+        pushed = f" (pushed to {self.remote})" if self.remote else ""
+        return f"{self.kind} {self.ref}{pushed}"
+
+
+class SessionState(BaseModel):
+    """The undo log.  Mutable: ``operations`` grows as tools record changes."""
+
+    started_at: datetime = Field(default_factory=_now)
+    operations: list[Operation] = Field(default_factory=list)
+
+    def last(self) -> Operation | None:
+        """The newest operation not yet undone."""
+        return next((op for op in reversed(self.operations) if not op.undone), None)
+
+
+async def _session_path(cwd: str | None) -> Path:
+    return Path((await get_repo_context(cwd)).git_dir) / _SESSION_FILE
+
+
+async def session_state(cwd: str | None = None) -> SessionState:
+    """The repository's undo log; empty when nothing was recorded (or it is
+    unreadable)."""
+    path = await _session_path(cwd)
+    try:
+        return SessionState.model_validate_json(path.read_text(encoding="utf-8"))
+    except (OSError, ValidationError):
+        return SessionState()
+
+
+async def _save(state: SessionState, cwd: str | None) -> None:
+    path = await _session_path(cwd)
+    state.operations = state.operations[-MAX_OPERATIONS:]
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_text(state.model_dump_json(indent=2), encoding="utf-8")
+
+
+async def record(operation: Operation, cwd: str | None = None) -> None:
+    """Append *operation* to the repository's undo log."""
+    state = await session_state(cwd)
+    state.operations.append(operation)
+    await _save(state, cwd)
+
+
+async def _git_out(args: list[str], cwd: str | None) -> str:
+    code, out, _ = await _run_git(args, cwd=cwd)
+    return out.strip() if code == 0 else ""
+
+
+async def record_head_commit(tool: str, cwd: str | None = None) -> None:
+    """Record the commit *tool* just made, i.e. ``HEAD``, with its parent."""
+    sha = await _git_out(["rev-parse", "HEAD"], cwd)
+    parent = await _git_out(["rev-parse", "--verify", "-q", "HEAD^"], cwd)
+    operation = Operation(kind="commit", tool=tool, ref=sha, previous=parent or None)
+    await record(operation, cwd)
+
+
+def _fail(message: str) -> GitResult:
+    return GitResult(success=False, stdout="", stderr=message, message=message)
+
+
+async def _undo_commands(
+    op: Operation, cwd: str | None
+) -> list[list[str]] | GitResult:
+    if op.kind == "commit":
+        if await _git_out(["rev-parse", "HEAD"], cwd) != op.ref:
+            return _fail(f"HEAD has moved past {op.describe()}; nothing was undone.")
+        if op.previous is None:
+            return _fail(f"{op.describe()} is the root commit and cannot be undone.")
+        if await _git_out(["branch", "-r", "--contains", op.ref], cwd):
+            return _fail(f"{op.describe()} is already on a remote; revert it instead.")
+        return [["reset", "--soft", op.previous]]
+    if op.kind == "branch":
+        current = await _git_out(["branch", "--show-current"], cwd)
+        commands = []
+        if current == op.ref:
+            if not op.previous:
+                return _fail(f"Branch '{op.ref}' is checked out; switch away first.")
+            merged, _, _ = await _run_git(
+                ["merge-base", "--is-ancestor", op.ref, op.previous], cwd=cwd
+            )
+            if merged != 0:
+                return _fail(
+                    f"Branch '{op.ref}' has commits that are not on "
+                    f"'{op.previous}'; nothing was undone."
+                )
+            commands.append(["switch", op.previous])
+        return [*commands, ["branch", "-d", op.ref]]
+    if op.kind == "release":
+        return _fail(
+            f"{op.describe()} is published on the forge; delete the release "
+            f"there first, then the tag with `git tag -d {op.ref}` and "
+            f"`git push {op.remote or 'origin'} --delete refs/tags/{op.ref}`."
+        )
+    commands = [["tag", "-d", op.ref]]
+    if op.remote:
+        commands.append(["push", op.remote, "--delete", f"refs/tags/{op.ref}"])
+    return commands
+
+
+async def undo_last(dry_run: bool = False, cwd: str | None = None) -> GitResult:
+    """Revert the newest recorded operation that is not undone yet.
+
+    Failures (nothing to undo, HEAD moved on, a commit already pushed) are
+    returned as an unsuccessful ``GitResult`` and leave the log unchanged.
+    """
+    state = await session_state(cwd)
+    op = state.last()
+    if op is None:
+        return _fail("Nothing recorded to undo.")
+    commands = await _undo_commands(op, cwd)
+    if isinstance(commands, GitResult):
+        return commands
+    if dry_run:
+        return planned(*(["git", *args] for args in commands))
+
+    outputs = []
+    for args in commands:
+        code, out, err = await _run_git(args, cwd=cwd)
+        if code != 0:
+            return GitResult(
+                success=False, stdout="\n".join([*outputs, out]), stderr=err
+            )
+        outputs.append(out)
+    index = state.operations.index(op)
+    state.operations[index] = op.model_copy(update={"undone": True})
+    await _save(state, cwd)
+    return GitResult(
+        success=True,
+        stdout="\n".join(filter(None, outputs)),
+        stderr="",
+        message=f"Undid {op.describe()} ({op.tool}).",
+    )
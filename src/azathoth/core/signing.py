@@ -0,0 +1,182 @@
+"""azathoth.core.signing — commit signing with GPG, SSH or X.509 keys.
+
+Public surface:
+  - ``signing_setup(cwd)``                  → ``SigningSetup``
+  - ``classify_signing_failure(stderr)``    → ``SigningFailure`` or ``None``
+  - ``signature_status(rev, cwd)``          → ``SignatureStatus``
+
+Git signs on its own when ``commit.gpgsign`` is set; ``stage_and_commit``
+adds ``-S`` when asked to sign explicitly.  Either way a signing failure
+surfaces from git as a bare "gpg failed to sign the data", so the signing
+program's stderr is matched to a reason the agent can act on: no key, an
+expired or revoked key, an agent that is not running, or a passphrase
+prompt nobody can answer.  Whether a commit is signed is read from its
+``gpgsig`` header, since verifying an SSH signature additionally needs
+``gpg.ssh.allowedSignersFile``.
+"""
+
+from __future__ import annotations
+
+import re
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.workflow import _run_git
+
+SignatureFormat = Literal["openpgp", "ssh", "x509"]
+FailureReason = Literal[
+    "missing_key",
+    "expired_key",
+    "agent_unavailable",
+    "passphrase_required",
+    "program_missing",
+    "unknown",
+]
+
+# Checked in order; the first pattern found in stderr names the reason.
+_FAILURES: list[tuple[FailureReason, re.Pattern[str]]] = [
+    (
+        "program_missing",
+        re.compile(r"cannot run \S+: No such file|ssh-keygen: not found", re.I),
+    ),
+    (
+        "agent_unavailable",
+        re.compile(
+            r"can't connect to the agent|no agent running|gpg-agent is not available"
+            r"|could not open a connection to your authentication agent"
+            r"|error connecting to agent|agent refused operation",
+            re.I,
+        ),
+    ),
+    ("expired_key", re.compile(r"expired|revoked|unusable secret key", re.I)),
+    (
+        "missing_key",
+        re.compile(
+            r"no secret key|secret key not available|no default secret key"
+            r"|couldn't load public key|no private key found"
+            r"|user\.signingkey needs to be set",
+            re.I,
+        ),
+    ),
+    (
+        "passphrase_required",
+        re.compile(r"inappropriate ioctl|no pinentry|bad passphrase", re.I),
+    ),
+    (
+        "unknown",
+        re.compile(r"failed to sign|signing failed|failed to write commit", re.I),
+    ),
+]
+
+_HINTS: dict[FailureReason, str] = {
+    "missing_key": "Set user.signingkey to a key you have (gpg --list-secret-keys, "
+    "or an SSH public key file with gpg.format=ssh).",
+    "expired_key": "Extend the key's expiry or set user.signingkey to a valid key.",
+    "agent_unavailable": "Start the agent (gpg-agent or ssh-agent) and load the key.",
+    "passphrase_required": "The key needs a passphrase and no prompt is available; "
+    "unlock it in the agent first (e.g. gpg-connect-agent or ssh-add).",
+    "program_missing": "Install the signing program or point gpg.program / "
+    "gpg.ssh.program at it.",
+    "unknown": "Run `git commit -S` in a terminal to see the signing program's error.",
+}
+
+# ``%G?`` codes (git log) → verification status.
+_VERIFICATION = {
+    "G": "good",
+    "U": "good (unknown validity)",
+    "X": "good (signature expired)",
+    "Y": "good (key expired)",
+    "R": "good (key revoked)",
+    "B": "bad",
+    "E": "unverified (cannot check)",
+    "N": "unverified",
+}
+
+
+class SigningSetup(BaseModel, frozen=True):
+    """Signing configuration git will apply to a commit."""
+
+    auto: bool = Field(description="commit.gpgsign is set")
+    format: SignatureFormat = "openpgp"
+    key: str | None = Field(None, description="user.signingkey")
+
+
+class SigningFailure(BaseModel, frozen=True):
+    reason: FailureReason
+    detail: str = Field(description="The signing program's error output")
+
+    @property
+    def hint(self) -> str:
+        return _HINTS[self.reason]
+
+    def render(self) -> str:
+        return f"Signing failed ({self.reason}): {self.hint}\n{self.detail}".rstrip()
+
+
+class SignatureStatus(BaseModel, frozen=True):
+    signed: bool
+    format: SignatureFormat | None = None
+    verification: str = Field("", description="good, bad, unverified, …")
+    signer: str = ""
+    key: str = ""
+
+    def describe(self) -> str:
+        if not self.signed:
+            return "unsigned"
+        who = f" by {self.signer}" if self.signer else ""
+        key = f", key {self.key}" if self.key else ""
+        return f"signed ({self.format}{key}){who}, {self.verification}"
+
+
+async def _config(key: str, cwd: str | None) -> str | None:
+    code, out, _ = await _run_git(["config", "--get", key], cwd=cwd)
+    return out if code == 0 and out else None
+
+
+async def signing_setup(cwd: str | None = None) -> SigningSetup:
+    code, out, _ = await _run_git(
+        ["config", "--type=bool", "--get", "commit.gpgsign"], cwd=cwd
+    )
+    fmt = await _config("gpg.format", cwd)
+    return SigningSetup(
+        auto=code == 0 and out == "true",
+        format=fmt if fmt in ("ssh", "x509") else "openpgp",
+        key=await _config("user.signingkey", cwd),
+    )
+
+
+def classify_signing_failure(stderr: str) -> SigningFailure | None:
+    """Why signing failed, or ``None`` if *stderr* is not a signing failure."""
+    for reason, pattern in _FAILURES:
+        if pattern.search(stderr):
+            return SigningFailure(reason=reason, detail=stderr.strip())
+    return None
+
+
+async def signature_status(
+    rev: str = "HEAD", cwd: str | None = None
+) -> SignatureStatus:
+    """Whether *rev* is signed, with what, and whether git can verify it."""
+    code, out, _ = await _run_git(["cat-file", "commit", rev], cwd=cwd)
+    header = out.split("\n\n", 1)[0] if code == 0 else ""
+    match = re.search(r"^gpgsig(?:-sha256)? (.*)$", header, re.M)
+    if not match:
+        return SignatureStatus(signed=False)
+    armor = match.group(1)
+    fmt: SignatureFormat = "openpgp"
+    if "SSH SIGNATURE" in armor:
+        fmt = "ssh"
+    elif "SIGNED MESSAGE" in armor:
+        fmt = "x509"
+    _, out, _ = await _run_git(
+        ["log", "-1", "--format=%G?%x1f%GS%x1f%GK", rev, "--"], cwd=cwd
+    )
+    mark, signer, key = [*out.split("\x1f"), "", ""][:3]
+    return SignatureStatus(
+        signed=True,
+        format=fmt,
+        verification=_VERIFICATION.get(mark, "unverified"),
+        signer=signer,
+        key=key,
+    )
@@ -0,0 +1,436 @@
+"""azathoth.core.stack — manifest parsing and established-library profiling.
+
+Public surface:
+  - ``declared_dependencies(root)`` → every dependency listed in the
+    project's manifests (pyproject.toml, requirements.txt, package.json,
+    Cargo.toml, go.mod).
+  - ``stack_profile(root)`` → the libraries the project already relies on,
+    grouped by concern (HTTP client, serialization, testing, logging, …),
+    with evidence from both manifests and source imports.
+
+The profile exists so that "add a feature" prompts reuse what the project
+already has instead of pulling in a second HTTP client or logger.
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+import re
+import tomllib
+from collections import Counter
+from pathlib import Path
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.traverse import iter_files
+
+log = logging.getLogger(__name__)
+
+# Source files scanned for imports, per ecosystem.
+_SOURCE_SUFFIXES: dict[str, tuple[str, ...]] = {
+    "python": (".py",),
+    "javascript": (".js", ".jsx", ".ts", ".tsx", ".mjs", ".cjs", ".svelte", ".vue"),
+    "rust": (".rs",),
+    "go": (".go",),
+}
+_MAX_SCANNED_FILES = 5000
+
+_PEP508_NAME = re.compile(r"^\s*([A-Za-z0-9][A-Za-z0-9._-]*)")
+_PY_IMPORT = re.compile(r"^\s*(?:from|import)\s+([A-Za-z_]\w*)", re.MULTILINE)
+_JS_IMPORT = re.compile(
+    r"""(?:from\s+|import\s+|require\(\s*)['"]([^'"./][^'"]*)['"]"""
+)
+_RS_USE = re.compile(
+    r"^\s*(?:pub\s+)?(?:use|extern\s+crate)\s+([a-z_]\w*)", re.MULTILINE
+)
+_GO_IMPORT = re.compile(r'"([a-z0-9.-]+\.[a-z]+/[^"]+)"')
+
+
+class DeclaredDependency(BaseModel, frozen=True):
+    """A dependency as listed in a manifest file."""
+
+    name: str
+    ecosystem: str
+    version_spec: str = ""
+    dev: bool = False
+    manifest: str
+
+
+class LibraryUsage(BaseModel, frozen=True):
+    """Evidence that the project uses a known library."""
+
+    name: str
+    ecosystem: str
+    declared: bool = Field(description="Listed in a manifest")
+    import_count: int = Field(description="Source files importing it")
+
+
+class ConcernProfile(BaseModel, frozen=True):
+    """Libraries already serving one concern, most used first."""
+
+    concern: str
+    libraries: list[LibraryUsage]
+
+
+class StackProfile(BaseModel, frozen=True):
+    """Established libraries per concern for a project."""
+
+    root: str
+    ecosystems: list[str]
+    concerns: list[ConcernProfile]
+    uncovered: list[str] = Field(
+        default_factory=list, description="Concerns with no established library"
+    )
+
+    def render_markdown(self) -> str:
+        lines = [f"# Stack profile: {self.root}", ""]
+        lines.append(f"**Ecosystems:** {', '.join(self.ecosystems) or 'none detected'}")
+        lines.append("")
+        for c in self.concerns:
+            libs = ", ".join(
+                f"`{lib.name}` ({lib.import_count} files"
+                f"{', declared' if lib.declared else ''})"
+                for lib in c.libraries
+            )
+            lines.append(f"- **{c.concern}:** {libs}")
+        if self.uncovered:
+            lines.append(f"- _No established library:_ {', '.join(self.uncovered)}")
+        lines.append("")
+        lines.append(
+            "Reuse the libraries above for their concern instead of adding "
+            "new dependencies."
+        )
+        return "\n".join(lines)
+
+
+# ── Catalog ───────────────────────────────────────────────────────────────────
+# concern → ecosystem → {library name: import aliases}.  Names are normalised
+# (lowercase, "_" → "-") before lookup.  Entries with no manifest presence
+# (stdlib modules) are reported from imports alone.
+
+_CATALOG: dict[str, dict[str, dict[str, tuple[str, ...]]]] = {
+    "HTTP client": {
+        "python": {
+            "httpx": (),
+            "requests": (),
+            "aiohttp": (),
+            "urllib3": (),
+            "urllib": (),
+        },
+        "javascript": {
+            "axios": (),
+            "node-fetch": (),
+            "got": (),
+            "ky": (),
+            "undici": (),
+        },
+        "rust": {"reqwest": (), "ureq": (), "hyper": (), "surf": ()},
+        "go": {"github.com/go-resty/resty": ()},
+    },
+    "Serialization / validation": {
+        "python": {
+            "pydantic": (),
+            "marshmallow": (),
+            "msgspec": (),
+            "orjson": (),
+            "attrs": ("attr",),
+            "pyyaml": ("yaml",),
+            "json": (),
+            "dataclasses": (),
+        },
+        "javascript": {"zod": (), "yup": (), "valibot": (), "superjson": ()},
+        "rust": {"serde": (), "serde-json": (), "bincode": (), "prost": ()},
+        "go": {"github.com/go-playground/validator": ()},
+    },
+    "Testing": {
+        "python": {"pytest": (), "hypothesis": (), "unittest": ()},
+        "javascript": {
+            "vitest": (),
+            "jest": (),
+            "mocha": (),
+            "@playwright/test": (),
+            "cypress": (),
+        },
+        "rust": {"proptest": (), "rstest": (), "insta": (), "mockall": ()},
+        "go": {"github.com/stretchr/testify": ()},
+    },
+    "Logging": {
+        "python": {"loguru": (), "structlog": (), "logging": ()},
+        "javascript": {"pino": (), "winston": (), "bunyan": ()},
+        "rust": {"tracing": (), "log": (), "env-logger": (), "slog": ()},
+        "go": {"go.uber.org/zap": (), "github.com/sirupsen/logrus": ()},
+    },
+    "CLI": {
+        "python": {"typer": (), "click": (), "argparse": ()},
+        "javascript": {"commander": (), "yargs": ()},
+        "rust": {"clap": (), "argh": ()},
+        "go": {"github.com/spf13/cobra": ()},
+    },
+    "Web framework": {
+        "python": {"fastapi": (), "flask": (), "django": (), "starlette": ()},
+        "javascript": {
+            "express": (),
+            "fastify": (),
+            "next": (),
+            "@sveltejs/kit": (),
+            "hono": (),
+        },
+        "rust": {"axum": (), "actix-web": (), "rocket": (), "warp": ()},
+        "go": {"github.com/gin-gonic/gin": (), "github.com/labstack/echo": ()},
+    },
+    "Async runtime": {
+        "python": {"asyncio": (), "anyio": (), "trio": ()},
+        "rust": {"tokio": (), "async-std": (), "smol": ()},
+    },
+    "Database / ORM": {
+        "python": {"sqlalchemy": (), "sqlmodel": (), "peewee": (), "psycopg": ()},
+        "javascript": {"prisma": ("@prisma/client",), "drizzle-orm": (), "typeorm": ()},
+        "rust": {"sqlx": (), "diesel": (), "sea-orm": (), "rusqlite": ()},
+        "go": {"gorm.io/gorm": ()},
+    },
+    "Terminal output": {
+        "python": {"rich": (), "colorama": ()},
+        "javascript": {"chalk": (), "kleur": ()},
+        "rust": {"colored": (), "owo-colors": (), "indicatif": ()},
+    },
+}
+
+
+def _norm(name: str) -> str:
+    return name.strip().lower().replace("_", "-")
+
+
+# ── Manifest parsing ──────────────────────────────────────────────────────────
+
+
+def _load_toml(path: Path) -> dict:
+    try:
+        with open(path, "rb") as f:
+            return tomllib.load(f)
+    except (tomllib.TOMLDecodeError, OSError) as exc:
+        log.warning("Could not parse %s: %s", path, exc)
+        return {}
+
+
+def _pep508(spec: str, manifest: str, *, dev: bool) -> DeclaredDependency | None:
+    match = _PEP508_NAME.match(spec)
+    if not match:
+        return None
+    name = match.group(1)
+    return DeclaredDependency(
+        name=_norm(name),
+        ecosystem="python",
+        version_spec=spec[match.end() :].strip(),
+        dev=dev,
+        manifest=manifest,
+    )
+
+
+def _python_deps(root: Path) -> list[DeclaredDependency]:
+    deps: list[DeclaredDependency] = []
+    pyproject = root / "pyproject.toml"
+    if pyproject.exists():
+        data = _load_toml(pyproject)
+        project = data.get("project", {})
+        specs: list[tuple[str, bool]] = [
+            (s, False) for s in project.get("dependencies", [])
+        ]
+        for group in project.get("optional-dependencies", {}).values():
+            specs += [(s, False) for s in group]
+        for group in data.get("dependency-groups", {}).values():
+            specs += [(s, True) for s in group if isinstance(s, str)]
+        for spec, dev in specs:
+            if dep := _pep508(spec, "pyproject.toml", dev=dev):
+                deps.append(dep)
+
+    requirements = root / "requirements.txt"
+    if requirements.exists():
+        for line in requirements.read_text(errors="ignore").splitlines():
+            line = line.split("#", 1)[0].strip()
+            if line and not line.startswith("-"):
+                if dep := _pep508(line, "requirements.txt", dev=False):
+                    deps.append(dep)
+    return deps
+
+
+def _js_deps(root: Path) -> list[DeclaredDependency]:
+    package_json = root / "package.json"
+    if not package_json.exists():
+        return []
+    try:
+        data = json.loads(package_json.read_text(encoding="utf-8"))
+    except (json.JSONDecodeError, OSError) as exc:
+        log.warning("Could not parse %s: %s", package_json, exc)
+        return []
+    deps: list[DeclaredDependency] = []
+    for key, dev in (
+        ("dependencies", False),
+        ("peerDependencies", False),
+        ("devDependencies", True),
+    ):
+        for name, version in data.get(key, {}).items():
+            deps.append(
+                DeclaredDependency(
+                    name=_norm(name),
+                    ecosystem="javascript",
+                    version_spec=str(version),
+                    dev=dev,
+                    manifest="package.json",
+                )
+            )
+    return deps
+
+
+def _rust_deps(root: Path) -> list[DeclaredDependency]:
+    cargo = root / "Cargo.toml"
+    if not cargo.exists():
+        return []
+    data = _load_toml(cargo)
+    tables = [
+        (data.get("dependencies", {}), False),
+        (data.get("build-dependencies", {}), False),
+        (data.get("dev-dependencies", {}), True),
+        (data.get("workspace", {}).get("dependencies", {}), False),
+    ]
+    deps: list[DeclaredDependency] = []
+    for table, dev in tables:
+        for name, spec in table.items():
+            version = spec if isinstance(spec, str) else spec.get("version", "")
+            deps.append(
+                DeclaredDependency(
+                    name=_norm(name),
+                    ecosystem="rust",
+                    version_spec=str(version),
+                    dev=dev,
+                    manifest="Cargo.toml",
+                )
+            )
+    return deps
+
+
+def _go_deps(root: Path) -> list[DeclaredDependency]:
+    go_mod = root / "go.mod"
+    if not go_mod.exists():
+        return []
+    deps: list[DeclaredDependency] = []
+    in_block = False
+    for raw in go_mod.read_text(errors="ignore").splitlines():
+        line = raw.split("//", 1)[0].strip()
+        if line.startswith("require ("):
+            in_block = True
+            continue
+        if in_block and line == ")":
+            in_block = False
+            continue
+        if line.startswith("require "):
+            line = line.removeprefix("require ").strip()
+        elif not in_block:
+            continue
+        parts = line.split()
+        if len(parts) >= 2:
+            deps.append(
+                DeclaredDependency(
+                    name=parts[0].lower(),
+                    ecosystem="go",
+                    version_spec=parts[1],
+                    manifest="go.mod",
+                )
+            )
+    return deps
+
+
+def declared_dependencies(root: Path) -> list[DeclaredDependency]:
+    """Return every dependency declared in the manifests directly under *root*."""
+    return [
+        *_python_deps(root),
+        *_js_deps(root),
+        *_rust_deps(root),
+        *_go_deps(root),
+    ]
+
+
+# ── Import scanning ───────────────────────────────────────────────────────────
+
+
+def _js_package(spec: str) -> str:
+    parts = spec.split("/")
+    return "/".join(parts[:2]) if spec.startswith("@") else parts[0]
+
+
+def _scan_imports(root: Path) -> dict[str, Counter[str]]:
+    """Return ``{ecosystem: Counter(normalised module → files importing it)}``."""
+    counts: dict[str, Counter[str]] = {eco: Counter() for eco in _SOURCE_SUFFIXES}
+    suffix_to_eco = {
+        suffix: eco for eco, suffixes in _SOURCE_SUFFIXES.items() for suffix in suffixes
+    }
+    for path in iter_files(
+        root, suffixes=suffix_to_eco.keys(), max_files=_MAX_SCANNED_FILES
+    ):
+        eco = suffix_to_eco[path.suffix.lower()]
+        try:
+            text = path.read_text(encoding="utf-8", errors="ignore")
+        except OSError:
+            continue
+        if eco == "python":
+            found = {_norm(m) for m in _PY_IMPORT.findall(text)}
+        elif eco == "javascript":
+            found = {_norm(_js_package(m)) for m in _JS_IMPORT.findall(text)}
+        elif eco == "rust":
+            found = {_norm(m) for m in _RS_USE.findall(text)}
+        else:
+            found = {m.lower() for m in _GO_IMPORT.findall(text)}
+        counts[eco].update(found)
+    return counts
+
+
+def _go_import_count(counter: Counter[str], module: str) -> int:
+    return sum(n for path, n in counter.items() if path.startswith(module))
+
+
+# ── Profile ───────────────────────────────────────────────────────────────────
+
+
+def stack_profile(root: Path) -> StackProfile:
+    """Profile the libraries *root* already uses for each common concern."""
+    root = root.resolve()
+    declared = {(d.ecosystem, d.name) for d in declared_dependencies(root)}
+    imports = _scan_imports(root)
+
+    ecosystems = sorted(
+        {eco for eco, _ in declared}
+        | {eco for eco, counter in imports.items() if counter}
+    )
+
+    concerns: list[ConcernProfile] = []
+    uncovered: list[str] = []
+    for concern, by_eco in _CATALOG.items():
+        found: list[LibraryUsage] = []
+        for eco, libraries in by_eco.items():
+            if eco not in ecosystems:
+                continue
+            for name, aliases in libraries.items():
+                keys = {_norm(name), *(_norm(a) for a in aliases)}
+                is_declared = any((eco, k) in declared for k in keys)
+                if eco == "go":
+                    count = _go_import_count(imports[eco], name)
+                else:
+                    count = sum(imports[eco][k] for k in keys)
+                if is_declared or count:
+                    found.append(
+                        LibraryUsage(
+                            name=name,
+                            ecosystem=eco,
+                            declared=is_declared,
+                            import_count=count,
+                        )
+                    )
+        if found:
+            found.sort(key=lambda lib: (-lib.import_count, not lib.declared, lib.name))
+            concerns.append(ConcernProfile(concern=concern, libraries=found))
+        else:
+            uncovered.append(concern)
+
+    return StackProfile(
+        root=str(root), ecosystems=ecosystems, concerns=concerns, uncovered=uncovered
+    )
@@ -0,0 +1,161 @@
+"""azathoth.core.stash — shelve and restore work in progress with git stash.
+
+Public surface:
+  - ``list_stashes(cwd)``                                  → ``[StashEntry]``
+  - ``save_stash(message, paths, include_untracked, …)``   → ``GitResult``
+  - ``stash_conflicts(index, cwd)``                        → ``[str]``
+  - ``pop_stash(index, cwd, dry_run)``                     → ``GitResult``
+
+Popping checks the stash's files against the working tree first: git refuses
+to apply over local changes to the same paths, so those are reported up front
+instead of as git's bare "would be overwritten".  When the pop itself stops on
+merge conflicts, git keeps the stash; the message names the conflicted paths
+and says so, since an agent retrying the pop would apply it twice.
+"""
+
+from __future__ import annotations
+
+import re
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.workflow import (
+    GitResult,
+    _refuse_bare,
+    _run_git,
+    get_repo_status,
+    planned,
+)
+
+_FORMAT = "%gd%x1f%gs%x1f%cI"
+# ``On main: message`` for ``stash push -m``, ``WIP on main: <sha> <subject>``.
+_SUBJECT = re.compile(r"^(?:WIP on|On) (?P<branch>[^:]+): (?P<message>.*)$")
+
+
+class StashEntry(BaseModel, frozen=True):
+    index: int
+    ref: str = Field(description="stash@{N}")
+    message: str
+    branch: str | None = Field(None, description="Branch the stash was made on")
+    date: str = Field(description="ISO 8601 creation time")
+    files: list[str] = Field(default_factory=list)
+
+
+def _fail(message: str) -> GitResult:
+    return GitResult(success=False, stdout="", stderr=message, message=message)
+
+
+def _ref(index: int) -> str:
+    return f"stash@{{{index}}}"
+
+
+async def _stash_files(ref: str, cwd: str | None) -> list[str]:
+    code, out, _ = await _run_git(
+        ["stash", "show", "--name-only", "--include-untracked", ref], cwd=cwd
+    )
+    return out.splitlines() if code == 0 else []
+
+
+async def list_stashes(cwd: str | None = None) -> list[StashEntry]:
+    """Stash entries, newest first, with the files each one touches."""
+    code, out, _ = await _run_git(["stash", "list", f"--format={_FORMAT}"], cwd=cwd)
+    if code != 0:
+        return []
+    entries = []
+    for index, line in enumerate(out.splitlines()):
+        ref, subject, date = (line.split("\x1f") + ["", ""])[:3]
+        match = _SUBJECT.match(subject)
+        entries.append(
+            StashEntry(
+                index=index,
+                ref=ref,
+                message=match.group("message") if match else subject,
+                branch=match.group("branch") if match else None,
+                date=date,
+                files=await _stash_files(ref, cwd),
+            )
+        )
+    return entries
+
+
+async def save_stash(
+    message: str | None = None,
+    paths: list[str] | None = None,
+    include_untracked: bool = True,
+    cwd: str | None = None,
+    dry_run: bool = False,
+) -> GitResult:
+    """Stash local changes (only *paths*, if given) and clean them from the tree."""
+    if refused := await _refuse_bare(cwd):
+        return refused
+    args = ["stash", "push"]
+    if include_untracked:
+        args.append("--include-untracked")
+    if message:
+        args += ["--message", message]
+    if paths:
+        args += ["--", *paths]
+    if dry_run:
+        return planned(["git", *args])
+
+    code, out, err = await _run_git(args, cwd=cwd)
+    if code == 0 and out.startswith("No local changes"):
+        return _fail("No local changes to stash.")
+    return GitResult(success=(code == 0), stdout=out, stderr=err)
+
+
+async def _dirty_paths(cwd: str | None) -> set[str]:
+    status = await get_repo_status(cwd)
+    return {c.path for c in (*status.staged, *status.unstaged)} | set(
+        status.untracked
+    )
+
+
+async def stash_conflicts(index: int = 0, cwd: str | None = None) -> list[str]:
+    """Files in stash *index* that also have local changes in the working tree."""
+    files = await _stash_files(_ref(index), cwd)
+    return sorted(set(files) & await _dirty_paths(cwd))
+
+
+async def pop_stash(
+    index: int = 0, cwd: str | None = None, dry_run: bool = False
+) -> GitResult:
+    """Apply stash *index* to the working tree and drop it.
+
+    Refuses when the stash touches files with local changes.  If applying
+    stops on merge conflicts, the stash is kept and the conflicted paths are
+    listed in ``message``.
+    """
+    if refused := await _refuse_bare(cwd):
+        return refused
+    ref = _ref(index)
+    code, _, _ = await _run_git(["rev-parse", "--verify", "--quiet", ref], cwd=cwd)
+    if code != 0:
+        return _fail(f"No stash entry {ref}.")
+    if overlap := await stash_conflicts(index, cwd):
+        return _fail(
+            f"{ref} touches files with local changes: {', '.join(overlap)}. "
+            "Commit or stash them first."
+        )
+    args = ["stash", "pop", ref]
+    if dry_run:
+        return planned(["git", *args])
+
+    code, out, err = await _run_git(args, cwd=cwd)
+    if code == 0:
+        return GitResult(success=True, stdout=out, stderr=err)
+    _, unmerged, _ = await _run_git(
+        ["diff", "--name-only", "--diff-filter=U"], cwd=cwd
+    )
+    if not unmerged:
+        return GitResult(success=False, stdout=out, stderr=err)
+    conflicts = ", ".join(unmerged.splitlines())
+    return GitResult(
+        success=False,
+        stdout=out,
+        stderr=err,
+        message=(
+            f"Conflicts applying {ref} in: {conflicts}. The stash was kept; "
+            f"resolve the files, then drop it with `git stash drop {ref}`."
+        ),
+    )
@@ -0,0 +1,369 @@
+"""azathoth.core.summarize — structured per-directory summaries.
+
+Public surface:
+  - ``summarize_directory(root, directory)`` → ``DirectorySummary``
+
+A summary covers the directory's whole subtree: its purpose (README or
+package docstring), the files carrying the most surface or inbound
+imports, the public symbols of its own files, and the import edges that
+cross its boundary — internal directories it depends on and that depend
+on it, plus external packages.  Subdirectories are listed so callers can
+summarise bottom-up and fold children into parents; ``content_hash``
+changes only when a file in the subtree does, so stored summaries can be
+reused until then.  Per-file facts come from ``core.symbols`` and are
+cached by content.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import posixpath
+import sys
+from collections import Counter
+from pathlib import Path
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.formatter import Table
+from azathoth.core.symbols import FileFacts, index_tree, python_module
+
+#: Files that define a directory's public face, checked in order.
+ENTRY_FILES = (
+    "__init__.py",
+    "lib.rs",
+    "mod.rs",
+    "main.rs",
+    "index.ts",
+    "index.tsx",
+    "index.js",
+    "doc.go",
+)
+
+_MAX_KEY_FILES = 8
+_MAX_SURFACE = 60
+_JS_RESOLVE_SUFFIXES = (".ts", ".tsx", ".js", ".jsx", ".mjs", ".cjs")
+_RS_ROOTS = ("crate", "self", "super")
+_RS_STD = {"std", "core", "alloc"}
+
+
+class KeyFile(BaseModel, frozen=True):
+    path: str
+    doc: str = ""
+    symbols: int = Field(description="Public symbols defined")
+    used_by: int = Field(description="Files elsewhere in the repo importing it")
+
+
+class SurfaceEntry(BaseModel, frozen=True):
+    file: str
+    name: str
+    kind: str
+
+
+class DirectorySummary(BaseModel, frozen=True):
+    """What a directory is for, what it exposes and how it is wired in."""
+
+    path: str
+    purpose: str = ""
+    files: int = 0
+    lines: int = 0
+    languages: list[str] = Field(default_factory=list)
+    key_files: list[KeyFile] = Field(default_factory=list)
+    public_surface: list[SurfaceEntry] = Field(default_factory=list)
+    depends_on: list[str] = Field(
+        default_factory=list, description="Repo directories this one imports from"
+    )
+    used_by: list[str] = Field(
+        default_factory=list, description="Repo directories importing from this one"
+    )
+    external: list[str] = Field(
+        default_factory=list, description="Third-party packages imported"
+    )
+    subdirectories: list[str] = Field(default_factory=list)
+    content_hash: str = ""
+
+    def render_markdown(self) -> str:
+        lines = [f"# {self.path}", ""]
+        if self.purpose:
+            lines += [self.purpose, ""]
+        lines.append(
+            f"{self.files} source files, {self.lines} lines"
+            + (f" ({', '.join(self.languages)})" if self.languages else "")
+        )
+        if self.key_files:
+            table = (
+                Table(overflow="ellipsis")
+                .column("File", max_width=50)
+                .column("Symbols", align="right")
+                .column("Used by", align="right")
+                .column("About", max_width=60)
+            )
+            for f in self.key_files:
+                table.row([f.path, f.symbols, f.used_by, f.doc])
+            lines += ["", "## Key files", "```", table.render(), "```"]
+        if self.public_surface:
+            lines += ["", "## Public surface"]
+            lines += [
+                f"- `{s.name}` ({s.kind}, {s.file})" for s in self.public_surface
+            ]
+        for title, items in (
+            ("Depends on", self.depends_on),
+            ("Used by", self.used_by),
+            ("External", self.external),
+            ("Subdirectories", self.subdirectories),
+        ):
+            if items:
+                lines += ["", f"## {title}", ", ".join(f"`{i}`" for i in items)]
+        return "\n".join(lines)
+
+
+# ── Import resolution ─────────────────────────────────────────────────────────
+
+
+class _ModuleIndex:
+    """Lookup tables for turning import strings into repo paths."""
+
+    def __init__(self, index: dict[str, FileFacts], root: Path):
+        self.paths = set(index)
+        self.python: dict[str, str] = {}
+        for path in index:
+            if not path.endswith(".py"):
+                continue
+            module = python_module(path)
+            self.python.setdefault(module, path)
+            # src-layout: ``src/pkg/mod.py`` is imported as ``pkg.mod``.
+            if module.startswith("src."):
+                self.python.setdefault(module.removeprefix("src."), path)
+        self.go_module = ""
+        go_mod = root / "go.mod"
+        if go_mod.is_file():
+            text = go_mod.read_text(encoding="utf-8", errors="ignore")
+            for line in text.splitlines():
+                if line.startswith("module "):
+                    self.go_module = line.split()[1]
+                    break
+
+
+def _python_target(target: str, modules: _ModuleIndex) -> str | None:
+    parts = target.split(".")
+    while parts:
+        if (path := modules.python.get(".".join(parts))) is not None:
+            return path
+        parts.pop()
+    return None
+
+
+def _js_target(facts: FileFacts, target: str, modules: _ModuleIndex) -> str | None:
+    if not target.startswith("."):
+        return None
+    base = posixpath.normpath(posixpath.join(posixpath.dirname(facts.path), target))
+    candidates = [base, *(base + s for s in _JS_RESOLVE_SUFFIXES)]
+    candidates += [f"{base}/index{s}" for s in _JS_RESOLVE_SUFFIXES]
+    # TS sources import compiled names: ``./util.js`` means ``./util.ts``.
+    stem, ext = posixpath.splitext(base)
+    if ext in (".js", ".jsx"):
+        candidates += [stem + ".ts", stem + ".tsx"]
+    return next((c for c in candidates if c in modules.paths), None)
+
+
+def _rust_target(facts: FileFacts, target: str, modules: _ModuleIndex) -> str | None:
+    here = posixpath.dirname(facts.path)
+    name = posixpath.basename(facts.path)
+    # Children of foo.rs live in foo/; those of lib.rs/main.rs/mod.rs beside it.
+    if name not in ("lib.rs", "main.rs", "mod.rs"):
+        here = posixpath.join(here, name.removesuffix(".rs"))
+    if target.startswith("mod "):
+        segments = [target.removeprefix("mod ")]
+        anchor = here
+    else:
+        segments = target.split("::")
+        if segments[0] == "crate":
+            parts = facts.path.split("/")
+            anchor = "/".join(parts[: parts.index("src") + 1]) if "src" in parts else ""
+        elif segments[0] == "self":
+            anchor = here
+        elif segments[0] == "super":
+            anchor = posixpath.dirname(here)
+        else:
+            return None
+        segments = segments[1:]
+    # Longest module path that exists wins; the rest are items inside it.
+    for end in range(len(segments), 0, -1):
+        stem = posixpath.join(anchor, *segments[:end])
+        for candidate in (f"{stem}.rs", f"{stem}/mod.rs"):
+            if candidate in modules.paths:
+                return candidate
+    return None
+
+
+def _go_target(target: str, modules: _ModuleIndex) -> str | None:
+    if not modules.go_module or not target.startswith(modules.go_module):
+        return None
+    directory = target.removeprefix(modules.go_module).strip("/")
+    prefix = f"{directory}/" if directory else ""
+    return next(
+        (
+            p
+            for p in sorted(modules.paths)
+            if p.endswith(".go")
+            and p.startswith(prefix)
+            and "/" not in p.removeprefix(prefix)
+        ),
+        None,
+    )
+
+
+def _resolve_import(
+    facts: FileFacts, target: str, modules: _ModuleIndex
+) -> str | None:
+    """Repo path an import points at, or ``None`` when it leaves the repo."""
+    if facts.language == "python":
+        return _python_target(target, modules)
+    if facts.language in ("javascript", "typescript"):
+        return _js_target(facts, target, modules)
+    if facts.language == "rust":
+        return _rust_target(facts, target, modules)
+    return _go_target(target, modules)
+
+
+def _external_name(
+    facts: FileFacts, target: str, modules: _ModuleIndex
+) -> str | None:
+    """Package name of an import that leaves the repo, or ``None`` for stdlib."""
+    if facts.language == "python":
+        top = target.split(".")[0]
+        return None if not top or top in sys.stdlib_module_names else top
+    if facts.language in ("javascript", "typescript"):
+        if target.startswith((".", "/", "node:")):
+            return None
+        parts = target.split("/")
+        return "/".join(parts[:2]) if target.startswith("@") else parts[0]
+    if facts.language == "rust":
+        if target.startswith("mod "):
+            return None
+        top = target.split("::")[0]
+        return None if top in _RS_STD or top in _RS_ROOTS else top
+    if "." not in target.split("/")[0]:
+        return None  # Go standard library
+    if modules.go_module and target.startswith(modules.go_module):
+        return None
+    return target
+
+
+# ── Summary ───────────────────────────────────────────────────────────────────
+
+
+def _readme_purpose(directory: Path) -> str:
+    for name in ("README.md", "readme.md", "README.rst", "README"):
+        path = directory / name
+        if not path.is_file():
+            continue
+        paragraph: list[str] = []
+        for line in path.read_text(encoding="utf-8", errors="ignore").splitlines():
+            stripped = line.strip()
+            if not stripped:
+                if paragraph:
+                    break
+                continue
+            if stripped.startswith(("#", "[!", "![", "<", "===", "---")):
+                if paragraph:
+                    break
+                continue
+            paragraph.append(stripped)
+        if paragraph:
+            return " ".join(paragraph)
+    return ""
+
+
+def _in_dir(path: str, prefix: str) -> bool:
+    return not prefix or path.startswith(prefix)
+
+
+def _dir_of(path: str) -> str:
+    return posixpath.dirname(path) or "."
+
+
+def summarize_directory(root: Path, directory: str = ".") -> DirectorySummary:
+    """Summarise *directory* (relative to *root*) against the whole repo's
+    import graph.
+
+    Raises:
+        WorkflowError: If *directory* is not a directory inside *root*.
+    """
+    root = root.resolve()
+    target = (root / directory).resolve()
+    if not target.is_dir() or not target.is_relative_to(root):
+        raise WorkflowError(f"'{directory}' is not a directory inside {root}.")
+    rel = target.relative_to(root).as_posix()
+    rel = "" if rel == "." else rel
+    prefix = f"{rel}/" if rel else ""
+
+    index = index_tree(root)
+    modules = _ModuleIndex(index, root)
+    inside = {p: f for p, f in index.items() if _in_dir(p, prefix)}
+
+    inbound: Counter[str] = Counter()
+    depends_on: set[str] = set()
+    used_by: set[str] = set()
+    external: set[str] = set()
+    for path, facts in index.items():
+        source_inside = path in inside
+        for imported in facts.imports:
+            resolved = _resolve_import(facts, imported, modules)
+            if resolved is None:
+                name = _external_name(facts, imported, modules)
+                if source_inside and name:
+                    external.add(name)
+                continue
+            target_inside = resolved in inside
+            if source_inside and not target_inside:
+                depends_on.add(_dir_of(resolved))
+            elif target_inside and not source_inside:
+                used_by.add(_dir_of(path))
+                inbound[resolved] += 1
+
+    def _rank(facts: FileFacts) -> tuple[int, int, int, str]:
+        entry = posixpath.basename(facts.path) in ENTRY_FILES
+        return (-inbound[facts.path], not entry, -len(facts.symbols), facts.path)
+
+    key_files = [
+        KeyFile(
+            path=f.path,
+            doc=f.doc,
+            symbols=len(f.symbols),
+            used_by=inbound[f.path],
+        )
+        for f in sorted(inside.values(), key=_rank)[:_MAX_KEY_FILES]
+    ]
+
+    own = [f for p, f in sorted(inside.items()) if "/" not in p.removeprefix(prefix)]
+    entries = [f for f in own if posixpath.basename(f.path) in ENTRY_FILES]
+    surface_files = entries if any(f.symbols for f in entries) else own
+    surface = [
+        SurfaceEntry(file=f.path, name=s.name, kind=s.kind)
+        for f in surface_files
+        for s in f.symbols
+    ][:_MAX_SURFACE]
+
+    purpose = _readme_purpose(target) or next((f.doc for f in entries if f.doc), "")
+    nested = (p.removeprefix(prefix) for p in inside)
+    subdirectories = sorted({n.split("/")[0] for n in nested if "/" in n})
+    digest = hashlib.sha256(
+        "\n".join(f"{p}\0{f.digest}" for p, f in sorted(inside.items())).encode(),
+        usedforsecurity=False,
+    ).hexdigest()
+
+    return DirectorySummary(
+        path=rel or ".",
+        purpose=purpose,
+        files=len(inside),
+        lines=sum(f.lines for f in inside.values()),
+        languages=sorted({f.language for f in inside.values()}),
+        key_files=key_files,
+        public_surface=surface,
+        depends_on=sorted(depends_on),
+        used_by=sorted(used_by),
+        external=sorted(external),
+        subdirectories=[f"{prefix}{d}" for d in subdirectories],
+        content_hash=digest,
+    )
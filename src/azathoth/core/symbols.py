@@ -0,0 +1,315 @@
+"""azathoth.core.symbols — per-file symbol and import facts, cached by content.
+
+Public surface:
+  - ``LANGUAGES``               — suffix → language name for indexed files
+  - ``file_facts(path, root)``  → ``FileFacts`` (doc line, public symbols,
+    raw imports) for one source file
+  - ``index_tree(root)``        → ``{relative path: FileFacts}`` for a tree
+  - ``content_hash(data)``      → cache key for a file's bytes
+  - ``python_module(path)``     → dotted module name of a repo-relative path
+
+Extraction is deliberately shallow: Python goes through ``ast`` (honouring
+``__all__``), JavaScript/TypeScript, Rust and Go through line regexes for
+their export syntax.  Facts are cached as JSON under
+``<config_dir>/cache/facts/`` keyed by the sha256 of the file's bytes, so
+re-indexing a large repo only parses what changed.
+"""
+
+from __future__ import annotations
+
+import ast
+import hashlib
+import logging
+import re
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field, ValidationError
+
+from azathoth.config import get_config
+from azathoth.core.traverse import iter_files
+
+log = logging.getLogger(__name__)
+
+SymbolKind = Literal["function", "class", "type", "constant", "module", "reexport"]
+
+LANGUAGES: dict[str, str] = {
+    ".py": "python",
+    ".js": "javascript",
+    ".jsx": "javascript",
+    ".mjs": "javascript",
+    ".cjs": "javascript",
+    ".ts": "typescript",
+    ".tsx": "typescript",
+    ".rs": "rust",
+    ".go": "go",
+}
+
+#: Bump when extraction changes so stale cache entries are ignored.
+_FACTS_VERSION = 1
+_MAX_FILES = 5000
+_MAX_BYTES = 512_000
+
+_JS_EXPORT = re.compile(
+    r"^\s*export\s+(?:default\s+)?(?:declare\s+)?(?:async\s+)?"
+    r"(function\*?|class|const|let|var|interface|type|enum)\s+([A-Za-z_$][\w$]*)",
+    re.MULTILINE,
+)
+_JS_IMPORT = re.compile(
+    r"""(?:\bfrom\s+|^\s*import\s+|\brequire\(\s*)['"]([^'"]+)['"]""", re.MULTILINE
+)
+_RS_PUB = re.compile(
+    r"^\s*pub\s+(?:async\s+)?(?:unsafe\s+)?(fn|struct|enum|trait|type|const|mod)"
+    r"\s+([A-Za-z_]\w*)",
+    re.MULTILINE,
+)
+_RS_USE = re.compile(r"^\s*(?:pub\s+)?use\s+([\w:]+)", re.MULTILINE)
+_RS_MOD = re.compile(r"^\s*(?:pub\s+)?mod\s+([A-Za-z_]\w*)\s*;", re.MULTILINE)
+_GO_DECL = re.compile(
+    r"^(func|type|const|var)\s+(?:\([^)]*\)\s*)?([A-Z]\w*)", re.MULTILINE
+)
+_GO_IMPORT = re.compile(r'^\s*(?:import\s+)?(?:\w+\s+)?"([^"]+)"\s*$', re.MULTILINE)
+
+_JS_KINDS: dict[str, SymbolKind] = {
+    "function": "function",
+    "function*": "function",
+    "class": "class",
+    "interface": "type",
+    "type": "type",
+    "enum": "type",
+}
+_RS_KINDS: dict[str, SymbolKind] = {
+    "fn": "function",
+    "struct": "class",
+    "enum": "type",
+    "trait": "type",
+    "type": "type",
+    "const": "constant",
+    "mod": "module",
+}
+_GO_KINDS: dict[str, SymbolKind] = {
+    "func": "function",
+    "type": "type",
+    "const": "constant",
+    "var": "constant",
+}
+
+
+class Symbol(BaseModel, frozen=True):
+    name: str
+    kind: SymbolKind
+
+
+class FileFacts(BaseModel, frozen=True):
+    """What a source file offers and what it pulls in."""
+
+    path: str
+    language: str
+    digest: str = Field(description="Content hash the facts were extracted from")
+    lines: int
+    doc: str = Field("", description="First line of the module docstring/comment")
+    symbols: list[Symbol] = Field(default_factory=list)
+    imports: list[str] = Field(
+        default_factory=list,
+        description="Import targets as written (dotted, relative or crate paths)",
+    )
+
+
+# ── Extraction ────────────────────────────────────────────────────────────────
+
+
+def _first_line(text: str | None) -> str:
+    for line in (text or "").strip().splitlines():
+        if line.strip():
+            return line.strip()
+    return ""
+
+
+def _python_all(tree: ast.Module) -> list[str] | None:
+    for node in tree.body:
+        if isinstance(node, ast.Assign) and any(
+            isinstance(t, ast.Name) and t.id == "__all__" for t in node.targets
+        ):
+            try:
+                names = ast.literal_eval(node.value)
+            except (ValueError, TypeError, SyntaxError):
+                return None
+            return [n for n in names if isinstance(n, str)]
+    return None
+
+
+def python_module(path: str) -> str:
+    """Dotted module of a ``.py`` file relative to the repo root."""
+    parts = list(Path(path).with_suffix("").parts)
+    if parts and parts[-1] == "__init__":
+        parts.pop()
+    return ".".join(parts)
+
+
+def _python_facts(source: str, path: str) -> tuple[str, list[Symbol], list[str]]:
+    try:
+        tree = ast.parse(source)
+    except SyntaxError:
+        return "", [], []
+
+    found: dict[str, SymbolKind] = {}
+    for node in tree.body:
+        if isinstance(node, (ast.FunctionDef, ast.AsyncFunctionDef)):
+            found[node.name] = "function"
+        elif isinstance(node, ast.ClassDef):
+            found[node.name] = "class"
+        elif isinstance(node, (ast.Assign, ast.AnnAssign)):
+            targets = node.targets if isinstance(node, ast.Assign) else [node.target]
+            for target in targets:
+                if isinstance(target, ast.Name) and target.id.isupper():
+                    found[target.id] = "constant"
+        elif isinstance(node, (ast.Import, ast.ImportFrom)):
+            for alias in node.names:
+                name = alias.asname or alias.name.split(".")[0]
+                found.setdefault(name, "reexport")
+
+    exported = _python_all(tree)
+    if exported is not None:
+        symbols = [Symbol(name=n, kind=found.get(n, "constant")) for n in exported]
+    else:
+        symbols = [
+            Symbol(name=n, kind=k)
+            for n, k in found.items()
+            if k != "reexport" and not n.startswith("_")
+        ]
+
+    package = python_module(path).split(".")
+    if not path.endswith("__init__.py"):
+        package = package[:-1]
+    imports: list[str] = []
+    for node in ast.walk(tree):
+        if isinstance(node, ast.Import):
+            imports += [alias.name for alias in node.names]
+        elif isinstance(node, ast.ImportFrom):
+            if node.level:
+                anchor = package[: len(package) - node.level + 1]
+                base = ".".join(anchor + ([node.module] if node.module else []))
+            else:
+                base = node.module or ""
+            if node.module is None or node.level:
+                # ``from . import x`` may name submodules; keep both spellings.
+                imports += [f"{base}.{alias.name}".strip(".") for alias in node.names]
+            if base:
+                imports.append(base)
+    return _first_line(ast.get_docstring(tree)), symbols, sorted(set(imports))
+
+
+def _leading_comment(source: str, markers: tuple[str, ...]) -> str:
+    for line in source.splitlines():
+        stripped = line.strip()
+        if not stripped:
+            continue
+        for marker in markers:
+            if stripped.startswith(marker):
+                return stripped.removeprefix(marker).strip(" */")
+        return ""
+    return ""
+
+
+def _regex_symbols(
+    source: str, pattern: re.Pattern[str], kinds: dict[str, SymbolKind]
+) -> list[Symbol]:
+    seen: dict[str, SymbolKind] = {}
+    for keyword, name in pattern.findall(source):
+        seen.setdefault(name, kinds.get(keyword, "constant"))
+    return [Symbol(name=n, kind=k) for n, k in seen.items()]
+
+
+def _extract(source: str, path: str, language: str, digest: str) -> FileFacts:
+    if language == "python":
+        doc, symbols, imports = _python_facts(source, path)
+    elif language in ("javascript", "typescript"):
+        doc = _leading_comment(source, ("/**", "//", "/*"))
+        symbols = _regex_symbols(source, _JS_EXPORT, _JS_KINDS)
+        imports = sorted(set(_JS_IMPORT.findall(source)))
+    elif language == "rust":
+        doc = _leading_comment(source, ("//!",))
+        symbols = _regex_symbols(source, _RS_PUB, _RS_KINDS)
+        imports = sorted(
+            set(_RS_USE.findall(source)) | {f"mod {m}" for m in _RS_MOD.findall(source)}
+        )
+    else:
+        doc = _leading_comment(source, ("// Package", "//"))
+        symbols = _regex_symbols(source, _GO_DECL, _GO_KINDS)
+        imports = sorted(set(_GO_IMPORT.findall(source)))
+    return FileFacts(
+        path=path,
+        language=language,
+        digest=digest,
+        lines=source.count("\n") + (0 if source.endswith("\n") else 1),
+        doc=doc,
+        symbols=symbols,
+        imports=imports,
+    )
+
+
+# ── Cache ─────────────────────────────────────────────────────────────────────
+
+
+def _cache_path(digest: str) -> Path:
+    return get_config().cache_dir / "facts" / f"{digest}.json"
+
+
+def _load_cached(digest: str, path: str) -> FileFacts | None:
+    cached = _cache_path(digest)
+    if not cached.is_file():
+        return None
+    try:
+        facts = FileFacts.model_validate_json(cached.read_text(encoding="utf-8"))
+    except (OSError, ValidationError):
+        return None
+    # Identical content at another path: only the path (and with it any
+    # relative-import anchoring for Python) differs.
+    return facts if facts.path == path else None
+
+
+def _store(digest: str, facts: FileFacts) -> None:
+    cached = _cache_path(digest)
+    try:
+        cached.parent.mkdir(parents=True, exist_ok=True)
+        cached.write_text(facts.model_dump_json(), encoding="utf-8")
+    except OSError as exc:
+        log.debug("Could not cache facts for %s: %s", facts.path, exc)
+
+
+def content_hash(data: bytes) -> str:
+    return hashlib.sha256(
+        f"v{_FACTS_VERSION}\0".encode() + data, usedforsecurity=False
+    ).hexdigest()
+
+
+def file_facts(path: Path, root: Path) -> FileFacts | None:
+    """Facts for one file, from cache when its content is unchanged.
+
+    Returns ``None`` for unsupported, unreadable or oversized files.
+    """
+    language = LANGUAGES.get(path.suffix.lower())
+    if language is None:
+        return None
+    try:
+        data = path.read_bytes()
+    except OSError:
+        return None
+    if len(data) > _MAX_BYTES:
+        return None
+    rel = path.relative_to(root).as_posix()
+    digest = content_hash(data)
+    if (facts := _load_cached(digest, rel)) is not None:
+        return facts
+    facts = _extract(data.decode("utf-8", errors="replace"), rel, language, digest)
+    _store(digest, facts)
+    return facts
+
+
+def index_tree(root: Path) -> dict[str, FileFacts]:
+    """Facts for every supported source file under *root*."""
+    index: dict[str, FileFacts] = {}
+    for path in iter_files(root, suffixes=LANGUAGES, max_files=_MAX_FILES):
+        if (facts := file_facts(path, root)) is not None:
+            index[facts.path] = facts
+    return index
@@ -0,0 +1,226 @@
+"""azathoth.core.sync — fetch, pull and push with upstream tracking.
+
+Public surface:
+  - ``tracking(cwd)``                              → ``TrackingState``
+  - ``fetch(remote, prune, cwd, dry_run)``         → ``SyncResult``
+  - ``pull(rebase, remote, branch, cwd, dry_run)`` → ``SyncResult``
+  - ``push(remote, branch, set_upstream, force_with_lease, …)`` → ``SyncResult``
+
+Every result carries the current branch's tracking state before and after
+the operation (upstream, ahead, behind), so an agent sees at once whether
+a fetch found new commits, a pull brought them in or a push published
+everything.  Ahead/behind counts are relative to the last fetch.
+
+``push`` targets the branch's upstream remote; a branch without one needs
+``set_upstream=True`` (pushed to ``origin`` unless *remote* says
+otherwise).  ``force_with_lease`` is refused on protected branches.
+``pull`` rebases by default; with ``rebase=False`` it merges.  A pull that
+stops on conflicts says so — ``list_conflicts`` and ``continue_rebase``
+take over from there.
+
+*remote* and *branch* are refused when they look like options
+(``--upload-pack=…`` or ``--receive-pack=…`` would make git run a command),
+and go after ``--`` in the git argv.  *branch* must be a plain branch name:
+a refspec such as ``HEAD:main`` or ``+main`` would slip past the protected
+branch checks.
+"""
+
+from __future__ import annotations
+
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.branches import list_branches
+from azathoth.core.conflicts import operation_in_progress
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.policy import is_protected_branch
+from azathoth.core.workflow import _run_git, format_command
+
+DEFAULT_REMOTE = "origin"
+
+
+class TrackingState(BaseModel, frozen=True):
+    branch: str | None = Field(description="None when HEAD is detached")
+    upstream: str | None = None
+    ahead: int = 0
+    behind: int = 0
+    upstream_gone: bool = False
+
+
+class SyncResult(BaseModel, frozen=True):
+    """Outcome (or dry-run plan) of a fetch, pull or push."""
+
+    action: Literal["fetch", "pull", "push"]
+    done: bool = Field(description="False for a dry run")
+    before: TrackingState
+    after: TrackingState
+    output: str = ""
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+async def tracking(cwd: str | None = None) -> TrackingState:
+    """Upstream and ahead/behind counts of the checked-out branch."""
+    for branch in await list_branches(cwd):
+        if branch.current:
+            return TrackingState(
+                branch=branch.name,
+                upstream=branch.upstream,
+                ahead=branch.ahead,
+                behind=branch.behind,
+                upstream_gone=branch.upstream_gone,
+            )
+    return TrackingState(branch=None)
+
+
+def _positional(kind: str, value: str) -> str:
+    """*value* stripped, for use as a positional git argument.
+
+    Raises:
+        WorkflowError: If *value* is empty or looks like an option.
+    """
+    if not value.strip() or value.lstrip().startswith("-"):
+        raise WorkflowError(f"Invalid {kind} '{value}'.")
+    return value.strip()
+
+
+async def _branch_name(branch: str, cwd: str | None) -> str:
+    """*branch* as a plain branch name, never a refspec (``HEAD:main``, ``+main``).
+
+    Raises:
+        WorkflowError: If *branch* looks like an option or is not a valid
+            branch name.
+    """
+    branch = _positional("branch", branch)
+    code, _, _ = await _run_git(["check-ref-format", "--branch", branch], cwd=cwd)
+    if code != 0 or branch.startswith("+"):
+        raise WorkflowError(
+            f"Invalid branch '{branch}'; pass a branch name, not a refspec."
+        )
+    return branch
+
+
+async def _branch_remote(branch: str, cwd: str | None) -> str | None:
+    code, out, _ = await _run_git(
+        ["config", "--get", f"branch.{branch}.remote"], cwd=cwd
+    )
+    return out if code == 0 and out else None
+
+
+async def _run(
+    action: Literal["fetch", "pull", "push"],
+    args: list[str],
+    before: TrackingState,
+    cwd: str | None,
+    dry_run: bool,
+) -> SyncResult:
+    if dry_run:
+        return SyncResult(
+            action=action,
+            done=False,
+            before=before,
+            after=before,
+            commands=[format_command(["git", *args])],
+        )
+    code, out, err = await _run_git(args, cwd=cwd)
+    if code != 0:
+        if action == "pull" and await operation_in_progress(cwd):
+            raise WorkflowError(
+                f"git pull stopped on conflicts: {err or out}\n"
+                "Resolve them (list_conflicts, resolve_conflict) and continue "
+                "(continue_rebase), or abort (abort_merge)."
+            )
+        raise WorkflowError(f"git {action} failed: {err or out}")
+    # Progress and ref updates go to stderr; keep both.
+    output = "\n".join(filter(None, [out, err]))
+    return SyncResult(
+        action=action,
+        done=True,
+        before=before,
+        after=await tracking(cwd),
+        output=output,
+    )
+
+
+async def fetch(
+    remote: str | None = None,
+    prune: bool = False,
+    cwd: str | None = None,
+    dry_run: bool = False,
+) -> SyncResult:
+    """Fetch *remote* (default: every remote) and report the new counts.
+
+    Raises:
+        WorkflowError: If *remote* looks like an option, or the fetch fails.
+    """
+    args = ["fetch", "--tags", *(["--prune"] if prune else [])]
+    args += ["--", _positional("remote", remote)] if remote else ["--all"]
+    return await _run("fetch", args, await tracking(cwd), cwd, dry_run)
+
+
+async def pull(
+    rebase: bool = True,
+    remote: str | None = None,
+    branch: str | None = None,
+    cwd: str | None = None,
+    dry_run: bool = False,
+) -> SyncResult:
+    """Pull the upstream (or *remote*/*branch*) into the current branch.
+
+    Raises:
+        WorkflowError: If HEAD is detached, there is nothing to pull from,
+            *remote* or *branch* looks like an option, or the pull fails or
+            stops on conflicts.
+    """
+    if remote and branch:
+        remote = _positional("remote", remote)
+        branch = await _branch_name(branch, cwd)
+    before = await tracking(cwd)
+    if before.branch is None:
+        raise WorkflowError("HEAD is detached; switch to a branch before pulling.")
+    if before.upstream is None and not (remote and branch):
+        raise WorkflowError(
+            f"'{before.branch}' has no upstream; pass remote and branch, or push "
+            "it with set_upstream=True first."
+        )
+    args = ["pull", "--rebase" if rebase else "--no-rebase"]
+    if remote and branch:
+        args += ["--", remote, branch]
+    return await _run("pull", args, before, cwd, dry_run)
+
+
+async def push(
+    remote: str | None = None,
+    branch: str | None = None,
+    set_upstream: bool = False,
+    force_with_lease: bool = False,
+    cwd: str | None = None,
+    dry_run: bool = False,
+) -> SyncResult:
+    """Push *branch* (default: the current one) and report the new counts.
+
+    Raises:
+        WorkflowError: If HEAD is detached, the branch has no upstream and
+            *set_upstream* is not set, *remote* looks like an option,
+            *branch* is not a plain branch name, a protected branch would be
+            force-pushed, or the push is rejected.
+    """
+    remote = _positional("remote", remote) if remote else None
+    branch = await _branch_name(branch, cwd) if branch else None
+    before = await tracking(cwd)
+    branch = branch or before.branch
+    if branch is None:
+        raise WorkflowError("HEAD is detached; pass the branch to push.")
+    if force_with_lease and is_protected_branch(branch):
+        raise WorkflowError(f"'{branch}' is protected and cannot be force-pushed.")
+    configured = await _branch_remote(branch, cwd)
+    if configured is None and not set_upstream:
+        raise WorkflowError(
+            f"'{branch}' has no upstream; pass set_upstream=True to push it to "
+            f"{remote or DEFAULT_REMOTE} and track it."
+        )
+    args = ["push"]
+    args += ["--set-upstream"] if set_upstream else []
+    args += ["--force-with-lease"] if force_with_lease else []
+    args += ["--", remote or configured or DEFAULT_REMOTE, branch]
+    return await _run("push", args, before, cwd, dry_run)
@@ -0,0 +1,217 @@
+"""azathoth.core.tags — create, list and delete tags.
+
+Public surface:
+  - ``tag_command(name, message, kind, ref)``         → ``git tag`` argv
+  - ``semver_key(name)``                              → sort key, semver order
+  - ``list_tags(pattern, cwd)``                       → ``[TagInfo]``, newest first
+  - ``create_tag(name, message, kind, ref, cwd, …)``  → ``GitResult``
+  - ``delete_tag(name, remote, confirm, cwd, …)``     → ``GitResult``
+
+Tags are ``annotated`` (``git tag -a``, with a message, tagger and date),
+``signed`` (``git tag -s``, annotated plus a GPG/SSH/X.509 signature from
+the same key setup commits use) or ``lightweight`` (a bare ref).  Releases
+are tagged ``release_tag_kind``, annotated by default, so ``git describe``
+and forges see who tagged them and when.
+
+``list_tags`` orders semantic versions by precedence (``v1.10.0`` after
+``v1.9.0``, ``v2.0.0-rc.1`` before ``v2.0.0``), newest first; other tags
+follow by name.  Deleting a tag needs ``confirm=True``, since a pushed tag
+may already be fetched, built or released from; ``remote=True`` deletes it
+on ``origin`` as well.
+"""
+
+from __future__ import annotations
+
+import re
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.signing import classify_signing_failure
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.workflow import GitResult, _run_git, ensure_revision, planned
+
+TagKind = Literal["annotated", "signed", "lightweight"]
+
+_SEMVER = re.compile(
+    r"^v?(\d+)\.(\d+)\.(\d+)(?:-([0-9A-Za-z.-]+))?(?:\+[0-9A-Za-z.-]+)?$"
+)
+_FIELD = "%1f"  # for-each-ref hex escape
+_RECORD = "%1e"
+_FORMAT = _FIELD.join(
+    [
+        "%(refname:short)",
+        "%(objectname)",
+        "%(*objectname)",
+        "%(creatordate:iso-strict)",
+        "%(contents:subject)",
+        "%(contents:signature)",
+        # Never empty, so stripping the output cannot eat a separator.
+        "%(objecttype)",
+    ]
+)
+
+
+class TagInfo(BaseModel, frozen=True):
+    name: str
+    sha: str = Field(description="The tagged commit")
+    kind: TagKind
+    version: str | None = Field(None, description="Semantic version, 'v' dropped")
+    date: str = ""
+    subject: str = Field("", description="Tag message (or commit) subject")
+
+
+def semver_key(name: str) -> tuple:
+    """Sort key ordering tags by semver precedence; non-versions sort first."""
+    match = _SEMVER.match(name)
+    if not match:
+        return (0, name)
+    major, minor, patch, pre = match.groups()
+    # A pre-release precedes its release; numeric identifiers precede others.
+    identifiers = tuple(
+        (0, int(part), "") if part.isdigit() else (1, 0, part)
+        for part in (pre or "").split(".")
+        if part
+    )
+    return (1, int(major), int(minor), int(patch), pre is None, identifiers)
+
+
+def tag_command(
+    name: str,
+    message: str | None = None,
+    kind: TagKind = "annotated",
+    ref: str | None = None,
+) -> list[str]:
+    """``git tag`` argv for a *kind* tag (the message defaults to *name*).
+
+    Raises:
+        WorkflowError: If *name* or *ref* is empty or looks like an option.
+    """
+    name = ensure_revision(name)
+    ref = ensure_revision(ref) if ref else None
+    if kind == "lightweight":
+        args = ["tag", name]
+    else:
+        flag = "-s" if kind == "signed" else "-a"
+        args = ["tag", flag, name, "-m", message or name]
+    return [*args, ref] if ref else args
+
+
+def _fail(message: str) -> GitResult:
+    return GitResult(success=False, stdout="", stderr=message, message=message)
+
+
+async def _tag_exists(name: str, cwd: str | None) -> bool:
+    code, _, _ = await _run_git(
+        ["rev-parse", "--verify", "--quiet", f"refs/tags/{name}"], cwd=cwd
+    )
+    return code == 0
+
+
+async def list_tags(
+    pattern: str | None = None, cwd: str | None = None
+) -> list[TagInfo]:
+    """Tags (matching the glob *pattern*, e.g. ``v1.*``), newest first."""
+    args = ["for-each-ref", f"--format={_FORMAT}{_RECORD}"]
+    args.append(f"refs/tags/{pattern}" if pattern else "refs/tags")
+    code, out, _ = await _run_git(args, cwd=cwd)
+    if code != 0:
+        return []
+    tags = []
+    for record in filter(None, (r.strip("\n") for r in out.split("\x1e"))):
+        name, sha, peeled, date, subject, signature, kind = record.split("\x1f")
+        annotated = kind == "tag"
+        match = _SEMVER.match(name)
+        tags.append(
+            TagInfo(
+                name=name,
+                sha=peeled if annotated and peeled else sha,
+                kind=(
+                    ("signed" if signature.strip() else "annotated")
+                    if annotated
+                    else "lightweight"
+                ),
+                version=name.removeprefix("v") if match else None,
+                date=date,
+                subject=subject,
+            )
+        )
+    versions = sorted(
+        (t for t in tags if t.version), key=lambda t: semver_key(t.name), reverse=True
+    )
+    others = sorted((t for t in tags if not t.version), key=lambda t: t.name)
+    return [*versions, *others]
+
+
+async def create_tag(
+    name: str,
+    message: str | None = None,
+    kind: TagKind = "annotated",
+    ref: str | None = None,
+    cwd: str | None = None,
+    dry_run: bool = False,
+) -> GitResult:
+    """Tag *ref* (default HEAD) as *name*; refuses a name already in use."""
+    try:
+        args = tag_command(name, message, kind, ref)
+    except WorkflowError as exc:
+        return _fail(str(exc))
+    code, _, _ = await _run_git(["check-ref-format", f"refs/tags/{name}"], cwd=cwd)
+    if code != 0:
+        return _fail(f"'{name}' is not a valid tag name.")
+    if await _tag_exists(name, cwd):
+        return _fail(f"Tag '{name}' already exists; delete it first to move it.")
+    if dry_run:
+        return planned(["git", *args])
+
+    code, out, err = await _run_git(args, cwd=cwd)
+    if code == 0:
+        return GitResult(success=True, stdout=out, stderr=err)
+    failure = classify_signing_failure(err) if kind == "signed" else None
+    return GitResult(
+        success=False,
+        stdout=out,
+        stderr=err,
+        message=failure.render() if failure else None,
+    )
+
+
+async def delete_tag(
+    name: str,
+    remote: bool = False,
+    confirm: bool = False,
+    cwd: str | None = None,
+    dry_run: bool = False,
+) -> GitResult:
+    """Delete tag *name* locally and, with *remote*, on ``origin``.
+
+    Without *confirm* nothing is deleted and the result says what would be.
+    """
+    try:
+        name = ensure_revision(name)
+    except WorkflowError as exc:
+        return _fail(str(exc))
+    exists = await _tag_exists(name, cwd)
+    if not exists and not remote:
+        return _fail(f"Tag '{name}' does not exist.")
+    commands = [["tag", "-d", name]] if exists else []
+    if remote:
+        commands.append(["push", "origin", "--delete", f"refs/tags/{name}"])
+    if dry_run:
+        return planned(*(["git", *args] for args in commands))
+    if not confirm:
+        where = "locally and on origin" if remote else "locally"
+        return _fail(
+            f"Deleting '{name}' {where} needs confirm=True; anyone who fetched "
+            "the tag keeps it."
+        )
+
+    outputs = []
+    for args in commands:
+        code, out, err = await _run_git(args, cwd=cwd)
+        if code != 0:
+            return GitResult(
+                success=False, stdout="\n".join([*outputs, out]), stderr=err
+            )
+        outputs.append(out)
+    return GitResult(success=True, stdout="\n".join(filter(None, outputs)), stderr="")
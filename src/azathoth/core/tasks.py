@@ -0,0 +1,230 @@
+"""azathoth.core.tasks — the repo's own task definitions, and running them.
+
+Public surface:
+  - ``make_targets(root)`` / ``just_recipes(root)`` → names defined at *root*
+  - ``discover_tasks(root)``                        → ``[Task]``
+  - ``resolve_task(name, runner, root)``            → the one matching ``Task``
+  - ``run_task(task, timeout, cwd)``                → ``TaskResult``
+  - ``run_argv(argv, timeout, cwd)``                → ``CommandResult``
+
+Only entry points the project itself declares are runnable — Makefile
+targets, justfile recipes, package.json scripts and cargo aliases — and
+they are executed as a fixed argv (``make build``, ``npm run lint``), never
+through a shell and never with caller-supplied arguments.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import json
+import re
+import time
+import tomllib
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel
+
+from azathoth.core.audit import record_command
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.host import kill_tree, resolve_argv, session_kwargs
+from azathoth.core.progress import collect
+
+#: ``npm`` covers package.json scripts whichever package manager runs them.
+TaskRunner = Literal["make", "just", "npm", "cargo"]
+
+#: Output beyond this many characters is cut from the front (the tail,
+#: where errors usually are, is kept).
+OUTPUT_LIMIT = 20_000
+
+_JUST_RECIPE = re.compile(r"^@?([A-Za-z0-9_-]+)(?:\s[^:]*)?:(?!=)")
+_MAKE_TARGET = re.compile(r"^([A-Za-z0-9_.-]+)\s*:(?!=)")
+_JS_LOCKFILES = (
+    ("pnpm-lock.yaml", "pnpm"),
+    ("yarn.lock", "yarn"),
+    ("bun.lockb", "bun"),
+    ("bun.lock", "bun"),
+)
+
+
+class Task(BaseModel, frozen=True):
+    """A runnable entry point declared by the repository."""
+
+    name: str
+    runner: TaskRunner
+    argv: list[str]
+    source: str
+
+
+class CommandResult(BaseModel, frozen=True):
+    exit_code: int | None
+    stdout: str
+    stderr: str
+    duration_s: float
+    timed_out: bool = False
+    truncated: bool = False
+
+    @property
+    def success(self) -> bool:
+        return self.exit_code == 0 and not self.timed_out
+
+
+class TaskResult(CommandResult, frozen=True):
+    task: Task
+
+
+# ── Discovery ─────────────────────────────────────────────────────────────────
+
+
+def _targets(path: Path, pattern: re.Pattern[str]) -> set[str]:
+    if not path.is_file():
+        return set()
+    found = set()
+    for line in path.read_text(encoding="utf-8", errors="ignore").splitlines():
+        if line[:1].isspace() or line.startswith("#"):
+            continue
+        if match := pattern.match(line):
+            found.add(match.group(1))
+    return found
+
+
+def make_targets(root: Path) -> set[str]:
+    """Explicit Makefile targets (special ``.TARGETS`` excluded)."""
+    targets = _targets(root / "Makefile", _MAKE_TARGET)
+    return {t for t in targets if not t.startswith(".")}
+
+
+def just_recipes(root: Path) -> set[str]:
+    return _targets(root / "justfile", _JUST_RECIPE) | _targets(
+        root / "Justfile", _JUST_RECIPE
+    )
+
+
+def _js_runner(root: Path) -> str:
+    for lockfile, runner in _JS_LOCKFILES:
+        if (root / lockfile).is_file():
+            return runner
+    return "npm"
+
+
+def _cargo_aliases(root: Path) -> tuple[set[str], str]:
+    for rel in (".cargo/config.toml", ".cargo/config"):
+        path = root / rel
+        if path.is_file():
+            try:
+                with open(path, "rb") as f:
+                    return set(tomllib.load(f).get("alias", {})), rel
+            except tomllib.TOMLDecodeError:
+                return set(), rel
+    return set(), ""
+
+
+def discover_tasks(root: Path) -> list[Task]:
+    """Every task declared at *root*, grouped by runner then sorted by name."""
+    tasks = [
+        Task(name=t, runner="make", argv=["make", t], source="Makefile")
+        for t in sorted(make_targets(root))
+    ]
+    tasks += [
+        Task(name=r, runner="just", argv=["just", r], source="justfile")
+        for r in sorted(just_recipes(root))
+    ]
+
+    package_json = root / "package.json"
+    if package_json.is_file():
+        try:
+            scripts = json.loads(package_json.read_text(encoding="utf-8")).get(
+                "scripts", {}
+            )
+        except json.JSONDecodeError:
+            scripts = {}
+        runner = _js_runner(root)
+        tasks += [
+            Task(
+                name=s, runner="npm", argv=[runner, "run", s], source="package.json"
+            )
+            for s in sorted(scripts)
+        ]
+
+    aliases, source = _cargo_aliases(root)
+    tasks += [
+        Task(name=a, runner="cargo", argv=["cargo", a], source=source)
+        for a in sorted(aliases)
+    ]
+    return tasks
+
+
+def resolve_task(name: str, runner: str | None, root: Path) -> Task:
+    """Find the declared task called *name* (optionally for one *runner*).
+
+    Raises:
+        WorkflowError: If no task or more than one task matches.
+    """
+    tasks = discover_tasks(root)
+    matches = [
+        t for t in tasks if t.name == name and (runner is None or t.runner == runner)
+    ]
+    if len(matches) == 1:
+        return matches[0]
+    if not matches:
+        available = ", ".join(f"{t.runner}:{t.name}" for t in tasks) or "none"
+        raise WorkflowError(
+            f"'{name}' is not a task declared by this repo. Available: {available}"
+        )
+    runners = ", ".join(t.runner for t in matches)
+    raise WorkflowError(f"'{name}' is defined for {runners}; pass a runner.")
+
+
+# ── Execution ─────────────────────────────────────────────────────────────────
+
+
+def _tail(text: str) -> tuple[str, bool]:
+    if len(text) <= OUTPUT_LIMIT:
+        return text, False
+    return f"…(truncated)\n{text[-OUTPUT_LIMIT:]}", True
+
+
+async def run_task(task: Task, timeout: float, cwd: str | None = None) -> TaskResult:
+    """Run *task* without a shell, killing it after *timeout* seconds."""
+    result = await run_argv(task.argv, timeout, cwd)
+    return TaskResult(task=task, **result.model_dump())
+
+
+async def run_argv(
+    argv: list[str], timeout: float, cwd: str | None = None
+) -> CommandResult:
+    """Run *argv* without a shell, killing it after *timeout* seconds."""
+    record_command(argv)
+    started = time.perf_counter()
+    try:
+        process = await asyncio.create_subprocess_exec(
+            *resolve_argv(argv),
+            stdin=asyncio.subprocess.DEVNULL,
+            stdout=asyncio.subprocess.PIPE,
+            stderr=asyncio.subprocess.PIPE,
+            cwd=cwd,
+            **session_kwargs(),  # so a timeout kills make's children too
+        )
+    except FileNotFoundError as exc:
+        return CommandResult(
+            exit_code=127, stdout="", stderr=str(exc), duration_s=0.0
+        )
+
+    timed_out = False
+    try:
+        stdout, stderr = await asyncio.wait_for(collect(process), timeout)
+    except asyncio.TimeoutError:
+        timed_out = True
+        await kill_tree(process)
+        stdout, stderr = await process.communicate()
+
+    out, out_cut = _tail(stdout.decode(errors="replace"))
+    err, err_cut = _tail(stderr.decode(errors="replace"))
+    return CommandResult(
+        exit_code=None if timed_out else process.returncode,
+        stdout=out,
+        stderr=err,
+        duration_s=round(time.perf_counter() - started, 2),
+        timed_out=timed_out,
+        truncated=out_cut or err_cut,
+    )
@@ -0,0 +1,64 @@
+"""azathoth.core.templates — prompt templates loaded from Jinja files.
+
+Public surface:
+  - ``TEMPLATE_SUFFIX``                       — ``.md.j2``
+  - ``template_dirs()``                       → directories searched, in order
+  - ``find_template(name)``                   → path of *name*'s file, or ``None``
+  - ``render_prompt(name, default, **vars)``  → the rendered prompt
+
+Long prompts (``explore``, ``autocommit``, ``autorelease`` …) are Jinja
+templates so their wording can be edited without touching code.  A template
+named ``autocommit`` is looked up as ``autocommit.md.j2`` first in the
+user's ``prompts_path`` (``<config_dir>/prompts`` by default), then in the
+checkout's ``assets/prompts``.  Each caller passes its built-in source as
+*default*: it is used when no file exists, and when a file fails to parse
+or references a variable the caller does not supply, so a broken edit
+degrades to the stock prompt instead of breaking the tool.
+"""
+
+from __future__ import annotations
+
+import logging
+from pathlib import Path
+from typing import Any
+
+from jinja2 import Environment, StrictUndefined, TemplateError
+
+from azathoth.config import get_config
+
+log = logging.getLogger(__name__)
+
+TEMPLATE_SUFFIX = ".md.j2"
+
+#: Templates shipped with a source checkout (repo root ``assets/prompts``).
+ASSET_DIR = Path(__file__).resolve().parents[3] / "assets" / "prompts"
+
+_env = Environment(
+    undefined=StrictUndefined,
+    keep_trailing_newline=True,
+    autoescape=False,
+)
+
+
+def template_dirs() -> list[Path]:
+    """Directories searched for templates, highest precedence first."""
+    return [get_config().prompts_dir, ASSET_DIR]
+
+
+def find_template(name: str) -> Path | None:
+    for directory in template_dirs():
+        path = directory / f"{name}{TEMPLATE_SUFFIX}"
+        if path.is_file():
+            return path
+    return None
+
+
+def render_prompt(name: str, default: str, **variables: Any) -> str:
+    """Render template *name* with *variables*, falling back to *default*."""
+    if (path := find_template(name)) is not None:
+        try:
+            source = path.read_text(encoding="utf-8")
+            return _env.from_string(source).render(**variables)
+        except (OSError, TemplateError) as exc:
+            log.warning("Prompt template %s unusable (%s); using built-in", path, exc)
+    return _env.from_string(default).render(**variables)
@@ -0,0 +1,228 @@
+"""azathoth.core.traverse — shared directory walking for scout tools.
+
+Public surface:
+  - ``DEFAULT_SKIP_DIRS``           — directory names never descended into
+  - ``IGNORE_FILES``                — per-directory ignore files read
+  - ``IgnoreRules.for_root(root)``  → the ignore rules in effect under *root*
+  - ``iter_files(root, …)``         → files under *root*, sorted
+
+Every scout tool that scans a source tree goes through ``iter_files`` so
+that dependency, build-output and VCS directories are skipped consistently.
+On top of ``DEFAULT_SKIP_DIRS``, ``IgnoreRules`` applies the project's own
+ignore files — ``.gitignore`` and ``.ignore`` in every directory, plus
+``.git/info/exclude`` — with git's semantics (``!`` re-includes, a leading
+or inner ``/`` anchors, a trailing ``/`` matches only directories, ``**``
+spans directories; a deeper file wins over a shallower one).
+``scout_exclude`` adds patterns in the same syntax and
+``scout_respect_gitignore = false`` turns the ignore files off.
+"""
+
+from __future__ import annotations
+
+import os
+import re
+from collections.abc import Iterable, Iterator
+from dataclasses import dataclass
+from pathlib import Path
+
+from azathoth.config import get_config
+
+#: Directory names never descended into.
+DEFAULT_SKIP_DIRS: frozenset[str] = frozenset(
+    {
+        ".git",
+        ".hg",
+        ".jj",
+        ".svn",
+        ".venv",
+        "venv",
+        "__pycache__",
+        ".mypy_cache",
+        ".pytest_cache",
+        ".ruff_cache",
+        "node_modules",
+        ".svelte-kit",
+        ".next",
+        "target",
+        "dist",
+        "build",
+        ".azathoth",
+    }
+)
+
+
+#: Ignore files read in every directory, in increasing precedence.
+IGNORE_FILES = (".gitignore", ".ignore")
+
+
+def _glob_regex(pattern: str) -> str:
+    """Translate one gitignore glob (no leading ``/``) to a regex body."""
+    out: list[str] = []
+    i = 0
+    while i < len(pattern):
+        char = pattern[i]
+        if pattern.startswith("**/", i):
+            out.append("(?:.*/)?")
+            i += 3
+            continue
+        if pattern.startswith("**", i):
+            out.append(".*")
+            i += 2
+            continue
+        if char == "*":
+            out.append("[^/]*")
+        elif char == "?":
+            out.append("[^/]")
+        elif char == "[" and (end := pattern.find("]", i + 1)) > i:
+            body = pattern[i + 1 : end].replace("\\", "\\\\")
+            out.append(f"[{'^' + body[1:] if body.startswith('!') else body}]")
+            i = end + 1
+            continue
+        elif char == "\\" and i + 1 < len(pattern):
+            i += 1
+            out.append(re.escape(pattern[i]))
+        else:
+            out.append(re.escape(char))
+        i += 1
+    return "".join(out)
+
+
+@dataclass(frozen=True)
+class _Rule:
+    base: str  # directory of the ignore file, relative to the root ("" for it)
+    regex: re.Pattern[str]
+    negated: bool
+    dir_only: bool
+
+
+def _parse(lines: Iterable[str], base: str) -> list[_Rule]:
+    rules: list[_Rule] = []
+    for raw in lines:
+        line = raw.rstrip("\n").rstrip("\r")
+        if not line.endswith("\\ "):
+            line = line.rstrip(" ")
+        if not line or line.startswith("#"):
+            continue
+        negated = line.startswith("!")
+        if negated or line.startswith("\\"):
+            line = line[1:]
+        dir_only = line.endswith("/")
+        line = line.rstrip("/")
+        if not line:
+            continue
+        anchored = "/" in line
+        body = _glob_regex(line.lstrip("/"))
+        regex = re.compile(body if anchored else f"(?:.*/)?{body}")
+        rules.append(_Rule(base, regex, negated, dir_only))
+    return rules
+
+
+class IgnoreRules:
+    """Decides which paths under a root the project ignores.
+
+    Ignore files are read lazily, once per directory, so one instance
+    should serve a whole walk.
+    """
+
+    def __init__(
+        self, root: Path, extra: Iterable[str] = (), use_files: bool = True
+    ):
+        self.root = Path(os.path.abspath(root))
+        self.use_files = use_files
+        self._global = _parse(extra, "")
+        if use_files:
+            exclude = self.root / ".git" / "info" / "exclude"
+            if exclude.is_file():
+                self._global = _parse(self._read(exclude), "") + self._global
+        self._by_dir: dict[str, list[_Rule]] = {}
+
+    @classmethod
+    def for_root(cls, root: Path) -> IgnoreRules:
+        """Rules under *root* as configured (``scout_exclude`` and friends)."""
+        settings = get_config()
+        return cls(root, settings.scout_exclude, settings.scout_respect_gitignore)
+
+    @staticmethod
+    def _read(path: Path) -> list[str]:
+        try:
+            return path.read_text(encoding="utf-8", errors="replace").splitlines()
+        except OSError:
+            return []
+
+    def _rules_in(self, rel_dir: str) -> list[_Rule]:
+        if rel_dir not in self._by_dir:
+            rules: list[_Rule] = []
+            if self.use_files:
+                directory = self.root / rel_dir
+                for name in IGNORE_FILES:
+                    rules += _parse(self._read(directory / name), rel_dir)
+            self._by_dir[rel_dir] = rules
+        return self._by_dir[rel_dir]
+
+    def _rel(self, path: Path) -> str:
+        return Path(os.path.relpath(os.path.abspath(path), self.root)).as_posix()
+
+    def matches(self, path: Path, is_dir: bool) -> bool:
+        """Whether *path* itself is ignored (its parents are not checked)."""
+        rel = self._rel(path)
+        if rel == "." or rel.startswith("../"):
+            return False
+        parts = rel.split("/")
+        rules = list(self._global)
+        for depth in range(len(parts)):
+            rules += self._rules_in("/".join(parts[:depth]))
+        ignored = False
+        for rule in rules:
+            if rule.dir_only and not is_dir:
+                continue
+            sub = rel[len(rule.base) + 1 :] if rule.base else rel
+            if rule.regex.fullmatch(sub):
+                ignored = not rule.negated
+        return ignored
+
+    def ignored(self, path: Path) -> bool:
+        """Whether *path* or any directory above it (up to the root) is ignored."""
+        rel = Path(self._rel(path))
+        for depth in range(1, len(rel.parts) + 1):
+            prefix = self.root.joinpath(*rel.parts[:depth])
+            is_dir = depth < len(rel.parts) or prefix.is_dir()
+            if self.matches(prefix, is_dir):
+                return True
+        return False
+
+
+def iter_files(
+    root: Path,
+    *,
+    suffixes: Iterable[str] | None = None,
+    max_files: int | None = None,
+    ignore: IgnoreRules | None = None,
+) -> Iterator[Path]:
+    """Yield files under *root* in a stable (sorted) order.
+
+    Args:
+        root:      Directory to walk.
+        suffixes:  Only yield files with one of these suffixes (e.g. ``".py"``).
+        max_files: Stop after this many files.
+        ignore:    Rules to apply (default: ``IgnoreRules.for_root(root)``).
+    """
+    wanted = {s.lower() for s in suffixes} if suffixes is not None else None
+    ignore = ignore or IgnoreRules.for_root(root)
+    count = 0
+    for dirpath, dirnames, filenames in os.walk(root):
+        here = Path(dirpath)
+        dirnames[:] = sorted(
+            d
+            for d in dirnames
+            if d not in DEFAULT_SKIP_DIRS and not ignore.matches(here / d, True)
+        )
+        for name in sorted(filenames):
+            path = here / name
+            if wanted is not None and path.suffix.lower() not in wanted:
+                continue
+            if ignore.matches(path, False):
+                continue
+            yield path
+            count += 1
+            if max_files is not None and count >= max_files:
+                return
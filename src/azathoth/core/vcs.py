@@ -0,0 +1,79 @@
+"""azathoth.core.vcs — version-control façade.
+
+Public surface:
+  - ``detect_backend(cwd)`` → name of the backend owning the working copy
+  - ``get_vcs(cwd, backend)`` → ``Vcs`` bound to *cwd*
+
+Consumer code imports from HERE only — never from ``vcs/*`` directly.
+Selection order: the explicit *backend* argument, then ``vcs_backend`` from
+config, then detection.  Detection prefers ``.jj`` over ``.hg`` over
+``.git`` (a colocated jj repository also has ``.git``) but only picks an
+experimental backend when ``vcs_experimental_backends`` is on; otherwise
+it falls back to git.
+"""
+
+from __future__ import annotations
+
+import logging
+from pathlib import Path
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.repos import repo_dir
+from azathoth.vcs.base import Vcs
+from azathoth.vcs.registry import get_backend
+
+log = logging.getLogger(__name__)
+
+_DETECTION_ORDER = ("jj", "hg", "git")
+
+
+def _load_backends() -> None:
+    """Import backend modules so they self-register."""
+    import azathoth.vcs.git  # noqa: F401
+    import azathoth.vcs.hg  # noqa: F401
+    import azathoth.vcs.jj  # noqa: F401
+
+
+def detect_backend(cwd: str | None = None) -> str:
+    """Name of the backend whose marker is closest to *cwd*.
+
+    ``.git`` may be a file (worktrees, submodules), so markers are checked
+    with ``exists``.  Falls back to ``git`` when nothing is found (git then
+    reports the error).
+    """
+    _load_backends()
+    markers = {name: get_backend(name)(None).marker for name in _DETECTION_ORDER}
+    start = Path(cwd or repo_dir() or ".").resolve()
+    for directory in (start, *start.parents):
+        for name in _DETECTION_ORDER:
+            if (directory / markers[name]).exists():
+                return name
+    return "git"
+
+
+def get_vcs(cwd: str | None = None, backend: str | None = None) -> Vcs:
+    """Return the backend for the working copy at *cwd*.
+
+    Raises:
+        WorkflowError: If an experimental backend is requested explicitly
+            while ``vcs_experimental_backends`` is off.
+    """
+    _load_backends()
+    config = get_config()
+    requested = backend or config.vcs_backend
+    name = detect_backend(cwd) if requested == "auto" else requested
+    try:
+        vcs = get_backend(name)(cwd)
+    except KeyError as exc:
+        raise WorkflowError(str(exc.args[0])) from exc
+
+    if vcs.experimental and not config.vcs_experimental_backends:
+        if requested != "auto":
+            raise WorkflowError(
+                f"The {name} backend is experimental; set "
+                "AZATHOTH_VCS_EXPERIMENTAL_BACKENDS=true to use it."
+            )
+        log.info("Found a %s working copy; experimental backends are off", name)
+        return get_backend("git")(cwd)
+    return vcs
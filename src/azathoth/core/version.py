@@ -0,0 +1,135 @@
+"""azathoth.core.version — semantic version bumps in project manifests.
+
+Public surface:
+  - ``bump(version, level)``                 → next version string
+  - ``find_manifest(root)``                  → ``Manifest`` (path + current version)
+  - ``write_version(manifest, new_version)`` → rewrites the manifest in place
+
+Manifests are edited textually so comments, key order and formatting
+survive; only the one ``version`` value changes.  Versions inherited from
+a Cargo workspace or declared ``dynamic`` in pyproject.toml are refused —
+the number lives somewhere this module does not own.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+import tomllib
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from azathoth.core.exceptions import WorkflowError
+
+BumpLevel = Literal["major", "minor", "patch"]
+
+#: Checked in order; the first manifest with a static version wins.
+MANIFESTS = ("Cargo.toml", "package.json", "pyproject.toml")
+
+_SEMVER = re.compile(r"^(\d+)\.(\d+)\.(\d+)(?:[-+].*)?$")
+_TOML_SECTION = re.compile(r"^\s*\[([^\]]+)\]\s*(?:#.*)?$")
+_TOML_VERSION = re.compile(r"""^(\s*version\s*=\s*)(["'])([^"']*)\2""")
+
+
+class Manifest(BaseModel, frozen=True):
+    path: Path
+    kind: Literal["cargo", "npm", "python"]
+    version: str
+
+
+class VersionBump(BaseModel, frozen=True):
+    """Outcome of a version bump."""
+
+    manifest: str
+    old_version: str
+    new_version: str
+    committed: bool = False
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+def bump(version: str, level: BumpLevel) -> str:
+    """Return *version* bumped by *level*; pre-release/build suffixes are dropped.
+
+    Raises:
+        WorkflowError: If *version* is not ``MAJOR.MINOR.PATCH``.
+    """
+    match = _SEMVER.match(version.strip().removeprefix("v"))
+    if not match:
+        raise WorkflowError(f"'{version}' is not a semantic version.")
+    major, minor, patch = (int(part) for part in match.groups())
+    if level == "major":
+        return f"{major + 1}.0.0"
+    if level == "minor":
+        return f"{major}.{minor + 1}.0"
+    return f"{major}.{minor}.{patch + 1}"
+
+
+def _toml_version(path: Path, table: str) -> str | None:
+    with open(path, "rb") as f:
+        data = tomllib.load(f).get(table, {})
+    version = data.get("version")
+    if isinstance(version, dict) and version.get("workspace"):
+        raise WorkflowError(f"{path.name}: version is inherited from the workspace.")
+    if version is None and "version" in data.get("dynamic", []):
+        raise WorkflowError(f"{path.name}: version is dynamic (set by the build).")
+    return version if isinstance(version, str) else None
+
+
+def find_manifest(root: Path) -> Manifest:
+    """Locate the project manifest under *root* and read its version.
+
+    Raises:
+        WorkflowError: If no supported manifest declares a static version.
+    """
+    for name in MANIFESTS:
+        path = root / name
+        if not path.is_file():
+            continue
+        try:
+            if name == "package.json":
+                version = json.loads(path.read_text(encoding="utf-8")).get("version")
+                kind = "npm"
+            elif name == "Cargo.toml":
+                version, kind = _toml_version(path, "package"), "cargo"
+            else:
+                version, kind = _toml_version(path, "project"), "python"
+        except (json.JSONDecodeError, tomllib.TOMLDecodeError) as exc:
+            raise WorkflowError(f"Invalid {name}: {exc}") from exc
+        if version:
+            return Manifest(path=path, kind=kind, version=version)
+    raise WorkflowError(
+        f"No manifest with a version found in {root} (looked for "
+        f"{', '.join(MANIFESTS)})."
+    )
+
+
+def _replace_toml_version(text: str, table: str, new_version: str) -> str:
+    lines = text.splitlines(keepends=True)
+    section = None
+    for i, line in enumerate(lines):
+        if header := _TOML_SECTION.match(line):
+            section = header.group(1).strip()
+            continue
+        if section == table and (match := _TOML_VERSION.match(line)):
+            prefix, quote = match.group(1), match.group(2)
+            lines[i] = f"{prefix}{quote}{new_version}{quote}" + line[match.end() :]
+            return "".join(lines)
+    raise WorkflowError(f"No version key found under [{table}].")
+
+
+def write_version(manifest: Manifest, new_version: str) -> None:
+    """Rewrite the version in *manifest*, leaving everything else untouched."""
+    text = manifest.path.read_text(encoding="utf-8")
+    if manifest.kind == "npm":
+        pattern = re.compile(
+            rf'("version"\s*:\s*")({re.escape(manifest.version)})(")'
+        )
+        updated, count = pattern.subn(rf"\g<1>{new_version}\g<3>", text, count=1)
+        if not count:
+            raise WorkflowError("No version key found in package.json.")
+    else:
+        table = "package" if manifest.kind == "cargo" else "project"
+        updated = _replace_toml_version(text, table, new_version)
+    manifest.path.write_text(updated, encoding="utf-8")
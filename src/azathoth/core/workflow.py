@@ -1,8 +1,13 @@
-import asyncio
 import tempfile
 from pathlib import Path
-from typing import Optional, Tuple
-from pydantic import BaseModel
+from typing import List, Optional, Tuple
+from pydantic import BaseModel, Field
+
+from azathoth.core import gitlib, host
+from azathoth.core.audit import record_command, uncaptured
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.repos import repo_dir
+from azathoth.core.runner import current_runner
 
 
 class GitResult(BaseModel):
@@ -10,53 +15,474 @@ class GitResult(BaseModel):
     stdout: str
     stderr: str
     message: Optional[str] = None
+    commands: List[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+class FileChange(BaseModel):
+    path: str
+    status: str
+    insertions: int = 0
+    deletions: int = 0
+    binary: bool = False
+
+
+class RepoContext(BaseModel):
+    """Shape of the repository a tool is operating on."""
+
+    git_dir: str
+    root: Optional[str] = None  # working tree; None for bare repositories
+    is_bare: bool = False
+    is_shallow: bool = False
+    common_dir: Optional[str] = None  # shared by all worktrees of the repo
+
+    @property
+    def is_linked_worktree(self) -> bool:
+        """Whether the working tree was added with ``git worktree add``."""
+        return self.common_dir is not None and self.common_dir != self.git_dir
+
+    @property
+    def shape(self) -> str:
+        if self.is_bare:
+            return "bare"
+        return "shallow" if self.is_shallow else "full"
+
+
+class RepoStatus(BaseModel):
+    branch: str
+    shape: str = "full"
+    worktree: Optional[str] = None
+    linked_worktree: bool = False
+    staged: List[FileChange] = Field(default_factory=list)
+    unstaged: List[FileChange] = Field(default_factory=list)
+    untracked: List[str] = Field(default_factory=list)
+
+    @property
+    def is_clean(self) -> bool:
+        return not (self.staged or self.unstaged or self.untracked)
+
+
+class DiffSummary(BaseModel):
+    staged: bool
+    files: List[FileChange] = Field(default_factory=list)
+    insertions: int = 0
+    deletions: int = 0
+    patch: str = ""
 
 
-async def _run_git(args: list[str], cwd: Optional[str] = None) -> Tuple[int, str, str]:
+class CommitInfo(BaseModel):
+    sha: str
+    author: str
+    date: str
+    subject: str
+
+
+async def run_command(
+    argv: list[str], cwd: Optional[str] = None, env: Optional[dict[str, str]] = None
+) -> Tuple[int, str, str]:
+    """Run *argv*, returning ``(code, stdout, stderr)``.  *env* extends os.environ.
+
+    A missing executable (or *cwd*) is reported as exit code 127, not raised.
+    Without *cwd*, runs in the repository selected by ``use_repo``, if any.
+    Execution is delegated to the active ``CommandRunner`` (``core.runner``).
+    """
+    record_command(argv)
+    return await current_runner()(argv, cwd or repo_dir(), env)
+
+
+async def _run_git(
+    args: list[str], cwd: Optional[str] = None, env: Optional[dict[str, str]] = None
+) -> Tuple[int, str, str]:
     """Internal helper to run git commands."""
-    process = await asyncio.create_subprocess_exec(
-        "git",
-        *args,
-        stdout=asyncio.subprocess.PIPE,
-        stderr=asyncio.subprocess.PIPE,
+    return await run_command(["git", *args], cwd=cwd, env=env)
+
+
+def format_command(cmd: list[str]) -> str:
+    """Render an argv list as a copy-pasteable shell command."""
+    return host.join(cmd)
+
+
+def planned(*commands: list[str]) -> GitResult:
+    """Result for a dry run: lists the commands that would have been executed."""
+    rendered = [format_command(cmd) for cmd in commands]
+    return GitResult(
+        success=True,
+        stdout="\n".join(rendered),
+        stderr="",
+        message="Dry run — nothing was executed.",
+        commands=rendered,
+    )
+
+
+async def get_repo_context(cwd: Optional[str] = None) -> RepoContext:
+    """Detect whether *cwd* is a bare repository and/or a shallow clone.
+
+    Raises:
+        WorkflowError: If *cwd* is not inside a git repository.
+    """
+    code, out, err = await _run_git(
+        [
+            "rev-parse",
+            "--is-bare-repository",
+            "--is-shallow-repository",
+            "--absolute-git-dir",
+            "--path-format=absolute",
+            "--git-common-dir",
+        ],
         cwd=cwd,
     )
-    stdout, stderr = await process.communicate()
-    assert process.returncode is not None
-    return process.returncode, stdout.decode().strip(), stderr.decode().strip()
+    if code != 0:
+        raise WorkflowError(f"Not a git repository: {err}")
+    lines = out.splitlines() + [""] * 4
+    is_bare, is_shallow, git_dir, common_dir = lines[:4]
+    root = None
+    if is_bare != "true":
+        _, root, _ = await _run_git(["rev-parse", "--show-toplevel"], cwd=cwd)
+    return RepoContext(
+        git_dir=git_dir,
+        root=root or None,
+        is_bare=is_bare == "true",
+        is_shallow=is_shallow == "true",
+        common_dir=common_dir or None,
+    )
+
+
+async def _refuse_bare(cwd: Optional[str]) -> Optional[GitResult]:
+    """Failure result for working-tree operations on a bare repository."""
+    try:
+        with uncaptured():
+            context = await get_repo_context(cwd)
+    except WorkflowError:
+        return None  # let git report the underlying problem
+    if not context.is_bare:
+        return None
+    return GitResult(
+        success=False,
+        stdout="",
+        stderr=f"{context.git_dir} is a bare repository (no working tree).",
+        message="Refusing to stage or commit in a bare repository.",
+    )
+
 
+async def history_contains(ref: Optional[str], cwd: Optional[str] = None) -> bool:
+    """Whether *ref* is an ancestor of HEAD (with ``None``: any tag is reachable)."""
+    if ref is None:
+        return await get_latest_tag(cwd=cwd) is not None
+    code, _, _ = await _run_git(["merge-base", "--is-ancestor", ref, "HEAD"], cwd=cwd)
+    return code == 0
 
-async def stage_all(cwd: Optional[str] = None) -> GitResult:
-    """Stages all changes (git add .)."""
-    code, out, err = await _run_git(["add", "."], cwd=cwd)
+
+async def deepen_until(
+    ref: Optional[str],
+    cwd: Optional[str] = None,
+    step: int = 100,
+    max_rounds: int = 10,
+) -> bool:
+    """Fetch more history in a shallow clone until *ref* is an ancestor of HEAD.
+
+    With *ref* ``None`` the goal is any reachable tag.  Returns ``True``
+    once the goal is met, ``False`` if the clone became complete (or the
+    round budget ran out) without meeting it.
+    """
+    for _ in range(max_rounds):
+        if await history_contains(ref, cwd):
+            return True
+        if not (await get_repo_context(cwd)).is_shallow:
+            return False
+        code, _, err = await _run_git(
+            ["fetch", f"--deepen={step}", "--tags", "origin"], cwd=cwd
+        )
+        if code != 0:
+            raise WorkflowError(f"git fetch --deepen failed: {err}")
+    return await history_contains(ref, cwd)
+
+
+def _add_args(paths: Optional[List[str]], include_untracked: bool) -> list[str]:
+    args = ["add"] if include_untracked else ["add", "--update"]
+    return [*args, "--", *paths] if paths else [*args, "."]
+
+
+async def stage_all(
+    cwd: Optional[str] = None,
+    dry_run: bool = False,
+    paths: Optional[List[str]] = None,
+    include_untracked: bool = True,
+) -> GitResult:
+    """Stages changes (git add .).
+
+    Args:
+        paths:             Stage only these paths instead of the whole tree.
+        include_untracked: When False, only already-tracked files are staged
+                           (``git add --update``).
+    """
+    if refused := await _refuse_bare(cwd):
+        return refused
+    args = _add_args(paths, include_untracked)
+    if dry_run:
+        return planned(["git", *args])
+    code, out, err = await _run_git(args, cwd=cwd)
     return GitResult(success=(code == 0), stdout=out, stderr=err)
 
 
-async def commit(title: str, body: str, cwd: Optional[str] = None) -> GitResult:
-    """Commits with a message."""
+async def commit(
+    title: str,
+    body: str,
+    cwd: Optional[str] = None,
+    dry_run: bool = False,
+    paths: Optional[List[str]] = None,
+    no_verify: bool = False,
+    sign: bool = False,
+) -> GitResult:
+    """Commits with a message.
+
+    With *paths*, only those paths are committed (``git commit -- <paths>``);
+    anything else already staged stays staged for a later commit.
+    *no_verify* skips the pre-commit and commit-msg hooks; *sign* passes
+    ``-S`` (git also signs on its own when ``commit.gpgsign`` is set).
+    """
+    if refused := await _refuse_bare(cwd):
+        return refused
     full_msg = f"{title}\n\n{body}"
+    pathspec = ["--", *paths] if paths else []
+    verify = ["--no-verify"] if no_verify else []
+    verify += ["-S"] if sign else []
+
+    if dry_run:
+        result = planned(
+            ["git", "commit", *verify, "-F", "<message-file>", *pathspec]
+        )
+        return result.model_copy(update={"stdout": f"{result.stdout}\n\n{full_msg}"})
 
     with tempfile.NamedTemporaryFile(mode="w", delete=False, encoding="utf-8") as tmp:
         tmp.write(full_msg)
         tmp_path = tmp.name
 
     try:
-        code, out, err = await _run_git(["commit", "-F", tmp_path], cwd=cwd)
+        code, out, err = await _run_git(
+            ["commit", *verify, "-F", tmp_path, *pathspec], cwd=cwd
+        )
         return GitResult(success=(code == 0), stdout=out, stderr=err)
     finally:
         Path(tmp_path).unlink(missing_ok=True)
 
 
-async def get_diff(staged: bool = True, cwd: Optional[str] = None) -> str:
-    """Gets the current git diff."""
+async def get_diff(
+    staged: bool = True, cwd: Optional[str] = None, paths: Optional[List[str]] = None
+) -> str:
+    """Gets the current git diff, optionally limited to *paths*."""
+    if (repo := gitlib.open_repo(cwd)) is not None:
+        if (read := gitlib.read_diff(repo, staged, paths)) is not None:
+            return read[0]
     args = ["diff"]
     if staged:
         args.append("--staged")
+    if paths:
+        args += ["--", *paths]
 
     code, out, err = await _run_git(args, cwd=cwd)
     return out if code == 0 else err
 
 
+def ensure_revision(rev: str) -> str:
+    """*rev* stripped, for use as a positional git argument.
+
+    Raises:
+        WorkflowError: If *rev* is empty or looks like an option (``--output=…``
+            would make git write files).
+    """
+    if not rev.strip() or rev.lstrip().startswith("-"):
+        raise WorkflowError(f"Invalid revision '{rev}'.")
+    return rev.strip()
+
+
+async def get_range_diff(
+    revisions: str, cwd: Optional[str] = None, paths: Optional[List[str]] = None
+) -> str:
+    """The diff of a revision range (``main..HEAD``, ``v1.2.0...feature``).
+
+    Raises:
+        WorkflowError: If *revisions* looks like an option or git rejects it.
+    """
+    # The "--" keeps a mistyped range from being taken for a path.
+    args = ["diff", ensure_revision(revisions), "--", *(paths or [])]
+    code, out, err = await _run_git(args, cwd=cwd)
+    if code != 0:
+        raise WorkflowError(f"git diff {revisions} failed: {err.strip()}")
+    return out
+
+
+def _parse_numstat(output: str) -> dict[str, Tuple[int, int, bool]]:
+    """Parse `git diff --numstat` into {path: (insertions, deletions, binary)}."""
+    stats: dict[str, Tuple[int, int, bool]] = {}
+    for line in output.splitlines():
+        parts = line.split("\t", 2)
+        if len(parts) != 3:
+            continue
+        ins, dels, path = parts
+        binary = ins == "-"
+        stats[path] = (0 if binary else int(ins), 0 if binary else int(dels), binary)
+    return stats
+
+
+def _file_change(change: gitlib.LibraryChange) -> FileChange:
+    return FileChange(
+        path=change.path,
+        status=change.status,
+        insertions=change.insertions,
+        deletions=change.deletions,
+        binary=change.binary,
+    )
+
+
+async def get_repo_status(cwd: Optional[str] = None) -> RepoStatus:
+    """Structured working-tree status with per-file line counts.
+
+    Bare repositories have no working tree; their status is the branch and
+    ``shape="bare"`` with no file lists.
+    """
+    context = await get_repo_context(cwd)
+    if not context.is_bare and (repo := gitlib.open_repo(cwd)) is not None:
+        if (read := gitlib.read_status(repo)) is not None:
+            return RepoStatus(
+                branch=read.branch,
+                shape=context.shape,
+                worktree=context.root,
+                linked_worktree=context.is_linked_worktree,
+                staged=[_file_change(c) for c in read.staged],
+                unstaged=[_file_change(c) for c in read.unstaged],
+                untracked=read.untracked,
+            )
+    _, branch, _ = await _run_git(["rev-parse", "--abbrev-ref", "HEAD"], cwd=cwd)
+    if context.is_bare:
+        return RepoStatus(branch=branch, shape=context.shape)
+    # Porcelain v2 never starts a line with a blank, so it survives the
+    # whitespace stripping in _run_git (v1's " M path" would not).
+    _, porcelain, _ = await _run_git(
+        ["status", "--porcelain=v2", "--untracked-files=all", "--no-renames"],
+        cwd=cwd,
+    )
+    _, staged_stat, _ = await _run_git(
+        ["diff", "--staged", "--numstat", "--no-renames"], cwd=cwd
+    )
+    _, unstaged_stat, _ = await _run_git(
+        ["diff", "--numstat", "--no-renames"], cwd=cwd
+    )
+    staged_counts = _parse_numstat(staged_stat)
+    unstaged_counts = _parse_numstat(unstaged_stat)
+
+    status = RepoStatus(
+        branch=branch,
+        shape=context.shape,
+        worktree=context.root,
+        linked_worktree=context.is_linked_worktree,
+    )
+    for line in porcelain.splitlines():
+        kind = line[:1]
+        if kind == "?":
+            status.untracked.append(line[2:])
+            continue
+        if kind == "1":
+            fields = line.split(" ", 8)
+        elif kind == "u":
+            fields = line.split(" ", 10)
+        else:
+            continue
+        xy, path = fields[1], fields[-1]
+        x, y = xy[0], xy[1]
+        if x != ".":
+            ins, dels, binary = staged_counts.get(path, (0, 0, False))
+            status.staged.append(
+                FileChange(
+                    path=path, status=x, insertions=ins, deletions=dels, binary=binary
+                )
+            )
+        if y != ".":
+            ins, dels, binary = unstaged_counts.get(path, (0, 0, False))
+            status.unstaged.append(
+                FileChange(
+                    path=path, status=y, insertions=ins, deletions=dels, binary=binary
+                )
+            )
+    return status
+
+
+async def get_diff_summary(
+    staged: bool = True, cwd: Optional[str] = None
+) -> DiffSummary:
+    """Diff with per-file insertion/deletion counts alongside the raw patch."""
+    if (repo := gitlib.open_repo(cwd)) is not None:
+        if (read := gitlib.read_diff(repo, staged)) is not None:
+            patch, changes = read
+            files = [_file_change(c) for c in changes]
+            return DiffSummary(
+                staged=staged,
+                files=files,
+                insertions=sum(f.insertions for f in files),
+                deletions=sum(f.deletions for f in files),
+                patch=patch,
+            )
+    base = ["diff", "--staged"] if staged else ["diff"]
+    _, numstat, _ = await _run_git([*base, "--numstat", "--no-renames"], cwd=cwd)
+    _, name_status, _ = await _run_git(
+        [*base, "--name-status", "--no-renames"], cwd=cwd
+    )
+    patch = await get_diff(staged=staged, cwd=cwd)
+
+    counts = _parse_numstat(numstat)
+    files = []
+    for line in name_status.splitlines():
+        code, _, path = line.partition("\t")
+        if not path:
+            continue
+        ins, dels, binary = counts.get(path, (0, 0, False))
+        files.append(
+            FileChange(
+                path=path, status=code, insertions=ins, deletions=dels, binary=binary
+            )
+        )
+    return DiffSummary(
+        staged=staged,
+        files=files,
+        insertions=sum(f.insertions for f in files),
+        deletions=sum(f.deletions for f in files),
+        patch=patch,
+    )
+
+
+async def get_log_entries(
+    rev_range: Optional[str] = None, limit: int = 20, cwd: Optional[str] = None
+) -> List[CommitInfo]:
+    """Structured commit list (newest first) for a revision range, or HEAD.
+
+    A repository without commits yet has an empty log.
+
+    Raises:
+        WorkflowError: If *rev_range* looks like an option, or ``git log``
+            fails (an unknown revision, say).
+    """
+    if rev_range is not None:
+        rev_range = ensure_revision(rev_range)
+    if (repo := gitlib.open_repo(cwd)) is not None:
+        if (read := gitlib.read_log(repo, rev_range, limit)) is not None:
+            return [
+                CommitInfo(sha=sha, author=author, date=date, subject=subject)
+                for sha, author, date, subject in read
+            ]
+    args = ["log", f"--max-count={limit}", "--pretty=format:%H%x1f%an%x1f%aI%x1f%s"]
+    if rev_range:
+        args.append(rev_range)
+    code, out, err = await _run_git(args, cwd=cwd)
+    if code != 0:
+        unborn, _, _ = await _run_git(["rev-parse", "--verify", "-q", "HEAD"], cwd=cwd)
+        if rev_range is None and unborn != 0:
+            return []
+        raise WorkflowError(f"git log failed: {err.strip()}")
+    entries = []
+    for line in out.splitlines():
+        sha, author, date, subject = (line.split("\x1f") + ["", "", ""])[:4]
+        entries.append(CommitInfo(sha=sha, author=author, date=date, subject=subject))
+    return entries
+
+
 async def get_latest_tag(cwd: Optional[str] = None) -> Optional[str]:
     """Gets the most recent git tag."""
     code, out, err = await _run_git(["describe", "--tags", "--abbrev=0"], cwd=cwd)
@@ -72,45 +498,23 @@ async def get_log_since(tag: str, cwd: Optional[str] = None) -> str:
 
 
 async def create_release(
-    tag: str, notes: str, is_prerelease: bool = False
+    tag: str,
+    notes: str,
+    is_prerelease: bool = False,
+    dry_run: bool = False,
+    cwd: Optional[str] = None,
+    assets: Optional[List[str]] = None,
 ) -> GitResult:
     """
-    Creates a release using 'gh' CLI.
+    Tags, pushes and publishes a release on the detected forge (see core/release.py).
     """
-    # First, tag and push
-    t_code, t_out, t_err = await _run_git(["tag", tag])
-    if t_code != 0:
-        return GitResult(
-            success=False, stdout=t_out, stderr=t_err, message="Tagging failed"
-        )
+    from azathoth.core import release  # late import — release imports this module
 
-    p_code, p_out, p_err = await _run_git(["push", "origin", tag])
-    if p_code != 0:
-        return GitResult(
-            success=False, stdout=p_out, stderr=p_err, message="Pushing tag failed"
-        )
-
-    # Use gh CLI
-    cmd = [
-        "gh",
-        "release",
-        "create",
+    return await release.create_release(
         tag,
-        "--notes",
         notes,
-        "--title",
-        f"Release {tag}",
-    ]
-    if is_prerelease:
-        cmd.append("--prerelease")
-
-    process = await asyncio.create_subprocess_exec(
-        *cmd, stdout=asyncio.subprocess.PIPE, stderr=asyncio.subprocess.PIPE
-    )
-    stdout, stderr = await process.communicate()
-
-    return GitResult(
-        success=(process.returncode == 0),
-        stdout=stdout.decode().strip(),
-        stderr=stderr.decode().strip(),
+        is_prerelease=is_prerelease,
+        dry_run=dry_run,
+        cwd=cwd,
+        assets=assets or (),
     )
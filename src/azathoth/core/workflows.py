@@ -0,0 +1,299 @@
+"""azathoth.core.workflows — multi-step workflows defined in YAML.
+
+Public surface:
+  - ``WORKFLOW_SUFFIXES``            — ``.yaml``, ``.yml``
+  - ``PROMPT_STEPS``                 — prompts a step may embed
+  - ``workflow_dirs(start)``         → directories searched, in order
+  - ``parse_workflow(text, source)`` → ``WorkflowDefinition``
+  - ``list_workflows(start)``        → ``[WorkflowDefinition]``
+  - ``load_workflow(name, start)``   → ``WorkflowDefinition``
+  - ``plan_workflow(definition, inputs)`` → ``WorkflowPlan``
+
+A workflow is a named sequence of steps, each calling one tool or embedding
+one of the stock prompts, e.g. preflight → autocommit → suggest_next_version
+→ autorelease::
+
+    name: ship
+    description: Check the tree, commit and optionally release.
+    inputs:
+      release: {description: Publish a release too, default: false}
+    steps:
+      - id: preflight
+        tool: preflight
+        instructions: Fix every error and call it again until ok is true.
+      - id: commit
+        prompt: autocommit
+      - id: release
+        prompt: autorelease
+        when: release
+        with: {version: "{{ version }}"}
+
+Definitions are looked up as ``<name>.yaml`` in the repository's
+``.azathoth/workflows``, then the user's ``workflows_path``
+(``<config_dir>/workflows``), then the checkout's ``assets/workflows``.
+
+``with`` values and ``instructions`` are Jinja templates over the inputs;
+a value that is a single ``{{ expression }}`` keeps the expression's type.
+``when`` is a Jinja expression decided while planning — a false one drops
+the step — whereas ``condition`` is prose the agent checks while running
+(``"preflight reported warnings"``).  ``on_failure`` is ``stop`` (default)
+or ``continue``.  Templates run in Jinja's sandbox, since definitions can
+come from the repository being worked on.
+"""
+
+from __future__ import annotations
+
+import re
+from pathlib import Path
+from typing import Any, Literal
+
+import yaml
+from jinja2 import StrictUndefined, TemplateError
+from jinja2.sandbox import SandboxedEnvironment
+from pydantic import BaseModel, ConfigDict, Field, ValidationError, model_validator
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.repo_config import find_repo_root
+
+WORKFLOW_SUFFIXES = (".yaml", ".yml")
+PROMPT_STEPS = (
+    "autocommit",
+    "autofix",
+    "autorelease",
+    "autotriage",
+    "explore",
+    "review",
+)
+
+#: Workflows shipped with a source checkout (repo root ``assets/workflows``).
+ASSET_DIR = Path(__file__).resolve().parents[3] / "assets" / "workflows"
+REPO_WORKFLOW_DIR = Path(".azathoth") / "workflows"
+
+_env = SandboxedEnvironment(undefined=StrictUndefined, autoescape=False)
+_FULL_EXPRESSION = re.compile(r"^\{\{(.+)\}\}$", re.DOTALL)
+_ID = re.compile(r"^[A-Za-z][\w-]*$")
+
+
+class WorkflowInput(BaseModel, frozen=True):
+    model_config = ConfigDict(extra="forbid")
+
+    description: str = ""
+    default: Any = None
+    required: bool = False
+
+
+class WorkflowStep(BaseModel, frozen=True):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    id: str
+    title: str | None = None
+    tool: str | None = None
+    prompt: str | None = None
+    arguments: dict[str, Any] = Field(default_factory=dict, alias="with")
+    instructions: str = ""
+    when: str | bool | None = Field(None, description="Jinja expression over inputs")
+    condition: str | None = Field(None, description="Checked by the agent at run time")
+    on_failure: Literal["stop", "continue"] = "stop"
+
+    @model_validator(mode="after")
+    def _one_action(self) -> WorkflowStep:
+        if not _ID.match(self.id):
+            raise ValueError(f"step id '{self.id}' must be a word (letters, -, _)")
+        if (self.tool is None) == (self.prompt is None):
+            raise ValueError(f"step '{self.id}' needs exactly one of tool or prompt")
+        if self.prompt is not None and self.prompt not in PROMPT_STEPS:
+            raise ValueError(
+                f"step '{self.id}': unknown prompt '{self.prompt}' "
+                f"(one of {', '.join(PROMPT_STEPS)})"
+            )
+        return self
+
+
+class WorkflowDefinition(BaseModel, frozen=True):
+    model_config = ConfigDict(extra="forbid")
+
+    name: str
+    description: str = ""
+    inputs: dict[str, WorkflowInput] = Field(default_factory=dict)
+    steps: list[WorkflowStep] = Field(min_length=1)
+    source: str | None = Field(None, description="File it was loaded from")
+
+    @model_validator(mode="after")
+    def _unique_ids(self) -> WorkflowDefinition:
+        seen: set[str] = set()
+        for step in self.steps:
+            if step.id in seen:
+                raise ValueError(f"duplicate step id '{step.id}'")
+            seen.add(step.id)
+        return self
+
+
+class PlannedStep(BaseModel, frozen=True):
+    """A step with its templates rendered against the inputs."""
+
+    id: str
+    title: str
+    tool: str | None = None
+    prompt: str | None = None
+    arguments: dict[str, Any] = Field(default_factory=dict)
+    instructions: str = ""
+    condition: str | None = None
+    on_failure: Literal["stop", "continue"] = "stop"
+
+
+class WorkflowPlan(BaseModel, frozen=True):
+    name: str
+    description: str = ""
+    inputs: dict[str, Any] = Field(default_factory=dict)
+    steps: list[PlannedStep] = Field(default_factory=list)
+    skipped: list[str] = Field(
+        default_factory=list, description="Steps whose `when` was false"
+    )
+
+
+def workflow_dirs(start: str | Path | None = None) -> list[Path]:
+    """Directories searched for workflows, highest precedence first."""
+    return [
+        find_repo_root(start) / REPO_WORKFLOW_DIR,
+        get_config().workflows_dir,
+        ASSET_DIR,
+    ]
+
+
+def parse_workflow(text: str, source: str | None = None) -> WorkflowDefinition:
+    """Parse and validate one YAML workflow definition.
+
+    Raises:
+        WorkflowError: If the YAML is malformed or does not describe a valid
+            workflow.
+    """
+    where = source or "workflow"
+    try:
+        data = yaml.safe_load(text)
+    except yaml.YAMLError as exc:
+        raise WorkflowError(f"Invalid YAML in {where}: {exc}") from exc
+    if not isinstance(data, dict):
+        raise WorkflowError(f"{where} must be a mapping with name and steps.")
+    if source and "name" not in data:
+        data["name"] = Path(source).stem
+    try:
+        return WorkflowDefinition.model_validate({**data, "source": source})
+    except ValidationError as exc:
+        problems = "; ".join(
+            f"{'.'.join(map(str, e['loc'])) or 'workflow'}: {e['msg']}"
+            for e in exc.errors()
+        )
+        raise WorkflowError(f"Invalid workflow {where}: {problems}") from exc
+
+
+def _files(start: str | Path | None) -> list[Path]:
+    found: dict[str, Path] = {}
+    for directory in workflow_dirs(start):
+        if not directory.is_dir():
+            continue
+        for path in sorted(directory.iterdir()):
+            if path.suffix in WORKFLOW_SUFFIXES and path.is_file():
+                found.setdefault(path.stem, path)
+    return [found[name] for name in sorted(found)]
+
+
+def list_workflows(start: str | Path | None = None) -> list[WorkflowDefinition]:
+    """Every workflow available, by name; a repository's definitions win.
+
+    Raises:
+        WorkflowError: If a definition file is invalid.
+    """
+    return [
+        parse_workflow(path.read_text(encoding="utf-8"), str(path))
+        for path in _files(start)
+    ]
+
+
+def load_workflow(name: str, start: str | Path | None = None) -> WorkflowDefinition:
+    """The workflow called *name*.
+
+    Raises:
+        WorkflowError: If there is no such workflow or it is invalid.
+    """
+    for path in _files(start):
+        if path.stem == name:
+            return parse_workflow(path.read_text(encoding="utf-8"), str(path))
+    available = ", ".join(path.stem for path in _files(start)) or "none"
+    raise WorkflowError(f"No workflow named '{name}' (available: {available}).")
+
+
+def _render(value: Any, context: dict[str, Any]) -> Any:
+    if isinstance(value, dict):
+        return {k: _render(v, context) for k, v in value.items()}
+    if isinstance(value, list):
+        return [_render(v, context) for v in value]
+    if not isinstance(value, str):
+        return value
+    if match := _FULL_EXPRESSION.match(value.strip()):
+        return _env.compile_expression(match.group(1).strip())(**context)
+    return _env.from_string(value).render(**context)
+
+
+def _resolve_inputs(
+    definition: WorkflowDefinition, inputs: dict[str, Any]
+) -> dict[str, Any]:
+    unknown = sorted(set(inputs) - set(definition.inputs))
+    if unknown:
+        raise WorkflowError(
+            f"Workflow '{definition.name}' has no input(s) {', '.join(unknown)}."
+        )
+    resolved = {}
+    for name, spec in definition.inputs.items():
+        if name in inputs:
+            resolved[name] = inputs[name]
+        elif spec.required:
+            raise WorkflowError(f"Workflow '{definition.name}' needs input '{name}'.")
+        else:
+            resolved[name] = spec.default
+    return resolved
+
+
+def plan_workflow(
+    definition: WorkflowDefinition, inputs: dict[str, Any] | None = None
+) -> WorkflowPlan:
+    """Render *definition*'s steps for *inputs*, dropping those whose ``when`` fails.
+
+    Raises:
+        WorkflowError: If an input is unknown or missing, or a template or
+            ``when`` expression cannot be evaluated.
+    """
+    context = _resolve_inputs(definition, inputs or {})
+    steps: list[PlannedStep] = []
+    skipped: list[str] = []
+    for step in definition.steps:
+        try:
+            when = step.when
+            if isinstance(when, str):
+                when = _render(when if "{{" in when else f"{{{{ {when} }}}}", context)
+            if when is not None and not when:
+                skipped.append(step.id)
+                continue
+            steps.append(
+                PlannedStep(
+                    id=step.id,
+                    title=step.title or step.id.replace("-", " ").replace("_", " "),
+                    tool=step.tool,
+                    prompt=step.prompt,
+                    arguments=_render(step.arguments, context),
+                    instructions=_render(step.instructions, context).strip(),
+                    condition=step.condition,
+                    on_failure=step.on_failure,
+                )
+            )
+        except TemplateError as exc:
+            raise WorkflowError(
+                f"Workflow '{definition.name}', step '{step.id}': {exc}"
+            ) from exc
+    return WorkflowPlan(
+        name=definition.name,
+        description=definition.description,
+        inputs=context,
+        steps=steps,
+        skipped=skipped,
+    )
@@ -0,0 +1,420 @@
+"""azathoth.core.workspace — release the changed packages of a monorepo.
+
+Public surface:
+  - ``discover_workspace(root)``                 → ``[WorkspacePackage]``
+  - ``package_tag(name, version)``               → the package's release tag
+  - ``release_order(packages)``                  → packages, dependencies first
+  - ``plan_workspace_release(root, only, cwd)``  → ``WorkspaceRelease``
+  - ``release_commit_message(release)``          → ``(title, body)``
+  - ``release_workspace(release, …)``            → ``WorkspaceRelease``
+
+Cargo workspaces (``[workspace] members``), npm/yarn ``workspaces`` and
+``pnpm-workspace.yaml`` are read; member globs and ``!`` / ``exclude``
+patterns are honoured.  A package changed when a commit since its own tag
+(``workflow_workspace_tag_format``, ``<name>-v<version>`` by default)
+touches its directory; the bump level comes from those commits exactly as
+for ``suggest_next_version``.  Every bumped manifest goes into one release
+commit, then each package is tagged and published (with its own changelog
+as notes) in dependency order, so a package is never released before a
+workspace dependency it needs.  Versions inherited from
+``[workspace.package]`` are refused, as in ``core.version``.
+"""
+
+from __future__ import annotations
+
+import json
+import tomllib
+from pathlib import Path
+
+from pydantic import BaseModel, Field
+
+from azathoth.config import get_config
+from azathoth.core.changelog import Changelog, get_commits
+from azathoth.core.defaults import bump_level
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.release import create_release
+from azathoth.core.vcs import get_vcs
+from azathoth.core.version import BumpLevel, Manifest, bump, write_version
+from azathoth.core.workflow import _run_git, run_command
+
+PNPM_WORKSPACE = "pnpm-workspace.yaml"
+
+_CARGO_DEP_TABLES = ("dependencies", "build-dependencies")
+_NPM_DEP_FIELDS = ("dependencies", "optionalDependencies", "peerDependencies")
+
+
+class WorkspacePackage(BaseModel, frozen=True):
+    name: str
+    path: str = Field(description="Package directory relative to the root")
+    manifest: Manifest
+    dependencies: list[str] = Field(
+        default_factory=list, description="Workspace packages it depends on"
+    )
+    inherited_version: bool = False
+
+
+class PackageRelease(BaseModel, frozen=True):
+    name: str
+    path: str
+    manifest: str = Field(description="Manifest relative to the root")
+    previous_tag: str | None = None
+    old_version: str
+    new_version: str
+    level: BumpLevel
+    reason: str
+    tag: str
+    notes: str = ""
+
+
+class WorkspaceRelease(BaseModel, frozen=True):
+    root: str
+    packages: list[PackageRelease] = Field(
+        default_factory=list, description="Packages to release, dependencies first"
+    )
+    unchanged: list[str] = Field(default_factory=list)
+    committed: bool = False
+    released: list[str] = Field(default_factory=list, description="Published tags")
+    error: str | None = None
+    commands: list[str] = Field(default_factory=list, description="Dry-run plan")
+
+
+# ── Discovery ─────────────────────────────────────────────────────────────────
+
+
+def _member_dirs(root: Path, patterns: list[str], manifest: str) -> list[Path]:
+    """Directories matched by *patterns* (``!`` negates) holding *manifest*."""
+    included: list[Path] = []
+    excluded: set[Path] = set()
+    for pattern in patterns:
+        negate = pattern.startswith("!")
+        glob = pattern.removeprefix("!").strip().rstrip("/")
+        matches = [root] if glob in ("", ".") else sorted(root.glob(glob))
+        for path in matches:
+            if not (path / manifest).is_file():
+                continue
+            if negate:
+                excluded.add(path)
+            elif path not in included:
+                included.append(path)
+    return [path for path in included if path not in excluded]
+
+
+def _pnpm_patterns(path: Path) -> list[str]:
+    """``packages:`` entries of a pnpm-workspace.yaml (flow lists unsupported)."""
+    patterns: list[str] = []
+    in_packages = False
+    for line in path.read_text(encoding="utf-8").splitlines():
+        stripped = line.split("#", 1)[0].strip()
+        if not stripped:
+            continue
+        if not line[0].isspace():
+            in_packages = stripped == "packages:"
+        elif in_packages and stripped.startswith("-"):
+            patterns.append(stripped[1:].strip().strip("'\""))
+    return patterns
+
+
+def _cargo_dependencies(data: dict) -> set[str]:
+    tables = [data.get(table, {}) for table in _CARGO_DEP_TABLES]
+    for target in data.get("target", {}).values():
+        tables += [target.get(table, {}) for table in _CARGO_DEP_TABLES]
+    names = set()
+    for table in tables:
+        for key, spec in table.items():
+            renamed = spec.get("package") if isinstance(spec, dict) else None
+            names.add(renamed or key)
+    return names
+
+
+def _cargo_packages(root: Path, data: dict) -> list[WorkspacePackage]:
+    workspace = data["workspace"]
+    shared = workspace.get("package", {}).get("version")
+    patterns = [
+        *workspace.get("members", []),
+        *(f"!{pattern}" for pattern in workspace.get("exclude", [])),
+    ]
+    found: list[tuple[WorkspacePackage, set[str]]] = []
+    for directory in _member_dirs(root, patterns, "Cargo.toml"):
+        path = directory / "Cargo.toml"
+        with open(path, "rb") as f:
+            member = tomllib.load(f)
+        package = member.get("package", {})
+        version = package.get("version")
+        inherited = isinstance(version, dict) and bool(version.get("workspace"))
+        if inherited:
+            version = shared
+        if "name" not in package or not isinstance(version, str):
+            continue
+        found.append(
+            (
+                WorkspacePackage(
+                    name=package["name"],
+                    path=directory.relative_to(root).as_posix() or ".",
+                    manifest=Manifest(path=path, kind="cargo", version=version),
+                    inherited_version=inherited,
+                ),
+                _cargo_dependencies(member),
+            )
+        )
+    return _link(found)
+
+
+def _npm_packages(root: Path, patterns: list[str]) -> list[WorkspacePackage]:
+    found: list[tuple[WorkspacePackage, set[str]]] = []
+    for directory in _member_dirs(root, patterns, "package.json"):
+        path = directory / "package.json"
+        data = json.loads(path.read_text(encoding="utf-8"))
+        if not data.get("name") or not data.get("version"):
+            continue
+        deps = {name for field in _NPM_DEP_FIELDS for name in data.get(field, {})}
+        found.append(
+            (
+                WorkspacePackage(
+                    name=data["name"],
+                    path=directory.relative_to(root).as_posix() or ".",
+                    manifest=Manifest(path=path, kind="npm", version=data["version"]),
+                ),
+                deps,
+            )
+        )
+    return _link(found)
+
+
+def _link(
+    found: list[tuple[WorkspacePackage, set[str]]],
+) -> list[WorkspacePackage]:
+    """Keep only dependencies on other members of the workspace."""
+    names = {package.name for package, _ in found}
+    return [
+        package.model_copy(
+            update={"dependencies": sorted((deps & names) - {package.name})}
+        )
+        for package, deps in found
+    ]
+
+
+def discover_workspace(root: Path) -> list[WorkspacePackage]:
+    """Member packages of the Cargo, npm or pnpm workspace at *root*.
+
+    Raises:
+        WorkflowError: If *root* declares no workspace or a manifest is invalid.
+    """
+    try:
+        cargo = root / "Cargo.toml"
+        if cargo.is_file():
+            with open(cargo, "rb") as f:
+                data = tomllib.load(f)
+            if "workspace" in data:
+                return _cargo_packages(root, data)
+        npm = root / "package.json"
+        if (root / PNPM_WORKSPACE).is_file():
+            return _npm_packages(root, _pnpm_patterns(root / PNPM_WORKSPACE))
+        if npm.is_file():
+            workspaces = json.loads(npm.read_text(encoding="utf-8")).get("workspaces")
+            if isinstance(workspaces, dict):
+                workspaces = workspaces.get("packages")
+            if workspaces:
+                return _npm_packages(root, list(workspaces))
+    except (json.JSONDecodeError, tomllib.TOMLDecodeError) as exc:
+        raise WorkflowError(f"Invalid workspace manifest: {exc}") from exc
+    raise WorkflowError(
+        f"No workspace found in {root} (looked for [workspace] in Cargo.toml, "
+        f"workspaces in package.json and {PNPM_WORKSPACE})."
+    )
+
+
+def release_order(packages: list[WorkspacePackage]) -> list[WorkspacePackage]:
+    """*packages* with every package after the workspace packages it needs.
+
+    Raises:
+        WorkflowError: On a dependency cycle.
+    """
+    pending = {p.name: p for p in packages}
+    ordered: list[WorkspacePackage] = []
+    while pending:
+        ready = [
+            p
+            for p in pending.values()
+            if not any(dep in pending for dep in p.dependencies)
+        ]
+        if not ready:
+            raise WorkflowError(
+                f"Dependency cycle between: {', '.join(sorted(pending))}."
+            )
+        for package in ready:
+            ordered.append(package)
+            del pending[package.name]
+    return ordered
+
+
+# ── Planning ──────────────────────────────────────────────────────────────────
+
+
+def package_tag(name: str, version: str) -> str:
+    return get_config().workflow_workspace_tag_format.format(
+        name=name, version=version
+    )
+
+
+async def _tag_exists(tag: str, cwd: str | None) -> bool:
+    code, _, _ = await _run_git(
+        ["rev-parse", "--verify", "--quiet", f"refs/tags/{tag}"], cwd=cwd
+    )
+    return code == 0
+
+
+async def plan_workspace_release(
+    root: Path, only: list[str] | None = None, cwd: str | None = None
+) -> WorkspaceRelease:
+    """Which packages changed since their tag, and their next versions.
+
+    A package without a tag for its current version counts every commit
+    touching its directory.
+
+    Raises:
+        WorkflowError: If there is no workspace, *only* names an unknown
+            package, or a changed package inherits its version.
+    """
+    packages = discover_workspace(root)
+    if only:
+        names = {p.name for p in packages}
+        if unknown := sorted(set(only) - names):
+            raise WorkflowError(f"Not workspace packages: {', '.join(unknown)}.")
+        packages = [p for p in packages if p.name in only]
+
+    changed: dict[str, PackageRelease] = {}
+    unchanged: list[str] = []
+    for package in packages:
+        version = package.manifest.version
+        previous: str | None = package_tag(package.name, version)
+        if not await _tag_exists(previous, cwd):
+            previous = None
+        try:
+            commits = await get_commits(
+                previous, cwd=cwd, paths=[str(root / package.path)]
+            )
+        except ValueError as exc:
+            raise WorkflowError(str(exc)) from exc
+        if not commits:
+            unchanged.append(package.name)
+            continue
+        if package.inherited_version:
+            raise WorkflowError(
+                f"{package.name} inherits its version from [workspace.package]; "
+                "per-package releases need a version in its own Cargo.toml."
+            )
+        level, reason = bump_level(commits)
+        new_version = bump(version, level)
+        notes = Changelog(from_ref=previous, to_ref="HEAD", commits=commits)
+        changed[package.name] = PackageRelease(
+            name=package.name,
+            path=package.path,
+            manifest=package.manifest.path.relative_to(root).as_posix(),
+            previous_tag=previous,
+            old_version=version,
+            new_version=new_version,
+            level=level,
+            reason=reason,
+            tag=package_tag(package.name, new_version),
+            notes=notes.render_markdown(),
+        )
+
+    order = release_order([p for p in packages if p.name in changed])
+    return WorkspaceRelease(
+        root=str(root),
+        packages=[changed[p.name] for p in order],
+        unchanged=unchanged,
+    )
+
+
+# ── Releasing ─────────────────────────────────────────────────────────────────
+
+
+def release_commit_message(release: WorkspaceRelease) -> tuple[str, str]:
+    """Title and body of the commit recording every bumped manifest."""
+    if len(release.packages) == 1:
+        only = release.packages[0]
+        return f"chore(release): bump {only.name} to {only.new_version}", ""
+    body = "\n".join(
+        f"- {p.name}: {p.old_version} → {p.new_version}" for p in release.packages
+    )
+    return f"chore(release): release {len(release.packages)} packages", body
+
+
+async def release_workspace(
+    release: WorkspaceRelease,
+    prerelease: bool = False,
+    cwd: str | None = None,
+    dry_run: bool = False,
+) -> WorkspaceRelease:
+    """Bump, commit, then tag and publish each package of *release* in order.
+
+    Manifests are restored if the commit fails; publishing stops at the
+    first failing package, with ``released`` listing the tags already out
+    and ``error`` saying what failed.
+    """
+    if not release.packages:
+        return release
+    root = Path(release.root)
+    manifests = [
+        Manifest(
+            path=root / p.manifest,
+            kind="cargo" if p.manifest.endswith("Cargo.toml") else "npm",
+            version=p.old_version,
+        )
+        for p in release.packages
+    ]
+    paths = [str(m.path) for m in manifests]
+    lockfile = root / "Cargo.lock"
+    title, body = release_commit_message(release)
+    vcs = get_vcs(cwd)
+
+    if dry_run:
+        commands = [
+            f"edit {p.manifest}: version {p.old_version} → {p.new_version}"
+            for p in release.packages
+        ]
+        if lockfile.is_file():
+            commands.append("cargo update --workspace --offline")
+        planned_commit = await vcs.commit(title, body, paths=paths, dry_run=True)
+        commands.append(planned_commit.stdout.split("\n\n")[0])
+        for package in release.packages:
+            res = await create_release(
+                package.tag,
+                package.notes,
+                is_prerelease=prerelease,
+                dry_run=True,
+                cwd=cwd,
+            )
+            commands += res.stdout.splitlines()
+        return release.model_copy(update={"commands": commands})
+
+    originals = {path: Path(path).read_text(encoding="utf-8") for path in paths}
+    if lockfile.is_file():
+        originals[str(lockfile)] = lockfile.read_text(encoding="utf-8")
+    for package, manifest in zip(release.packages, manifests):
+        write_version(manifest, package.new_version)
+    if lockfile.is_file():
+        code, _, _ = await run_command(
+            ["cargo", "update", "--workspace", "--offline"], cwd=str(root)
+        )
+        if code == 0:
+            paths.append(str(lockfile))
+    res = await vcs.commit(title, body, paths=paths)
+    if not res.success:
+        for path, text in originals.items():
+            Path(path).write_text(text, encoding="utf-8")
+        raise WorkflowError(f"Commit failed (manifests restored): {res.stderr}")
+
+    released: list[str] = []
+    for package in release.packages:
+        res = await create_release(
+            package.tag, package.notes, is_prerelease=prerelease, cwd=cwd
+        )
+        if not res.success:
+            error = f"{package.tag}: {res.message or 'release failed'}: {res.stderr}"
+            return release.model_copy(
+                update={"committed": True, "released": released, "error": error}
+            )
+        released.append(package.tag)
+    return release.model_copy(update={"committed": True, "released": released})
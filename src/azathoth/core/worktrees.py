@@ -0,0 +1,158 @@
+"""azathoth.core.worktrees — linked worktrees for working on several branches.
+
+Public surface:
+  - ``parse_worktree_list(porcelain, current)``             → ``[WorktreeInfo]``
+  - ``list_worktrees(cwd)``                                 → ``[WorktreeInfo]``
+  - ``worktree_path(branch, path, cwd)``                    → ``Path``
+  - ``create_worktree(branch, path, start_point, cwd, …)``  → ``GitResult``
+
+New worktrees go to ``workflow_worktree_dir`` (or the system temp directory)
+as ``<dir>/<repo>/<branch>``, so an agent can check out a second branch
+without disturbing the working tree it was started in.  An explicit path is
+taken relative to ``<dir>/<repo>`` and may not leave it.  A branch can only be
+checked out in one worktree; asking for one that already is returns its path
+in the refusal instead of git's bare "already checked out".
+"""
+
+from __future__ import annotations
+
+import os
+import tempfile
+from pathlib import Path
+
+from pydantic import BaseModel
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import SandboxError, WorkflowError
+from azathoth.core.files import resolve_inside
+from azathoth.core.workflow import (
+    GitResult,
+    _run_git,
+    ensure_revision,
+    get_repo_context,
+    planned,
+)
+
+
+class WorktreeInfo(BaseModel, frozen=True):
+    path: str
+    branch: str | None = None  # None when detached or bare
+    head: str = ""
+    current: bool = False
+    bare: bool = False
+    detached: bool = False
+    locked: bool = False
+    prunable: bool = False
+
+
+def _fail(message: str) -> GitResult:
+    return GitResult(success=False, stdout="", stderr=message, message=message)
+
+
+def _same_path(a: str, b: str | None) -> bool:
+    return b is not None and os.path.realpath(a) == os.path.realpath(b)
+
+
+def parse_worktree_list(
+    porcelain: str, current: str | None = None
+) -> list[WorktreeInfo]:
+    """Parse ``git worktree list --porcelain``; *current* marks the caller's."""
+    worktrees = []
+    for record in porcelain.split("\n\n"):
+        fields: dict[str, str] = {}
+        for line in record.splitlines():
+            key, _, value = line.partition(" ")
+            fields[key] = value
+        if "worktree" not in fields:
+            continue
+        branch = fields.get("branch")
+        worktrees.append(
+            WorktreeInfo(
+                path=fields["worktree"],
+                branch=branch.removeprefix("refs/heads/") if branch else None,
+                head=fields.get("HEAD", ""),
+                current=_same_path(fields["worktree"], current),
+                bare="bare" in fields,
+                detached="detached" in fields,
+                locked="locked" in fields,
+                prunable="prunable" in fields,
+            )
+        )
+    return worktrees
+
+
+async def list_worktrees(cwd: str | None = None) -> list[WorktreeInfo]:
+    """The repository's worktrees, main one first."""
+    code, out, _ = await _run_git(["worktree", "list", "--porcelain"], cwd=cwd)
+    if code != 0:
+        return []
+    context = await get_repo_context(cwd)
+    return parse_worktree_list(out, context.root)
+
+
+async def worktree_path(
+    branch: str, path: str | None = None, cwd: str | None = None
+) -> Path:
+    """Where ``create_worktree`` puts *branch*: ``<worktree dir>/<repo>/<path>``
+    if *path* is given, else ``<worktree dir>/<repo>/<branch>``.
+
+    Raises:
+        WorkflowError: If *path* leads outside ``<worktree dir>/<repo>``.
+    """
+    context = await get_repo_context(cwd)
+    common = Path(context.common_dir or context.git_dir)
+    repo = common.parent if common.name == ".git" else common
+    configured = get_config().workflow_worktree_dir
+    if configured is None:
+        base = Path(tempfile.gettempdir()) / "azathoth-worktrees"
+    else:
+        base = Path(context.root or repo).joinpath(configured.expanduser())
+    home = base / repo.name.removesuffix(".git")
+    try:
+        return resolve_inside(home, path or branch.replace("/", "-"))
+    except SandboxError as exc:
+        raise WorkflowError(f"Worktrees stay in {home.resolve()}: {exc}") from exc
+
+
+async def create_worktree(
+    branch: str,
+    path: Path,
+    start_point: str | None = None,
+    cwd: str | None = None,
+    dry_run: bool = False,
+) -> GitResult:
+    """Check *branch* out in a new worktree at *path*.
+
+    An existing branch is checked out as is; a new one is created at
+    *start_point* (default ``HEAD``).
+    """
+    code, _, _ = await _run_git(["check-ref-format", "--branch", branch], cwd=cwd)
+    if code != 0:
+        return _fail(f"'{branch}' is not a valid branch name.")
+    if start_point is not None:
+        try:
+            start_point = ensure_revision(start_point)
+        except WorkflowError as exc:
+            return _fail(str(exc))
+    if path.exists() and any(path.iterdir()):
+        return _fail(f"{path} already exists and is not empty.")
+    for worktree in await list_worktrees(cwd):
+        if worktree.branch == branch:
+            return _fail(f"'{branch}' is already checked out in {worktree.path}.")
+
+    code, _, _ = await _run_git(
+        ["show-ref", "--verify", "--quiet", f"refs/heads/{branch}"], cwd=cwd
+    )
+    if code == 0:
+        if start_point:
+            return _fail(f"Branch '{branch}' already exists; omit start_point.")
+        args = ["worktree", "add", str(path), branch]
+    else:
+        args = ["worktree", "add", "-b", branch, str(path)]
+        args += [start_point] if start_point else []
+    if dry_run:
+        return planned(["git", *args])
+
+    path.parent.mkdir(parents=True, exist_ok=True)
+    code, out, err = await _run_git(args, cwd=cwd)
+    return GitResult(success=(code == 0), stdout=out, stderr=err)
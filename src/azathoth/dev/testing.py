@@ -0,0 +1,146 @@
+"""azathoth.dev.testing — drive MCP servers and git repos from tests.
+
+Public surface:
+  - ``ServerHarness(server)``   — in-process client: ``call``, ``prompt``, ``tools``
+  - ``GitRepo.create(path)``    → a fresh repository with a committer set up
+  - ``GitRepo``                 — ``write``, ``commit``, ``switch``, ``tag``, ``git``
+
+The harness talks to a FastMCP server through FastMCP's in-memory
+transport, so a call runs the server's whole middleware chain (audit,
+mutation guard, dynamic defaults, render modes) without spawning a stdio
+process.  ``GitRepo`` builds the throwaway repository such a call acts on:
+files are written and committed through the real ``git`` binary, so what
+the tools see is exactly what they would see in a user's checkout.
+
+Usage::
+
+    repo = GitRepo.create(tmp_path / "repo")
+    repo.commit("chore: initial commit", {"README.md": "# demo\\n"})
+    async with ServerHarness(workflow.mcp) as server:
+        status = await server.call("get_status")
+        steps = await server.prompt("autocommit", focus="a fix")
+"""
+
+from __future__ import annotations
+
+import subprocess
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from fastmcp import Client, FastMCP
+
+#: Committer used for every ``GitRepo``.
+AUTHOR_NAME = "Your Name"
+AUTHOR_EMAIL = "you@example.com"
+
+
+class ServerHarness:
+    """In-process MCP client for *server*; use as ``async with``.
+
+    *elicitation_handler* answers the questions tools ask the user and
+    *sampling_handler* the prompts they send the client's model (see
+    ``fastmcp.Client``); without one the client does not support that.
+    """
+
+    def __init__(
+        self,
+        server: FastMCP,
+        elicitation_handler: Any = None,
+        sampling_handler: Any = None,
+    ):
+        self.server = server
+        self._client = Client(
+            server,
+            elicitation_handler=elicitation_handler,
+            sampling_handler=sampling_handler,
+        )
+
+    async def __aenter__(self) -> ServerHarness:
+        await self._client.__aenter__()
+        return self
+
+    async def __aexit__(self, *exc_info: Any) -> None:
+        await self._client.__aexit__(*exc_info)
+
+    async def call(self, tool: str, meta: dict | None = None, **arguments: Any):
+        """Call *tool* and return its result as the tool returned it.
+
+        Raises:
+            ToolError: If the tool, or a middleware in front of it, fails.
+        """
+        result = await self._client.call_tool(tool, arguments, meta=meta)
+        return result.data
+
+    async def prompt(self, name: str, **arguments: Any) -> str:
+        """Render prompt *name* and return its messages' text."""
+        result = await self._client.get_prompt(name, arguments)
+        return "\n\n".join(
+            m.content.text for m in result.messages if hasattr(m.content, "text")
+        )
+
+    async def tools(self) -> list[str]:
+        return sorted(tool.name for tool in await self._client.list_tools())
+
+
+@dataclass(frozen=True)
+class GitRepo:
+    """A git repository for tests, built up one commit at a time."""
+
+    path: Path
+
+    @classmethod
+    def create(cls, path: Path, branch: str | None = None) -> GitRepo:
+        """``git init`` *path* (created if missing) with a test committer.
+
+        *branch* names the unborn initial branch; by default git's
+        ``init.defaultBranch`` applies.
+        """
+        path.mkdir(parents=True, exist_ok=True)
+        repo = cls(path)
+        repo.git("init", "-q")
+        if branch:
+            repo.git("symbolic-ref", "HEAD", f"refs/heads/{branch}")
+        repo.git("config", "user.email", AUTHOR_EMAIL)
+        repo.git("config", "user.name", AUTHOR_NAME)
+        return repo
+
+    def git(self, *args: str, check: bool = True) -> str:
+        """Run ``git *args`` in the repository and return its stripped stdout.
+
+        With ``check=False`` a failing command (an expected merge conflict)
+        does not raise.
+        """
+        return subprocess.run(
+            ["git", *args], cwd=self.path, check=check, capture_output=True, text=True
+        ).stdout.strip()
+
+    def write(self, name: str, content: str) -> Path:
+        path = self.path / name
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.write_text(content)
+        return path
+
+    def commit(self, message: str, files: dict[str, str] | None = None) -> str:
+        """Write *files*, stage them (everything if none) and commit.
+
+        Returns the new commit's sha.
+        """
+        for name, content in (files or {}).items():
+            self.write(name, content)
+        if files:
+            self.git("add", "--", *files)
+        else:
+            self.git("add", "--all")
+        self.git("commit", "-qm", message)
+        return self.git("rev-parse", "HEAD")
+
+    def switch(self, branch: str, create: bool = False) -> None:
+        self.git("switch", "-qc" if create else "-q", branch)
+
+    def tag(self, name: str, message: str | None = None) -> None:
+        self.git("tag", *(["-am", message] if message else []), name)
+
+    def subjects(self, rev_range: str = "HEAD") -> list[str]:
+        """Commit subjects of *rev_range*, newest first."""
+        return self.git("log", "--format=%s", rev_range).splitlines()
@@ -0,0 +1,36 @@
+"""
+mcp — FastMCP servers, one module per tool family.
+
+``SERVERS`` is the registry used by ``azathoth serve`` / ``azathoth
+list-servers``; modules are imported lazily so listing servers stays cheap.
+"""
+
+from __future__ import annotations
+
+import importlib
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from fastmcp import FastMCP
+
+#: server name → (module path, one-line description)
+SERVERS: dict[str, tuple[str, str]] = {
+    "workflow": ("azathoth.mcp.workflow", "Git workflow: status, commit, release"),
+    "i18n": ("azathoth.mcp.i18n", "Inlang translation audit and AI translation"),
+    "scout": ("azathoth.mcp.scout", "Project reconnaissance: stack profile"),
+    "directives": ("azathoth.mcp.directives", "Coding directives: adapt, resources"),
+    "unified": (
+        "azathoth.mcp.unified",
+        "workflow, scout and directives as git.*, scout.*, style.*",
+    ),
+}
+
+
+def load_server(name: str) -> FastMCP:
+    """Import and return the ``mcp`` instance of the named server.
+
+    Raises:
+        KeyError: If *name* is not in ``SERVERS``.
+    """
+    module_path, _ = SERVERS[name]
+    return importlib.import_module(module_path).mcp
@@ -0,0 +1,48 @@
+"""
+mcp/audit.py — middleware recording every tool call via core/audit.py.
+
+Registered first on each server so it also records calls that later
+middleware (e.g. ``MutationGuard``) rejects.
+"""
+
+import time
+
+from fastmcp.server.middleware import Middleware, MiddlewareContext
+
+from azathoth.core.audit import (
+    AuditRecord,
+    capture_commands,
+    redact_arguments,
+    write_record,
+)
+
+
+class AuditLog(Middleware):
+    """Writes one ``AuditRecord`` per tool invocation."""
+
+    def __init__(self, server: str):
+        self.server = server
+
+    async def on_call_tool(self, context: MiddlewareContext, call_next):
+        name = context.message.name
+        arguments = redact_arguments(context.message.arguments or {})
+        started = time.perf_counter()
+        error: str | None = None
+        with capture_commands() as commands:
+            try:
+                return await call_next(context)
+            except Exception as exc:
+                error = f"{type(exc).__name__}: {exc}"
+                raise
+            finally:
+                write_record(
+                    AuditRecord(
+                        server=self.server,
+                        tool=name,
+                        arguments=arguments,
+                        duration_ms=round((time.perf_counter() - started) * 1000, 1),
+                        success=error is None,
+                        error=error,
+                        commands=list(commands),
+                    )
+                )
@@ -0,0 +1,145 @@
+"""
+mcp/compose.py — one MCP server assembled from several others.
+
+``CompositeServer`` mounts existing servers, each under an optional prefix,
+so tool families written as separate servers can be served together or
+reused in new combinations without touching their modules.  Components of
+a prefixed member are exposed as ``<prefix><separator><name>`` (``.`` by
+default: ``git.get_status``).  Members keep their own middleware (audit,
+render modes, the mutation guard), which sees the unprefixed name.
+
+Clashes are refused: ``add`` rejects a prefix that is taken or overlaps
+another one, and ``check`` lists the members' tools and prompts and raises
+``CompositionError`` when two of them would be exposed under one name.
+"""
+
+from __future__ import annotations
+
+from collections import defaultdict
+from typing import Any
+
+from fastmcp import FastMCP
+from fastmcp.server.middleware import Middleware, MiddlewareContext
+
+from azathoth.core.exceptions import CompositionError
+
+#: What FastMCP puts between a mount namespace and a component name.
+_MOUNT_SEPARATOR = "_"
+
+
+class CompositeServer(FastMCP):
+    """A FastMCP server whose tools and prompts come from member servers."""
+
+    def __init__(self, name: str, *, separator: str = ".", **settings: Any):
+        super().__init__(name=name, **settings)
+        if not separator:
+            raise CompositionError("Prefix separator must not be empty")
+        self.separator = separator
+        #: prefix ("" for unprefixed members) → servers
+        self.members: dict[str, list[FastMCP]] = defaultdict(list)
+        if separator != _MOUNT_SEPARATOR:
+            self.add_middleware(PrefixedNames(self))
+
+    @property
+    def prefixes(self) -> list[str]:
+        return [prefix for prefix in self.members if prefix]
+
+    def add(self, server: FastMCP, prefix: str = "") -> CompositeServer:
+        """Mount *server*, exposing its components under *prefix*.
+
+        Raises:
+            CompositionError: If *prefix* is in use, contains the separator,
+                or overlaps another prefix once joined to component names.
+        """
+        if prefix:
+            self._check_prefix(prefix)
+        self.members[prefix].append(server)
+        self.mount(server, namespace=prefix or None)
+        return self
+
+    def _check_prefix(self, prefix: str) -> None:
+        if self.separator in prefix:
+            raise CompositionError(
+                f"Prefix {prefix!r} contains the separator {self.separator!r}"
+            )
+        for taken in self.prefixes:
+            if prefix == taken:
+                raise CompositionError(f"Prefix {prefix!r} is already in use")
+            shorter, longer = sorted((prefix, taken), key=len)
+            if longer.startswith(shorter + _MOUNT_SEPARATOR):
+                raise CompositionError(
+                    f"Prefixes {shorter!r} and {longer!r} overlap: "
+                    f"'{longer}{_MOUNT_SEPARATOR}x' could belong to either"
+                )
+
+    def exposed(self, prefix: str, name: str) -> str:
+        return f"{prefix}{self.separator}{name}" if prefix else name
+
+    async def conflicts(self) -> dict[str, list[str]]:
+        """Exposed name → the members' components claiming it, when > 1."""
+        claims: dict[str, list[str]] = defaultdict(list)
+        for prefix, servers in self.members.items():
+            for server in servers:
+                components = [
+                    *(("tool", t.name) for t in await server.list_tools()),
+                    *(("prompt", p.name) for p in await server.list_prompts()),
+                ]
+                for kind, name in components:
+                    claims[f"{kind} {self.exposed(prefix, name)}"].append(
+                        f"{server.name}:{name}"
+                    )
+        return {name: owners for name, owners in claims.items() if len(owners) > 1}
+
+    async def check(self) -> None:
+        """Raise if two members' components are exposed under one name.
+
+        Raises:
+            CompositionError: Listing every clashing name and its owners.
+        """
+        if clashes := await self.conflicts():
+            details = "; ".join(
+                f"{name} ← {', '.join(owners)}" for name, owners in clashes.items()
+            )
+            raise CompositionError(f"Conflicting component names: {details}")
+
+
+class PrefixedNames(Middleware):
+    """Shows mounted ``<prefix>_<name>`` components as ``<prefix>.<name>``.
+
+    FastMCP joins a mount namespace with an underscore; this renames listed
+    tools and prompts to the composite's separator and maps calls back.
+    """
+
+    def __init__(self, server: CompositeServer):
+        self.server = server
+
+    def exposed(self, name: str) -> str:
+        for prefix in self.server.prefixes:
+            if name.startswith(prefix + _MOUNT_SEPARATOR):
+                return self.server.exposed(prefix, name[len(prefix) + 1 :])
+        return name
+
+    def mounted(self, name: str) -> str:
+        prefix, sep, rest = name.partition(self.server.separator)
+        if sep and prefix in self.server.prefixes:
+            return f"{prefix}{_MOUNT_SEPARATOR}{rest}"
+        return name
+
+    def _renamed(self, components):
+        return [c.model_copy(update={"name": self.exposed(c.name)}) for c in components]
+
+    def _routed(self, context: MiddlewareContext) -> MiddlewareContext:
+        name = self.mounted(context.message.name)
+        return context.copy(message=context.message.model_copy(update={"name": name}))
+
+    async def on_list_tools(self, context: MiddlewareContext, call_next):
+        return self._renamed(await call_next(context))
+
+    async def on_list_prompts(self, context: MiddlewareContext, call_next):
+        return self._renamed(await call_next(context))
+
+    async def on_call_tool(self, context: MiddlewareContext, call_next):
+        return await call_next(self._routed(context))
+
+    async def on_get_prompt(self, context: MiddlewareContext, call_next):
+        return await call_next(self._routed(context))
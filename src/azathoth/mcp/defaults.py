@@ -0,0 +1,37 @@
+"""
+mcp/defaults.py — middleware filling omitted tool parameters from repo state.
+
+Each server maps tool → {parameter: resolver name} (see core/defaults.py)
+and registers a ``DynamicDefaults``; the model can leave those parameters
+out instead of guessing or spending a call to look them up.
+"""
+
+import logging
+from collections.abc import Mapping
+
+from fastmcp.server.middleware import Middleware, MiddlewareContext
+
+from azathoth.core.defaults import RESOLVERS, resolve_defaults
+
+log = logging.getLogger(__name__)
+
+
+class DynamicDefaults(Middleware):
+    """Resolves declared parameter defaults server-side before the tool runs."""
+
+    def __init__(self, specs: Mapping[str, Mapping[str, str]]):
+        names = {name for spec in specs.values() for name in spec.values()}
+        if unknown := names - set(RESOLVERS):
+            raise ValueError(f"Unknown default resolvers: {', '.join(sorted(unknown))}")
+        self.specs = {tool: dict(spec) for tool, spec in specs.items()}
+
+    async def on_call_tool(self, context: MiddlewareContext, call_next):
+        spec = self.specs.get(context.message.name)
+        if spec:
+            arguments, resolved = await resolve_defaults(
+                context.message.arguments or {}, spec
+            )
+            if resolved:
+                log.info("%s: resolved defaults %s", context.message.name, resolved)
+                context.message.arguments = arguments
+        return await call_next(context)
@@ -0,0 +1,180 @@
+"""
+mcp/directives.py — MCP server exposing coding directives.
+
+Presentation layer only — every tool wraps exactly one core/ operation.
+Each directive is also published as a ``directive://<name>`` resource so
+clients can browse and attach guidance without calling a tool.
+Runs on stdio transport via `uv run directives`.
+"""
+
+from pathlib import Path
+
+from fastmcp import FastMCP
+from fastmcp.exceptions import ToolError
+
+from azathoth.config import get_config
+from azathoth.core.directive_coverage import CoverageReport, directive_coverage
+from azathoth.core.directive_lint import (
+    MAX_SECTION_TOKENS,
+    DirectiveLintReport,
+    lint_directives,
+)
+from azathoth.core.directives import (
+    DirectiveContext,
+    OutputFormat,
+    build_master_context,
+    list_directives,
+    load_directive,
+)
+from azathoth.core.exceptions import DirectiveError, SandboxError
+from azathoth.core.files import resolve_inside
+from azathoth.mcp.audit import AuditLog
+from azathoth.mcp.health import register_health
+from azathoth.mcp.limits import CallLimiter
+from azathoth.mcp.render import RenderOutput
+from azathoth.mcp.telemetry import Telemetry
+
+mcp = FastMCP(
+    name="azathoth-directives",
+    instructions=(
+        "Coding directives (core philosophy plus per-language rules). Call "
+        "adapt with the project's languages before writing code, or read the "
+        "directive://<name> resources directly. Directives may extend others; "
+        "adapt pulls in the whole chain and merges it, with the repository's "
+        ".azathoth/directives/ overrides on top; give it max_tokens "
+        "when several languages must share a small context, and locale (e.g. "
+        "'es') for translated directives where a team provides them. "
+        "lint_directive checks directive files a team writes (front matter, "
+        "extends targets, links, headings, section sizes), and coverage "
+        "shows which of a repository's languages and frameworks lack "
+        "directives or have stale ones."
+    ),
+)
+
+mcp.add_middleware(AuditLog("directives"))
+mcp.add_middleware(Telemetry("directives"))
+mcp.add_middleware(RenderOutput("directives"))
+mcp.add_middleware(CallLimiter())
+register_health(mcp, "directives")
+
+
+# ── Resources ────────────────────────────────────────────────────────────
+
+
+async def _read_directive(name: str) -> str:
+    directive = await load_directive(name, get_config().directive_locale)
+    if directive is None:
+        raise ValueError(f"Unknown directive: {name}")
+    return directive.render()
+
+
+def _reader(name: str):
+    # Static resources must take no parameters, so bind the name in a closure.
+    async def read() -> str:
+        return await _read_directive(name)
+
+    return read
+
+
+def register_directive_resources(server: FastMCP) -> None:
+    """Publish every directive on *server* as a ``directive://<name>`` resource.
+
+    Directives present at startup are listed individually; the URI template
+    also resolves directives added to the user directory afterwards.
+    Resources follow ``directive_locale``; ``directive://<name>.<locale>``
+    reads one translation explicitly.
+    """
+    for name in list_directives():
+        server.resource(
+            f"directive://{name}",
+            name=name,
+            description=f"Coding directive '{name}' rendered as Markdown.",
+            mime_type="text/markdown",
+        )(_reader(name))
+
+    @server.resource("directive://{name}", mime_type="text/markdown")
+    async def directive(name: str) -> str:
+        """Coding directive rendered as Markdown."""
+        return await _read_directive(name)
+
+
+register_directive_resources(mcp)
+
+
+# ── Tools ────────────────────────────────────────────────────────────────
+
+
+@mcp.tool()
+async def adapt(
+    languages: list[str],
+    max_tokens: int | None = None,
+    max_chars: int | None = None,
+    project_core: bool = True,
+    locale: str | None = None,
+    project_directives: bool = True,
+    output_format: OutputFormat = "markdown",
+) -> DirectiveContext:
+    """Return the combined coding directives (core philosophy plus one per language, e.g. ['python', 'rust']) to follow while writing code: content is the Markdown, directives the files loaded in merge order, estimated_tokens its size. Directives they extend are included too, parents first, with repeated sections merged into one document. Pass max_tokens and/or max_chars to fit a context budget: the lowest-priority sections of language directives (section_priority in their front matter) are dropped first and listed in omitted and a closing note; core philosophy is never trimmed. A project's .azathoth/core-philosophy.md (nearest one from the working directory up to the repo root) extends the core philosophy, or replaces it when its front matter says mode: override; pass project_core=False to ignore it. The repository's .azathoth/directives/ is layered over the global directives: a file named like one (d-rust.md) replaces its sections with the same titles and its rules, listed in overlaid; other files there are project-only directives. Pass project_directives=False to ignore them. locale (e.g. 'es', 'pt-BR'; default AZATHOTH_DIRECTIVE_LOCALE) loads translated directives (d-rust.es.md) where they exist and falls back to the default ones, listed in untranslated. output_format: "markdown" (default), "plain" (content without Markdown markup, for plain-text system prompts) or "json" (also fills sections with {directive, title, priority, content} per section, to slot into prompts or UI panels)."""
+    try:
+        return await build_master_context(
+            languages,
+            max_chars=max_chars,
+            max_tokens=max_tokens,
+            project_core=project_core,
+            locale=locale,
+            project_directives=project_directives,
+            output_format=output_format,
+        )
+    except DirectiveError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+def _confined(target: str, *roots: Path) -> Path:
+    """*target* resolved under the first of *roots* where it exists (else the
+    first it stays inside), so clients cannot read other host files.
+
+    Raises:
+        ToolError: If *target* leads outside every root.
+    """
+    inside = []
+    for root in roots:
+        try:
+            inside.append(resolve_inside(root, target))
+        except SandboxError:
+            continue
+    if not inside:
+        raise ToolError(f"'{target}' is outside {', '.join(map(str, roots))}.")
+    return next((path for path in inside if path.exists()), inside[0])
+
+
+@mcp.tool()
+async def lint_directive(
+    target: str, max_section_tokens: int = MAX_SECTION_TOKENS
+) -> DirectiveLintReport:
+    """Validate user-authored directives: target is a directive file, a directory of them (e.g. a team's directive pack) or a directive name. Paths are relative to the working directory or the user directives directory and may not leave them. Reports, per file, diagnostics with severity, code, message and line: front-matter errors (unparseable, wrong types), unknown extends targets, broken internal links (#anchors, relative files, directive:// names), and warnings for unknown front-matter keys, section_priority headings the file lacks, duplicate headings and sections over max_section_tokens. errors and warnings total them; fix errors before publishing the directives."""
+    path = _confined(target, Path.cwd(), get_config().directives_dir)
+    try:
+        # A path that exists nowhere may still be a directive name.
+        return lint_directives(path if path.exists() else target, max_section_tokens)
+    except DirectiveError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def coverage(
+    target_directory: str = ".", stale_days: int = 180
+) -> CoverageReport:
+    """Compare the languages and frameworks detected in target_directory (as detect_stack finds them; relative to the working directory, which it may not leave) with the available directives, including the repository's .azathoth/directives/. Each technology is covered (its own directive, or one whose applies_to names it), stale (that directive unchanged for more than stale_days), partial (a framework only its language's directive covers) or missing; missing ones come first so maintainers know which directives to write next. invalid lists directive files that fail to load."""
+    try:
+        root = _confined(target_directory, Path.cwd())
+        return await directive_coverage(root, stale_days)
+    except DirectiveError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+# ── Entry point ──────────────────────────────────────────────────────────
+
+
+def run():
+    """Script entry point: `uv run directives`."""
+    mcp.run(transport="stdio")
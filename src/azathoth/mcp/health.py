@@ -0,0 +1,23 @@
+"""
+mcp/health.py — the ``health`` tool every server carries.
+
+``register_health`` adds it to a server under the server's name; the
+report itself comes from core/health.py.  Uptime counts from registration,
+i.e. from when the server module was imported.
+"""
+
+from datetime import datetime, timezone
+
+from fastmcp import FastMCP
+
+from azathoth.core.health import HealthReport, check_health
+
+
+def register_health(server: FastMCP, name: str) -> None:
+    """Add a read-only ``health`` tool to *server*."""
+    started_at = datetime.now(timezone.utc)
+
+    @server.tool()
+    async def health() -> HealthReport:
+        """Server health and environment: azathoth version, uptime, working directory, the git repository and branch it detects, whether git and gh are on PATH (with versions), and SHA-256 checksums of the directive files in use. problems lists what will make tools fail. Call this first when a tool fails unexpectedly."""
+        return await check_health(name, started_at)
@@ -1,6 +1,7 @@
 from pathlib import Path
 from fastmcp import FastMCP
 
+from azathoth.core.formatter import render_table
 from azathoth.core.i18n import (
     InlangConfig,
     resolve_paths,
@@ -11,8 +12,20 @@ from azathoth.core.i18n import (
     write_translations,
     build_matrix,
 )
+from azathoth.mcp.audit import AuditLog
+from azathoth.mcp.health import register_health
+from azathoth.mcp.limits import CallLimiter
+from azathoth.mcp.policy import MutationGuard
+from azathoth.mcp.render import RenderOutput
+from azathoth.mcp.telemetry import Telemetry
 
 mcp = FastMCP("azathoth-i18n")
+mcp.add_middleware(AuditLog("i18n"))
+mcp.add_middleware(Telemetry("i18n"))
+mcp.add_middleware(RenderOutput("i18n"))
+mcp.add_middleware(MutationGuard({"translate_project"}))
+mcp.add_middleware(CallLimiter({"translate_project"}))
+register_health(mcp, "i18n")
 
 
 @mcp.tool()
@@ -29,22 +42,23 @@ async def audit_project(settings_path: str) -> str:
 
     matrix = build_matrix(translations, config.locales)
 
-    report = [f"i18n Audit for {settings_path}"]
-    report.append("-" * 40)
-
-    for key in matrix.keys:
-        status = []
-        for locale in config.locales:
-            val = matrix.matrix[key][locale]
-            status.append(f"{locale}: {'✓' if val else '✗'}")
-        report.append(f"{key}: {' | '.join(status)}")
-
-    report.append("-" * 40)
-    totals = []
-    for locale in config.locales:
-        count = sum(1 for k in matrix.keys if matrix.matrix[k][locale])
-        totals.append(f"{locale}: {count}/{len(matrix.keys)}")
-    report.append(f"TOTALS: {' | '.join(totals)}")
+    rows = [
+        [key, *("✓" if matrix.matrix[key][loc] else "✗" for loc in config.locales)]
+        for key in matrix.keys
+    ]
+    totals = [
+        f"{sum(1 for k in matrix.keys if matrix.matrix[k][loc])}/{len(matrix.keys)}"
+        for loc in config.locales
+    ]
+    rows.append(["TOTAL", *totals])
+
+    table = render_table(
+        ["Key", *config.locales],
+        rows,
+        max_widths=[48, *([None] * len(config.locales))],
+        overflow="ellipsis",
+    )
+    report = [f"i18n Audit for {settings_path}", "", table]
 
     return "\n".join(report)
 
@@ -0,0 +1,98 @@
+"""
+mcp/limits.py — middleware bounding how many tool calls run, and how often.
+
+Two git commands mutating one repository at once fight over its index
+lock, so each server registers a ``CallLimiter`` naming its serialized
+tools (its mutating ones): those run one at a time per repository, while
+read-only tools only share the server-wide ``mcp_max_concurrent_calls``
+slots.  A call waits up to ``mcp_queue_timeout`` seconds for its turn,
+then fails with a "busy" error naming what holds the repository.
+``mcp_rate_limits`` caps calls per minute for individual tools (e.g.
+``{"create_release": 2}``); a call over the cap fails straight away with
+the wait until the next one is allowed.
+"""
+
+import asyncio
+import time
+from collections import defaultdict, deque
+from collections.abc import Iterable
+
+from fastmcp.exceptions import ToolError
+from fastmcp.server.middleware import Middleware, MiddlewareContext
+
+from azathoth.config import get_config
+from azathoth.core.repo_config import find_repo_root
+
+_WINDOW = 60.0  # seconds; mcp_rate_limits are calls per minute
+
+
+class CallLimiter(Middleware):
+    """Serializes *serialized_tools* per repository, caps concurrent calls
+    and applies per-tool rate limits.
+
+    Register it after any middleware that selects the repository a call
+    works on (``repo_path``), since that decides which lock it takes.
+    """
+
+    def __init__(self, serialized_tools: Iterable[str] = ()):
+        self.serialized_tools = frozenset(serialized_tools)
+        self._repo_locks: defaultdict[str, asyncio.Lock] = defaultdict(asyncio.Lock)
+        self._holders: dict[str, str] = {}
+        self._calls: defaultdict[str, deque[float]] = defaultdict(deque)
+        self._slots: asyncio.Semaphore | None = None
+        self._slot_count = 0
+
+    def _check_rate(self, name: str) -> None:
+        limit = get_config().mcp_rate_limits.get(name)
+        if limit is None:
+            return
+        now = time.monotonic()
+        calls = self._calls[name]
+        while calls and now - calls[0] >= _WINDOW:
+            calls.popleft()
+        if len(calls) >= limit:
+            wait = _WINDOW - (now - calls[0])
+            raise ToolError(
+                f"Rate limited: {name} allows {limit} call(s) per minute; "
+                f"retry in {wait:.0f}s."
+            )
+        calls.append(now)
+
+    def _semaphore(self) -> asyncio.Semaphore | None:
+        count = get_config().mcp_max_concurrent_calls
+        if count <= 0:
+            return None
+        if self._slots is None or count != self._slot_count:
+            self._slots, self._slot_count = asyncio.Semaphore(count), count
+        return self._slots
+
+    async def _acquire(self, lock: asyncio.Lock | asyncio.Semaphore, busy: str):
+        timeout = get_config().mcp_queue_timeout
+        try:
+            await asyncio.wait_for(lock.acquire(), timeout)
+        except asyncio.TimeoutError:
+            message = f"Busy: {busy}; waited {timeout:.0f}s. Retry shortly."
+            raise ToolError(message) from None
+
+    async def on_call_tool(self, context: MiddlewareContext, call_next):
+        name = context.message.name
+        self._check_rate(name)
+        slots = self._semaphore()
+        if slots is not None:
+            await self._acquire(slots, "every tool-call slot is in use")
+        try:
+            if name not in self.serialized_tools:
+                return await call_next(context)
+            repo = str(find_repo_root())
+            lock = self._repo_locks[repo]
+            holder = self._holders.get(repo, "another tool")
+            await self._acquire(lock, f"{holder} is running in {repo}")
+            self._holders[repo] = name
+            try:
+                return await call_next(context)
+            finally:
+                self._holders.pop(repo, None)
+                lock.release()
+        finally:
+            if slots is not None:
+                slots.release()
@@ -0,0 +1,94 @@
+"""
+mcp/policy.py — middleware enforcing core/policy.py across MCP servers.
+
+Each server lists its mutating tools, which of those are destructive, and
+which become destructive when a flag argument is set, and registers a
+``MutationGuard`` so the pause kill-switch and approval mode are honoured
+uniformly.  Unlisted tools are read-only.
+
+Tools that hit a risky case mid-call (an unmerged branch, a force push, a
+dirty tree) ask the user with ``confirm_with_user`` — an MCP elicitation
+the client shows as a yes/no question — rather than failing or proceeding
+silently.
+"""
+
+from collections.abc import Iterable, Mapping
+
+from fastmcp import Context
+from fastmcp.exceptions import ToolError
+from fastmcp.server.middleware import Middleware, MiddlewareContext
+from mcp.shared.exceptions import McpError
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import PolicyDenied
+from azathoth.core.policy import ToolClass, ensure_approved, ensure_mutations_allowed
+
+#: Key in a tool call's ``_meta`` that carries the approval token.
+APPROVAL_META_KEY = "approval_token"
+
+
+class MutationGuard(Middleware):
+    """Denies calls to mutating tools while mutations are paused, and calls
+    to destructive tools in approval mode without the approval token.
+
+    *destructive_flags* maps a mutating tool to the boolean argument that
+    makes a call to it destructive (e.g. ``push`` with ``force_with_lease``).
+    Calls made with ``dry_run=True`` change nothing and are let through.
+    """
+
+    def __init__(
+        self,
+        mutating_tools: Iterable[str],
+        destructive_tools: Iterable[str] = (),
+        destructive_flags: Mapping[str, str] | None = None,
+    ):
+        self.destructive_tools = frozenset(destructive_tools)
+        self.destructive_flags = dict(destructive_flags or {})
+        self.mutating_tools = (
+            frozenset(mutating_tools)
+            | self.destructive_tools
+            | frozenset(self.destructive_flags)
+        )
+
+    def tool_class(self, name: str, arguments: Mapping | None = None) -> ToolClass:
+        if name in self.destructive_tools:
+            return "destructive"
+        flag = self.destructive_flags.get(name)
+        if flag is not None and (arguments or {}).get(flag) is True:
+            return "destructive"
+        return "mutating" if name in self.mutating_tools else "read_only"
+
+    async def on_call_tool(self, context: MiddlewareContext, call_next):
+        name = context.message.name
+        arguments = context.message.arguments or {}
+        kind = self.tool_class(name, arguments)
+        if kind != "read_only" and arguments.get("dry_run") is not True:
+            try:
+                ensure_mutations_allowed(name)
+                if kind == "destructive":
+                    ensure_approved(name, _approval_token(context))
+            except PolicyDenied as exc:
+                raise ToolError(str(exc)) from exc
+        return await call_next(context)
+
+
+def _approval_token(context: MiddlewareContext) -> str | None:
+    meta = getattr(context.message, "meta", None)
+    token = getattr(meta, APPROVAL_META_KEY, None) if meta is not None else None
+    return token if isinstance(token, str) else None
+
+
+async def confirm_with_user(ctx: Context | None, question: str) -> bool | None:
+    """Ask the user *question* through the client.
+
+    Returns whether they accepted, or ``None`` when nobody can be asked:
+    no request context, ``elicit_confirmations`` off, or a client without
+    elicitation support.  Callers then keep their non-interactive behaviour.
+    """
+    if ctx is None or not get_config().elicit_confirmations:
+        return None
+    try:
+        result = await ctx.elicit(question, response_type=None)
+    except McpError:
+        return None
+    return result.action == "accept"
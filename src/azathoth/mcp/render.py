@@ -0,0 +1,53 @@
+"""
+mcp/render.py — middleware passing tool text through core/render.py.
+
+Each server registers a ``RenderOutput`` under its name, so its render mode
+(``mcp_render_modes``) applies to every text result and tool error.  Tools
+whose output has a syntax worth highlighting name a fence info string
+(``{"get_diff": "diff"}``) used in ``markdown`` mode.  Structured results
+are JSON and pass through unchanged.
+"""
+
+from collections.abc import Mapping
+
+from fastmcp.exceptions import ToolError
+from fastmcp.server.middleware import Middleware, MiddlewareContext
+from fastmcp.tools.tool import ToolResult
+from mcp.types import TextContent
+
+from azathoth.core.render import render, render_mode
+
+
+class RenderOutput(Middleware):
+    """Renders text results and error messages in the server's mode."""
+
+    def __init__(self, server: str, fenced: Mapping[str, str] | None = None):
+        self.server = server
+        self.fenced = dict(fenced or {})
+
+    async def on_call_tool(self, context: MiddlewareContext, call_next):
+        mode = render_mode(self.server)
+        if mode == "ansi":
+            return await call_next(context)
+        fence = self.fenced.get(context.message.name)
+        try:
+            result = await call_next(context)
+        except ToolError as exc:
+            raise ToolError(render(str(exc), mode)) from exc
+        return _render_result(result, lambda text: render(text, mode, fence))
+
+
+def _render_result(result: ToolResult, render_text) -> ToolResult:
+    structured = result.structured_content
+    if structured is not None:
+        # A str-returning tool's text is mirrored as {"result": text}.
+        if set(structured) != {"result"} or not isinstance(structured["result"], str):
+            return result
+        structured = {"result": render_text(structured["result"])}
+    content = [
+        block.model_copy(update={"text": render_text(block.text)})
+        if isinstance(block, TextContent)
+        else block
+        for block in result.content
+    ]
+    return ToolResult(content=content, structured_content=structured)
@@ -0,0 +1,32 @@
+"""
+mcp/sampling.py — let tools borrow the connected client's model.
+
+``sample_with_client`` sends one prompt back to the client as an MCP
+sampling request, so a tool that needs generated text (a commit message)
+works without any provider configured on the server.  How tools choose
+between the client's model and the providers is ``llm_sampling``.
+"""
+
+from fastmcp import Context
+from mcp.shared.exceptions import McpError
+
+#: Upper bound on the client's reply; commit messages and notes are short.
+SAMPLING_MAX_TOKENS = 1024
+
+
+async def sample_with_client(
+    ctx: Context | None, system_prompt: str, user_message: str
+) -> str | None:
+    """The client model's reply to *user_message*, or ``None`` when there is
+    no request context or the client does not support sampling."""
+    if ctx is None:
+        return None
+    try:
+        reply = await ctx.sample(
+            user_message,
+            system_prompt=system_prompt,
+            max_tokens=SAMPLING_MAX_TOKENS,
+        )
+    except McpError:
+        return None
+    return getattr(reply, "text", None)
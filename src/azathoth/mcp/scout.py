@@ -0,0 +1,333 @@
+"""
+mcp/scout.py — MCP server exposing project reconnaissance tools.
+
+Presentation layer only — every tool wraps exactly one core/ operation.
+Runs on stdio transport via `uv run scout`.
+"""
+
+from pathlib import Path
+from typing import Literal
+
+from fastmcp import FastMCP
+from fastmcp.exceptions import ToolError
+
+from azathoth.config import get_config
+from azathoth.core import prompts
+from azathoth.core.assets import AssetCatalog
+from azathoth.core.assets import catalog_assets as core_catalog_assets
+from azathoth.core.config_drift import ConfigDriftReport, check_config_drift
+from azathoth.core.dependencies import DependencyReport
+from azathoth.core.dependencies import dependencies as core_dependencies
+from azathoth.core.detect import StackDetection
+from azathoth.core.detect import detect_stack as core_detect_stack
+from azathoth.core.doc_drift import DriftReport, check_doc_drift
+from azathoth.core.embeddings import get_embedder
+from azathoth.core.exceptions import ProviderError, SandboxError, WorkflowError
+from azathoth.core.files import (
+    DirectoryListing,
+    FileContent,
+    GlobResult,
+    Sandbox,
+    glob_files,
+    resolve_inside,
+)
+from azathoth.core.files import list_directory as core_list_directory
+from azathoth.core.files import read_file as core_read_file
+from azathoth.core.licenses import LicenseReport
+from azathoth.core.licenses import license_report as core_license_report
+from azathoth.core.markers import MarkerReport
+from azathoth.core.markers import scan_markers as core_scan_markers
+from azathoth.core.module_map import ModuleMap
+from azathoth.core.module_map import module_map as core_module_map
+from azathoth.core.outline import Outline
+from azathoth.core.outline import outline as core_outline
+from azathoth.core.repo_stats import RepoStats
+from azathoth.core.repo_stats import repo_stats as core_repo_stats
+from azathoth.core.search import SearchResult
+from azathoth.core.search import search as core_search
+from azathoth.core.secrets import SecretReport, scan_staged, scan_tree
+from azathoth.core.semantic import SemanticResult
+from azathoth.core.semantic import semantic_search as core_semantic_search
+from azathoth.core.stack import StackProfile, stack_profile as core_stack_profile
+from azathoth.core.summarize import DirectorySummary
+from azathoth.core.summarize import summarize_directory as core_summarize_directory
+from azathoth.mcp.audit import AuditLog
+from azathoth.mcp.directives import register_directive_resources
+from azathoth.mcp.health import register_health
+from azathoth.mcp.limits import CallLimiter
+from azathoth.mcp.render import RenderOutput
+from azathoth.mcp.telemetry import Telemetry
+
+mcp = FastMCP(
+    name="azathoth-scout",
+    instructions=(
+        "Project reconnaissance tools. Start with detect_stack: it reports "
+        "the project's languages and frameworks from its manifests and which "
+        "directives to pass to adapt. Call stack_profile before recommending "
+        "or adding a library: it reports which libraries the project already "
+        "uses for each concern (HTTP client, serialization, testing, logging, "
+        "…) so new code reuses them instead of introducing alternatives. "
+        "repo_stats measures lines of code per language, the largest files "
+        "and recent commit activity. dependencies lists every dependency with "
+        "its resolved version, direct or transitive, dev or runtime, read from "
+        "the manifests and lockfiles, and license_report checks the project "
+        "license and flags dependency licenses that conflict with it. "
+        "Use doc_drift to find stale commands, paths, badges and versions in "
+        "the docs before a documentation fix, and config_drift to flag keys "
+        "missing or differently typed between environment config files. "
+        "catalog_assets inventories images, fonts, models and fixtures and "
+        "flags the ones no source file mentions. "
+        "scan_markers collects TODO/FIXME/HACK/XXX comments by file and author "
+        "with a Markdown summary for tech-debt sections. "
+        "Run scan_secrets before committing: it flags AWS keys, private keys, "
+        ".env files and high-entropy tokens in the staged changes. "
+        "For architecture, module_map outlines binaries vs libraries, entry "
+        "points and the size of each module. "
+        "To understand a large codebase, call summarize_directory bottom-up "
+        "(leaf subdirectories first) instead of reading every file. "
+        "read_file, list_directory and glob give read-only access confined "
+        "to target_directory, never entering .git/objects, node_modules or "
+        "other scout_deny paths; binary files are reported, not returned. "
+        "Every tool's target_directory is relative to the sandbox root "
+        "(scout_root, or the server's working directory) and may not leave "
+        "it. "
+        "outline lists a file's or directory's functions, types and methods "
+        "with signatures, without the bodies. "
+        "search finds a regex or literal across the project with line numbers "
+        "and context — use it for \"where is X defined or used\". "
+        "When scout_embeddings is configured, semantic_search answers "
+        "questions like \"where do we retry failed HTTP requests\" with the "
+        "most relevant code snippets, even when the wording differs. "
+        "Coding directives are available as directive://<name> resources. "
+        "The explore prompt scripts a full scouting pass into an overview "
+        "report; the onboard prompt turns the same tools (with adapt) into an "
+        "onboarding guide for a new backend, frontend or devops developer."
+    ),
+)
+
+mcp.add_middleware(AuditLog("scout"))
+mcp.add_middleware(Telemetry("scout"))
+mcp.add_middleware(RenderOutput("scout"))
+mcp.add_middleware(CallLimiter())
+register_directive_resources(mcp)
+register_health(mcp, "scout")
+
+
+# ── Helpers ──────────────────────────────────────────────────────────────
+
+
+def _sandbox(target_directory: str) -> Sandbox:
+    """The file tools' sandbox: *target_directory* minus ``scout_deny``."""
+    return Sandbox.for_root(_target(target_directory))
+
+
+def _target(target_directory: str) -> Path:
+    """*target_directory*, confined to ``scout_root`` (by default the
+    server's working directory), so callers cannot pick the sandbox."""
+    root = get_config().scout_root or Path.cwd()
+    try:
+        return resolve_inside(root, target_directory)
+    except SandboxError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+# ── Tools ────────────────────────────────────────────────────────────────
+
+
+@mcp.tool()
+async def detect_stack(target_directory: str = ".") -> StackDetection:
+    """Detect the project's languages (primary first) and frameworks from its manifests — Cargo.toml, package.json, pyproject.toml/requirements.txt, go.mod, pom.xml/build.gradle — at the root and two levels below, plus Dockerfile/compose files. directives lists the names to pass to adapt; use this instead of reading manifests by hand."""
+    return core_detect_stack(_target(target_directory))
+
+
+@mcp.tool()
+async def stack_profile(target_directory: str = ".") -> StackProfile:
+    """Summarise the libraries a project already relies on, grouped by concern (HTTP client, serialization, testing, logging, CLI, web framework, …). Evidence comes from manifests (pyproject.toml, package.json, Cargo.toml, go.mod) and source imports; use it to prefer established libraries over new dependencies."""
+    return core_stack_profile(_target(target_directory))
+
+
+@mcp.tool()
+async def repo_stats(target_directory: str = ".") -> RepoStats:
+    """Measured size of the project: lines of code, comments and blanks per language (from tokei when installed, else a built-in line counter; counter says which), file count, the largest files, and commit activity over the last twelve weeks (commits, authors, commits per week). Use it to ground the technology-stack section of a report in numbers."""
+    return await core_repo_stats(_target(target_directory))
+
+
+@mcp.tool()
+async def dependencies(target_directory: str = ".") -> DependencyReport:
+    """Normalized dependency list from Cargo.toml/Cargo.lock, package.json/package-lock.json, pyproject.toml/requirements.txt with uv.lock or poetry.lock, and go.mod: name, ecosystem, version (resolved from the lockfile, else the declared spec), direct vs transitive and dev vs runtime. Transitive packages only appear when a lockfile is present; lockfiles lists the ones read. Use it instead of reading manifests or lockfiles raw."""
+    return core_dependencies(_target(target_directory))
+
+
+@mcp.tool()
+async def license_report(target_directory: str = ".") -> LicenseReport:
+    """Detect the project license (LICENSE/COPYING files recognised by their wording, plus the license declared in pyproject.toml, Cargo.toml or package.json) and each dependency's license from metadata already on disk (node_modules, .venv dist-info, cargo registry, Go module cache). issues lists missing license files, undeclared or mismatched licenses, runtime dependencies whose license is incompatible with the project's (e.g. GPL in an MIT project) or needs review (weak copyleft), and dependencies with no license metadata. Dev dependencies are never flagged."""
+    return core_license_report(_target(target_directory))
+
+
+@mcp.tool()
+async def doc_drift(target_directory: str = ".") -> DriftReport:
+    """Cross-check README/CONTRIBUTING/docs claims against the repo: shell commands (just/make/npm/uv/cargo targets), linked and inline file paths, registry badges and pinned versions. Each issue gives file, line and the stale reference, so doc fixes have concrete targets."""
+    return check_doc_drift(_target(target_directory))
+
+
+@mcp.tool()
+async def config_drift(target_directory: str = ".") -> ConfigDriftReport:
+    """Compare environment-specific config files that belong together (config/dev.toml vs config/prod.toml, settings.staging.json vs settings.production.json, .env vs .env.example vs .env.production.example) and report keys present in some but not others, plus value-shape mismatches (integer vs string, url vs plain string, table vs scalar). Only key names and shapes are reported, never values."""
+    return check_config_drift(_target(target_directory))
+
+
+@mcp.tool()
+async def module_map(target_directory: str = ".", depth: int = 3) -> ModuleMap:
+    """Structural outline of the source tree: targets (binaries vs libraries) declared by Cargo.toml (including workspace members and src/bin), pyproject.toml scripts and package, package.json bin/main/exports and Go package main directories; entry points (binary entry files plus conventional __main__.py, main.go, main.rs, …); and modules — source directories cut at depth levels — with files, lines, public symbol count and docstring, largest first. Use it for the architecture section of a report, then summarize_directory on the modules worth a closer look."""
+    return core_module_map(_target(target_directory), depth)
+
+
+@mcp.tool()
+async def summarize_directory(
+    directory: str, target_directory: str = "."
+) -> DirectorySummary:
+    """Structured summary of one directory of the repo at target_directory: purpose (README or package docstring), key files ranked by inbound imports, public surface (exported symbols of its own files), internal directories it depends on and that depend on it, and external packages. Lists subdirectories so whole-repo summaries can be built bottom-up; content_hash changes only when a file under the directory does. Per-file facts are cached by content, so repeated calls on a large repo stay cheap."""
+    try:
+        return core_summarize_directory(_target(target_directory), directory)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def catalog_assets(target_directory: str = ".") -> AssetCatalog:
+    """Inventory the project's images, fonts, 3D/ML models and test fixtures (files under fixtures/ or testdata/) with path, format, size in bytes and, for PNG/JPEG/GIF/WebP/BMP/SVG, pixel dimensions read from the file header. referenced=false means no source, markup or config file mentions the asset's file name — a candidate for removal, to be confirmed before deleting (dynamic paths are not resolved)."""
+    return core_catalog_assets(_target(target_directory))
+
+
+@mcp.tool()
+async def scan_markers(target_directory: str = ".") -> MarkerReport:
+    """Find TODO/FIXME/HACK/XXX comments in target_directory, skipping files .gitignore excludes. Each marker has path, line, tag, text and, for tracked files, the author and date from git blame; by_file and by_author group the counts (most first), and markdown is a ready-made summary to paste into a report."""
+    return await core_scan_markers(_target(target_directory))
+
+
+@mcp.tool()
+async def scan_secrets(
+    target_directory: str = ".", whole_tree: bool = False
+) -> SecretReport:
+    """Check the staged changes of the git repo at target_directory for likely credentials: AWS access key ids and secret keys, private key blocks, .env files (not .env.example and other templates) and high-entropy tokens. Only added lines are checked; each finding has path, line (0 for a whole file), kind and a redacted excerpt. whole_tree=True scans every file .gitignore does not exclude instead. Run it before stage_and_commit, which can refuse on findings with block_secrets=True."""
+    root = _target(target_directory)
+    if whole_tree:
+        return await scan_tree(root)
+    try:
+        return await scan_staged(root)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def read_file(
+    path: str,
+    target_directory: str = ".",
+    start_line: int = 1,
+    max_lines: int | None = None,
+) -> FileContent:
+    """Read a text file inside target_directory, starting at start_line (1-based) for at most max_lines lines, capped at AZATHOTH_SCOUT_MAX_READ_BYTES. truncated=true means more lines follow end_line — call again with start_line=end_line+1. Binary files come back with binary=true and no content; paths that leave target_directory (.., absolute, symlinks out) or fall under a scout_deny pattern (.git/objects, node_modules by default) are refused."""
+    try:
+        return core_read_file(_sandbox(target_directory), path, start_line, max_lines)
+    except SandboxError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def list_directory(
+    path: str = ".", target_directory: str = ".", recursive: bool = False
+) -> DirectoryListing:
+    """List a directory inside target_directory (directories first, files with sizes). With recursive=true, walks breadth-first but does not descend into dependency, build or VCS directories (node_modules, target, .git, …) or scout_deny paths; a path under a scout_deny pattern is refused. Entries excluded by the project's .gitignore/.ignore files or scout_exclude are left out. Stops at AZATHOTH_SCOUT_MAX_ENTRIES entries with truncated=true."""
+    try:
+        return core_list_directory(_sandbox(target_directory), path, recursive)
+    except SandboxError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def glob(pattern: str, target_directory: str = ".") -> GlobResult:
+    """Find paths inside target_directory matching a glob pattern relative to it (``**`` recurses, e.g. "src/**/*.py"). Matches under dependency, build and VCS directories, and paths excluded by .gitignore/.ignore or scout_exclude, are skipped; absolute patterns and .. are refused. Capped at AZATHOTH_SCOUT_MAX_ENTRIES matches."""
+    try:
+        return glob_files(_sandbox(target_directory), pattern)
+    except SandboxError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def outline(
+    path: str = ".", target_directory: str = ".", public_only: bool = False
+) -> Outline:
+    """Compact outline of a source file, or of every Rust, Python, TypeScript/JavaScript and Go file below a directory, inside target_directory: functions, structs/classes, enums, traits/interfaces, impl blocks, type aliases and constants with their signature, line span and whether they are public; methods nest under their class, impl or trait. public_only=true keeps the public API. Much cheaper than read_file for understanding what a module offers — read the bodies only where they matter. Parsed with tree-sitter when installed (the outline extra); parser says which parser was used."""
+    try:
+        return core_outline(_sandbox(target_directory), path, public_only)
+    except SandboxError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def search(
+    pattern: str,
+    target_directory: str = ".",
+    fixed_strings: bool = False,
+    ignore_case: bool = False,
+    glob: str | None = None,
+    context: int = 2,
+    max_results: int | None = None,
+) -> SearchResult:
+    """Search the files inside target_directory for a regular expression (Python syntax; fixed_strings=true for a literal), line by line like ripgrep. Each match has path, line number, the line and up to context lines before and after. glob narrows the files ("*.rs" by name, "src/*/models.py" by path). Skips binary files, files over 1 MB and what .gitignore, scout_exclude or scout_deny exclude; stops at max_results (default AZATHOTH_SCOUT_MAX_SEARCH_RESULTS) with truncated=true. Use it to find where a symbol is defined or used instead of reading files."""
+    try:
+        return core_search(
+            _sandbox(target_directory),
+            pattern,
+            fixed_strings=fixed_strings,
+            ignore_case=ignore_case,
+            glob=glob,
+            context=context,
+            max_results=max_results,
+        )
+    except (SandboxError, WorkflowError) as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def semantic_search(
+    query: str, target_directory: str = ".", limit: int = 10, glob: str | None = None
+) -> SemanticResult:
+    """Find the code inside target_directory that best answers a natural-language query ("where is the retry logic for HTTP calls?"), ranked by embedding similarity; each match has path, line span, score and the snippet. glob narrows the files like in search. Source files are embedded once and cached in .azathoth/cache, so only changed files are re-embedded. Needs scout_embeddings set to "ollama" or "openai"; use search for exact names."""
+    embedder = get_embedder()
+    if embedder is None:
+        raise ToolError(
+            "Semantic search is off; set scout_embeddings to 'ollama' or 'openai'."
+        )
+    try:
+        return await core_semantic_search(
+            _sandbox(target_directory), query, embedder, limit=limit, glob=glob
+        )
+    except (SandboxError, ProviderError) as exc:
+        raise ToolError(str(exc)) from exc
+
+
+# ── Prompts ──────────────────────────────────────────────────────────
+
+
+@mcp.prompt()
+async def explore(target_directory: str = ".") -> str:
+    """Scout a codebase with the tools above and write a Markdown overview of its stack, structure and tech debt."""
+    return prompts.explore(target_directory)
+
+
+@mcp.prompt()
+async def onboard(
+    target_directory: str = ".",
+    role: Literal["backend", "frontend", "devops"] = "backend",
+) -> str:
+    """Write a Markdown onboarding guide for a new developer in the given role (backend, frontend or devops): stack, setup, where things live, the conventions from adapt, pitfalls and first tasks."""
+    return prompts.onboard(target_directory, role)
+
+
+# ── Entry point ──────────────────────────────────────────────────────────
+
+
+def run():
+    """Script entry point: `uv run scout`."""
+    mcp.run(transport="stdio")
@@ -0,0 +1,137 @@
+"""
+mcp/telemetry.py — spans, metrics and logs for every tool and prompt call.
+
+Each server registers a ``Telemetry`` under its name.  It wraps every
+call in an OpenTelemetry span (``tool stage_and_commit``,
+``prompt autocommit``) and records two instruments: ``azathoth.mcp.calls``,
+a counter by server, kind, name and outcome, and ``azathoth.mcp.duration``,
+a histogram in milliseconds — enough for call counts, latencies and
+failure rates per tool.  Without the ``otel`` extra the spans and metrics
+are skipped and only the debug log line remains.
+
+``configure_telemetry`` is run by ``azathoth serve``: it sends logs to
+stderr (stdout belongs to the stdio transport) at ``mcp_log_level`` and,
+when ``mcp_otlp_endpoint`` is set, exports spans and metrics over OTLP/HTTP.
+"""
+
+import logging
+import sys
+import time
+from collections.abc import Awaitable, Callable
+from contextlib import nullcontext
+from typing import Any
+
+from fastmcp.server.middleware import Middleware, MiddlewareContext
+
+from azathoth.config import get_config
+
+try:
+    from opentelemetry import metrics, trace
+except ImportError:  # the otel extra is not installed
+    metrics = trace = None
+
+log = logging.getLogger("azathoth.mcp")
+
+_SCOPE = "azathoth.mcp"
+
+
+class Telemetry(Middleware):
+    """Traces, counts and times the tool and prompt calls of *server*."""
+
+    def __init__(self, server: str):
+        self.server = server
+        self._tracer = trace.get_tracer(_SCOPE) if trace else None
+        meter = metrics.get_meter(_SCOPE) if metrics else None
+        self._calls = (
+            meter.create_counter("azathoth.mcp.calls", description="MCP calls")
+            if meter
+            else None
+        )
+        self._duration = (
+            meter.create_histogram(
+                "azathoth.mcp.duration", unit="ms", description="MCP call latency"
+            )
+            if meter
+            else None
+        )
+
+    async def _observe(
+        self, kind: str, name: str, call: Callable[[], Awaitable[Any]]
+    ) -> Any:
+        attributes = {"mcp.server": self.server, "mcp.kind": kind, "mcp.name": name}
+        span = (
+            self._tracer.start_as_current_span(f"{kind} {name}", attributes=attributes)
+            if self._tracer
+            else nullcontext()
+        )
+        started = time.perf_counter()
+        outcome = "ok"
+        with span:
+            try:
+                return await call()
+            except Exception:
+                outcome = "error"
+                raise
+            finally:
+                elapsed = (time.perf_counter() - started) * 1000
+                log.debug(
+                    "%s %s/%s: %s in %.1f ms", kind, self.server, name, outcome, elapsed
+                )
+                if self._calls is not None:
+                    self._calls.add(1, {**attributes, "mcp.outcome": outcome})
+                if self._duration is not None:
+                    self._duration.record(elapsed, attributes)
+
+    async def on_call_tool(self, context: MiddlewareContext, call_next):
+        return await self._observe(
+            "tool", context.message.name, lambda: call_next(context)
+        )
+
+    async def on_get_prompt(self, context: MiddlewareContext, call_next):
+        return await self._observe(
+            "prompt", context.message.name, lambda: call_next(context)
+        )
+
+
+def configure_telemetry(server: str) -> None:
+    """Log to stderr and, with ``mcp_otlp_endpoint``, export over OTLP."""
+    settings = get_config()
+    logging.basicConfig(
+        stream=sys.stderr,
+        level=settings.mcp_log_level,
+        format="%(asctime)s %(levelname)s %(name)s: %(message)s",
+    )
+    if not settings.mcp_otlp_endpoint:
+        return
+    try:
+        from opentelemetry.exporter.otlp.proto.http.metric_exporter import (
+            OTLPMetricExporter,
+        )
+        from opentelemetry.exporter.otlp.proto.http.trace_exporter import (
+            OTLPSpanExporter,
+        )
+        from opentelemetry.sdk.metrics import MeterProvider
+        from opentelemetry.sdk.metrics.export import PeriodicExportingMetricReader
+        from opentelemetry.sdk.resources import Resource
+        from opentelemetry.sdk.trace import TracerProvider
+        from opentelemetry.sdk.trace.export import BatchSpanProcessor
+    except ImportError:
+        log.warning(
+            "mcp_otlp_endpoint is set but the otel extra is missing "
+            "(install azathoth[otel]); telemetry is not exported."
+        )
+        return
+
+    endpoint = settings.mcp_otlp_endpoint.rstrip("/")
+    resource = Resource.create({"service.name": f"azathoth-{server}"})
+    tracer_provider = TracerProvider(resource=resource)
+    tracer_provider.add_span_processor(
+        BatchSpanProcessor(OTLPSpanExporter(endpoint=f"{endpoint}/v1/traces"))
+    )
+    trace.set_tracer_provider(tracer_provider)
+    reader = PeriodicExportingMetricReader(
+        OTLPMetricExporter(endpoint=f"{endpoint}/v1/metrics")
+    )
+    meter_provider = MeterProvider(resource=resource, metric_readers=[reader])
+    metrics.set_meter_provider(meter_provider)
+    log.info("Exporting telemetry for %s to %s", server, endpoint)
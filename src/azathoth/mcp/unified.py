@@ -0,0 +1,52 @@
+"""
+mcp/unified.py — workflow, scout and directives behind one MCP server.
+
+Mounts the three servers in one process so a client needs a single entry
+instead of three.  Tool and prompt names are namespaced with a dot:
+``git.stage_and_commit``, ``scout.dependencies``, ``style.adapt``.  Each
+mounted server keeps its own middleware (audit, render modes, the mutation
+guard), which sees the tool's unprefixed name.  Assembled with
+``CompositeServer`` from ``mcp/compose.py``.
+Runs via `azathoth serve unified`.
+"""
+
+from fastmcp import FastMCP
+
+from azathoth.mcp.compose import CompositeServer
+from azathoth.mcp.directives import mcp as directives
+from azathoth.mcp.scout import mcp as scout
+from azathoth.mcp.workflow import mcp as workflow
+
+#: namespace → mounted server
+NAMESPACES: dict[str, FastMCP] = {
+    "git": workflow,
+    "scout": scout,
+    "style": directives,
+}
+
+mcp = CompositeServer(
+    "azathoth",
+    instructions=(
+        "Every azathoth tool in one server, namespaced by family. git.* tools "
+        "inspect and change the repository (status, diff, commit, branches, "
+        "releases); mutating ones take dry_run. scout.* tools profile an "
+        "unfamiliar project (stack, dependencies, module map, tech debt, "
+        "secrets, licenses). style.* tools return coding directives: call "
+        "style.adapt with the project's languages before writing code. The "
+        "git.autocommit, git.autorelease and git.autotriage prompts script a "
+        "full commit, release or issue triage with the git.* tools, and "
+        "scout.explore a full scouting pass."
+    ),
+)
+
+
+for _namespace, _server in NAMESPACES.items():
+    mcp.add(_server, prefix=_namespace)
+
+
+# ── Entry point ──────────────────────────────────────────────────────────
+
+
+def run():
+    """Script entry point: `azathoth serve unified`."""
+    mcp.run(transport="stdio")
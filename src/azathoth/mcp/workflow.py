@@ -1,152 +1,2037 @@
 """
 mcp/workflow.py — MCP server exposing git workflow tools.
 
-Presentation layer only — every tool wraps exactly one core/ operation.
+Presentation layer over core/: git, policy and forge logic lives there.
+Tools sequence those operations (stage_and_commit stages, scans for
+secrets, runs the hooks, asks the LLM, checks the commit policy and
+commits) and turn their errors into ToolError.  Middleware selects the
+repo_path repository, enforces the pause and approval mode, and serializes
+mutating calls per repository.
 Runs on stdio transport via `uv run workflow`.
 """
 
 import json
+import sys
+from collections.abc import Iterator
+from contextlib import contextmanager
+from typing import Annotated, Any
 
-from fastmcp import FastMCP
+from fastmcp import Context, FastMCP
+from fastmcp.exceptions import PromptError, ToolError
+from pydantic import Field
+from fastmcp.server.middleware import Middleware, MiddlewareContext
 
 from azathoth.core.workflow import (
-    stage_all,
-    commit,
-    get_diff as core_get_diff,
+    CommitInfo,
+    DiffSummary,
+    GitResult,
+    RepoStatus,
+    format_command,
+    get_diff_summary,
+    get_log_entries,
+    get_range_diff,
+    get_repo_status,
     get_latest_tag,
     get_log_since,
     create_release as core_create_release,
-    _run_git,
 )
-from azathoth.core.prompts import get_commit_system_prompt, get_release_system_prompt
+from azathoth.core import focus, policy, prompts
+from azathoth.core.artifacts import ReleaseArtifacts
+from azathoth.core.checks import CheckKind, CheckResult, SuiteResult
+from azathoth.core.checks import run_check as core_run_check
+from azathoth.core.checks import run_tests as core_run_tests
+from azathoth.core.artifacts import (
+    build_release_artifacts as core_build_release_artifacts,
+)
+from azathoth.core.files import FileWrite, Sandbox
+from azathoth.core.files import edit_file as core_edit_file
+from azathoth.core.files import write_file as core_write_file
+from azathoth.core.focus import FocusSession
+from azathoth.core.branches import (
+    BranchInfo,
+    create_branch as core_create_branch,
+    delete_branch as core_delete_branch,
+    list_branches as core_list_branches,
+    switch_branch as core_switch_branch,
+)
+from azathoth.core.commit_graph import CommitGraph, get_commit_graph
+from azathoth.core.conflicts import (
+    ConflictReport,
+    abort_operation,
+    continue_operation,
+    list_conflicts as core_list_conflicts,
+    resolve_conflict as core_resolve_conflict,
+)
+from azathoth.core.commit_lint import CommitLint
+from azathoth.core.commit_lint import lint_commit_message as core_lint_commit_message
+from azathoth.core.commit_policy import load_commit_policy
+from azathoth.core.defaults import VersionSuggestion
+from azathoth.core.detect import languages_of
+from azathoth.core.diffs import DiffPage, split_diff
+from azathoth.core.diffs import summarize_diff as core_summarize_diff
+from azathoth.core.directives import build_master_context
+from azathoth.core.crates import CratePublish
+from azathoth.core.crates import publish_crate as core_publish_crate
+from azathoth.core.defaults import suggest_next_version as core_suggest_next_version
+from azathoth.core.github import PullRequest
+from azathoth.core.github import create_pull_request as core_create_pull_request
+from azathoth.core.history import BlameReport, FileHistory
+from azathoth.core.history import blame_range as core_blame_range
+from azathoth.core.history import file_history as core_file_history
+from azathoth.core.hooks import detect_hooks, hook_command, run_hooks
+from azathoth.core.preflight import PreflightReport
+from azathoth.core.preflight import preflight as core_preflight
+from azathoth.core.progress import stream_output
+from azathoth.core.promote import Promotion
+from azathoth.core.promote import (
+    promote_release_candidate as core_promote_release_candidate,
+)
+from azathoth.core.render import render, render_mode
+from azathoth.core.repo_config import find_repo_root
+from azathoth.core.issues import CloseReason, IssueAction, IssueInfo, IssueState
+from azathoth.core.issues import close_issue as core_close_issue
+from azathoth.core.issues import comment_issue as core_comment_issue
+from azathoth.core.issues import label_issue as core_label_issue
+from azathoth.core.issues import list_issues as core_list_issues
+from azathoth.core.issues import list_labels
+from azathoth.core.milestones import (
+    MilestoneAction,
+    MilestoneInfo,
+    MilestoneState,
+    MilestoneStatus,
+)
+from azathoth.core.milestones import assign_milestone as core_assign_milestone
+from azathoth.core.milestones import close_milestone as core_close_milestone
+from azathoth.core.milestones import create_milestone as core_create_milestone
+from azathoth.core.milestones import list_milestones as core_list_milestones
+from azathoth.core.milestones import milestone_status as core_milestone_status
+from azathoth.core.repos import RepoEntry, resolve_repo, use_repo
+from azathoth.core.workflows import WorkflowDefinition, load_workflow, plan_workflow
+from azathoth.core.workflows import list_workflows as core_list_workflows
+from azathoth.core.reviews import (
+    PullRequestDiff,
+    PullRequestInfo,
+    PullRequestState,
+    ReviewComment,
+    ReviewEvent,
+    ReviewPost,
+    Side,
+)
+from azathoth.core.reviews import list_prs as core_list_prs
+from azathoth.core.reviews import pr_comment as core_pr_comment
+from azathoth.core.reviews import pr_diff as core_pr_diff
+from azathoth.core.reviews import pr_review as core_pr_review
+from azathoth.core.repos import list_repos as core_list_repos
+from azathoth.core.results import (
+    ActionResult,
+    CommitResult,
+    ReleaseResult,
+    RepoOverview,
+    ScriptResult,
+    patch_stats,
+)
+from azathoth.core.secrets import SecretReport, scan_diff
+from azathoth.core.session import (
+    Operation,
+    SessionState,
+    record,
+    record_head_commit,
+    session_state,
+)
+from azathoth.core.session import undo_last as core_undo_last
+from azathoth.core.signing import (
+    classify_signing_failure,
+    signature_status,
+    signing_setup,
+)
+from azathoth.core.stash import (
+    StashEntry,
+    list_stashes,
+    pop_stash,
+    save_stash,
+)
+from azathoth.core.tasks import (
+    Task,
+    TaskRunner,
+    discover_tasks,
+    resolve_task,
+    run_task,
+)
+from azathoth.core.sync import SyncResult
+from azathoth.core.sync import tracking as core_tracking
+from azathoth.core.sync import fetch as core_fetch
+from azathoth.core.sync import pull as core_pull
+from azathoth.core.sync import push as core_push
+from azathoth.core.tags import (
+    TagInfo,
+    TagKind,
+    create_tag as core_create_tag,
+    delete_tag as core_delete_tag,
+    list_tags as core_list_tags,
+)
+from azathoth.core.vcs import get_vcs
+from azathoth.core.workspace import (
+    WorkspaceRelease,
+    plan_workspace_release,
+    release_commit_message,
+    release_workspace as core_release_workspace,
+)
+from azathoth.core.worktrees import (
+    WorktreeInfo,
+    create_worktree as core_create_worktree,
+    list_worktrees as core_list_worktrees,
+    worktree_path,
+)
+from azathoth.core.version import (
+    BumpLevel,
+    VersionBump,
+    bump,
+    find_manifest,
+    write_version,
+)
+from azathoth.core.rebase import (
+    RebasePlan,
+    RebaseResult,
+    RebaseStep,
+    execute_rebase as core_execute_rebase,
+    plan_rebase as core_plan_rebase,
+)
+from azathoth.core.rewrite import (
+    RewriteResult,
+    SquashGroup,
+    branch_commits,
+    group_diff,
+    resolve_base,
+    rewrite_history,
+    validate_plan,
+)
+from azathoth.core.bisect import BisectResult
+from azathoth.core.bisect import bisect_run as core_bisect_run
+from azathoth.core.changelog import generate_changelog as core_generate_changelog
+from azathoth.core.release import origin_web_url
 from azathoth.core.llm import generate, LLMError
+from azathoth.core.exceptions import (
+    DirectiveError,
+    PolicyDenied,
+    SandboxError,
+    WorkflowError,
+)
+from azathoth.config import get_config
+from azathoth.mcp.audit import AuditLog
+from azathoth.mcp.defaults import DynamicDefaults
+from azathoth.mcp.health import register_health
+from azathoth.mcp.limits import CallLimiter
+from azathoth.mcp.policy import MutationGuard, confirm_with_user
+from azathoth.mcp.render import RenderOutput
+from azathoth.mcp.sampling import sample_with_client
+from azathoth.mcp.telemetry import Telemetry
 
 mcp = FastMCP(
     name="azathoth-workflow",
     instructions=(
-        "Git workflow automation tools. Use get_status to inspect the repo, "
-        "get_diff to see changes, stage_and_commit to AI-commit, "
-        "get_log to review history, and create_release to publish."
+        "Git workflow automation tools. Results are structured (JSON); a "
+        "failure is returned as a tool error with the reason. Tools work on "
+        "the server's working directory; pass repo_path to work on another "
+        "repository this server manages (list_repos lists them). Use get_status "
+        "for an overview of the repo, get_diff to see changes (git_status, "
+        "git_diff_staged and git_log give per-file detail; summarize_diff "
+        "pages through a diff too large to read at once, or a revision "
+        "range), preflight to "
+        "catch conflict markers, large files and build artifacts before "
+        "committing, stage_and_commit to AI-commit, lint_commit_message to "
+        "check a message you wrote (e.g. for cleanup_branch_history) against "
+        "the commit policy, "
+        "get_log to review history, commit_graph for branch topology, "
+        "blame_range and file_history to find who changed code and why, "
+        "list_branches / create_branch / switch_branch / delete_branch for "
+        "branch management, list_tags / create_tag / delete_tag for tags "
+        "(annotated by default), session_log / undo_last to review and revert "
+        "the commits, branches and tags this server created, "
+        "stash_save / stash_pop / stash_list to shelve "
+        "unrelated work before switching branches or committing, "
+        "list_worktrees / create_worktree to work on several branches at once "
+        "(get_status reports the worktree tools operate in), "
+        "list_scripts / run_script to build, lint or "
+        "test through the repo's own Makefile/justfile/package.json/cargo "
+        "tasks (output streams as progress notifications), run_tests to run "
+        "the project's test suite and get pass/fail counts and failing test "
+        "names (run_build, run_lint and run_fmt do the same for its build, "
+        "linter and formatter, returning file/line diagnostics; write_file and "
+        "edit_file change files to fix them, and the autofix prompt loops "
+        "through all of it until the project is green), "
+        "generate_changelog for grouped release notes input, "
+        "suggest_next_version for the tag the commits call for, bump_version "
+        "to raise the manifest version, and "
+        "create_release to publish (build_release_artifacts builds and "
+        "checksums binaries to attach as its assets; "
+        "promote_release_candidate turns a verified vX.Y.Z-rc.N into vX.Y.Z; "
+        "release_workspace releases the "
+        "changed packages of a Cargo/npm/pnpm workspace; publish_crate "
+        "pushes a tagged crate to crates.io). "
+        "Before opening a PR, tidy an agent branch "
+        "with cleanup_branch_history (squash/reword; dry_run first), or "
+        "plan_rebase then execute_rebase to reorder, drop or autosquash "
+        "fixup! commits; create_pull_request then pushes it and opens the "
+        "PR (fetch, pull and push sync a branch with its upstream and report "
+        "ahead/behind counts). To review a pull request, list_prs finds it, "
+        "pr_diff returns its hunks, and pr_comment and pr_review post line "
+        "comments and an approve/request-changes verdict (the review prompt "
+        "reviews a PR or revision range against the directives of the "
+        "languages it touches); list_issues, "
+        "label_issue, comment_issue and close_issue triage issues (the "
+        "autotriage prompt walks through it); create_milestone, "
+        "assign_milestone and milestone_status plan a release, and "
+        "close_milestone closes it once published. When a merge, "
+        "rebase or cherry-pick stops on conflicts, list_conflicts shows "
+        "them, resolve_conflict stages each resolved file, then "
+        "continue_rebase (or abort_merge). Wrap a unit of work in "
+        "start_focus_session / end_focus_session to get a reviewable summary. "
+        "Omit base (cleanup_branch_history, plan_rebase, execute_rebase) and "
+        "tag (create_release) to have "
+        "them resolved from the repo: the default branch and the suggested "
+        "next version. "
+        "stage_and_commit and bump_version refuse protected branches "
+        "(main, master, release/*) unless allow_protected is set. "
+        "While mutations are paused (pause_mutations or a .azathoth/pause file), "
+        "committing, branch changes, history cleanup and releases are denied. "
+        "When the server runs in approval mode, destructive tools (history "
+        "cleanup, rebase, abort_merge, delete_branch, delete_tag, releases, "
+        "publish_crate) "
+        "fail with 'Approval required' unless the call carries the user's "
+        "approval token; ask the user instead of retrying, or use dry_run. "
+        "To find the commit that broke a task, call bisect_run once instead "
+        "of stepping through git bisect. "
+        "The run_workflow prompt chains tools and prompts from a YAML "
+        "definition (list_workflows shows them, e.g. ship). "
+        "The autocommit and autorelease prompts script a full commit or "
+        "release with these tools. When a tool fails for no clear reason, "
+        "call health: it reports the repo, branch, git/gh availability and "
+        "what is misconfigured."
     ),
 )
 
 
+class _RepoScope(Middleware):
+    """Runs a call that names ``repo_path`` inside that (allowed) repository."""
+
+    async def on_call_tool(self, context: MiddlewareContext, call_next):
+        path = (context.message.arguments or {}).get("repo_path")
+        if not path:
+            return await call_next(context)
+        try:
+            repo = resolve_repo(path)
+        except WorkflowError as exc:
+            raise ToolError(str(exc)) from exc
+        with use_repo(repo):
+            return await call_next(context)
+
+
+mcp.add_middleware(AuditLog("workflow"))
+mcp.add_middleware(Telemetry("workflow"))
+mcp.add_middleware(RenderOutput("workflow", {"get_diff": "diff"}))
+# Before the guard and defaults, which inspect the selected repository.
+mcp.add_middleware(_RepoScope())
+_guard = MutationGuard(
+    {
+        "stage_and_commit",
+        "resolve_conflict",
+        "continue_rebase",
+        "create_branch",
+        "create_tag",
+        "create_pull_request",
+        "assign_milestone",
+        "close_issue",
+        "close_milestone",
+        "comment_issue",
+        "create_milestone",
+        "label_issue",
+        "pr_comment",
+        "pr_review",
+        "fetch",
+        "pull",
+        "push",
+        "switch_branch",
+        "stash_save",
+        "stash_pop",
+        "create_worktree",
+        "bump_version",
+        "run_script",
+        "run_tests",
+        "run_build",
+        "run_lint",
+        "run_fmt",
+        "write_file",
+        "edit_file",
+        "build_release_artifacts",
+        "bisect_run",
+    },
+    # Rewrite or drop history, or push tags and packages others fetch.
+    destructive_tools={
+        "cleanup_branch_history",
+        "execute_rebase",
+        "abort_merge",
+        "delete_branch",
+        "delete_tag",
+        "create_release",
+        "promote_release_candidate",
+        "release_workspace",
+        "publish_crate",
+        "undo_last",
+    },
+    # Overwrites the remote branch.
+    destructive_flags={"push": "force_with_lease"},
+)
+mcp.add_middleware(_guard)
+# After the guard, so denied calls never queue for a repository.
+mcp.add_middleware(CallLimiter(_guard.mutating_tools))
+# Parameters the model may omit; they are computed from the repo instead.
+mcp.add_middleware(
+    DynamicDefaults(
+        {
+            "cleanup_branch_history": {"base": "default_branch"},
+            "plan_rebase": {"base": "default_branch"},
+            "execute_rebase": {"base": "default_branch"},
+            "create_release": {"tag": "next_version"},
+        }
+    )
+)
+register_health(mcp, "workflow")
+
+
+# ── Helpers ──────────────────────────────────────────────────────────────
+
+
+def _is_dry_run(requested: bool) -> bool:
+    """A tool call is a dry run if requested or if the server runs with --dry-run."""
+    return requested or get_config().workflow_dry_run
+
+
+@contextmanager
+def _streaming(ctx: Context | None) -> Iterator[None]:
+    """Relay command output inside the block as MCP progress notifications.
+
+    Clients that did not send a progress token simply receive nothing extra.
+    """
+    if ctx is None:
+        yield
+        return
+    lines = 0
+
+    async def sink(line: str) -> None:
+        nonlocal lines
+        lines += 1
+        await ctx.report_progress(progress=lines, message=line)
+
+    with stream_output(sink):
+        yield
+
+
+async def _ensure_branch_writable(
+    action: str, allow_protected: bool, branch: str | None = None
+) -> None:
+    try:
+        await policy.ensure_branch_writable(action, allow_protected, branch=branch)
+    except PolicyDenied as exc:
+        raise ToolError(str(exc)) from exc
+
+
+def _action_result(res: GitResult, summary: str, dry_run: bool) -> ActionResult:
+    if not res.success:
+        raise ToolError(res.message or res.stderr)
+    if dry_run:
+        return ActionResult(done=False, summary=summary, commands=res.commands)
+    return ActionResult(
+        done=True, summary=summary, detail=res.message, output=res.stdout
+    )
+
+
+async def _generate_json(ctx: Context | None, system_prompt: str, user_msg: str) -> str:
+    """JSON from the providers or, per ``llm_sampling``, the client's model."""
+    mode = get_config().llm_sampling
+    if mode == "prefer":
+        if reply := await sample_with_client(ctx, system_prompt, user_msg):
+            return _unfence(reply)
+    try:
+        return await generate(system_prompt, user_msg, json_mode=True)
+    except LLMError:
+        if mode == "fallback":
+            if reply := await sample_with_client(ctx, system_prompt, user_msg):
+                return _unfence(reply)
+        raise
+
+
+def _unfence(reply: str) -> str:
+    # Client models are not in JSON mode and often wrap the object in a fence.
+    text = reply.strip().removeprefix("```json").removeprefix("```")
+    return text.removesuffix("```").strip()
+
+
 # ── Tools ────────────────────────────────────────────────────────────────
 
 
 @mcp.tool()
-async def get_status() -> str:
-    """Get a structured overview of the current repo: branch, staged/unstaged/untracked counts, latest tag, and commits since tag."""
-    _, branch, _ = await _run_git(["rev-parse", "--abbrev-ref", "HEAD"])
-    _, porcelain, _ = await _run_git(["status", "--porcelain"])
-
-    staged = unstaged = untracked = 0
-    for line in porcelain.splitlines():
-        if not line:
-            continue
-        x, y = line[0], line[1]
-        if x == "?":
-            untracked += 1
-        elif x != " ":
-            staged += 1
-        if y not in (" ", "?"):
-            unstaged += 1
-
-    tag = await get_latest_tag()
+async def get_status(repo_path: str | None = None) -> RepoOverview:
+    """Get a structured overview of the current repo: VCS backend, branch, the worktree being operated in, repo shape (full, shallow or bare), staged/unstaged/untracked counts, latest tag, and commits since tag."""
+    try:
+        vcs = get_vcs()
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+    status = await vcs.status()
+    tag = await vcs.latest_tag()
     commits_since = 0
     if tag:
-        log = await get_log_since(tag)
+        log = await vcs.log_since(tag)
         commits_since = len(log.splitlines()) if log else 0
-
-    return (
-        f"Branch: {branch}\n"
-        f"Staged: {staged}\n"
-        f"Unstaged: {unstaged}\n"
-        f"Untracked: {untracked}\n"
-        f"Latest tag: {tag or 'none'}\n"
-        f"Commits since tag: {commits_since}"
+    return RepoOverview(
+        backend=vcs.name,
+        branch=status.branch,
+        worktree=status.worktree,
+        linked_worktree=status.linked_worktree,
+        shape=status.shape,
+        staged=len(status.staged),
+        unstaged=len(status.unstaged),
+        untracked=len(status.untracked),
+        latest_tag=tag,
+        commits_since_tag=commits_since,
     )
 
 
 @mcp.tool()
-async def get_diff(staged: bool = True) -> str:
-    """Get the current git diff. Set staged=True for staged changes, False for unstaged."""
-    diff = await core_get_diff(staged=staged)
+async def list_repos() -> list[RepoEntry]:
+    """List the repositories tools can select with repo_path (workflow_repos): name, absolute path, whether it exists as a repository, and whether it is the server's working directory (used when repo_path is omitted)."""
+    return core_list_repos()
+
+
+@mcp.tool()
+async def git_status(repo_path: str | None = None) -> RepoStatus:
+    """Structured repo status as JSON: branch, worktree path (linked_worktree when added with git worktree add), repo shape (full/shallow/bare), staged/unstaged files with insertion/deletion counts, and untracked paths."""
+    return await get_repo_status()
+
+
+@mcp.tool()
+async def git_diff_staged(repo_path: str | None = None) -> DiffSummary:
+    """Structured staged diff as JSON: per-file status and line counts, totals, and the raw patch."""
+    return await get_diff_summary(staged=True)
+
+
+@mcp.tool()
+async def git_log(
+    rev_range: str | None = None, limit: int = 20, repo_path: str | None = None
+) -> list[CommitInfo]:
+    """Structured commit list as JSON (sha, author, ISO date, subject), newest first. rev_range is any git revision range (e.g. 'v1.0.0..HEAD'); defaults to HEAD."""
+    try:
+        return await get_log_entries(rev_range, limit=limit)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def commit_graph(
+    limit: int = 50,
+    since: str | None = None,
+    all_branches: bool = True,
+    mermaid: bool = False,
+    repo_path: str | None = None,
+) -> CommitGraph:
+    """Recent history as DAG data (nodes with parents, refs, author, date, subject), newest first. Use for reasoning about merges and divergence. since accepts git dates (e.g. '2 weeks ago'); mermaid=True adds a Mermaid gitGraph rendering."""
+    try:
+        graph = await get_commit_graph(limit, since=since, all_refs=all_branches)
+    except ValueError as exc:
+        raise ToolError(str(exc)) from exc
+    if mermaid:
+        graph = graph.model_copy(update={"mermaid": graph.render_mermaid()})
+    return graph
+
+
+@mcp.tool()
+async def blame_range(
+    path: str,
+    start: int,
+    end: int,
+    rev: str = "HEAD",
+    repo_path: str | None = None,
+) -> BlameReport:
+    """Who last changed lines start..end (1-based, inclusive) of a file at rev: each line with its commit sha and content, and each of those commits once with author, ISO date, subject and how many of the lines it touched. Use file_history or git_log for the surrounding story."""
+    try:
+        return await core_blame_range(path, start, end, rev)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def file_history(
+    path: str,
+    limit: int = 20,
+    include_diffs: bool = False,
+    repo_path: str | None = None,
+) -> FileHistory:
+    """Commits that changed a file, newest first, following renames: sha, author, ISO date, subject, the file's path in that commit, and its inserted/deleted lines. include_diffs=True adds each commit's patch of the file; ask for it with a small limit."""
+    try:
+        return await core_file_history(path, limit=limit, include_diffs=include_diffs)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def list_branches(repo_path: str | None = None) -> list[BranchInfo]:
+    """List local branches as JSON: name, whether current or protected, upstream with ahead/behind counts (upstream_gone when the remote branch was deleted), and tip sha and subject."""
+    return await core_list_branches()
+
+
+@mcp.tool()
+async def create_branch(
+    name: str,
+    start_point: str | None = None,
+    switch: bool = True,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> ActionResult:
+    """Create a branch at start_point (default HEAD) and switch to it unless switch=False. Uncommitted changes are carried over, with a warning. undo_last deletes it again. With dry_run=True the git command is returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    left = (await get_repo_status()).branch if switch else None
+    left = None if left == "HEAD" else left  # detached: nowhere to switch back to
+    res = await core_create_branch(name, start_point, switch=switch, dry_run=dry_run)
+    result = _action_result(res, f"Created {name}", dry_run)
+    if result.done:
+        await record(
+            Operation(kind="branch", tool="create_branch", ref=name, previous=left)
+        )
+    return result
+
+
+@mcp.tool()
+async def switch_branch(
+    name: str, dry_run: bool = False, repo_path: str | None = None
+) -> ActionResult:
+    """Switch to an existing local branch. Uncommitted changes are carried over, with a warning; git refuses if they would be overwritten. With dry_run=True the git command is returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    res = await core_switch_branch(name, dry_run=dry_run)
+    return _action_result(res, f"Switched to {name}", dry_run)
+
+
+@mcp.tool()
+async def delete_branch(
+    name: str,
+    force: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> ActionResult:
+    """Delete a local branch. Refuses the current branch and protected branches (workflow_protected_branches, default main/master). An unmerged branch needs force=True; without it the user is asked to confirm through the client when it supports elicitation, and the call fails otherwise. With dry_run=True the git command is returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    res = await core_delete_branch(name, force=force, dry_run=dry_run)
+    if not res.success and "not fully merged" in res.stderr:
+        question = (
+            f"Branch '{name}' has commits that are not merged anywhere else. "
+            "Delete it anyway? They will only be recoverable from the reflog."
+        )
+        if await confirm_with_user(ctx, question):
+            res = await core_delete_branch(name, force=True)
+    return _action_result(res, f"Deleted {name}", dry_run)
+
+
+@mcp.tool()
+async def list_tags(
+    pattern: str | None = None, repo_path: str | None = None
+) -> list[TagInfo]:
+    """List tags as JSON, semantic versions first in release order (newest first, v1.10.0 above v1.9.0, v2.0.0 above v2.0.0-rc.1), then other tags by name. Each has the tagged commit sha, kind (annotated, signed or lightweight), version, date and message subject. pattern filters by glob, e.g. 'v1.*'."""
+    return await core_list_tags(pattern)
+
+
+@mcp.tool()
+async def create_tag(
+    name: str,
+    message: str | None = None,
+    kind: TagKind = "annotated",
+    ref: str | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> ActionResult:
+    """Tag ref (default HEAD) without pushing it. kind is annotated (default; git tag -a with message, which defaults to the name), signed (git tag -s, using the commit signing key) or lightweight. Refuses a name that is already a tag. undo_last deletes it again. With dry_run=True the git command is returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    res = await core_create_tag(name, message, kind, ref, dry_run=dry_run)
+    result = _action_result(res, f"Tagged {ref or 'HEAD'} as {name}", dry_run)
+    if result.done:
+        await record(Operation(kind="tag", tool="create_tag", ref=name))
+    return result
+
+
+@mcp.tool()
+async def delete_tag(
+    name: str,
+    remote: bool = False,
+    confirm: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> ActionResult:
+    """Delete a tag locally and, with remote=True, on origin too. Nothing is deleted unless confirm=True or the user accepts the confirmation question shown by a client that supports elicitation: a pushed tag may already be fetched or released from. With dry_run=True the git commands are returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    if not confirm and not dry_run:
+        where = " locally and on origin" if remote else ""
+        question = f"Delete tag '{name}'{where}?"
+        confirm = bool(await confirm_with_user(ctx, question))
+    res = await core_delete_tag(name, remote=remote, confirm=confirm, dry_run=dry_run)
+    return _action_result(res, f"Deleted tag {name}", dry_run)
+
+
+@mcp.tool()
+async def session_log(repo_path: str | None = None) -> SessionState:
+    """The undo log: commits (stage_and_commit, bump_version), branches (create_branch) and tags (create_tag, create_release, with the remote they were pushed to) created through this server in the repo, oldest first, each marked undone once undo_last reverted it. Kept in the repo's git directory across server restarts."""
+    try:
+        return await session_state()
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def undo_last(
+    dry_run: bool = False, repo_path: str | None = None
+) -> ActionResult:
+    """Revert the newest operation in session_log that is not undone yet: a commit is soft-reset to its parent (its changes stay staged; refused once HEAD moved on or the commit is on a remote), a created branch is deleted after switching back to the branch it was created from (git branch -d, so unmerged work is kept), a tag is deleted locally and on the remote it was pushed to; a release published by create_release is refused (delete it on the forge first). With dry_run=True the git commands are returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    try:
+        res = await core_undo_last(dry_run=dry_run)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+    return _action_result(res, res.message or "Undid the last operation", dry_run)
+
+
+@mcp.tool()
+async def list_worktrees(repo_path: str | None = None) -> list[WorktreeInfo]:
+    """List the repository's worktrees as JSON: path, checked-out branch (null when detached), HEAD sha, and whether it is the current, bare, locked or prunable one."""
+    return await core_list_worktrees()
+
+
+@mcp.tool()
+async def create_worktree(
+    branch: str,
+    path: str | None = None,
+    start_point: str | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> ActionResult:
+    """Check a branch out in a new linked worktree and return its path, so several branches can be worked on at once without switching. An existing branch is used as is; a new one is created at start_point (default HEAD). The worktree goes to workflow_worktree_dir (default: the system temp directory) as <dir>/<repo>/<branch>; path replaces <branch> and may not leave <dir>/<repo>. Refuses a branch already checked out elsewhere, naming that worktree. Tools keep operating in the server's own worktree; point a separate session at the new path to use it. With dry_run=True the git command is returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    try:
+        target = await worktree_path(branch, path)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+    res = await core_create_worktree(branch, target, start_point, dry_run=dry_run)
+    return _action_result(res, f"Created worktree for {branch}: {target}", dry_run)
+
+
+@mcp.tool()
+async def stash_list(repo_path: str | None = None) -> list[StashEntry]:
+    """List stash entries as JSON, newest first: index, ref (stash@{N}), message, the branch it was made on, ISO date, and the files it touches (untracked ones included)."""
+    return await list_stashes()
+
+
+@mcp.tool()
+async def stash_save(
+    message: str | None = None,
+    paths: list[str] | None = None,
+    include_untracked: bool = True,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> ActionResult:
+    """Stash local changes and clean them from the working tree. Pass paths to shelve only those files (e.g. work unrelated to the next commit); include_untracked=False leaves new files in place. With dry_run=True the git command is returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    res = await save_stash(message, paths, include_untracked, dry_run=dry_run)
+    return _action_result(res, f"Stashed: {message}" if message else "Stashed", dry_run)
+
+
+@mcp.tool()
+async def stash_pop(
+    index: int = 0, dry_run: bool = False, repo_path: str | None = None
+) -> ActionResult:
+    """Restore stash entry index (0 = newest, see stash_list) and drop it. Refuses, listing the files, when the stash touches files that have local changes. If applying hits merge conflicts, the conflicted files are listed and the stash is kept. With dry_run=True the checks run and the git command is returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    res = await pop_stash(index, dry_run=dry_run)
+    return _action_result(res, f"Restored stash@{{{index}}}", dry_run)
+
+
+@mcp.tool()
+async def get_diff(staged: bool = True, repo_path: str | None = None) -> str:
+    """Get the current diff. Set staged=True for staged changes, False for unstaged (backends without an index, jj and hg, always return all pending changes)."""
+    try:
+        diff = await get_vcs().diff(staged=staged)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
     return diff if diff else "(no changes)"
 
 
 @mcp.tool()
-async def stage_and_commit(focus: str | None = None) -> str:
-    """Stage all changes, generate an AI commit message, and commit. Pass an optional focus hint to guide the message."""
-    await stage_all()
-    diff = await core_get_diff(staged=True)
-    if not diff:
-        return "No staged changes — nothing to commit."
+async def summarize_diff(
+    staged: bool = True,
+    paths: list[str] | None = None,
+    max_file_bytes: int | None = None,
+    offset: int = 0,
+    limit: int = 50,
+    revisions: str | None = None,
+    repo_path: str | None = None,
+) -> DiffPage:
+    """Summarize a diff too large to read whole: every changed file's status, insertions/deletions and byte size, with its hunk headers (line ranges and function context), plus the full patch text only for files up to max_file_bytes (workflow_diff_inline_bytes, default 8000). Pass paths to get just those files with their full text. offset and limit page through the file list; next_offset is null on the last page. staged as for get_diff; revisions (git only, e.g. "main..HEAD" or "v1.2.0...feature") summarizes that range of commits instead of pending changes."""
+    try:
+        if revisions is not None:
+            diff = await get_range_diff(revisions)
+        else:
+            diff = await get_vcs().diff(staged=staged)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+    if max_file_bytes is None:
+        max_file_bytes = get_config().workflow_diff_inline_bytes
+    return core_summarize_diff(
+        diff, paths=paths, max_file_bytes=max_file_bytes, offset=offset, limit=limit
+    )
+
+
+@mcp.tool()
+async def list_scripts(repo_path: str | None = None) -> list[Task]:
+    """List the tasks this repo declares — Makefile targets, justfile recipes, package.json scripts and cargo aliases — with the exact command run_script would execute."""
+    return discover_tasks(find_repo_root())
+
 
+@mcp.tool()
+async def run_script(
+    name: str,
+    runner: TaskRunner | None = None,
+    timeout: float | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> ScriptResult:
+    """Run one of the repo's declared tasks (see list_scripts) from the repo root and return its exit code and output. Only declared tasks can run — no arbitrary shell and no extra arguments. runner (make, just, npm, cargo) disambiguates a name defined twice. timeout is in seconds, capped at workflow_script_timeout. Output lines are streamed as progress notifications while the task runs; long output keeps its tail in the result. With dry_run=True the command is returned without running it."""
+    root = find_repo_root()
     try:
-        system_prompt = get_commit_system_prompt(focus)
-        raw = await generate(system_prompt, diff, json_mode=True)
-        data = json.loads(raw)
-        title = data["title"]
-        body = data.get("body", "")
-    except LLMError as exc:
-        return f"LLM error: {exc}"
-    except (json.JSONDecodeError, KeyError) as exc:
-        return f"Failed to parse LLM response: {exc}"
+        task = resolve_task(name, runner, root)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+    planned_run = ScriptResult(
+        name=task.name,
+        source=task.source,
+        command=format_command(task.argv),
+        ran=False,
+    )
+    if _is_dry_run(dry_run):
+        return planned_run
+
+    limit = get_config().workflow_script_timeout
+    with _streaming(ctx):
+        result = await run_task(task, min(timeout or limit, limit), cwd=str(root))
+    # Structured fields bypass RenderOutput, so render them here.
+    mode = render_mode("workflow")
+    return planned_run.model_copy(
+        update={
+            "ran": True,
+            "success": result.success,
+            "exit_code": result.exit_code,
+            "duration_s": result.duration_s,
+            "timed_out": result.timed_out,
+            "stdout": render(result.stdout, mode),
+            "stderr": render(result.stderr, mode),
+            "truncated": result.truncated,
+        }
+    )
+
+
+@mcp.tool()
+async def run_tests(
+    timeout: float | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> SuiteResult:
+    """Run the project's test suite from the repo root: workflow_test_command if configured, else the detected one (cargo test, go test, npm test, pytest). Returns success, the passed/failed/skipped counts and the failing tests' names read from the runner's summary (None when its output could not be parsed), and the tail of the output. No command can be passed. timeout is in seconds, capped at workflow_test_timeout. Output lines are streamed as progress notifications. With dry_run=True the command is returned without running it."""
+    limit = get_config().workflow_test_timeout
+    try:
+        with _streaming(ctx):
+            result = await core_run_tests(
+                find_repo_root(), min(timeout or limit, limit), _is_dry_run(dry_run)
+            )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+    return result.model_copy(
+        update={"output": render(result.output, render_mode("workflow"))}
+    )
+
+
+async def _run_check(
+    kind: CheckKind,
+    timeout: float | None,
+    dry_run: bool,
+    ctx: Context | None,
+    check: bool = False,
+) -> CheckResult:
+    limit = get_config().workflow_check_timeout
+    try:
+        with _streaming(ctx):
+            result = await core_run_check(
+                kind,
+                find_repo_root(),
+                min(timeout or limit, limit),
+                check=check,
+                dry_run=_is_dry_run(dry_run),
+            )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+    return result.model_copy(
+        update={"output": render(result.output, render_mode("workflow"))}
+    )
+
+
+@mcp.tool()
+async def run_build(
+    timeout: float | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> CheckResult:
+    """Build the project from the repo root: workflow_build_command if configured, else the detected build (cargo build, go build, the package.json build script). Returns success and the compiler's diagnostics (file, line, column, severity, message), with error and warning counts and the tail of the output. No command can be passed. timeout is in seconds, capped at workflow_check_timeout. Output lines are streamed as progress notifications. With dry_run=True the command is returned without running it."""
+    return await _run_check("build", timeout, dry_run, ctx)
+
+
+@mcp.tool()
+async def run_lint(
+    timeout: float | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> CheckResult:
+    """Run the project's linter from the repo root: workflow_lint_command if configured, else the detected one (cargo clippy, go vet, the package.json lint script, eslint, ruff). Returns success and the findings as diagnostics (file, line, column, severity, message), with error and warning counts and the tail of the output. No command can be passed. timeout is in seconds, capped at workflow_check_timeout. With dry_run=True the command is returned without running it."""
+    return await _run_check("lint", timeout, dry_run, ctx)
+
+
+@mcp.tool()
+async def run_fmt(
+    check: bool = False,
+    timeout: float | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> CheckResult:
+    """Format the project's files in place with its formatter: workflow_fmt_command if configured, else the detected one (cargo fmt, gofmt, the package.json format script, prettier, ruff format). With check=True files are left alone and each one that needs formatting is reported as a diagnostic, making success false. No command can be passed. timeout is in seconds, capped at workflow_check_timeout. With dry_run=True the command is returned without running it."""
+    return await _run_check("fmt", timeout, dry_run, ctx, check=check)
+
+
+@mcp.tool()
+async def write_file(
+    path: str,
+    content: str,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> FileWrite:
+    """Write content to a file in the repository (path relative to the repo root), creating it and its parent directories if needed and replacing it otherwise. Paths that leave the repo (.., absolute, symlinks out), fall under a scout_deny pattern or lie inside .git are refused. Prefer edit_file to change part of an existing file. With dry_run=True nothing is written."""
+    try:
+        return core_write_file(
+            Sandbox.for_root(find_repo_root()), path, content, _is_dry_run(dry_run)
+        )
+    except SandboxError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def edit_file(
+    path: str,
+    old: str,
+    new: str,
+    replace_all: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> FileWrite:
+    """Replace the exact text old with new in a repository file (path relative to the repo root). old must occur exactly once — include surrounding lines to make it unique — unless replace_all=True replaces every occurrence; replacements reports how many were made. Binary files and the paths write_file refuses are refused. With dry_run=True the file is left unchanged."""
+    try:
+        return core_edit_file(
+            Sandbox.for_root(find_repo_root()),
+            path,
+            old,
+            new,
+            replace_all=replace_all,
+            dry_run=_is_dry_run(dry_run),
+        )
+    except SandboxError as exc:
+        raise ToolError(str(exc)) from exc
+
 
-    res = await commit(title, body)
-    if res.success:
-        return f"✓ Committed: {title}"
+@mcp.tool()
+async def bisect_run(
+    good: str,
+    bad: str,
+    test: str,
+    runner: TaskRunner | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> BisectResult:
+    """Find the first bad commit between good and bad (refs) with git bisect run, using the declared task test (see list_scripts; runner disambiguates) as the check: exit 0 is good, 125 skips the commit, anything else is bad. Returns first_bad (sha, author, date, subject) with its body, diffstat and line counts, plus every step's verdict. The bisect is always reset, so HEAD ends where it started; refused while tracked files have uncommitted changes. Capped at workflow_bisect_timeout seconds. With dry_run=True the git commands are returned without running them."""
+    root = find_repo_root()
+    try:
+        task = resolve_task(test, runner, root)
+        with _streaming(ctx):
+            return await core_bisect_run(
+                good,
+                bad,
+                task,
+                get_config().workflow_bisect_timeout,
+                cwd=str(root),
+                dry_run=_is_dry_run(dry_run),
+            )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def preflight(repo_path: str | None = None) -> PreflightReport:
+    """Check what committing everything (staged, modified and untracked files) would include, before staging. Errors (ok=false): leftover conflict markers, files over workflow_max_file_size. Warnings: untracked build artifacts missing from .gitignore, text files without a final newline, a branch behind its upstream (as of the last fetch). Each issue names the check, severity, path and the fix to apply."""
+    try:
+        return await core_preflight()
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def lint_commit_message(
+    message: str, repo_path: str | None = None
+) -> CommitLint:
+    """Check a proposed commit message (title, blank line, body) before committing. Errors (valid=false): the repo's commit policy — conventional-commit header, allowed types and scopes, title length, forbidden trailers — and a missing blank line after the title. Warnings: non-imperative descriptions ('added' → 'add'), a trailing period, body lines over max_body_line_length (default 72). Each violation has a rule, severity, message, line and, where possible, a corrected suggestion."""
+    try:
+        policy = load_commit_policy()
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+    return core_lint_commit_message(message, policy)
+
+
+@mcp.tool()
+async def stage_and_commit(
+    focus: str | None = None,
+    title: str | None = None,
+    body: str = "",
+    paths: list[str] | None = None,
+    include_untracked: bool = True,
+    skip_hooks: bool = False,
+    sign: bool = False,
+    allow_protected: bool = False,
+    block_secrets: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> CommitResult:
+    """Stage changes, generate an AI commit message, and commit. Returns the new commit's sha, branch, title, body, files changed, insertions and deletions. Pass an optional focus hint to guide the message, or title (and body) to commit with your own message instead. The message comes from the configured LLM providers or, per llm_sampling, from the client's own model through MCP sampling — by default only when no provider answers. Pass paths to stage and commit only those files (other work in progress is left alone); include_untracked=False skips new files. The message must satisfy the repo's commit policy ([commit] in .azathoth.toml) or the commit is rejected. The repo's pre-commit hooks (.pre-commit-config.yaml, or a pre-commit script in core.hooksPath/.git/hooks) run on the staged changes first; if one fails, nothing is committed and the error names the failing hook and its output. skip_hooks=True deliberately bypasses them (git commit --no-verify). The commit is signed when the repo sets commit.gpgsign or sign=True (git commit -S; GPG, SSH or X.509 per gpg.format) and signature reports it; a signing failure names its reason (missing_key, expired_key, agent_unavailable, passphrase_required, program_missing, unknown) with a fix. Refuses to commit directly on a protected branch (workflow_protected_branches: main, master, release/* by default) unless allow_protected=True. With block_secrets=True (or workflow_block_secrets set) the commit is refused when the changes contain likely credentials — AWS keys, private key blocks, .env files, high-entropy tokens — and the error lists them redacted. With dry_run=True nothing is staged or committed; committed is false and commands lists what would run."""
+    dry_run = _is_dry_run(dry_run)
+    if not dry_run:
+        await _ensure_branch_writable("stage_and_commit", allow_protected)
+    try:
+        policy = load_commit_policy()
+        vcs = get_vcs()
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+    stage_res = await vcs.stage(
+        paths=paths, include_untracked=include_untracked, dry_run=dry_run
+    )
+    if not stage_res.success:
+        raise ToolError(f"Staging failed: {stage_res.message or stage_res.stderr}")
+    if dry_run and vcs.has_index:
+        # Nothing was staged, so preview against staged + unstaged changes.
+        staged = await vcs.diff(staged=True, paths=paths)
+        unstaged = await vcs.diff(staged=False, paths=paths)
+        diff = "\n".join(d for d in (staged, unstaged) if d)
     else:
-        return f"✗ Commit failed: {res.stderr}"
+        diff = await vcs.diff(staged=True, paths=paths)
+    if not diff:
+        raise ToolError("No staged changes — nothing to commit.")
+    if block_secrets or get_config().workflow_block_secrets:
+        if findings := scan_diff(diff):
+            report = SecretReport(
+                scope="staged", files_scanned=patch_stats(diff)[0], findings=findings
+            )
+            raise ToolError(
+                f"Commit refused: {report.render()}\n"
+                "Unstage or remove them (add .env files to .gitignore) and retry."
+            )
+
+    hooks = None if skip_hooks or vcs.name != "git" else await detect_hooks()
+    if hooks is not None and not dry_run:
+        # Before the LLM call: a failing hook makes the message moot.
+        try:
+            hook_run = await run_hooks(hooks, paths)
+        except WorkflowError as exc:
+            raise ToolError(str(exc)) from exc
+        if not hook_run.success:
+            raise ToolError(hook_run.render_failure())
+
+    if title is None:
+        try:
+            system_prompt = prompts.commit_system(focus, policy)
+            data = json.loads(await _generate_json(ctx, system_prompt, diff))
+            title = data["title"]
+            body = data.get("body", "")
+        except LLMError as exc:
+            raise ToolError(f"LLM error: {exc}") from exc
+        except (json.JSONDecodeError, KeyError) as exc:
+            raise ToolError(f"Failed to parse LLM response: {exc}") from exc
+
+    violations = policy.check(title, body)
+    if violations:
+        problems = "\n".join(f"- {v}" for v in violations)
+        raise ToolError(f"Commit rejected by policy: {title}\n{problems}")
+
+    files, insertions, deletions = patch_stats(diff)
+    result = CommitResult(
+        committed=False,
+        title=title,
+        body=body,
+        files_changed=files,
+        insertions=insertions,
+        deletions=deletions,
+    )
+    signing = sign or (vcs.name == "git" and (await signing_setup()).auto)
+    res = await vcs.commit(
+        title, body, paths=paths, dry_run=dry_run, no_verify=skip_hooks, sign=sign
+    )
+    if dry_run:
+        try:
+            hook_cmd = [format_command(hook_command(hooks, paths))] if hooks else []
+        except WorkflowError as exc:
+            raise ToolError(str(exc)) from exc
+        commands = [*stage_res.commands, *hook_cmd, *res.commands]
+        return result.model_copy(update={"commands": commands})
+    if not res.success:
+        if signing and (failure := classify_signing_failure(res.stderr)):
+            raise ToolError(failure.render())
+        raise ToolError(f"Commit failed: {res.message or res.stderr}")
+
+    if vcs.name == "git":
+        await record_head_commit("stage_and_commit")
+    head = await vcs.log(limit=1)
+    return result.model_copy(
+        update={
+            "committed": True,
+            "sha": head[0].sha if head else None,
+            "branch": (await vcs.status()).branch,
+            "signature": await signature_status() if vcs.name == "git" else None,
+        }
+    )
+
+
+@mcp.tool()
+async def cleanup_branch_history(
+    plan: list[SquashGroup],
+    base: str | None = None,
+    allow_force_push: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> RewriteResult:
+    """Squash and reword the current branch's commits (base..HEAD) without an interactive rebase. plan is an ordered list of groups, each {commits: [sha, …], title, body}; every branch commit must appear exactly once, in order, and each group must be contiguous. Groups without a title get an AI-generated message from their combined diff. When omitted, base is resolved to the repo's default branch (origin/HEAD, else main/master) and the fork point from it is used. Refuses protected branches, dirty trees and merge commits; rewriting commits already pushed requires allow_force_push=True (pushes with --force-with-lease). The final tree is unchanged and the old tip is kept under refs/azathoth/backup/. Returns the new subjects (commits), new head and backup ref. With dry_run=True the new subjects and commands are returned instead."""
+    dry_run = _is_dry_run(dry_run)
+    try:
+        commit_policy = load_commit_policy()
+        commits = await branch_commits(await resolve_base(base))
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+    problems = validate_plan(plan, commits)
+    if problems:
+        listing = "\n".join(f"  {c.sha[:7]} {c.subject}" for c in commits)
+        issues = "\n".join(f"- {p}" for p in problems)
+        raise ToolError(
+            f"Plan rejected:\n{issues}\nBranch commits (oldest first):\n{listing}"
+        )
+
+    groups: list[SquashGroup] = []
+    for group in plan:
+        if not group.title.strip():
+            try:
+                diff = await group_diff(group, commits)
+                system_prompt = prompts.commit_system(None, commit_policy)
+                data = json.loads(await generate(system_prompt, diff, json_mode=True))
+                group = group.model_copy(
+                    update={"title": data["title"], "body": data.get("body", "")}
+                )
+            except WorkflowError as exc:
+                raise ToolError(str(exc)) from exc
+            except LLMError as exc:
+                raise ToolError(f"LLM error: {exc}") from exc
+            except (json.JSONDecodeError, KeyError) as exc:
+                raise ToolError(f"Failed to parse LLM response: {exc}") from exc
+        violations = commit_policy.check(group.title, group.body)
+        if violations:
+            issues = "\n".join(f"- {v}" for v in violations)
+            raise ToolError(f"Message rejected by policy: {group.title}\n{issues}")
+        groups.append(group)
+
+    try:
+        with _streaming(ctx):
+            return await rewrite_history(
+                groups, base=base, allow_force_push=allow_force_push, dry_run=dry_run
+            )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def plan_rebase(
+    base: str | None = None, repo_path: str | None = None
+) -> RebasePlan:
+    """Plan a rebase of the current branch onto base: the commits base..HEAD would replay (oldest first, with the files each touches), fixup/squash candidates (fixup!/squash! commits, and commits touching only files an earlier branch commit changed), and a suggested step list with the fixup!/squash! commits moved after their targets. Edit the steps and pass them to execute_rebase. When omitted, base is resolved to the repo's default branch."""
+    try:
+        return await core_plan_rebase(base)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def execute_rebase(
+    steps: list[RebaseStep],
+    base: str | None = None,
+    allow_force_push: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> RebaseResult:
+    """Rebase the current branch onto base following steps, without an editor. steps lists every commit of base..HEAD exactly once, in the new order, each {action, commit, message}: action is pick, reword (message required), squash (into the previous kept commit; message optional, else the messages are combined), fixup (keeps the previous message) or drop. plan_rebase returns a suggested list. Refuses protected branches and dirty trees; rewriting commits already pushed requires allow_force_push=True (pushes with --force-with-lease). The old tip is kept under refs/azathoth/backup/. If a step conflicts the rebase stops and is left in progress (stopped=true, conflicts lists the files): inspect them with list_conflicts, stage fixes with resolve_conflict, then continue_rebase — or abort_merge to go back. With dry_run=True the todo list and commands are returned instead."""
+    try:
+        return await core_execute_rebase(
+            steps,
+            base=base,
+            allow_force_push=allow_force_push,
+            dry_run=_is_dry_run(dry_run),
+        )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def list_conflicts(repo_path: str | None = None) -> ConflictReport:
+    """List the conflicts of a stopped merge, rebase, cherry-pick or revert as JSON: the operation, and per conflicted file its kind (both modified, deleted by us/them, both added, …), whether the working-tree file still has conflict markers, and three-way hunks (ours, base, theirs, plus up to 3 lines of preceding context) rebuilt from the index, so they are complete even after partial edits. During a rebase, ours is the branch being rebased onto and theirs the commit being replayed."""
+    return await core_list_conflicts()
+
+
+@mcp.tool()
+async def resolve_conflict(
+    path: str,
+    content: str | None = None,
+    delete: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> ActionResult:
+    """Resolve one conflicted file: write content (the complete resolved file, no conflict markers) and stage it. delete=True removes the file instead, e.g. to accept a deletion. Once every file is resolved, call continue_rebase. With dry_run=True the git command is returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    res = await core_resolve_conflict(path, content, delete=delete, dry_run=dry_run)
+    return _action_result(res, f"Resolved {path}", dry_run)
+
+
+@mcp.tool()
+async def abort_merge(
+    dry_run: bool = False, repo_path: str | None = None
+) -> ActionResult:
+    """Abort the stopped merge, rebase, cherry-pick or revert and return to the state before it started. With dry_run=True the git command is returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    return _action_result(await abort_operation(dry_run=dry_run), "Aborted", dry_run)
+
+
+@mcp.tool()
+async def continue_rebase(
+    dry_run: bool = False, repo_path: str | None = None
+) -> ActionResult:
+    """Continue the stopped rebase (or merge, cherry-pick or revert) once every conflict is resolved, keeping the commit messages git proposes. Refuses while conflicts remain; if a later commit conflicts, the new conflicted files are listed. With dry_run=True the git command is returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    res = await continue_operation(dry_run=dry_run)
+    return _action_result(res, "Continued", dry_run)
 
 
 @mcp.tool()
-async def get_log() -> str:
+async def get_log(repo_path: str | None = None) -> str:
     """Get the commit log since the latest tag. Useful before deciding to cut a release."""
-    tag = await get_latest_tag()
+    try:
+        vcs = get_vcs()
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+    tag = await vcs.latest_tag()
     if not tag:
-        return "No tags found — cannot determine changelog."
-    log = await get_log_since(tag)
+        raise ToolError("No tags found — cannot determine changelog.")
+    log = await vcs.log_since(tag)
     return f"Commits since {tag}:\n{log}" if log else f"No commits since {tag}."
 
 
 @mcp.tool()
-async def create_release(pre: bool = False) -> str:
-    """Generate AI release notes from the commit log and publish via `gh release create`."""
-    tag = await get_latest_tag()
-    if not tag:
-        return "No previous tag found — cannot determine changelog."
+async def generate_changelog(
+    from_ref: str | None = None, to_ref: str = "HEAD", repo_path: str | None = None
+) -> str:
+    """Generate a Markdown changelog for from_ref..to_ref, grouped by conventional-commit type (feat, fix, chore, …). from_ref defaults to the latest tag."""
+    try:
+        changelog = await core_generate_changelog(from_ref, to_ref)
+    except (ValueError, WorkflowError) as exc:
+        raise ToolError(f"Changelog failed: {exc}") from exc
+    return changelog.render_markdown()
+
+
+@mcp.tool()
+async def bump_version(
+    level: BumpLevel,
+    allow_protected: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> VersionBump:
+    """Bump the project version (level: major, minor or patch) in the first manifest with a static version — Cargo.toml, package.json, then pyproject.toml — and commit just that file as "chore(release): bump version to X". Returns the manifest, old and new version. On a protected branch the commit is refused unless allow_protected=True. With dry_run=True nothing is written or committed."""
+    dry_run = _is_dry_run(dry_run)
+    if not dry_run:
+        await _ensure_branch_writable("bump_version", allow_protected)
+    try:
+        root = find_repo_root()
+        manifest = find_manifest(root)
+        new_version = bump(manifest.version, level)
+        commit_policy = load_commit_policy()
+        vcs = get_vcs()
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
 
-    log = await get_log_since(tag)
+    title = f"chore(release): bump version to {new_version}"
+    if violations := commit_policy.check(title):
+        problems = "; ".join(violations)
+        raise ToolError(f"Commit rejected by policy: {title}; {problems}")
+
+    rel = manifest.path.relative_to(root).as_posix()
+    result = VersionBump(
+        manifest=rel, old_version=manifest.version, new_version=new_version
+    )
+    if dry_run:
+        planned_commit = await vcs.commit(
+            title, "", paths=[str(manifest.path)], dry_run=True
+        )
+        return result.model_copy(
+            update={
+                "commands": [
+                    f"edit {rel}: version {manifest.version} → {new_version}",
+                    planned_commit.stdout.split("\n\n")[0],
+                ]
+            }
+        )
+
+    original = manifest.path.read_text(encoding="utf-8")
+    write_version(manifest, new_version)
+    res = await vcs.commit(title, "", paths=[str(manifest.path)])
+    if not res.success:
+        manifest.path.write_text(original, encoding="utf-8")
+        raise ToolError(f"Commit failed (manifest restored): {res.stderr}")
+    if vcs.name == "git":
+        await record_head_commit("bump_version")
+    return result.model_copy(update={"committed": True})
+
+
+@mcp.tool()
+async def create_release(
+    tag: str | None = None,
+    pre: bool = False,
+    assets: list[str] | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> ReleaseResult:
+    """Generate AI release notes from the commit log and publish them on the repo's forge — GitHub (REST API when a token is configured, else gh), GitLab (glab) or Gitea (tea), chosen by release_backend or the origin remote URL. assets are repo-relative files attached to the release. Returns the tag, previous tag, notes and release URL. tag defaults to suggest_next_version's answer (breaking → major, feat → minor, else patch). Push and publish output is streamed as progress notifications. A client that supports elicitation is asked to confirm a release from a working tree with uncommitted changes. With dry_run=True published is false and the tag, push and publish commands are returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    previous = await get_latest_tag()
+    if not previous:
+        raise ToolError("No previous tag found — cannot determine changelog.")
+
+    log = await get_log_since(previous)
     if not log:
-        return f"No commits since {tag} — nothing to release."
+        raise ToolError(f"No commits since {previous} — nothing to release.")
+    status = await get_repo_status()
+    uncommitted = len(status.staged) + len(status.unstaged)
+    if uncommitted and not dry_run:
+        question = (
+            f"The working tree has {uncommitted} uncommitted change(s); the "
+            "release will not include them. Release anyway?"
+        )
+        if await confirm_with_user(ctx, question) is False:
+            raise ToolError("Release cancelled: the working tree is not clean.")
 
     try:
-        system_prompt = get_release_system_prompt()
-        user_msg = f"Previous tag: {tag}\n\nCommit log:\n{log}"
+        system_prompt = prompts.release_system()
+        user_msg = f"Previous tag: {previous}\n\nCommit log:\n{log}"
+        if tag:
+            user_msg += f"\n\nThe new tag is {tag}; use it."
         raw = await generate(system_prompt, user_msg, json_mode=True)
         data = json.loads(raw)
-        new_tag = data["tag"]
+        new_tag = tag or data["tag"]
         notes = data["notes"]
     except LLMError as exc:
-        return f"LLM error: {exc}"
+        raise ToolError(f"LLM error: {exc}") from exc
     except (json.JSONDecodeError, KeyError) as exc:
-        return f"Failed to parse LLM response: {exc}"
+        raise ToolError(f"Failed to parse LLM response: {exc}") from exc
 
-    res = await core_create_release(new_tag, notes, is_prerelease=pre)
-    if res.success:
-        return f"✓ Released {new_tag}\n\n{notes}"
-    else:
-        msg = f"✗ Release failed: {res.stderr}"
-        if res.message:
-            msg += f"\n{res.message}"
-        return msg
+    with _streaming(ctx):
+        res = await core_create_release(
+            new_tag, notes, is_prerelease=pre, dry_run=dry_run, assets=assets
+        )
+    if not res.success:
+        detail = f"\n{res.message}" if res.message else ""
+        raise ToolError(f"Release failed: {res.stderr}{detail}")
+    if not dry_run:
+        await record(
+            Operation(
+                kind="release", tool="create_release", ref=new_tag, remote="origin"
+            )
+        )
+    url = res.stdout.splitlines()[0] if res.stdout and not dry_run else ""
+    return ReleaseResult(
+        tag=new_tag,
+        previous_tag=previous,
+        notes=notes,
+        prerelease=pre,
+        published=not dry_run,
+        url=url if url.startswith("https://") else None,
+        commands=res.commands,
+    )
+
+
+@mcp.tool()
+async def fetch(
+    remote: str | None = None,
+    prune: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> SyncResult:
+    """Fetch remote (default: all remotes) with tags; prune=True drops remote-tracking branches deleted on the remote. Returns the current branch's upstream and ahead/behind counts before and after. With dry_run=True the git command is returned instead of executed."""
+    try:
+        with _streaming(ctx):
+            return await core_fetch(remote, prune=prune, dry_run=_is_dry_run(dry_run))
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def pull(
+    rebase: bool = True,
+    remote: str | None = None,
+    branch: str | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> SyncResult:
+    """Pull the current branch's upstream (or remote and branch) into it, rebasing local commits by default (rebase=False merges). Returns the upstream and ahead/behind counts before and after. If it stops on conflicts the error says so: use list_conflicts, resolve_conflict and continue_rebase, or abort_merge. With dry_run=True the git command is returned instead of executed."""
+    try:
+        with _streaming(ctx):
+            return await core_pull(
+                rebase, remote, branch, dry_run=_is_dry_run(dry_run)
+            )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def push(
+    remote: str | None = None,
+    branch: str | None = None,
+    set_upstream: bool = False,
+    force_with_lease: bool = False,
+    allow_protected: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> SyncResult:
+    """Push branch (default: the current one; a branch name, not a refspec such as HEAD:main) to its upstream's remote. A branch without an upstream needs set_upstream=True (pushed to remote, default origin, and tracked). force_with_lease=True overwrites the remote branch only if it is where we last fetched it; it counts as destructive (approval mode needs the token) and is always refused on protected branches, and a client that supports elicitation asks the user to confirm it first. Returns the upstream and ahead/behind counts before and after. Push output is streamed as progress notifications. Pushing a protected branch (workflow_protected_branches: main, master, release/* by default) is refused unless allow_protected=True. With dry_run=True the git command is returned instead of executed."""
+    dry_run = _is_dry_run(dry_run)
+    if not dry_run:
+        await _ensure_branch_writable("push", allow_protected, branch)
+    if force_with_lease and not dry_run:
+        state = await core_tracking()
+        target = branch or state.branch or "HEAD"
+        dropped = (
+            f" It is {state.behind} commit(s) behind {state.upstream}; those "
+            "commits will be dropped from the remote branch."
+            if state.branch == target and state.behind
+            else ""
+        )
+        question = f"Force-push '{target}'?{dropped}"
+        if await confirm_with_user(ctx, question) is False:
+            raise ToolError(f"Force push of '{target}' cancelled by the user.")
+    try:
+        with _streaming(ctx):
+            return await core_push(
+                remote,
+                branch,
+                set_upstream=set_upstream,
+                force_with_lease=force_with_lease,
+                dry_run=dry_run,
+            )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def create_pull_request(
+    title: str,
+    body: str = "",
+    base: str | None = None,
+    head: str | None = None,
+    draft: bool = False,
+    allow_protected: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> PullRequest:
+    """Push a branch (head, default the current one) to origin and open a GitHub pull request into base (default the repo's default branch). Uses the GitHub REST API when github_token or GITHUB_TOKEN/GH_TOKEN is set, else the gh CLI. Returns the PR number and URL. Pushing a protected branch as head is refused unless allow_protected=True. With dry_run=True created is false and the push and API request (or gh command) are returned instead."""
+    dry_run = _is_dry_run(dry_run)
+    if not dry_run:
+        await _ensure_branch_writable("create_pull_request", allow_protected, head)
+    try:
+        return await core_create_pull_request(
+            title,
+            body,
+            base=base,
+            head=head,
+            draft=draft,
+            dry_run=dry_run,
+        )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def list_prs(
+    state: PullRequestState = "open",
+    base: str | None = None,
+    limit: int = 30,
+    repo_path: str | None = None,
+) -> list[PullRequestInfo]:
+    """List the repository's GitHub pull requests (open by default, optionally only those into base), most recently updated first, with number, title, author, head and base branches, draft flag and labels."""
+    try:
+        return await core_list_prs(state, base, limit)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def pr_diff(number: int, repo_path: str | None = None) -> PullRequestDiff:
+    """Fetch the diff of pull request number as structured per-file hunks: each file's path, status and counts, and each hunk's old/new line ranges and +/-/space-prefixed lines. Use the new-file line numbers (side RIGHT) to anchor pr_comment and pr_review comments."""
+    try:
+        return await core_pr_diff(number)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def pr_comment(
+    number: int,
+    body: str,
+    path: str | None = None,
+    line: int | None = None,
+    side: Side = "RIGHT",
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> ReviewPost:
+    """Comment on pull request number. With path and line the comment is attached to that line of the diff (side RIGHT: new file, LEFT: removed line); without them it goes to the PR conversation. With dry_run=True the API request is returned instead of made."""
+    try:
+        return await core_pr_comment(
+            number, body, path, line, side, dry_run=_is_dry_run(dry_run)
+        )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def pr_review(
+    number: int,
+    event: ReviewEvent,
+    body: str = "",
+    comments: list[ReviewComment] | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> ReviewPost:
+    """Submit a review of pull request number: event APPROVE, REQUEST_CHANGES or COMMENT, a summary body and optional line comments ({path, line, body, side}) anchored as in pr_diff. REQUEST_CHANGES and COMMENT need a body or comments. With dry_run=True the API request is returned instead of made."""
+    try:
+        return await core_pr_review(
+            number, event, body, comments, dry_run=_is_dry_run(dry_run)
+        )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def list_issues(
+    state: IssueState = "open",
+    labels: list[str] | None = None,
+    unlabeled: bool = False,
+    limit: int = 30,
+    repo_path: str | None = None,
+) -> list[IssueInfo]:
+    """List the repository's GitHub issues (pull requests excluded), newest first, with title, author, labels, comment count and body. labels keeps issues carrying all of them; unlabeled=True keeps only issues nobody has triaged yet."""
+    try:
+        return await core_list_issues(state, labels, unlabeled, limit)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def label_issue(
+    number: int,
+    add: list[str] | None = None,
+    remove: list[str] | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> IssueAction:
+    """Add and/or remove labels on issue number. Only labels that already exist in the repository can be added. Returns the issue's labels afterwards. With dry_run=True the API requests are returned instead of made."""
+    try:
+        return await core_label_issue(
+            number, add, remove, dry_run=_is_dry_run(dry_run)
+        )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def comment_issue(
+    number: int, body: str, dry_run: bool = False, repo_path: str | None = None
+) -> IssueAction:
+    """Post a Markdown comment on issue number. With dry_run=True the API request is returned instead of made."""
+    try:
+        return await core_comment_issue(number, body, dry_run=_is_dry_run(dry_run))
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def close_issue(
+    number: int,
+    reason: CloseReason = "completed",
+    comment: str | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> IssueAction:
+    """Close issue number as completed or not_planned (duplicates, spam, won't fix), posting comment first if given. With dry_run=True the API requests are returned instead of made."""
+    try:
+        return await core_close_issue(
+            number, reason, comment, dry_run=_is_dry_run(dry_run)
+        )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def list_milestones(
+    state: MilestoneState = "open", repo_path: str | None = None
+) -> list[MilestoneInfo]:
+    """List the repository's GitHub milestones by due date, with their open and closed item counts."""
+    try:
+        return await core_list_milestones(state)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def create_milestone(
+    title: str,
+    description: str = "",
+    due_on: str | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> MilestoneAction:
+    """Create a GitHub milestone, typically named after the release it plans (e.g. 'v1.4.0'); due_on is an ISO 8601 date. With dry_run=True the API request is returned instead of made."""
+    try:
+        return await core_create_milestone(
+            title, description, due_on, dry_run=_is_dry_run(dry_run)
+        )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def close_milestone(
+    milestone: str, dry_run: bool = False, repo_path: str | None = None
+) -> MilestoneAction:
+    """Close the milestone with this title (or number), e.g. after its release is published. With dry_run=True the API request is returned instead of made."""
+    try:
+        return await core_close_milestone(milestone, dry_run=_is_dry_run(dry_run))
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def assign_milestone(
+    number: int,
+    milestone: str | None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+) -> MilestoneAction:
+    """Assign issue or pull request number to the milestone with this title (or number); milestone=None removes it from its milestone. With dry_run=True the API request is returned instead of made."""
+    try:
+        return await core_assign_milestone(
+            number, milestone, dry_run=_is_dry_run(dry_run)
+        )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def milestone_status(
+    milestone: str, repo_path: str | None = None
+) -> MilestoneStatus:
+    """Check whether a milestone (title or number) is complete: its progress and the issues and pull requests still open in it. Call before cutting the release it tracks."""
+    try:
+        return await core_milestone_status(milestone)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def release_workspace(
+    packages: list[str] | None = None,
+    pre: bool = False,
+    allow_protected: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> WorkspaceRelease:
+    """Release the changed packages of a monorepo — a Cargo workspace, npm/yarn workspaces or pnpm-workspace.yaml. A package changed when commits since its own tag (workflow_workspace_tag_format, default <name>-v<version>) touch its directory; each gets a bump by its Conventional Commits (breaking → major, feat → minor, else patch). All bumped manifests (and Cargo.lock) are committed together, then each package is tagged, pushed and published on the forge with its own changelog as notes, in dependency order. packages limits the release to those names. Publishing stops at the first failure (error says which; released lists tags already out). On a protected branch the commit is refused unless allow_protected=True. With dry_run=True the plan and commands are returned and nothing changes."""
+    dry_run = _is_dry_run(dry_run)
+    try:
+        release = await plan_workspace_release(find_repo_root(), only=packages)
+        if not release.packages:
+            return release
+        title, _ = release_commit_message(release)
+        if violations := load_commit_policy().check(title):
+            problems = "; ".join(violations)
+            raise ToolError(f"Commit rejected by policy: {title}; {problems}")
+        if not dry_run:
+            await _ensure_branch_writable("release_workspace", allow_protected)
+        with _streaming(ctx):
+            return await core_release_workspace(
+                release, prerelease=pre, dry_run=dry_run
+            )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def promote_release_candidate(
+    rc_tag: str,
+    allow_missing_ci: bool = False,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> Promotion:
+    """Promote a GitHub pre-release (e.g. v1.2.0-rc.2) to its final version (v1.2.0): requires every CI run on the RC commit to have passed (allow_missing_ci=True if the repo has no CI) and every release asset to match a published checksum (SHA256SUMS, checksums.txt or <asset>.sha256), then tags the same commit, pushes the tag and publishes a release with the RC's notes and assets, marked as latest. With dry_run=True the checks still run and the tag/push/publish commands are returned instead of executed."""
+    try:
+        with _streaming(ctx):
+            return await core_promote_release_candidate(
+                rc_tag,
+                allow_missing_ci=allow_missing_ci,
+                dry_run=_is_dry_run(dry_run),
+            )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def build_release_artifacts(
+    targets: list[str] | None = None,
+    artifacts: list[str] | None = None,
+    out_dir: str = "dist",
+    cross: bool = True,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> ReleaseArtifacts:
+    """Build the binaries to ship with a release: cargo build --release for the host, or cross build --release --target <triple> for each of targets (default release_targets; cross=False uses cargo). Binaries are copied into out_dir as <bin>-<triple>, and a SHA256SUMS file is written beside them. Non-Rust projects are built with release_build_command; artifacts are glob patterns, relative to the repo, of the files it produces (default release_artifacts). No command can be passed, and out_dir and artifacts may not leave the repo. Returns each artifact's path, sha256 and size plus assets, the list to pass to create_release. Build output is streamed as progress notifications. With dry_run=True the build commands are returned instead."""
+    try:
+        with _streaming(ctx):
+            return await core_build_release_artifacts(
+                find_repo_root(),
+                targets=targets or (),
+                patterns=artifacts or (),
+                out_dir=out_dir,
+                cross=cross,
+                dry_run=_is_dry_run(dry_run),
+            )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def publish_crate(
+    package: str | None = None,
+    dry_run: bool = False,
+    repo_path: str | None = None,
+    ctx: Context | None = None,
+) -> CratePublish:
+    """Publish the repo's Rust crate (or the workspace member named package) to crates.io. Pre-flight checks come first: the Cargo.toml version must not already be on crates.io, HEAD must carry its tag (v<version>, <version> or <name>-v<version>), and cargo publish --dry-run must succeed; then cargo publish runs and the crates.io URL is returned. Publishing cannot be undone, so tag (create_release) before and try dry_run=True first: it runs every check and returns the publish command instead."""
+    try:
+        with _streaming(ctx):
+            return await core_publish_crate(
+                find_repo_root(), package=package, dry_run=_is_dry_run(dry_run)
+            )
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def suggest_next_version(repo_path: str | None = None) -> VersionSuggestion:
+    """Suggest the next release tag from Conventional Commits since the latest tag: breaking changes ('!' or a BREAKING CHANGE: footer) bump major, feat bumps minor, anything else patch (v prefix kept; v0.1.0 when untagged). Returns the current and next tag, the level (pass it to bump_version), a reason and evidence: the commits that decided the level. create_release uses this when tag is omitted."""
+    try:
+        return await core_suggest_next_version()
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def start_focus_session(
+    goal: str, minutes: int = 25, repo_path: str | None = None
+) -> FocusSession:
+    """Start a time-boxed focus session with a stated goal; returns the goal, time box in minutes and UTC start. Tool calls and commits made until end_focus_session are collected into a summary."""
+    try:
+        return focus.start_session(goal, minutes)
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+@mcp.tool()
+async def end_focus_session(repo_path: str | None = None) -> str:
+    """End the running focus session. Returns a Markdown summary (tool calls, commits, time used), appends it to the journal, and gives a commit trailer line referencing the session."""
+    try:
+        summary = await focus.end_session()
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+    return f"{summary.render_markdown()}\n\nTrailer:\n{summary.trailer()}"
+
+
+@mcp.tool()
+async def pause_mutations(paused: bool = True) -> str:
+    """Kill-switch for mutating tools. paused=True makes every mutating tool (commit, release, translate) return PolicyDenied until resumed; paused=False lifts only a pause set with this tool, never one the user set (mutations_paused in config or a .azathoth/pause file)."""
+    policy.set_paused(paused)
+    return policy.pause_state().describe()
+
+
+@mcp.tool()
+async def list_workflows(repo_path: str | None = None) -> list[WorkflowDefinition]:
+    """List the multi-step workflows defined in YAML (the repo's .azathoth/workflows, the user's workflows_path, then the stock ones such as 'ship'), with their inputs and steps. Run one with the run_workflow prompt."""
+    try:
+        return core_list_workflows()
+    except WorkflowError as exc:
+        raise ToolError(str(exc)) from exc
+
+
+# ── Prompts ──────────────────────────────────────────────────────────
+
+
+@mcp.prompt()
+async def autocommit(
+    focus: Annotated[
+        str | None,
+        Field(description="What the commit message should emphasise, e.g. 'a fix'"),
+    ] = None,
+    scope: Annotated[
+        str | None,
+        Field(description="Conventional-commit scope for the title, e.g. 'api'"),
+    ] = None,
+) -> str:
+    """Stage all changes and commit them with a conventional message that follows the repo's commit policy."""
+    return await _autocommit_prompt(focus, scope)
+
+
+@mcp.prompt()
+async def autorelease(
+    version: Annotated[
+        str | None,
+        Field(
+            description="Tag to release, e.g. 'v1.4.0'; defaults to the version the "
+            "commits since the last tag call for"
+        ),
+    ] = None,
+    prerelease: Annotated[
+        bool, Field(description="Publish as a pre-release (e.g. for v1.4.0-rc.1)")
+    ] = False,
+    milestone: Annotated[
+        str | None,
+        Field(
+            description="GitHub milestone whose issues and PRs must all be closed "
+            "before releasing, e.g. 'v1.4.0'"
+        ),
+    ] = None,
+) -> str:
+    """Write release notes from the changelog since the last tag, bump the manifest and publish the release."""
+    return await _autorelease_prompt(version, prerelease, milestone)
+
+
+@mcp.prompt()
+async def autofix(
+    max_iterations: Annotated[
+        int, Field(description="Rounds of build, lint and test to try at most")
+    ] = 5,
+    commit: Annotated[
+        bool, Field(description="Commit the fixes once everything passes")
+    ] = False,
+    focus: Annotated[
+        str | None,
+        Field(description="What the fixes are for, e.g. 'the parser refactor'"),
+    ] = None,
+) -> str:
+    """Fix the code until the build, the linter and the tests pass, repeating run_build, run_lint and run_tests and editing the files they point at, for at most max_iterations rounds."""
+    return await _autofix_prompt(max_iterations, commit, focus)
+
+
+@mcp.prompt()
+async def autotriage(
+    limit: Annotated[
+        int, Field(description="How many untriaged issues to handle at most")
+    ] = 20,
+    focus: Annotated[
+        str | None,
+        Field(description="What to pay attention to, e.g. 'crash reports first'"),
+    ] = None,
+) -> str:
+    """Categorize the repo's unlabeled GitHub issues, label them with the repo's labels and draft a response to each."""
+    return await _autotriage_prompt(limit, focus)
+
+
+@mcp.prompt()
+async def run_workflow(
+    workflow: Annotated[
+        str, Field(description="Workflow to run, e.g. 'ship' (see list_workflows)")
+    ],
+    inputs: Annotated[
+        dict[str, Any] | None,
+        Field(description="Values for the workflow's inputs, e.g. {\"release\": true}"),
+    ] = None,
+) -> str:
+    """Render a YAML-defined workflow (a named sequence of tool and prompt steps with conditions) as one plan for the agent to carry out."""
+    try:
+        plan = plan_workflow(load_workflow(workflow), inputs)
+    except WorkflowError as exc:
+        raise PromptError(str(exc)) from exc
+    known = {tool.name for tool in await mcp.list_tools()}
+    unknown = sorted({s.tool for s in plan.steps if s.tool and s.tool not in known})
+    if unknown:
+        raise PromptError(
+            f"Workflow '{workflow}' calls unknown tool(s): {', '.join(unknown)}."
+        )
+    embedded = {}
+    for step in plan.steps:
+        if step.prompt is None:
+            continue
+        arguments = {k: v for k, v in step.arguments.items() if v is not None}
+        try:
+            embedded[step.id] = await _PROMPT_STEPS[step.prompt](**arguments)
+        except TypeError as exc:
+            raise PromptError(
+                f"Workflow '{workflow}', step '{step.id}': bad arguments for "
+                f"{step.prompt}: {exc}"
+            ) from exc
+    return prompts.run_workflow(plan, embedded)
+
+
+@mcp.prompt()
+async def review(
+    target: Annotated[
+        str,
+        Field(
+            description="Pull request number (e.g. '42' or '#42') or git revision "
+            "range (e.g. 'main..HEAD')"
+        ),
+    ],
+) -> str:
+    """Review a pull request or revision range against the directives of the languages it touches, as blocking issues, suggestions and nitpicks (posted with pr_review for a PR)."""
+    return await _review_prompt(target)
+
+
+async def _review_prompt(target: str) -> str:
+    number = target.strip().removeprefix("#")
+    try:
+        if number.isdigit():
+            pr_number, revisions = int(number), None
+            files = [f.path for f in (await core_pr_diff(pr_number)).files]
+        else:
+            pr_number, revisions = None, target.strip()
+            files = [p.path for p in split_diff(await get_range_diff(revisions))]
+        languages = languages_of(files)
+        context = await build_master_context(languages, cwd=find_repo_root())
+    except (WorkflowError, DirectiveError) as exc:
+        raise PromptError(str(exc)) from exc
+    if not files:
+        raise PromptError(f"Nothing to review: {target} changes no files.")
+    return prompts.review(
+        files,
+        languages,
+        context.directives,
+        context.content,
+        pr_number=pr_number,
+        revisions=revisions,
+    )
+
+
+async def _autocommit_prompt(
+    focus: str | None = None, scope: str | None = None
+) -> str:
+    try:
+        commit_policy = load_commit_policy()
+    except WorkflowError as exc:
+        raise PromptError(str(exc)) from exc
+    return prompts.autocommit(
+        focus, commit_policy, scope, test_gate=get_config().workflow_test_gate
+    )
+
+
+async def _autorelease_prompt(
+    version: str | None = None,
+    prerelease: bool = False,
+    milestone: str | None = None,
+) -> str:
+    old_version = await get_latest_tag()
+    if not old_version:
+        raise PromptError("No previous tag found — cannot determine changelog.")
+    repo_url = await origin_web_url()
+    if repo_url is None:
+        raise PromptError("No origin remote — cannot link the full changelog.")
+    new_version = version or (await core_suggest_next_version()).next
+    ships_binaries = bool(get_config().release_build_command) or (
+        (find_repo_root() / "Cargo.toml").is_file()
+    )
+    return prompts.autorelease(
+        new_version,
+        repo_url,
+        old_version,
+        prerelease,
+        build_artifacts=ships_binaries,
+        milestone=milestone,
+        test_gate=get_config().workflow_test_gate,
+    )
+
+
+async def _autofix_prompt(
+    max_iterations: int = 5, commit: bool = False, focus: str | None = None
+) -> str:
+    if max_iterations < 1:
+        raise PromptError("max_iterations must be at least 1.")
+    return prompts.autofix(max_iterations, commit, focus)
+
+
+async def _autotriage_prompt(limit: int = 20, focus: str | None = None) -> str:
+    try:
+        labels = await list_labels()
+    except WorkflowError:
+        labels = []
+    return prompts.autotriage(labels, limit, focus)
+
+
+async def _explore_prompt(target_directory: str = ".") -> str:
+    return prompts.explore(target_directory)
+
+
+#: Prompts a workflow step can embed (``PROMPT_STEPS`` in core/workflows.py).
+_PROMPT_STEPS = {
+    "autocommit": _autocommit_prompt,
+    "autofix": _autofix_prompt,
+    "autorelease": _autorelease_prompt,
+    "autotriage": _autotriage_prompt,
+    "explore": _explore_prompt,
+    "review": _review_prompt,
+}
 
 
 # ── Entry point ──────────────────────────────────────────────────────────
 
 
 def run():
-    """Script entry point: `uv run workflow`."""
+    """Script entry point: `uv run workflow [--dry-run]`."""
+    if "--dry-run" in sys.argv:
+        get_config().workflow_dry_run = True
     mcp.run(transport="stdio")
@@ -0,0 +1,16 @@
+"""azathoth.vcs — version-control backend abstraction.
+
+Public surface (re-exported here for convenience):
+  - ``Vcs``                                       — the structural typing Protocol
+  - ``register``, ``get_backend``, ``list_backends`` — registry helpers
+
+Consumer code goes through ``core/vcs.py`` (``get_vcs``), which handles
+detection and the experimental-backend gate.
+"""
+
+from __future__ import annotations
+
+from azathoth.vcs.base import Vcs
+from azathoth.vcs.registry import get_backend, list_backends, register
+
+__all__ = ["Vcs", "register", "get_backend", "list_backends"]
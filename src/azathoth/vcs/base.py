@@ -0,0 +1,90 @@
+"""azathoth.vcs.base — the ``Vcs`` Protocol every version-control backend satisfies.
+
+A backend is bound to one working copy (``cwd``) and covers the operations
+the workflow tools and prompts need: status, diff, stage, commit, tag and
+log.  Results reuse the models from ``core/workflow.py`` (``GitResult``,
+``RepoStatus``, ``CommitInfo``) so callers do not care which backend ran.
+
+NO backend implementation code lives here.
+"""
+
+from __future__ import annotations
+
+from typing import Protocol, runtime_checkable
+
+from azathoth.core.workflow import CommitInfo, GitResult, RepoStatus
+
+
+@runtime_checkable
+class Vcs(Protocol):
+    """Structural typing contract for a version-control backend."""
+
+    #: Registry key (``"git"``, ``"jj"``, ``"hg"``).
+    name: str
+
+    #: Executable the backend shells out to; shown in planned commands.
+    binary: str
+
+    #: Directory marking a working copy of this backend (``.git``, ``.jj``…).
+    marker: str
+
+    #: Whether changes must be staged before committing (git's index).
+    has_index: bool
+
+    #: Experimental backends are only selected when
+    #: ``vcs_experimental_backends`` is enabled.
+    experimental: bool
+
+    #: Working copy the backend operates on (``None`` = process cwd).
+    cwd: str | None
+
+    async def status(self) -> RepoStatus:
+        """Current branch (or bookmark) and changed/untracked files."""
+        ...  # pragma: no cover
+
+    async def diff(self, staged: bool = True, paths: list[str] | None = None) -> str:
+        """Patch of pending changes.  Backends without an index ignore *staged*."""
+        ...  # pragma: no cover
+
+    async def stage(
+        self,
+        paths: list[str] | None = None,
+        include_untracked: bool = True,
+        dry_run: bool = False,
+    ) -> GitResult:
+        """Prepare changes for the next commit (a no-op where there is no index)."""
+        ...  # pragma: no cover
+
+    async def commit(
+        self,
+        title: str,
+        body: str,
+        paths: list[str] | None = None,
+        dry_run: bool = False,
+        no_verify: bool = False,
+        sign: bool = False,
+    ) -> GitResult:
+        """Record pending changes (only *paths*, when given) with a message.
+
+        *no_verify* bypasses the backend's pre-commit hooks; *sign* signs the
+        commit, or fails where the backend cannot.
+        """
+        ...  # pragma: no cover
+
+    async def tag(self, name: str, dry_run: bool = False) -> GitResult:
+        """Tag the current revision."""
+        ...  # pragma: no cover
+
+    async def latest_tag(self) -> str | None:
+        """Most recent tag reachable from the current revision."""
+        ...  # pragma: no cover
+
+    async def log(
+        self, rev_range: str | None = None, limit: int = 20
+    ) -> list[CommitInfo]:
+        """Commits in *rev_range* (backend revision syntax), newest first."""
+        ...  # pragma: no cover
+
+    async def log_since(self, tag: str) -> str:
+        """``- subject`` lines for commits after *tag*, newest first."""
+        ...  # pragma: no cover
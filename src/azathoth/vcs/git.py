@@ -0,0 +1,74 @@
+"""azathoth.vcs.git — the default backend, wrapping ``core/workflow.py``."""
+
+from __future__ import annotations
+
+from azathoth.core import workflow
+from azathoth.core.workflow import CommitInfo, GitResult, RepoStatus
+from azathoth.vcs.registry import register
+
+
+@register
+class GitVcs:
+    name = "git"
+    binary = "git"
+    marker = ".git"
+    has_index = True
+    experimental = False
+
+    def __init__(self, cwd: str | None = None):
+        self.cwd = cwd
+
+    async def status(self) -> RepoStatus:
+        return await workflow.get_repo_status(self.cwd)
+
+    async def diff(self, staged: bool = True, paths: list[str] | None = None) -> str:
+        return await workflow.get_diff(staged=staged, cwd=self.cwd, paths=paths)
+
+    async def stage(
+        self,
+        paths: list[str] | None = None,
+        include_untracked: bool = True,
+        dry_run: bool = False,
+    ) -> GitResult:
+        return await workflow.stage_all(
+            cwd=self.cwd,
+            dry_run=dry_run,
+            paths=paths,
+            include_untracked=include_untracked,
+        )
+
+    async def commit(
+        self,
+        title: str,
+        body: str,
+        paths: list[str] | None = None,
+        dry_run: bool = False,
+        no_verify: bool = False,
+        sign: bool = False,
+    ) -> GitResult:
+        return await workflow.commit(
+            title,
+            body,
+            cwd=self.cwd,
+            dry_run=dry_run,
+            paths=paths,
+            no_verify=no_verify,
+            sign=sign,
+        )
+
+    async def tag(self, name: str, dry_run: bool = False) -> GitResult:
+        if dry_run:
+            return workflow.planned(["git", "tag", name])
+        code, out, err = await workflow._run_git(["tag", name], cwd=self.cwd)
+        return GitResult(success=(code == 0), stdout=out, stderr=err)
+
+    async def latest_tag(self) -> str | None:
+        return await workflow.get_latest_tag(self.cwd)
+
+    async def log(
+        self, rev_range: str | None = None, limit: int = 20
+    ) -> list[CommitInfo]:
+        return await workflow.get_log_entries(rev_range, limit=limit, cwd=self.cwd)
+
+    async def log_since(self, tag: str) -> str:
+        return await workflow.get_log_since(tag, self.cwd)
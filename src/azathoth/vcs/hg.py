@@ -0,0 +1,128 @@
+"""azathoth.vcs.hg — experimental Mercurial backend.
+
+Mercurial has no index: modified tracked files are committed as-is, so
+every pending change is reported as staged and ``stage`` only runs
+``hg addremove`` to pick up new and deleted files.  The branch is the
+named branch (``hg branch``), not a bookmark.
+"""
+
+from __future__ import annotations
+
+from azathoth.core.workflow import (
+    CommitInfo,
+    FileChange,
+    GitResult,
+    RepoStatus,
+    planned,
+    run_command,
+)
+from azathoth.vcs.registry import register
+
+_SEP = "\x1f"
+_LOG_TEMPLATE = (
+    f"{{node}}{_SEP}{{author|person}}{_SEP}{{date|rfc3339date}}{_SEP}"
+    "{desc|firstline}\\n"
+)
+
+
+@register
+class HgVcs:
+    name = "hg"
+    binary = "hg"
+    marker = ".hg"
+    has_index = False
+    experimental = True
+
+    def __init__(self, cwd: str | None = None):
+        self.cwd = cwd
+
+    async def _hg(self, *args: str) -> tuple[int, str, str]:
+        return await run_command(["hg", *args], cwd=self.cwd)
+
+    async def status(self) -> RepoStatus:
+        _, branch, _ = await self._hg("branch")
+        status = RepoStatus(branch=branch)
+        _, out, _ = await self._hg("status")
+        for line in out.splitlines():
+            kind, _, path = line.partition(" ")
+            if kind == "?":
+                status.untracked.append(path)
+            elif path:
+                status.staged.append(FileChange(path=path, status=kind))
+        return status
+
+    async def diff(self, staged: bool = True, paths: list[str] | None = None) -> str:
+        code, out, err = await self._hg("diff", "--git", *(paths or []))
+        return out if code == 0 else err
+
+    async def stage(
+        self,
+        paths: list[str] | None = None,
+        include_untracked: bool = True,
+        dry_run: bool = False,
+    ) -> GitResult:
+        if not include_untracked:
+            return GitResult(success=True, stdout="", stderr="")
+        args = ["addremove", *(paths or [])]
+        if dry_run:
+            return planned(["hg", *args])
+        code, out, err = await self._hg(*args)
+        return GitResult(success=(code == 0), stdout=out, stderr=err)
+
+    async def commit(
+        self,
+        title: str,
+        body: str,
+        paths: list[str] | None = None,
+        dry_run: bool = False,
+        no_verify: bool = False,
+        sign: bool = False,
+    ) -> GitResult:
+        if sign:
+            message = f"Commit signing is not supported with {self.name}; use git."
+            return GitResult(success=False, stdout="", stderr=message, message=message)
+        args = ["commit", "-m", f"{title}\n\n{body}".strip(), *(paths or [])]
+        if no_verify:
+            hooks = ["--config", "hooks.precommit=", "--config", "hooks.pretxncommit="]
+            args = [*hooks, *args]
+        if dry_run:
+            return planned(["hg", *args])
+        code, out, err = await self._hg(*args)
+        return GitResult(success=(code == 0), stdout=out, stderr=err)
+
+    async def tag(self, name: str, dry_run: bool = False) -> GitResult:
+        if dry_run:
+            return planned(["hg", "tag", name])
+        code, out, err = await self._hg("tag", name)
+        return GitResult(success=(code == 0), stdout=out, stderr=err)
+
+    async def latest_tag(self) -> str | None:
+        code, out, _ = await self._hg("log", "-r", ".", "-T", "{latesttag}")
+        return out if code == 0 and out and out != "null" else None
+
+    async def log(
+        self, rev_range: str | None = None, limit: int = 20
+    ) -> list[CommitInfo]:
+        code, out, _ = await self._hg(
+            "log",
+            "-r",
+            f"reverse({rev_range or '::.'})",
+            f"--limit={limit}",
+            "-T",
+            _LOG_TEMPLATE,
+        )
+        if code != 0:
+            return []
+        entries = []
+        for line in out.splitlines():
+            sha, author, date, subject = (line.split(_SEP) + ["", "", ""])[:4]
+            entries.append(
+                CommitInfo(sha=sha, author=author, date=date, subject=subject)
+            )
+        return entries
+
+    async def log_since(self, tag: str) -> str:
+        code, out, _ = await self._hg(
+            "log", "-r", f"reverse({tag}::. - {tag})", "-T", "- {desc|firstline}\\n"
+        )
+        return out if code == 0 else ""
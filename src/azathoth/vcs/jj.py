@@ -0,0 +1,135 @@
+"""azathoth.vcs.jj — experimental Jujutsu backend.
+
+Jujutsu has no index: the working copy is snapshotted into ``@`` on every
+command, so ``stage`` is a no-op and ``diff`` ignores ``staged``.  The
+"branch" reported by ``status`` is the bookmark on ``@-``.  Tags can be
+read but not created (``jj`` has no tag command); in a colocated repo use
+the git backend for releases.
+"""
+
+from __future__ import annotations
+
+from azathoth.core.workflow import (
+    CommitInfo,
+    FileChange,
+    GitResult,
+    RepoStatus,
+    planned,
+    run_command,
+)
+from azathoth.vcs.registry import register
+
+_SEP = "\x1f"
+_LOG_TEMPLATE = (
+    f'commit_id ++ "{_SEP}" ++ author.name() ++ "{_SEP}" ++ '
+    f'author.timestamp().format("%Y-%m-%dT%H:%M:%S%:z") ++ "{_SEP}" ++ '
+    'description.first_line() ++ "\\n"'
+)
+
+
+@register
+class JjVcs:
+    name = "jj"
+    binary = "jj"
+    marker = ".jj"
+    has_index = False
+    experimental = True
+
+    def __init__(self, cwd: str | None = None):
+        self.cwd = cwd
+
+    async def _jj(self, *args: str) -> tuple[int, str, str]:
+        return await run_command(["jj", *args], cwd=self.cwd)
+
+    async def status(self) -> RepoStatus:
+        _, bookmarks, _ = await self._jj(
+            "log", "-r", "@-", "--no-graph", "-T", 'bookmarks.join(",")'
+        )
+        status = RepoStatus(branch=bookmarks or "(no bookmark)")
+        _, summary, _ = await self._jj("diff", "--summary")
+        for line in summary.splitlines():
+            kind, _, path = line.partition(" ")
+            if path:
+                status.staged.append(FileChange(path=path, status=kind))
+        return status
+
+    async def diff(self, staged: bool = True, paths: list[str] | None = None) -> str:
+        code, out, err = await self._jj("diff", "--git", *(paths or []))
+        return out if code == 0 else err
+
+    async def stage(
+        self,
+        paths: list[str] | None = None,
+        include_untracked: bool = True,
+        dry_run: bool = False,
+    ) -> GitResult:
+        return GitResult(
+            success=True,
+            stdout="",
+            stderr="",
+            message="jj snapshots the working copy automatically; nothing to stage.",
+        )
+
+    async def commit(
+        self,
+        title: str,
+        body: str,
+        paths: list[str] | None = None,
+        dry_run: bool = False,
+        no_verify: bool = False,
+        sign: bool = False,
+    ) -> GitResult:
+        if sign:
+            message = f"Commit signing is not supported with {self.name}; use git."
+            return GitResult(success=False, stdout="", stderr=message, message=message)
+        # jj runs no commit hooks, so *no_verify* has nothing to bypass.
+        args = ["commit", "-m", f"{title}\n\n{body}".strip(), *(paths or [])]
+        if dry_run:
+            return planned(["jj", *args])
+        code, out, err = await self._jj(*args)
+        return GitResult(success=(code == 0), stdout=out, stderr=err)
+
+    async def tag(self, name: str, dry_run: bool = False) -> GitResult:
+        message = (
+            "jj cannot create tags; in a colocated repository use the git backend."
+        )
+        return GitResult(success=False, stdout="", stderr=message, message=message)
+
+    async def latest_tag(self) -> str | None:
+        code, out, _ = await self._jj(
+            "log", "-r", "latest(tags() & ::@)", "--no-graph", "-T", 'tags.join("\\n")'
+        )
+        return out.splitlines()[0] if code == 0 and out else None
+
+    async def log(
+        self, rev_range: str | None = None, limit: int = 20
+    ) -> list[CommitInfo]:
+        code, out, _ = await self._jj(
+            "log",
+            "-r",
+            rev_range or "::@-",
+            f"--limit={limit}",
+            "--no-graph",
+            "-T",
+            _LOG_TEMPLATE,
+        )
+        if code != 0:
+            return []
+        entries = []
+        for line in out.splitlines():
+            sha, author, date, subject = (line.split(_SEP) + ["", "", ""])[:4]
+            entries.append(
+                CommitInfo(sha=sha, author=author, date=date, subject=subject)
+            )
+        return entries
+
+    async def log_since(self, tag: str) -> str:
+        code, out, _ = await self._jj(
+            "log",
+            "-r",
+            f"{tag}..@-",
+            "--no-graph",
+            "-T",
+            '"- " ++ description.first_line() ++ "\\n"',
+        )
+        return out if code == 0 else ""
@@ -0,0 +1,52 @@
+"""azathoth.vcs.registry — name → class mapping for VCS backends.
+
+Responsibilities:
+  - ``register(backend)``   — add a backend class.
+  - ``get_backend(name)``   — resolve a name to a backend class.
+  - ``list_backends()``     — enumerate registered names.
+
+Selection (auto-detection, the experimental gate) lives in ``core/vcs.py``.
+"""
+
+from __future__ import annotations
+
+import logging
+from typing import Callable
+
+from azathoth.vcs.base import Vcs
+
+log = logging.getLogger(__name__)
+
+_BACKENDS: dict[str, Callable[[str | None], Vcs]] = {}
+
+
+def register(backend: Callable[[str | None], Vcs]) -> Callable[[str | None], Vcs]:
+    """Register a backend class; usable as a class decorator.
+
+    Raises:
+        TypeError: If an instance does not satisfy the ``Vcs`` Protocol.
+    """
+    instance = backend(None)
+    if not isinstance(instance, Vcs):
+        raise TypeError(f"{backend!r} does not satisfy the Vcs Protocol.")
+    _BACKENDS[instance.name] = backend
+    log.debug("Registered VCS backend '%s'", instance.name)
+    return backend
+
+
+def get_backend(name: str) -> Callable[[str | None], Vcs]:
+    """Return the backend class registered as *name*.
+
+    Raises:
+        KeyError: If *name* has not been registered.
+    """
+    if name not in _BACKENDS:
+        raise KeyError(
+            f"VCS backend '{name}' is not registered. Available: {list_backends()}"
+        )
+    return _BACKENDS[name]
+
+
+def list_backends() -> list[str]:
+    """Return a sorted list of all registered backend names."""
+    return sorted(_BACKENDS)
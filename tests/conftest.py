@@ -1,5 +1,9 @@
 import pytest
-import subprocess
+from pydantic import SecretStr
+
+from azathoth.config import get_config
+from azathoth.core import github
+from azathoth.dev.testing import GitRepo
 
 
 @pytest.fixture
@@ -18,11 +22,28 @@ def temp_dir(tmp_path):
 
 @pytest.fixture
 def git_repo(tmp_path):
-    d = tmp_path / "git_test"
-    d.mkdir()
-    subprocess.run(["git", "init"], cwd=d, check=True)
-    subprocess.run(
-        ["git", "config", "user.email", "you@example.com"], cwd=d, check=True
-    )
-    subprocess.run(["git", "config", "user.name", "Your Name"], cwd=d, check=True)
-    return d
+    return GitRepo.create(tmp_path / "git_test").path
+
+
+class _Sent(list):
+    """Requests made, as ``(method, url, token, kwargs)``."""
+
+    replies: list
+
+
+@pytest.fixture
+def api(git_repo, monkeypatch):
+    """Requests sent to a fake GitHub API, answered from ``api.replies``."""
+    GitRepo(git_repo).git("remote", "add", "origin", "git@github.com:team/app.git")
+    monkeypatch.setattr(get_config(), "release_backend", "auto")
+    monkeypatch.setattr(get_config(), "github_token", SecretStr("t0ken"))
+    monkeypatch.setattr(get_config(), "github_api_url", None)
+    sent = _Sent()
+    sent.replies = []
+
+    async def request(method, url, token, **kwargs):
+        sent.append((method, url, token, kwargs))
+        return sent.replies.pop(0)
+
+    monkeypatch.setattr(github, "_request", request)
+    return sent
@@ -0,0 +1,98 @@
+import hashlib
+import json
+
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core import artifacts
+from azathoth.core.artifacts import build_release_artifacts
+from azathoth.core.exceptions import WorkflowError
+
+
+@pytest.fixture
+def cargo(tmp_path, monkeypatch):
+    """A binary crate whose builds write ``<target>/release/app``."""
+    root = tmp_path / "app"
+    root.mkdir()
+    (root / "Cargo.toml").write_text('[package]\nname = "app"\nversion = "1.0.0"\n')
+    target_dir = root / "target"
+    metadata = {
+        "target_directory": str(target_dir),
+        "packages": [
+            {"targets": [{"name": "app", "kind": ["bin"]}, {"kind": ["lib"]}]}
+        ],
+    }
+    commands = []
+
+    async def run(argv, cwd=None, env=None):
+        commands.append(argv)
+        if argv[1] == "metadata":
+            return 0, json.dumps(metadata), ""
+        triple = argv[argv.index("--target") + 1] if "--target" in argv else ""
+        suffix = ".exe" if "windows" in triple else ""
+        (target_dir / triple / "release").mkdir(parents=True, exist_ok=True)
+        (target_dir / triple / "release" / f"app{suffix}").write_text(triple or "host")
+        return 0, "", ""
+
+    monkeypatch.setattr(artifacts, "run_command", run)
+    monkeypatch.setattr(get_config(), "release_build_command", None)
+    monkeypatch.setattr(get_config(), "release_targets", [])
+    return root, commands
+
+
+@pytest.mark.asyncio
+async def test_cross_builds_each_target_and_writes_checksums(cargo):
+    root, commands = cargo
+    targets = ["x86_64-unknown-linux-musl", "x86_64-pc-windows-gnu"]
+
+    plan = await build_release_artifacts(root, targets, dry_run=True)
+    assert plan.commands == [f"cross build --release --target {t}" for t in targets]
+    assert not commands
+
+    built = await build_release_artifacts(root, targets)
+
+    assert built.assets == [
+        "dist/app-x86_64-unknown-linux-musl",
+        "dist/app-x86_64-pc-windows-gnu.exe",
+        "dist/SHA256SUMS",
+    ]
+    digest = hashlib.sha256(b"x86_64-pc-windows-gnu").hexdigest()
+    assert built.artifacts[1].sha256 == digest
+    sums = (root / "dist" / "SHA256SUMS").read_text().splitlines()
+    assert sums[1] == f"{digest}  app-x86_64-pc-windows-gnu.exe"
+
+
+@pytest.mark.asyncio
+async def test_configured_command_collects_its_globs(cargo, monkeypatch):
+    root, commands = cargo
+    monkeypatch.setattr(get_config(), "release_build_command", "make dist")
+    monkeypatch.setattr(get_config(), "release_artifacts", [])
+    with pytest.raises(WorkflowError, match="needs release_artifacts"):
+        await build_release_artifacts(root)
+
+    (root / "out").mkdir()
+    (root / "out" / "app.tar.gz").write_bytes(b"tarball")
+    built = await build_release_artifacts(root, patterns=["out/*.tar.gz"])
+
+    assert commands == [["make", "dist"]]
+    assert built.assets == ["dist/app.tar.gz", "dist/SHA256SUMS"]
+    assert built.artifacts[0].size == len(b"tarball")
+
+
+@pytest.mark.asyncio
+async def test_out_dir_and_patterns_stay_in_the_repository(cargo, monkeypatch):
+    root, commands = cargo
+    outside = root.parent / "outside"
+    for out_dir in ("../outside", str(outside)):
+        with pytest.raises(WorkflowError, match="stay in the repository"):
+            await build_release_artifacts(root, out_dir=out_dir)
+    assert not commands and not outside.exists()
+
+    monkeypatch.setattr(get_config(), "release_build_command", "make dist")
+    (root.parent / "secret.key").write_text("key")
+    with pytest.raises(WorkflowError, match="must be relative"):
+        await build_release_artifacts(root, patterns=[str(root.parent / "*.key")])
+    assert not commands
+    with pytest.raises(WorkflowError, match="stay in the repository"):
+        await build_release_artifacts(root, patterns=["../*.key"])
+    assert not (root / "dist" / "secret.key").exists()
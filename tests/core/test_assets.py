@@ -0,0 +1,67 @@
+import struct
+
+from azathoth.core.assets import catalog_assets, image_dimensions
+
+
+def _png(width, height):
+    return b"\x89PNG\r\n\x1a\n" + b"\0\0\0\rIHDR" + struct.pack(">II", width, height)
+
+
+def _jpeg(width, height):
+    app0 = b"\xff\xe0" + struct.pack(">H", 16) + b"JFIF\0" + b"\0" * 9
+    sof0 = b"\xff\xc0" + struct.pack(">HBHH", 17, 8, height, width) + b"\0" * 10
+    return b"\xff\xd8" + app0 + sof0
+
+
+def test_image_dimensions(tmp_path):
+    samples = {
+        "a.png": _png(64, 32),
+        "b.gif": b"GIF89a" + struct.pack("<HH", 10, 20),
+        "c.jpg": _jpeg(800, 600),
+        "d.svg": b'<?xml version="1.0"?>\n<svg width="24px" height="16" />',
+        "e.svg": b'<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 48 12"/>',
+        "f.png": b"not a png",
+    }
+    for name, data in samples.items():
+        (tmp_path / name).write_bytes(data)
+
+    dims = {name: image_dimensions(tmp_path / name) for name in samples}
+
+    assert dims == {
+        "a.png": (64, 32),
+        "b.gif": (10, 20),
+        "c.jpg": (800, 600),
+        "d.svg": (24, 16),
+        "e.svg": (48, 12),
+        "f.png": None,
+    }
+
+
+def test_catalog_assets_flags_unreferenced(tmp_path):
+    static = tmp_path / "static"
+    static.mkdir()
+    (static / "logo.png").write_bytes(_png(16, 16))
+    (static / "old-banner.png").write_bytes(_png(1200, 300))
+    (static / "Inter.woff2").write_bytes(b"wOF2")
+    fixtures = tmp_path / "tests" / "fixtures"
+    fixtures.mkdir(parents=True)
+    (fixtures / "users.json").write_text("[]")
+    (fixtures / "helpers.py").write_text("")
+    (tmp_path / "index.html").write_text('<img src="/static/logo.png">')
+    (tmp_path / "style.css").write_text("src: url(Inter.woff2)")
+    (tmp_path / "tests" / "test_api.py").write_text("load('fixtures/users.json')")
+    (tmp_path / "node_modules").mkdir()
+    (tmp_path / "node_modules" / "icon.png").write_bytes(_png(1, 1))
+
+    catalog = catalog_assets(tmp_path)
+
+    assert {a.path: a.kind for a in catalog.assets} == {
+        "static/Inter.woff2": "font",
+        "static/logo.png": "image",
+        "static/old-banner.png": "image",
+        "tests/fixtures/users.json": "fixture",
+    }
+    assert [a.path for a in catalog.unreferenced] == ["static/old-banner.png"]
+    banner = catalog.unreferenced[0]
+    assert (banner.format, banner.width, banner.height) == ("png", 1200, 300)
+    assert "unreferenced" in catalog.render_markdown()
@@ -0,0 +1,54 @@
+import json
+
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core import audit
+from azathoth.core.workflow import stage_all
+
+
+@pytest.fixture(autouse=True)
+def _audit_dir(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path)
+    monkeypatch.setattr(get_config(), "audit_enabled", True)
+
+
+@pytest.mark.asyncio
+async def test_git_commands_captured_only_inside_block(git_repo):
+    await stage_all(cwd=str(git_repo))  # outside any capture: ignored
+    with audit.capture_commands() as commands:
+        await stage_all(cwd=str(git_repo))
+    assert commands == ["git add ."]
+
+
+def test_write_record_appends_jsonl():
+    audit.write_record(
+        audit.AuditRecord(
+            server="workflow",
+            tool="stage_and_commit",
+            arguments=audit.redact_arguments({"focus": "x" * 1000, "dry_run": True}),
+            duration_ms=12.5,
+            success=False,
+            error="ToolError: paused",
+            commands=["git add ."],
+        )
+    )
+    audit.write_record(
+        audit.AuditRecord(server="scout", tool="doc_drift", duration_ms=1, success=True)
+    )
+
+    lines = get_config().audit_file.read_text().splitlines()
+    first = json.loads(lines[0])
+    assert len(lines) == 2
+    assert first["tool"] == "stage_and_commit"
+    assert first["arguments"]["dry_run"] is True
+    assert first["arguments"]["focus"].endswith("(1000 chars)")
+    assert first["commands"] == ["git add ."]
+
+
+def test_disabled_audit_writes_nothing(monkeypatch):
+    monkeypatch.setattr(get_config(), "audit_enabled", False)
+    audit.write_record(
+        audit.AuditRecord(server="s", tool="t", duration_ms=0, success=True)
+    )
+    assert not get_config().audit_file.exists()
@@ -0,0 +1,60 @@
+import sys
+
+import pytest
+
+from azathoth.core.bisect import bisect_run
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.tasks import Task
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def history(git_repo):
+    """Six commits; the fourth breaks the check."""
+    repo = GitRepo(git_repo)
+    for n in range(1, 7):
+        state = "broken" if n >= 4 else "ok"
+        repo.commit(f"change {n}", {"state.txt": f"{state} {n}\n"})
+    # Untracked, so every checkout keeps it.
+    repo.write(
+        "check.py", "import sys\nsys.exit('broken' in open('state.txt').read())\n"
+    )
+    return repo
+
+
+@pytest.fixture
+def check():
+    return Task(
+        name="check", runner="make", argv=[sys.executable, "check.py"], source="test"
+    )
+
+
+@pytest.mark.asyncio
+async def test_bisect_finds_first_bad_commit(history, check):
+    head = history.git("rev-parse", "HEAD")
+
+    result = await bisect_run("HEAD~5", "HEAD", check, 60, cwd=str(history.path))
+
+    assert result.ran
+    assert result.first_bad.subject == "change 4"
+    assert (result.files_changed, result.insertions, result.deletions) == (1, 1, 1)
+    assert "state.txt" in result.diffstat
+    assert {step.verdict for step in result.steps} == {"good", "bad"}
+    assert history.git("rev-parse", "HEAD") == head
+    assert not (history.path / ".git" / "BISECT_LOG").exists()
+
+
+@pytest.mark.asyncio
+async def test_bisect_dry_run_and_refusals(history, check):
+    cwd = str(history.path)
+
+    plan = await bisect_run("HEAD~5", "HEAD", check, 60, cwd=cwd, dry_run=True)
+
+    assert not plan.ran
+    assert plan.commands[0] == "git bisect start HEAD 'HEAD~5'"
+    assert plan.commands[2] == "git bisect reset"
+    with pytest.raises(WorkflowError, match="Unknown revision 'v9'"):
+        await bisect_run("v9", "HEAD", check, 60, cwd=cwd)
+    (history.path / "state.txt").write_text("edited\n")
+    with pytest.raises(WorkflowError, match="uncommitted changes"):
+        await bisect_run("HEAD~5", "HEAD", check, 60, cwd=cwd)
@@ -0,0 +1,60 @@
+import pytest
+
+from azathoth.core.branches import (
+    create_branch,
+    delete_branch,
+    list_branches,
+    switch_branch,
+)
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def repo(git_repo):
+    repo = GitRepo(git_repo)
+    repo.commit("init", {"a.txt": "a"})
+    repo.git("branch", "-M", "main")
+    return repo
+
+
+@pytest.mark.asyncio
+async def test_create_switch_and_list(repo):
+    cwd = str(repo.path)
+    res = await create_branch("feature/x", cwd=cwd)
+    assert res.success
+    assert res.message is None
+    assert repo.git("branch", "--show-current") == "feature/x"
+
+    assert not (await create_branch("feature/x", cwd=cwd)).success
+    assert not (await create_branch("bad..name", cwd=cwd)).success
+    for switch in (True, False):
+        res = await create_branch("other", "--orphan", switch=switch, cwd=cwd)
+        assert not res.success and "Invalid revision" in res.message
+
+    (repo.path / "a.txt").write_text("changed")
+    res = await switch_branch("main", cwd=cwd)
+    assert res.success
+    assert "uncommitted" in res.message
+
+    branches = {b.name: b for b in await list_branches(cwd)}
+    assert branches["main"].current and branches["main"].protected
+    assert not branches["feature/x"].current
+    assert branches["feature/x"].subject == "init"
+
+
+@pytest.mark.asyncio
+async def test_delete_refuses_current_and_protected(repo):
+    cwd = str(repo.path)
+    await create_branch("topic", cwd=cwd)
+
+    res = await delete_branch("topic", cwd=cwd)
+    assert not res.success and "checked out" in res.message
+
+    await switch_branch("main", cwd=cwd)
+    res = await delete_branch("main", cwd=cwd)
+    assert not res.success and "protected" in res.message
+
+    planned = await delete_branch("topic", cwd=cwd, dry_run=True)
+    assert planned.stdout == "git branch -d topic"
+    assert (await delete_branch("topic", cwd=cwd)).success
+    assert [b.name for b in await list_branches(cwd)] == ["main"]
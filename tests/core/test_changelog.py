@@ -0,0 +1,89 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.changelog import (
+    BREAKING_SECTION,
+    OTHER_SECTION,
+    generate_changelog,
+    parse_commit,
+)
+from azathoth.dev.testing import GitRepo
+
+
+def test_parse_commit_with_scope():
+    c = parse_commit("abc1234def", "feat(cli): add --json flag")
+    assert c.type == "feat"
+    assert c.scope == "cli"
+    assert c.description == "add --json flag"
+    assert not c.breaking
+    assert c.short_sha == "abc1234"
+
+
+def test_parse_commit_breaking_markers():
+    assert parse_commit("a", "refactor!: drop py3.10").breaking
+    assert parse_commit("a", "fix: x", "BREAKING CHANGE: api moved").breaking
+
+
+def test_parse_commit_non_conventional():
+    c = parse_commit("a", "Update README")
+    assert c.type is None
+    assert c.description == "Update README"
+
+
+@pytest.mark.asyncio
+async def test_generate_changelog_groups_since_tag(git_repo):
+    repo = GitRepo(git_repo)
+    repo.commit("chore: initial", {"a.txt": "a"})
+    repo.tag("v0.1.0")
+    repo.commit("feat(api): add endpoint", {"b.txt": "b"})
+    repo.commit("fix: handle empty input", {"c.txt": "c"})
+    repo.commit("feat!: rename config keys", {"d.txt": "d"})
+    repo.commit("tweak things", {"e.txt": "e"})
+
+    changelog = await generate_changelog(cwd=str(git_repo))
+
+    assert changelog.from_ref == "v0.1.0"
+    assert len(changelog.commits) == 4
+    groups = changelog.grouped()
+    assert list(groups)[0] == BREAKING_SECTION
+    assert len(groups["🚀 Features"]) == 2
+    assert groups[OTHER_SECTION][0].description == "tweak things"
+
+    md = changelog.render_markdown()
+    assert "# Changelog (v0.1.0..HEAD)" in md
+    assert "- **api:** add endpoint" in md
+    assert "initial" not in md
+
+
+@pytest.mark.asyncio
+async def test_generate_changelog_bad_ref_raises(git_repo):
+    GitRepo(git_repo).commit("chore: initial", {"a.txt": "a"})
+    with pytest.raises(ValueError):
+        await generate_changelog("does-not-exist", cwd=str(git_repo))
+    for ref in ("--output=/tmp/x", "-p"):
+        with pytest.raises(ValueError, match="Invalid revision"):
+            await generate_changelog(ref, cwd=str(git_repo))
+
+
+@pytest.mark.asyncio
+async def test_generate_changelog_refuses_when_deepening_falls_short(
+    git_repo, tmp_path, monkeypatch
+):
+    repo = GitRepo(git_repo)
+    repo.commit("chore: initial", {"a.txt": "a"})
+    repo.tag("v0.1.0")
+    for n in range(3):
+        repo.commit(f"feat: c{n}", {"a.txt": f"c{n}"})
+    shallow = GitRepo(tmp_path / "shallow")
+    repo.git("clone", "-q", "--depth=1", f"file://{git_repo}", str(shallow.path))
+    monkeypatch.setattr(get_config(), "workflow_allow_deepen", True)
+    shallow.git("remote", "set-url", "origin", str(tmp_path / "gone"))
+    with pytest.raises(ValueError, match="deepening failed"):
+        await generate_changelog("v0.1.0", cwd=str(shallow.path))
+
+    async def exhausted(ref, cwd=None):
+        return False
+
+    monkeypatch.setattr("azathoth.core.changelog.deepen_until", exhausted)
+    with pytest.raises(ValueError, match="did not reach v0.1.0"):
+        await generate_changelog("v0.1.0", cwd=str(shallow.path))
@@ -0,0 +1,205 @@
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.checks import (
+    Diagnostic,
+    detect_check_command,
+    detect_test_command,
+    parse_diagnostics,
+    parse_test_output,
+    run_check,
+    run_tests,
+)
+from azathoth.core.exceptions import WorkflowError
+
+_CARGO = """\
+running 3 tests
+test parser::tests::empty ... ok
+test parser::tests::nested ... FAILED
+test render::tests::wide ... ignored
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out
+
+running 2 tests
+test result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out
+"""
+
+_PYTEST = """\
+tests/test_a.py .F.s                                                     [100%]
+=========================== short test summary info ============================
+FAILED tests/test_a.py::test_b - AssertionError: assert 1 == 2
+=================== 1 failed, 2 passed, 1 skipped in 0.12s ====================
+"""
+
+_GO = """\
+=== RUN   TestParse
+--- PASS: TestParse (0.00s)
+=== RUN   TestRender
+    render_test.go:12: got 1
+--- FAIL: TestRender (0.00s)
+FAIL
+"""
+
+_JEST = """\
+  ● Parser › rejects empty input
+
+Tests:       1 failed, 4 passed, 1 todo, 6 total
+"""
+
+
+@pytest.mark.parametrize(
+    "runner, output, expected",
+    [
+        ("cargo", _CARGO, (3, 1, 1, ["parser::tests::nested"])),
+        ("pytest", _PYTEST, (2, 1, 1, ["tests/test_a.py::test_b"])),
+        ("go", _GO, (1, 1, 0, ["TestRender"])),
+        ("npm", _JEST, (4, 1, 1, ["Parser › rejects empty input"])),
+        ("custom", _PYTEST, (2, 1, 1, ["tests/test_a.py::test_b"])),
+        ("pytest", "Segmentation fault", (None, None, None, [])),
+    ],
+)
+def test_parse_test_output(runner, output, expected):
+    assert parse_test_output(runner, output) == expected
+
+
+def test_detect_test_command(tmp_path, monkeypatch):
+    with pytest.raises(WorkflowError, match="workflow_test_command"):
+        detect_test_command(tmp_path)
+
+    (tmp_path / "package.json").write_text(
+        json.dumps({"scripts": {"test": 'echo "Error: no test specified" && exit 1'}})
+    )
+    (tmp_path / "pyproject.toml").write_text("")
+    assert detect_test_command(tmp_path) == ("pytest", ["pytest"])
+
+    (tmp_path / "package.json").write_text(json.dumps({"scripts": {"test": "jest"}}))
+    (tmp_path / "pnpm-lock.yaml").write_text("")
+    assert detect_test_command(tmp_path) == ("npm", ["pnpm", "run", "test"])
+
+    (tmp_path / "Cargo.toml").write_text("")
+    assert detect_test_command(tmp_path) == ("cargo", ["cargo", "test"])
+
+    monkeypatch.setattr(get_config(), "workflow_test_command", "make check")
+    assert detect_test_command(tmp_path) == ("custom", ["make", "check"])
+
+
+async def test_run_tests_reports_counts_and_failures(tmp_path, monkeypatch):
+    script = tmp_path / "suite.py"
+    script.write_text(f"import sys\nprint({_PYTEST!r})\nsys.exit(1)\n")
+    monkeypatch.setattr(
+        get_config(), "workflow_test_command", f'"{sys.executable}" suite.py'
+    )
+
+    planned = await run_tests(tmp_path, timeout=30, dry_run=True)
+    assert not planned.ran and planned.command.endswith("suite.py")
+
+    result = await run_tests(tmp_path, timeout=30)
+    assert result.ran and not result.success and result.exit_code == 1
+    assert (result.passed, result.failed, result.skipped) == (2, 1, 1)
+    assert result.failures == ["tests/test_a.py::test_b"]
+    assert "short test summary info" in result.output
+
+
+_RUSTC = """\
+   Compiling demo v0.1.0 (/work/demo)
+warning: unused variable: `x`
+ --> src/main.rs:2:9
+  |
+2 |     let x = 1;
+  |         ^ help: prefix it with an underscore: `_x`
+
+error[E0425]: cannot find value `y` in scope
+ --> /work/demo/src/main.rs:3:20
+error: could not compile `demo` (bin "demo") due to 1 previous error
+"""
+
+
+def _d(file, line, column, severity, message):
+    return Diagnostic(
+        file=file, line=line, column=column, severity=severity, message=message
+    )
+
+
+@pytest.mark.parametrize(
+    "output, expected",
+    [
+        (
+            _RUSTC,
+            [
+                _d("src/main.rs", 2, 9, "warning", "unused variable: `x`"),
+                _d("src/main.rs", 3, 20, "error", "cannot find value `y` in scope"),
+            ],
+        ),
+        (
+            "./cmd/main.go:7:2: undefined: render\n",
+            [_d("cmd/main.go", 7, 2, "error", "undefined: render")],
+        ),
+        (
+            "app.py:1:8: F401 [*] `os` imported but unused\nFound 1 error.\n",
+            [_d("app.py", 1, 8, "error", "F401 [*] `os` imported but unused")],
+        ),
+        (
+            "src/a.ts(3,5): error TS2304: Cannot find name 'x'.\n",
+            [_d("src/a.ts", 3, 5, "error", "TS2304: Cannot find name 'x'.")],
+        ),
+        (
+            "/work/demo/src/a.js\n  4:7  warning  'y' is unused  no-unused-vars\n",
+            [_d("src/a.js", 4, 7, "warning", "'y' is unused")],
+        ),
+        (
+            "Diff in /work/demo/src/lib.rs:1:\nDiff in /work/demo/src/lib.rs:9:\n"
+            "Would reformat: app.py\n",
+            [
+                _d("src/lib.rs", None, None, "error", "Not formatted."),
+                _d("app.py", None, None, "error", "Not formatted."),
+            ],
+        ),
+    ],
+)
+def test_parse_diagnostics(output, expected):
+    assert parse_diagnostics(output, Path("/work/demo")) == expected
+
+
+def test_detect_check_command(tmp_path, monkeypatch):
+    with pytest.raises(WorkflowError, match="No linter found"):
+        detect_check_command("lint", tmp_path)
+
+    (tmp_path / "pyproject.toml").write_text("[tool.ruff]\nline-length = 88\n")
+    assert detect_check_command("fmt", tmp_path, check=True) == (
+        "ruff",
+        ["ruff", "format", "--check", "."],
+    )
+    (tmp_path / "package.json").write_text(json.dumps({"scripts": {"lint": "eslint"}}))
+    assert detect_check_command("lint", tmp_path) == ("npm", ["npm", "run", "lint"])
+
+    (tmp_path / "go.mod").write_text("module demo\n")
+    assert detect_check_command("build", tmp_path) == ("go", ["go", "build", "./..."])
+
+    monkeypatch.setattr(get_config(), "workflow_fmt_command", "make fmt")
+    assert detect_check_command("fmt", tmp_path) == ("custom", ["make", "fmt"])
+
+
+async def test_run_check_reports_diagnostics(tmp_path, monkeypatch):
+    script = tmp_path / "lint.py"
+    script.write_text(
+        "import sys\n"
+        "print('src/a.py:3:1: warning: trailing whitespace', file=sys.stderr)\n"
+        "print('src/b.py:1:5: E999 SyntaxError')\n"
+        "sys.exit(1)\n"
+    )
+    monkeypatch.setattr(
+        get_config(), "workflow_lint_command", f'"{sys.executable}" lint.py'
+    )
+
+    result = await run_check("lint", tmp_path, timeout=30)
+
+    assert result.ran and not result.success and result.exit_code == 1
+    assert (result.errors, result.warnings) == (1, 1)
+    assert [(d.file, d.line, d.severity) for d in result.diagnostics] == [
+        ("src/a.py", 3, "warning"),
+        ("src/b.py", 1, "error"),
+    ]
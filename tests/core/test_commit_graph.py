@@ -0,0 +1,45 @@
+import pytest
+
+from azathoth.core.commit_graph import get_commit_graph
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.mark.asyncio
+async def test_graph_captures_fork_and_merge(git_repo):
+    repo = GitRepo(git_repo)
+    repo.commit("add a", {"a": "a"})
+    repo.tag("v0.1.0")
+    repo.git("checkout", "-q", "-b", "feature")
+    repo.commit("add b", {"b": "b"})
+    repo.git("checkout", "-q", "-")
+    repo.commit("add c", {"c": "c"})
+    repo.git("merge", "-q", "--no-ff", "-m", "merge feature", "feature")
+
+    graph = await get_commit_graph(cwd=str(git_repo))
+
+    assert len(graph.nodes) == 4
+    assert not graph.truncated
+    merge = graph.nodes[0]
+    assert merge.is_merge and graph.head == merge.sha
+    assert graph.merges == [merge]
+    root = graph.nodes[-1]
+    assert "tag: v0.1.0" in root.refs and root.parents == []
+
+    mermaid = graph.render_mermaid()
+    assert "gitGraph" in mermaid
+    assert "branch feature" in mermaid
+    assert "merge feature" in mermaid
+    assert 'tag: "v0.1.0"' in mermaid
+
+
+@pytest.mark.asyncio
+async def test_graph_limit_marks_truncation(git_repo):
+    repo = GitRepo(git_repo)
+    for name in "abc":
+        repo.commit(f"add {name}", {name: name})
+
+    graph = await get_commit_graph(limit=2, cwd=str(git_repo))
+
+    assert len(graph.nodes) == 2
+    assert graph.truncated
+    assert graph.render_mermaid().count("commit id") == 2
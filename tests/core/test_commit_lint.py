@@ -0,0 +1,53 @@
+from azathoth.core.commit_lint import imperative_form, lint_commit_message
+from azathoth.core.commit_policy import CommitPolicy
+
+
+def _rules(lint):
+    return [(v.rule, v.severity) for v in lint.violations]
+
+
+def test_imperative_form_knows_common_inflections():
+    assert [imperative_form(w) for w in ("Added", "fixes", "dropping", "wrote")] == [
+        "add",
+        "fix",
+        "drop",
+        "write",
+    ]
+    assert imperative_form("add") is None
+    assert imperative_form("changes") is None
+
+
+def test_clean_message_is_valid():
+    lint = lint_commit_message("fix(cli): handle empty input\n\nIt crashed before.\n")
+
+    assert lint.valid
+    assert lint.title == "fix(cli): handle empty input"
+    assert lint.violations == []
+
+
+def test_violations_carry_severity_and_suggestions():
+    policy = CommitPolicy(require_scope=True, max_body_line_length=20)
+    message = (
+        "feat: Added a flag.\n"
+        "more detail here that runs long\n"
+        "\n"
+        "Co-authored-by: someone\n"
+        "https://example.com/a/very/long/link/that/cannot/be/wrapped\n"
+    )
+
+    lint = lint_commit_message(message, policy)
+
+    assert not lint.valid
+    assert _rules(lint) == [
+        ("policy", "error"),
+        ("trailing-period", "warning"),
+        ("imperative-mood", "warning"),
+        ("blank-line", "error"),
+        ("policy", "error"),
+        ("body-line-length", "warning"),
+    ]
+    by_rule = {v.rule: v for v in lint.violations}
+    assert by_rule["imperative-mood"].suggestion == "feat: Add a flag."
+    assert by_rule["trailing-period"].suggestion == "feat: Added a flag"
+    assert by_rule["body-line-length"].line == 2
+    assert not lint_commit_message("\n\n").valid
@@ -0,0 +1,42 @@
+import pytest
+
+from azathoth.core import prompts
+from azathoth.core.commit_policy import CommitPolicy, load_commit_policy
+from azathoth.core.exceptions import WorkflowError
+
+
+def test_default_policy_matches_conventional_commits():
+    policy = CommitPolicy()
+    assert policy.check("feat(core): add parser", "Why it matters.") == []
+    assert policy.check("add parser")  # no type
+    assert policy.check("feat: " + "x" * 80)  # too long
+    assert policy.check("fix: typo", "Co-Authored-By: Someone <a@b.c>")
+
+
+def test_policy_from_repo_config(git_repo):
+    (git_repo / ".azathoth.toml").write_text(
+        "[commit]\n"
+        'types = ["feat", "fix"]\n'
+        "require_scope = true\n"
+        'scopes = ["cli", "core"]\n'
+        "allow_breaking = false\n"
+        'forbidden_trailers = ["^Reviewed-by:"]\n'
+    )
+    sub = git_repo / "nested"
+    sub.mkdir()
+
+    policy = load_commit_policy(sub)
+
+    assert policy.check("feat(cli): add flag") == []
+    violations = policy.check("docs(web)!: rewrite", "Reviewed-by: x")
+    assert len(violations) == 4  # type, scope, breaking, trailer
+    assert policy.check("fix: missing scope") == [
+        "A scope is required, e.g. 'feat(core): …'."
+    ]
+    assert "one of: feat, fix" in prompts.commit_system(policy=policy)
+
+
+def test_invalid_policy_raises(git_repo):
+    (git_repo / ".azathoth.toml").write_text('[commit]\nforbidden_trailers = ["("]\n')
+    with pytest.raises(WorkflowError):
+        load_commit_policy(git_repo)
@@ -0,0 +1,58 @@
+from azathoth.core.config_drift import (
+    check_config_drift,
+    find_config_families,
+    flatten_shapes,
+)
+
+
+def _project(tmp_path):
+    config = tmp_path / "config"
+    config.mkdir()
+    (config / "dev.toml").write_text(
+        'debug = true\nport = 8000\n[db]\nurl = "sqlite:///dev.db"\npool = 5\n'
+        "[cache]\nttl = 60\n"
+    )
+    (config / "prod.toml").write_text(
+        'debug = false\nport = "8000"\n[db]\nurl = "postgres://db/app"\n'
+    )
+    (tmp_path / ".env.example").write_text("API_KEY=\nTIMEOUT=30\nREGION=eu\n")
+    (tmp_path / ".env.production.example").write_text(
+        "API_KEY=secret-value\nTIMEOUT=fast\n"
+    )
+    (tmp_path / "pyproject.toml").write_text('[project]\nname = "x"\n')
+    (tmp_path / "settings.dev.json").write_text('{"a": 1}')
+    return tmp_path
+
+
+def test_families_pair_env_siblings_only(tmp_path):
+    families = {f.name: f for f in find_config_families(_project(tmp_path))}
+
+    assert set(families) == {".env", "config/*.toml"}
+    assert families[".env"].files == [".env.example", ".env.production.example"]
+    assert families["config/*.toml"].format == "toml"
+
+
+def test_flatten_shapes_records_tables():
+    shapes = flatten_shapes({"db": {"url": "https://x", "opts": {"ssl": True}}})
+    assert shapes == {
+        "db": "table",
+        "db.url": "url",
+        "db.opts": "table",
+        "db.opts.ssl": "boolean",
+    }
+
+
+def test_config_drift_reports_missing_and_shape(tmp_path):
+    report = check_config_drift(_project(tmp_path))
+    found = {(i.family, i.key, i.kind) for i in report.issues}
+
+    assert ("config/*.toml", "port", "shape") in found
+    assert ("config/*.toml", "db.pool", "missing") in found
+    assert ("config/*.toml", "cache", "missing") in found
+    assert ("config/*.toml", "cache.ttl", "missing") not in found
+    assert ("config/*.toml", "db.url", "shape") not in found
+    assert (".env", "REGION", "missing") in found
+    assert (".env", "TIMEOUT", "shape") in found
+    # An empty placeholder is compatible with any value.
+    assert (".env", "API_KEY", "shape") not in found
+    assert "secret-value" not in report.render_markdown()
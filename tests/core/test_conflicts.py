@@ -0,0 +1,89 @@
+import pytest
+
+from azathoth.core.conflicts import (
+    abort_operation,
+    continue_operation,
+    list_conflicts,
+    operation_in_progress,
+    parse_conflict_hunks,
+    resolve_conflict,
+)
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def merge_conflict(git_repo):
+    repo = GitRepo(git_repo)
+    repo.commit("init", {"app.py": "x = 1\ny = 1\n"})
+    repo.git("branch", "-M", "main")
+    repo.git("checkout", "-qb", "topic")
+    repo.commit("topic", {"app.py": "x = 1\ny = 2\n"})
+    repo.commit("add gone", {"gone.py": "new\n"})
+    repo.git("checkout", "-q", "main")
+    repo.commit("main", {"app.py": "x = 1\ny = 3\n"})
+    repo.commit("add gone differently", {"gone.py": "old\n"})
+    repo.git("merge", "topic", check=False)
+    return repo
+
+
+def test_parse_conflict_hunks():
+    text = (
+        "a\nb\nc\nd\n<<<<<<< ours\nmine\n||||||| base\norig\n=======\nyours\n"
+        ">>>>>>> theirs\ne\n<<<<<<< HEAD\n1\n=======\n2\n>>>>>>> topic\n"
+    )
+
+    first, second = parse_conflict_hunks(text)
+
+    assert (first.context, first.ours, first.base, first.theirs) == (
+        ["b", "c", "d"],
+        "mine",
+        "orig",
+        "yours",
+    )
+    assert (second.context, second.ours, second.base, second.theirs) == (
+        ["e"],
+        "1",
+        "",
+        "2",
+    )
+
+
+@pytest.mark.asyncio
+async def test_list_resolve_and_continue(merge_conflict):
+    cwd = str(merge_conflict.path)
+
+    report = await list_conflicts(cwd)
+
+    assert report.operation == "merge"
+    app, gone = report.files
+    assert (app.path, app.kind, app.markers_in_tree) == (
+        "app.py",
+        "both modified",
+        True,
+    )
+    [hunk] = app.hunks
+    assert (hunk.ours, hunk.base, hunk.theirs) == ("y = 3", "y = 1", "y = 2")
+    assert gone.kind == "both added"
+    assert (gone.hunks[0].ours, gone.hunks[0].base) == ("old", "")
+
+    refused = await resolve_conflict("app.py", "<<<<<<< x\n>>>>>>> y\n", cwd=cwd)
+    assert not refused.success and "markers" in refused.message
+    assert (await resolve_conflict("app.py", "x = 1\ny = 5\n", cwd=cwd)).success
+    assert not (await continue_operation(cwd)).success
+    assert (await resolve_conflict("gone.py", delete=True, cwd=cwd)).success
+    assert not (await resolve_conflict("app.py", "", cwd=cwd)).success
+
+    assert (await continue_operation(cwd)).success
+    assert await operation_in_progress(cwd) is None
+    assert (merge_conflict.path / "app.py").read_text() == "x = 1\ny = 5\n"
+    assert merge_conflict.git("status", "--porcelain") == ""
+
+
+@pytest.mark.asyncio
+async def test_abort(merge_conflict):
+    cwd = str(merge_conflict.path)
+
+    assert (await abort_operation(cwd, dry_run=True)).stdout == "git merge --abort"
+    assert (await abort_operation(cwd)).success
+    assert (await list_conflicts(cwd)).files == []
+    assert not (await abort_operation(cwd)).success
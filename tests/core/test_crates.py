@@ -0,0 +1,102 @@
+import pytest
+
+from azathoth.core import crates
+from azathoth.core.crates import find_crate, publish_crate
+from azathoth.core.exceptions import WorkflowError
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def crate_repo(git_repo, monkeypatch):
+    repo = GitRepo(git_repo)
+    manifest = '[package]\nname = "widget"\nversion = "0.3.0"\n'
+    repo.commit("chore: release 0.3.0", {"Cargo.toml": manifest})
+
+    async def unpublished(name, version):
+        return False
+
+    monkeypatch.setattr(crates, "is_published", unpublished)
+    return repo
+
+
+@pytest.fixture
+def cargo(monkeypatch):
+    commands = []
+
+    async def run(argv, cwd=None, env=None):
+        commands.append(argv)
+        return 0, "", ""
+
+    monkeypatch.setattr(crates, "run_command", run)
+    return commands
+
+
+def test_find_crate_in_workspace(git_repo):
+    (git_repo / "Cargo.toml").write_text('[workspace]\nmembers = ["crates/*"]\n')
+    (git_repo / "crates" / "core").mkdir(parents=True)
+    (git_repo / "crates" / "core" / "Cargo.toml").write_text(
+        '[package]\nname = "core"\nversion = "1.2.0"\n'
+    )
+
+    crate = find_crate(git_repo, "core")
+
+    assert (crate.name, crate.version, crate.member) == ("core", "1.2.0", True)
+    with pytest.raises(WorkflowError, match="No crate named 'cli'"):
+        find_crate(git_repo, "cli")
+    with pytest.raises(WorkflowError):
+        find_crate(git_repo)
+
+
+@pytest.mark.asyncio
+async def test_publish_requires_matching_tag(crate_repo, cargo):
+    cwd = str(crate_repo.path)
+    with pytest.raises(WorkflowError, match="no release tag for widget 0.3.0"):
+        await publish_crate(crate_repo.path, cwd=cwd)
+
+    crate_repo.git("tag", "v0.2.0")
+    with pytest.raises(WorkflowError, match="tagged v0.2.0, but Cargo.toml"):
+        await publish_crate(crate_repo.path, cwd=cwd)
+    assert cargo == []
+
+
+@pytest.mark.asyncio
+async def test_publish_refuses_version_on_registry(crate_repo, monkeypatch):
+    crate_repo.git("tag", "v0.3.0")
+
+    async def published(name, version):
+        return True
+
+    monkeypatch.setattr(crates, "is_published", published)
+    with pytest.raises(WorkflowError, match="already on crates.io"):
+        await publish_crate(crate_repo.path, cwd=str(crate_repo.path))
+
+
+@pytest.mark.asyncio
+async def test_publish_dry_run_then_publish(crate_repo, cargo):
+    cwd = str(crate_repo.path)
+    crate_repo.git("tag", "v0.3.0")
+
+    plan = await publish_crate(crate_repo.path, cwd=cwd, dry_run=True)
+
+    assert plan.tag == "v0.3.0"
+    assert plan.commands == ["cargo publish"]
+    assert not plan.published
+    assert cargo == [["cargo", "publish", "--dry-run"]]
+
+    result = await publish_crate(crate_repo.path, cwd=cwd)
+
+    assert result.published
+    assert result.url == "https://crates.io/crates/widget/0.3.0"
+    assert cargo[-1] == ["cargo", "publish"]
+
+
+@pytest.mark.asyncio
+async def test_publish_stops_when_dry_run_fails(crate_repo, monkeypatch):
+    crate_repo.git("tag", "v0.3.0")
+
+    async def failing(argv, cwd=None, env=None):
+        return 101, "", "error: missing field `description`"
+
+    monkeypatch.setattr(crates, "run_command", failing)
+    with pytest.raises(WorkflowError, match="--dry-run failed: .*description"):
+        await publish_crate(crate_repo.path, cwd=str(crate_repo.path))
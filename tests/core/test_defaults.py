@@ -0,0 +1,74 @@
+import pytest
+
+from azathoth.core.defaults import (
+    default_branch,
+    resolve_defaults,
+    suggest_next_version,
+)
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.mark.asyncio
+async def test_default_branch_prefers_origin_head(git_repo):
+    repo = GitRepo(git_repo)
+    repo.git("checkout", "-qb", "master")
+    repo.git("commit", "--allow-empty", "-qm", "init")
+    assert await default_branch(str(git_repo)) == "master"
+
+    repo.git("update-ref", "refs/remotes/origin/trunk", "HEAD")
+    repo.git("symbolic-ref", "refs/remotes/origin/HEAD", "refs/remotes/origin/trunk")
+    assert await default_branch(str(git_repo)) == "trunk"
+
+
+@pytest.mark.asyncio
+async def test_suggest_next_version_levels(git_repo):
+    repo = GitRepo(git_repo)
+    cwd = str(git_repo)
+    repo.git("commit", "--allow-empty", "-qm", "init")
+    assert (await suggest_next_version(cwd)).next == "v0.1.0"
+
+    repo.tag("v1.2.3")
+    repo.git("commit", "--allow-empty", "-qm", "fix: typo")
+    suggestion = await suggest_next_version(cwd)
+    assert (suggestion.current, suggestion.next, suggestion.level) == (
+        "v1.2.3",
+        "v1.2.4",
+        "patch",
+    )
+
+    assert [e.split(" ", 1)[1] for e in suggestion.evidence] == ["fix: typo"]
+
+    repo.git("commit", "--allow-empty", "-qm", "feat(cli): new flag")
+    suggestion = await suggest_next_version(cwd)
+    assert suggestion.next == "v1.3.0"
+    assert [e.split(" ", 1)[1] for e in suggestion.evidence] == ["feat(cli): new flag"]
+
+    repo.git("commit", "--allow-empty", "-qm", "refactor!: drop old API")
+    assert (await suggest_next_version(cwd)).next == "v2.0.0"
+
+    repo.tag("v2.0.0")
+    breaking = "fix: parser\n\nBREAKING CHANGE: errors are raised"
+    repo.git("commit", "--allow-empty", "-qm", breaking)
+    suggestion = await suggest_next_version(cwd)
+    assert (suggestion.next, suggestion.level) == ("v3.0.0", "major")
+    assert [e.split(" ", 1)[1] for e in suggestion.evidence] == ["fix: parser"]
+
+
+@pytest.mark.asyncio
+async def test_resolve_defaults_fills_only_missing(git_repo):
+    repo = GitRepo(git_repo)
+    cwd = str(git_repo)
+    repo.git("checkout", "-qb", "dev")
+    repo.git("commit", "--allow-empty", "-qm", "init")
+    repo.tag("1.0.0")
+    repo.git("commit", "--allow-empty", "-qm", "feat: x")
+    spec = {"tag": "next_version", "base": "default_branch"}
+
+    args, resolved = await resolve_defaults({"tag": None, "pre": True}, spec, cwd)
+    assert args["tag"] == "1.1.0" and args["pre"] is True
+    # No origin/HEAD and no main/master: left for the tool's own fallback.
+    assert resolved == {"tag": "1.1.0"} and "base" not in args
+
+    args, resolved = await resolve_defaults({"tag": "v9.9.9"}, spec, cwd)
+    assert args["tag"] == "v9.9.9"
+    assert "tag" not in resolved
@@ -0,0 +1,106 @@
+import json
+
+from azathoth.core.dependencies import dependencies
+
+
+def test_cargo_lock_resolves_transitive_and_dev(tmp_path):
+    (tmp_path / "Cargo.toml").write_text(
+        '[package]\nname = "app"\n[dependencies]\nserde = "1"\n'
+        '[dev-dependencies]\ninsta = "1"\n'
+    )
+    (tmp_path / "Cargo.lock").write_text(
+        'version = 3\n\n[[package]]\nname = "app"\nversion = "0.1.0"\n'
+        'dependencies = ["insta", "serde"]\n\n'
+        '[[package]]\nname = "serde"\nversion = "1.0.200"\nsource = "registry"\n'
+        'dependencies = ["serde_derive"]\n\n'
+        '[[package]]\nname = "serde_derive"\nversion = "1.0.200"\n'
+        'source = "registry"\n\n'
+        '[[package]]\nname = "insta"\nversion = "1.39.0"\nsource = "registry"\n'
+        'dependencies = ["similar", "serde"]\n\n'
+        '[[package]]\nname = "similar"\nversion = "2.5.0"\nsource = "registry"\n'
+    )
+
+    report = dependencies(tmp_path)
+    deps = {d.name: d for d in report.dependencies}
+
+    assert report.lockfiles == ["Cargo.lock"]
+    assert "app" not in deps
+    assert (deps["serde"].version, deps["serde"].direct) == ("1.0.200", True)
+    assert not deps["serde-derive"].direct and not deps["serde-derive"].dev
+    # Reachable from serde as well as insta: still a runtime package.
+    assert not deps["serde"].dev
+    assert deps["insta"].direct and deps["insta"].dev
+    assert not deps["similar"].direct and deps["similar"].dev
+    assert [d.name for d in report.direct] == ["insta", "serde"]
+
+
+def test_package_lock_and_go_mod(tmp_path):
+    (tmp_path / "package.json").write_text(
+        json.dumps({"dependencies": {"zod": "^3"}, "devDependencies": {"vitest": "1"}})
+    )
+    (tmp_path / "package-lock.json").write_text(
+        json.dumps(
+            {
+                "packages": {
+                    "": {
+                        "dependencies": {"zod": "^3"},
+                        "devDependencies": {"vitest": "1"},
+                    },
+                    "node_modules/zod": {"version": "3.23.8"},
+                    "node_modules/vitest": {"version": "1.6.0", "dev": True},
+                    "node_modules/vitest/node_modules/chai": {
+                        "version": "4.4.1",
+                        "dev": True,
+                    },
+                }
+            }
+        )
+    )
+    (tmp_path / "go.mod").write_text(
+        "module x\n\nrequire (\n\tgithub.com/spf13/cobra v1.8.0\n"
+        "\tgithub.com/spf13/pflag v1.0.5 // indirect\n)\n"
+    )
+
+    deps = {d.name: d for d in dependencies(tmp_path).dependencies}
+
+    assert (deps["zod"].version, deps["zod"].direct, deps["zod"].dev) == (
+        "3.23.8",
+        True,
+        False,
+    )
+    assert not deps["chai"].direct and deps["chai"].dev
+    assert deps["github.com/spf13/cobra"].direct
+    assert not deps["github.com/spf13/pflag"].direct
+
+
+def test_uv_lock_and_unlocked_fallback(tmp_path):
+    (tmp_path / "pyproject.toml").write_text(
+        '[project]\nname = "x"\ndependencies = ["httpx>=0.28"]\n'
+        '[dependency-groups]\ndev = ["pytest>=8"]\n'
+    )
+    report = dependencies(tmp_path)
+    assert report.lockfiles == []
+    assert [(d.name, d.version, d.direct) for d in report.dependencies] == [
+        ("httpx", ">=0.28", True),
+        ("pytest", ">=8", True),
+    ]
+
+    (tmp_path / "uv.lock").write_text(
+        'version = 1\n\n[[package]]\nname = "x"\nversion = "0.1.0"\n'
+        'source = { editable = "." }\ndependencies = [{ name = "httpx" }]\n\n'
+        '[package.dev-dependencies]\ndev = [{ name = "pytest" }]\n\n'
+        '[[package]]\nname = "httpx"\nversion = "0.28.1"\n'
+        'dependencies = [{ name = "anyio" }]\n\n'
+        '[[package]]\nname = "anyio"\nversion = "4.6.0"\n\n'
+        '[[package]]\nname = "pytest"\nversion = "8.3.3"\n'
+        'dependencies = [{ name = "pluggy" }]\n\n'
+        '[[package]]\nname = "pluggy"\nversion = "1.5.0"\n'
+    )
+    report = dependencies(tmp_path)
+    deps = {d.name: d for d in report.dependencies}
+
+    assert report.lockfiles == ["uv.lock"]
+    assert "x" not in deps
+    assert deps["httpx"].version == "0.28.1" and deps["httpx"].direct
+    assert not deps["anyio"].direct and not deps["anyio"].dev
+    assert deps["pluggy"].dev and not deps["pluggy"].direct
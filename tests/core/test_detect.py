@@ -0,0 +1,47 @@
+import json
+
+from azathoth.config import get_config
+from azathoth.core.detect import detect_stack, languages_of
+
+
+def test_detect_stack_monorepo(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path / "cfg")
+    (get_config().directives_dir / "d-svelte.md").write_text("Svelte rules\n")
+    (tmp_path / "pyproject.toml").write_text(
+        '[project]\nname = "api"\ndependencies = ["fastapi>=0.110"]\n'
+    )
+    (tmp_path / "Dockerfile").write_text("FROM python:3.12\n")
+    web = tmp_path / "apps" / "web"
+    web.mkdir(parents=True)
+    (web / "package.json").write_text(
+        json.dumps({"devDependencies": {"@sveltejs/kit": "^2", "svelte": "^5"}})
+    )
+    (web / "tsconfig.json").write_text("{}")
+    deps = tmp_path / "node_modules" / "x"
+    deps.mkdir(parents=True)
+    (deps / "package.json").write_text("{}")
+
+    stack = detect_stack(tmp_path)
+
+    assert stack.languages == ["python", "typescript"]
+    assert {f.name: f.manifest for f in stack.frameworks} == {
+        "fastapi": "pyproject.toml",
+        "sveltekit": "apps/web/package.json",
+        "svelte": "apps/web/package.json",
+    }
+    assert stack.manifests == ["pyproject.toml", "apps/web/package.json"]
+    assert stack.container_files == ["Dockerfile"]
+    assert stack.directives == ["svelte"]
+
+
+def test_detect_stack_empty(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path / "cfg")
+    stack = detect_stack(tmp_path)
+
+    assert stack.languages == [] and stack.directives == []
+    assert "none detected" in stack.render_markdown()
+
+
+def test_languages_of_changed_files():
+    paths = ["src/a.rs", "src/b.rs", "web/App.tsx", "README.md", "x.py", "Cargo.toml"]
+    assert languages_of(paths) == ["rust", "python", "typescript"]
@@ -0,0 +1,67 @@
+from azathoth.core.diffs import split_diff, summarize_diff
+
+_PATCH = """\
+diff --git a/src/app.py b/src/app.py
+index 1111111..2222222 100644
+--- a/src/app.py
++++ b/src/app.py
+@@ -1,3 +1,4 @@ def main():
+ import sys
++import os
+ print(sys.argv)
+-exit(0)
++exit(1)
+@@ -20,2 +21,3 @@ class App:
+     pass
++    run = True
+diff --git a/old.txt b/new.txt
+similarity index 100%
+rename from old.txt
+rename to new.txt
+diff --git a/logo.png b/logo.png
+new file mode 100644
+index 0000000..3333333
+Binary files /dev/null and b/logo.png differ
+diff --git a/gone.md b/gone.md
+deleted file mode 100644
+index 4444444..0000000
+--- a/gone.md
++++ /dev/null
+@@ -1 +0,0 @@
+-bye
+"""
+
+
+def test_split_diff_reads_status_per_file():
+    files = split_diff(_PATCH)
+
+    assert [(f.path, f.status, f.binary) for f in files] == [
+        ("src/app.py", "modified", False),
+        ("new.txt", "renamed", False),
+        ("logo.png", "added", True),
+        ("gone.md", "removed", False),
+    ]
+    assert files[1].previous_path == "old.txt"
+
+
+def test_summarize_diff_inlines_small_files_and_pages():
+    first = summarize_diff(_PATCH, max_file_bytes=100, limit=2)
+    rest = summarize_diff(_PATCH, max_file_bytes=100, offset=2, limit=2)
+
+    app = first.files[0]
+    assert (first.files_changed, first.insertions, first.deletions) == (4, 3, 2)
+    assert [(h.header, h.new_start, h.insertions) for h in app.hunks] == [
+        ("def main():", 1, 2),
+        ("class App:", 21, 1),
+    ]
+    assert app.patch is None and app.size > 100
+    assert first.files[1].patch is not None
+    assert (first.next_offset, rest.next_offset) == (2, None)
+    assert [f.path for f in rest.files] == ["logo.png", "gone.md"]
+
+
+def test_summarize_diff_returns_requested_files_whole():
+    page = summarize_diff(_PATCH, paths=["src/app.py", "old.txt"], max_file_bytes=1)
+
+    assert [f.path for f in page.files] == ["src/app.py", "new.txt"]
+    assert "+    run = True" in page.files[0].patch
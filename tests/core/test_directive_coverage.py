@@ -0,0 +1,51 @@
+import json
+import os
+import time
+
+from azathoth.config import get_config
+from azathoth.core.directive_coverage import directive_coverage
+
+
+async def test_directive_coverage_statuses(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path / "cfg")
+    directives = get_config().directives_dir
+    (directives / "d-python.md").write_text("## Python\nType everything.\n")
+    (directives / "d-typescript.md").write_text("## TS\nStrict mode.\n")
+    old = directives / "web-ui.md"
+    old.write_text("---\napplies_to: [svelte]\n---\n## Svelte\nRunes.\n")
+    long_ago = time.time() - 400 * 86400
+    os.utime(old, (long_ago, long_ago))
+    (directives / "broken.md").write_text("---\nname: x\n")
+
+    repo = tmp_path / "repo"
+    (repo / ".git").mkdir(parents=True)
+    (repo / "pyproject.toml").write_text(
+        '[project]\nname = "api"\ndependencies = ["fastapi", "typer"]\n'
+    )
+    (repo / ".azathoth" / "directives").mkdir(parents=True)
+    (repo / ".azathoth" / "directives" / "d-typer.md").write_text("## CLI\nHelp.\n")
+    web = repo / "web"
+    web.mkdir()
+    (web / "package.json").write_text(
+        json.dumps({"dependencies": {"svelte": "^5", "express": "^4"}})
+    )
+    (web / "tsconfig.json").write_text("{}")
+    (repo / "go.mod").write_text("module example.com/x\n")
+
+    report = await directive_coverage(repo, stale_days=365)
+
+    statuses = {t.name: (t.status, t.directive) for t in report.technologies}
+    assert statuses == {
+        "python": ("covered", "d-python"),
+        "typescript": ("covered", "d-typescript"),
+        "go": ("missing", None),
+        "fastapi": ("partial", "d-python"),
+        "typer": ("covered", "d-typer"),
+        "svelte": ("stale", "web-ui"),
+        "express": ("partial", "d-typescript"),
+    }
+    assert [t.name for t in report.technologies][:3] == ["go", "fastapi", "express"]
+    assert report.invalid == ["broken"]
+    svelte = next(t for t in report.technologies if t.name == "svelte")
+    assert svelte.age_days >= 399
+    assert "**go** (language): missing" in report.render_markdown()
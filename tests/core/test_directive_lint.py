@@ -0,0 +1,76 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.directive_lint import lint_directive_file, lint_directives
+from azathoth.core.exceptions import DirectiveError
+
+
+def _codes(lint):
+    return [(d.severity, d.code, d.line) for d in lint.diagnostics]
+
+
+def test_lint_reports_each_problem_with_its_line(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path / "cfg")
+    monkeypatch.setattr(
+        "azathoth.core.directive_lint.estimate_tokens", lambda text: len(text) // 4
+    )
+    pack = tmp_path / "pack"
+    pack.mkdir()
+    (pack / "d-web.md").write_text("## Web\nUse semantic HTML.\n")
+    directive = pack / "d-svelte.md"
+    directive.write_text(
+        "---\n"
+        "extends: [d-web, d-missing]\n"
+        "priorty: 3\n"
+        "section_priority:\n"
+        "  Styling: 2\n"
+        "---\n"
+        "## Runes\n"
+        "See [stores](#stores), [web](directive://d-web) and [notes](notes.md).\n"
+        "\n"
+        "```md\n"
+        "## Runes\n"
+        "```\n"
+        "## Runes\n"
+        + "word " * 200
+        + "\n"
+    )
+
+    lint = lint_directive_file(directive, max_section_tokens=100)
+
+    assert lint.name == "d-svelte"
+    assert _codes(lint) == [
+        ("error", "unknown-extends", 2),
+        ("error", "broken-link", 8),
+        ("error", "broken-link", 8),
+        ("warning", "unknown-key", 3),
+        ("warning", "unknown-section", 5),
+        ("warning", "duplicate-heading", 13),
+        ("warning", "oversized-section", 13),
+    ]
+    messages = " ".join(d.message for d in lint.diagnostics)
+    assert "'d-missing'" in messages and "'#stores'" in messages
+    assert "'notes.md'" in messages
+
+
+def test_lint_directives_targets_and_front_matter_errors(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path / "cfg")
+    pack = tmp_path / "pack"
+    pack.mkdir()
+    (pack / "good.md").write_text("---\nextends: core\n---\n## Fine\nYes.\n")
+    (pack / "good.es.md").write_text("---\nextends: core\n---\n## Bien\nSí.\n")
+    (pack / "typed.md").write_text("---\npriority: high\n---\n## X\n")
+    (pack / "broken.toml").write_text("[meta\n")
+
+    report = lint_directives(pack)
+
+    by_name = {lint.path.rsplit("/", 1)[-1]: lint for lint in report.files}
+    assert _codes(by_name["good.md"]) == []
+    assert _codes(by_name["good.es.md"]) == []
+    assert _codes(by_name["typed.md"]) == [("error", "front-matter", 2)]
+    assert _codes(by_name["broken.toml"])[0][:2] == ("error", "front-matter")
+    assert (report.errors, report.warnings) == (2, 0)
+
+    assert [f.name for f in lint_directives("core").files] == ["Core Philosophy"]
+    with pytest.raises(DirectiveError, match="No directive file"):
+        lint_directives(tmp_path / "nowhere")
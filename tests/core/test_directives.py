@@ -1,4 +1,19 @@
-from azathoth.core.directives import Directive, DirectiveMeta
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.directives import (
+    Directive,
+    DirectiveMeta,
+    build_master_context,
+    compose,
+    get_master_context,
+    list_directives,
+    list_locales,
+    parse_front_matter,
+    plain_text,
+    resolve_directives,
+)
+from azathoth.core.exceptions import DirectiveError
 
 
 def test_directive_render():
@@ -13,3 +28,260 @@ def test_directive_render():
     assert "- **rule1**: Do this." in rendered
     assert "## Examples" in rendered
     assert "print('hi')" in rendered
+
+
+def test_list_directives_includes_builtins_and_user(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path)
+    (get_config().directives_dir / "custom.toml").write_text("")
+
+    names = list_directives()
+    assert "core" in names
+    assert "custom" in names
+    assert names == sorted(names)
+
+
+def _write(directory, name, text):
+    (directory / name).write_text(text)
+
+
+def test_parse_front_matter_subset():
+    meta, body = parse_front_matter(
+        "---\nextends: [d-web, core]\ntags:\n  - ui\n  - 'svelte'\n"
+        "priority: 5\n---\n# Svelte\n"
+    )
+
+    assert meta == {
+        "extends": ["d-web", "core"],
+        "tags": ["ui", "svelte"],
+        "priority": "5",
+    }
+    assert body == "# Svelte"
+    assert parse_front_matter("# No front matter") == ({}, "# No front matter")
+
+
+@pytest.mark.asyncio
+async def test_master_context_resolves_extends_chain(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path)
+    directives = get_config().directives_dir
+    _write(directives, "core-philosophy.md", "# Core\n\n## Shared\nBe kind.\n")
+    _write(
+        directives,
+        "d-web.md",
+        "---\nextends: core-philosophy\n---\n## Web\nUse semantic HTML.\n"
+        "\n## Shared\nBe   kind.\n",
+    )
+    _write(
+        directives,
+        "d-svelte.md",
+        "---\nextends: [d-web]\ntags: [ui]\npriority: 3\n---\n## Svelte\nRunes.\n",
+    )
+
+    merged = await get_master_context(["svelte"])
+
+    assert merged.index("# Core") < merged.index("## Web") < merged.index("## Svelte")
+    assert merged.count("## Shared") == 1
+    assert merged == await get_master_context(["svelte"])
+
+
+@pytest.mark.asyncio
+async def test_rule_overrides_follow_merge_order(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path)
+    _write(
+        get_config().directives_dir,
+        "strict.toml",
+        '[meta]\nname = "Strict"\nextends = ["core"]\n'
+        '[rules]\ncomments = "No comments."\n',
+    )
+
+    resolved = await resolve_directives(["strict"])
+    merged = compose([d for _, d in resolved])
+
+    assert [name for name, _ in resolved] == ["core", "strict"]
+    assert merged.count("**comments**") == 1
+    assert "- **comments**: No comments." in merged
+
+
+@pytest.mark.asyncio
+async def test_resolve_reports_cycles_and_unknown_parents(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path)
+    directives = get_config().directives_dir
+    _write(directives, "a.md", "---\nextends: b\n---\nA\n")
+    _write(directives, "b.md", "---\nextends: a\n---\nB\n")
+    _write(directives, "c.md", "---\nextends: missing\n---\nC\n")
+
+    with pytest.raises(DirectiveError, match="a → b → a"):
+        await resolve_directives(["a"])
+    with pytest.raises(DirectiveError, match="'c' extends unknown"):
+        await resolve_directives(["c"])
+
+    _write(tmp_path, "secret.md", "Secret\n")
+    _write(directives, "d.md", "---\nextends: ../secret\n---\nD\n")
+    with pytest.raises(DirectiveError, match="'d' extends unknown"):
+        await resolve_directives(["d"])
+
+
+def test_parse_front_matter_nested_mapping():
+    meta, _ = parse_front_matter(
+        "---\nsection_priority:\n  Testing: 2\n  Examples: -1\nextends: core\n---\n"
+    )
+
+    assert meta == {
+        "section_priority": {"Testing": "2", "Examples": "-1"},
+        "extends": "core",
+    }
+
+
+@pytest.mark.asyncio
+async def test_master_context_trims_low_priority_sections(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path)
+    directives = get_config().directives_dir
+    _write(
+        directives,
+        "d-python.md",
+        "---\nsection_priority:\n  Typing: 5\n  Examples: -1\n---\n"
+        "## Typing\nHint everything.\n\n## Style\nPEP 8.\n\n"
+        "## Examples\n" + "x = 1\n" * 200,
+    )
+    _write(directives, "d-go.md", "## Errors\nWrap errors.\n\n## Layout\ncmd/.\n")
+
+    full = await get_master_context(["python", "go"])
+    assert full == await get_master_context(["python", "go"], max_chars=len(full))
+
+    budget = len(full) - 500
+    trimmed = await get_master_context(["python", "go"], max_chars=budget)
+
+    assert len(trimmed) <= budget
+    assert "## Examples" not in trimmed and "## Layout" in trimmed
+    assert "omitted: d-python › Examples." in trimmed
+
+    context = await build_master_context(["python", "go"], max_chars=budget)
+    assert context.content == trimmed
+    assert context.directives == ["core", "d-go", "d-python"]
+    assert context.omitted == ["d-python › Examples"]
+
+    core_only = await get_master_context(["python", "go"], max_chars=10)
+    assert "## Typing" not in core_only and "## Errors" not in core_only
+    assert "**strict_typing**" in core_only  # core philosophy is never trimmed
+    assert "# Directive: d-python" not in core_only
+
+
+@pytest.mark.asyncio
+async def test_project_core_philosophy_extends_or_overrides(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path / "cfg")
+    repo = tmp_path / "repo"
+    nested = repo / "packages" / "api"
+    nested.mkdir(parents=True)
+    (repo / ".git").mkdir()
+    (repo / ".azathoth").mkdir()
+    local = repo / ".azathoth" / "core-philosophy.md"
+    local.write_text("## House rules\nNo global state.\n")
+
+    extended = await get_master_context([], cwd=nested)
+    assert extended.index("**strict_typing**") < extended.index("## House rules")
+    assert "# Directive: Project Core Philosophy" in extended
+
+    plain = await get_master_context([], project_core=False, cwd=nested)
+    assert "House rules" not in plain
+
+    local.write_text("---\nmode: override\n---\n## House rules\nNo global state.\n")
+    overridden = await get_master_context([], cwd=nested)
+    assert "House rules" in overridden and "strict_typing" not in overridden
+
+    local.write_text("---\nmode: replace\n---\nx\n")
+    with pytest.raises(DirectiveError, match="mode must be one of"):
+        await get_master_context([], cwd=nested)
+
+    # Discovery stops at the repository root.
+    (tmp_path / ".azathoth").mkdir()
+    (tmp_path / ".azathoth" / "core-philosophy.md").write_text("Outside.\n")
+    local.unlink()
+    assert "Outside" not in await get_master_context([], cwd=nested)
+
+
+async def test_localized_directives_fall_back_to_default(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path)
+    directives = get_config().directives_dir
+    _write(directives, "d-rust.md", "## Errors\nUse thiserror.\n")
+    _write(directives, "d-rust.es.md", "## Errores\nUsa thiserror.\n")
+    _write(directives, "d-go.md", "## Errors\nWrap with %w.\n")
+
+    assert "d-rust.es" not in list_directives()
+    assert list_locales("d-rust") == ["es"]
+
+    context = await build_master_context(
+        ["rust", "go"], project_core=False, locale="es_MX"
+    )
+    assert "Errores" in context.content and "Use thiserror" not in context.content
+    assert "# Directive: d-rust" in context.content
+    assert context.locale == "es-mx"
+    assert context.untranslated == ["core", "d-go"]
+
+    monkeypatch.setattr(get_config(), "directive_locale", "es")
+    assert "Errores" in await get_master_context(["rust"], project_core=False)
+    english = await get_master_context(["rust"], project_core=False, locale="en")
+    assert "Use thiserror" in english
+
+    with pytest.raises(DirectiveError, match="Invalid locale"):
+        await get_master_context(["rust"], locale="../es")
+
+
+async def test_repository_directives_overlay_the_global_set(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path / "cfg")
+    _write(
+        get_config().directives_dir,
+        "d-rust.md",
+        "## Errors\nUse anyhow.\n\n## Testing\nUse proptest.\n",
+    )
+    repo = tmp_path / "repo"
+    overlays = repo / ".azathoth" / "directives"
+    overlays.mkdir(parents=True)
+    (repo / ".git").mkdir()
+    _write(overlays, "d-rust.md", "## errors\nUse thiserror.\n\n## Unsafe\nNever.\n")
+    _write(overlays, "d-house.md", "---\nextends: core\n---\n## House\nTabs.\n")
+
+    context = await build_master_context(["rust", "house"], cwd=repo)
+
+    assert context.directives == ["core", "d-house", "d-rust"]
+    assert context.overlaid == ["d-rust"]
+    content = context.content
+    assert "Use thiserror" in content and "anyhow" not in content
+    assert content.index("Use thiserror") < content.index("proptest")
+    assert content.index("proptest") < content.index("Never.")
+    assert "Tabs." in content
+
+    plain = await build_master_context(
+        ["rust"], cwd=repo, project_directives=False
+    )
+    assert "anyhow" in plain.content and plain.overlaid == []
+
+
+async def test_master_context_output_formats(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path)
+    _write(
+        get_config().directives_dir,
+        "d-go.md",
+        "---\nsection_priority:\n  Style: 2\n---\nPrefer the stdlib.\n\n"
+        "## Style\nRun **gofmt**; see [docs](https://go.dev).\n\n"
+        "```go\n// **kept**\n```\n",
+    )
+
+    context = await build_master_context(
+        ["go"], project_core=False, output_format="json"
+    )
+    assert context.format == "json" and "## Style" in context.content
+    titles = [(s.directive, s.title, s.priority) for s in context.sections]
+    assert ("d-go", "d-go", 0) in titles and ("d-go", "Style", 2) in titles
+    assert titles[0][0] == "Core Philosophy"
+    style = next(s for s in context.sections if s.title == "Style")
+    assert style.content.startswith("## Style\nRun **gofmt**")
+
+    plain = await build_master_context(
+        ["go"], project_core=False, output_format="plain"
+    )
+    assert plain.sections is None
+    assert "Run gofmt; see docs (https://go.dev)." in plain.content
+    assert "\nStyle\n" in plain.content and "// **kept**" in plain.content
+    assert plain_text("> *Note* `x`\n") == "Note x\n"
+
+    with pytest.raises(DirectiveError, match="Unknown output format"):
+        await build_master_context(["go"], output_format="html")
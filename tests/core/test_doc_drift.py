@@ -0,0 +1,59 @@
+from azathoth.core.doc_drift import check_doc_drift, extract_commands
+
+README = """\
+# Demo
+
+[![PyPI](https://img.shields.io/pypi/v/demo.svg)](https://pypi.org/project/demo/)
+[![Crates](https://img.shields.io/crates/v/demo)](https://crates.io/crates/demo)
+![version](https://img.shields.io/badge/version-0.1.0-blue)
+
+See [the guide](docs/guide.md) and [missing](docs/missing.md).
+Config lives in `src/demo/config.py`; old code was in `src/demo/old.py`.
+
+```sh
+$ just build
+just deploy
+uv run demo
+uv run ghost-script
+pip install demo==0.1.0
+```
+"""
+
+
+def _project(tmp_path):
+    (tmp_path / "pyproject.toml").write_text(
+        '[project]\nname = "demo"\nversion = "0.2.0"\n'
+        '[project.scripts]\ndemo = "demo.cli:app"\n'
+    )
+    (tmp_path / "justfile").write_text("build:\n    uv build\n")
+    (tmp_path / "docs").mkdir()
+    (tmp_path / "docs" / "guide.md").write_text("# Guide\n")
+    (tmp_path / "src" / "demo").mkdir(parents=True)
+    (tmp_path / "src" / "demo" / "config.py").write_text("")
+    (tmp_path / "README.md").write_text(README)
+    return tmp_path
+
+
+def test_extract_commands_strips_prompts():
+    commands = [cmd for _, cmd in extract_commands(README)]
+    assert commands[0] == "just build"
+    assert "uv run ghost-script" in commands
+    assert extract_commands("```python\nimport os\n```") == []
+
+
+def test_doc_drift_reports_each_kind(tmp_path):
+    report = check_doc_drift(_project(tmp_path))
+    found = {(i.kind, i.reference) for i in report.issues}
+
+    assert report.docs_checked == ["README.md", "docs/guide.md"]
+    assert ("command", "just deploy") in found
+    assert ("command", "uv run ghost-script") in found
+    assert ("command", "just build") not in found
+    assert ("command", "uv run demo") not in found
+    assert ("path", "docs/missing.md") in found
+    assert ("path", "src/demo/old.py") in found
+    assert ("path", "src/demo/config.py") not in found
+    assert ("badge", "demo") in found  # crates badge without Cargo.toml
+    assert ("version", "0.1.0") in found
+    assert ("version", "demo==0.1.0") in found
+    assert "| command |" in report.render_markdown()
@@ -0,0 +1,146 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import SandboxError
+from azathoth.core.files import (
+    Sandbox,
+    edit_file,
+    glob_files,
+    is_binary,
+    list_directory,
+    read_file,
+    write_file,
+)
+
+
+@pytest.fixture
+def project(tmp_path):
+    root = tmp_path / "project"
+    (root / "src" / "pkg").mkdir(parents=True)
+    (root / "src" / "pkg" / "mod.py").write_text("a = 1\nb = 2\nc = 3\n")
+    (root / "src" / "main.py").write_text("print('hi')\n")
+    (root / "node_modules" / "dep").mkdir(parents=True)
+    (root / "node_modules" / "dep" / "index.py").write_text("")
+    (root / "logo.png").write_bytes(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR")
+    (tmp_path / "secret.txt").write_text("outside\n")
+    return Sandbox(root)
+
+
+def test_paths_outside_root_are_refused(project):
+    with pytest.raises(SandboxError):
+        read_file(project, "../secret.txt")
+    with pytest.raises(SandboxError):
+        read_file(project, str(project.root.parent / "secret.txt"))
+    (project.root / "escape").symlink_to(project.root.parent / "secret.txt")
+    with pytest.raises(SandboxError):
+        read_file(project, "escape")
+    with pytest.raises(SandboxError):
+        list_directory(project, "..")
+    with pytest.raises(SandboxError):
+        glob_files(project, "../*")
+
+
+def test_is_binary():
+    assert is_binary(b"\x89PNG\0\0")
+    assert not is_binary("héllo\tworld\n".encode())
+    assert is_binary(bytes(range(1, 32)) * 4)
+
+
+def test_read_file_pages_and_limits(project, monkeypatch):
+    whole = read_file(project, "src/pkg/mod.py")
+    assert whole.content == "a = 1\nb = 2\nc = 3\n"
+    assert (whole.end_line, whole.total_lines, whole.truncated) == (3, 3, False)
+
+    page = read_file(project, "src/pkg/mod.py", start_line=2, max_lines=1)
+    assert page.content == "b = 2\n" and page.truncated
+
+    monkeypatch.setattr(get_config(), "scout_max_read_bytes", 8)
+    capped = read_file(project, "src/pkg/mod.py")
+    assert capped.content == "a = 1\n" and capped.total_lines is None
+
+    binary = read_file(project, "logo.png")
+    assert binary.binary and binary.content == ""
+
+    with pytest.raises(SandboxError, match="not a file"):
+        read_file(project, "src")
+
+
+def test_list_directory(project, monkeypatch):
+    top = list_directory(project)
+    assert [(e.path, e.kind) for e in top.entries] == [
+        ("node_modules", "dir"),
+        ("src", "dir"),
+        ("logo.png", "file"),
+    ]
+
+    deep = [e.path for e in list_directory(project, recursive=True).entries]
+    assert "src/pkg/mod.py" in deep and "node_modules" in deep
+    assert not any(p.startswith("node_modules/") for p in deep)
+
+    monkeypatch.setattr(get_config(), "scout_max_entries", 2)
+    assert list_directory(project, recursive=True).truncated
+
+
+def test_glob(project):
+    assert glob_files(project, "**/*.py").matches == ["src/main.py", "src/pkg/mod.py"]
+    assert glob_files(project, "*.md").matches == []
+
+
+def test_deny_patterns_refuse_reads_and_hide_matches(project):
+    root = project.root
+    (root / ".git" / "objects" / "ab").mkdir(parents=True)
+    (root / ".git" / "HEAD").write_text("ref: refs/heads/main\n")
+    (root / "src" / "node_modules").mkdir()
+    (root / "src" / "node_modules" / "x.py").write_text("")
+    (root / "vendored").symlink_to(root / "node_modules")
+
+    with pytest.raises(SandboxError, match="off limits"):
+        read_file(project, "src/node_modules/x.py")
+    with pytest.raises(SandboxError, match="off limits"):
+        list_directory(project, "vendored")
+    with pytest.raises(SandboxError, match=r"matches '\.git/objects'"):
+        list_directory(project, ".git/objects")
+    assert read_file(project, ".git/HEAD").content.startswith("ref:")
+
+    custom = Sandbox(root, deny=("src/pkg",))
+    assert glob_files(custom, "**/*.py").matches == ["src/main.py"]
+    assert "src/pkg/mod.py" not in [
+        e.path for e in list_directory(custom, recursive=True).entries
+    ]
+    assert read_file(custom, "src/node_modules/x.py").content == ""
+
+
+def test_listing_and_glob_leave_out_ignored_paths(project):
+    root = project.root
+    (root / ".gitignore").write_text("*.png\nsrc/pkg/\n")
+
+    listed = [e.path for e in list_directory(project, recursive=True).entries]
+
+    assert "logo.png" not in listed and "src/pkg" not in listed
+    assert "src/main.py" in listed
+    assert glob_files(project, "**/*.py").matches == ["src/main.py"]
+    assert read_file(project, "src/pkg/mod.py").content.startswith("a = 1")
+
+
+def test_write_and_edit_stay_in_the_sandbox(project):
+    written = write_file(project, "docs/notes.md", "one\ntwo\ntwo\n")
+    assert written.created and written.size == 12
+    assert (project.root / "docs" / "notes.md").read_text() == "one\ntwo\ntwo\n"
+
+    edited = edit_file(project, "docs/notes.md", "one", "1")
+    assert edited.replacements == 1 and not edited.created
+    with pytest.raises(SandboxError, match="occurs 2 times"):
+        edit_file(project, "docs/notes.md", "two", "2")
+    replaced = edit_file(project, "docs/notes.md", "two", "2", replace_all=True)
+    assert replaced.replacements == 2
+    planned = edit_file(project, "docs/notes.md", "1", "one", dry_run=True)
+    assert not planned.written
+    assert (project.root / "docs" / "notes.md").read_text() == "1\n2\n2\n"
+
+    with pytest.raises(SandboxError, match="not in"):
+        edit_file(project, "docs/notes.md", "three", "3")
+    with pytest.raises(SandboxError, match="binary"):
+        edit_file(project, "logo.png", "PNG", "GIF")
+    for path in ("../secret.txt", ".git/hooks/pre-commit", "node_modules/x.js"):
+        with pytest.raises(SandboxError):
+            write_file(project, path, "x")
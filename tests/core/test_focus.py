@@ -0,0 +1,56 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core import focus
+from azathoth.core.audit import AuditRecord, write_record
+from azathoth.core.exceptions import WorkflowError
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture(autouse=True)
+def _no_active_session(monkeypatch):
+    monkeypatch.setattr(focus, "_active", None)
+
+
+def test_start_twice_raises():
+    focus.start_session("first")
+    with pytest.raises(WorkflowError, match="already running"):
+        focus.start_session("second")
+
+
+@pytest.mark.asyncio
+async def test_end_without_session_raises():
+    with pytest.raises(WorkflowError):
+        await focus.end_session()
+
+
+@pytest.mark.asyncio
+async def test_session_summary_collects_calls_and_commits(
+    git_repo, tmp_path, monkeypatch
+):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path / "cfg")
+    monkeypatch.setattr(get_config(), "audit_enabled", False)
+
+    def audited(tool):
+        write_record(
+            AuditRecord(server="workflow", tool=tool, duration_ms=1, success=True)
+        )
+
+    audited("ignored_before_start")
+    session = focus.start_session("ship parser", minutes=30)
+    assert session.remaining().total_seconds() > 0
+
+    audited("get_diff")
+    audited("get_diff")
+    audited("stage_and_commit")
+    GitRepo(git_repo).commit("feat: parser", {"a.txt": "a"})
+
+    summary = await focus.end_session(cwd=str(git_repo))
+
+    assert focus.active_session() is None
+    assert summary.tool_calls == {"get_diff": 2, "stage_and_commit": 1}
+    assert len(summary.commits) == 1
+    assert summary.commits[0].endswith("feat: parser")
+    assert summary.trailer().startswith("Focus-Session: ship parser")
+    journal = (tmp_path / "cfg" / "journal.md").read_text()
+    assert "## Focus session: ship parser" in journal
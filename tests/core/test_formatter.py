@@ -0,0 +1,123 @@
+import random
+from dataclasses import dataclass
+
+import pytest
+from pydantic import BaseModel
+
+from azathoth.core.formatter import (
+    Table,
+    display_width,
+    render_table,
+    truncate,
+    wrap,
+)
+
+# Alphabet mixing ASCII, CJK, emoji, combining marks and zero-width joiners.
+_ALPHABET = list("abcXYZ 019-_") + list("漢字한국어カナ") + [
+    "🚀",
+    "✓",
+    "\u00e9",  # precomposed é
+    "e\u0301",  # e + combining acute
+    "\u200d",  # zero width joiner
+    "\U0001f469\u200d\U0001f4bb",  # woman technologist (ZWJ sequence)
+]
+
+
+def _random_strings(seed: int, count: int = 200) -> list[str]:
+    rng = random.Random(seed)
+    return [
+        "".join(rng.choice(_ALPHABET) for _ in range(rng.randint(0, 30)))
+        for _ in range(count)
+    ]
+
+
+def test_display_width_basics():
+    assert display_width("abc") == 3
+    assert display_width("漢字") == 4
+    assert display_width("🚀") == 2
+    assert display_width("é") == 1
+    assert display_width("") == 0
+
+
+def test_truncate_adds_ellipsis():
+    assert truncate("hello world", 6) == "hello…"
+    assert truncate("short", 10) == "short"
+    assert display_width(truncate("漢字漢字漢字", 5)) <= 5
+
+
+@pytest.mark.parametrize("seed", range(5))
+def test_truncate_never_exceeds_width(seed):
+    for s in _random_strings(seed):
+        for width in (1, 3, 8):
+            assert display_width(truncate(s, width)) <= width
+
+
+@pytest.mark.parametrize("seed", range(5))
+def test_wrap_lines_fit_and_preserve_content(seed):
+    for s in _random_strings(seed):
+        for width in (1, 2, 5, 11):
+            lines = wrap(s, width)
+            assert all(display_width(line) <= width for line in lines)
+            if width > 1:
+                assert "".join(lines).replace(" ", "") == s.replace(" ", "")
+
+
+def test_wrap_replaces_characters_wider_than_the_line():
+    assert wrap("a漢b", 1) == ["a", "…", "b"]
+    assert wrap("🚀", 1) == ["…"]
+
+
+@pytest.mark.parametrize("overflow", ["wrap", "ellipsis"])
+@pytest.mark.parametrize("seed", range(5))
+def test_render_table_rows_align(seed, overflow):
+    rng = random.Random(seed)
+    cells = _random_strings(seed, count=30)
+    rows = [[cells[i], cells[i + 1], str(rng.randint(0, 999))] for i in range(0, 28, 3)]
+
+    table = render_table(
+        ["Label", "値", "N"], rows, max_widths=[12, 8, None], overflow=overflow
+    )
+
+    widths = {display_width(line) for line in table.splitlines()}
+    assert len(widths) == 1
+
+
+def test_render_table_validates_shape():
+    with pytest.raises(ValueError):
+        render_table(["a", "b"], [["only one"]])
+    with pytest.raises(ValueError):
+        render_table(["a"], [["x"]], max_widths=[1, 2])
+
+
+def test_table_builder_aligns_and_accepts_mixed_rows():
+    class Model(BaseModel):
+        id: int
+        label: str
+
+    @dataclass
+    class Point:
+        id: int
+        label: str
+
+    out = (
+        Table()
+        .column("ID", key="id", align="right")
+        .column("Label", key="label")
+        .rows([Model(id=7, label="seven"), Point(id=12, label="twelve")])
+        .row({"id": 100, "label": None})
+        .row([3, "three"])
+        .render()
+    )
+    lines = out.splitlines()
+    assert lines[0] == "ID  | Label "
+    assert lines[2] == "  7 | seven "
+    assert lines[4] == "100 |       "
+    assert lines[5] == "  3 | three "
+    assert str(Table().column("x").row(["y"])) == "x\n-\ny"
+
+
+def test_table_rejects_unsupported_rows():
+    with pytest.raises(TypeError):
+        Table().column("a").row(42)
+    with pytest.raises(ValueError):
+        Table().column("a").row(["x"]).column("b")
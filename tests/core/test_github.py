@@ -0,0 +1,97 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.github import create_pull_request
+from azathoth.core.release import create_release
+from azathoth.core.runner import Response, ScriptedRunner, SubprocessRunner, use_runner
+from azathoth.dev.testing import GitRepo
+
+_API = "https://api.github.com/repos/team/app"
+
+
+@pytest.fixture
+def scripted_push():
+    runner = ScriptedRunner(
+        [Response(["git", "tag"]), Response(["git", "push"])],
+        fallback=SubprocessRunner(),
+    )
+    with use_runner(runner):
+        yield runner
+
+
+@pytest.mark.asyncio
+async def test_release_is_published_through_the_api(git_repo, api, scripted_push):
+    (git_repo / "dist").mkdir()
+    (git_repo / "dist" / "app.tar.gz").write_bytes(b"archive")
+    api.replies += [
+        {
+            "html_url": "https://github.com/team/app/releases/tag/v1.0.0",
+            "upload_url": "https://uploads.github.com/repos/team/app/releases/7"
+            "/assets{?name,label}",
+        },
+        {"browser_download_url": "https://github.com/team/app/download/app.tar.gz"},
+    ]
+
+    plan = await create_release(
+        "v1.0.0", "notes", dry_run=True, cwd=str(git_repo), assets=["dist/app.tar.gz"]
+    )
+    assert plan.stdout.splitlines()[-2:] == [
+        f"POST {_API}/releases",
+        f"POST '{_API}/releases/<id>/assets?name=app.tar.gz'",
+    ]
+    res = await create_release(
+        "v1.0.0", "notes", cwd=str(git_repo), assets=["dist/app.tar.gz"]
+    )
+
+    assert res.success
+    assert res.stdout.splitlines()[0].endswith("/releases/tag/v1.0.0")
+    (method, url, token, kwargs), upload = api
+    assert (method, url, token) == ("POST", f"{_API}/releases", "t0ken")
+    assert kwargs["json"]["tag_name"] == "v1.0.0"
+    assert upload[1].endswith("/releases/7/assets?name=app.tar.gz")
+    assert upload[3]["content"] == b"archive"
+    assert [argv[1] for argv in scripted_push.commands("git")][-2:] == ["tag", "push"]
+    assert not scripted_push.commands("gh")
+
+
+@pytest.mark.asyncio
+async def test_pull_request_uses_api_with_a_token_and_gh_without(
+    git_repo, api, scripted_push, monkeypatch
+):
+    api.replies += [{"default_branch": "main"}, {"number": 12, "html_url": "u/12"}]
+
+    pr = await create_pull_request("feat: x", head="feat/x", cwd=str(git_repo))
+
+    assert (pr.via, pr.number, pr.base, pr.created) == ("api", 12, "main", True)
+    assert api[1][3]["json"]["head"] == "feat/x"
+    assert scripted_push.commands("git", "push")[-1][-1] == "feat/x"
+
+    monkeypatch.setattr(get_config(), "github_token", None)
+    monkeypatch.delenv("GITHUB_TOKEN", raising=False)
+    monkeypatch.delenv("GH_TOKEN", raising=False)
+    plan = await create_pull_request(
+        "feat: x", head="feat/x", draft=True, dry_run=True, cwd=str(git_repo)
+    )
+    assert plan.via == "gh"
+    assert plan.commands[-1].startswith("gh pr create --title 'feat: x'")
+    assert plan.commands[-1].endswith("--draft")
+
+
+@pytest.mark.asyncio
+async def test_token_never_goes_to_an_inferred_enterprise_host(
+    git_repo, api, scripted_push, monkeypatch
+):
+    scripted_push.responses.append(Response(["gh", "pr"], stdout="u/3\n"))
+    remote = "git@github.attacker.tld:team/app.git"
+    GitRepo(git_repo).git("remote", "set-url", "origin", remote)
+
+    pr = await create_pull_request("feat: x", head="feat/x", cwd=str(git_repo))
+
+    assert (pr.via, pr.number) == ("gh", 3)
+    assert list(api) == []
+
+    monkeypatch.setattr(get_config(), "github_api_url", "https://ghe.corp/api/v3")
+    api.replies += [{"default_branch": "main"}, {"number": 4, "html_url": "u/4"}]
+    pr = await create_pull_request("feat: x", head="feat/x", cwd=str(git_repo))
+    assert pr.via == "api"
+    assert api[0][1] == "https://ghe.corp/api/v3/repos/team/app"
@@ -0,0 +1,58 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core import gitlib
+from azathoth.core.history import blame_range
+from azathoth.core.runner import ScriptedRunner, use_runner
+from azathoth.core.workflow import (
+    get_diff,
+    get_diff_summary,
+    get_log_entries,
+    get_repo_status,
+)
+from azathoth.dev.testing import GitRepo
+
+
+async def _reads(cwd):
+    return (
+        await get_repo_status(cwd),
+        await get_diff(staged=True, cwd=cwd),
+        await get_diff(staged=False, cwd=cwd, paths=["src"]),
+        await get_diff_summary(staged=False, cwd=cwd),
+        await get_log_entries(cwd=cwd),
+        await get_log_entries("HEAD~1..HEAD", cwd=cwd),
+        await blame_range("src/app.py", 1, 3, cwd=cwd),
+    )
+
+
+@pytest.mark.asyncio
+async def test_library_reads_match_git(git_repo, monkeypatch):
+    pytest.importorskip("pygit2")
+    repo = GitRepo(git_repo)
+    repo.commit(
+        "feat: app", {"src/app.py": "a = 1\nb = 2\nc = 3\n", "README.md": "# demo\n"}
+    )
+    repo.commit("fix: b\n\nbody", {"src/app.py": "a = 1\nb = 20\nc = 3\n"})
+    repo.write("README.md", "# demo\n\nmore\n")
+    repo.git("add", "README.md")
+    (git_repo / "src" / "app.py").write_text("a = 1\nb = 20\n")
+    (git_repo / "logo.bin").write_bytes(b"\x00\x01")
+    cwd = str(git_repo)
+
+    monkeypatch.setattr(get_config(), "vcs_git_reads", "process")
+    expected = await _reads(cwd)
+    monkeypatch.setattr(get_config(), "vcs_git_reads", "pygit2")
+    assert gitlib.open_repo(cwd) is not None
+
+    assert await _reads(cwd) == expected
+    assert [c.path for c in expected[0].unstaged] == ["src/app.py"]
+    assert expected[0].untracked == ["logo.bin"]
+
+
+def test_reads_go_to_git_when_configured_or_scripted(git_repo, monkeypatch):
+    monkeypatch.setattr(get_config(), "vcs_git_reads", "process")
+    assert gitlib.open_repo(str(git_repo)) is None
+
+    monkeypatch.setattr(get_config(), "vcs_git_reads", "pygit2")
+    with use_runner(ScriptedRunner()):
+        assert gitlib.open_repo(str(git_repo)) is None
@@ -0,0 +1,28 @@
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.health import check_health
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.mark.asyncio
+async def test_health_reports_repo_binaries_and_directive_checksums(
+    git_repo, monkeypatch
+):
+    repo = GitRepo(git_repo)
+    repo.git("symbolic-ref", "HEAD", "refs/heads/main")
+    repo.commit("chore: init", {"a.txt": "a"})
+    (get_config().directives_dir / "house.md").write_text("# House style\n")
+    monkeypatch.setattr("azathoth.core.health.shutil.which", lambda name: None)
+    started = datetime.now(timezone.utc) - timedelta(minutes=2)
+
+    report = await check_health("workflow", started, cwd=str(git_repo))
+
+    assert (report.repo_root, report.branch) == (str(git_repo), "main")
+    assert report.uptime_seconds >= 120
+    assert [(b.name, b.path) for b in report.binaries] == [("git", None), ("gh", None)]
+    assert report.problems[0].startswith("git is not on PATH")
+    house = next(d for d in report.directives if d.name == "house")
+    assert (house.source, len(house.sha256)) == ("user", 64)
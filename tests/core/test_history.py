@@ -0,0 +1,59 @@
+import pytest
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.history import blame_range, file_history
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def story(git_repo):
+    repo = GitRepo(git_repo)
+    repo.commit("feat: add app", {"app.py": "a = 1\nb = 2\nc = 3\n"})
+    repo.write("app.py", "a = 1\nb = 20\nc = 3\n")
+    repo.git("commit", "-qam", "fix: correct b", "--author=Ada <ada@example.com>")
+    repo.git("mv", "app.py", "main.py")
+    repo.git("commit", "-qm", "refactor: rename app")
+    return repo
+
+
+@pytest.mark.asyncio
+async def test_blame_range_attributes_lines(story):
+    report = await blame_range("main.py", 1, 2, cwd=str(story.path))
+
+    assert [(line.line, line.content) for line in report.lines] == [
+        (1, "a = 1"),
+        (2, "b = 20"),
+    ]
+    assert [(c.subject, c.author, c.lines) for c in report.commits] == [
+        ("feat: add app", "Your Name", 1),
+        ("fix: correct b", "Ada", 1),
+    ]
+    assert report.lines[1].sha == report.commits[1].sha
+    assert report.commits[0].date.startswith("20")
+
+    with pytest.raises(WorkflowError, match="Invalid line range"):
+        await blame_range("main.py", 3, 1, cwd=str(story.path))
+    with pytest.raises(WorkflowError, match="git blame failed"):
+        await blame_range("gone.py", 1, 2, cwd=str(story.path))
+    with pytest.raises(WorkflowError, match="Invalid revision"):
+        await blame_range(
+            "main.py", 1, 2, rev="--contents=/etc/hostname", cwd=str(story.path)
+        )
+
+
+@pytest.mark.asyncio
+async def test_file_history_follows_renames(story):
+    history = await file_history("main.py", cwd=str(story.path))
+
+    assert [(c.subject, c.path) for c in history.commits] == [
+        ("refactor: rename app", "main.py"),
+        ("fix: correct b", "app.py"),
+        ("feat: add app", "app.py"),
+    ]
+    assert (history.commits[1].insertions, history.commits[1].deletions) == (1, 1)
+    assert history.commits[0].diff is None
+
+    with_diffs = await file_history(
+        "main.py", limit=2, include_diffs=True, cwd=str(story.path)
+    )
+    assert "+b = 20" in with_diffs.commits[1].diff
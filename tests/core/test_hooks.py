@@ -0,0 +1,95 @@
+import pytest
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.hooks import (
+    HookSetup,
+    detect_hooks,
+    hook_command,
+    parse_pre_commit,
+    run_hooks,
+)
+from azathoth.core.workflow import commit
+from azathoth.dev.testing import GitRepo
+
+PRE_COMMIT_OUTPUT = """\
+[INFO] Initializing environment for https://github.com/astral-sh/ruff-pre-commit.
+ruff.....................................................................Failed
+- hook id: ruff
+- exit code: 1
+
+app.py:1:8: F401 [*] `os` imported but unused
+
+trim trailing whitespace.................................................Passed
+mypy.................................................(no files to check)Skipped
+"""
+
+
+def _hook(directory, script):
+    directory.mkdir(parents=True, exist_ok=True)
+    hook = directory / "pre-commit"
+    hook.write_text(script)
+    hook.chmod(0o755)
+    return hook
+
+
+def test_parse_pre_commit():
+    hooks = parse_pre_commit(PRE_COMMIT_OUTPUT)
+
+    assert [(h.name, h.status) for h in hooks] == [
+        ("ruff", "failed"),
+        ("trim trailing whitespace", "passed"),
+        ("mypy", "skipped"),
+    ]
+    assert hooks[0].output.endswith("F401 [*] `os` imported but unused")
+    assert "- hook id: ruff" in hooks[0].output
+
+
+def test_hook_command_refuses_option_like_paths():
+    setup = HookSetup(
+        kind="pre-commit",
+        path=".pre-commit-config.yaml",
+        command=["pre-commit", "run", "--color", "never"],
+    )
+
+    assert hook_command(setup, ["a.py"])[-2:] == ["--files", "a.py"]
+    with pytest.raises(WorkflowError, match="must not start with '-'"):
+        hook_command(setup, ["a.py", "--hook-stage=manual"])
+
+
+@pytest.mark.asyncio
+async def test_detect_hooks(git_repo):
+    cwd = str(git_repo)
+    assert await detect_hooks(cwd) is None
+
+    (git_repo / ".git" / "hooks" / "pre-commit").write_text("#!/bin/sh\n")
+    assert await detect_hooks(cwd) is None  # not executable: git ignores it
+
+    _hook(git_repo / ".git" / "hooks", "#!/bin/sh\n")
+    assert (await detect_hooks(cwd)).kind == "script"
+
+    GitRepo(git_repo).git("config", "core.hooksPath", ".githooks")
+    assert await detect_hooks(cwd) is None
+    custom = _hook(git_repo / ".githooks", "#!/bin/sh\n")
+    assert (await detect_hooks(cwd)).path == str(custom)
+
+    (git_repo / ".pre-commit-config.yaml").write_text("repos: []\n")
+    setup = await detect_hooks(cwd)
+    assert setup.kind == "pre-commit" and setup.command[:2] == ["pre-commit", "run"]
+
+
+@pytest.mark.asyncio
+async def test_failing_hook_is_reported_and_can_be_bypassed(git_repo):
+    cwd = str(git_repo)
+    script = "#!/bin/sh\necho 'lint: trailing space' >&2\nexit 1\n"
+    _hook(git_repo / ".git" / "hooks", script)
+    repo = GitRepo(git_repo)
+    repo.write("a.txt", "a \n")
+    repo.git("add", "a.txt")
+
+    run = await run_hooks(await detect_hooks(cwd), cwd=cwd)
+    assert not run.success
+    assert run.failed[0].output == "lint: trailing space"
+    assert "lint: trailing space" in run.render_failure()
+
+    assert not (await commit("feat: a", "", cwd=cwd)).success
+    assert (await commit("feat: a", "", cwd=cwd, no_verify=True)).success
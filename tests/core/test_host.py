@@ -0,0 +1,33 @@
+import pytest
+
+from azathoth.core import host
+
+
+@pytest.fixture
+def windows(monkeypatch):
+    monkeypatch.setattr(host, "IS_WINDOWS", True)
+
+
+def test_posix_quoting_and_splitting():
+    argv = ["git", "commit", "-m", "it's done"]
+
+    assert host.split(host.join(argv)) == argv
+    assert host.resolve_argv(["npm", "run", "lint"]) == ["npm", "run", "lint"]
+    assert host.session_kwargs() == {"start_new_session": True}
+
+
+def test_windows_keeps_backslashes_and_resolves_shims(windows, monkeypatch):
+    monkeypatch.setattr(
+        host.shutil, "which", lambda name: rf"C:\Program Files\nodejs\{name}.CMD"
+    )
+
+    assert host.join(["type", r"C:\Program Files\a.txt"]) == (
+        r'type "C:\Program Files\a.txt"'
+    )
+    assert host.split(r'build.bat --out "C:\dist dir" .\x') == [
+        "build.bat", "--out", r"C:\dist dir", r".\x"
+    ]
+    assert host.resolve_argv(["npm", "run", "lint"]) == [
+        r"C:\Program Files\nodejs\npm.CMD", "run", "lint"
+    ]
+    assert host.resolve_argv(["tool.exe"]) == ["tool.exe"]
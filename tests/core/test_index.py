@@ -0,0 +1,56 @@
+import json
+import os
+
+from pydantic import BaseModel
+
+from azathoth.config import get_config
+from azathoth.core.index import INDEX_DIR, RepoIndex
+
+
+class Length(BaseModel, frozen=True):
+    chars: int
+
+
+def _length(path):
+    return Length(chars=len(path.read_text()))
+
+
+def test_facts_survive_restarts_until_the_content_changes(tmp_path):
+    (tmp_path / ".git").mkdir()
+    source = tmp_path / "src" / "a.py"
+    source.parent.mkdir()
+    source.write_text("abc")
+
+    first = RepoIndex.open(tmp_path / "src")
+    assert first.cached("length", source, Length, _length) == Length(chars=3)
+    first.save()
+    assert (tmp_path / INDEX_DIR / ".gitignore").read_text() == "*\n"
+
+    again = RepoIndex.open(tmp_path)
+    assert again.cached("length", source, Length, lambda p: Length(chars=-1)).chars == 3
+    assert (again.hits, again.misses) == (1, 0)
+
+    stat = source.stat()
+    os.utime(source, ns=(stat.st_atime_ns, stat.st_mtime_ns + 10**9))
+    assert again.cached("length", source, Length, _length).chars == 3  # same hash
+    source.write_text("abcdef")
+    assert again.cached("length", source, Length, _length).chars == 6
+    assert (again.hits, again.misses) == (2, 1)
+
+    source.unlink()
+    again.save()
+    manifest = json.loads((tmp_path / INDEX_DIR / "index.json").read_text())
+    assert manifest["files"] == {}
+
+
+def test_disabled_index_writes_nothing(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "scout_index", False)
+    (tmp_path / "a.py").write_text("x")
+
+    index = RepoIndex.open(tmp_path)
+    index.cached("length", tmp_path / "a.py", Length, _length)
+    assert index.cached("length", tmp_path / "a.py", Length, _length).chars == 1
+    index.save()
+
+    assert index.hits == 1
+    assert not (tmp_path / ".azathoth").exists()
@@ -0,0 +1,75 @@
+import pytest
+
+from azathoth.core import prompts
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.issues import LabelInfo, close_issue, label_issue, list_issues
+
+_API = "https://api.github.com/repos/team/app"
+_LABELS = [
+    {"name": "bug", "description": "Something is broken"},
+    {"name": "good first issue"},
+]
+
+
+@pytest.mark.asyncio
+async def test_list_issues_skips_pull_requests(git_repo, api):
+    api.replies.append(
+        [
+            {"number": 9, "title": "Crash on start", "labels": [], "body": None},
+            {"number": 8, "title": "feat: x", "labels": [], "pull_request": {}},
+            {"number": 7, "title": "Docs", "labels": [{"name": "docs"}]},
+        ]
+    )
+
+    issues = await list_issues(unlabeled=True, cwd=str(git_repo))
+
+    assert [(i.number, i.body) for i in issues] == [(9, "")]
+
+
+@pytest.mark.asyncio
+async def test_label_issue_only_applies_existing_labels(git_repo, api):
+    cwd = str(git_repo)
+    api.replies += [_LABELS, _LABELS, _LABELS, [{"name": "bug"}], []]
+
+    with pytest.raises(WorkflowError, match="Unknown label.*crash"):
+        await label_issue(9, add=["crash"], cwd=cwd)
+    plan = await label_issue(
+        9, add=["bug"], remove=["good first issue"], dry_run=True, cwd=cwd
+    )
+    assert plan.commands == [
+        f"POST {_API}/issues/9/labels",
+        f"DELETE {_API}/issues/9/labels/good%20first%20issue",
+    ]
+
+    done = await label_issue(9, add=["Bug"], remove=["good first issue"], cwd=cwd)
+
+    assert done.done and done.labels == []
+    assert [(m, u.removeprefix(_API)) for m, u, _, _ in api[3:]] == [
+        ("POST", "/issues/9/labels"),
+        ("DELETE", "/issues/9/labels/good%20first%20issue"),
+    ]
+    assert api[3][3]["json"] == {"labels": ["Bug"]}
+
+
+@pytest.mark.asyncio
+async def test_close_issue_comments_first(git_repo, api):
+    api.replies += [{"html_url": "u/c"}, {"html_url": "u/9", "labels": []}]
+
+    closed = await close_issue(9, "not_planned", "Duplicate of #3.", cwd=str(git_repo))
+
+    assert closed.done and closed.url == "u/9"
+    (post, _, _, comment), (patch, _, _, update) = api
+    assert (post, comment["json"]) == ("POST", {"body": "Duplicate of #3."})
+    assert (patch, update["json"]) == (
+        "PATCH",
+        {"state": "closed", "state_reason": "not_planned"},
+    )
+
+
+def test_triage_prompt_lists_the_repo_labels():
+    rendered = prompts.autotriage(
+        [LabelInfo(name="bug", description="Something is broken")], limit=5
+    )
+
+    assert "`limit` set to 5" in rendered
+    assert "- `bug` — Something is broken" in rendered
@@ -0,0 +1,79 @@
+import json
+
+from azathoth.core.licenses import (
+    category_of,
+    identify_license,
+    license_report,
+    normalize_license,
+)
+
+MIT_TEXT = (
+    "MIT License\n\nPermission is hereby granted, free of charge, to any person "
+    "obtaining a copy\nof this software..."
+)
+GPL2_TEXT = (
+    "GNU GENERAL PUBLIC LICENSE\nVersion 2, June 1991\n"
+    "Copyright (C) 1989, 1991 Free Software Foundation"
+)
+
+
+def test_identify_and_normalize():
+    assert identify_license(MIT_TEXT) == "MIT"
+    assert identify_license(GPL2_TEXT) == "GPL-2.0"
+    assert identify_license("All rights reserved.") is None
+    assert normalize_license("MIT/Apache-2.0") == "MIT OR Apache-2.0"
+    assert normalize_license("Apache Software License") == "Apache-2.0"
+    assert normalize_license("gpl-3.0-or-later") == "GPL-3.0-or-later"
+    assert normalize_license("UNKNOWN") is None
+    assert category_of("GPL-3.0-only OR MIT") == "permissive"
+    assert category_of("MIT AND LGPL-2.1") == "weak_copyleft"
+
+
+def _node_package(root, name, license_, dev=False):
+    package = root / "node_modules" / name
+    package.mkdir(parents=True)
+    (package / "package.json").write_text(json.dumps({"license": license_}))
+    return {"version": "1.0.0", **({"dev": True} if dev else {})}
+
+
+def test_report_flags_copyleft_runtime_dependencies(tmp_path):
+    (tmp_path / "LICENSE").write_text(MIT_TEXT)
+    (tmp_path / "package.json").write_text(
+        json.dumps(
+            {
+                "license": "MIT",
+                "dependencies": {"left-pad": "1", "readline-gpl": "1", "lgpl-lib": "1"},
+                "devDependencies": {"gpl-tool": "1"},
+            }
+        )
+    )
+    packages = {
+        "node_modules/left-pad": _node_package(tmp_path, "left-pad", "ISC"),
+        "node_modules/readline-gpl": _node_package(tmp_path, "readline-gpl", "GPL-3.0"),
+        "node_modules/lgpl-lib": _node_package(tmp_path, "lgpl-lib", "LGPL-2.1"),
+        "node_modules/gpl-tool": _node_package(tmp_path, "gpl-tool", "GPL-3.0", True),
+    }
+    (tmp_path / "package-lock.json").write_text(json.dumps({"packages": packages}))
+
+    report = license_report(tmp_path)
+    issues = {(i.kind, i.subject.split()[0]) for i in report.issues}
+
+    assert (report.project_license, report.category) == ("MIT", "permissive")
+    assert report.license_files == {"LICENSE": "MIT"}
+    assert issues == {("incompatible", "readline-gpl"), ("review", "lgpl-lib")}
+    licenses = {d.name: d.license for d in report.dependencies}
+    assert licenses["left-pad"] == "ISC"
+    assert "readline-gpl" in report.render_markdown()
+
+
+def test_report_missing_file_and_mismatch(tmp_path):
+    (tmp_path / "Cargo.toml").write_text('[package]\nname = "x"\nlicense = "MIT"\n')
+
+    report = license_report(tmp_path)
+    assert [(i.kind, i.severity) for i in report.issues] == [
+        ("missing_license_file", "error")
+    ]
+
+    (tmp_path / "COPYING").write_text(GPL2_TEXT)
+    report = license_report(tmp_path)
+    assert [(i.kind, i.subject) for i in report.issues] == [("mismatch", "Cargo.toml")]
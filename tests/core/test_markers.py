@@ -0,0 +1,40 @@
+import pytest
+
+from azathoth.core.markers import scan_markers
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.mark.asyncio
+async def test_markers_grouped_by_file_and_author(git_repo):
+    repo = GitRepo(git_repo)
+    repo.write("app.py", "# TODO: split this module\nTODO_LIST = []  # not a marker\n")
+    repo.write(".gitignore", "build/\n")
+    repo.git("add", "-A")
+    repo.git("commit", "-qm", "init", "--author=Ada <ada@example.com>")
+    (git_repo / "lib.rs").write_text("// FIXME(perf): avoid the clone\n")
+    (git_repo / "build").mkdir()
+    (git_repo / "build" / "gen.py").write_text("# TODO: generated\n")
+
+    report = await scan_markers(git_repo)
+
+    assert [(m.path, m.line, m.tag, m.text) for m in report.markers] == [
+        ("app.py", 1, "TODO", "split this module"),
+        ("lib.rs", 1, "FIXME", "avoid the clone"),
+    ]
+    assert report.markers[0].author == "Ada"
+    # Untracked files are scanned but have no blame.
+    assert report.markers[1].author is None
+    assert report.tags == {"TODO": 1, "FIXME": 1}
+    assert [g.name for g in report.by_author] == ["Ada"]
+    assert [(g.name, g.count) for g in report.by_file] == [("app.py", 1), ("lib.rs", 1)]
+    assert "`lib.rs:1` FIXME: avoid the clone" in report.markdown
+
+
+@pytest.mark.asyncio
+async def test_markers_outside_git(tmp_path):
+    (tmp_path / "notes.sql").write_text("-- HACK: temporary index\nSELECT 1;\n")
+
+    report = await scan_markers(tmp_path)
+
+    assert [(m.tag, m.author) for m in report.markers] == [("HACK", None)]
+    assert report.by_author == []
@@ -0,0 +1,76 @@
+import pytest
+
+from azathoth.core import prompts
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.milestones import (
+    assign_milestone,
+    close_milestone,
+    create_milestone,
+    milestone_status,
+)
+
+_API = "https://api.github.com/repos/team/app"
+_MILESTONES = [
+    {"number": 3, "title": "v1.4.0", "open_issues": 1, "closed_issues": 3},
+    {"number": 2, "title": "v1.3.0", "state": "closed", "closed_issues": 5},
+]
+
+
+@pytest.mark.asyncio
+async def test_milestone_status_lists_what_blocks_the_release(git_repo, api):
+    cwd = str(git_repo)
+    api.replies += [
+        _MILESTONES,
+        [{"number": 41, "title": "Fix login", "pull_request": {}}],
+        _MILESTONES,
+        [],
+    ]
+
+    blocked = await milestone_status("v1.4.0", cwd=cwd)
+    done = await milestone_status("v1.3.0", cwd=cwd)
+
+    assert (blocked.complete, blocked.progress) == (False, 0.75)
+    assert [(i.number, i.kind) for i in blocked.open_items] == [(41, "pull_request")]
+    assert api[1][1] == f"{_API}/issues?milestone=3&state=open&per_page=100"
+    assert done.complete and done.progress == 1.0
+    api.replies.append(_MILESTONES)
+    with pytest.raises(WorkflowError, match="No milestone titled 'v9'"):
+        await milestone_status("v9", cwd=cwd)
+
+
+@pytest.mark.asyncio
+async def test_create_assign_and_close(git_repo, api):
+    cwd = str(git_repo)
+    api.replies += [
+        {"number": 4, "title": "v1.5.0"},
+        {"number": 4, "title": "v1.5.0"},
+        {},
+        _MILESTONES,
+        _MILESTONES,
+        {"number": 3, "title": "v1.4.0", "state": "closed"},
+    ]
+
+    created = await create_milestone("v1.5.0", due_on="2026-12-01", cwd=cwd)
+    assigned = await assign_milestone(12, 4, cwd=cwd)
+    plan = await close_milestone("v1.4.0", dry_run=True, cwd=cwd)
+    closed = await close_milestone("v1.4.0", cwd=cwd)
+
+    assert created.milestone.number == 4
+    assert api[0][3]["json"]["due_on"] == "2026-12-01T00:00:00Z"
+    assert assigned.item == 12
+    assert (api[2][0], api[2][1], api[2][3]["json"]) == (
+        "PATCH",
+        f"{_API}/issues/12",
+        {"milestone": 4},
+    )
+    assert plan.commands == [f"PATCH {_API}/milestones/3"]
+    assert closed.milestone.state == "closed"
+
+
+def test_release_prompt_gates_on_the_milestone():
+    args = ("v1.4.0", "https://x/app", "v1.3.0")
+
+    gated = prompts.autorelease(*args, milestone="v1.4.0")
+
+    assert "`milestone_status` tool with `milestone` set to `v1.4.0`" in gated
+    assert "milestone_status" not in prompts.autorelease(*args)
@@ -0,0 +1,58 @@
+import json
+
+from azathoth.core.module_map import module_map
+
+
+def test_cargo_workspace_targets_and_modules(tmp_path):
+    (tmp_path / "Cargo.toml").write_text('[workspace]\nmembers = ["crates/*"]\n')
+    core = tmp_path / "crates" / "core"
+    (core / "src").mkdir(parents=True)
+    (core / "Cargo.toml").write_text('[package]\nname = "core"\n')
+    (core / "src" / "lib.rs").write_text("//! Core types.\npub fn parse() {}\n")
+    cli = tmp_path / "crates" / "cli"
+    (cli / "src" / "bin").mkdir(parents=True)
+    (cli / "Cargo.toml").write_text('[package]\nname = "cli"\n')
+    (cli / "src" / "main.rs").write_text("fn main() {}\n")
+    (cli / "src" / "bin" / "migrate.rs").write_text("fn main() {}\n")
+
+    result = module_map(tmp_path)
+    targets = {(t.name, t.kind): t.entry for t in result.targets}
+
+    assert targets[("core", "library")] == "crates/core/src/lib.rs"
+    assert targets[("cli", "binary")] == "crates/cli/src/main.rs"
+    assert targets[("migrate", "binary")] == "crates/cli/src/bin/migrate.rs"
+    assert "crates/cli/src/main.rs" in result.entry_points
+    modules = {m.path: m for m in result.modules}
+    assert modules["crates/core/src"].public
+    assert modules["crates/cli/src"].files == 2
+    assert "## Targets" in result.render_markdown()
+
+
+def test_python_and_js_targets(tmp_path):
+    (tmp_path / "pyproject.toml").write_text(
+        '[project]\nname = "my-tool"\n[project.scripts]\ntool = "my_tool.cli:main"\n'
+    )
+    package = tmp_path / "src" / "my_tool"
+    package.mkdir(parents=True)
+    (package / "__init__.py").write_text('"""My tool."""\n')
+    (package / "cli.py").write_text("def main():\n    pass\n")
+    (package / "_internal.py").write_text("def helper():\n    pass\n")
+    tests = tmp_path / "tests"
+    tests.mkdir()
+    (tests / "test_cli.py").write_text("def test_main():\n    pass\n")
+    (tmp_path / "package.json").write_text(
+        json.dumps({"name": "web", "bin": "bin/web.js", "main": "dist/index.js"})
+    )
+
+    result = module_map(tmp_path, depth=2)
+    targets = {(t.name, t.kind): t.entry for t in result.targets}
+
+    assert targets[("tool", "binary")] == "src/my_tool/cli.py"
+    assert targets[("my_tool", "library")] == "src/my_tool/__init__.py"
+    assert targets[("web", "binary")] == "bin/web.js"
+    assert targets[("web", "library")] == "dist/index.js"
+    assert result.entry_points == ["src/my_tool/cli.py"]
+    modules = {m.path: m for m in result.modules}
+    assert modules["src/my_tool"].doc == "My tool."
+    assert modules["src/my_tool"].public
+    assert not modules["tests"].public
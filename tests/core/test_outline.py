@@ -0,0 +1,127 @@
+import pytest
+
+from azathoth.core import outline as outline_module
+from azathoth.core.exceptions import SandboxError
+from azathoth.core.files import Sandbox
+from azathoth.core.outline import outline, outline_file
+
+PYTHON = '''\
+"""Module."""
+
+MAX_SIZE = 10
+
+
+class Store:
+    """Keeps things."""
+
+    def get(self, key: str) -> bytes:
+        return b""
+
+    def _evict(self):
+        pass
+
+
+async def load(
+    path: str,
+) -> Store:
+    return Store()
+'''
+
+RUST = """\
+pub struct Cache {
+    size: usize,
+}
+
+impl Cache {
+    pub fn new(size: usize) -> Self {
+        Self { size }
+    }
+
+    fn evict(&mut self) {}
+}
+
+pub(crate) const LIMIT: u32 = 4;
+fn helper() {}
+"""
+
+GO = """\
+package store
+
+type Store struct {
+\titems map[string]string
+}
+
+func (s *Store) Get(key string) string { return s.items[key] }
+
+func newStore() *Store { return &Store{} }
+"""
+
+TS = """\
+export interface Options { size: number }
+export async function load(path: string): Promise<void> {}
+class Internal {}
+"""
+
+
+@pytest.fixture
+def project(tmp_path, monkeypatch):
+    monkeypatch.setattr(outline_module, "_ts_parser", None)
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "store.py").write_text(PYTHON)
+    (tmp_path / "src" / "cache.rs").write_text(RUST)
+    (tmp_path / "src" / "store.go").write_text(GO)
+    (tmp_path / "src" / "load.ts").write_text(TS)
+    (tmp_path / "README.md").write_text("# x\n")
+    return Sandbox(tmp_path)
+
+
+def _shape(items):
+    return [(i.kind, i.name, i.public, _shape(i.children)) for i in items]
+
+
+def test_python_outline_has_signatures_spans_and_methods(project):
+    result = outline_file(project, "src/store.py")
+
+    assert result.parser == "ast"
+    assert _shape(result.items) == [
+        ("constant", "MAX_SIZE", True, []),
+        (
+            "class",
+            "Store",
+            True,
+            [("method", "get", True, []), ("method", "_evict", False, [])],
+        ),
+        ("function", "load", True, []),
+    ]
+    load = result.items[2]
+    assert load.signature == "async def load( path: str, ) -> Store"
+    assert (load.line, load.end_line) == (16, 19)
+
+
+def test_directory_outline_covers_each_language(project):
+    result = outline(project, "src", public_only=True)
+
+    by_path = {f.path: f for f in result.files}
+    assert sorted(by_path) == [
+        "src/cache.rs",
+        "src/load.ts",
+        "src/store.go",
+        "src/store.py",
+    ]
+    assert _shape(by_path["src/cache.rs"].items) == [
+        ("struct", "Cache", True, []),
+        ("impl", "Cache", False, [("method", "new", True, [])]),
+        ("constant", "LIMIT", True, []),
+    ]
+    assert by_path["src/cache.rs"].items[1].children[0].signature == (
+        "pub fn new(size: usize) -> Self"
+    )
+    assert _shape(by_path["src/store.go"].items) == [
+        ("struct", "Store", True, []),
+        ("method", "Get", True, []),
+    ]
+    assert [i.name for i in by_path["src/load.ts"].items] == ["Options", "load"]
+    assert "- L6 `pub fn new(size: usize) -> Self`" in result.render_markdown()
+
+    with pytest.raises(SandboxError, match="not a supported source file"):
+        outline(project, "README.md")
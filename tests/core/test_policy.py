@@ -0,0 +1,89 @@
+import pytest
+from pydantic import SecretStr
+
+from azathoth.config import get_config
+from azathoth.core import policy
+from azathoth.core.exceptions import PolicyDenied
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture(autouse=True)
+def _unpaused(monkeypatch):
+    monkeypatch.setattr(get_config(), "mutations_paused", False)
+    monkeypatch.setattr(get_config(), "approval_required", False)
+    monkeypatch.setattr(policy, "_paused_by_agent", False)
+
+
+def test_allowed_by_default(tmp_path):
+    assert not policy.pause_state(str(tmp_path)).paused
+    policy.ensure_mutations_allowed("commit", cwd=str(tmp_path))
+
+
+def test_sentinel_file_denies_with_reason(tmp_path):
+    sentinel = policy.pause_file(str(tmp_path))
+    sentinel.parent.mkdir()
+    sentinel.write_text("agent is looping\n")
+
+    with pytest.raises(PolicyDenied, match="agent is looping") as exc:
+        policy.ensure_mutations_allowed("commit", cwd=str(tmp_path))
+    assert "pause_mutations(paused=False)" not in str(exc.value)
+
+    # Resuming via the tool cannot override the user's sentinel.
+    policy.set_paused(False)
+    assert policy.pause_state(str(tmp_path)).by_file
+
+
+def test_agent_pause_toggles(tmp_path):
+    policy.set_paused(True)
+    with pytest.raises(PolicyDenied, match="pause_mutations was called") as exc:
+        policy.ensure_mutations_allowed("create_release", cwd=str(tmp_path))
+    assert "paused=False" not in str(exc.value)
+    policy.set_paused(False)
+    policy.ensure_mutations_allowed("create_release", cwd=str(tmp_path))
+
+
+def test_agent_cannot_lift_the_users_config_pause(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "mutations_paused", True)
+
+    policy.set_paused(False)
+
+    with pytest.raises(PolicyDenied, match="mutations_paused is set"):
+        policy.ensure_mutations_allowed("commit", cwd=str(tmp_path))
+    assert get_config().mutations_paused
+
+
+def test_protected_branch_patterns(monkeypatch):
+    monkeypatch.setattr(
+        get_config(), "workflow_protected_branches", ["main", "release/*"]
+    )
+
+    assert policy.is_protected_branch("release/1.0")
+    assert not policy.is_protected_branch("feature/release")
+    assert policy.protected_branch_names() == ["main"]
+
+
+@pytest.mark.asyncio
+async def test_ensure_branch_writable(git_repo):
+    cwd, repo = str(git_repo), GitRepo(git_repo)
+    repo.git("symbolic-ref", "HEAD", "refs/heads/main")
+
+    with pytest.raises(PolicyDenied, match="allow_protected"):
+        await policy.ensure_branch_writable("stage_and_commit", cwd=cwd)
+    await policy.ensure_branch_writable("stage_and_commit", True, cwd=cwd)
+
+    repo.git("symbolic-ref", "HEAD", "refs/heads/fix/x")
+    await policy.ensure_branch_writable("stage_and_commit", cwd=cwd)
+
+
+def test_approval_mode(monkeypatch):
+    policy.ensure_approved("publish_crate", None)
+
+    monkeypatch.setattr(get_config(), "approval_required", True)
+    monkeypatch.setattr(get_config(), "approval_token", None)
+    with pytest.raises(PolicyDenied, match="no approval_token is configured"):
+        policy.ensure_approved("publish_crate", "anything")
+
+    monkeypatch.setattr(get_config(), "approval_token", SecretStr("s3cret"))
+    with pytest.raises(PolicyDenied, match="Approval required"):
+        policy.ensure_approved("publish_crate", None)
+    policy.ensure_approved("publish_crate", "s3cret")
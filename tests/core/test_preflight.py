@@ -0,0 +1,66 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.preflight import is_artifact, preflight
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def repo(git_repo):
+    repo = GitRepo(git_repo)
+    repo.commit("init", {"app.py": "print('hi')\n"})
+    return repo
+
+
+def test_is_artifact():
+    assert is_artifact("target/debug/app")
+    assert is_artifact("pkg/__pycache__/mod.cpython-312.pyc")
+    assert is_artifact("server.log")
+    assert not is_artifact("src/app.py")
+
+
+@pytest.mark.asyncio
+async def test_clean_change_passes(repo):
+    (repo.path / "app.py").write_text("print('hello')\n")
+
+    report = await preflight(str(repo.path))
+
+    assert report.ok
+    assert (report.files_checked, report.issues) == (1, [])
+
+
+@pytest.mark.asyncio
+async def test_reports_each_problem_with_a_fix(repo, monkeypatch):
+    monkeypatch.setattr(get_config(), "workflow_max_file_size", 100)
+    (repo.path / "app.py").write_text("<<<<<<< HEAD\na\n=======\nb\n>>>>>>> feat\n")
+    (repo.path / "notes.md").write_text("no newline")
+    (repo.path / "data.bin").write_bytes(b"\0" * 200)
+    (repo.path / "target" / "debug").mkdir(parents=True)
+    (repo.path / "target" / "debug" / "app").write_bytes(b"\0" * 500)
+
+    report = await preflight(str(repo.path))
+
+    assert not report.ok
+    assert [(i.check, i.severity, i.path) for i in report.issues] == [
+        ("build-artifact", "warning", "target/"),
+        ("merge-marker", "error", "app.py"),
+        ("large-file", "error", "data.bin"),
+        ("missing-newline", "warning", "notes.md"),
+    ]
+    assert all(issue.fix for issue in report.issues)
+
+
+@pytest.mark.asyncio
+async def test_warns_when_behind_upstream(repo):
+    repo.git("remote", "add", "origin", str(repo.path))
+    repo.git("update-ref", "refs/remotes/origin/main", "HEAD")
+    repo.git("branch", "--set-upstream-to=origin/main")
+    repo.commit("upstream change", {"app.py": "print('upstream')\n"})
+    repo.git("update-ref", "refs/remotes/origin/main", "HEAD")
+    repo.git("reset", "-q", "--hard", "HEAD~1")
+
+    report = await preflight(str(repo.path))
+
+    assert report.ok
+    assert [i.check for i in report.issues] == ["behind-upstream"]
+    assert "1 commit(s) behind origin/main" in report.issues[0].message
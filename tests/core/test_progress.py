@@ -0,0 +1,77 @@
+import sys
+
+import pytest
+
+from azathoth.core.progress import MAX_LINE_CHARS, stream_output
+from azathoth.core.tasks import Task, run_task
+from azathoth.core.workflow import run_command
+
+_SCRIPT = (
+    "import sys\n"
+    "print('one', flush=True)\n"
+    "sys.stderr.write('50%\\r100%\\n'); sys.stderr.flush()\n"
+    "print('x' * 2000, flush=True)\n"
+    "print('two')\n"
+)
+
+
+@pytest.mark.asyncio
+async def test_run_command_streams_lines_while_returning_output():
+    seen: list[str] = []
+
+    async def sink(line: str) -> None:
+        seen.append(line)
+
+    with stream_output(sink):
+        code, out, err = await run_command([sys.executable, "-c", _SCRIPT])
+
+    assert code == 0
+    assert out.startswith("one\n") and out.endswith("two")
+    assert err == "50%\r100%"
+    assert {"one", "two", "50%", "100%"} <= set(seen)
+    assert max(len(line) for line in seen) == MAX_LINE_CHARS
+
+
+@pytest.mark.asyncio
+async def test_no_sink_outside_block():
+    seen: list[str] = []
+
+    async def sink(line: str) -> None:
+        seen.append(line)
+
+    with stream_output(sink):
+        pass
+    code, out, _ = await run_command([sys.executable, "-c", "print('quiet')"])
+
+    assert (code, out, seen) == (0, "quiet", [])
+
+
+@pytest.mark.asyncio
+async def test_failing_sink_does_not_break_command():
+    async def sink(line: str) -> None:
+        raise RuntimeError("client went away")
+
+    with stream_output(sink):
+        code, out, _ = await run_command([sys.executable, "-c", "print('ok')"])
+
+    assert (code, out) == (0, "ok")
+
+
+@pytest.mark.asyncio
+async def test_run_task_streams(tmp_path):
+    seen: list[str] = []
+
+    async def sink(line: str) -> None:
+        seen.append(line)
+
+    task = Task(
+        name="t",
+        runner="make",
+        argv=[sys.executable, "-c", "print('built')"],
+        source="Makefile",
+    )
+    with stream_output(sink):
+        result = await run_task(task, timeout=30, cwd=str(tmp_path))
+
+    assert result.success
+    assert seen == ["built"]
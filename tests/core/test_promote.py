@@ -0,0 +1,72 @@
+import hashlib
+
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.promote import (
+    CheckRun,
+    final_tag,
+    promote_release_candidate,
+    verify_checksums,
+)
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.mark.parametrize(
+    "rc, expected",
+    [("v1.2.0-rc.2", "v1.2.0"), ("1.0.0-beta1", "1.0.0"), ("v3.1.4-RC", "v3.1.4")],
+)
+def test_final_tag(rc, expected):
+    assert final_tag(rc) == expected
+
+
+def test_final_tag_rejects_releases():
+    with pytest.raises(WorkflowError):
+        final_tag("v1.2.0")
+
+
+def test_check_run_passed():
+    assert CheckRun(name="ci", status="completed", conclusion="skipped").passed
+    assert not CheckRun(name="ci", status="in_progress").passed
+    assert not CheckRun(name="ci", status="completed", conclusion="failure").passed
+
+
+def test_verify_checksums(tmp_path):
+    (tmp_path / "app.tar.gz").write_bytes(b"good")
+    (tmp_path / "app.zip").write_bytes(b"tampered")
+    (tmp_path / "notes.pdf").write_bytes(b"x")
+    (tmp_path / "tool.bin").write_bytes(b"bin")
+    good = hashlib.sha256(b"good").hexdigest()
+    (tmp_path / "SHA256SUMS").write_text(
+        f"{good}  app.tar.gz\n{hashlib.sha256(b'original').hexdigest()} *app.zip\n"
+    )
+    (tmp_path / "tool.bin.sha256").write_text(hashlib.sha256(b"bin").hexdigest())
+
+    verified, problems = verify_checksums(tmp_path)
+
+    assert verified == ["app.tar.gz", "tool.bin"]
+    assert problems == [
+        "app.zip: checksum mismatch",
+        "notes.pdf: no published checksum",
+    ]
+
+
+@pytest.mark.asyncio
+async def test_promotion_refuses_non_github_and_missing_tag(git_repo, monkeypatch):
+    cwd = str(git_repo)
+    repo = GitRepo(git_repo)
+    repo.git("commit", "--allow-empty", "-qm", "init")
+
+    monkeypatch.setattr(get_config(), "release_backend", "gitlab")
+    with pytest.raises(WorkflowError, match="needs GitHub"):
+        await promote_release_candidate("v1.0.0-rc.1", dry_run=True, cwd=cwd)
+
+    monkeypatch.setattr(get_config(), "release_backend", "github")
+    with pytest.raises(WorkflowError, match="does not exist"):
+        await promote_release_candidate("v1.0.0-rc.1", dry_run=True, cwd=cwd)
+
+    repo.tag("v1.0.0-rc.1")
+    repo.tag("v1.0.0")
+    with pytest.raises(WorkflowError, match="already exists"):
+        await promote_release_candidate("v1.0.0-rc.1", dry_run=True, cwd=cwd)
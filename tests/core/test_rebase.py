@@ -0,0 +1,113 @@
+import pytest
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.rebase import (
+    RebaseCommit,
+    RebaseStep,
+    execute_rebase,
+    find_candidates,
+    plan_rebase,
+    validate_steps,
+)
+from azathoth.core.workflow import CommitInfo
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def feature_branch(git_repo):
+    repo = GitRepo(git_repo)
+    repo.commit("chore: init", {"base.txt": "base"})
+    repo.git("branch", "-M", "main")
+    repo.git("checkout", "-qb", "agent/work")
+    shas = [
+        repo.commit("feat: add a", {"a.txt": "a"}),
+        repo.commit("feat: add b", {"b.txt": "b"}),
+        repo.commit("fixup! feat: add a", {"a.txt": "a2"}),
+    ]
+    return repo, shas
+
+
+def test_find_candidates():
+    commits = [
+        RebaseCommit(sha="1" * 40, subject="feat: parser", files=["p.py", "t.py"]),
+        RebaseCommit(sha="2" * 40, subject="docs: readme", files=["README.md"]),
+        RebaseCommit(sha="3" * 40, subject="squash! feat: parser", files=["x.py"]),
+        RebaseCommit(sha="4" * 40, subject="fix tests", files=["t.py"]),
+    ]
+
+    found = {
+        (c.commit[0], c.target[0], c.action, c.detected_by)
+        for c in find_candidates(commits)
+    }
+
+    assert found == {("3", "1", "squash", "message"), ("4", "1", "squash", "files")}
+
+
+def test_validate_steps():
+    commits = [CommitInfo(sha=c * 40, author="x", date="", subject=c) for c in "ab"]
+
+    assert validate_steps(
+        [RebaseStep(commit="b" * 7), RebaseStep(action="fixup", commit="a" * 7)],
+        commits,
+    ) == []
+    problems = validate_steps(
+        [
+            RebaseStep(action="fixup", commit="a" * 7),
+            RebaseStep(action="reword", commit="a" * 7),
+        ],
+        commits,
+    )
+    assert problems == [
+        "Step 2: aaaaaaa is listed twice.",
+        "Step 2: reword needs a message.",
+        "The first kept step cannot be fixup.",
+        "Commits missing from the steps (use drop to remove one): bbbbbbb.",
+    ]
+
+
+@pytest.mark.asyncio
+async def test_plan_and_execute_autosquash(feature_branch):
+    repo, shas = feature_branch
+    cwd = str(repo.path)
+
+    plan = await plan_rebase("main", cwd=cwd)
+
+    assert [c.files for c in plan.commits] == [["a.txt"], ["b.txt"], ["a.txt"]]
+    assert [(s.action, s.commit) for s in plan.suggested] == [
+        ("pick", shas[0]),
+        ("fixup", shas[2]),
+        ("pick", shas[1]),
+    ]
+
+    reword = RebaseStep(
+        action="reword", commit=shas[1], message="feat: add b\n\nWith a body."
+    )
+    steps = [*plan.suggested[:2], reword]
+    preview = await execute_rebase(steps, "main", cwd=cwd, dry_run=True)
+    assert f"  fixup {shas[2]}" in preview.commands
+    assert repo.git("rev-parse", "HEAD") == shas[2]
+
+    result = await execute_rebase(steps, "main", cwd=cwd)
+
+    assert result.commits == ["feat: add a", "feat: add b"]
+    assert repo.git("show", "HEAD~1:a.txt") == "a2"
+    assert repo.git("log", "-1", "--format=%b") == "With a body."
+    assert repo.git("rev-parse", result.backup_ref) == shas[2]
+    assert not (repo.path / ".git" / "azathoth-rebase").exists()
+
+
+@pytest.mark.asyncio
+async def test_execute_stops_on_conflict(feature_branch):
+    repo, shas = feature_branch
+    cwd = str(repo.path)
+    steps = [RebaseStep(commit=sha) for sha in (shas[2], shas[0], shas[1])]
+
+    result = await execute_rebase(steps, "main", cwd=cwd)
+
+    assert result.stopped and result.conflicts == ["a.txt"]
+    repo.git("rebase", "--abort")
+    assert repo.git("rev-parse", "HEAD") == shas[2]
+
+    repo.git("checkout", "-q", "main")
+    with pytest.raises(WorkflowError, match="protected"):
+        await execute_rebase(steps, "main", cwd=cwd)
@@ -0,0 +1,75 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.release import create_release, detect_release_backend, web_url
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.mark.parametrize(
+    "url, expected",
+    [
+        ("https://github.com/Yrrrrrf/azathoth.git", "github"),
+        ("git@github.com:Yrrrrrf/azathoth.git", "github"),
+        ("ssh://git@gitlab.example.org:2222/team/app.git", "gitlab"),
+        ("https://codeberg.org/someone/app", "gitea"),
+        ("git@gitea.internal:team/app.git", "gitea"),
+        ("https://example.org/repo.git", None),
+    ],
+)
+def test_detect_release_backend(url, expected):
+    assert detect_release_backend(url) == expected
+
+
+@pytest.mark.parametrize(
+    "url",
+    [
+        "https://github.com/Yrrrrrf/azathoth.git",
+        "git@github.com:Yrrrrrf/azathoth.git",
+        "ssh://git@github.com:22/Yrrrrrf/azathoth",
+        "https://token@github.com/Yrrrrrf/azathoth/",
+    ],
+)
+def test_web_url(url):
+    assert web_url(url) == "https://github.com/Yrrrrrf/azathoth"
+
+
+@pytest.mark.asyncio
+async def test_create_release_uses_remote_forge(git_repo, monkeypatch):
+    monkeypatch.setattr(get_config(), "release_backend", "auto")
+    GitRepo(git_repo).git("remote", "add", "origin", "git@gitlab.com:team/app.git")
+
+    res = await create_release(
+        "v1.0.0", "notes", is_prerelease=True, dry_run=True, cwd=str(git_repo)
+    )
+    assert res.stdout.splitlines()[-1].startswith("glab release create v1.0.0")
+    assert "(pre-release)" in res.stdout
+
+    monkeypatch.setattr(get_config(), "release_backend", "gitea")
+    res = await create_release("v1.0.0", "notes", dry_run=True, cwd=str(git_repo))
+    assert "tea release create --tag v1.0.0" in res.stdout
+
+
+@pytest.mark.asyncio
+async def test_create_release_refuses_assets_outside_the_repository(git_repo):
+    (git_repo.parent / "secret").write_text("key")
+    (git_repo / "app.tar.gz").write_bytes(b"tarball")
+
+    for asset in ("../secret", str(git_repo.parent / "secret")):
+        res = await create_release(
+            "v1.0.0", "notes", dry_run=True, cwd=str(git_repo), assets=[asset]
+        )
+        assert not res.success
+        assert "inside the repository" in res.message
+
+    res = await create_release(
+        "v1.0.0", "notes", dry_run=True, cwd=str(git_repo), assets=["app.tar.gz"]
+    )
+    assert res.success
+
+
+@pytest.mark.asyncio
+async def test_create_release_refuses_option_like_tag(git_repo):
+    res = await create_release("--force", "notes", dry_run=True, cwd=str(git_repo))
+
+    assert not res.success
+    assert "Invalid revision" in res.message
@@ -0,0 +1,28 @@
+from azathoth.core.render import render, strip_ansi
+
+_COLOURED = "\x1b[1m+++ b/app.py\x1b[m\n\x1b[32m+print('hello')\x1b[m\n"
+
+
+def test_strip_ansi():
+    coloured = "\x1b[1;31merror\x1b[0m: \x1b]8;;https://x.dev\x1b\\docs\x1b]8;;\x1b\\"
+
+    assert strip_ansi(coloured) == "error: docs"
+    assert strip_ansi("plain ✓") == "plain ✓"
+
+
+def test_render_modes():
+    plain = "+++ b/app.py\n+print('hello')\n"
+
+    assert render(_COLOURED, "ansi", fence="diff") == _COLOURED
+    assert render(_COLOURED, "plain", fence="diff") == plain
+    assert render(_COLOURED, "markdown") == plain
+    assert render(_COLOURED, "markdown", fence="diff") == (
+        "```diff\n+++ b/app.py\n+print('hello')\n```"
+    )
+
+
+def test_markdown_fence_outlasts_backticks_in_text():
+    text = "+```python\n+x = 1\n+```"
+
+    assert render(text, "markdown", fence="diff") == f"````diff\n{text}\n````"
+    assert render("", "markdown", fence="diff") == ""
@@ -0,0 +1,72 @@
+import json
+
+import pytest
+
+from azathoth.core import repo_stats as stats_module
+from azathoth.core.repo_stats import count_lines, repo_stats
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def no_tokei(monkeypatch):
+    real = stats_module.run_command
+
+    async def run(argv, cwd=None, env=None):
+        if argv[0] == "tokei":
+            return 127, "", "tokei: not found"
+        return await real(argv, cwd=cwd, env=env)
+
+    monkeypatch.setattr(stats_module, "run_command", run)
+
+
+def test_count_lines_by_language():
+    source = "# setup\nimport os\n\n\nprint(os.sep)  # inline\n"
+
+    assert count_lines(source, "Python") == (2, 1, 2)
+    assert count_lines("// note\nfn main() {}\n", "Rust") == (1, 1, 0)
+    assert count_lines("# Title\n\ntext\n", "Markdown") == (2, 0, 1)
+
+
+@pytest.mark.asyncio
+async def test_builtin_counter_and_activity(git_repo, no_tokei):
+    repo = GitRepo(git_repo)
+    repo.write("src/app.py", "# app\nx = 1\ny = 2\n")
+    repo.write("main.rs", "fn main() {}\n")
+    repo.write("node_modules/dep.js", "let a = 1;\n" * 100)
+    (git_repo / "logo.png").write_bytes(b"\x89PNG\0" + b"\0" * 4000)
+    repo.commit("init")
+
+    stats = await repo_stats(git_repo)
+
+    assert stats.counter == "builtin"
+    assert stats.files == 3
+    assert [(s.language, s.code, s.comments) for s in stats.languages] == [
+        ("Python", 2, 1),
+        ("Rust", 1, 0),
+    ]
+    assert stats.code_lines == 3
+    assert stats.largest_files[0].path == "logo.png"
+    assert (stats.activity.commits, stats.activity.authors) == (1, 1)
+    assert stats.activity.weekly[-1] == 1
+
+
+@pytest.mark.asyncio
+async def test_tokei_counts_preferred(tmp_path, monkeypatch):
+    report = {
+        "Python": {"code": 40, "comments": 5, "blanks": 8, "reports": [{}, {}]},
+        "Total": {"code": 40, "comments": 5, "blanks": 8, "reports": []},
+    }
+
+    async def run(argv, cwd=None, env=None):
+        if argv[0] == "tokei":
+            return 0, json.dumps(report), ""
+        return 128, "", "fatal: not a git repository"
+
+    monkeypatch.setattr(stats_module, "run_command", run)
+    stats = await repo_stats(tmp_path)
+
+    assert stats.counter == "tokei"
+    assert [(s.language, s.files, s.code) for s in stats.languages] == [
+        ("Python", 2, 40)
+    ]
+    assert stats.activity is None
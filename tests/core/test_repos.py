@@ -0,0 +1,55 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.repos import list_repos, repo_dir, resolve_repo, use_repo
+from azathoth.core.workflow import _run_git
+
+
+@pytest.fixture
+def repos(tmp_path, monkeypatch):
+    for name in ("api", "web"):
+        (tmp_path / "code" / name / ".git").mkdir(parents=True)
+    (tmp_path / "code" / "notes.txt").write_text("")
+    (tmp_path / "secret").mkdir()
+    monkeypatch.setattr(
+        get_config(), "workflow_repos", [tmp_path / "code" / "*", tmp_path / "gone"]
+    )
+    return tmp_path
+
+
+def test_resolve_repo_checks_the_allow_list(repos):
+    assert resolve_repo(str(repos / "code" / "api")) == repos / "code" / "api"
+    with pytest.raises(WorkflowError, match="not an allowed repository"):
+        resolve_repo(str(repos / "secret"))
+    with pytest.raises(WorkflowError, match="not an allowed repository"):
+        resolve_repo(str(repos / "code" / "api" / ".." / ".." / "secret"))
+    with pytest.raises(WorkflowError, match="does not exist"):
+        resolve_repo(str(repos / "gone"))
+
+
+def test_list_repos_expands_patterns(repos, monkeypatch):
+    monkeypatch.chdir(repos / "code" / "web")
+
+    entries = list_repos()
+
+    assert [(e.name, e.available, e.current) for e in entries] == [
+        ("api", True, False),
+        ("web", True, True),
+        ("gone", False, False),
+    ]
+
+
+@pytest.mark.asyncio
+async def test_use_repo_scopes_commands(git_repo, tmp_path, monkeypatch):
+    other = tmp_path / "elsewhere"
+    other.mkdir()
+    monkeypatch.chdir(other)
+
+    with use_repo(git_repo):
+        assert repo_dir() == str(git_repo)
+        _, top, _ = await _run_git(["rev-parse", "--show-toplevel"])
+    assert repo_dir() is None
+    assert top == str(git_repo.resolve())
+    code, _, _ = await _run_git(["rev-parse", "--show-toplevel"])
+    assert code != 0
@@ -0,0 +1,20 @@
+from azathoth.core.results import patch_stats
+
+_PATCH = """\
+diff --git a/app.py b/app.py
+index 1111111..2222222 100644
+--- a/app.py
++++ b/app.py
+@@ -1,2 +1,2 @@
+-print('hi')
++print('hello')
++--- not a header
+diff --git a/logo.png b/logo.png
+new file mode 100644
+Binary files /dev/null and b/logo.png differ
+"""
+
+
+def test_patch_stats_counts_hunk_lines_only():
+    assert patch_stats(_PATCH) == (2, 2, 1)
+    assert patch_stats("") == (0, 0, 0)
@@ -0,0 +1,129 @@
+import json
+
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.reviews import (
+    ReviewComment,
+    list_prs,
+    parse_hunks,
+    pr_comment,
+    pr_diff,
+    pr_review,
+)
+from azathoth.core.runner import Response, ScriptedRunner, use_runner
+
+_API = "https://api.github.com/repos/team/app"
+_PATCH = """@@ -1,3 +1,4 @@ fn main() {
+ a
+-b
++B
++c
+ d
+@@ -20 +21,2 @@
+-x
++y
++z"""
+
+
+def test_parse_hunks_reads_ranges_and_lines():
+    first, second = parse_hunks(_PATCH)
+
+    assert (first.old_start, first.old_lines) == (1, 3)
+    assert (first.new_start, first.new_lines) == (1, 4)
+    assert first.header == "fn main() {"
+    assert first.lines == [" a", "-b", "+B", "+c", " d"]
+    assert (second.old_start, second.old_lines, second.new_lines) == (20, 1, 2)
+    assert parse_hunks("") == []
+
+
+@pytest.mark.asyncio
+async def test_list_and_diff_pull_requests(git_repo, api):
+    cwd = str(git_repo)
+    api.replies += [
+        [
+            {
+                "number": 4,
+                "title": "feat: x",
+                "user": {"login": "ann"},
+                "head": {"ref": "feat/x"},
+                "base": {"ref": "main"},
+                "labels": [{"name": "enhancement"}],
+            }
+        ],
+        {"head": {"sha": "abc123"}},
+        [
+            {"filename": "src/main.rs", "status": "modified", "patch": _PATCH},
+            {"filename": "logo.png", "status": "added"},
+        ],
+    ]
+
+    (pr,) = await list_prs(base="main", cwd=cwd)
+    diff = await pr_diff(4, cwd=cwd)
+
+    assert (pr.number, pr.author, pr.head) == (4, "ann", "feat/x")
+    assert pr.labels == ["enhancement"]
+    assert api[0][1].endswith("&base=main")
+    assert diff.head_sha == "abc123"
+    assert [len(f.hunks) for f in diff.files] == [2, 0]
+    assert diff.files[1].binary
+    assert api[2][1] == f"{_API}/pulls/4/files?per_page=100&page=1"
+
+
+@pytest.mark.asyncio
+async def test_comments_and_reviews(git_repo, api):
+    cwd = str(git_repo)
+    api.replies += [
+        {"head": {"sha": "abc123"}},
+        {"html_url": "u/c1"},
+        {"html_url": "u/r1"},
+    ]
+
+    with pytest.raises(WorkflowError, match="both path and line"):
+        await pr_comment(4, "nit", path="a.rs", cwd=cwd)
+    with pytest.raises(WorkflowError, match="needs a body"):
+        await pr_review(4, "REQUEST_CHANGES", cwd=cwd)
+    plan = await pr_review(4, "APPROVE", dry_run=True, cwd=cwd)
+    assert plan.commands == [f"POST {_API}/pulls/4/reviews"]
+    assert not api
+
+    comment = await pr_comment(4, "nit", path="a.rs", line=7, cwd=cwd)
+    review = await pr_review(
+        4,
+        "REQUEST_CHANGES",
+        "Needs tests.",
+        [ReviewComment(path="a.rs", line=9, body="Handle None")],
+        cwd=cwd,
+    )
+
+    assert (comment.kind, comment.url, review.url) == ("line-comment", "u/c1", "u/r1")
+    assert api[1][3]["json"] == {
+        "body": "nit",
+        "commit_id": "abc123",
+        "path": "a.rs",
+        "line": 7,
+        "side": "RIGHT",
+    }
+    assert api[2][3]["json"]["comments"] == [
+        {"path": "a.rs", "line": 9, "body": "Handle None", "side": "RIGHT"}
+    ]
+
+
+@pytest.mark.asyncio
+async def test_gh_api_is_used_without_a_token(git_repo, api, monkeypatch):
+    monkeypatch.setattr(get_config(), "github_token", None)
+    monkeypatch.delenv("GITHUB_TOKEN", raising=False)
+    monkeypatch.delenv("GH_TOKEN", raising=False)
+    runner = ScriptedRunner([Response(["gh", "api"], stdout=json.dumps({"id": 1}))])
+
+    with use_runner(runner):
+        plan = await pr_comment(4, "thanks", dry_run=True, cwd=str(git_repo))
+        posted = await pr_comment(4, "thanks", cwd=str(git_repo))
+
+    assert plan.commands == [
+        "gh api --method POST 'repos/{owner}/{repo}/issues/4/comments'"
+    ]
+    assert posted.posted and posted.kind == "comment"
+    (argv,) = runner.commands("gh")
+    assert argv[4:6] == ["repos/{owner}/{repo}/issues/4/comments", "--input"]
@@ -0,0 +1,88 @@
+import pytest
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.rewrite import (
+    SquashGroup,
+    branch_commits,
+    resolve_base,
+    rewrite_history,
+    validate_plan,
+)
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def feature_branch(git_repo):
+    repo = GitRepo(git_repo)
+    repo.commit("chore: init", {"base.txt": "base"})
+    repo.git("branch", "-M", "main")
+    repo.git("checkout", "-qb", "agent/work")
+    shas = [
+        repo.commit("wip", {"a.txt": "a"}),
+        repo.commit("fixup", {"a.txt": "a2"}),
+        repo.commit("more", {"b.txt": "b"}),
+    ]
+    return repo, shas
+
+
+def test_validate_plan_reports_gaps_and_order():
+    from azathoth.core.workflow import CommitInfo
+
+    commits = [
+        CommitInfo(sha=c * 40, author="x", date="", subject=c) for c in "abc"
+    ]
+    ok = [
+        SquashGroup(commits=["a" * 7, "b" * 7], title="feat: x"),
+        SquashGroup(commits=["c" * 7], title="fix: y"),
+    ]
+    assert validate_plan(ok, commits) == []
+
+    problems = validate_plan([SquashGroup(commits=["c" * 7, "a" * 7])], commits)
+    assert any("out of order" in p for p in problems)
+    assert any("bbbbbbb" in p for p in problems)
+    assert validate_plan([SquashGroup(commits=["abc"])], commits)[0].startswith(
+        "Group 1: 'abc'"
+    )
+
+
+@pytest.mark.asyncio
+async def test_rewrite_squashes_and_keeps_tree(feature_branch):
+    repo, shas = feature_branch
+    cwd = str(repo.path)
+    tree_before = repo.git("rev-parse", "HEAD^{tree}")
+
+    base = await resolve_base(cwd=cwd)
+    assert [c.sha for c in await branch_commits(base, cwd)] == shas
+
+    plan = [
+        SquashGroup(commits=shas[:2], title="feat: add a", body="Adds a."),
+        SquashGroup(commits=shas[2:], title="feat: add b"),
+    ]
+    preview = await rewrite_history(plan, cwd=cwd, dry_run=True)
+    assert preview.new_head is None
+    assert repo.git("rev-parse", "HEAD") == shas[-1]
+
+    result = await rewrite_history(plan, cwd=cwd)
+
+    assert repo.git("rev-parse", "HEAD^{tree}") == tree_before
+    assert repo.git("log", "--format=%s", "main..HEAD").splitlines() == [
+        "feat: add b",
+        "feat: add a",
+    ]
+    assert repo.git("rev-parse", result.backup_ref) == shas[-1]
+
+
+@pytest.mark.asyncio
+async def test_rewrite_refuses_protected_and_dirty(feature_branch):
+    repo, shas = feature_branch
+    cwd = str(repo.path)
+    plan = [SquashGroup(commits=shas, title="feat: all")]
+
+    (repo.path / "a.txt").write_text("dirty")
+    with pytest.raises(WorkflowError, match="local changes"):
+        await rewrite_history(plan, cwd=cwd)
+
+    repo.git("checkout", "-q", "--", "a.txt")
+    repo.git("checkout", "-q", "main")
+    with pytest.raises(WorkflowError, match="protected"):
+        await rewrite_history(plan, base="main", cwd=cwd)
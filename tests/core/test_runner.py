@@ -0,0 +1,58 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.release import create_release
+from azathoth.core.runner import (
+    Response,
+    ScriptedRunner,
+    SubprocessRunner,
+    current_runner,
+    use_runner,
+)
+from azathoth.core.workflow import commit, run_command
+
+
+@pytest.mark.asyncio
+async def test_scripted_release_stops_when_the_push_fails(monkeypatch):
+    monkeypatch.setattr(get_config(), "release_backend", "github")
+    runner = ScriptedRunner(
+        [
+            Response(["git", "tag"]),
+            Response(["git", "push"], code=128, stderr="rejected"),
+            Response(["gh", "release"]),
+        ]
+    )
+
+    with use_runner(runner):
+        result = await create_release("v1.0.0", "notes", cwd="/repo")
+
+    assert (result.success, result.message) == (False, "Pushing tag failed")
+    assert result.stderr == "rejected"
+    assert runner.commands() == [
+        ["git", "tag", "-a", "v1.0.0", "-m", "Release v1.0.0"],
+        ["git", "push", "origin", "v1.0.0"],
+    ]
+    assert {cwd for _, cwd in runner.calls} == {"/repo"}
+    assert isinstance(current_runner(), SubprocessRunner)
+
+
+@pytest.mark.asyncio
+async def test_unscripted_commands_fall_back_or_fail(git_repo):
+    runner = ScriptedRunner(
+        [Response(["git", "commit"], code=1, stderr="hook failed", times=1)],
+        fallback=SubprocessRunner(),
+    )
+    (git_repo / "a.txt").write_text("a\n")
+
+    with use_runner(runner):
+        assert (await run_command(["git", "add", "a.txt"], cwd=str(git_repo)))[0] == 0
+        first = await commit("feat: a", "", cwd=str(git_repo))
+        second = await commit("feat: a", "", cwd=str(git_repo))
+
+    assert (first.success, first.stderr) == (False, "hook failed")
+    assert second.success
+    assert len(runner.commands("git", "commit")) == 2
+
+    with use_runner(ScriptedRunner()):
+        code, _, err = await run_command(["gh", "auth", "status"])
+    assert (code, err) == (127, "No scripted response for: gh auth status")
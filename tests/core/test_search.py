@@ -0,0 +1,49 @@
+import pytest
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.files import Sandbox
+from azathoth.core.search import search
+
+
+@pytest.fixture
+def project(tmp_path):
+    root = tmp_path / "project"
+    (root / "src").mkdir(parents=True)
+    (root / "src" / "app.py").write_text(
+        "import os\n\n\ndef load_config(path):\n    return os.environ\n"
+    )
+    (root / "src" / "cli.rs").write_text("fn main() {\n    load_config();\n}\n")
+    (root / "notes.txt").write_text("call Load_Config first\n")
+    (root / "blob.bin").write_bytes(b"load_config\0\0")
+    (root / ".gitignore").write_text("generated/\n")
+    (root / "generated").mkdir()
+    (root / "generated" / "api.py").write_text("def load_config(): ...\n")
+    (tmp_path / "secret.py").write_text("load_config = 'outside'\n")
+    (root / "escape.py").symlink_to(tmp_path / "secret.py")
+    return Sandbox(root)
+
+
+def test_search_reports_matches_with_context(project):
+    result = search(project, r"def \w+_config", context=1)
+
+    assert [(m.path, m.line) for m in result.matches] == [("src/app.py", 4)]
+    match = result.matches[0]
+    assert (match.before, match.after) == ([""], ["    return os.environ"])
+    assert (result.files_searched, result.files_matched) == (4, 1)
+
+
+def test_search_options(project):
+    everywhere = search(project, "load_config", fixed_strings=True)
+    assert [m.path for m in everywhere.matches] == ["src/app.py", "src/cli.rs"]
+
+    folded = search(project, "load_config", ignore_case=True, glob="*.txt")
+    assert [m.path for m in folded.matches] == ["notes.txt"]
+    assert [m.path for m in search(project, "load", glob="src/*.rs").matches] == [
+        "src/cli.rs"
+    ]
+
+    capped = search(project, "load_config", max_results=1)
+    assert len(capped.matches) == 1 and capped.truncated
+
+    with pytest.raises(WorkflowError, match="Invalid regular expression"):
+        search(project, "(unclosed")
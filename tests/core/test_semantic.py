@@ -0,0 +1,67 @@
+import math
+import re
+
+import pytest
+
+from azathoth.core.files import Sandbox
+from azathoth.core.semantic import chunk, semantic_search
+
+_VOCABULARY = ["retry", "backoff", "http", "parse", "config", "toml", "render"]
+
+
+class WordsEmbedder:
+    """Bag-of-words vectors over a fixed vocabulary; counts texts embedded."""
+
+    model = "fake/words"
+
+    def __init__(self):
+        self.embedded = 0
+
+    async def embed(self, texts):
+        self.embedded += len(texts)
+        vectors = []
+        for text in texts:
+            words = re.findall(r"[a-z]+", text.lower())
+            vector = [float(words.count(term)) for term in _VOCABULARY]
+            norm = math.sqrt(sum(x * x for x in vector)) or 1.0
+            vectors.append([x / norm for x in vector])
+        return vectors
+
+
+@pytest.fixture
+def project(tmp_path):
+    root = tmp_path / "project"
+    (root / "src").mkdir(parents=True)
+    (root / "src" / "client.py").write_text(
+        "def fetch(url):\n    # retry the http call with backoff\n    ...\n"
+    )
+    (root / "src" / "settings.rs").write_text(
+        "// parse the toml config\nfn load() {}\n"
+    )
+    (root / "src" / "view.ts").write_text("export function render() {}\n")
+    (root / "data.json").write_text('{"retry": "http backoff"}\n')
+    return Sandbox(root)
+
+
+def test_chunk_windows_overlap():
+    text = "\n".join(f"line {n}" for n in range(1, 46))
+    assert [(start, end) for start, end, _ in chunk(text)] == [(1, 40), (31, 45)]
+    assert chunk("\n\n") == []
+
+
+async def test_semantic_search_ranks_and_reuses_the_index(project):
+    embedder = WordsEmbedder()
+    result = await semantic_search(project, "http retry with backoff", embedder)
+
+    assert result.matches[0].path == "src/client.py"
+    assert result.matches[0].start_line == 1
+    assert "backoff" in result.matches[0].snippet
+    assert (result.files_indexed, result.chunks_embedded) == (3, 3)
+    assert result.model == "fake/words"
+
+    again = await semantic_search(
+        project, "toml config", embedder, limit=1, glob="*.rs"
+    )
+    assert [m.path for m in again.matches] == ["src/settings.rs"]
+    assert again.chunks_embedded == 0
+    assert embedder.embedded == 3 + 1 + 1  # chunks once, then one query each
@@ -0,0 +1,79 @@
+import pytest
+
+from azathoth.core.session import (
+    Operation,
+    record,
+    record_head_commit,
+    session_state,
+    undo_last,
+)
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def repo(git_repo):
+    repo = GitRepo(git_repo)
+    repo.git("symbolic-ref", "HEAD", "refs/heads/main")
+    repo.commit("chore: init", {"a.txt": "a"})
+    return repo
+
+
+@pytest.mark.asyncio
+async def test_undo_reverts_newest_first_and_keeps_changes_staged(repo):
+    cwd = str(repo.path)
+    repo.switch("feat/x", create=True)
+    await record(
+        Operation(kind="branch", tool="create_branch", ref="feat/x", previous="main"),
+        cwd,
+    )
+    sha = repo.commit("feat: b", {"b.txt": "b"})
+    await record_head_commit("stage_and_commit", cwd)
+
+    parent = repo.git("rev-parse", "HEAD^")
+    plan = await undo_last(dry_run=True, cwd=cwd)
+    undone = await undo_last(cwd=cwd)
+
+    assert plan.commands == [f"git reset --soft {parent}"]
+    assert undone.message == f"Undid commit {sha[:12]} (stage_and_commit)."
+    assert repo.subjects() == ["chore: init"]
+    assert "A  b.txt" in repo.git("status", "--porcelain")
+
+    repo.git("commit", "-qm", "feat: b again")
+    assert not (await undo_last(cwd=cwd)).success  # the branch has unmerged work
+    repo.git("reset", "-q", "--hard", "HEAD^")
+    assert (await undo_last(cwd=cwd)).success
+    assert repo.git("branch", "--show-current") == "main"
+    assert repo.git("branch", "--list", "feat/x") == ""
+    assert [op.undone for op in (await session_state(cwd)).operations] == [True, True]
+    assert (await undo_last(cwd=cwd)).message == "Nothing recorded to undo."
+
+
+@pytest.mark.asyncio
+async def test_undo_refuses_once_head_moved_on(repo):
+    cwd = str(repo.path)
+    repo.commit("feat: b", {"b.txt": "b"})
+    await record_head_commit("stage_and_commit", cwd)
+    repo.commit("feat: c", {"c.txt": "c"})
+
+    result = await undo_last(cwd=cwd)
+
+    assert not result.success and "HEAD has moved past" in result.message
+    assert repo.subjects()[0] == "feat: c"
+    assert not (await session_state(cwd)).operations[0].undone
+
+
+@pytest.mark.asyncio
+async def test_undo_refuses_a_published_release(repo):
+    cwd = str(repo.path)
+    repo.tag("v1.0.0")
+    await record(
+        Operation(kind="release", tool="create_release", ref="v1.0.0", remote="origin"),
+        cwd,
+    )
+
+    result = await undo_last(cwd=cwd)
+
+    assert not result.success
+    assert "delete the release there first" in result.message
+    assert repo.git("tag", "--list", "v1.0.0") == "v1.0.0"
+    assert not (await session_state(cwd)).operations[0].undone
@@ -0,0 +1,121 @@
+import subprocess
+
+import pytest
+
+from azathoth.core.signing import (
+    classify_signing_failure,
+    signature_status,
+    signing_setup,
+)
+from azathoth.core.workflow import commit
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def ssh_key(tmp_path):
+    key = tmp_path / "signing_key"
+    subprocess.run(
+        ["ssh-keygen", "-q", "-t", "ed25519", "-N", "", "-f", str(key)], check=True
+    )
+    return key
+
+
+@pytest.mark.parametrize(
+    "stderr, reason",
+    [
+        (
+            'gpg: skipped "ABCD1234": No secret key\n'
+            "gpg: signing failed: No secret key\n"
+            "error: gpg failed to sign the data",
+            "missing_key",
+        ),
+        (
+            "error: Couldn't load public key /home/me/.ssh/id.pub: "
+            "No such file or directory?",
+            "missing_key",
+        ),
+        (
+            'gpg: skipped "ABCD1234": Unusable secret key\n'
+            "gpg: signing failed: Unusable secret key",
+            "expired_key",
+        ),
+        (
+            "gpg: can't connect to the agent: IPC connect call failed\n"
+            "error: gpg failed to sign the data",
+            "agent_unavailable",
+        ),
+        (
+            "Could not open a connection to your authentication agent.",
+            "agent_unavailable",
+        ),
+        (
+            "gpg: signing failed: Inappropriate ioctl for device",
+            "passphrase_required",
+        ),
+        ("error: cannot run gpg: No such file or directory", "program_missing"),
+        ("error: gpg failed to sign the data", "unknown"),
+    ],
+)
+def test_classify_signing_failure(stderr, reason):
+    failure = classify_signing_failure(stderr)
+
+    assert failure is not None and failure.reason == reason
+    assert failure.render().startswith(f"Signing failed ({reason}): ")
+
+
+def test_classify_ignores_other_failures():
+    assert classify_signing_failure("error: pathspec 'x' did not match") is None
+
+
+@pytest.mark.asyncio
+async def test_signing_setup_reads_git_config(git_repo):
+    repo = GitRepo(git_repo)
+    cwd = str(repo.path)
+    assert not (await signing_setup(cwd)).auto
+
+    repo.git("config", "commit.gpgsign", "yes")
+    repo.git("config", "gpg.format", "ssh")
+    repo.git("config", "user.signingkey", "~/.ssh/id.pub")
+    setup = await signing_setup(cwd)
+
+    assert (setup.auto, setup.format, setup.key) == (True, "ssh", "~/.ssh/id.pub")
+
+
+@pytest.mark.asyncio
+async def test_signed_and_unsigned_commit_status(git_repo, ssh_key):
+    repo = GitRepo(git_repo)
+    cwd = str(repo.path)
+    repo.git("commit", "--allow-empty", "-qm", "chore: plain")
+    assert (await signature_status(cwd=cwd)).describe() == "unsigned"
+
+    repo.git("config", "gpg.format", "ssh")
+    repo.git("config", "user.signingkey", f"{ssh_key}.pub")
+    repo.write("a.txt", "a\n")
+    repo.git("add", "a.txt")
+    assert (await commit("feat: a", "", cwd=cwd, sign=True)).success
+    status = await signature_status(cwd=cwd)
+
+    assert (status.signed, status.format) == (True, "ssh")
+    assert status.verification == "unverified"
+
+
+@pytest.mark.asyncio
+async def test_sign_reports_missing_key(git_repo, tmp_path):
+    repo = GitRepo(git_repo)
+    cwd = str(repo.path)
+    repo.git("config", "gpg.format", "ssh")
+    repo.git("config", "user.signingkey", str(tmp_path / "nope.pub"))
+    repo.write("a.txt", "a\n")
+    repo.git("add", "a.txt")
+
+    res = await commit("feat: a", "", cwd=cwd, sign=True)
+
+    assert not res.success
+    assert classify_signing_failure(res.stderr).reason == "missing_key"
+
+
+@pytest.mark.asyncio
+async def test_sign_dry_run_plans_dash_s(git_repo):
+    res = await commit("feat: a", "", cwd=str(git_repo), dry_run=True, sign=True)
+
+    assert "git commit -S -F" in res.stdout
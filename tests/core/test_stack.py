@@ -0,0 +1,57 @@
+import json
+
+from azathoth.core.stack import declared_dependencies, stack_profile
+
+
+def test_declared_dependencies_across_manifests(tmp_path):
+    (tmp_path / "pyproject.toml").write_text(
+        '[project]\nname = "x"\ndependencies = ["httpx>=0.28", "Pydantic_Core"]\n'
+        '[dependency-groups]\ndev = ["pytest>=8"]\n'
+    )
+    (tmp_path / "package.json").write_text(
+        json.dumps({"dependencies": {"zod": "^3"}, "devDependencies": {"vitest": "1"}})
+    )
+    (tmp_path / "Cargo.toml").write_text(
+        '[dependencies]\nserde = { version = "1", features = ["derive"] }\n'
+        'tokio = "1"\n'
+    )
+    (tmp_path / "go.mod").write_text(
+        "module x\n\nrequire (\n\tgithub.com/spf13/cobra v1.8.0 // cli\n)\n"
+    )
+
+    deps = {(d.ecosystem, d.name): d for d in declared_dependencies(tmp_path)}
+
+    assert deps[("python", "httpx")].version_spec == ">=0.28"
+    assert ("python", "pydantic-core") in deps
+    assert deps[("python", "pytest")].dev
+    assert deps[("javascript", "vitest")].dev
+    assert deps[("rust", "serde")].version_spec == "1"
+    assert deps[("go", "github.com/spf13/cobra")].version_spec == "v1.8.0"
+
+
+def test_stack_profile_prefers_most_imported(tmp_path):
+    (tmp_path / "pyproject.toml").write_text(
+        '[project]\nname = "x"\ndependencies = ["httpx", "requests"]\n'
+    )
+    src = tmp_path / "src"
+    src.mkdir()
+    (src / "a.py").write_text("import httpx\nimport logging\n")
+    (src / "b.py").write_text("from httpx import AsyncClient\n")
+    (src / "c.py").write_text("import requests\n")
+    skipped = tmp_path / ".venv"
+    skipped.mkdir()
+    (skipped / "d.py").write_text("import requests\n")
+
+    profile = stack_profile(tmp_path)
+    concerns = {c.concern: c for c in profile.concerns}
+
+    assert profile.ecosystems == ["python"]
+    http = concerns["HTTP client"].libraries
+    assert [(lib.name, lib.import_count) for lib in http] == [
+        ("httpx", 2),
+        ("requests", 1),
+    ]
+    assert concerns["Logging"].libraries[0].name == "logging"
+    assert not concerns["Logging"].libraries[0].declared
+    assert "Testing" in profile.uncovered
+    assert "`httpx`" in profile.render_markdown()
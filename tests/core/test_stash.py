@@ -0,0 +1,56 @@
+import pytest
+
+from azathoth.core.stash import list_stashes, pop_stash, save_stash
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def repo(git_repo):
+    repo = GitRepo(git_repo)
+    repo.commit("init", {"a.txt": "a\n", "b.txt": "b\n"})
+    repo.git("branch", "-M", "main")
+    return repo
+
+
+@pytest.mark.asyncio
+async def test_save_list_and_pop(repo):
+    cwd = str(repo.path)
+    (repo.path / "a.txt").write_text("a2\n")
+    (repo.path / "b.txt").write_text("b2\n")
+    (repo.path / "new.txt").write_text("new\n")
+
+    assert (await save_stash("wip a", paths=["a.txt", "new.txt"], cwd=cwd)).success
+    assert repo.git("status", "--porcelain") == "M b.txt"
+
+    [entry] = await list_stashes(cwd)
+    assert (entry.index, entry.ref, entry.message, entry.branch) == (
+        0,
+        "stash@{0}",
+        "wip a",
+        "main",
+    )
+    assert entry.files == ["a.txt", "new.txt"]
+    assert entry.date.startswith("20")
+
+    assert (await pop_stash(cwd=cwd)).success
+    assert (repo.path / "a.txt").read_text() == "a2\n"
+    assert await list_stashes(cwd) == []
+    assert not (await save_stash(paths=["missing.txt"], cwd=cwd)).success
+
+
+@pytest.mark.asyncio
+async def test_pop_refuses_overlap_and_reports_conflicts(repo):
+    cwd = str(repo.path)
+    (repo.path / "a.txt").write_text("stashed\n")
+    await save_stash(cwd=cwd)
+
+    (repo.path / "a.txt").write_text("local\n")
+    refused = await pop_stash(cwd=cwd)
+    assert not refused.success and "a.txt" in refused.message
+    assert not (await pop_stash(3, cwd=cwd)).success
+
+    repo.git("commit", "-qam", "diverge")
+    res = await pop_stash(cwd=cwd)
+    assert not res.success
+    assert "Conflicts applying stash@{0} in: a.txt" in res.message
+    assert len(await list_stashes(cwd)) == 1
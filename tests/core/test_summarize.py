@@ -0,0 +1,100 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.summarize import summarize_directory
+from azathoth.core.symbols import file_facts
+
+
+@pytest.fixture
+def repo(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path / "cfg")
+    root = tmp_path / "repo"
+    pkg = root / "src" / "app"
+    (pkg / "store").mkdir(parents=True)
+    (pkg / "__init__.py").write_text('"""app — the demo application."""\n')
+    (pkg / "store" / "__init__.py").write_text(
+        '"""app.store — persistence layer."""\n'
+        "from .models import Record\n"
+        '__all__ = ["Record", "open_db"]\n'
+        "def open_db(): ...\n"
+    )
+    (pkg / "store" / "models.py").write_text(
+        "import json\nimport httpx\n\nclass Record: ...\n\nclass _Hidden: ...\n"
+    )
+    (pkg / "cli.py").write_text(
+        "from app.store import open_db\nfrom app.util import helper\n"
+    )
+    (pkg / "util.py").write_text("def helper(): ...\n")
+    return root
+
+
+def test_file_facts_python_symbols_and_imports(repo):
+    facts = file_facts(repo / "src/app/store/__init__.py", repo)
+
+    assert facts.doc == "app.store — persistence layer."
+    assert [(s.name, s.kind) for s in facts.symbols] == [
+        ("Record", "reexport"),
+        ("open_db", "function"),
+    ]
+    assert "src.app.store.models" in facts.imports
+
+
+def test_file_facts_cached_by_content(repo):
+    path = repo / "src/app/util.py"
+    first = file_facts(path, repo)
+    cached = list((get_config().cache_dir / "facts").glob("*.json"))
+
+    assert len(cached) == 1
+    assert file_facts(path, repo) == first
+
+    path.write_text("def helper(): ...\ndef other(): ...\n")
+    assert [s.name for s in file_facts(path, repo).symbols] == ["helper", "other"]
+
+
+def test_summarize_directory_edges_and_surface(repo):
+    summary = summarize_directory(repo, "src/app/store")
+
+    assert summary.purpose == "app.store — persistence layer."
+    assert summary.files == 2
+    assert [s.name for s in summary.public_surface] == ["Record", "open_db"]
+    assert summary.used_by == ["src/app"]
+    assert summary.depends_on == []
+    assert summary.external == ["httpx"]
+    assert summary.key_files[0].path == "src/app/store/__init__.py"
+
+
+def test_summarize_directory_lists_subdirectories(repo):
+    summary = summarize_directory(repo, "src/app")
+
+    assert summary.subdirectories == ["src/app/store"]
+    assert summary.files == 5
+    assert "## Key files" in summary.render_markdown()
+
+
+def test_content_hash_tracks_subtree_only(repo):
+    before = summarize_directory(repo, "src/app/store").content_hash
+    (repo / "src/app/util.py").write_text("def changed(): ...\n")
+    assert summarize_directory(repo, "src/app/store").content_hash == before
+
+    (repo / "src/app/store/models.py").write_text("class Record: ...\n")
+    assert summarize_directory(repo, "src/app/store").content_hash != before
+
+
+def test_js_relative_imports_resolve(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path / "cfg")
+    (tmp_path / "lib").mkdir()
+    (tmp_path / "lib" / "index.ts").write_text("export function api() {}\n")
+    (tmp_path / "main.ts").write_text(
+        "import { api } from './lib';\nimport React from 'react';\n"
+    )
+
+    summary = summarize_directory(tmp_path, "lib")
+
+    assert summary.used_by == ["."]
+    assert [s.name for s in summary.public_surface] == ["api"]
+
+
+def test_rejects_paths_outside_root(repo):
+    with pytest.raises(WorkflowError):
+        summarize_directory(repo, "../..")
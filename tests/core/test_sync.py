@@ -0,0 +1,88 @@
+import pytest
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.runner import ScriptedRunner, use_runner
+from azathoth.core.sync import fetch, pull, push
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def repo(git_repo, tmp_path):
+    repo = GitRepo(git_repo)
+    repo.commit("init", {"a.txt": "a"})
+    repo.git("branch", "-M", "main")
+    GitRepo(tmp_path).git("init", "-q", "--bare", "remote.git")
+    repo.git("remote", "add", "origin", str(tmp_path / "remote.git"))
+    return repo
+
+
+@pytest.mark.asyncio
+async def test_push_needs_set_upstream_then_tracks(repo):
+    cwd = str(repo.path)
+    repo.switch("feature", create=True)
+
+    with pytest.raises(WorkflowError, match="set_upstream=True"):
+        await push(cwd=cwd)
+    plan = await push(set_upstream=True, cwd=cwd, dry_run=True)
+    assert plan.commands == ["git push --set-upstream -- origin feature"]
+    assert not plan.done
+
+    result = await push(set_upstream=True, cwd=cwd)
+
+    assert result.done
+    assert result.before.upstream is None
+    assert result.after.upstream == "origin/feature"
+    repo.commit("feat: b", {"b.txt": "b"})
+    again = await push(cwd=cwd)
+    assert (again.before.ahead, again.after.ahead) == (1, 0)
+
+
+@pytest.mark.asyncio
+async def test_fetch_and_pull_report_behind_counts(repo, tmp_path):
+    cwd = str(repo.path)
+    await push(set_upstream=True, cwd=cwd)
+    clone = GitRepo(tmp_path / "clone")
+    GitRepo(tmp_path).git(
+        "clone", "-qb", "main", str(tmp_path / "remote.git"), str(clone.path)
+    )
+    clone.git("config", "user.name", "t")
+    clone.git("config", "user.email", "t@t")
+    for name in ("x", "y"):
+        clone.commit(name, {f"{name}.txt": name})
+    clone.git("push", "-q", "origin", "main")
+
+    fetched = await fetch(cwd=cwd)
+    assert (fetched.before.behind, fetched.after.behind) == (0, 2)
+    assert (await pull(cwd=cwd, dry_run=True)).commands == ["git pull --rebase"]
+
+    pulled = await pull(cwd=cwd)
+
+    assert (pulled.before.behind, pulled.after.behind) == (2, 0)
+    assert (repo.path / "y.txt").exists()
+
+
+@pytest.mark.asyncio
+async def test_force_push_refused_on_protected_branch(repo):
+    with pytest.raises(WorkflowError, match="protected"):
+        await push(force_with_lease=True, set_upstream=True, cwd=str(repo.path))
+    with pytest.raises(WorkflowError, match="no upstream"):
+        await pull(cwd=str(repo.path))
+
+
+@pytest.mark.asyncio
+async def test_option_like_remote_or_branch_refused_before_git_runs(repo):
+    cwd = str(repo.path)
+    runner = ScriptedRunner()
+    with use_runner(runner):
+        with pytest.raises(WorkflowError, match="Invalid remote"):
+            await fetch("--upload-pack=touch pwned", cwd=cwd)
+        with pytest.raises(WorkflowError, match="Invalid remote"):
+            await pull(remote="--upload-pack=touch pwned", branch="main", cwd=cwd)
+        with pytest.raises(WorkflowError, match="Invalid branch"):
+            await pull(remote="origin", branch="--rebase=false", cwd=cwd)
+        with pytest.raises(WorkflowError, match="Invalid remote"):
+            await push("--receive-pack=touch pwned", ".", set_upstream=True, cwd=cwd)
+        with pytest.raises(WorkflowError, match="Invalid branch"):
+            await push("origin", "--mirror", cwd=cwd)
+    assert runner.calls == []
+    assert not (repo.path / "pwned").exists()
@@ -0,0 +1,84 @@
+import pytest
+
+from azathoth.core.tags import create_tag, delete_tag, list_tags, semver_key
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def repo(git_repo):
+    repo = GitRepo(git_repo)
+    repo.commit("init", {"a.txt": "a"})
+    return repo
+
+
+def test_semver_key_follows_precedence():
+    ordered = sorted(
+        ["v1.10.0", "v2.0.0", "v1.9.0", "v2.0.0-rc.1", "v2.0.0-beta", "v2.0.0-rc.10"],
+        key=semver_key,
+    )
+
+    assert ordered == [
+        "v1.9.0",
+        "v1.10.0",
+        "v2.0.0-beta",
+        "v2.0.0-rc.1",
+        "v2.0.0-rc.10",
+        "v2.0.0",
+    ]
+
+
+@pytest.mark.asyncio
+async def test_create_and_list_tags(repo):
+    cwd = str(repo.path)
+    head = repo.git("rev-parse", "HEAD")
+
+    plan = await create_tag("v1.10.0", "Second", cwd=cwd, dry_run=True)
+    assert plan.stdout == "git tag -a v1.10.0 -m Second"
+    assert (await create_tag("v1.10.0", "Second", cwd=cwd)).success
+    assert (await create_tag("v1.9.0", kind="lightweight", cwd=cwd)).success
+    assert (await create_tag("nightly", cwd=cwd)).success
+    assert not (await create_tag("v1.9.0", cwd=cwd)).success
+    assert not (await create_tag("bad..name", cwd=cwd)).success
+
+    tags = await list_tags(cwd=cwd)
+
+    assert [(t.name, t.kind, t.version) for t in tags] == [
+        ("v1.10.0", "annotated", "1.10.0"),
+        ("v1.9.0", "lightweight", "1.9.0"),
+        ("nightly", "annotated", None),
+    ]
+    assert {t.sha for t in tags} == {head}
+    assert tags[0].subject == "Second"
+    assert [t.name for t in await list_tags("v1.*", cwd=cwd)] == ["v1.10.0", "v1.9.0"]
+
+
+@pytest.mark.asyncio
+async def test_delete_tag_needs_confirmation(repo):
+    cwd = str(repo.path)
+    repo.git("tag", "v0.1.0")
+
+    plan = await delete_tag("v0.1.0", remote=True, cwd=cwd, dry_run=True)
+    assert plan.stdout.splitlines() == [
+        "git tag -d v0.1.0",
+        "git push origin --delete refs/tags/v0.1.0",
+    ]
+    refused = await delete_tag("v0.1.0", cwd=cwd)
+    assert not refused.success
+    assert "confirm=True" in refused.message
+    assert repo.git("tag", "--list") == "v0.1.0"
+
+    assert (await delete_tag("v0.1.0", confirm=True, cwd=cwd)).success
+    assert repo.git("tag", "--list") == ""
+    assert not (await delete_tag("v0.1.0", confirm=True, cwd=cwd)).success
+
+
+@pytest.mark.asyncio
+async def test_option_like_names_and_refs_are_refused(repo):
+    cwd = str(repo.path)
+
+    for name, ref in [("--force", None), ("v1.0.0", "--force"), ("-f", "HEAD")]:
+        res = await create_tag(name, ref=ref, cwd=cwd, dry_run=True)
+        assert not res.success
+        assert "Invalid revision" in res.message
+    assert not (await delete_tag("--force", confirm=True, cwd=cwd)).success
+    assert repo.git("tag", "--list") == ""
@@ -0,0 +1,62 @@
+import json
+import sys
+
+import pytest
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.tasks import Task, discover_tasks, resolve_task, run_task
+
+
+def _project(root):
+    (root / "Makefile").write_text(
+        ".PHONY: build\nbuild:\n\techo hi\nVERSION := 1\ntest: build\n"
+    )
+    (root / "justfile").write_text("# recipes\nlint:\n    ruff .\ntest arg='x':\n")
+    (root / "package.json").write_text(json.dumps({"scripts": {"dev": "vite"}}))
+    (root / "pnpm-lock.yaml").write_text("")
+    (root / ".cargo").mkdir()
+    (root / ".cargo" / "config.toml").write_text('[alias]\nxtask = "run -p xtask"\n')
+    return root
+
+
+def test_discover_tasks(tmp_path):
+    tasks = {(t.runner, t.name): t for t in discover_tasks(_project(tmp_path))}
+
+    assert set(tasks) == {
+        ("make", "build"),
+        ("make", "test"),
+        ("just", "lint"),
+        ("just", "test"),
+        ("npm", "dev"),
+        ("cargo", "xtask"),
+    }
+    assert tasks[("npm", "dev")].argv == ["pnpm", "run", "dev"]
+    assert tasks[("cargo", "xtask")].source == ".cargo/config.toml"
+
+
+def test_resolve_task_rejects_unknown_and_ambiguous(tmp_path):
+    _project(tmp_path)
+
+    assert resolve_task("test", "just", tmp_path).argv == ["just", "test"]
+    with pytest.raises(WorkflowError, match="pass a runner"):
+        resolve_task("test", None, tmp_path)
+    with pytest.raises(WorkflowError, match="not a task declared"):
+        resolve_task("rm -rf /", None, tmp_path)
+
+
+@pytest.mark.asyncio
+async def test_run_task_captures_output_and_times_out(tmp_path):
+    ok = Task(
+        name="hi",
+        runner="make",
+        argv=[sys.executable, "-c", "print('hello')"],
+        source="test",
+    )
+    result = await run_task(ok, timeout=30, cwd=str(tmp_path))
+    assert result.success and result.stdout.strip() == "hello"
+
+    slow = ok.model_copy(
+        update={"argv": [sys.executable, "-c", "import time; time.sleep(30)"]}
+    )
+    result = await run_task(slow, timeout=0.5, cwd=str(tmp_path))
+    assert result.timed_out and not result.success
@@ -0,0 +1,122 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core import prompts
+from azathoth.core.commit_policy import CommitPolicy
+from azathoth.core.templates import ASSET_DIR, TEMPLATE_SUFFIX, render_prompt
+
+
+@pytest.fixture
+def prompts_dir(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "prompts_path", tmp_path)
+    return tmp_path
+
+
+def test_user_template_overrides_the_default(prompts_dir):
+    (prompts_dir / f"autocommit{TEMPLATE_SUFFIX}").write_text(
+        "Commit{% if focus %} about {{ focus }}{% endif %}.\n{{ rules }}"
+    )
+
+    rendered = prompts.autocommit(focus="auth")
+
+    assert rendered.startswith("Commit about auth.\n")
+    assert "title" in rendered
+
+
+def test_builders_render_their_named_template(prompts_dir):
+    template = prompts_dir / f"explore{TEMPLATE_SUFFIX}"
+    template.write_text("Scout {{ target_directory }}")
+
+    assert prompts.explore("src") == "Scout src"
+    assert "`limit` set to 20" in prompts.autotriage()
+    assert set(prompts.__all__) == {
+        "explore",
+        "onboard",
+        "autocommit",
+        "autofix",
+        "autorelease",
+        "autotriage",
+        "commit_system",
+        "release_system",
+        "review",
+        "run_workflow",
+    }
+
+
+def test_broken_template_falls_back_to_the_default(prompts_dir):
+    (prompts_dir / f"explore{TEMPLATE_SUFFIX}").write_text("{{ unknown_variable }}")
+
+    rendered = render_prompt("explore", "Scout {{ target_directory }}", target_directory="x")
+
+    assert rendered == "Scout x"
+
+
+@pytest.mark.parametrize(
+    "name, default",
+    [
+        ("explore", prompts.EXPLORE_TEMPLATE),
+        ("onboard", prompts.ONBOARD_TEMPLATE),
+        ("review", prompts.REVIEW_TEMPLATE),
+        ("autocommit", prompts.AUTOCOMMIT_TEMPLATE),
+        ("autofix", prompts.AUTOFIX_TEMPLATE),
+        ("autorelease", prompts.AUTORELEASE_TEMPLATE),
+        ("autotriage", prompts.AUTOTRIAGE_TEMPLATE),
+        ("run-workflow", prompts.RUN_WORKFLOW_TEMPLATE),
+        ("commit-system", prompts.COMMIT_SYSTEM_TEMPLATE),
+        ("release-system", prompts.RELEASE_SYSTEM_TEMPLATE),
+    ],
+)
+def test_shipped_templates_match_the_built_in_defaults(name, default):
+    assert (ASSET_DIR / f"{name}{TEMPLATE_SUFFIX}").read_text() == default
+
+
+def test_explore_suggests_commands_for_the_host_platform():
+    assert "`ls -R`" in prompts.explore("src", windows=False)
+    windows = prompts.explore("src", windows=True)
+    assert "`Get-ChildItem -Recurse -Name`" in windows and "ls -R" not in windows
+
+
+def test_onboard_tailors_the_guide_to_the_role():
+    devops = prompts.onboard("svc", "devops")
+    assert "new devops developer" in devops and "scan_secrets" in devops
+    assert "## 6. Deployment & Operations" in devops
+    frontend = prompts.onboard("svc", "frontend")
+    assert "catalog_assets" in frontend and "scan_secrets" not in frontend
+
+    with pytest.raises(ValueError, match="Unknown role"):
+        prompts.onboard("svc", "designer")
+
+
+def test_review_posts_only_for_pull_requests():
+    args = (["src/a.rs"], ["rust"], ["core", "d-rust"], "## Errors\nUse thiserror.")
+    pr = prompts.review(*args, pr_number=42)
+    assert "pull request #42" in pr and "Use thiserror." in pr
+    assert "`pr_diff` with number=42" in pr and "`pr_review`" in pr
+
+    ranged = prompts.review(*args, revisions="main..HEAD")
+    assert 'revisions="main..HEAD"' in ranged and "pr_review" not in ranged
+    assert "for rust): core, d-rust" in ranged
+
+
+def test_test_gate_runs_the_tests_before_committing_or_releasing():
+    assert "run_tests" not in prompts.autocommit()
+    assert "do NOT commit" in prompts.autocommit(test_gate=True)
+    gated = prompts.autorelease(
+        "v1.1.0", "https://example.com/x", "v1.0.0", test_gate=True
+    )
+    assert gated.index("`run_tests`") < gated.index("**Previous Version:**")
+
+
+def test_zero_law_follows_the_forbidden_trailers():
+    assert "matching ^co-authored-by:, ^signed-off-by:" in prompts.autocommit()
+    custom = CommitPolicy(forbidden_trailers=["^Reviewed-by:"])
+    assert "matching ^Reviewed-by: (" in prompts.autocommit(policy=custom)
+    bare = prompts.autocommit(policy=CommitPolicy(forbidden_trailers=[]))
+    assert "ZERO LAW" not in bare
+
+
+def test_autofix_loops_and_optionally_commits():
+    rendered = prompts.autofix(max_iterations=3)
+    assert "at most 3 of them" in rendered and "`edit_file`" in rendered
+    assert "stage_and_commit" not in rendered
+    assert "`stage_and_commit`" in prompts.autofix(commit=True)
@@ -0,0 +1,51 @@
+from azathoth.config import get_config
+from azathoth.core.traverse import IgnoreRules, iter_files
+
+
+def _tree(root, files):
+    for name, text in files.items():
+        (root / name).parent.mkdir(parents=True, exist_ok=True)
+        (root / name).write_text(text)
+
+
+def test_iter_files_honours_ignore_files_and_config(tmp_path, monkeypatch):
+    _tree(
+        tmp_path,
+        {
+            ".gitignore": "*.log\n!keep.log\n/out/\ndocs/**/*.tmp\n",
+            "app/.ignore": "generated/\n",
+            "app/main.py": "",
+            "app/generated/api.py": "",
+            "app/out/kept.py": "",
+            "out/bundle.js": "",
+            "debug.log": "",
+            "keep.log": "",
+            "docs/a/b/draft.tmp": "",
+            "docs/index.md": "",
+            "big.min.js": "",
+        },
+    )
+    monkeypatch.setattr(get_config(), "scout_exclude", ["*.min.js"])
+
+    files = [p.relative_to(tmp_path).as_posix() for p in iter_files(tmp_path)]
+
+    assert files == [
+        ".gitignore",
+        "keep.log",
+        "app/.ignore",
+        "app/main.py",
+        "app/out/kept.py",
+        "docs/index.md",
+    ]
+
+    monkeypatch.setattr(get_config(), "scout_respect_gitignore", False)
+    assert "debug.log" in [p.name for p in iter_files(tmp_path)]
+
+
+def test_ignored_checks_parent_directories(tmp_path):
+    _tree(tmp_path, {"vendor/lib/x.py": "", "src/x.py": ""})
+    rules = IgnoreRules(tmp_path, ["vendor/"])
+
+    assert rules.ignored(tmp_path / "vendor" / "lib" / "x.py")
+    assert not rules.ignored(tmp_path / "src" / "x.py")
+    assert not rules.matches(tmp_path / "vendor" / "lib" / "x.py", False)
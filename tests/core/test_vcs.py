@@ -0,0 +1,49 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.vcs import detect_backend, get_vcs
+
+
+@pytest.fixture(autouse=True)
+def _defaults(monkeypatch):
+    monkeypatch.setattr(get_config(), "vcs_backend", "auto")
+    monkeypatch.setattr(get_config(), "vcs_experimental_backends", False)
+
+
+def test_detect_prefers_jj_in_colocated_repo(tmp_path):
+    (tmp_path / ".git").mkdir()
+    (tmp_path / ".jj").mkdir()
+    nested = tmp_path / "src" / "pkg"
+    nested.mkdir(parents=True)
+
+    assert detect_backend(str(nested)) == "jj"
+    (tmp_path / ".jj").rmdir()
+    assert detect_backend(str(nested)) == "git"
+
+
+def test_experimental_backends_are_gated(tmp_path, monkeypatch):
+    (tmp_path / ".hg").mkdir()
+
+    assert get_vcs(str(tmp_path)).name == "git"
+    with pytest.raises(WorkflowError, match="experimental"):
+        get_vcs(str(tmp_path), backend="hg")
+
+    monkeypatch.setattr(get_config(), "vcs_experimental_backends", True)
+    assert get_vcs(str(tmp_path)).name == "hg"
+
+
+@pytest.mark.asyncio
+async def test_git_backend_round_trip(git_repo):
+    vcs = get_vcs(str(git_repo))
+    (git_repo / "a.txt").write_text("a")
+
+    assert (await vcs.status()).untracked == ["a.txt"]
+    assert (await vcs.stage()).success
+    assert "a.txt" in await vcs.diff(staged=True)
+    assert (await vcs.commit("feat: add a", "")).success
+    assert (await vcs.tag("v0.1.0")).success
+
+    assert await vcs.latest_tag() == "v0.1.0"
+    assert [c.subject for c in await vcs.log()] == ["feat: add a"]
+    assert (await vcs.tag("v0.2.0", dry_run=True)).stdout == "git tag v0.2.0"
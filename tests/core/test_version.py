@@ -0,0 +1,51 @@
+import json
+
+import pytest
+
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.version import bump, find_manifest, write_version
+
+
+def test_bump_levels():
+    assert bump("1.2.3", "patch") == "1.2.4"
+    assert bump("1.2.3", "minor") == "1.3.0"
+    assert bump("v1.2.3-rc.1", "major") == "2.0.0"
+    with pytest.raises(WorkflowError):
+        bump("1.2", "patch")
+
+
+def test_cargo_preferred_and_rewritten_in_place(tmp_path):
+    (tmp_path / "pyproject.toml").write_text('[project]\nversion = "9.9.9"\n')
+    cargo = tmp_path / "Cargo.toml"
+    cargo.write_text(
+        "[package]\n"
+        'name = "demo"  # crate\n'
+        "version = '0.3.1'  # keep me\n"
+        "\n[dependencies]\n"
+        'serde = { version = "1" }\n'
+    )
+
+    manifest = find_manifest(tmp_path)
+    assert (manifest.kind, manifest.version) == ("cargo", "0.3.1")
+
+    write_version(manifest, "0.4.0")
+    text = cargo.read_text()
+    assert "version = '0.4.0'  # keep me" in text
+    assert 'serde = { version = "1" }' in text
+
+
+def test_package_json_and_refusals(tmp_path):
+    (tmp_path / "package.json").write_text(
+        json.dumps({"name": "x", "version": "1.0.0", "deps": {"version": "1.0.0"}})
+    )
+    manifest = find_manifest(tmp_path)
+    write_version(manifest, "1.0.1")
+    data = json.loads((tmp_path / "package.json").read_text())
+    assert data["version"] == "1.0.1"
+    assert data["deps"]["version"] == "1.0.0"
+
+    other = tmp_path / "ws"
+    other.mkdir()
+    (other / "Cargo.toml").write_text("[package]\nversion.workspace = true\n")
+    with pytest.raises(WorkflowError, match="workspace"):
+        find_manifest(other)
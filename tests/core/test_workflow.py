@@ -1,5 +1,6 @@
 import pytest
 from azathoth.core.workflow import stage_all, commit, get_diff
+from azathoth.dev.testing import GitRepo
 
 
 @pytest.mark.asyncio
@@ -21,7 +22,144 @@ async def test_workflow_full_cycle(git_repo):
     assert "feat: test" in res_commit.stdout
 
     # 5. Check Log (Verify commit exists)
-    import subprocess
+    assert GitRepo(git_repo).subjects()[0] == "feat: test"
 
-    log = subprocess.check_output(["git", "log"], cwd=git_repo).decode()
-    assert "feat: test" in log
+
+@pytest.mark.asyncio
+async def test_dry_run_does_not_mutate(git_repo):
+    from azathoth.core.workflow import create_release
+
+    (git_repo / "new.txt").write_text("Change")
+
+    res_stage = await stage_all(cwd=str(git_repo), dry_run=True)
+    assert res_stage.stdout == "git add ."
+    assert await get_diff(staged=True, cwd=str(git_repo)) == ""
+
+    res_commit = await commit("feat: test", "body", cwd=str(git_repo), dry_run=True)
+    assert res_commit.success
+    assert "git commit -F" in res_commit.stdout
+    assert "feat: test" in res_commit.stdout
+
+    res_release = await create_release("v1.0.0", "notes", dry_run=True)
+    assert res_release.stdout.splitlines()[:2] == [
+        "git tag -a v1.0.0 -m 'Release v1.0.0'",
+        "git push origin v1.0.0",
+    ]
+    assert "gh release create v1.0.0" in res_release.stdout
+
+
+@pytest.mark.asyncio
+async def test_structured_status_diff_and_log(git_repo):
+    from azathoth.core.exceptions import WorkflowError
+    from azathoth.core.workflow import (
+        get_diff_summary,
+        get_log_entries,
+        get_repo_status,
+    )
+
+    repo = GitRepo(git_repo)
+    repo.commit("init", {"tracked.txt": "one\n"})
+
+    repo.write("tracked.txt", "one\ntwo\n")
+    repo.write("staged.txt", "a\nb\nc\n")
+    repo.git("add", "staged.txt")
+    repo.write("loose.txt", "x")
+
+    status = await get_repo_status(cwd=str(git_repo))
+    assert status.branch == repo.git("rev-parse", "--abbrev-ref", "HEAD")
+    assert [(f.path, f.status, f.insertions) for f in status.staged] == [
+        ("staged.txt", "A", 3)
+    ]
+    assert [(f.path, f.status, f.insertions) for f in status.unstaged] == [
+        ("tracked.txt", "M", 1)
+    ]
+    assert status.untracked == ["loose.txt"]
+    assert not status.is_clean
+
+    diff = await get_diff_summary(staged=True, cwd=str(git_repo))
+    assert [f.path for f in diff.files] == ["staged.txt"]
+    assert diff.insertions == 3 and diff.deletions == 0
+    assert "+b" in diff.patch
+
+    log = await get_log_entries(cwd=str(git_repo))
+    assert [c.subject for c in log] == ["init"]
+    assert log[0].author == "Your Name"
+
+    with pytest.raises(WorkflowError, match="Invalid revision"):
+        await get_log_entries("--output=/tmp/x", cwd=str(git_repo))
+    with pytest.raises(WorkflowError, match="git log failed: .*no-such-ref"):
+        await get_log_entries("no-such-ref", cwd=str(git_repo))
+
+
+@pytest.mark.asyncio
+async def test_bare_and_shallow_repos(git_repo, tmp_path):
+    from azathoth.core.changelog import generate_changelog
+    from azathoth.core.workflow import deepen_until, get_repo_context, get_repo_status
+
+    repo = GitRepo(git_repo)
+    for n in range(3):
+        repo.commit(f"feat: c{n}", {f"c{n}.txt": str(n)})
+    repo.git("tag", "v0.1.0", "HEAD~2")
+
+    bare = tmp_path / "bare.git"
+    repo.git("clone", "-q", "--bare", str(git_repo), str(bare))
+    context = await get_repo_context(str(bare))
+    assert context.is_bare and context.shape == "bare"
+    result = await commit("feat: nope", "", cwd=str(bare))
+    assert not result.success and "bare" in result.message
+    assert (await get_repo_status(str(bare))).shape == "bare"
+
+    shallow = tmp_path / "shallow"
+    repo.git("clone", "-q", "--depth=1", f"file://{git_repo}", str(shallow))
+    assert (await get_repo_context(str(shallow))).shape == "shallow"
+    with pytest.raises(ValueError, match="Shallow clone"):
+        await generate_changelog("v0.1.0", cwd=str(shallow))
+
+    assert await deepen_until("v0.1.0", cwd=str(shallow))
+    changelog = await generate_changelog("v0.1.0", cwd=str(shallow))
+    assert len(changelog.commits) == 2
+
+
+@pytest.mark.asyncio
+async def test_selective_staging_leaves_other_work(git_repo):
+    (git_repo / "tracked.txt").write_text("v1")
+    await stage_all(cwd=str(git_repo))
+    await commit("chore: init", "", cwd=str(git_repo))
+
+    (git_repo / "tracked.txt").write_text("v2")
+    (git_repo / "wanted.txt").write_text("new")
+    (git_repo / "wip.txt").write_text("unrelated")
+
+    plan = await stage_all(cwd=str(git_repo), dry_run=True, paths=["wanted.txt"])
+    assert plan.stdout == "git add -- wanted.txt"
+
+    res = await stage_all(cwd=str(git_repo), include_untracked=False)
+    assert res.success
+    res = await stage_all(cwd=str(git_repo), paths=["wanted.txt"])
+    assert res.success
+    res = await commit("feat: wanted", "", cwd=str(git_repo), paths=["wanted.txt"])
+    assert res.success
+
+    committed = GitRepo(git_repo).git("show", "--name-only", "--pretty=format:")
+    assert committed.split() == ["wanted.txt"]
+    diff = await get_diff(staged=True, cwd=str(git_repo))
+    assert "tracked.txt" in diff  # still staged, not committed
+    assert "wip.txt" not in diff
+
+
+@pytest.mark.asyncio
+async def test_range_diff(git_repo):
+    from azathoth.core.exceptions import WorkflowError
+    from azathoth.core.workflow import get_range_diff
+
+    for name in ("README.md", "lib.rs"):
+        (git_repo / name).write_text("fn main() {}\n")
+        await stage_all(cwd=str(git_repo))
+        await commit(f"feat: {name}", "", cwd=str(git_repo))
+
+    diff = await get_range_diff("HEAD~1..HEAD", cwd=str(git_repo))
+    assert "diff --git a/lib.rs b/lib.rs" in diff and "README" not in diff
+
+    for bad in ("--output=/tmp/x", "no-such-ref..HEAD"):
+        with pytest.raises(WorkflowError):
+            await get_range_diff(bad, cwd=str(git_repo))
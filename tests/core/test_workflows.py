@@ -0,0 +1,108 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core import prompts
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.workflows import (
+    list_workflows,
+    load_workflow,
+    parse_workflow,
+    plan_workflow,
+)
+
+_SHIP = """
+name: ship
+inputs:
+  release: {default: false}
+  level: {required: true}
+steps:
+  - id: check
+    tool: preflight
+  - id: bump
+    tool: bump_version
+    when: release and level != "none"
+    with: {level: "{{ level }}", dry_run: "{{ not release }}", note: "bump {{ level }}"}
+  - id: publish
+    prompt: autorelease
+    when: "{{ release }}"
+    condition: the bump succeeded
+    on_failure: continue
+"""
+
+
+@pytest.fixture
+def user_workflows(tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "workflows_path", tmp_path / "user")
+    (tmp_path / "user").mkdir()
+    return tmp_path / "user"
+
+
+def test_plan_renders_arguments_and_drops_steps_by_when():
+    definition = parse_workflow(_SHIP)
+
+    plan = plan_workflow(definition, {"release": True, "level": "minor"})
+    quiet = plan_workflow(definition, {"level": "minor"})
+
+    assert [s.id for s in plan.steps] == ["check", "bump", "publish"]
+    assert plan.steps[1].arguments == {
+        "level": "minor",
+        "dry_run": False,
+        "note": "bump minor",
+    }
+    assert plan.steps[2].condition == "the bump succeeded"
+    assert [s.id for s in quiet.steps] == ["check"]
+    assert quiet.skipped == ["bump", "publish"]
+    with pytest.raises(WorkflowError, match="needs input 'level'"):
+        plan_workflow(definition)
+    with pytest.raises(WorkflowError, match="no input"):
+        plan_workflow(definition, {"level": "x", "colour": "red"})
+
+
+@pytest.mark.parametrize(
+    "text, problem",
+    [
+        ("steps: [", "Invalid YAML"),
+        ("name: x\nsteps: []", "at least 1"),
+        ("name: x\nsteps: [{id: a}]", "exactly one of tool or prompt"),
+        ("name: x\nsteps: [{id: a, prompt: deploy}]", "unknown prompt 'deploy'"),
+        ("name: x\nsteps: [{id: a, tool: t}, {id: a, tool: u}]", "duplicate step id"),
+        ("name: x\nsteps: [{id: a, tool: t, retries: 3}]", "retries"),
+    ],
+)
+def test_invalid_definitions_are_reported(text, problem):
+    with pytest.raises(WorkflowError, match=problem):
+        parse_workflow(text, "x.yaml")
+
+
+def test_repository_workflows_override_user_and_stock_ones(git_repo, user_workflows):
+    repo_dir = git_repo / ".azathoth" / "workflows"
+    repo_dir.mkdir(parents=True)
+    (repo_dir / "ship.yaml").write_text("steps: [{id: only, tool: preflight}]")
+    (user_workflows / "triage.yml").write_text(
+        "description: Mine\nsteps: [{id: t, prompt: autotriage}]"
+    )
+
+    names = {w.name: w for w in list_workflows(git_repo)}
+
+    assert [s.id for s in names["ship"].steps] == ["only"]
+    assert names["triage"].description == "Mine"
+    assert load_workflow("triage", git_repo).source.endswith("triage.yml")
+    with pytest.raises(WorkflowError, match="available: .*ship"):
+        load_workflow("deploy", git_repo)
+
+
+def test_stock_ship_workflow_renders(git_repo, user_workflows):
+    plan = plan_workflow(load_workflow("ship", git_repo), {"release": True})
+
+    rendered = prompts.run_workflow(plan, {"commit": "Commit it.", "release": "Ok."})
+
+    assert [s.id for s in plan.steps] == [
+        "preflight",
+        "commit",
+        "version",
+        "push",
+        "release",
+    ]
+    assert "2.  **Commit**: Follow the `autocommit` instructions below." in rendered
+    assert "    Ok.\n" in rendered
+    assert "(only if the branch has an upstream and is ahead of it)" in rendered
@@ -0,0 +1,124 @@
+import json
+
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.workflow import GitResult
+from azathoth.core.workspace import (
+    discover_workspace,
+    plan_workspace_release,
+    release_order,
+    release_workspace,
+)
+from azathoth.dev.testing import GitRepo
+
+
+def _crate(name, version, deps=""):
+    return {
+        f"crates/{name}/Cargo.toml": (
+            f'[package]\nname = "{name}"\nversion = "{version}"\n\n'
+            f"[dependencies]\n{deps}"
+        ),
+        f"crates/{name}/src/lib.rs": "",
+    }
+
+
+@pytest.fixture
+def cargo_workspace(git_repo, monkeypatch):
+    monkeypatch.setattr(get_config(), "mutations_paused", False)
+    repo = GitRepo(git_repo)
+    repo.commit(
+        "chore: init",
+        {
+            "Cargo.toml": (
+                '[workspace]\nmembers = ["crates/*"]\nexclude = ["crates/scratch"]\n'
+            ),
+            **_crate("core", "0.2.0"),
+            **_crate("cli", "1.0.0", 'core = { path = "../core" }\n'),
+            **_crate("scratch", "0.0.1"),
+        },
+    )
+    repo.tag("core-v0.2.0")
+    repo.tag("cli-v1.0.0")
+    return repo
+
+
+def test_discover_cargo_workspace(cargo_workspace):
+    packages = discover_workspace(cargo_workspace.path)
+
+    assert {p.name: p.dependencies for p in packages} == {"cli": ["core"], "core": []}
+    assert [p.name for p in release_order(packages)] == ["core", "cli"]
+
+
+def test_discover_pnpm_and_npm_workspaces(tmp_path):
+    (tmp_path / "pnpm-workspace.yaml").write_text(
+        "packages:\n  - 'packages/*'\n  - '!packages/legacy'\n"
+    )
+    members = {"ui": {}, "app": {"@acme/ui": "workspace:*"}, "legacy": {}}
+    for name, deps in members.items():
+        manifest = {"name": f"@acme/{name}", "version": "1.0.0", "dependencies": deps}
+        (tmp_path / "packages" / name).mkdir(parents=True)
+        (tmp_path / "packages" / name / "package.json").write_text(json.dumps(manifest))
+
+    packages = discover_workspace(tmp_path)
+
+    assert {p.name: p.dependencies for p in packages} == {
+        "@acme/app": ["@acme/ui"],
+        "@acme/ui": [],
+    }
+
+    (tmp_path / "pnpm-workspace.yaml").unlink()
+    (tmp_path / "package.json").write_text(json.dumps({"workspaces": ["packages/*"]}))
+    assert len(discover_workspace(tmp_path)) == 3
+    with pytest.raises(WorkflowError, match="No workspace"):
+        discover_workspace(tmp_path / "packages")
+
+
+def test_release_order_rejects_cycles(tmp_path):
+    (tmp_path / "package.json").write_text(json.dumps({"workspaces": ["a", "b"]}))
+    for name, dep in [("a", "b"), ("b", "a")]:
+        (tmp_path / name).mkdir()
+        (tmp_path / name / "package.json").write_text(
+            json.dumps({"name": name, "version": "1.0.0", "dependencies": {dep: "*"}})
+        )
+
+    with pytest.raises(WorkflowError, match="cycle"):
+        release_order(discover_workspace(tmp_path))
+
+
+@pytest.mark.asyncio
+async def test_plan_and_release_changed_packages(cargo_workspace, monkeypatch):
+    root = cargo_workspace.path
+    cargo_workspace.commit(
+        "feat(core): add f", {"crates/core/src/lib.rs": "pub fn f() {}\n"}
+    )
+    cargo_workspace.commit("fix(cli): typo", {"crates/cli/src/lib.rs": "// fix\n"})
+
+    plan = await plan_workspace_release(root, cwd=str(root))
+
+    assert [(p.name, p.new_version, p.tag) for p in plan.packages] == [
+        ("core", "0.3.0", "core-v0.3.0"),
+        ("cli", "1.0.1", "cli-v1.0.1"),
+    ]
+    assert plan.unchanged == []
+    assert "add f" in plan.packages[0].notes
+    assert "typo" not in plan.packages[0].notes
+    only_cli = await plan_workspace_release(root, only=["cli"], cwd=str(root))
+    assert [p.name for p in only_cli.packages] == ["cli"]
+
+    released = []
+
+    async def create_release(tag, notes, is_prerelease=False, dry_run=False, cwd=None):
+        released.append(tag)
+        return GitResult(success=tag != "cli-v1.0.1", stdout="", stderr="boom")
+
+    monkeypatch.setattr("azathoth.core.workspace.create_release", create_release)
+    result = await release_workspace(plan, cwd=str(root))
+
+    assert result.committed and result.released == ["core-v0.3.0"]
+    assert result.error.startswith("cli-v1.0.1")
+    assert released == ["core-v0.3.0", "cli-v1.0.1"]
+    manifest = (root / "crates" / "core" / "Cargo.toml").read_text()
+    assert 'version = "0.3.0"' in manifest
+    assert cargo_workspace.subjects()[0] == "chore(release): release 2 packages"
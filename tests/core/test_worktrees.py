@@ -0,0 +1,80 @@
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core.exceptions import WorkflowError
+from azathoth.core.workflow import get_repo_status
+from azathoth.core.worktrees import (
+    create_worktree,
+    list_worktrees,
+    parse_worktree_list,
+    worktree_path,
+)
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def repo(git_repo, tmp_path, monkeypatch):
+    repo = GitRepo(git_repo)
+    repo.commit("init", {"a.txt": "a"})
+    repo.git("branch", "-M", "main")
+    monkeypatch.setattr(get_config(), "workflow_worktree_dir", tmp_path / "wt")
+    return repo
+
+
+def test_parse_worktree_list():
+    porcelain = (
+        "worktree /src/app\nHEAD abc\nbranch refs/heads/main\n\n"
+        "worktree /tmp/app-fix\nHEAD def\ndetached\nlocked in use\n"
+    )
+
+    main, fix = parse_worktree_list(porcelain, current="/tmp/app-fix")
+
+    assert (main.path, main.branch, main.current) == ("/src/app", "main", False)
+    assert (fix.branch, fix.detached, fix.locked, fix.current) == (
+        None,
+        True,
+        True,
+        True,
+    )
+
+
+@pytest.mark.asyncio
+async def test_create_worktree_for_new_and_existing_branch(repo, tmp_path):
+    cwd = str(repo.path)
+    target = await worktree_path("feature/x", cwd=cwd)
+    assert target == (tmp_path / "wt" / repo.path.name / "feature-x").resolve()
+
+    assert (await create_worktree("feature/x", target, cwd=cwd)).success
+    assert GitRepo(target).git("branch", "--show-current") == "feature/x"
+    assert repo.git("branch", "--show-current") == "main"
+
+    status = await get_repo_status(str(target))
+    assert status.linked_worktree and status.worktree == str(target)
+    assert not (await get_repo_status(cwd)).linked_worktree
+
+    refused = await create_worktree("main", tmp_path / "other", cwd=str(target))
+    assert not refused.success and str(repo.path) in refused.message
+
+    repo.git("branch", "topic")
+    planned = await create_worktree("topic", tmp_path / "t", cwd=cwd, dry_run=True)
+    assert planned.stdout == f"git worktree add {tmp_path / 't'} topic"
+
+    worktrees = {w.branch: w for w in await list_worktrees(str(target))}
+    assert set(worktrees) == {"main", "feature/x"}
+    assert worktrees["feature/x"].current and not worktrees["main"].current
+
+
+@pytest.mark.asyncio
+async def test_worktree_paths_and_start_points_are_confined(repo, tmp_path):
+    cwd = str(repo.path)
+    home = (tmp_path / "wt" / repo.path.name).resolve()
+
+    assert await worktree_path("topic", "work/topic", cwd=cwd) == home / "work/topic"
+    assert (await worktree_path("topic", "~/.config", cwd=cwd)).is_relative_to(home)
+    for path in ("../elsewhere", str(tmp_path / "elsewhere")):
+        with pytest.raises(WorkflowError, match="Worktrees stay in"):
+            await worktree_path("topic", path, cwd=cwd)
+
+    res = await create_worktree("topic", home / "t", "--detach", cwd=cwd)
+    assert not res.success and "Invalid revision" in res.message
+    assert not (home / "t").exists()
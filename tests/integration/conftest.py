@@ -0,0 +1,36 @@
+"""Scenario fixtures: a throwaway repo on ``main`` with the workflow server
+pointed at it, an isolated config dir and a scripted LLM."""
+
+import json
+
+import pytest
+
+from azathoth.config import get_config
+from azathoth.core import policy
+from azathoth.dev.testing import GitRepo
+
+
+@pytest.fixture
+def repo(git_repo, tmp_path, monkeypatch):
+    builder = GitRepo(git_repo)
+    builder.git("symbolic-ref", "HEAD", "refs/heads/main")
+    builder.commit("chore: initial commit", {"README.md": "# demo\n"})
+    monkeypatch.chdir(git_repo)
+    monkeypatch.setattr(get_config(), "config_dir", tmp_path / "config")
+    monkeypatch.setattr(get_config(), "workflow_dry_run", False)
+    monkeypatch.setattr(get_config(), "mutations_paused", False)
+    monkeypatch.setattr(policy, "_paused_by_agent", False)
+    monkeypatch.setattr(get_config(), "release_backend", "github")
+    return git_repo
+
+
+@pytest.fixture
+def llm(monkeypatch):
+    """Queue of JSON replies returned, in order, by the workflow's LLM calls."""
+    replies: list[dict] = []
+
+    async def generate(system_prompt, user_msg, json_mode=False):
+        return json.dumps(replies.pop(0))
+
+    monkeypatch.setattr("azathoth.mcp.workflow.generate", generate)
+    return replies
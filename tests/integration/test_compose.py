@@ -0,0 +1,51 @@
+"""CompositeServer: prefixed mounting, name routing and conflict detection."""
+
+import pytest
+from fastmcp import Client, FastMCP
+
+from azathoth.core.exceptions import CompositionError
+from azathoth.mcp.compose import CompositeServer
+
+
+def _server(name, *tools):
+    server = FastMCP(name=name)
+    for tool in tools:
+
+        async def fn(text: str = "") -> str:
+            return f"{name}:{text}"
+
+        fn.__name__ = tool
+        server.tool(fn)
+    return server
+
+
+@pytest.mark.asyncio
+async def test_prefixed_members_are_listed_and_called_by_exposed_name():
+    composite = CompositeServer("both")
+    composite.add(_server("a", "echo", "read_file"), prefix="a")
+    composite.add(_server("b", "echo"), prefix="b")
+
+    async with Client(composite) as client:
+        names = {tool.name for tool in await client.list_tools()}
+        assert names == {"a.echo", "a.read_file", "b.echo"}
+        assert (await client.call_tool("b.echo", {"text": "hi"})).data == "b:hi"
+        assert (await client.call_tool("a.read_file", {})).data == "a:"
+    await composite.check()
+
+
+@pytest.mark.asyncio
+async def test_clashing_prefixes_and_names_are_refused():
+    composite = CompositeServer("clash")
+    composite.add(_server("git", "status"), prefix="git")
+    with pytest.raises(CompositionError, match="already in use"):
+        composite.add(_server("other"), prefix="git")
+    with pytest.raises(CompositionError, match="overlap"):
+        composite.add(_server("other"), prefix="git_extra")
+    with pytest.raises(CompositionError, match="separator"):
+        composite.add(_server("other"), prefix="a.b")
+
+    composite.add(_server("x", "lint", "fmt"))
+    composite.add(_server("y", "lint"))
+    assert await composite.conflicts() == {"tool lint": ["x:lint", "y:lint"]}
+    with pytest.raises(CompositionError, match="tool lint ← x:lint, y:lint"):
+        await composite.check()
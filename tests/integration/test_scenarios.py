@@ -0,0 +1,672 @@
+"""End-to-end workflow scenarios: real git repositories driven through the
+workflow server's full tool pipeline (audit, mutation guard, dynamic
+defaults) with an in-process client, asserting on the resulting repo state."""
+
+import asyncio
+import json
+import subprocess
+from types import SimpleNamespace
+
+import pytest
+from fastmcp.exceptions import ToolError
+from mcp.types import ElicitResult
+from pydantic import SecretStr
+
+from azathoth.config import get_config
+from azathoth.core.llm import LLMError
+from azathoth.dev.testing import GitRepo, ServerHarness
+from azathoth.mcp.telemetry import Telemetry
+from azathoth.mcp.unified import mcp as unified
+from azathoth.mcp.workflow import mcp
+
+
+def _git(repo, *args):
+    return GitRepo(repo).git(*args)
+
+
+def _commit(repo, name, content, message):
+    GitRepo(repo).commit(message, {name: content})
+
+
+def _subjects(repo, rev_range="HEAD"):
+    return GitRepo(repo).subjects(rev_range)
+
+
+async def _call(tool, **arguments):
+    async with ServerHarness(mcp) as server:
+        return await server.call(tool, **arguments)
+
+
+@pytest.mark.asyncio
+async def test_commit_only_requested_paths(repo, llm):
+    _git(repo, "switch", "-qc", "feat/app")
+    (repo / "app.py").write_text("print('hi')\n")
+    (repo / "scratch.txt").write_text("wip\n")
+    llm.append({"title": "feat: add app entry point", "body": ""})
+
+    result = await _call("stage_and_commit", paths=["app.py"])
+
+    assert (result.committed, result.title) == (True, "feat: add app entry point")
+    assert (result.branch, result.files_changed, result.insertions) == (
+        "feat/app",
+        1,
+        1,
+    )
+    assert result.sha == _git(repo, "rev-parse", "HEAD")
+    assert not result.signature.signed
+    assert _subjects(repo)[0] == "feat: add app entry point"
+    assert _git(repo, "show", "--name-only", "--format=", "HEAD") == "app.py"
+    assert "?? scratch.txt" in _git(repo, "status", "--porcelain")
+
+
+@pytest.mark.asyncio
+async def test_commit_message_from_the_client_when_no_provider_answers(
+    repo, monkeypatch
+):
+    async def no_provider(system_prompt, user_msg, json_mode=False):
+        raise LLMError("no provider configured")
+
+    async def client_model(messages, params, context):
+        assert "app.py" in messages[0].content.text
+        return '```json\n{"title": "feat: add app entry point"}\n```'
+
+    monkeypatch.setattr("azathoth.mcp.workflow.generate", no_provider)
+    _git(repo, "switch", "-qc", "feat/app")
+    (repo / "app.py").write_text("print('hi')\n")
+
+    with pytest.raises(ToolError, match="LLM error"):
+        await _call("stage_and_commit")
+    async with ServerHarness(mcp, sampling_handler=client_model) as server:
+        sampled = await server.call("stage_and_commit")
+    (repo / "app.py").write_text("print('hello')\n")
+    given = await _call("stage_and_commit", title="fix: greet properly")
+
+    assert sampled.title == "feat: add app entry point"
+    assert given.committed
+    assert _subjects(repo) == [
+        "fix: greet properly",
+        "feat: add app entry point",
+        "chore: initial commit",
+    ]
+
+
+@pytest.mark.asyncio
+async def test_stash_unrelated_work_around_a_commit(repo, llm):
+    _git(repo, "switch", "-qc", "feat/app")
+    (repo / "app.py").write_text("print('hi')\n")
+    (repo / "README.md").write_text("# demo\n\nhalf-written docs\n")
+
+    shelved = await _call("stash_save", message="docs wip", paths=["README.md"])
+    assert (shelved.done, shelved.summary) == (True, "Stashed: docs wip")
+    [entry] = await _call("stash_list")
+    assert (entry.message, entry.files) == ("docs wip", ["README.md"])
+
+    llm.append({"title": "feat: add app", "body": ""})
+    await _call("stage_and_commit")
+    assert _git(repo, "show", "--name-only", "--format=", "HEAD") == "app.py"
+
+    assert (await _call("stash_pop")).summary == "Restored stash@{0}"
+    assert "half-written" in (repo / "README.md").read_text()
+    assert await _call("stash_list") == []
+
+
+@pytest.mark.asyncio
+async def test_commit_dry_run_changes_nothing(repo, llm):
+    (repo / "README.md").write_text("# demo\n\nUsage notes.\n")
+    llm.append({"title": "docs: add usage notes", "body": ""})
+
+    result = await _call("stage_and_commit", dry_run=True)
+
+    assert not result.committed
+    assert result.title == "docs: add usage notes"
+    assert result.commands[-1].startswith("git commit -F")
+    assert _subjects(repo) == ["chore: initial commit"]
+    assert _git(repo, "diff", "--cached", "--name-only") == ""
+
+
+@pytest.mark.asyncio
+async def test_failing_pre_commit_hook_blocks_commit_until_skipped(repo, llm):
+    _git(repo, "switch", "-qc", "feat/app")
+    hook = repo / ".git" / "hooks" / "pre-commit"
+    hook.write_text("#!/bin/sh\necho 'debug print left in app.py' >&2\nexit 1\n")
+    hook.chmod(0o755)
+    (repo / "app.py").write_text("print('debug')\n")
+
+    with pytest.raises(ToolError, match="Pre-commit hook failed") as blocked:
+        await _call("stage_and_commit")
+
+    assert "debug print left in app.py" in str(blocked.value)
+    assert _subjects(repo) == ["chore: initial commit"]
+
+    llm.append({"title": "feat: add app", "body": ""})
+    assert (await _call("stage_and_commit", skip_hooks=True)).committed
+    assert _subjects(repo)[0] == "feat: add app"
+
+
+@pytest.mark.asyncio
+async def test_commit_refused_when_staged_changes_hold_secrets(repo, llm):
+    _git(repo, "switch", "-qc", "feat/settings")
+    (repo / "settings.py").write_text("KEY = '" + "AKIA" + "IOSFODNN7EXAMPLE'\n")
+    (repo / ".env").write_text("DEBUG=1\n")
+
+    with pytest.raises(ToolError, match="Commit refused") as refused:
+        await _call("stage_and_commit", block_secrets=True)
+
+    assert "settings.py:1  aws_access_key" in str(refused.value)
+    assert ".env  env_file" in str(refused.value)
+    assert _subjects(repo) == ["chore: initial commit"]
+
+
+@pytest.mark.asyncio
+async def test_protected_branches_refuse_commits_without_opt_in(repo, llm):
+    (repo / "app.py").write_text("print('hi')\n")
+
+    with pytest.raises(ToolError, match="protected branch 'main'"):
+        await _call("stage_and_commit")
+    _git(repo, "switch", "-qc", "release/1.x")
+    with pytest.raises(ToolError, match="protected branch 'release/1.x'"):
+        await _call("bump_version", level="patch")
+    assert _subjects(repo) == ["chore: initial commit"]
+
+    llm.append({"title": "feat: add app", "body": ""})
+    result = await _call("stage_and_commit", allow_protected=True)
+
+    assert (result.committed, result.branch) == (True, "release/1.x")
+
+
+@pytest.mark.asyncio
+async def test_protected_branches_refuse_pushes_without_opt_in(repo, tmp_path):
+    _git(tmp_path, "init", "-q", "--bare", "remote.git")
+    _git(repo, "remote", "add", "origin", str(tmp_path / "remote.git"))
+
+    with pytest.raises(ToolError, match="'push' would write .* branch 'main'"):
+        await _call("push", set_upstream=True)
+    with pytest.raises(ToolError, match="protected branch 'main'"):
+        await _call("create_pull_request", title="feat: x", head="main")
+    _git(repo, "switch", "-qc", "feat/app")
+    with pytest.raises(ToolError, match="protected branch 'main'"):
+        await _call("push", branch="main", set_upstream=True)
+    assert _git(tmp_path / "remote.git", "branch", "--list") == ""
+
+    pushed = await _call("push", branch="main", set_upstream=True, allow_protected=True)
+
+    assert pushed.done
+    assert _git(tmp_path / "remote.git", "branch", "--list") == "main"
+
+
+@pytest.mark.asyncio
+async def test_push_refuses_refspecs_aimed_at_protected_branches(repo, tmp_path):
+    _git(tmp_path, "init", "-q", "--bare", "remote.git")
+    _git(repo, "remote", "add", "origin", str(tmp_path / "remote.git"))
+    _git(repo, "switch", "-qc", "feat/app")
+
+    for branch in ("HEAD:main", "feat/app:refs/heads/main", "+main"):
+        for force in (False, True):
+            with pytest.raises(ToolError, match="not a refspec"):
+                await _call(
+                    "push", branch=branch, set_upstream=True, force_with_lease=force
+                )
+    assert _git(tmp_path / "remote.git", "branch", "--list") == ""
+
+
+@pytest.mark.asyncio
+async def test_commits_follow_the_repo_signing_setup(repo, llm, tmp_path):
+    _git(repo, "switch", "-qc", "feat/app")
+    key = tmp_path / "signing_key"
+    subprocess.run(
+        ["ssh-keygen", "-q", "-t", "ed25519", "-N", "", "-f", str(key)], check=True
+    )
+    _git(repo, "config", "gpg.format", "ssh")
+    _git(repo, "config", "user.signingkey", str(tmp_path / "missing.pub"))
+    _git(repo, "config", "commit.gpgsign", "true")
+    (repo / "app.py").write_text("print('hi')\n")
+    llm.append({"title": "feat: add app", "body": ""})
+
+    with pytest.raises(ToolError, match=r"Signing failed \(missing_key\)"):
+        await _call("stage_and_commit")
+    assert _subjects(repo) == ["chore: initial commit"]
+
+    _git(repo, "config", "user.signingkey", f"{key}.pub")
+    llm.append({"title": "feat: add app", "body": ""})
+    result = await _call("stage_and_commit")
+
+    assert (result.signature.signed, result.signature.format) == (True, "ssh")
+    assert "gpgsig" in _git(repo, "cat-file", "commit", "HEAD")
+
+
+@pytest.mark.asyncio
+async def test_branch_lifecycle(repo):
+    assert (await _call("create_branch", name="feature/login")).done
+    assert _git(repo, "branch", "--show-current") == "feature/login"
+    _commit(repo, "login.py", "", "feat: login form")
+
+    assert (await _call("switch_branch", name="main")).done
+    with pytest.raises(ToolError):
+        await _call("delete_branch", name="feature/login")
+    assert "feature/login" in _git(repo, "branch", "--list", "feature/login")
+
+    with pytest.raises(ToolError):
+        await _call("delete_branch", name="main", force=True)
+    assert (await _call("delete_branch", name="feature/login", force=True)).done
+    assert _git(repo, "branch", "--list", "feature/login") == ""
+
+
+@pytest.mark.asyncio
+async def test_undo_last_reverts_what_the_agent_did(repo, llm):
+    assert (await _call("create_branch", name="feat/app")).done
+    (repo / "app.py").write_text("print('hi')\n")
+    llm.append({"title": "feat: add app entry point", "body": ""})
+    await _call("stage_and_commit")
+
+    log = await _call("session_log")
+    undone = await _call("undo_last")
+
+    assert [(op.kind, op.tool) for op in log.operations] == [
+        ("branch", "create_branch"),
+        ("commit", "stage_and_commit"),
+    ]
+    assert undone.summary.startswith("Undid commit")
+    assert _subjects(repo) == ["chore: initial commit"]
+    assert "A  app.py" in _git(repo, "status", "--porcelain")
+    _git(repo, "reset", "-q")
+    undone = await _call("undo_last")
+    assert undone.summary == "Undid branch feat/app (create_branch)."
+    assert _git(repo, "branch", "--show-current") == "main"
+
+
+@pytest.mark.asyncio
+async def test_risky_deletes_and_force_pushes_ask_the_user(repo):
+    questions: list[str] = []
+
+    def answer(reply):
+        async def handler(message, response_type, params, context):
+            questions.append(message)
+            return reply
+
+        return handler
+
+    _git(repo, "branch", "feature/login")
+    _git(repo, "switch", "-q", "feature/login")
+    _commit(repo, "login.py", "", "feat: login form")
+    _git(repo, "switch", "-q", "main")
+    _git(repo, "tag", "v0.1.0")
+
+    decline = answer(ElicitResult(action="decline"))
+    async with ServerHarness(mcp, elicitation_handler=decline) as server:
+        with pytest.raises(ToolError):
+            await server.call("delete_branch", name="feature/login")
+        with pytest.raises(ToolError, match="cancelled by the user"):
+            await server.call(
+                "push", branch="feature/login", force_with_lease=True
+            )
+    async with ServerHarness(mcp, elicitation_handler=answer({})) as server:
+        assert (await server.call("delete_branch", name="feature/login")).done
+        assert (await server.call("delete_tag", name="v0.1.0")).done
+
+    assert "not merged" in questions[0]
+    assert questions[1].startswith("Force-push 'feature/login'?")
+    assert questions[3] == "Delete tag 'v0.1.0'?"
+    assert _git(repo, "branch", "--list", "feature/login") == ""
+    assert _git(repo, "tag", "--list") == ""
+
+
+@pytest.mark.asyncio
+async def test_worktree_for_a_second_branch(repo, tmp_path, monkeypatch):
+    monkeypatch.setattr(get_config(), "workflow_worktree_dir", tmp_path / "wt")
+
+    result = await _call("create_worktree", branch="fix/typo")
+
+    path = result.summary.split(": ", 1)[1]
+    assert result.summary.startswith("Created worktree for fix/typo")
+    assert _git(path, "branch", "--show-current") == "fix/typo"
+    assert _git(repo, "branch", "--show-current") == "main"
+    assert (await _call("get_status")).worktree == str(repo)
+    assert [w.branch for w in await _call("list_worktrees")] == ["main", "fix/typo"]
+
+
+@pytest.mark.asyncio
+async def test_changelog_and_release_plan_since_tag(repo):
+    _git(repo, "tag", "v0.1.0")
+    _commit(repo, "a.py", "", "feat: add exporter")
+    _commit(repo, "b.py", "", "fix: handle empty input")
+
+    changelog = await _call("generate_changelog")
+    assert "add exporter" in changelog and "handle empty input" in changelog
+    assert changelog.index("add exporter") < changelog.index("handle empty input")
+
+    suggestion = await _call("suggest_next_version")
+    assert (suggestion.current, suggestion.next) == ("v0.1.0", "v0.2.0")
+
+
+@pytest.mark.asyncio
+async def test_release_dry_run_resolves_tag_and_creates_nothing(repo, llm):
+    _git(repo, "tag", "v1.4.2")
+    _commit(repo, "a.py", "", "fix: retry on timeout")
+    llm.append({"tag": "ignored", "notes": "Bug fixes."})
+
+    result = await _call("create_release", dry_run=True)
+
+    assert (result.tag, result.previous_tag, result.published) == (
+        "v1.4.3",
+        "v1.4.2",
+        False,
+    )
+    assert "git tag -a v1.4.3 -m 'Release v1.4.3'" in result.commands
+    assert _git(repo, "tag", "--list") == "v1.4.2"
+
+
+@pytest.mark.asyncio
+async def test_bump_version_commits_manifest(repo):
+    _commit(repo, "pyproject.toml", '[project]\nversion = "0.3.1"\n', "chore: add")
+
+    bumped = await _call("bump_version", level="minor", allow_protected=True)
+
+    assert (bumped.old_version, bumped.new_version, bumped.committed) == (
+        "0.3.1",
+        "0.4.0",
+        True,
+    )
+    assert 'version = "0.4.0"' in (repo / "pyproject.toml").read_text()
+    assert _subjects(repo)[0] == "chore(release): bump version to 0.4.0"
+
+
+@pytest.mark.asyncio
+async def test_bump_version_rolls_back_manifest_when_commit_fails(repo):
+    _git(repo, "switch", "-qc", "chore/bump")
+    _commit(repo, "pyproject.toml", '[project]\nversion = "0.3.1"\n', "chore: add")
+    hook = repo / ".git" / "hooks" / "pre-commit"
+    hook.write_text("#!/bin/sh\nexit 1\n")
+    hook.chmod(0o755)
+
+    with pytest.raises(ToolError, match="manifest restored"):
+        await _call("bump_version", level="patch")
+
+    assert 'version = "0.3.1"' in (repo / "pyproject.toml").read_text()
+    assert _git(repo, "status", "--porcelain") == ""
+    assert _subjects(repo)[0] == "chore: add"
+
+
+@pytest.mark.asyncio
+async def test_history_cleanup_keeps_tree_and_backup(repo):
+    _git(repo, "switch", "-qc", "agent/work")
+    for i, subject in enumerate(["wip", "wip 2", "fix typo"]):
+        _commit(repo, f"f{i}.py", f"x = {i}\n", subject)
+    old_head = _git(repo, "rev-parse", "HEAD")
+    old_tree = _git(repo, "rev-parse", "HEAD^{tree}")
+    shas = _git(repo, "rev-list", "--reverse", "main..HEAD").splitlines()
+
+    result = await _call(
+        "cleanup_branch_history",
+        plan=[{"commits": shas, "title": "feat: add workers", "body": ""}],
+    )
+
+    assert (result.branch, result.commits) == ("agent/work", ["feat: add workers"])
+    assert _subjects(repo, "main..HEAD") == ["feat: add workers"]
+    assert _git(repo, "rev-parse", "HEAD^{tree}") == old_tree
+    backup = result.backup_ref
+    assert _git(repo, "rev-parse", backup) == old_head
+
+    # Rolling back to the backup restores the original history exactly.
+    _git(repo, "reset", "-q", "--hard", backup)
+    assert _subjects(repo, "main..HEAD") == ["fix typo", "wip 2", "wip"]
+
+
+@pytest.mark.asyncio
+async def test_rebase_plan_autosquashes_fixups(repo):
+    _git(repo, "switch", "-qc", "agent/work")
+    _commit(repo, "a.py", "a = 1\n", "feat: add a")
+    _commit(repo, "b.py", "b = 1\n", "feat: add b")
+    _commit(repo, "a.py", "a = 2\n", "fixup! feat: add a")
+
+    plan = await _call("plan_rebase")
+    steps = [{"action": s.action, "commit": s.commit} for s in plan.suggested]
+    assert [s["action"] for s in steps] == ["pick", "fixup", "pick"]
+
+    result = await _call("execute_rebase", steps=steps)
+
+    assert (result.branch, len(result.commits), result.stopped) == (
+        "agent/work",
+        2,
+        False,
+    )
+    assert _subjects(repo, "main..HEAD") == ["feat: add b", "feat: add a"]
+    assert (repo / "a.py").read_text() == "a = 2\n"
+
+
+@pytest.mark.asyncio
+async def test_rebase_conflict_resolved_and_continued(repo):
+    _git(repo, "switch", "-qc", "agent/work")
+    _commit(repo, "a.py", "a = 1\n", "feat: add a")
+    _commit(repo, "a.py", "a = 2\n", "fix: bump a")
+    first, second = _git(repo, "rev-list", "--reverse", "main..HEAD").splitlines()
+    steps = [{"commit": second}, {"commit": first}]
+
+    stopped = await _call("execute_rebase", steps=steps)
+    assert (stopped.stopped, stopped.conflicts) == (True, ["a.py"])
+
+    report = await _call("list_conflicts")
+    assert report.operation == "rebase"
+    assert [f.path for f in report.files] == ["a.py"]
+    with pytest.raises(ToolError, match="Unresolved conflicts"):
+        await _call("continue_rebase")
+
+    await _call("resolve_conflict", path="a.py", content="a = 2\n")
+    # Replaying the original first commit conflicts in turn.
+    with pytest.raises(ToolError, match="a.py"):
+        await _call("continue_rebase")
+    await _call("resolve_conflict", path="a.py", content="a = 3\n")
+    assert (await _call("continue_rebase")).done
+
+    assert _subjects(repo, "main..HEAD") == ["feat: add a", "fix: bump a"]
+    assert (repo / "a.py").read_text() == "a = 3\n"
+    assert _git(repo, "status", "--porcelain") == ""
+
+
+@pytest.mark.asyncio
+async def test_paused_mutations_are_denied_and_audited(repo):
+    await _call("pause_mutations", paused=True)
+
+    with pytest.raises(ToolError):
+        await _call("create_branch", name="blocked")
+    with pytest.raises(ToolError, match="PolicyDenied: 'fetch'"):
+        await _call("fetch")
+    preview = await _call("create_branch", name="blocked", dry_run=True)
+
+    assert not preview.done
+    assert preview.commands == ["git switch --create blocked"]
+    assert _git(repo, "branch", "--list", "blocked") == ""
+    records = [
+        json.loads(line)
+        for line in get_config().audit_file.read_text().splitlines()
+    ]
+    denied = [r for r in records if r["tool"] == "create_branch" and not r["success"]]
+    assert len(denied) == 1
+
+
+@pytest.mark.asyncio
+async def test_destructive_tools_need_approval(repo, monkeypatch):
+    _git(repo, "branch", "old")
+    monkeypatch.setattr(get_config(), "approval_required", True)
+    monkeypatch.setattr(get_config(), "approval_token", SecretStr("s3cret"))
+
+    with pytest.raises(ToolError, match="Approval required.*no approval token"):
+        await _call("delete_branch", name="old")
+    with pytest.raises(ToolError, match="Approval required: 'push'"):
+        await _call("push", force_with_lease=True)
+    assert (await _call("create_branch", name="feat/x")).done
+    assert not (await _call("delete_branch", name="old", dry_run=True)).done
+
+    async with ServerHarness(mcp) as server:
+        with pytest.raises(ToolError, match="wrong approval token"):
+            await server.call(
+                "delete_branch", meta={"approval_token": "guess"}, name="old"
+            )
+        assert _git(repo, "branch", "--list", "old")
+        approved = await server.call(
+            "delete_branch", meta={"approval_token": "s3cret"}, name="old"
+        )
+    assert approved.done
+    assert _git(repo, "branch", "--list", "old") == ""
+
+
+@pytest.mark.asyncio
+async def test_mutations_queue_per_repo_and_rate_limits_apply(repo, monkeypatch):
+    (repo / "Makefile").write_text("slow:\n\tsleep 1\n")
+    monkeypatch.setattr(get_config(), "mcp_queue_timeout", 0.2)
+    monkeypatch.setattr(get_config(), "mcp_rate_limits", {"list_tags": 1})
+
+    async def create_branch_while_running():
+        await asyncio.sleep(0.1)
+        return await _call("create_branch", name="feat/x")
+
+    script, branch = await asyncio.gather(
+        _call("run_script", name="slow"),
+        create_branch_while_running(),
+        return_exceptions=True,
+    )
+
+    assert script.exit_code == 0
+    assert isinstance(branch, ToolError)
+    assert str(branch).startswith("Busy: run_script is running in")
+    await _call("list_tags")
+    with pytest.raises(ToolError, match="Rate limited: list_tags allows 1 call"):
+        await _call("list_tags")
+
+
+@pytest.mark.asyncio
+async def test_telemetry_counts_calls_by_outcome(repo, monkeypatch):
+    counted = []
+    telemetry = next(m for m in mcp.middleware if isinstance(m, Telemetry))
+    monkeypatch.setattr(
+        telemetry, "_calls", SimpleNamespace(add=lambda n, attrs: counted.append(attrs))
+    )
+
+    await _call("get_status")
+    with pytest.raises(ToolError):
+        await _call("switch_branch", name="missing")
+
+    assert [(a["mcp.name"], a["mcp.outcome"]) for a in counted] == [
+        ("get_status", "ok"),
+        ("switch_branch", "error"),
+    ]
+    assert counted[0]["mcp.server"] == "workflow"
+
+
+@pytest.mark.asyncio
+async def test_tools_work_on_an_allowed_second_repo(repo, tmp_path, llm, monkeypatch):
+    other = GitRepo.create(tmp_path / "other", branch="main").path
+    _commit(other, "lib.py", "x = 1\n", "chore: initial commit")
+    _git(other, "switch", "-qc", "feat/lib")
+    (other / "lib.py").write_text("x = 2\n")
+    monkeypatch.setattr(get_config(), "workflow_repos", [other])
+
+    [entry] = await _call("list_repos")
+    assert (entry.name, entry.available, entry.current) == ("other", True, False)
+
+    llm.append({"title": "fix: bump x", "body": ""})
+    result = await _call("stage_and_commit", repo_path=str(other))
+
+    assert (result.branch, result.title) == ("feat/lib", "fix: bump x")
+    assert _subjects(other)[0] == "fix: bump x"
+    assert _subjects(repo) == ["chore: initial commit"]
+    with pytest.raises(ToolError, match="not an allowed repository"):
+        await _call("get_status", repo_path=str(tmp_path))
+
+
+@pytest.mark.asyncio
+async def test_diff_follows_the_render_mode(repo, monkeypatch):
+    _git(repo, "config", "color.diff", "always")
+    (repo / "README.md").write_text("# demo\n\nmore\n")
+    _git(repo, "add", "README.md")
+
+    plain = await _call("get_diff")
+    assert plain.startswith("diff --git")
+    assert "\x1b[" not in plain
+    assert "+more" in plain
+
+    monkeypatch.setattr(get_config(), "mcp_render_modes", {"workflow": "markdown"})
+    assert await _call("get_diff") == f"```diff\n{plain}\n```"
+
+    monkeypatch.setattr(get_config(), "mcp_render_mode", "ansi")
+    monkeypatch.setattr(get_config(), "mcp_render_modes", {})
+    assert "\x1b[" in await _call("get_diff")
+
+
+@pytest.mark.asyncio
+async def test_unified_server_namespaces_every_family(repo):
+    async with ServerHarness(unified) as server:
+        names = await server.tools()
+        assert {"git.get_status", "scout.dependencies", "style.adapt"} <= set(names)
+        assert not any(name.startswith("git_") for name in names)
+
+        await server.call("git.pause_mutations", paused=True)
+        with pytest.raises(ToolError):
+            await server.call("git.create_branch", name="blocked")
+    assert _git(repo, "branch", "--list", "blocked") == ""
+
+
+@pytest.mark.asyncio
+async def test_scout_tools_stay_inside_the_working_directory(repo):
+    async with ServerHarness(unified) as server:
+        readme = await server.call("scout.read_file", path="README.md")
+        assert "# demo" in str(readme)
+        for target in ("/", "..", str(repo.parent)):
+            with pytest.raises(ToolError, match="outside"):
+                await server.call(
+                    "scout.read_file", path="etc/hostname", target_directory=target
+                )
+
+
+@pytest.mark.asyncio
+async def test_lint_directive_reads_only_directives_and_the_working_tree(
+    repo, tmp_path
+):
+    (tmp_path / "secret.md").write_text("# Secret\n")
+    (repo / "team.md").write_text("# Team\n")
+    async with ServerHarness(unified) as server:
+        assert (await server.call("style.lint_directive", target="team.md")).files
+        assert (await server.call("style.lint_directive", target="core")).files
+        for target in ("../secret.md", str(tmp_path / "secret.md"), "/etc"):
+            with pytest.raises(ToolError, match="outside"):
+                await server.call("style.lint_directive", target=target)
+
+
+@pytest.mark.asyncio
+async def test_directive_coverage_stays_inside_the_working_directory(repo):
+    async with ServerHarness(unified) as server:
+        assert (await server.call("style.coverage")).root == str(repo.resolve())
+        for target in ("/", "..", str(repo.parent)):
+            with pytest.raises(ToolError, match="outside"):
+                await server.call("style.coverage", target_directory=target)
+
+
+@pytest.mark.asyncio
+async def test_autocommit_prompt_carries_focus_and_scope(repo):
+    async with ServerHarness(mcp) as server:
+        text = await server.prompt("autocommit", focus="the login fix", scope="auth")
+
+    assert "'the login fix'" in text
+    assert "`feat(auth): ...`" in text
+    assert "`stage_and_commit`" in text
+
+
+@pytest.mark.asyncio
+async def test_run_workflow_prompt_embeds_prompt_steps(repo):
+    workflows = repo / ".azathoth" / "workflows"
+    workflows.mkdir(parents=True)
+    (workflows / "tidy.yaml").write_text(
+        "inputs:\n"
+        "  focus: {default: the cleanup}\n"
+        "steps:\n"
+        "  - {id: check, tool: preflight}\n"
+        "  - {id: commit, prompt: autocommit, with: {focus: '{{ focus }}'}}\n"
+    )
+    (workflows / "broken.yaml").write_text("steps:\n  - {id: x, tool: no_such_tool}\n")
+
+    async with ServerHarness(mcp) as server:
+        text = await server.prompt("run_workflow", workflow="tidy")
+        with pytest.raises(Exception, match="no_such_tool"):
+            await server.prompt("run_workflow", workflow="broken")
+
+    assert "1.  **Check**: Call the `preflight` tool." in text
+    assert "    **User's Focus for this commit is:** 'the cleanup'." in text
@@ -0,0 +1,153 @@
+import pytest
+
+from azathoth.config import ConfigError, Settings, load_layered_config
+
+
+@pytest.fixture
+def layered(tmp_path):
+    user = tmp_path / "config.toml"
+    user.write_text(
+        'ollama_model = "user-model"\n'
+        'ollama_host = "http://${OLLAMA_HOSTNAME:-localhost}:11434"\n'
+    )
+    project = tmp_path / "azathoth.toml"
+    project.write_text(
+        'ollama_model = "project-model"\n'
+        "[profiles.ci]\n"
+        "workflow_dry_run = true\n"
+        'gemini_model = "${CI_MODEL}"\n'
+    )
+    return [user, project]
+
+
+def test_later_files_override_and_env_interpolates(layered, monkeypatch):
+    monkeypatch.setenv("OLLAMA_HOSTNAME", "gpu-box")
+
+    data = load_layered_config(layered)
+
+    assert data["ollama_model"] == "project-model"
+    assert data["ollama_host"] == "http://gpu-box:11434"
+    assert "workflow_dry_run" not in data
+    assert "profiles" not in data
+
+
+def test_interpolation_default_when_unset(layered, monkeypatch):
+    monkeypatch.delenv("OLLAMA_HOSTNAME", raising=False)
+    assert load_layered_config(layered)["ollama_host"] == "http://localhost:11434"
+
+
+def test_profile_overrides_base(layered, monkeypatch):
+    monkeypatch.setenv("CI_MODEL", "ci-model")
+
+    data = load_layered_config(layered, profile="ci")
+
+    assert data["profile"] == "ci"
+    assert data["workflow_dry_run"] is True
+    assert data["gemini_model"] == "ci-model"
+
+
+def test_unknown_profile_warns(layered):
+    with pytest.warns(UserWarning, match="Unknown config profile"):
+        load_layered_config(layered, profile="nope")
+
+
+def test_settings_use_profile_and_env_wins(layered, monkeypatch):
+    monkeypatch.setattr("azathoth.config.config_files", lambda: layered)
+    monkeypatch.setenv("AZATHOTH_PROFILE", "ci")
+    monkeypatch.setenv("CI_MODEL", "ci-model")
+    monkeypatch.setenv("AZATHOTH_OLLAMA_MODEL", "env-model")
+
+    s = Settings()
+
+    assert s.profile == "ci"
+    assert s.workflow_dry_run is True
+    assert s.ollama_model == "env-model"
+
+
+def test_server_tables_set_prefixed_settings(tmp_path, monkeypatch):
+    project = tmp_path / "azathoth.toml"
+    project.write_text(
+        "[workflow]\n"
+        'protected_branches = ["trunk"]\n'
+        "[scout]\n"
+        'root = "/srv/projects"\n'
+        "[mcp]\n"
+        'transport = "http"\n'
+        "[profiles.ci.workflow]\n"
+        "dry_run = true\n"
+    )
+    monkeypatch.setattr("azathoth.config.config_files", lambda: [project])
+    monkeypatch.setenv("AZATHOTH_PROFILE", "ci")
+
+    s = Settings()
+
+    assert s.workflow_protected_branches == ["trunk"]
+    assert str(s.scout_root) == "/srv/projects"
+    assert s.mcp_transport == "http"
+    assert s.workflow_dry_run is True
+
+
+def test_unknown_server_key_is_an_error(tmp_path):
+    project = tmp_path / "azathoth.toml"
+    project.write_text("[workflow]\nprotect = true\n")
+
+    with pytest.raises(ConfigError, match=r"unknown setting 'protect' in \[workflow\]"):
+        load_layered_config([project])
+
+
+def test_invalid_value_names_file_and_key(tmp_path, monkeypatch):
+    user = tmp_path / "config.toml"
+    user.write_text('[workflow]\nscript_timeout = "soon"\n')
+    monkeypatch.setattr("azathoth.config.config_files", lambda: [user])
+
+    with pytest.raises(ConfigError, match=r"config.toml: \[workflow\] script_timeout"):
+        Settings()
+
+
+def test_project_config_cannot_grant_trust(tmp_path, monkeypatch):
+    monkeypatch.chdir(tmp_path)
+    user = tmp_path / "config.toml"
+    user.write_text('approval_token = "from-user"\n')
+    project = tmp_path / "azathoth.toml"
+    monkeypatch.setattr("azathoth.config.config_files", lambda: [user, project])
+
+    assert Settings().approval_token.get_secret_value() == "from-user"
+    for line in (
+        'approval_token = "known"',
+        "approval_required = false",
+        '[workflow]\nrepos = ["/"]',
+        'github_api_url = "https://evil.example"',
+        '[scout]\nembedding_url = "https://evil.example"',
+        '[scout]\nroot = "/"',
+        "audit_enabled = false",
+        "elicit_confirmations = false",
+        "[workflow]\nprotected_branches = []",
+        "[scout]\ndeny = []",
+        'config_dir = "/tmp/elsewhere"',
+        '[mcp]\notlp_endpoint = "https://evil.example"',
+        'ollama_host = "https://evil.example"',
+        'llm_providers = ["ollama"]',
+        'llm_provider = "ollama"',
+    ):
+        project.write_text(line + "\n")
+        with pytest.raises(ConfigError, match="only be set in the user config"):
+            Settings()
+
+
+def test_project_config_is_not_interpolated(tmp_path, monkeypatch):
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setenv("GITHUB_TOKEN", "secret")
+    user = tmp_path / "config.toml"
+    user.write_text('gemini_model = "${GITHUB_TOKEN}"\n')
+    project = tmp_path / "azathoth.toml"
+    project.write_text(
+        'ollama_model = "${GITHUB_TOKEN}"\n'
+        "[profiles.ci]\n"
+        'scout_embedding_model = "${GITHUB_TOKEN:-x}"\n'
+    )
+
+    data = load_layered_config([user, project], profile="ci")
+
+    assert data["gemini_model"] == "secret"
+    assert data["ollama_model"] == "${GITHUB_TOKEN}"
+    assert data["scout_embedding_model"] == "${GITHUB_TOKEN:-x}"